@@ -0,0 +1,38 @@
+//! CSV dialect shared by the importer and the load test populator.
+
+/// Delimiter, quote character and header handling of a CSV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// Field delimiter (default: `,`)
+    pub delimiter: u8,
+    /// Quote character (default: `"`)
+    pub quote: u8,
+    /// Whether the first record is a header row (default: true)
+    pub has_headers: bool,
+}
+
+impl CsvDialect {
+    /// Tab-separated values with a header row.
+    pub fn tsv() -> Self {
+        Self {
+            delimiter: b'\t',
+            ..Self::default()
+        }
+    }
+
+    /// Set whether the first record is a header row.
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            has_headers: true,
+        }
+    }
+}
@@ -6,7 +6,10 @@
 mod metrics;
 mod sync;
 
-pub use sync::{sync, sync_with_transforms, Config};
+pub use crate::CsvDialect;
+pub use sync::{infer_schema, sync, sync_with_transforms, Config};
 
 // Re-export file source types for convenience
-pub use surreal_sync_file::{FileSource, ResolvedSource, DEFAULT_BUFFER_SIZE};
+pub use surreal_sync_file::{
+    FileSource, HttpOptions, ResolvedSource, S3Credentials, DEFAULT_BUFFER_SIZE,
+};
@@ -3,12 +3,17 @@
 //! This module handles streaming CSV files from various sources and importing them into SurrealDB tables.
 
 use crate::types::{csv_string_to_typed_value, csv_string_to_typed_value_inferred};
+use crate::CsvDialect;
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use indexmap::IndexMap;
 use std::path::PathBuf;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{GeneratorTableDefinition, Row, Schema, Type, TypedValue, Value};
-use surreal_sync_file::{FileSource, ResolvedSource, DEFAULT_BUFFER_SIZE};
+use surreal_sync_core::{
+    FieldMap, GeneratorTableDefinition, Row, Schema, TableSchemaInferrer, Type, TypedValue, Value,
+};
+use surreal_sync_file::{
+    FileSource, HttpOptions, ResolvedSource, S3Credentials, SourceOptions, DEFAULT_BUFFER_SIZE,
+};
 use surreal_sync_runtime::{
     run_source_runtime, ApplyOpts, CheckpointPolicy, Pipeline, PositionedEvent, SourceDriver,
     SourceRuntimeOpts,
@@ -31,17 +36,20 @@ pub struct Config {
     /// List of HTTP/HTTPS URLs to import (legacy, use `sources` instead)
     pub http_uris: Vec<String>,
 
+    /// AWS credentials for `s3://` sources (default provider chain unless set)
+    pub s3_credentials: S3Credentials,
+
+    /// Bearer token, extra headers and timeout for HTTP/HTTPS sources
+    pub http_options: HttpOptions,
+
     /// Target SurrealDB table name
     pub table: String,
 
     /// Number of rows to process in each batch
     pub batch_size: usize,
 
-    /// Whether the CSV has headers (default: true)
-    pub has_headers: bool,
-
-    /// CSV delimiter character (default: ',')
-    pub delimiter: u8,
+    /// Delimiter, quote character and header handling
+    pub dialect: CsvDialect,
 
     /// Optional field to use as record ID
     pub id_field: Option<String>,
@@ -50,8 +58,10 @@ pub struct Config {
     /// When two or more are set, the ID is an [`Value::Array`].
     pub id_columns: Vec<String>,
 
-    /// Optional column names when has_headers is false
-    /// If provided, must match the number of columns in the CSV
+    /// Optional column names when the dialect has no header row
+    /// If provided, must match the number of columns in the CSV.
+    /// Otherwise columns map positionally to the table in `schema`, or
+    /// are named `column_0`, `column_1`, ... without one
     pub column_names: Option<Vec<String>>,
 
     /// Optional path to emit metrics during execution
@@ -66,6 +76,16 @@ pub struct Config {
     pub schema: Option<Schema>,
 }
 
+impl Config {
+    fn source_options(&self) -> SourceOptions {
+        SourceOptions {
+            s3_credentials: self.s3_credentials.clone(),
+            http: self.http_options.clone(),
+            ..SourceOptions::default()
+        }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -73,10 +93,11 @@ impl Default for Config {
             files: vec![],
             s3_uris: vec![],
             http_uris: vec![],
+            s3_credentials: S3Credentials::default(),
+            http_options: HttpOptions::default(),
             table: String::new(),
             batch_size: 1000,
-            has_headers: true,
-            delimiter: b',',
+            dialect: CsvDialect::default(),
             id_field: None,
             id_columns: Vec::new(),
             column_names: None,
@@ -164,6 +185,13 @@ impl CsvStreamDriver {
     }
 
     fn record_to_event(&mut self, record: &csv::StringRecord) -> Result<PositionedEvent<u64>> {
+        let row = self.record_to_row(record)?;
+        let pos = row.index;
+        let change = surreal_sync_core::Change::update(row.table, row.id, row.fields);
+        Ok(PositionedEvent::change(change, pos))
+    }
+
+    fn record_to_row(&mut self, record: &csv::StringRecord) -> Result<Row> {
         if record.len() != self.headers.len() {
             anyhow::bail!(
                 "Column count mismatch in CSV row {}: expected {} columns ({}), but found {} columns",
@@ -174,7 +202,7 @@ impl CsvStreamDriver {
             );
         }
 
-        let mut data: HashMap<String, TypedValue> = HashMap::new();
+        let mut data: IndexMap<String, TypedValue> = IndexMap::new();
         for (i, value) in record.iter().enumerate() {
             if i < self.headers.len() {
                 let column_name = &self.headers[i];
@@ -206,13 +234,10 @@ impl CsvStreamDriver {
             surreal_sync_core::build_composite_record_id(parts)
         };
 
-        let fields: HashMap<String, Value> =
-            data.into_iter().map(|(k, tv)| (k, tv.value)).collect();
+        let fields: FieldMap = data.into_iter().map(|(k, tv)| (k, tv.value)).collect();
         let row = Row::new(self.table.clone(), self.record_count, id_value, fields);
-        let pos = self.record_count;
         self.record_count = self.record_count.saturating_add(1);
-        let change = surreal_sync_core::Change::update(row.table, row.id, row.fields);
-        Ok(PositionedEvent::change(change, pos))
+        Ok(row)
     }
 }
 
@@ -264,28 +289,37 @@ impl SourceDriver for CsvStreamDriver {
     }
 }
 
-/// Process CSV data from a reader and import into SurrealDB
+/// Column names for a header-less file, in schema order.
 ///
-/// This function handles all CSV parsing, data conversion, and SurrealDB insertion
-/// for a single CSV source (file, S3, or HTTP).
-async fn process_csv_reader<S: SurrealSink>(
-    surreal: &S,
+/// A record with one column per schema field maps onto the fields; otherwise
+/// the first column is taken as `id`, the layout the load test populator
+/// writes.
+fn positional_columns(table_schema: &GeneratorTableDefinition, width: usize) -> Vec<String> {
+    let fields = table_schema.field_names();
+    let mut columns = Vec::with_capacity(fields.len() + 1);
+    if width != fields.len() {
+        columns.push("id".to_string());
+    }
+    columns.extend(fields.into_iter().map(str::to_string));
+    columns
+}
+
+/// Build a [`CsvStreamDriver`] over `reader`, reading (or synthesizing) the
+/// column names. Returns `None` for an empty header-less source.
+fn csv_driver(
     config: &Config,
     reader: Box<dyn std::io::Read + Send>,
-    source_name: &str,
-    metrics_collector: Option<&super::metrics::MetricsCollector>,
-    pipeline: &Pipeline,
-    apply_opts: &ApplyOpts,
-) -> Result<()> {
-    info!("Processing CSV from: {source_name}");
-
+    table_schema: Option<GeneratorTableDefinition>,
+) -> Result<Option<CsvStreamDriver>> {
     // Create CSV reader with configuration
+    let dialect = config.dialect;
     let mut csv_reader = csv::ReaderBuilder::new()
-        .has_headers(config.has_headers)
-        .delimiter(config.delimiter)
+        .has_headers(dialect.has_headers)
+        .delimiter(dialect.delimiter)
+        .quote(dialect.quote)
         .from_reader(reader);
 
-    let (headers, pending_first) = if config.has_headers {
+    let (headers, pending_first) = if dialect.has_headers {
         let headers = csv_reader
             .headers()
             .context("Failed to read CSV headers")?
@@ -301,13 +335,15 @@ async fn process_csv_reader<S: SurrealSink>(
         let mut first = csv::StringRecord::new();
         match csv_reader.read_record(&mut first) {
             Ok(true) => {
-                let headers: Vec<String> =
-                    (0..first.len()).map(|i| format!("column_{i}")).collect();
+                let headers = match &table_schema {
+                    Some(table_schema) => positional_columns(table_schema, first.len()),
+                    None => (0..first.len()).map(|i| format!("column_{i}")).collect(),
+                };
                 (headers, Some(first))
             }
             Ok(false) => {
                 warn!("CSV file is empty");
-                return Ok(());
+                return Ok(None);
             }
             Err(e) => {
                 return Err(anyhow::anyhow!("Failed to read CSV records: {e}"));
@@ -317,13 +353,7 @@ async fn process_csv_reader<S: SurrealSink>(
 
     debug!("CSV headers/columns: {headers:?}");
 
-    let table_schema = config
-        .schema
-        .as_ref()
-        .and_then(|s| s.get_table(&config.table))
-        .cloned();
-
-    let mut driver = CsvStreamDriver {
+    Ok(Some(CsvStreamDriver {
         reader: csv_reader,
         headers,
         table: config.table.clone(),
@@ -335,6 +365,32 @@ async fn process_csv_reader<S: SurrealSink>(
         sunk_count: 0,
         finished: false,
         pending_first,
+    }))
+}
+
+/// Process CSV data from a reader and import into SurrealDB
+///
+/// This function handles all CSV parsing, data conversion, and SurrealDB insertion
+/// for a single CSV source (file, S3, or HTTP).
+async fn process_csv_reader<S: SurrealSink>(
+    surreal: &S,
+    config: &Config,
+    reader: Box<dyn std::io::Read + Send>,
+    source_name: &str,
+    metrics_collector: Option<&super::metrics::MetricsCollector>,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<()> {
+    info!("Processing CSV from: {source_name}");
+
+    let table_schema = config
+        .schema
+        .as_ref()
+        .and_then(|s| s.get_table(&config.table))
+        .cloned();
+
+    let Some(mut driver) = csv_driver(config, reader, table_schema)? else {
+        return Ok(());
     };
 
     if config.dry_run {
@@ -370,6 +426,115 @@ async fn process_csv_reader<S: SurrealSink>(
     Ok(())
 }
 
+/// Resolve `sources` and the legacy `files` / `s3_uris` / `http_uris` into
+/// the CSV sources to import, in order.
+async fn resolve_sources(config: &Config) -> Result<Vec<ResolvedSource>> {
+    let mut all_resolved: Vec<ResolvedSource> = Vec::new();
+
+    // Process new unified sources
+    for source in &config.sources {
+        let resolved = source
+            .resolve_with(&config.source_options())
+            .await
+            .with_context(|| format!("Failed to resolve source: {}", source.display_name()))?;
+
+        // Filter by .csv / .tsv extension
+        let csv_files: Vec<_> = resolved
+            .into_iter()
+            .filter(|r| {
+                r.extension()
+                    .map(|e| e.eq_ignore_ascii_case("csv") || e.eq_ignore_ascii_case("tsv"))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        if csv_files.is_empty() && source.is_directory() {
+            warn!("No CSV files found in directory: {}", source.display_name());
+        }
+
+        all_resolved.extend(csv_files);
+    }
+
+    // Also process legacy fields for backward compatibility
+    for file_path in &config.files {
+        all_resolved.push(ResolvedSource::Local(file_path.clone()));
+    }
+
+    for s3_uri in &config.s3_uris {
+        // Parsed as a source so wildcard keys (`s3://bucket/users_*.csv`)
+        // expand to the matching objects.
+        all_resolved.extend(
+            FileSource::parse(s3_uri)?
+                .resolve_with(&config.source_options())
+                .await
+                .with_context(|| format!("Failed to resolve source: {s3_uri}"))?,
+        );
+    }
+
+    for http_uri in &config.http_uris {
+        all_resolved.push(ResolvedSource::Http(http_uri.clone()));
+    }
+
+    info!("Resolved {} CSV sources to process", all_resolved.len());
+    Ok(all_resolved)
+}
+
+/// Infer the target table's schema from the first `sample_size` records.
+///
+/// Sources are read in import order until the sample is full. Values are
+/// parsed exactly as an import without `--schema-file` would parse them, so
+/// the inferred types describe what the import writes.
+pub async fn infer_schema(config: &Config, sample_size: usize) -> Result<Schema> {
+    // Id columns stay regular fields alongside the record id; index them.
+    let id_columns = if config.id_columns.is_empty() {
+        config.id_field.iter().cloned().collect()
+    } else {
+        config.id_columns.clone()
+    };
+    let mut inferrer =
+        TableSchemaInferrer::new(config.table.clone()).with_indexed_fields(id_columns);
+    for resolved_source in resolve_sources(config).await? {
+        if inferrer.records() >= sample_size as u64 {
+            break;
+        }
+        let reader = resolved_source
+            .open_with(DEFAULT_BUFFER_SIZE, &config.source_options())
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to open CSV source: {}",
+                    resolved_source.display_name()
+                )
+            })?;
+        let Some(mut driver) = csv_driver(config, reader, None)? else {
+            continue;
+        };
+        while inferrer.records() < sample_size as u64 {
+            let record = if let Some(first) = driver.pending_first.take() {
+                first
+            } else {
+                let mut record = csv::StringRecord::new();
+                if !driver
+                    .reader
+                    .read_record(&mut record)
+                    .context("Failed to read CSV record")?
+                {
+                    break;
+                }
+                record
+            };
+            let row = driver.record_to_row(&record)?;
+            inferrer.observe(&row.id, &row.fields);
+        }
+    }
+    info!(
+        "Inferred schema for table {} from {} sampled records",
+        config.table,
+        inferrer.records()
+    );
+    Ok(Schema::new(vec![inferrer.finish()]))
+}
+
 /// Sync CSV files to SurrealDB with identity transforms.
 ///
 /// This function streams CSV files from various sources and imports them into a SurrealDB table
@@ -436,53 +601,12 @@ pub async fn sync_with_transforms<S: SurrealSink>(
     // Get metrics collector reference for passing to process_csv_reader
     let metrics_ref = metrics_task.as_ref().map(|(collector, _)| collector);
 
-    // Collect all resolved sources
-    let mut all_resolved: Vec<ResolvedSource> = Vec::new();
-
-    // Process new unified sources
-    for source in &config.sources {
-        let resolved = source
-            .resolve()
-            .await
-            .with_context(|| format!("Failed to resolve source: {}", source.display_name()))?;
-
-        // Filter by .csv extension
-        let csv_files: Vec<_> = resolved
-            .into_iter()
-            .filter(|r| {
-                r.extension()
-                    .map(|e| e.eq_ignore_ascii_case("csv"))
-                    .unwrap_or(false)
-            })
-            .collect();
-
-        if csv_files.is_empty() && source.is_directory() {
-            warn!("No CSV files found in directory: {}", source.display_name());
-        }
-
-        all_resolved.extend(csv_files);
-    }
-
-    // Also process legacy fields for backward compatibility
-    for file_path in &config.files {
-        all_resolved.push(ResolvedSource::Local(file_path.clone()));
-    }
-
-    for s3_uri in &config.s3_uris {
-        let (bucket, key) = surreal_sync_file::parse_s3_uri(s3_uri)?;
-        all_resolved.push(ResolvedSource::S3 { bucket, key });
-    }
-
-    for http_uri in &config.http_uris {
-        all_resolved.push(ResolvedSource::Http(http_uri.clone()));
-    }
-
-    info!("Resolved {} CSV sources to process", all_resolved.len());
+    let all_resolved = resolve_sources(&config).await?;
 
     // Process each resolved source
     for resolved_source in &all_resolved {
         let reader = resolved_source
-            .open(DEFAULT_BUFFER_SIZE)
+            .open_with(DEFAULT_BUFFER_SIZE, &config.source_options())
             .await
             .with_context(|| {
                 format!(
@@ -566,8 +690,7 @@ mod tests {
     fn test_config_default() {
         let config = Config::default();
         assert_eq!(config.batch_size, 1000);
-        assert!(config.has_headers);
-        assert_eq!(config.delimiter, b',');
+        assert_eq!(config.dialect, CsvDialect::default());
         assert!(!config.dry_run);
     }
 
@@ -595,6 +718,155 @@ mod tests {
         assert_eq!(mock_sink.rows_written(), 2); // 2 data rows
     }
 
+    #[tokio::test]
+    async fn test_infer_schema_samples_first_records() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "id,name,age,score").unwrap();
+        writeln!(temp_file, "1,Alice,30,1.5").unwrap();
+        writeln!(temp_file, "2,Bob,,2").unwrap();
+        writeln!(temp_file, "3,Carol,41,not-a-number").unwrap();
+        temp_file.flush().unwrap();
+
+        let config = Config {
+            files: vec![temp_file.path().to_path_buf()],
+            table: "people".to_string(),
+            id_field: Some("id".to_string()),
+            ..Default::default()
+        };
+        let schema = infer_schema(&config, 2).await.unwrap();
+        let table = schema.get_table("people").unwrap();
+        assert_eq!(table.id.id_type, Type::Int64);
+        assert_eq!(table.get_field_type("name"), Some(&Type::Text));
+        assert_eq!(table.get_field_type("score"), Some(&Type::Float64));
+        let age = table.get_field("age").unwrap();
+        assert_eq!(age.field_type, Type::Int64);
+        assert!(age.nullable);
+    }
+
+    /// Read every row of `data` through the import driver.
+    fn read_rows(config: &Config, data: &str) -> Vec<Row> {
+        let table_schema = config
+            .schema
+            .as_ref()
+            .and_then(|s| s.get_table(&config.table))
+            .cloned();
+        let reader: Box<dyn std::io::Read + Send> =
+            Box::new(std::io::Cursor::new(data.as_bytes().to_vec()));
+        let mut driver = csv_driver(config, reader, table_schema).unwrap().unwrap();
+        let mut rows = Vec::new();
+        loop {
+            let record = if let Some(first) = driver.pending_first.take() {
+                first
+            } else {
+                let mut record = csv::StringRecord::new();
+                if !driver.reader.read_record(&mut record).unwrap() {
+                    break;
+                }
+                record
+            };
+            rows.push(driver.record_to_row(&record).unwrap());
+        }
+        rows
+    }
+
+    fn users_schema() -> Schema {
+        Schema::from_yaml(
+            r#"
+version: 1
+seed: 42
+tables:
+  - name: users
+    id:
+      type: int
+      generator:
+        type: sequential
+        start: 1
+    fields:
+      - name: name
+        type: text
+        generator:
+          type: pattern
+          pattern: "user_{index}"
+      - name: age
+        type: int
+        generator:
+          type: int_range
+          min: 18
+          max: 80
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tsv_with_headers() {
+        let config = Config {
+            table: "users".to_string(),
+            id_field: Some("id".to_string()),
+            dialect: CsvDialect::tsv(),
+            ..Default::default()
+        };
+        let rows = read_rows(
+            &config,
+            "id\tname\tnote\n1\tAlice\ta, b\n2\tBob\t\"x\ty\"\n",
+        );
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].id, Value::Int64(1));
+        assert_eq!(rows[0].get_field("name").unwrap().as_str(), Some("Alice"));
+        assert_eq!(rows[0].get_field("note").unwrap().as_str(), Some("a, b"));
+        assert_eq!(rows[1].get_field("note").unwrap().as_str(), Some("x\ty"));
+    }
+
+    #[test]
+    fn test_tsv_without_headers_maps_schema_fields() {
+        let config = Config {
+            table: "users".to_string(),
+            id_field: Some("id".to_string()),
+            dialect: CsvDialect::tsv().with_headers(false),
+            schema: Some(users_schema()),
+            ..Default::default()
+        };
+        let rows = read_rows(&config, "1\tAlice\t30\n2\tBob\t25\n");
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].id, Value::Int64(1));
+        assert_eq!(rows[0].get_field("name").unwrap().as_str(), Some("Alice"));
+        assert_eq!(rows[0].get_field("age"), Some(&Value::Int32(30)));
+        assert_eq!(rows[1].get_field("age"), Some(&Value::Int32(25)));
+    }
+
+    #[test]
+    fn test_headerless_without_id_column_maps_fields_only() {
+        let config = Config {
+            table: "users".to_string(),
+            dialect: CsvDialect::tsv().with_headers(false),
+            schema: Some(users_schema()),
+            ..Default::default()
+        };
+        let rows = read_rows(&config, "Alice\t30\n");
+
+        assert_eq!(rows[0].get_field("name").unwrap().as_str(), Some("Alice"));
+        assert_eq!(rows[0].get_field("age"), Some(&Value::Int32(30)));
+        assert!(rows[0].get_field("id").is_none());
+    }
+
+    #[test]
+    fn test_pipe_delimiter_with_custom_quote() {
+        let config = Config {
+            table: "users".to_string(),
+            dialect: CsvDialect {
+                delimiter: b'|',
+                quote: b'\'',
+                has_headers: true,
+            },
+            ..Default::default()
+        };
+        let rows = read_rows(&config, "name|note\nAlice|'a|b'\n");
+
+        assert_eq!(rows[0].get_field("note").unwrap().as_str(), Some("a|b"));
+    }
+
     #[test]
     fn test_parse_value_with_schema_int() {
         let result = parse_value_with_schema("42", Some(&Type::Int32));
@@ -1,5 +1,9 @@
 //! CSV type conversions and from_csv origin for surreal-sync.
 
+pub mod dialect;
+
+pub use dialect::CsvDialect;
+
 #[cfg(feature = "types")]
 pub mod types;
 
@@ -4,7 +4,7 @@
 
 use base64::Engine;
 use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
-use surreal_sync_core::{Type, TypedValue, Value};
+use surreal_sync_core::{FieldMap, Type, TypedValue, Value};
 
 /// A CSV string with schema information for reverse conversion.
 ///
@@ -425,7 +425,7 @@ pub fn csv_string_to_typed_value(
                 })?;
 
             if let serde_json::Value::Object(obj) = json {
-                let map: std::collections::HashMap<String, Value> = obj
+                let map: FieldMap = obj
                     .into_iter()
                     .map(|(k, v)| (k, json_to_generated_value(&v)))
                     .collect();
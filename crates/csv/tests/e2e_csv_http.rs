@@ -10,7 +10,7 @@ use axum::{
     routing::get,
     Router,
 };
-use surreal_sync_csv::from_csv::{sync, Config};
+use surreal_sync_csv::from_csv::{sync, Config, CsvDialect};
 use surreal_sync_surreal::version::testing::SurrealDbContainer;
 use surreal_sync_surreal::version::SurrealMajorVersion;
 use surrealdb3::types::SurrealValue;
@@ -153,10 +153,11 @@ async fn test_csv_http_import() {
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![csv_url.clone()],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: table.to_string(),
         batch_size: 100,
-        has_headers: true,
-        delimiter: b',',
+        dialect: CsvDialect::default(),
         id_field: Some("id".to_string()),
         id_columns: Vec::new(),
         column_names: None,
@@ -257,10 +258,11 @@ async fn test_csv_http_import_with_path() {
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![csv_url],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: table.to_string(),
         batch_size: 100,
-        has_headers: true,
-        delimiter: b',',
+        dialect: CsvDialect::default(),
         id_field: Some("id".to_string()),
         id_columns: Vec::new(),
         column_names: None,
@@ -416,10 +418,11 @@ async fn test_csv_http_404_error() {
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![csv_url],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: "users".to_string(),
         batch_size: 100,
-        has_headers: true,
-        delimiter: b',',
+        dialect: CsvDialect::default(),
         id_field: None,
         id_columns: Vec::new(),
         column_names: None,
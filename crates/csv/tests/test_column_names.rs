@@ -3,7 +3,7 @@
 //! Works with both SurrealDB v2 and v3 servers.
 
 use std::io::Write;
-use surreal_sync_csv::from_csv::{sync, Config};
+use surreal_sync_csv::from_csv::{sync, Config, CsvDialect};
 use surreal_sync_surreal::version::testing::SurrealDbContainer;
 use surreal_sync_surreal::version::SurrealMajorVersion;
 use surrealdb3::types::SurrealValue;
@@ -94,10 +94,11 @@ async fn test_csv_with_custom_column_names() {
         files: vec![temp_file.path().to_path_buf()],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: table.to_string(),
         batch_size: 100,
-        has_headers: false,
-        delimiter: b',',
+        dialect: CsvDialect::default().with_headers(false),
         id_field: Some("user_id".to_string()),
         id_columns: Vec::new(),
         column_names: Some(vec![
@@ -199,10 +200,11 @@ async fn test_csv_column_count_mismatch_error() {
         files: vec![temp_file.path().to_path_buf()],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: table.to_string(),
         batch_size: 100,
-        has_headers: false,
-        delimiter: b',',
+        dialect: CsvDialect::default().with_headers(false),
         id_field: Some("user_id".to_string()),
         id_columns: Vec::new(),
         column_names: Some(vec![
@@ -272,10 +274,11 @@ async fn test_csv_without_headers_auto_generated_names() {
         files: vec![temp_file.path().to_path_buf()],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: table.to_string(),
         batch_size: 100,
-        has_headers: false,
-        delimiter: b',',
+        dialect: CsvDialect::default().with_headers(false),
         id_field: Some("column_0".to_string()),
         id_columns: Vec::new(),
         column_names: None,
@@ -368,10 +371,11 @@ async fn test_csv_column_count_mismatch_with_extra_columns_in_row() {
         files: vec![temp_file.path().to_path_buf()],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: table.to_string(),
         batch_size: 100,
-        has_headers: false,
-        delimiter: b',',
+        dialect: CsvDialect::default().with_headers(false),
         id_field: Some("id".to_string()),
         id_columns: Vec::new(),
         column_names: Some(vec![
@@ -0,0 +1,145 @@
+//! Streaming decompression for compressed source files
+
+use anyhow::{Context, Result};
+use std::io::Read;
+
+/// Compression applied to a source file's bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Plain bytes, returned unwrapped
+    #[default]
+    None,
+    /// gzip (`.gz`); concatenated members are read as one stream
+    Gzip,
+    /// Zstandard (`.zst`)
+    Zstd,
+    /// bzip2 (`.bz2`); concatenated streams are read as one stream
+    Bzip2,
+}
+
+impl Compression {
+    /// Compression named by a file extension (without the dot, any case).
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        if extension.eq_ignore_ascii_case("gz") {
+            Some(Self::Gzip)
+        } else if extension.eq_ignore_ascii_case("zst") {
+            Some(Self::Zstd)
+        } else if extension.eq_ignore_ascii_case("bz2") {
+            Some(Self::Bzip2)
+        } else {
+            None
+        }
+    }
+
+    /// Compression named by the last extension of a file name, or
+    /// [`Compression::None`].
+    pub fn from_file_name(name: &str) -> Self {
+        name.rsplit_once('.')
+            .and_then(|(_, extension)| Self::from_extension(extension))
+            .unwrap_or_default()
+    }
+
+    /// Wrap `reader` in the matching streaming decoder. Plain input is
+    /// returned as-is.
+    pub fn decode(self, reader: Box<dyn Read + Send>) -> Result<Box<dyn Read + Send>> {
+        Ok(match self {
+            Self::None => reader,
+            Self::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            Self::Zstd => Box::new(
+                zstd::stream::read::Decoder::new(reader).context("Failed to start zstd decoder")?,
+            ),
+            Self::Bzip2 => Box::new(bzip2::read::MultiBzDecoder::new(reader)),
+        })
+    }
+}
+
+/// `name` without a trailing `.gz` / `.zst` / `.bz2` suffix:
+/// `data.csv.gz` becomes `data.csv`.
+pub fn strip_compression_suffix(name: &str) -> &str {
+    match name.rsplit_once('.') {
+        Some((stem, extension)) if Compression::from_extension(extension).is_some() => stem,
+        _ => name,
+    }
+}
+
+/// Extension of a file name (without the dot), skipping a trailing
+/// compression suffix: `data.csv.gz` reports `csv`.
+pub(crate) fn data_extension(name: &str) -> Option<&str> {
+    strip_compression_suffix(name)
+        .rsplit_once('.')
+        .map(|(_, extension)| extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn decode_all(compression: Compression, bytes: Vec<u8>) -> String {
+        let mut out = String::new();
+        compression
+            .decode(Box::new(std::io::Cursor::new(bytes)))
+            .unwrap()
+            .read_to_string(&mut out)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn test_decode_round_trips_each_format() {
+        let text = "{\"id\":1}\n{\"id\":2}\n";
+
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(text.as_bytes()).unwrap();
+        assert_eq!(decode_all(Compression::Gzip, gzip.finish().unwrap()), text);
+
+        let zstd = zstd::stream::encode_all(text.as_bytes(), 0).unwrap();
+        assert_eq!(decode_all(Compression::Zstd, zstd), text);
+
+        let mut bzip2 = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+        bzip2.write_all(text.as_bytes()).unwrap();
+        assert_eq!(
+            decode_all(Compression::Bzip2, bzip2.finish().unwrap()),
+            text
+        );
+
+        assert_eq!(decode_all(Compression::None, text.into()), text);
+    }
+
+    #[test]
+    fn test_decode_reads_concatenated_gzip_members() {
+        let mut bytes = Vec::new();
+        for part in ["a\n", "b\n"] {
+            let mut gzip =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            gzip.write_all(part.as_bytes()).unwrap();
+            bytes.extend(gzip.finish().unwrap());
+        }
+        assert_eq!(decode_all(Compression::Gzip, bytes), "a\nb\n");
+    }
+
+    #[test]
+    fn test_compression_and_data_extension_from_name() {
+        assert_eq!(
+            Compression::from_file_name("data.jsonl.gz"),
+            Compression::Gzip
+        );
+        assert_eq!(
+            Compression::from_file_name("data.csv.ZST"),
+            Compression::Zstd
+        );
+        assert_eq!(
+            Compression::from_file_name("data.csv.bz2"),
+            Compression::Bzip2
+        );
+        assert_eq!(Compression::from_file_name("data.csv"), Compression::None);
+        assert_eq!(Compression::from_file_name("gz"), Compression::None);
+
+        assert_eq!(strip_compression_suffix("data.csv.gz"), "data.csv");
+        assert_eq!(strip_compression_suffix("data.csv"), "data.csv");
+        assert_eq!(data_extension("data.csv.gz"), Some("csv"));
+        assert_eq!(data_extension("data.csv"), Some("csv"));
+        assert_eq!(data_extension("data.gz"), None);
+        assert_eq!(data_extension("data"), None);
+    }
+}
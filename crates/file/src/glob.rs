@@ -0,0 +1,157 @@
+//! Wildcard patterns in source paths (`*`, `?`, `[...]`, `**`)
+
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobMatcher};
+
+/// Characters a backslash makes literal inside a pattern.
+const ESCAPABLE: &[char] = &['*', '?', '[', ']', '{', '}', '\\'];
+
+/// A compiled pattern, matched against paths relative to the directory
+/// (or S3 prefix) it was split from.
+#[derive(Debug, Clone)]
+pub(crate) struct Pattern {
+    matcher: GlobMatcher,
+    max_depth: Option<usize>,
+}
+
+impl Pattern {
+    /// Compile `pattern`. `*` and `?` stay within one path component, `**`
+    /// spans any number of them, and `\` makes the next character literal.
+    pub(crate) fn new(pattern: &str) -> Result<Self> {
+        let matcher = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .backslash_escape(true)
+            .build()
+            .with_context(|| format!("Invalid wildcard pattern: {pattern}"))?
+            .compile_matcher();
+        let max_depth = (!pattern.contains("**")).then(|| pattern.split('/').count());
+        Ok(Self { matcher, max_depth })
+    }
+
+    /// Whether `relative` (`/`-separated) matches the pattern.
+    pub(crate) fn is_match(&self, relative: &str) -> bool {
+        self.matcher.is_match(relative)
+    }
+
+    /// Deepest number of path components a match can have, or `None` when
+    /// the pattern contains `**`.
+    #[cfg_attr(not(feature = "local"), allow(dead_code))]
+    pub(crate) fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+}
+
+/// Split `path` at the last `/` before its first unescaped wildcard:
+/// `data/2024-*/orders.csv` becomes `("data/", "2024-*/orders.csv")`.
+/// Returns `None` when `path` has no wildcard.
+pub(crate) fn split(path: &str) -> Option<(&str, &str)> {
+    let at = first_wildcard(path)?;
+    let base_len = path[..at].rfind('/').map_or(0, |slash| slash + 1);
+    Some(path.split_at(base_len))
+}
+
+/// Literal text before the first wildcard of `pattern`, with escapes
+/// removed: `users_\[a\]*.jsonl` gives `users_[a]`.
+#[cfg_attr(not(feature = "s3"), allow(dead_code))]
+pub(crate) fn literal_prefix(pattern: &str) -> String {
+    let end = first_wildcard(pattern).unwrap_or(pattern.len());
+    unescape(&pattern[..end])
+}
+
+/// `path` with backslash escapes of wildcard characters removed, for
+/// literal paths such as `data/\[raw\].csv`.
+pub(crate) fn unescape(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(&next) if c == '\\' && ESCAPABLE.contains(&next) => {
+                out.push(next);
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Byte offset of the first unescaped `*`, `?`, or `[` that has a closing `]`.
+fn first_wildcard(path: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in path.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            '*' | '?' => return Some(i),
+            '[' if path[i + 1..].contains(']') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_at_last_directory_before_wildcard() {
+        assert_eq!(
+            split("exports/users_*.jsonl"),
+            Some(("exports/", "users_*.jsonl"))
+        );
+        assert_eq!(
+            split("/data/**/orders.csv"),
+            Some(("/data/", "**/orders.csv"))
+        );
+        assert_eq!(
+            split("/data/2024-??/orders.csv"),
+            Some(("/data/", "2024-??/orders.csv"))
+        );
+        assert_eq!(split("*.csv"), Some(("", "*.csv")));
+        assert_eq!(split("/data/orders.csv"), None);
+        assert_eq!(split("/data/\\[raw\\].csv"), None);
+        assert_eq!(split("/data/[unclosed.csv"), None);
+    }
+
+    #[test]
+    fn test_star_stays_within_one_component() {
+        let pattern = Pattern::new("users_*.jsonl").unwrap();
+        assert!(pattern.is_match("users_1.jsonl"));
+        assert!(pattern.is_match("users_.jsonl"));
+        assert!(!pattern.is_match("users_1.csv"));
+        assert!(!pattern.is_match("old/users_1.jsonl"));
+        assert_eq!(pattern.max_depth(), Some(1));
+        assert_eq!(literal_prefix("users_*.jsonl"), "users_");
+    }
+
+    #[test]
+    fn test_double_star_matches_any_depth() {
+        let pattern = Pattern::new("**/orders.csv").unwrap();
+        assert!(pattern.is_match("orders.csv"));
+        assert!(pattern.is_match("2024/orders.csv"));
+        assert!(pattern.is_match("2024/01/orders.csv"));
+        assert!(!pattern.is_match("2024/01/orders.csv.bak"));
+        assert!(!pattern.is_match("2024/returns.csv"));
+        assert_eq!(pattern.max_depth(), None);
+        assert_eq!(literal_prefix("**/orders.csv"), "");
+    }
+
+    #[test]
+    fn test_escaped_brackets_are_literal() {
+        let pattern = Pattern::new("report\\[1\\]_*.csv").unwrap();
+        assert!(pattern.is_match("report[1]_a.csv"));
+        assert!(!pattern.is_match("report1_a.csv"));
+        assert_eq!(literal_prefix("report\\[1\\]_*.csv"), "report[1]_");
+
+        let class = Pattern::new("part-[0-9].csv").unwrap();
+        assert!(class.is_match("part-3.csv"));
+        assert!(!class.is_match("part-x.csv"));
+
+        assert_eq!(unescape("data/\\[raw\\].csv"), "data/[raw].csv");
+        assert_eq!(unescape("data\\file.csv"), "data\\file.csv");
+    }
+}
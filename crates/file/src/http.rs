@@ -1,6 +1,50 @@
 //! HTTP/HTTPS file reader implementation
 
 use anyhow::{Context, Result};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, LOCATION};
+use reqwest::Url;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Redirects followed before a request is given up on (reqwest's default).
+const MAX_REDIRECTS: usize = 10;
+
+/// Request settings for HTTP/HTTPS sources.
+///
+/// Headers (the bearer token included) are sent to the requested host and
+/// to redirect targets on that same host only, so a redirect to another
+/// host never sees the credentials.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpOptions {
+    /// Sent as `Authorization: Bearer <token>`.
+    pub bearer_token: Option<String>,
+    /// Extra request headers, by name. An `Authorization` entry here is
+    /// replaced by `bearer_token` when both are set.
+    pub headers: HashMap<String, String>,
+    /// Limit on the whole download, body included. `None` waits
+    /// indefinitely.
+    pub timeout: Option<Duration>,
+}
+
+impl HttpOptions {
+    fn header_map(&self) -> Result<HeaderMap> {
+        let mut map = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .with_context(|| format!("Invalid HTTP header name: {name}"))?;
+            let value = HeaderValue::from_str(value)
+                .with_context(|| format!("Invalid value for HTTP header {name}"))?;
+            map.insert(name, value);
+        }
+        if let Some(token) = &self.bearer_token {
+            let mut value = HeaderValue::from_str(&format!("Bearer {token}"))
+                .context("Invalid HTTP bearer token")?;
+            value.set_sensitive(true);
+            map.insert(AUTHORIZATION, value);
+        }
+        Ok(map)
+    }
+}
 
 /// Reads a file from HTTP/HTTPS
 pub struct HttpFileReader;
@@ -20,16 +64,77 @@ impl HttpFileReader {
     /// ).await?;
     /// // Use reader with BufReader for line-by-line processing
     /// ```
-    pub async fn open(url: String, _buffer_size: usize) -> Result<Box<dyn std::io::Read + Send>> {
-        // Create HTTP client
-        let client = reqwest::Client::new();
-
-        // Fetch the URL
-        let response = client
-            .get(&url)
-            .send()
-            .await
-            .with_context(|| format!("Failed to fetch URL: {url}"))?;
+    pub async fn open(url: String, buffer_size: usize) -> Result<Box<dyn std::io::Read + Send>> {
+        Self::open_with_options(url, buffer_size, &HttpOptions::default()).await
+    }
+
+    /// Like [`Self::open`], sending `headers` with the request (e.g.
+    /// `Authorization` for an authenticated gateway)
+    pub async fn open_with_headers(
+        url: String,
+        headers: HashMap<String, String>,
+        buffer_size: usize,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        let options = HttpOptions {
+            headers,
+            ..HttpOptions::default()
+        };
+        Self::open_with_options(url, buffer_size, &options).await
+    }
+
+    /// Like [`Self::open`], with a bearer token, extra headers and a timeout
+    /// (see [`HttpOptions`])
+    pub async fn open_with_options(
+        url: String,
+        _buffer_size: usize,
+        options: &HttpOptions,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        let headers = options.header_map()?;
+
+        // Redirects are followed by hand so headers can be dropped when
+        // the target is another host.
+        let mut builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        let client = builder.build().context("Failed to build HTTP client")?;
+
+        let origin = Url::parse(&url).with_context(|| format!("Invalid URL: {url}"))?;
+        let mut current = origin.clone();
+        let mut redirects = 0;
+
+        let response = loop {
+            let mut request = client.get(current.clone());
+            if same_host(&origin, &current) {
+                request = request.headers(headers.clone());
+            }
+
+            // Fetch the URL
+            let response = request
+                .send()
+                .await
+                .with_context(|| format!("Failed to fetch URL: {current}"))?;
+
+            if !response.status().is_redirection() {
+                break response;
+            }
+            let Some(location) = response.headers().get(LOCATION) else {
+                break response;
+            };
+            if redirects == MAX_REDIRECTS {
+                anyhow::bail!("Too many redirects ({MAX_REDIRECTS}) for URL: {url}");
+            }
+            redirects += 1;
+
+            let location = location
+                .to_str()
+                .with_context(|| format!("Invalid redirect location from: {current}"))?;
+            let next = current
+                .join(location)
+                .with_context(|| format!("Invalid redirect location from {current}: {location}"))?;
+            tracing::debug!("Following redirect from {} to {}", current, next);
+            current = next;
+        };
 
         // Check for successful status
         let status = response.status();
@@ -53,8 +158,122 @@ impl HttpFileReader {
     }
 }
 
+/// Whether a redirect target may receive the headers meant for `origin`.
+fn same_host(origin: &Url, target: &Url) -> bool {
+    origin.host_str() == target.host_str()
+}
+
 #[cfg(test)]
 mod tests {
-    // HTTP tests would require a mock server, skipping for now
-    // The implementation is tested via integration tests in csv/jsonl crates
+    use super::*;
+    use std::io::Read;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Answer one request on `listener` with `response` and return the
+    /// request head, lowercased.
+    async fn serve_once(listener: TcpListener, response: String) -> String {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = stream.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+        stream.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8(request).unwrap().to_lowercase()
+    }
+
+    fn ok(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn redirect(location: &str) -> String {
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: {location}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        )
+    }
+
+    fn options() -> HttpOptions {
+        HttpOptions {
+            bearer_token: Some("secret".to_string()),
+            headers: HashMap::from([("X-Api-Key".to_string(), "key".to_string())]),
+            timeout: Some(Duration::from_secs(10)),
+        }
+    }
+
+    /// Fetch from a server that redirects to `target_host`, returning the
+    /// body and the request head the redirect target received.
+    async fn fetch_via_redirect(target_host: &str) -> (String, String) {
+        let first = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let second = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!(
+            "http://127.0.0.1:{}/data.jsonl",
+            first.local_addr().unwrap().port()
+        );
+        let location = format!(
+            "http://{target_host}:{}/moved.jsonl",
+            second.local_addr().unwrap().port()
+        );
+
+        let first = tokio::spawn(serve_once(first, redirect(&location)));
+        let second = tokio::spawn(serve_once(second, ok("{}\n")));
+
+        let mut reader = HttpFileReader::open_with_options(url, 1024, &options())
+            .await
+            .unwrap();
+        let mut body = String::new();
+        reader.read_to_string(&mut body).unwrap();
+
+        let first_request = first.await.unwrap();
+        assert!(first_request.contains("authorization: bearer secret"));
+        assert!(first_request.contains("x-api-key: key"));
+        (body, second.await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_headers_follow_same_host_redirect() {
+        let (body, request) = fetch_via_redirect("127.0.0.1").await;
+        assert_eq!(body, "{}\n");
+        assert!(request.starts_with("get /moved.jsonl"));
+        assert!(request.contains("authorization: bearer secret"));
+        assert!(request.contains("x-api-key: key"));
+    }
+
+    #[tokio::test]
+    async fn test_headers_dropped_on_cross_host_redirect() {
+        let (body, request) = fetch_via_redirect("localhost").await;
+        assert_eq!(body, "{}\n");
+        assert!(!request.contains("authorization"));
+        assert!(!request.contains("x-api-key"));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_ends_hung_download() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/data.csv", listener.local_addr().unwrap());
+        let _server = tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let options = HttpOptions {
+            timeout: Some(Duration::from_millis(200)),
+            ..HttpOptions::default()
+        };
+        let result = HttpFileReader::open_with_options(url, 1024, &options).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_header_name_is_rejected() {
+        let options = HttpOptions {
+            headers: HashMap::from([("bad header".to_string(), "x".to_string())]),
+            ..HttpOptions::default()
+        };
+        assert!(options.header_map().is_err());
+    }
 }
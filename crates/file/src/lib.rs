@@ -1,7 +1,7 @@
-//! File source abstraction for reading from local filesystem, S3, or HTTP/HTTPS
+//! File source abstraction for reading from local filesystem, S3, GCS, or HTTP/HTTPS
 //!
 //! This crate provides a unified interface for reading files from various sources,
-//! with support for directory enumeration (for local and S3).
+//! with support for directory enumeration (for local, S3 and GCS).
 //!
 //! # Features
 //!
@@ -10,6 +10,7 @@
 //! | `local` | yes | Local filesystem paths and directory listing |
 //! | `http` | yes | `http://` / `https://` single-file URLs (`reqwest`) |
 //! | `s3` | yes | `s3://bucket/key` objects and prefixes (`aws-sdk-s3`) |
+//! | `gcs` | yes | `gs://bucket/object` objects and prefixes (`google-cloud-storage`) |
 //!
 //! Disable backends you do not need with `default-features = false` and an
 //! explicit feature list, e.g. `features = ["local"]`.
@@ -18,6 +19,8 @@
 //!
 //! - **Local**: Files or directories on the local filesystem
 //! - **S3**: Objects or prefixes in AWS S3 buckets
+//! - **GCS**: Objects or prefixes in Google Cloud Storage buckets, read with
+//!   application default credentials
 //! - **HTTP/HTTPS**: Single URLs (no directory support)
 //!
 //! # Directory Detection
@@ -25,6 +28,36 @@
 //! A source is treated as a directory/prefix if it ends with `/`:
 //! - `/data/` - Local directory
 //! - `s3://bucket/prefix/` - S3 prefix
+//! - `gs://bucket/prefix/` - GCS prefix
+//!
+//! # Wildcards
+//!
+//! Local paths and S3 keys may use `*`, `?` and `[...]` in their final
+//! components, and `**` to match any number of directories:
+//! - `/data/users_*.jsonl` - matching files in `/data/`
+//! - `s3://bucket/exports/**/orders.csv` - `orders.csv` at any depth
+//!
+//! `*` and `?` never match `/`. Escape a literal wildcard character with a
+//! backslash (`/data/\[raw\].csv`). Only the directory (or the longest
+//! literal S3 prefix) in front of the first wildcard is listed.
+//!
+//! # HTTP Authentication
+//!
+//! [`SourceOptions::http`] adds a bearer token, extra headers and a timeout
+//! to HTTP/HTTPS requests. Redirects keep the headers only while they stay
+//! on the requested host.
+//!
+//! # Compression
+//!
+//! Files ending in `.gz`, `.zst` or `.bz2` are decompressed while reading,
+//! and [`ResolvedSource::extension`] skips that suffix (`data.csv.gz` reports
+//! `csv`). Use [`ResolvedSource::open_with_compression`] when the extension
+//! does not match the content.
+//!
+//! # Opening Many Files
+//!
+//! [`open_all`] opens a resolved listing with up to
+//! [`SourceOptions::max_concurrent`] opens in flight, keeping input order.
 //!
 //! # Example
 //!
@@ -42,6 +75,11 @@
 //! }
 //! ```
 
+mod compression;
+#[cfg(feature = "gcs")]
+mod gcs;
+#[cfg(any(feature = "local", feature = "s3"))]
+mod glob;
 #[cfg(feature = "http")]
 mod http;
 #[cfg(feature = "local")]
@@ -53,25 +91,75 @@ use anyhow::Result;
 #[cfg(feature = "local")]
 use std::path::PathBuf;
 
+pub use compression::{strip_compression_suffix, Compression};
+#[cfg(feature = "gcs")]
+pub use gcs::{GcsClient, GcsFileReader};
 #[cfg(feature = "http")]
-pub use http::HttpFileReader;
+pub use http::{HttpFileReader, HttpOptions};
 #[cfg(feature = "local")]
 pub use local::LocalFileReader;
 #[cfg(feature = "s3")]
-pub use s3::{S3Client, S3FileReader};
+pub use s3::{S3Client, S3Credentials, S3FileReader, S3ReadOptions};
 
 /// Default buffer size for reading operations (1MB)
 pub const DEFAULT_BUFFER_SIZE: usize = 1024 * 1024;
 
+/// Backend settings used when resolving and opening sources.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceOptions {
+    /// Credentials for `s3://` listing and reads.
+    #[cfg(feature = "s3")]
+    pub s3_credentials: S3Credentials,
+    /// Retry and parallel-part settings for `s3://` reads.
+    #[cfg(feature = "s3")]
+    pub s3_read: S3ReadOptions,
+    /// Auth headers and timeout for `http://` / `https://` reads.
+    #[cfg(feature = "http")]
+    pub http: HttpOptions,
+    /// Sources [`open_all`] opens at once. `None` opens them one at a time.
+    pub max_concurrent: Option<usize>,
+}
+
+/// Open every source in `sources`, up to [`SourceOptions::max_concurrent`]
+/// at a time, yielding the readers in input order
+///
+/// Useful after resolving a large S3 prefix, where opening each object
+/// sequentially spends most of the time waiting on the first byte.
+pub fn open_all<'a>(
+    sources: &'a [ResolvedSource],
+    buffer_size: usize,
+    options: &'a SourceOptions,
+) -> impl futures::Stream<Item = Result<Box<dyn std::io::Read + Send>>> + 'a {
+    use futures::StreamExt;
+    futures::stream::iter(sources)
+        .map(move |source| source.open_with(buffer_size, options))
+        .buffered(options.max_concurrent.unwrap_or(1).max(1))
+}
+
 /// Unified source type representing a file location
 #[derive(Debug, Clone)]
 pub enum FileSource {
     /// Local filesystem path (file or directory if ends with /)
     #[cfg(feature = "local")]
     Local(PathBuf),
+    /// Local files under `base` whose path relative to it matches the
+    /// wildcard `pattern`
+    #[cfg(feature = "local")]
+    LocalGlob { base: PathBuf, pattern: String },
     /// S3 location (object or prefix if ends with /)
     #[cfg(feature = "s3")]
     S3 { bucket: String, key: String },
+    /// S3 objects under `prefix` whose key relative to it matches the
+    /// wildcard `pattern`
+    #[cfg(feature = "s3")]
+    S3Glob {
+        bucket: String,
+        prefix: String,
+        pattern: String,
+    },
+    /// GCS location (object or prefix if ends with /)
+    #[cfg(feature = "gcs")]
+    Gcs { bucket: String, object: String },
     /// HTTP/HTTPS URL (single file only)
     #[cfg(feature = "http")]
     Http(String),
@@ -81,17 +169,42 @@ impl FileSource {
     /// Parse a string into a FileSource, auto-detecting the source type
     ///
     /// - `s3://bucket/key` -> S3 (requires feature `s3`)
+    /// - `gs://bucket/object` -> Gcs (requires feature `gcs`)
     /// - `http://` or `https://` -> Http (requires feature `http`)
     /// - Everything else -> Local (requires feature `local`)
+    ///
+    /// Local paths and S3 keys containing an unescaped `*`, `?` or `[...]`
+    /// become [`FileSource::LocalGlob`] / [`FileSource::S3Glob`].
     pub fn parse(uri: &str) -> Result<Self> {
         if uri.starts_with("s3://") {
             #[cfg(feature = "s3")]
             {
                 let (bucket, key) = parse_s3_uri(uri)?;
-                Ok(FileSource::S3 { bucket, key })
+                match glob::split(&key) {
+                    Some((prefix, pattern)) => {
+                        glob::Pattern::new(pattern)?;
+                        Ok(FileSource::S3Glob {
+                            prefix: prefix.to_string(),
+                            pattern: pattern.to_string(),
+                            bucket,
+                        })
+                    }
+                    None => Ok(FileSource::S3 {
+                        key: glob::unescape(&key),
+                        bucket,
+                    }),
+                }
             }
             #[cfg(not(feature = "s3"))]
             anyhow::bail!("S3 URI requires the `s3` feature of surreal-sync-file (got {uri})")
+        } else if uri.starts_with("gs://") {
+            #[cfg(feature = "gcs")]
+            {
+                let (bucket, object) = parse_gcs_uri(uri)?;
+                Ok(FileSource::Gcs { bucket, object })
+            }
+            #[cfg(not(feature = "gcs"))]
+            anyhow::bail!("GCS URI requires the `gcs` feature of surreal-sync-file (got {uri})")
         } else if uri.starts_with("http://") || uri.starts_with("https://") {
             #[cfg(feature = "http")]
             {
@@ -102,7 +215,16 @@ impl FileSource {
         } else {
             #[cfg(feature = "local")]
             {
-                Ok(FileSource::Local(PathBuf::from(uri)))
+                match glob::split(uri) {
+                    Some((base, pattern)) => {
+                        glob::Pattern::new(pattern)?;
+                        Ok(FileSource::LocalGlob {
+                            base: PathBuf::from(base),
+                            pattern: pattern.to_string(),
+                        })
+                    }
+                    None => Ok(FileSource::Local(PathBuf::from(glob::unescape(uri)))),
+                }
             }
             #[cfg(not(feature = "local"))]
             anyhow::bail!(
@@ -111,7 +233,8 @@ impl FileSource {
         }
     }
 
-    /// Check if this source represents a directory/prefix (ends with /)
+    /// Check if this source represents a directory/prefix (ends with /) or
+    /// a wildcard pattern, i.e. may resolve to any number of files
     pub fn is_directory(&self) -> bool {
         match self {
             #[cfg(feature = "local")]
@@ -119,8 +242,14 @@ impl FileSource {
                 path.to_string_lossy().ends_with('/')
                     || path.to_string_lossy().ends_with(std::path::MAIN_SEPARATOR)
             }
+            #[cfg(feature = "local")]
+            FileSource::LocalGlob { .. } => true,
             #[cfg(feature = "s3")]
             FileSource::S3 { key, .. } => key.ends_with('/'),
+            #[cfg(feature = "s3")]
+            FileSource::S3Glob { .. } => true,
+            #[cfg(feature = "gcs")]
+            FileSource::Gcs { object, .. } => object.ends_with('/'),
             #[cfg(feature = "http")]
             FileSource::Http(_) => false, // HTTP doesn't support directories
         }
@@ -129,8 +258,17 @@ impl FileSource {
     /// Resolve this source into concrete file references
     ///
     /// If this is a directory/prefix, lists all immediate children (non-recursive).
+    /// If this is a wildcard pattern, lists the files matching it.
     /// If this is a single file, returns it directly.
     pub async fn resolve(&self) -> Result<Vec<ResolvedSource>> {
+        self.resolve_with(&SourceOptions::default()).await
+    }
+
+    /// Like [`Self::resolve`], with explicit backend settings (e.g. S3
+    /// credentials for prefix listing).
+    pub async fn resolve_with(&self, options: &SourceOptions) -> Result<Vec<ResolvedSource>> {
+        #[cfg(not(feature = "s3"))]
+        let _ = options;
         match self {
             #[cfg(feature = "local")]
             FileSource::Local(path) => {
@@ -140,10 +278,14 @@ impl FileSource {
                     Ok(vec![ResolvedSource::Local(path.clone())])
                 }
             }
+            #[cfg(feature = "local")]
+            FileSource::LocalGlob { base, pattern } => {
+                local::list_matching(base, &glob::Pattern::new(pattern)?).await
+            }
             #[cfg(feature = "s3")]
             FileSource::S3 { bucket, key } => {
                 if self.is_directory() {
-                    let client = S3Client::new().await?;
+                    let client = S3Client::with_credentials(&options.s3_credentials).await?;
                     client.list_prefix(bucket, key).await
                 } else {
                     Ok(vec![ResolvedSource::S3 {
@@ -152,6 +294,26 @@ impl FileSource {
                     }])
                 }
             }
+            #[cfg(feature = "s3")]
+            FileSource::S3Glob {
+                bucket,
+                prefix,
+                pattern,
+            } => {
+                let client = S3Client::with_credentials(&options.s3_credentials).await?;
+                client.list_matching(bucket, prefix, pattern).await
+            }
+            #[cfg(feature = "gcs")]
+            FileSource::Gcs { bucket, object } => {
+                if self.is_directory() {
+                    GcsClient::new().await?.list_prefix(bucket, object).await
+                } else {
+                    Ok(vec![ResolvedSource::Gcs {
+                        bucket: bucket.clone(),
+                        object: object.clone(),
+                    }])
+                }
+            }
             #[cfg(feature = "http")]
             FileSource::Http(url) => Ok(vec![ResolvedSource::Http(url.clone())]),
         }
@@ -162,8 +324,18 @@ impl FileSource {
         match self {
             #[cfg(feature = "local")]
             FileSource::Local(path) => path.display().to_string(),
+            #[cfg(feature = "local")]
+            FileSource::LocalGlob { base, pattern } => format!("{}{pattern}", base.display()),
             #[cfg(feature = "s3")]
             FileSource::S3 { bucket, key } => format!("s3://{bucket}/{key}"),
+            #[cfg(feature = "s3")]
+            FileSource::S3Glob {
+                bucket,
+                prefix,
+                pattern,
+            } => format!("s3://{bucket}/{prefix}{pattern}"),
+            #[cfg(feature = "gcs")]
+            FileSource::Gcs { bucket, object } => format!("gs://{bucket}/{object}"),
             #[cfg(feature = "http")]
             FileSource::Http(url) => url.clone(),
         }
@@ -179,23 +351,74 @@ pub enum ResolvedSource {
     /// S3 object
     #[cfg(feature = "s3")]
     S3 { bucket: String, key: String },
+    /// GCS object
+    #[cfg(feature = "gcs")]
+    Gcs { bucket: String, object: String },
     /// HTTP/HTTPS URL
     #[cfg(feature = "http")]
     Http(String),
 }
 
 impl ResolvedSource {
-    /// Open this source and return a reader
+    /// Open this source and return a reader, decompressing it when the
+    /// extension names a compression format (see [`Self::compression`]).
     pub async fn open(&self, buffer_size: usize) -> Result<Box<dyn std::io::Read + Send>> {
+        self.open_with(buffer_size, &SourceOptions::default()).await
+    }
+
+    /// Like [`Self::open`], with explicit backend settings (e.g. S3
+    /// credentials).
+    pub async fn open_with(
+        &self,
+        buffer_size: usize,
+        options: &SourceOptions,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        let reader = self.open_raw(buffer_size, options).await?;
+        self.compression().decode(reader)
+    }
+
+    /// Like [`Self::open`], decoding with `compression` whatever the
+    /// extension says.
+    pub async fn open_with_compression(
+        &self,
+        buffer_size: usize,
+        compression: Compression,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        let reader = self
+            .open_raw(buffer_size, &SourceOptions::default())
+            .await?;
+        compression.decode(reader)
+    }
+
+    async fn open_raw(
+        &self,
+        buffer_size: usize,
+        options: &SourceOptions,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        #[cfg(not(any(feature = "s3", feature = "http")))]
+        let _ = options;
         match self {
             #[cfg(feature = "local")]
             ResolvedSource::Local(path) => LocalFileReader::open(path.clone(), buffer_size).await,
             #[cfg(feature = "s3")]
             ResolvedSource::S3 { bucket, key } => {
-                S3FileReader::open(bucket.clone(), key.clone(), buffer_size).await
+                S3FileReader::open_with_options(
+                    bucket.clone(),
+                    key.clone(),
+                    buffer_size,
+                    &options.s3_credentials,
+                    &options.s3_read,
+                )
+                .await
+            }
+            #[cfg(feature = "gcs")]
+            ResolvedSource::Gcs { bucket, object } => {
+                GcsFileReader::open(bucket.clone(), object.clone(), buffer_size).await
             }
             #[cfg(feature = "http")]
-            ResolvedSource::Http(url) => HttpFileReader::open(url.clone(), buffer_size).await,
+            ResolvedSource::Http(url) => {
+                HttpFileReader::open_with_options(url.clone(), buffer_size, &options.http).await
+            }
         }
     }
 
@@ -206,28 +429,39 @@ impl ResolvedSource {
             ResolvedSource::Local(path) => path.display().to_string(),
             #[cfg(feature = "s3")]
             ResolvedSource::S3 { bucket, key } => format!("s3://{bucket}/{key}"),
+            #[cfg(feature = "gcs")]
+            ResolvedSource::Gcs { bucket, object } => format!("gs://{bucket}/{object}"),
             #[cfg(feature = "http")]
             ResolvedSource::Http(url) => url.clone(),
         }
     }
 
-    /// Get the file extension (without the dot)
+    /// Get the file extension (without the dot), skipping a compression
+    /// suffix: `data.csv.gz` reports `csv`
     pub fn extension(&self) -> Option<&str> {
+        let extension = compression::data_extension(self.file_name()?)?;
+        // A query string left in an HTTP URL is not an extension.
+        (!extension.contains('?') && !extension.contains('&')).then_some(extension)
+    }
+
+    /// Compression named by the file's last extension
+    pub fn compression(&self) -> Compression {
+        self.file_name()
+            .map(Compression::from_file_name)
+            .unwrap_or_default()
+    }
+
+    /// Last path segment of the file location
+    fn file_name(&self) -> Option<&str> {
         match self {
             #[cfg(feature = "local")]
-            ResolvedSource::Local(path) => path.extension().and_then(|e| e.to_str()),
+            ResolvedSource::Local(path) => path.file_name().and_then(|name| name.to_str()),
             #[cfg(feature = "s3")]
-            ResolvedSource::S3 { key, .. } => {
-                key.rsplit('.').next().filter(|ext| !ext.contains('/'))
-            }
+            ResolvedSource::S3 { key, .. } => key.rsplit('/').next(),
+            #[cfg(feature = "gcs")]
+            ResolvedSource::Gcs { object, .. } => object.rsplit('/').next(),
             #[cfg(feature = "http")]
-            ResolvedSource::Http(url) => {
-                // Extract path from URL, then get extension
-                url.rsplit('/')
-                    .next()
-                    .and_then(|filename| filename.rsplit('.').next())
-                    .filter(|ext| !ext.contains('?') && !ext.contains('&'))
-            }
+            ResolvedSource::Http(url) => url.rsplit('/').next(),
         }
     }
 }
@@ -248,9 +482,27 @@ pub fn parse_s3_uri(uri: &str) -> Result<(String, String)> {
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
+/// Parse GCS URI in the format: gs://bucket/object/name
+#[cfg(feature = "gcs")]
+pub fn parse_gcs_uri(uri: &str) -> Result<(String, String)> {
+    use anyhow::Context;
+    let uri = uri
+        .strip_prefix("gs://")
+        .context("GCS URI must start with 'gs://'")?;
+
+    let parts: Vec<&str> = uri.splitn(2, '/').collect();
+    if parts.len() != 2 || parts[0].is_empty() {
+        anyhow::bail!("GCS URI must be in format 'gs://bucket/object/name'");
+    }
+
+    Ok((parts[0].to_string(), parts[1].to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "local")]
+    use std::path::Path;
 
     #[cfg(feature = "local")]
     #[test]
@@ -284,10 +536,46 @@ mod tests {
         assert!(source.is_directory());
     }
 
+    #[cfg(feature = "local")]
+    #[test]
+    fn test_parse_local_glob() {
+        let source = FileSource::parse("/data/**/orders.csv").unwrap();
+        assert!(matches!(
+            &source,
+            FileSource::LocalGlob { base, pattern }
+                if base == Path::new("/data/") && pattern == "**/orders.csv"
+        ));
+        assert!(source.is_directory());
+        assert_eq!(source.display_name(), "/data/**/orders.csv");
+
+        let literal = FileSource::parse("/data/\\[raw\\].csv").unwrap();
+        assert!(matches!(
+            literal,
+            FileSource::Local(path) if path == Path::new("/data/[raw].csv")
+        ));
+        assert!(FileSource::parse("/data/[z-a].csv").is_err());
+    }
+
+    #[cfg(feature = "s3")]
+    #[test]
+    fn test_parse_s3_glob() {
+        let source = FileSource::parse("s3://my-bucket/exports/users_*.jsonl").unwrap();
+        assert!(matches!(
+            &source,
+            FileSource::S3Glob { bucket, prefix, pattern }
+                if bucket == "my-bucket" && prefix == "exports/" && pattern == "users_*.jsonl"
+        ));
+        assert!(source.is_directory());
+        assert_eq!(
+            source.display_name(),
+            "s3://my-bucket/exports/users_*.jsonl"
+        );
+    }
+
     #[cfg(feature = "http")]
     #[test]
     fn test_parse_http_url() {
-        let source = FileSource::parse("https://example.com/data.csv").unwrap();
+        let source = FileSource::parse("https://example.com/data.csv?x=1").unwrap();
         assert!(matches!(source, FileSource::Http(_)));
         assert!(!source.is_directory());
     }
@@ -299,6 +587,83 @@ mod tests {
         assert_eq!(resolved.extension(), Some("csv"));
     }
 
+    #[cfg(feature = "local")]
+    #[test]
+    fn test_resolved_extension_skips_compression_suffix() {
+        let resolved = ResolvedSource::Local(PathBuf::from("/data/file.csv.gz"));
+        assert_eq!(resolved.extension(), Some("csv"));
+        assert_eq!(resolved.compression(), Compression::Gzip);
+    }
+
+    #[cfg(feature = "local")]
+    #[tokio::test]
+    async fn test_open_decompresses_by_extension() {
+        use std::io::{Read, Write};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("rows.jsonl.gz");
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(b"{\"id\":1}\n").unwrap();
+        std::fs::write(&path, gzip.finish().unwrap()).unwrap();
+
+        let resolved = ResolvedSource::Local(path);
+        let mut text = String::new();
+        resolved
+            .open(DEFAULT_BUFFER_SIZE)
+            .await
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "{\"id\":1}\n");
+
+        // An explicit choice wins over the extension.
+        let mut raw = Vec::new();
+        resolved
+            .open_with_compression(DEFAULT_BUFFER_SIZE, Compression::None)
+            .await
+            .unwrap()
+            .read_to_end(&mut raw)
+            .unwrap();
+        assert_eq!(&raw[..2], &[0x1f, 0x8b]);
+    }
+
+    #[cfg(feature = "local")]
+    #[tokio::test]
+    async fn test_open_all_keeps_input_order() {
+        use futures::TryStreamExt;
+        use std::io::Read;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let sources: Vec<ResolvedSource> = (0..10)
+            .map(|i| {
+                let path = dir.path().join(format!("part-{i}.jsonl"));
+                std::fs::write(&path, format!("{i}\n")).unwrap();
+                ResolvedSource::Local(path)
+            })
+            .collect();
+        // The other fields only exist with the `s3` / `http` features.
+        #[allow(clippy::needless_update)]
+        let options = SourceOptions {
+            max_concurrent: Some(4),
+            ..SourceOptions::default()
+        };
+
+        let readers: Vec<_> = open_all(&sources, DEFAULT_BUFFER_SIZE, &options)
+            .try_collect()
+            .await
+            .unwrap();
+        let contents: Vec<String> = readers
+            .into_iter()
+            .map(|mut reader| {
+                let mut text = String::new();
+                reader.read_to_string(&mut text).unwrap();
+                text
+            })
+            .collect();
+        let expected: Vec<String> = (0..10).map(|i| format!("{i}\n")).collect();
+        assert_eq!(contents, expected);
+    }
+
     #[cfg(feature = "s3")]
     #[test]
     fn test_resolved_extension_s3() {
@@ -352,4 +717,33 @@ mod tests {
         let result = parse_s3_uri("s3://my-bucket");
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "gcs")]
+    #[test]
+    fn test_parse_gcs_object_and_prefix() {
+        let source = FileSource::parse("gs://my-bucket/exports/users.jsonl").unwrap();
+        assert!(matches!(source, FileSource::Gcs { .. }));
+        assert!(!source.is_directory());
+        assert_eq!(source.display_name(), "gs://my-bucket/exports/users.jsonl");
+
+        let source = FileSource::parse("gs://my-bucket/exports/").unwrap();
+        assert!(source.is_directory());
+    }
+
+    #[cfg(feature = "gcs")]
+    #[test]
+    fn test_parse_gcs_uri_valid() {
+        let (bucket, object) = parse_gcs_uri("gs://my-bucket/path/to/file.csv").unwrap();
+        assert_eq!(bucket, "my-bucket");
+        assert_eq!(object, "path/to/file.csv");
+    }
+
+    #[cfg(feature = "gcs")]
+    #[test]
+    fn test_parse_gcs_uri_invalid() {
+        assert!(parse_gcs_uri("my-bucket/path/to/file.csv").is_err());
+        assert!(parse_gcs_uri("s3://my-bucket/file.csv").is_err());
+        assert!(parse_gcs_uri("gs://my-bucket").is_err());
+        assert!(parse_gcs_uri("gs:///file.csv").is_err());
+    }
 }
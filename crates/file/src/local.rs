@@ -1,5 +1,6 @@
 //! Local filesystem file reader implementation
 
+use crate::glob::Pattern;
 use crate::ResolvedSource;
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
@@ -67,6 +68,48 @@ pub async fn list_directory(path: &Path) -> Result<Vec<ResolvedSource>> {
     Ok(results)
 }
 
+/// List files under `base` whose `/`-separated path relative to it matches
+/// `pattern`, descending only as deep as the pattern can match.
+pub(crate) async fn list_matching(base: &Path, pattern: &Pattern) -> Result<Vec<ResolvedSource>> {
+    let mut results = Vec::new();
+    let root = if base.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        base
+    };
+    let mut pending = vec![(root.to_path_buf(), String::new(), 1)];
+
+    while let Some((dir, relative_dir, depth)) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&dir)
+            .await
+            .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+
+        while let Some(entry) = entries.next_entry().await? {
+            let entry_path = entry.path();
+            let metadata = entry
+                .metadata()
+                .await
+                .with_context(|| format!("Failed to get metadata for: {}", entry_path.display()))?;
+            let relative = format!("{relative_dir}{}", entry.file_name().to_string_lossy());
+
+            if metadata.is_file() {
+                if pattern.is_match(&relative) {
+                    results.push(ResolvedSource::Local(base.join(&relative)));
+                }
+            } else if metadata.is_dir() && pattern.max_depth().is_none_or(|max| depth < max) {
+                pending.push((entry_path, format!("{relative}/"), depth + 1));
+            }
+        }
+    }
+
+    // Sort for consistent ordering
+    results.sort_by_key(|a| a.display_name());
+
+    tracing::debug!("Matched {} files under {}", results.len(), root.display());
+
+    Ok(results)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,4 +166,44 @@ mod tests {
         let result = list_directory(Path::new("/nonexistent/path")).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_list_matching_walks_only_as_deep_as_pattern() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("2024/01")).unwrap();
+        std::fs::write(root.join("orders.csv"), "top").unwrap();
+        std::fs::write(root.join("users_1.jsonl"), "u1").unwrap();
+        std::fs::write(root.join("users_2.csv"), "u2").unwrap();
+        std::fs::write(root.join("2024/orders.csv"), "year").unwrap();
+        std::fs::write(root.join("2024/users_3.jsonl"), "u3").unwrap();
+        std::fs::write(root.join("2024/01/orders.csv"), "month").unwrap();
+
+        let names = |results: Vec<ResolvedSource>| -> Vec<String> {
+            results
+                .iter()
+                .map(|r| match r {
+                    ResolvedSource::Local(path) => path
+                        .strip_prefix(root)
+                        .unwrap()
+                        .to_string_lossy()
+                        .into_owned(),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                })
+                .collect()
+        };
+
+        let users = Pattern::new("users_*.jsonl").unwrap();
+        assert_eq!(
+            names(list_matching(root, &users).await.unwrap()),
+            vec!["users_1.jsonl"]
+        );
+
+        let orders = Pattern::new("**/orders.csv").unwrap();
+        assert_eq!(
+            names(list_matching(root, &orders).await.unwrap()),
+            vec!["2024/01/orders.csv", "2024/orders.csv", "orders.csv"]
+        );
+    }
 }
@@ -1,8 +1,75 @@
 //! S3 file reader implementation with prefix listing support
 
+use crate::glob::{self, Pattern};
 use crate::ResolvedSource;
 use anyhow::{Context, Result};
-use aws_config::BehaviorVersion;
+use aws_config::sts::AssumeRoleProvider;
+use aws_config::{BehaviorVersion, SdkConfig};
+use aws_sdk_s3::error::DisplayErrorContext;
+use aws_sdk_s3::primitives::ByteStream;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Where S3 reads get their AWS credentials.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum S3Credentials {
+    /// The SDK's default provider chain (environment, `AWS_PROFILE`, SSO,
+    /// instance / task roles, ...).
+    #[default]
+    DefaultChain,
+    /// A named profile from `~/.aws/config` / `~/.aws/credentials`.
+    Profile(String),
+    /// Assume `role_arn` through STS, using default-chain credentials (or
+    /// `source_profile`) to call STS. Temporary credentials are cached and
+    /// refreshed by the SDK shortly before they expire, so long syncs keep
+    /// reading.
+    AssumeRole {
+        role_arn: String,
+        session_name: String,
+        source_profile: Option<String>,
+    },
+}
+
+impl S3Credentials {
+    /// Load the AWS SDK config for these credentials.
+    pub async fn sdk_config(&self) -> SdkConfig {
+        match self {
+            S3Credentials::DefaultChain => {
+                aws_config::load_defaults(BehaviorVersion::latest()).await
+            }
+            S3Credentials::Profile(name) => {
+                aws_config::defaults(BehaviorVersion::latest())
+                    .profile_name(name)
+                    .load()
+                    .await
+            }
+            S3Credentials::AssumeRole {
+                role_arn,
+                session_name,
+                source_profile,
+            } => {
+                let mut base = aws_config::defaults(BehaviorVersion::latest());
+                if let Some(profile) = source_profile {
+                    base = base.profile_name(profile);
+                }
+                let base = base.load().await;
+                let provider = AssumeRoleProvider::builder(role_arn)
+                    .session_name(session_name)
+                    .configure(&base)
+                    .build()
+                    .await;
+                aws_config::defaults(BehaviorVersion::latest())
+                    .region(base.region().cloned())
+                    .credentials_provider(provider)
+                    .load()
+                    .await
+            }
+        }
+    }
+}
 
 /// Shared S3 client for efficient operations
 ///
@@ -13,80 +80,161 @@ pub struct S3Client {
 }
 
 impl S3Client {
-    /// Create a new S3 client from AWS config
+    /// Create a new S3 client from AWS config (default credential chain)
     pub async fn new() -> Result<Self> {
-        let sdk_config = aws_config::load_defaults(BehaviorVersion::latest()).await;
+        Self::with_credentials(&S3Credentials::DefaultChain).await
+    }
+
+    /// Create an S3 client using a named AWS profile
+    pub async fn with_profile(name: impl Into<String>) -> Result<Self> {
+        Self::with_credentials(&S3Credentials::Profile(name.into())).await
+    }
+
+    /// Create an S3 client that assumes `role_arn` (e.g. a cross-account
+    /// read role) with default-chain credentials
+    pub async fn with_assumed_role(
+        role_arn: impl Into<String>,
+        session_name: impl Into<String>,
+    ) -> Result<Self> {
+        Self::with_credentials(&S3Credentials::AssumeRole {
+            role_arn: role_arn.into(),
+            session_name: session_name.into(),
+            source_profile: None,
+        })
+        .await
+    }
+
+    /// Create an S3 client for the given credentials
+    pub async fn with_credentials(credentials: &S3Credentials) -> Result<Self> {
+        let sdk_config = credentials.sdk_config().await;
         let client = aws_sdk_s3::Client::new(&sdk_config);
         Ok(Self { client })
     }
 
+    /// List objects under `prefix` whose key relative to it matches the
+    /// wildcard `pattern` (see [`crate::FileSource::S3Glob`])
+    ///
+    /// Lists the longest literal prefix (`prefix` plus the text before the
+    /// first wildcard) without a delimiter, then filters keys client-side.
+    pub(crate) async fn list_matching(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        pattern: &str,
+    ) -> Result<Vec<ResolvedSource>> {
+        let matcher = Pattern::new(pattern)?;
+        let list_prefix = format!("{prefix}{}", glob::literal_prefix(pattern));
+        let mut results: Vec<ResolvedSource> = self
+            .list_keys(bucket, &list_prefix, None)
+            .await?
+            .into_iter()
+            .filter(|key| {
+                key.strip_prefix(prefix)
+                    .is_some_and(|relative| matcher.is_match(relative))
+                    && !key.ends_with('/')
+            })
+            .map(|key| ResolvedSource::S3 {
+                bucket: bucket.to_string(),
+                key,
+            })
+            .collect();
+
+        // Sort for consistent ordering
+        results.sort_by_key(|a| a.display_name());
+
+        tracing::debug!(
+            "Matched {} objects for s3://{}/{}{}",
+            results.len(),
+            bucket,
+            prefix,
+            pattern
+        );
+
+        Ok(results)
+    }
+
     /// List all objects under a prefix (non-recursive, immediate level only)
     ///
     /// Note: S3 doesn't have true directories, so this lists all objects
     /// that start with the prefix but filters out "subdirectory" entries
     /// by excluding keys that have additional `/` characters after the prefix.
     pub async fn list_prefix(&self, bucket: &str, prefix: &str) -> Result<Vec<ResolvedSource>> {
-        let mut results = Vec::new();
+        // Use delimiter to get only immediate children
+        let mut results: Vec<ResolvedSource> = self
+            .list_keys(bucket, prefix, Some("/"))
+            .await?
+            .into_iter()
+            // Skip the prefix itself and "directory" markers (keys ending with /)
+            .filter(|key| key != prefix && !key.ends_with('/'))
+            .map(|key| ResolvedSource::S3 {
+                bucket: bucket.to_string(),
+                key,
+            })
+            .collect();
+
+        // Sort for consistent ordering
+        results.sort_by_key(|a| a.display_name());
+
+        tracing::debug!(
+            "Listed {} objects in S3 prefix: s3://{}/{}",
+            results.len(),
+            bucket,
+            prefix
+        );
+
+        Ok(results)
+    }
+
+    /// Keys of every object under `prefix`, following continuation tokens
+    /// until the listing is no longer truncated
+    ///
+    /// `ListObjectsV2` returns at most 1000 keys per call, so large prefixes
+    /// take many pages.
+    async fn list_keys(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        delimiter: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut pages = 0usize;
         let mut continuation_token: Option<String> = None;
 
         loop {
-            let mut request = self
+            let response = self
                 .client
                 .list_objects_v2()
                 .bucket(bucket)
                 .prefix(prefix)
-                .delimiter("/"); // Use delimiter to get only immediate children
-
-            if let Some(token) = &continuation_token {
-                request = request.continuation_token(token);
-            }
-
-            let response = request
+                .set_delimiter(delimiter.map(str::to_string))
+                .set_continuation_token(continuation_token.take())
                 .send()
                 .await
                 .with_context(|| format!("Failed to list S3 prefix: s3://{bucket}/{prefix}"))?;
+            pages += 1;
 
-            // Process objects (files at this level)
-            if let Some(contents) = response.contents {
-                for object in contents {
-                    if let Some(key) = object.key {
-                        // Skip the prefix itself if it appears as an object
-                        if key == prefix {
-                            continue;
-                        }
-
-                        // Skip "directory" markers (keys ending with /)
-                        if key.ends_with('/') {
-                            continue;
-                        }
-
-                        results.push(ResolvedSource::S3 {
-                            bucket: bucket.to_string(),
-                            key,
-                        });
-                    }
-                }
-            }
+            keys.extend(
+                response
+                    .contents
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|o| o.key),
+            );
 
-            // Handle pagination
-            if response.is_truncated == Some(true) {
-                continuation_token = response.next_continuation_token;
-            } else {
-                break;
-            }
+            continuation_token = match (response.is_truncated, response.next_continuation_token) {
+                (Some(true), Some(token)) => Some(token),
+                (Some(true), None) => anyhow::bail!(
+                    "S3 listing of s3://{bucket}/{prefix} is truncated but has no continuation token"
+                ),
+                _ => break,
+            };
         }
 
-        // Sort for consistent ordering
-        results.sort_by_key(|a| a.display_name());
-
-        tracing::debug!(
-            "Listed {} objects in S3 prefix: s3://{}/{}",
-            results.len(),
-            bucket,
-            prefix
+        tracing::trace!(
+            "Listed {} keys in {pages} page(s) under s3://{bucket}/{prefix}",
+            keys.len()
         );
-
-        Ok(results)
+        Ok(keys)
     }
 
     /// Open an S3 object for reading
@@ -96,6 +244,49 @@ impl S3Client {
         key: &str,
         buffer_size: usize,
     ) -> Result<Box<dyn std::io::Read + Send>> {
+        self.open_with_options(bucket, key, buffer_size, &S3ReadOptions::default())
+            .await
+    }
+
+    /// Open an S3 object for reading, resuming or splitting the download as
+    /// `options` says
+    ///
+    /// A read that fails mid-body is retried with a `Range` request starting
+    /// at the first byte not yet returned, pinned to the object's ETag so a
+    /// replaced object fails the read instead of splicing two versions.
+    pub async fn open_with_options(
+        &self,
+        bucket: &str,
+        key: &str,
+        buffer_size: usize,
+        options: &S3ReadOptions,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        if options.parallel_parts > 1 {
+            let head = self
+                .client
+                .head_object()
+                .bucket(bucket)
+                .key(key)
+                .send()
+                .await
+                .with_context(|| format!("Failed to stat object in S3: s3://{bucket}/{key}"))?;
+            let len = head.content_length.unwrap_or(0).max(0) as u64;
+            if len > options.part_size {
+                tracing::debug!(
+                    "Reading s3://{bucket}/{key} ({len} bytes) in {}-byte parts, {} at a time",
+                    options.part_size,
+                    options.parallel_parts
+                );
+                let fetch = self.part_fetcher(bucket, key, head.e_tag);
+                return Ok(Box::new(PartsReader::new(
+                    tokio::runtime::Handle::current(),
+                    fetch,
+                    len,
+                    options,
+                )));
+            }
+        }
+
         let response = self
             .client
             .get_object()
@@ -104,17 +295,282 @@ impl S3Client {
             .send()
             .await
             .with_context(|| format!("Failed to fetch object from S3: s3://{bucket}/{key}"))?;
+        let len = response.content_length.map(|len| len.max(0) as u64);
+        let e_tag = response.e_tag.clone();
+
+        let handle = tokio::runtime::Handle::current();
+        let client = self.client.clone();
+        let (bucket, key) = (bucket.to_string(), key.to_string());
+        let reopen = move |offset: u64| -> std::io::Result<Box<dyn std::io::Read + Send>> {
+            let response = handle
+                .block_on(
+                    client
+                        .get_object()
+                        .bucket(&bucket)
+                        .key(&key)
+                        .range(format!("bytes={offset}-"))
+                        .set_if_match(e_tag.clone())
+                        .send(),
+                )
+                .map_err(|e| std::io::Error::other(DisplayErrorContext(e).to_string()))?;
+            Ok(Box::new(body_reader(response.body, buffer_size)))
+        };
+
+        Ok(Box::new(ResumableReader::new(
+            Box::new(body_reader(response.body, buffer_size)),
+            reopen,
+            len,
+            options.max_retries,
+        )))
+    }
+
+    /// Fetches byte ranges of one object version with ranged GETs.
+    fn part_fetcher(&self, bucket: &str, key: &str, e_tag: Option<String>) -> PartFetch {
+        let client = self.client.clone();
+        let (bucket, key) = (bucket.to_string(), key.to_string());
+        Arc::new(move |start, end| {
+            let request = client
+                .get_object()
+                .bucket(&bucket)
+                .key(&key)
+                .range(format!("bytes={start}-{}", end - 1))
+                .set_if_match(e_tag.clone());
+            Box::pin(async move {
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|e| std::io::Error::other(DisplayErrorContext(e).to_string()))?;
+                let body = response
+                    .body
+                    .collect()
+                    .await
+                    .map_err(std::io::Error::other)?;
+                Ok(body.to_vec())
+            })
+        })
+    }
+}
+
+/// A GET body as a buffered, sync-compatible reader.
+fn body_reader(body: ByteStream, buffer_size: usize) -> impl std::io::Read + Send {
+    let buffered = tokio::io::BufReader::with_capacity(buffer_size, body.into_async_read());
+    tokio_util::io::SyncIoBridge::new(buffered)
+}
+
+/// How S3 object reads recover from dropped connections and split large
+/// objects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3ReadOptions {
+    /// Ranged re-requests allowed after a failed read before giving up. The
+    /// count resets whenever a read makes progress; with parts, each part
+    /// gets its own budget.
+    pub max_retries: u32,
+    /// Parts downloaded concurrently. `1` streams the object with one GET.
+    pub parallel_parts: usize,
+    /// Bytes per part when `parallel_parts > 1`. Objects no larger than one
+    /// part are streamed.
+    pub part_size: u64,
+}
+
+impl Default for S3ReadOptions {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            parallel_parts: 1,
+            part_size: 8 * 1024 * 1024,
+        }
+    }
+}
+
+/// Delay before the `attempt`-th retry.
+fn retry_backoff(attempt: u32) -> Duration {
+    Duration::from_millis(100 * 2u64.pow(attempt.min(6)))
+}
+
+/// Streams one body, reopening it at the current offset when a read fails
+/// or the body ends short of its length.
+struct ResumableReader<F> {
+    inner: Box<dyn std::io::Read + Send>,
+    reopen: F,
+    offset: u64,
+    len: Option<u64>,
+    max_retries: u32,
+    retries: u32,
+    backoff: fn(u32) -> Duration,
+}
+
+impl<F> ResumableReader<F>
+where
+    F: FnMut(u64) -> std::io::Result<Box<dyn std::io::Read + Send>>,
+{
+    fn new(
+        inner: Box<dyn std::io::Read + Send>,
+        reopen: F,
+        len: Option<u64>,
+        max_retries: u32,
+    ) -> Self {
+        Self {
+            inner,
+            reopen,
+            offset: 0,
+            len,
+            max_retries,
+            retries: 0,
+            backoff: retry_backoff,
+        }
+    }
+
+    /// Reopen at `self.offset`, spending retries until it works.
+    fn resume(&mut self, mut error: std::io::Error) -> std::io::Result<()> {
+        loop {
+            if self.retries >= self.max_retries {
+                return Err(error);
+            }
+            self.retries += 1;
+            tracing::warn!(
+                "S3 read failed at byte {} ({error}); resuming (retry {}/{})",
+                self.offset,
+                self.retries,
+                self.max_retries
+            );
+            std::thread::sleep((self.backoff)(self.retries));
+            match (self.reopen)(self.offset) {
+                Ok(inner) => {
+                    self.inner = inner;
+                    return Ok(());
+                }
+                Err(e) => error = e,
+            }
+        }
+    }
+}
+
+impl<F> std::io::Read for ResumableReader<F>
+where
+    F: FnMut(u64) -> std::io::Result<Box<dyn std::io::Read + Send>>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            match self.inner.read(buf) {
+                Ok(0) => match self.len {
+                    Some(len) if self.offset < len => {
+                        let short = std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            format!("body ended at byte {} of {len}", self.offset),
+                        );
+                        self.resume(short)?;
+                    }
+                    _ => return Ok(0),
+                },
+                Ok(n) => {
+                    self.offset += n as u64;
+                    self.retries = 0;
+                    return Ok(n);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => self.resume(e)?,
+            }
+        }
+    }
+}
 
-        // Convert byte stream to async read
-        let stream = response.body.into_async_read();
+/// Fetches bytes `start..end` of an object.
+type PartFetch = Arc<
+    dyn Fn(u64, u64) -> Pin<Box<dyn Future<Output = std::io::Result<Vec<u8>>> + Send>>
+        + Send
+        + Sync,
+>;
 
-        // Wrap in buffered reader
-        let buffered = tokio::io::BufReader::with_capacity(buffer_size, stream);
+/// Downloads an object as fixed-size parts, up to `parallel_parts` in
+/// flight, and returns them in order. A failed part is refetched on its own.
+struct PartsReader {
+    handle: tokio::runtime::Handle,
+    fetch: PartFetch,
+    len: u64,
+    part_size: u64,
+    parallel_parts: usize,
+    max_retries: u32,
+    backoff: fn(u32) -> Duration,
+    next_start: u64,
+    pending: VecDeque<tokio::task::JoinHandle<std::io::Result<Vec<u8>>>>,
+    current: std::io::Cursor<Vec<u8>>,
+}
 
-        // Bridge async to sync
-        let reader = tokio_util::io::SyncIoBridge::new(buffered);
+impl PartsReader {
+    fn new(
+        handle: tokio::runtime::Handle,
+        fetch: PartFetch,
+        len: u64,
+        options: &S3ReadOptions,
+    ) -> Self {
+        Self {
+            handle,
+            fetch,
+            len,
+            part_size: options.part_size.max(1),
+            parallel_parts: options.parallel_parts.max(1),
+            max_retries: options.max_retries,
+            backoff: retry_backoff,
+            next_start: 0,
+            pending: VecDeque::new(),
+            current: std::io::Cursor::new(Vec::new()),
+        }
+    }
 
-        Ok(Box::new(reader))
+    /// Start fetching parts until `parallel_parts` are in flight.
+    fn fill(&mut self) {
+        while self.pending.len() < self.parallel_parts && self.next_start < self.len {
+            let start = self.next_start;
+            let end = (start + self.part_size).min(self.len);
+            self.next_start = end;
+            let fetch = self.fetch.clone();
+            let (max_retries, backoff) = (self.max_retries, self.backoff);
+            self.pending.push_back(self.handle.spawn(async move {
+                let mut retries = 0;
+                loop {
+                    let error = match fetch(start, end).await {
+                        Ok(bytes) if bytes.len() as u64 == end - start => return Ok(bytes),
+                        Ok(bytes) => std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            format!("got {} bytes for range {start}..{end}", bytes.len()),
+                        ),
+                        Err(e) => e,
+                    };
+                    if retries >= max_retries {
+                        return Err(error);
+                    }
+                    retries += 1;
+                    tracing::warn!(
+                        "S3 part {start}..{end} failed ({error}); refetching (retry {retries}/{max_retries})"
+                    );
+                    tokio::time::sleep(backoff(retries)).await;
+                }
+            }));
+        }
+    }
+}
+
+impl std::io::Read for PartsReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let n = self.current.read(buf)?;
+            if n > 0 || buf.is_empty() {
+                return Ok(n);
+            }
+            self.fill();
+            let Some(part) = self.pending.pop_front() else {
+                return Ok(0);
+            };
+            let bytes = self
+                .handle
+                .block_on(part)
+                .map_err(std::io::Error::other)??;
+            self.current = std::io::Cursor::new(bytes);
+            self.fill();
+        }
     }
 }
 
@@ -143,35 +599,46 @@ impl S3FileReader {
         key: String,
         buffer_size: usize,
     ) -> Result<Box<dyn std::io::Read + Send>> {
-        // Load AWS configuration
-        let sdk_config = aws_config::load_defaults(BehaviorVersion::latest()).await;
-        let client = aws_sdk_s3::Client::new(&sdk_config);
-
-        // Get object from S3
-        let response = client
-            .get_object()
-            .bucket(&bucket)
-            .key(&key)
-            .send()
-            .await
-            .with_context(|| format!("Failed to fetch object from S3: s3://{bucket}/{key}"))?;
-
-        // Convert byte stream to async read
-        let stream = response.body.into_async_read();
-
-        // Wrap in buffered reader
-        let buffered = tokio::io::BufReader::with_capacity(buffer_size, stream);
+        Self::open_with_credentials(bucket, key, buffer_size, &S3Credentials::DefaultChain).await
+    }
 
-        // Bridge async to sync
-        let reader = tokio_util::io::SyncIoBridge::new(buffered);
+    /// Like [`Self::open`], reading with the given credentials
+    pub async fn open_with_credentials(
+        bucket: String,
+        key: String,
+        buffer_size: usize,
+        credentials: &S3Credentials,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        Self::open_with_options(
+            bucket,
+            key,
+            buffer_size,
+            credentials,
+            &S3ReadOptions::default(),
+        )
+        .await
+    }
 
-        Ok(Box::new(reader))
+    /// Like [`Self::open_with_credentials`], with explicit retry and
+    /// parallel-part settings
+    pub async fn open_with_options(
+        bucket: String,
+        key: String,
+        buffer_size: usize,
+        credentials: &S3Credentials,
+        options: &S3ReadOptions,
+    ) -> Result<Box<dyn std::io::Read + Send>> {
+        S3Client::with_credentials(credentials)
+            .await?
+            .open_with_options(&bucket, &key, buffer_size, options)
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Read;
 
     #[test]
     fn test_resolved_source_s3_extension() {
@@ -194,6 +661,148 @@ mod tests {
         assert_eq!(source.extension(), None);
     }
 
-    // Integration tests for S3 operations would require AWS credentials
-    // or mocking, which is beyond the scope of unit tests
+    #[tokio::test]
+    async fn test_assumed_role_config_uses_its_own_provider() {
+        // Building the provider is offline; STS is only called on first use.
+        let credentials = S3Credentials::AssumeRole {
+            role_arn: "arn:aws:iam::123456789012:role/export-reader".to_string(),
+            session_name: "surreal-sync".to_string(),
+            source_profile: None,
+        };
+        let config = credentials.sdk_config().await;
+        let provider = config.credentials_provider().expect("credentials provider");
+        assert!(
+            format!("{provider:?}").contains("AssumeRole"),
+            "{provider:?}"
+        );
+        assert_eq!(S3Credentials::default(), S3Credentials::DefaultChain);
+    }
+
+    fn object() -> Vec<u8> {
+        (0..20_000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Serves `data[offset..]`, failing after `fail_after` bytes if set.
+    struct FlakyBody {
+        body: std::io::Cursor<Vec<u8>>,
+        fail_after: Option<usize>,
+    }
+
+    impl std::io::Read for FlakyBody {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let limit = match self.fail_after {
+                Some(0) => return Err(std::io::Error::other("connection reset")),
+                Some(left) => left.min(buf.len()),
+                None => buf.len(),
+            };
+            let n = self.body.read(&mut buf[..limit])?;
+            if let Some(left) = &mut self.fail_after {
+                *left -= n;
+            }
+            Ok(n)
+        }
+    }
+
+    fn flaky(data: &[u8], offset: u64, fail_after: Option<usize>) -> Box<dyn std::io::Read + Send> {
+        Box::new(FlakyBody {
+            body: std::io::Cursor::new(data[offset as usize..].to_vec()),
+            fail_after,
+        })
+    }
+
+    #[test]
+    fn test_resumable_reader_resumes_from_last_offset() {
+        let data = object();
+        let mut reopened = Vec::new();
+        let mut reader = ResumableReader::new(
+            flaky(&data, 0, Some(4096)),
+            |offset| {
+                reopened.push(offset);
+                // The first resume drops again part way through.
+                let fail_after = (reopened.len() == 1).then_some(3000);
+                Ok(flaky(&data, offset, fail_after))
+            },
+            Some(data.len() as u64),
+            2,
+        );
+        reader.backoff = |_| Duration::ZERO;
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        drop(reader);
+        assert_eq!(out, data);
+        assert_eq!(reopened, vec![4096, 7096]);
+    }
+
+    #[test]
+    fn test_resumable_reader_resumes_short_body_and_gives_up() {
+        let data = object();
+        // A body that ends early without an error is resumed too.
+        let short = Box::new(std::io::Cursor::new(data[..5000].to_vec()));
+        let mut reader = ResumableReader::new(
+            short,
+            |offset| Ok(flaky(&data, offset, None)),
+            Some(20_000),
+            1,
+        );
+        reader.backoff = |_| Duration::ZERO;
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, data);
+
+        let mut reader = ResumableReader::new(
+            flaky(&data, 0, Some(100)),
+            |offset| Ok(flaky(&data, offset, Some(0))),
+            Some(20_000),
+            2,
+        );
+        reader.backoff = |_| Duration::ZERO;
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "connection reset");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parts_reader_refetches_failed_part_in_order() {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        let data = Arc::new(object());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let dropped = Arc::new(AtomicBool::new(false));
+        let fetch: PartFetch = {
+            let (data, calls) = (data.clone(), calls.clone());
+            Arc::new(move |start, end| {
+                let data = data.clone();
+                calls.fetch_add(1, Ordering::SeqCst);
+                let drop_now = start == 6000 && !dropped.swap(true, Ordering::SeqCst);
+                Box::pin(async move {
+                    // The first attempt at the second part drops.
+                    if drop_now {
+                        return Err(std::io::Error::other("connection reset"));
+                    }
+                    Ok(data[start as usize..end as usize].to_vec())
+                })
+            })
+        };
+        let options = S3ReadOptions {
+            max_retries: 1,
+            parallel_parts: 3,
+            part_size: 6000,
+        };
+        let mut reader = PartsReader::new(
+            tokio::runtime::Handle::current(),
+            fetch,
+            data.len() as u64,
+            &options,
+        );
+        reader.backoff = |_| Duration::ZERO;
+        let out = tokio::task::spawn_blocking(move || {
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).map(|_| out)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(out, *data);
+        // Four parts plus one refetch.
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
 }
@@ -0,0 +1,316 @@
+//! Full (one-shot) sync of [`TableQuery`] results into SurrealDB.
+//!
+//! Each query's rows stream through [`RowChunkDriver`] /
+//! [`run_source_runtime_with`] in `sync_opts.batch_size` chunks, so transform
+//! batching and `max_in_flight` apply within each table, as in the Snowflake
+//! source. Queries run one after another on the same connection.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use surreal_sync_core::{FieldMap, Row, SurrealSink, Type, Value};
+use surreal_sync_runtime::{
+    run_source_runtime_with, ApplyOpts, Pipeline, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
+};
+
+use crate::types::{convert_cell, SqlCell, SqlColumn};
+use crate::{SourceOpts, SqlConnection, SqlRows, SyncOpts, TableQuery};
+
+/// Run every query of `opts` with an identity transform pipeline.
+///
+/// Returns the total number of rows sunk (rows converted in dry-run).
+pub async fn run_full_sync<S: SurrealSink>(
+    connection: &mut dyn SqlConnection,
+    sink: &S,
+    opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+) -> Result<usize> {
+    let pipeline = Pipeline::new();
+    let apply_opts = ApplyOpts::identity();
+    run_full_sync_with_transforms(connection, sink, opts, sync_opts, &pipeline, &apply_opts).await
+}
+
+/// Run every query of `opts` through the shared transform/apply path.
+pub async fn run_full_sync_with_transforms<S: SurrealSink>(
+    connection: &mut dyn SqlConnection,
+    sink: &S,
+    opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<usize> {
+    if opts.queries.is_empty() {
+        tracing::warn!("No table queries configured — nothing to sync");
+        return Ok(0);
+    }
+
+    let mut total = 0;
+    for query in &opts.queries {
+        let count = sync_query(
+            connection, sink, query, opts, sync_opts, pipeline, apply_opts,
+        )
+        .await
+        .with_context(|| format!("sync of table '{}' failed", query.table))?;
+        tracing::info!("Synced {count} row(s) into table '{}'", query.table);
+        total += count;
+    }
+    tracing::info!("Generic SQL full sync complete: {total} total row(s)");
+    Ok(total)
+}
+
+async fn sync_query<S: SurrealSink>(
+    connection: &mut dyn SqlConnection,
+    sink: &S,
+    query: &TableQuery,
+    opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<usize> {
+    tracing::debug!("Running query for table '{}': {}", query.table, query.sql);
+    let rows = connection.query(&query.sql).await?;
+    let columns = rows.columns().to_vec();
+    let id_indices = resolve_id_columns(&columns, &opts.id_columns)?;
+    let batch_size = sync_opts.batch_size.max(1);
+
+    let mut chunks = QueryChunks {
+        rows,
+        table: query.table.clone(),
+        id_index_set: id_indices.iter().copied().collect(),
+        columns,
+        id_indices,
+        batch_size,
+        next_row_index: 0,
+    };
+
+    if sync_opts.dry_run {
+        let mut converted = 0;
+        while let Some(batch) = chunks.next_chunk().await? {
+            converted += batch.len();
+        }
+        tracing::info!(
+            "Dry-run scanned table '{}': {converted} row(s)",
+            query.table
+        );
+        return Ok(converted);
+    }
+
+    let mut driver = RowChunkDriver::new(chunks);
+    let transformer = Arc::new(pipeline.clone());
+    let runtime_opts = SourceRuntimeOpts::new();
+    run_source_runtime_with(&mut driver, sink, transformer, apply_opts, &runtime_opts).await?;
+    Ok(driver.sunk_count() as usize)
+}
+
+struct QueryChunks<'a> {
+    rows: Box<dyn SqlRows + 'a>,
+    columns: Vec<SqlColumn>,
+    table: String,
+    id_indices: Vec<usize>,
+    id_index_set: HashSet<usize>,
+    batch_size: usize,
+    next_row_index: usize,
+}
+
+#[async_trait]
+impl RowChunkSource for QueryChunks<'_> {
+    async fn next_chunk(&mut self) -> Result<Option<Vec<Row>>> {
+        let Some(raw_batch) = self.rows.next_batch(self.batch_size).await? else {
+            return Ok(None);
+        };
+        let mut batch = Vec::with_capacity(raw_batch.len());
+        for cells in raw_batch {
+            batch.push(self.convert_row(&cells)?);
+            self.next_row_index += 1;
+        }
+        Ok(Some(batch))
+    }
+}
+
+impl QueryChunks<'_> {
+    fn convert_row(&self, cells: &[SqlCell]) -> Result<Row> {
+        let row_index = self.next_row_index;
+        if cells.len() != self.columns.len() {
+            return Err(anyhow!(
+                "row {row_index} of table '{}' has {} cells but {} columns were declared",
+                self.table,
+                cells.len(),
+                self.columns.len()
+            ));
+        }
+
+        let mut fields = FieldMap::new();
+        for (i, column) in self.columns.iter().enumerate() {
+            // Explicit ID columns become the record ID, not fields.
+            if self.id_index_set.contains(&i) {
+                continue;
+            }
+            fields.insert(column.name.clone(), convert_cell(&cells[i], column)?);
+        }
+
+        let id = match self.id_indices.as_slice() {
+            [] => Value::Int64(row_index as i64),
+            [only] => id_part(&cells[*only], &self.columns[*only])?,
+            many => Value::Array {
+                elements: many
+                    .iter()
+                    .map(|&i| id_part(&cells[i], &self.columns[i]))
+                    .collect::<Result<_>>()?,
+                element_type: Box::new(Type::Json),
+            },
+        };
+        Ok(Row::new(self.table.clone(), row_index as u64, id, fields))
+    }
+}
+
+/// Map the configured ID column names to their positions in the result set,
+/// case-insensitively.
+fn resolve_id_columns(columns: &[SqlColumn], id_columns: &[String]) -> Result<Vec<usize>> {
+    id_columns
+        .iter()
+        .map(|name| {
+            columns
+                .iter()
+                .position(|c| c.name.eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| anyhow!("id column '{name}' not found in result set"))
+        })
+        .collect()
+}
+
+fn id_part(cell: &SqlCell, column: &SqlColumn) -> Result<Value> {
+    if *cell == SqlCell::Null {
+        return Err(anyhow!("id column '{}' is NULL", column.name));
+    }
+    convert_cell(cell, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_runtime::test_support::RecordingSink;
+
+    /// In-memory connection serving one fixed result set per query text.
+    struct FakeConnection {
+        columns: Vec<SqlColumn>,
+        rows: Vec<Vec<SqlCell>>,
+    }
+
+    struct FakeRows {
+        columns: Vec<SqlColumn>,
+        rows: std::vec::IntoIter<Vec<SqlCell>>,
+    }
+
+    #[async_trait]
+    impl SqlConnection for FakeConnection {
+        async fn query(&mut self, _sql: &str) -> Result<Box<dyn SqlRows + '_>> {
+            Ok(Box::new(FakeRows {
+                columns: self.columns.clone(),
+                rows: self.rows.clone().into_iter(),
+            }))
+        }
+    }
+
+    #[async_trait]
+    impl SqlRows for FakeRows {
+        fn columns(&self) -> &[SqlColumn] {
+            &self.columns
+        }
+
+        async fn next_batch(&mut self, max: usize) -> Result<Option<Vec<Vec<SqlCell>>>> {
+            let batch: Vec<_> = self.rows.by_ref().take(max).collect();
+            Ok((!batch.is_empty()).then_some(batch))
+        }
+    }
+
+    fn customers() -> FakeConnection {
+        FakeConnection {
+            columns: vec![
+                SqlColumn::new("CustomerId", "int"),
+                SqlColumn::new("Name", "nvarchar(50)"),
+                SqlColumn::new("Balance", "decimal(10,2)"),
+            ],
+            rows: (1..=5)
+                .map(|id| {
+                    vec![
+                        SqlCell::Int(id),
+                        SqlCell::Text(format!("c{id}")),
+                        SqlCell::Text(format!("{id}.50")),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn rows_are_converted_and_keyed_by_id_columns() {
+        let mut connection = customers();
+        let sink = RecordingSink::new();
+        let opts = SourceOpts {
+            queries: vec![TableQuery::new("customers", "SELECT * FROM Customers")],
+            id_columns: vec!["customerid".to_string()],
+        };
+        let sync_opts = SyncOpts {
+            batch_size: 2,
+            dry_run: false,
+        };
+
+        let count = run_full_sync(&mut connection, &sink, &opts, &sync_opts)
+            .await
+            .unwrap();
+
+        assert_eq!(count, 5);
+        let rows: Vec<Row> = sink.rows_written().into_iter().flatten().collect();
+        assert_eq!(rows.len(), 5);
+        let first = &rows[0];
+        assert_eq!(first.table, "customers");
+        assert_eq!(first.id, Value::Int32(1));
+        let fields = &first.fields;
+        assert!(!fields.contains_key("CustomerId"));
+        assert_eq!(
+            fields["Name"],
+            Value::VarChar {
+                value: "c1".into(),
+                length: 50
+            }
+        );
+        assert_eq!(fields["Balance"], Value::decimal("1.50", 10, 2));
+    }
+
+    #[tokio::test]
+    async fn dry_run_converts_without_writing_and_reports_bad_cells() {
+        let mut connection = customers();
+        let sink = RecordingSink::new();
+        let mut opts = SourceOpts {
+            queries: vec![TableQuery::new("customers", "SELECT * FROM Customers")],
+            id_columns: Vec::new(),
+        };
+        let sync_opts = SyncOpts {
+            batch_size: 10,
+            dry_run: true,
+        };
+        assert_eq!(
+            run_full_sync(&mut connection, &sink, &opts, &sync_opts)
+                .await
+                .unwrap(),
+            5
+        );
+        assert!(sink.rows_written().is_empty());
+
+        connection.rows[3][2] = SqlCell::Text("n/a".into());
+        let err = run_full_sync(&mut connection, &sink, &opts, &sync_opts)
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("Balance"), "{err:#}");
+
+        opts.id_columns = vec!["missing".into()];
+        let err = run_full_sync(&mut connection, &sink, &opts, &sync_opts)
+            .await
+            .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("id column 'missing'"),
+            "{err:#}"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Full sync from a generic SQL database for surreal-sync.
+//!
+//! For relational sources without a dedicated connector (Oracle, SQL Server,
+//! DB2, ...), this crate runs one `SELECT` per target table and writes the
+//! rows to SurrealDB through the shared transform/apply path. Column values
+//! are converted by their declared type name (see [`types`]), so any driver
+//! that can report a column's type name and hand back primitive cells works.
+//!
+//! The crate does not bundle a driver. Embedders implement [`SqlConnection`]
+//! (and the [`SqlRows`] it returns) over the client of their choice — an
+//! ODBC binding, `sqlx`'s `Any` driver, `tiberius`, ... — and pass it to
+//! [`full_sync::run_full_sync`]. The connection string is the driver's
+//! concern and never reaches this crate.
+//!
+//! There is no CDC/incremental sync and no durable source cursor: a failed
+//! run is repeated from the start (writes are upserts, so rows already
+//! written are overwritten, not duplicated, when ids come from
+//! [`SourceOpts::id_columns`]).
+//!
+//! ```ignore
+//! use surreal_sync_generic_sql::{full_sync, SourceOpts, SyncOpts, TableQuery};
+//!
+//! let opts = SourceOpts {
+//!     queries: vec![TableQuery::new("customers", "SELECT * FROM dbo.Customers")],
+//!     id_columns: vec!["CustomerId".to_string()],
+//! };
+//! let sync_opts = SyncOpts { batch_size: 1000, dry_run: false };
+//! full_sync::run_full_sync(&mut connection, &sink, &opts, &sync_opts).await?;
+//! ```
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+pub mod full_sync;
+pub mod types;
+
+pub use types::{convert_cell, SqlCell, SqlColumn};
+
+/// A `SELECT` whose rows are written to one SurrealDB table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableQuery {
+    /// Target SurrealDB table.
+    pub table: String,
+    /// Query run verbatim against the source.
+    pub sql: String,
+}
+
+impl TableQuery {
+    pub fn new(table: impl Into<String>, sql: impl Into<String>) -> Self {
+        Self {
+            table: table.into(),
+            sql: sql.into(),
+        }
+    }
+}
+
+/// Selection options for the generic SQL source.
+#[derive(Clone, Debug, Default)]
+pub struct SourceOpts {
+    /// Queries to run, in order, one per target table.
+    pub queries: Vec<TableQuery>,
+    /// Result columns forming the SurrealDB record ID (matched
+    /// case-insensitively, applied to every query). Empty means a sequential
+    /// per-table row index.
+    pub id_columns: Vec<String>,
+}
+
+/// Non-connection sync options.
+#[derive(Clone, Debug)]
+pub struct SyncOpts {
+    /// Number of rows per read chunk fed into the apply window.
+    pub batch_size: usize,
+    /// When true, read and convert but do not write to SurrealDB.
+    pub dry_run: bool,
+}
+
+/// A connection able to run a query and stream its rows.
+#[async_trait]
+pub trait SqlConnection: Send {
+    /// Run `sql` and return its result set.
+    async fn query(&mut self, sql: &str) -> Result<Box<dyn SqlRows + '_>>;
+}
+
+/// The result set of one query.
+#[async_trait]
+pub trait SqlRows: Send {
+    /// Result columns, in cell order.
+    fn columns(&self) -> &[SqlColumn];
+
+    /// Up to `max` further rows, each with one cell per column; `None` once
+    /// the result set is exhausted.
+    async fn next_batch(&mut self, max: usize) -> Result<Option<Vec<Vec<SqlCell>>>>;
+}
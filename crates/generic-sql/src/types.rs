@@ -0,0 +1,487 @@
+//! Type-name-driven conversion of generic SQL cells.
+//!
+//! Drivers hand back each cell as a primitive [`SqlCell`] (most generic
+//! drivers can only do that much — ODBC and `sqlx::Any` both fall back to
+//! text for anything beyond integers, floats, strings and bytes), together
+//! with the column's declared type name. [`convert_cell`] uses the type name
+//! to recover the richer [`Value`] the rest of surreal-sync writes.
+//!
+//! Type names are matched case-insensitively after dropping parameters and
+//! normalizing whitespace, so `NUMBER(10, 2)`, `nvarchar(max)` and
+//! `TIMESTAMP(6) WITH TIME ZONE` are recognized. Parameters still inform the
+//! result: the scale of `NUMBER`/`DECIMAL` and the length of `CHAR`/`VARCHAR`.
+//!
+//! | Type names | Value | Accepted cells |
+//! |------------|-------|----------------|
+//! | `bit`, `bool`, `boolean` | `Bool` | bool, 0/1, `true`/`false`/`t`/`f`/`1`/`0` |
+//! | `tinyint`, `smallint`, `int2` | `Int16` | integer or integer text |
+//! | `int`, `integer`, `int4`, `mediumint` | `Int32` (`Int64` if `unsigned`) | integer or integer text |
+//! | `bigint`, `int8` | `Int64` (`Decimal` past `i64`) | integer or integer text |
+//! | `number`, `numeric`, `decimal`, `dec`, `money`, `smallmoney` | `Int64` with scale 0, else `Decimal` | integer, float or numeric text |
+//! | `real`, `float4`, `binary_float` | `Float32` | number or numeric text |
+//! | `float`, `float8`, `double`, `double precision`, `binary_double` | `Float64` | number or numeric text |
+//! | `char`, `nchar`, `character` | `Char` | text |
+//! | `varchar`, `nvarchar`, `varchar2`, `nvarchar2`, `character varying` | `VarChar` (`Text` without a length) | text |
+//! | `text`, `ntext`, `clob`, `nclob`, `long`, `string`, `xml`, `tinytext`, `mediumtext`, `longtext` | `Text` | text |
+//! | `date` | `Date` (`LocalDateTime` when the text has a time part, as Oracle `DATE` does) | `YYYY-MM-DD[ HH:MM:SS[.f]]` |
+//! | `time` | `Time` | `HH:MM:SS[.f]` |
+//! | `datetime`, `datetime2`, `smalldatetime`, `timestamp`, `timestamp without time zone` | `LocalDateTime` | `YYYY-MM-DD[ T]HH:MM:SS[.f]` |
+//! | `datetimeoffset`, `timestamptz`, `timestamp with time zone`, `timestamp with local time zone` | `ZonedDateTime` | RFC 3339, or `YYYY-MM-DD HH:MM:SS[.f] ±HH:MM` |
+//! | `uuid`, `uniqueidentifier` | `Uuid` | text, optionally in braces |
+//! | `json` / `jsonb` | `Json` / `Jsonb` | JSON text |
+//! | `binary`, `varbinary`, `blob`, `bytea`, `raw`, `long raw`, `image`, `rowversion` | `Bytes` | bytes, or hex text (optional `0x`) |
+//!
+//! Limits:
+//! - A byte cell is kept as `Bytes` whatever the type name says. SQL Server's
+//!   `timestamp` is a binary row version, not a date.
+//! - Unknown type names (user-defined types, `interval`, spatial types, ...)
+//!   are not interpreted. The cell is kept as its primitive value (`Bool`,
+//!   `Int64`, `Float64`, `Text` or `Bytes`).
+//! - Temporal text must be in the ISO forms above. Drivers that render dates
+//!   in a locale format need a query that formats them, e.g.
+//!   `CONVERT(varchar, col, 126)` or `TO_CHAR(col, 'YYYY-MM-DD HH24:MI:SS')`.
+//! - `Decimal` keeps the source text. SurrealDB writes values beyond its
+//!   decimal range as floats.
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use surreal_sync_core::Value;
+
+/// A result column: its name and declared type name, as the driver reports
+/// them (e.g. `NUMBER(10,2)`, `nvarchar(255)`, `datetimeoffset`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SqlColumn {
+    pub name: String,
+    pub type_name: String,
+}
+
+impl SqlColumn {
+    pub fn new(name: impl Into<String>, type_name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            type_name: type_name.into(),
+        }
+    }
+}
+
+/// A cell as a generic driver returns it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SqlCell {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+/// Convert `cell` to a [`Value`] according to `column`'s type name.
+pub fn convert_cell(cell: &SqlCell, column: &SqlColumn) -> Result<Value> {
+    if *cell == SqlCell::Null {
+        return Ok(Value::Null);
+    }
+    if let SqlCell::Bytes(bytes) = cell {
+        return Ok(Value::Bytes(bytes.clone()));
+    }
+    let (base, params) = parse_type_name(&column.type_name);
+    convert_typed(cell, &base, &params).with_context(|| {
+        format!(
+            "column '{}' ({}): cannot convert {cell:?}",
+            column.name, column.type_name
+        )
+    })
+}
+
+fn convert_typed(cell: &SqlCell, base: &str, params: &[u32]) -> Result<Value> {
+    let unsigned = base.ends_with(" unsigned");
+    let base = base.trim_end_matches(" unsigned");
+    Ok(match base {
+        "bit" | "bool" | "boolean" => Value::Bool(match cell {
+            SqlCell::Bool(b) => *b,
+            SqlCell::Int(0) => false,
+            SqlCell::Int(1) => true,
+            _ => parse_bool(&cell_text(cell))?,
+        }),
+
+        "tinyint" | "smallint" | "int2" => Value::Int16(i16::try_from(cell_int(cell)?)?),
+        "int" | "integer" | "int4" | "mediumint" if unsigned => Value::Int64(cell_int(cell)?),
+        "int" | "integer" | "int4" | "mediumint" => Value::Int32(i32::try_from(cell_int(cell)?)?),
+        "bigint" | "int8" => match cell_int(cell) {
+            Ok(n) => Value::Int64(n),
+            // `bigint unsigned` past i64::MAX.
+            Err(_) if unsigned => decimal(&cell_text(cell), params, 20)?,
+            Err(e) => return Err(e),
+        },
+
+        "number" | "numeric" | "decimal" | "dec" => decimal(&cell_text(cell), params, 38)?,
+        "money" | "smallmoney" => decimal(&cell_text(cell), &[19, 4], 19)?,
+
+        "real" | "float4" | "binary_float" => Value::Float32(cell_float(cell)? as f32),
+        "float" | "float8" | "double" | "double precision" | "binary_double" => {
+            Value::Float64(cell_float(cell)?)
+        }
+
+        "char" | "nchar" | "character" => Value::Char {
+            value: cell_text(cell),
+            length: length_param(params).unwrap_or(1),
+        },
+        "varchar" | "nvarchar" | "varchar2" | "nvarchar2" | "character varying" => {
+            match length_param(params) {
+                Some(length) => Value::VarChar {
+                    value: cell_text(cell),
+                    length,
+                },
+                None => Value::Text(cell_text(cell)),
+            }
+        }
+        "text" | "ntext" | "clob" | "nclob" | "long" | "string" | "xml" | "tinytext"
+        | "mediumtext" | "longtext" => Value::Text(cell_text(cell)),
+
+        "date" => {
+            let text = cell_text(cell);
+            match NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d") {
+                Ok(date) => Value::Date(date.and_time(NaiveTime::MIN).and_utc()),
+                Err(_) => Value::LocalDateTime(parse_local_datetime(&text)?),
+            }
+        }
+        "time" => {
+            let time = NaiveTime::parse_from_str(cell_text(cell).trim(), "%H:%M:%S%.f")?;
+            Value::Time(
+                NaiveDate::from_ymd_opt(1970, 1, 1)
+                    .unwrap()
+                    .and_time(time)
+                    .and_utc(),
+            )
+        }
+        "datetime"
+        | "datetime2"
+        | "smalldatetime"
+        | "timestamp"
+        | "timestamp without time zone" => {
+            Value::LocalDateTime(parse_local_datetime(&cell_text(cell))?)
+        }
+        "datetimeoffset"
+        | "timestamptz"
+        | "timestamp with time zone"
+        | "timestamp with local time zone" => {
+            Value::ZonedDateTime(parse_zoned_datetime(&cell_text(cell))?)
+        }
+
+        "uuid" | "uniqueidentifier" => {
+            let text = cell_text(cell);
+            let text = text.trim().trim_start_matches('{').trim_end_matches('}');
+            Value::Uuid(uuid::Uuid::parse_str(text)?)
+        }
+
+        "json" => Value::Json(Box::new(serde_json::from_str(&cell_text(cell))?)),
+        "jsonb" => Value::Jsonb(Box::new(serde_json::from_str(&cell_text(cell))?)),
+
+        "binary" | "varbinary" | "blob" | "bytea" | "raw" | "long raw" | "image" | "rowversion" => {
+            Value::Bytes(decode_hex(&cell_text(cell))?)
+        }
+
+        _ => match cell {
+            SqlCell::Bool(b) => Value::Bool(*b),
+            SqlCell::Int(n) => Value::Int64(*n),
+            SqlCell::Float(f) => Value::Float64(*f),
+            SqlCell::Text(s) => Value::Text(s.clone()),
+            SqlCell::Null | SqlCell::Bytes(_) => unreachable!("handled by convert_cell"),
+        },
+    })
+}
+
+/// Lower-cased type name without parameters, plus its numeric parameters:
+/// `TIMESTAMP(6) WITH TIME ZONE` → (`timestamp with time zone`, `[6]`).
+/// Non-numeric parameters (`max`, `100 char`) are dropped.
+fn parse_type_name(type_name: &str) -> (String, Vec<u32>) {
+    let mut base = String::new();
+    let mut params = Vec::new();
+    let mut rest = type_name;
+    while let Some(open) = rest.find('(') {
+        base.push_str(&rest[..open]);
+        let close = rest[open..].find(')').map_or(rest.len(), |i| open + i);
+        params.extend(
+            rest[open + 1..close]
+                .split(',')
+                .filter_map(|p| p.split_whitespace().next()?.parse::<u32>().ok()),
+        );
+        rest = rest.get(close + 1..).unwrap_or_default();
+    }
+    base.push_str(rest);
+    let base = base
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase();
+    (base, params)
+}
+
+fn length_param(params: &[u32]) -> Option<u16> {
+    params
+        .first()
+        .map(|&length| u16::try_from(length).unwrap_or(u16::MAX))
+}
+
+/// `Int64` for integral values of scale-0 columns, otherwise a `Decimal`
+/// keeping the source text.
+fn decimal(text: &str, params: &[u32], default_precision: u8) -> Result<Value> {
+    let text = text.trim();
+    let fraction_digits = text.split_once('.').map_or(0, |(_, f)| f.len());
+    let scale = match params.get(1) {
+        Some(&scale) => scale,
+        // `NUMBER(10)` has scale 0; a bare `NUMBER` takes whatever it holds.
+        None if params.len() == 1 => 0,
+        None => fraction_digits as u32,
+    };
+    if scale == 0 {
+        if let Ok(n) = text.parse::<i64>() {
+            return Ok(Value::Int64(n));
+        }
+    }
+    if text.parse::<f64>().is_err() {
+        bail!("invalid number '{text}'");
+    }
+    let precision = params
+        .first()
+        .map_or(default_precision, |&p| u8::try_from(p).unwrap_or(u8::MAX));
+    Ok(Value::decimal(
+        text,
+        precision,
+        u8::try_from(scale).unwrap_or(u8::MAX),
+    ))
+}
+
+fn cell_text(cell: &SqlCell) -> String {
+    match cell {
+        SqlCell::Null => String::new(),
+        SqlCell::Bool(b) => b.to_string(),
+        SqlCell::Int(n) => n.to_string(),
+        SqlCell::Float(f) => f.to_string(),
+        SqlCell::Text(s) => s.clone(),
+        SqlCell::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+fn cell_int(cell: &SqlCell) -> Result<i64> {
+    match cell {
+        SqlCell::Int(n) => Ok(*n),
+        SqlCell::Bool(b) => Ok(i64::from(*b)),
+        _ => Ok(cell_text(cell).trim().parse()?),
+    }
+}
+
+fn cell_float(cell: &SqlCell) -> Result<f64> {
+    match cell {
+        SqlCell::Float(f) => Ok(*f),
+        SqlCell::Int(n) => Ok(*n as f64),
+        _ => Ok(cell_text(cell).trim().parse()?),
+    }
+}
+
+fn parse_bool(text: &str) -> Result<bool> {
+    match text.trim().to_ascii_lowercase().as_str() {
+        "true" | "t" | "1" | "yes" | "y" => Ok(true),
+        "false" | "f" | "0" | "no" | "n" => Ok(false),
+        other => Err(anyhow!("invalid boolean value '{other}'")),
+    }
+}
+
+fn parse_local_datetime(text: &str) -> Result<DateTime<Utc>> {
+    let text = text.trim();
+    [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+    .map(|dt| dt.and_utc())
+    .ok_or_else(|| anyhow!("invalid timestamp '{text}'"))
+}
+
+fn parse_zoned_datetime(text: &str) -> Result<DateTime<Utc>> {
+    let text = text.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    [
+        "%Y-%m-%d %H:%M:%S%.f %:z",
+        "%Y-%m-%d %H:%M:%S%.f%:z",
+        "%Y-%m-%d %H:%M:%S%.f %z",
+        "%Y-%m-%d %H:%M:%S%.f%#z",
+    ]
+    .iter()
+    .find_map(|format| DateTime::parse_from_str(text, format).ok())
+    .map(|dt| dt.with_timezone(&Utc))
+    .ok_or_else(|| anyhow!("invalid timestamp with time zone '{text}'"))
+}
+
+fn decode_hex(text: &str) -> Result<Vec<u8>> {
+    let text = text.trim();
+    let hex = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .unwrap_or(text);
+    if !hex.len().is_multiple_of(2) {
+        bail!("odd-length hex string");
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| anyhow!("invalid hex '{text}'"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(type_name: &str, cell: SqlCell) -> Value {
+        convert_cell(&cell, &SqlColumn::new("c", type_name)).unwrap()
+    }
+
+    fn text(s: &str) -> SqlCell {
+        SqlCell::Text(s.to_string())
+    }
+
+    #[test]
+    fn type_names_are_normalized() {
+        assert_eq!(
+            parse_type_name("TIMESTAMP(6)  WITH TIME ZONE"),
+            ("timestamp with time zone".to_string(), vec![6])
+        );
+        assert_eq!(
+            parse_type_name("NUMBER(10, 2)"),
+            ("number".to_string(), vec![10, 2])
+        );
+        assert_eq!(
+            parse_type_name("nvarchar(max)"),
+            ("nvarchar".to_string(), vec![])
+        );
+        assert_eq!(
+            parse_type_name("INT UNSIGNED"),
+            ("int unsigned".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn numeric_types() {
+        assert_eq!(convert("BIT", SqlCell::Int(1)), Value::Bool(true));
+        assert_eq!(convert("boolean", text("f")), Value::Bool(false));
+        assert_eq!(convert("SMALLINT", SqlCell::Int(-3)), Value::Int16(-3));
+        assert_eq!(convert("int", text("42")), Value::Int32(42));
+        assert_eq!(
+            convert("int unsigned", SqlCell::Int(4_000_000_000)),
+            Value::Int64(4_000_000_000)
+        );
+        assert_eq!(
+            convert("bigint unsigned", text("18446744073709551615")),
+            Value::decimal("18446744073709551615", 20, 0)
+        );
+        assert_eq!(convert("NUMBER(10)", text("7")), Value::Int64(7));
+        assert_eq!(
+            convert("NUMBER(10,2)", text("12.50")),
+            Value::decimal("12.50", 10, 2)
+        );
+        assert_eq!(
+            convert("NUMBER", text("3.125")),
+            Value::decimal("3.125", 38, 3)
+        );
+        assert_eq!(convert("NUMBER", SqlCell::Int(9)), Value::Int64(9));
+        assert_eq!(
+            convert("money", text("1.2500")),
+            Value::decimal("1.2500", 19, 4)
+        );
+        assert_eq!(convert("real", text("1.5")), Value::Float32(1.5));
+        assert_eq!(convert("FLOAT", SqlCell::Int(2)), Value::Float64(2.0));
+
+        let err = convert_cell(&SqlCell::Int(70_000), &SqlColumn::new("qty", "smallint"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("qty") && err.contains("smallint"), "{err}");
+        assert!(convert_cell(&text("abc"), &SqlColumn::new("n", "decimal(5,2)")).is_err());
+    }
+
+    #[test]
+    fn string_and_binary_types() {
+        assert_eq!(
+            convert("NCHAR(3)", text("abc")),
+            Value::Char {
+                value: "abc".into(),
+                length: 3
+            }
+        );
+        assert_eq!(
+            convert("VARCHAR2(100 CHAR)", text("x")),
+            Value::VarChar {
+                value: "x".into(),
+                length: 100
+            }
+        );
+        assert_eq!(
+            convert("nvarchar(max)", text("long")),
+            Value::Text("long".into())
+        );
+        assert_eq!(
+            convert("varbinary(16)", text("0xDEADBEEF")),
+            Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        // SQL Server `timestamp` is a binary row version.
+        assert_eq!(
+            convert("timestamp", SqlCell::Bytes(vec![0, 1])),
+            Value::Bytes(vec![0, 1])
+        );
+        assert_eq!(
+            convert(
+                "UNIQUEIDENTIFIER",
+                text("{6F9619FF-8B86-D011-B42D-00C04FC964FF}")
+            ),
+            Value::Uuid("6f9619ff-8b86-d011-b42d-00c04fc964ff".parse().unwrap())
+        );
+        assert_eq!(
+            convert("json", text(r#"{"a": [1, 2]}"#)),
+            Value::Json(Box::new(serde_json::json!({"a": [1, 2]})))
+        );
+        assert_eq!(
+            convert("geography", text("POINT(1 2)")),
+            Value::Text("POINT(1 2)".into())
+        );
+        assert_eq!(convert("INTERVAL", SqlCell::Int(5)), Value::Int64(5));
+        assert_eq!(convert("varchar(10)", SqlCell::Null), Value::Null);
+    }
+
+    #[test]
+    fn temporal_types() {
+        let utc = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        assert_eq!(
+            convert("date", text("2024-02-29")),
+            Value::Date(utc("2024-02-29T00:00:00Z"))
+        );
+        // Oracle DATE carries a time of day.
+        assert_eq!(
+            convert("DATE", text("2024-02-29 13:45:00")),
+            Value::LocalDateTime(utc("2024-02-29T13:45:00Z"))
+        );
+        assert_eq!(
+            convert("time(7)", text("01:02:03.5")),
+            Value::Time(utc("1970-01-01T01:02:03.5Z"))
+        );
+        assert_eq!(
+            convert("datetime2(7)", text("2024-01-02 03:04:05.1234567")),
+            Value::LocalDateTime(utc("2024-01-02T03:04:05.1234567Z"))
+        );
+        assert_eq!(
+            convert("datetimeoffset", text("2024-01-02 03:04:05.5 +02:00")),
+            Value::ZonedDateTime(utc("2024-01-02T01:04:05.5Z"))
+        );
+        assert_eq!(
+            convert(
+                "TIMESTAMP(6) WITH TIME ZONE",
+                text("2024-01-02T03:04:05-05:00")
+            ),
+            Value::ZonedDateTime(utc("2024-01-02T08:04:05Z"))
+        );
+        assert!(convert_cell(&text("02/01/2024"), &SqlColumn::new("d", "datetime")).is_err());
+    }
+}
@@ -12,19 +12,55 @@ use anyhow::{anyhow, Result};
 ///
 /// This means: Objects with `type="user"` will be converted to Thing references
 /// to the `users` table using the `user_id` field.
+///
+/// Such a rule applies to top-level fields. A trailing `,path=...` points it
+/// at nested objects instead: `path=author.ref` for the `ref` object inside
+/// `author`, `path=tags[].ref` for the `ref` of every element of `tags`.
 #[derive(Debug, Clone)]
 pub struct ConversionRule {
     pub type_field: String,
     pub type_value: String,
     pub id_field: String,
     pub target_table: String,
+    /// Where the referencing objects live; empty for top-level fields
+    pub path: Vec<PathSegment>,
+}
+
+/// One step of a [`ConversionRule`] path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// An object field
+    Field(String),
+    /// Every element of an array (`[]`)
+    Each,
+}
+
+/// Parse a dotted path such as `author.ref` or `tags[].ref`.
+fn parse_path(path: &str) -> Result<Vec<PathSegment>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let mut name = part;
+        let mut each = 0;
+        while let Some(stripped) = name.strip_suffix("[]") {
+            name = stripped;
+            each += 1;
+        }
+        if name.is_empty() {
+            return Err(anyhow!(
+                "Invalid path specification: {path}. Expected: field.field or field[].field"
+            ));
+        }
+        segments.push(PathSegment::Field(name.to_string()));
+        segments.extend(std::iter::repeat_n(PathSegment::Each, each));
+    }
+    Ok(segments)
 }
 
 impl ConversionRule {
     /// Parse a conversion rule from a string
     ///
     /// # Format
-    /// `type="value",id_field table:id_field`
+    /// `type="value",id_field table:id_field[,path=field.field]`
     ///
     /// # Examples
     /// ```
@@ -82,11 +118,25 @@ impl ConversionRule {
             ));
         }
 
+        // Parse the optional path=field.field
+        let path = match parts.get(2).map(|p| p.trim()) {
+            None => Vec::new(),
+            Some(path_part) => {
+                let Some(path) = path_part.strip_prefix("path=") else {
+                    return Err(anyhow!(
+                        "Invalid path specification. Expected: path=field.field"
+                    ));
+                };
+                parse_path(path)?
+            }
+        };
+
         Ok(ConversionRule {
             type_field: "type".to_string(),
             type_value,
             id_field: id_field.to_string(),
             target_table,
+            path,
         })
     }
 }
@@ -334,4 +384,52 @@ mod tests {
         assert_eq!(rule.id_field, "id");
         assert_eq!(rule.target_table, "profiles");
     }
+
+    /// Tests that a rule without a path applies to top-level fields.
+    #[test]
+    fn test_parse_without_path() {
+        let rule = ConversionRule::parse(r#"type="user",user_id users:user_id"#).unwrap();
+        assert!(rule.path.is_empty());
+    }
+
+    /// Tests parsing a nested path and an array path.
+    #[test]
+    fn test_parse_path() {
+        let rule = ConversionRule::parse(r#"type="user",id users:id,path=author.ref"#).unwrap();
+        assert_eq!(rule.target_table, "users");
+        assert_eq!(
+            rule.path,
+            vec![
+                PathSegment::Field("author".to_string()),
+                PathSegment::Field("ref".to_string()),
+            ]
+        );
+
+        let rule = ConversionRule::parse(r#"type="tag",id tags:id, path=tags[].ref"#).unwrap();
+        assert_eq!(
+            rule.path,
+            vec![
+                PathSegment::Field("tags".to_string()),
+                PathSegment::Each,
+                PathSegment::Field("ref".to_string()),
+            ]
+        );
+    }
+
+    /// Tests that malformed paths and unknown trailing parts are rejected.
+    #[test]
+    fn test_parse_invalid_path() {
+        for rule_str in [
+            r#"type="user",id users:id,author.ref"#,
+            r#"type="user",id users:id,path="#,
+            r#"type="user",id users:id,path=author..ref"#,
+            r#"type="user",id users:id,path=[].ref"#,
+        ] {
+            let result = ConversionRule::parse(rule_str);
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid path specification"));
+        }
+    }
 }
@@ -6,8 +6,13 @@
 pub mod conversion;
 mod sync;
 
-pub use conversion::ConversionRule;
-pub use sync::{sync, sync_with_transforms, Config, SourceOpts};
+pub use conversion::{ConversionRule, PathSegment};
+pub use sync::{
+    infer_schema, sync, sync_with_transforms, Config, ImportReport, OnError, SkippedLine,
+    SourceOpts,
+};
 
 // Re-export file source types for convenience
-pub use surreal_sync_file::{FileSource, ResolvedSource, DEFAULT_BUFFER_SIZE};
+pub use surreal_sync_file::{
+    FileSource, HttpOptions, ResolvedSource, S3Credentials, DEFAULT_BUFFER_SIZE,
+};
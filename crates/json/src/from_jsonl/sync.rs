@@ -1,15 +1,19 @@
 //! JSONL synchronization logic
 
-use super::conversion::ConversionRule;
+use super::conversion::{ConversionRule, PathSegment};
 use crate::types::JsonValueWithSchema;
 use anyhow::{anyhow, Context, Result};
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{DatabaseSchema, Row, TableDefinition, Type, TypedValue, Value};
-use surreal_sync_file::{FileSource, DEFAULT_BUFFER_SIZE};
+use surreal_sync_core::{
+    DatabaseSchema, FieldMap, Row, Schema, TableDefinition, TableSchemaInferrer, Type, TypedValue,
+    Value,
+};
+use surreal_sync_file::{
+    FileSource, HttpOptions, ResolvedSource, S3Credentials, SourceOptions, DEFAULT_BUFFER_SIZE,
+};
 use surreal_sync_runtime::{
     run_source_runtime, ApplyOpts, CheckpointPolicy, Pipeline, PositionedEvent, SourceDriver,
     SourceRuntimeOpts,
@@ -37,6 +41,12 @@ pub struct Config {
     /// List of HTTP/HTTPS URLs to import (legacy, use `sources` instead)
     pub http_uris: Vec<String>,
 
+    /// AWS credentials for `s3://` sources (default provider chain unless set)
+    pub s3_credentials: S3Credentials,
+
+    /// Bearer token, extra headers and timeout for HTTP/HTTPS sources
+    pub http_options: HttpOptions,
+
     /// Field to use as record ID (default: "id")
     pub id_field: String,
 
@@ -55,6 +65,54 @@ pub struct Config {
 
     /// Optional schema for type-aware conversion (e.g., UUID, DateTime parsing)
     pub schema: Option<DatabaseSchema>,
+
+    /// What to do with a line that cannot be imported (default: abort)
+    pub on_error: OnError,
+}
+
+/// Handling of a line that is not valid JSON or cannot become a row (not an
+/// object, missing or invalid ID).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnError {
+    /// Fail the import
+    #[default]
+    Abort,
+    /// Log the line and continue
+    SkipAndLog,
+    /// Log the line, continue, and return it in [`ImportReport::skipped_lines`]
+    SkipAndCollect,
+}
+
+/// A line skipped under [`OnError::SkipAndCollect`].
+#[derive(Clone, Debug)]
+pub struct SkippedLine {
+    /// Source the line was read from
+    pub source: String,
+    /// 1-based line number within the source
+    pub line_number: u64,
+    /// Why the line could not be imported
+    pub error: String,
+    /// The line as read
+    pub raw_line: String,
+}
+
+/// Outcome of a JSONL import.
+#[derive(Clone, Debug, Default)]
+pub struct ImportReport {
+    /// Number of lines skipped (under either skip policy)
+    pub skipped: u64,
+    /// Skipped lines, collected under [`OnError::SkipAndCollect`]
+    pub skipped_lines: Vec<SkippedLine>,
+}
+
+impl Config {
+    fn source_options(&self) -> SourceOptions {
+        SourceOptions {
+            s3_credentials: self.s3_credentials.clone(),
+            http: self.http_options.clone(),
+            ..SourceOptions::default()
+        }
+    }
 }
 
 impl Default for Config {
@@ -64,12 +122,15 @@ impl Default for Config {
             files: vec![],
             s3_uris: vec![],
             http_uris: vec![],
+            s3_credentials: S3Credentials::default(),
+            http_options: HttpOptions::default(),
             id_field: "id".to_string(),
             id_columns: Vec::new(),
             conversion_rules: vec![],
             batch_size: 1000,
             dry_run: false,
             schema: None,
+            on_error: OnError::Abort,
         }
     }
 }
@@ -114,6 +175,48 @@ struct JsonlStreamDriver {
     line_count: u64,
     sunk_count: u64,
     finished: bool,
+    source_name: String,
+    on_error: OnError,
+    report: ImportReport,
+}
+
+impl JsonlStreamDriver {
+    fn line_to_row(&self, line: &str) -> Result<Row> {
+        let json_value: JsonValue = serde_json::from_str(line)
+            .map_err(|e| anyhow!("Error parsing JSON at line {}: {e}", self.line_count))?;
+
+        convert_json_to_universal_row(
+            &json_value,
+            &self.table_name,
+            &self.id_field,
+            &self.id_columns,
+            &self.rules,
+            self.table_schema.as_ref(),
+            self.line_count,
+        )
+    }
+
+    /// Apply [`OnError`] to a line that failed to convert.
+    fn skip_line(&mut self, line: String, error: anyhow::Error) -> Result<()> {
+        if self.on_error == OnError::Abort {
+            return Err(error);
+        }
+        tracing::warn!(
+            "Skipping line {} of {}: {error:#}",
+            self.line_count,
+            self.source_name
+        );
+        self.report.skipped += 1;
+        if self.on_error == OnError::SkipAndCollect {
+            self.report.skipped_lines.push(SkippedLine {
+                source: self.source_name.clone(),
+                line_number: self.line_count,
+                error: format!("{error:#}"),
+                raw_line: line,
+            });
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -137,18 +240,13 @@ impl SourceDriver for JsonlStreamDriver {
                 continue;
             }
 
-            let json_value: JsonValue = serde_json::from_str(&line)
-                .map_err(|e| anyhow!("Error parsing JSON at line {}: {e}", self.line_count))?;
-
-            let row = convert_json_to_universal_row(
-                &json_value,
-                &self.table_name,
-                &self.id_field,
-                &self.id_columns,
-                &self.rules,
-                self.table_schema.as_ref(),
-                self.line_count,
-            )?;
+            let row = match self.line_to_row(&line) {
+                Ok(row) => row,
+                Err(e) => {
+                    self.skip_line(line, e)?;
+                    continue;
+                }
+            };
             let pos = self.line_count;
             let change = surreal_sync_core::Change::update(row.table, row.id, row.fields);
             events.push(PositionedEvent::change(change, pos));
@@ -173,27 +271,11 @@ impl SourceDriver for JsonlStreamDriver {
     }
 }
 
-/// Process JSONL data from a reader and import into SurrealDB
-///
-/// This function handles all JSONL parsing, data conversion, and SurrealDB insertion
-/// for a single JSONL source (file, S3, or HTTP).
-///
-/// [`ConversionRule`]s are applied while building each [`Row`], before
-/// the batch is passed through the transform [`Pipeline`].
-async fn process_jsonl_reader<S: SurrealSink>(
-    surreal: &S,
-    config: &Config,
-    reader: Box<dyn std::io::Read + Send>,
-    source_name: &str,
-    rules: &[ConversionRule],
-    pipeline: &Pipeline,
-    apply_opts: &ApplyOpts,
-) -> Result<()> {
-    tracing::info!("Processing JSONL from: {source_name}");
-
-    // Determine table name from source name (filename without extension)
-    let table_name = if source_name.starts_with("http://") || source_name.starts_with("https://") {
-        // For HTTP URLs, extract filename from path
+/// Target table for a JSONL source: the file name without its `.jsonl`
+/// extension (`items` when none can be derived).
+fn table_name_for_source(source_name: &str) -> String {
+    let source_name = surreal_sync_file::strip_compression_suffix(source_name);
+    if source_name.starts_with("http://") || source_name.starts_with("https://") {
         source_name
             .rsplit('/')
             .next()
@@ -201,7 +283,6 @@ async fn process_jsonl_reader<S: SurrealSink>(
             .unwrap_or("items")
             .to_string()
     } else if source_name.starts_with("s3://") {
-        // For S3 URIs, extract filename from key
         source_name
             .rsplit('/')
             .next()
@@ -209,13 +290,34 @@ async fn process_jsonl_reader<S: SurrealSink>(
             .unwrap_or("items")
             .to_string()
     } else {
-        // For local files, use the file stem
         let path = PathBuf::from(source_name);
         path.file_stem()
             .and_then(|s| s.to_str())
             .unwrap_or("items")
             .to_string()
-    };
+    }
+}
+
+/// Process JSONL data from a reader and import into SurrealDB
+///
+/// This function handles all JSONL parsing, data conversion, and SurrealDB insertion
+/// for a single JSONL source (file, S3, or HTTP).
+///
+/// [`ConversionRule`]s are applied while building each [`Row`], before
+/// the batch is passed through the transform [`Pipeline`].
+async fn process_jsonl_reader<S: SurrealSink>(
+    surreal: &S,
+    config: &Config,
+    reader: Box<dyn std::io::Read + Send>,
+    source_name: &str,
+    rules: &[ConversionRule],
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<ImportReport> {
+    tracing::info!("Processing JSONL from: {source_name}");
+
+    // Determine table name from source name (filename without extension)
+    let table_name = table_name_for_source(source_name);
 
     tracing::info!("Target table: {table_name}");
 
@@ -237,6 +339,9 @@ async fn process_jsonl_reader<S: SurrealSink>(
         line_count: 0,
         sunk_count: 0,
         finished: false,
+        source_name: source_name.to_string(),
+        on_error: config.on_error,
+        report: ImportReport::default(),
     };
 
     if config.dry_run {
@@ -267,7 +372,123 @@ async fn process_jsonl_reader<S: SurrealSink>(
         table_name
     );
 
-    Ok(())
+    Ok(driver.report)
+}
+
+/// Resolve `sources` (keeping only `.jsonl` files) and the legacy `files` /
+/// `s3_uris` / `http_uris` into the JSONL sources to import, in order.
+async fn resolve_sources(config: &Config) -> Result<Vec<ResolvedSource>> {
+    let mut all_resolved = Vec::new();
+
+    for source in &config.sources {
+        let jsonl_sources: Vec<_> = source
+            .resolve_with(&config.source_options())
+            .await?
+            .into_iter()
+            .filter(|s| s.extension() == Some("jsonl"))
+            .collect();
+
+        if jsonl_sources.is_empty() && source.is_directory() {
+            tracing::warn!("No .jsonl files found in directory: {:?}", source);
+        }
+
+        all_resolved.extend(jsonl_sources);
+    }
+
+    for file_path in &config.files {
+        all_resolved.extend(
+            FileSource::Local(file_path.clone())
+                .resolve_with(&config.source_options())
+                .await?,
+        );
+    }
+
+    for uri in config.s3_uris.iter().chain(&config.http_uris) {
+        all_resolved.extend(
+            FileSource::parse(uri)?
+                .resolve_with(&config.source_options())
+                .await?,
+        );
+    }
+
+    Ok(all_resolved)
+}
+
+/// Infer a schema for every target table from the first `sample_size`
+/// records of each.
+///
+/// Lines are converted exactly as an import without `--schema-file` would
+/// convert them (including [`Config::conversion_rules`]), so the inferred
+/// types describe what the import writes. Tables appear in import order.
+pub async fn infer_schema(config: &Config, sample_size: usize) -> Result<Schema> {
+    let rules = config
+        .conversion_rules
+        .iter()
+        .map(|rule| ConversionRule::parse(rule))
+        .collect::<Result<Vec<_>>>()?;
+    let mut inferrers: Vec<TableSchemaInferrer> = Vec::new();
+
+    for resolved in resolve_sources(config).await? {
+        let source_name = resolved.display_name();
+        let table_name = table_name_for_source(&source_name);
+        let idx = match inferrers.iter().position(|i| i.name() == table_name) {
+            Some(idx) => idx,
+            None => {
+                inferrers.push(TableSchemaInferrer::new(table_name.clone()));
+                inferrers.len() - 1
+            }
+        };
+        let inferrer = &mut inferrers[idx];
+        if inferrer.records() >= sample_size as u64 {
+            continue;
+        }
+
+        let reader = resolved
+            .open_with(DEFAULT_BUFFER_SIZE, &config.source_options())
+            .await
+            .with_context(|| format!("Failed to open JSONL source: {source_name}"))?;
+        for (idx, line) in BufReader::new(reader).lines().enumerate() {
+            if inferrer.records() >= sample_size as u64 {
+                break;
+            }
+            let line = line?;
+            let line_count = idx as u64 + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = serde_json::from_str::<JsonValue>(&line)
+                .map_err(|e| anyhow!("Error parsing JSON at line {line_count}: {e}"))
+                .and_then(|json_value| {
+                    convert_json_to_universal_row(
+                        &json_value,
+                        &table_name,
+                        &config.id_field,
+                        &config.id_columns,
+                        &rules,
+                        None,
+                        line_count,
+                    )
+                });
+            let row = match row {
+                Ok(row) => row,
+                // The import skips (and reports) this line too
+                Err(_) if config.on_error != OnError::Abort => continue,
+                Err(e) => return Err(e),
+            };
+            inferrer.observe(&row.id, &row.fields);
+        }
+    }
+
+    for inferrer in &inferrers {
+        tracing::info!(
+            "Inferred schema for table {} from {} sampled records",
+            inferrer.name(),
+            inferrer.records()
+        );
+    }
+    Ok(Schema::new(
+        inferrers.into_iter().map(|i| i.finish()).collect(),
+    ))
 }
 
 /// Sync JSONL files to SurrealDB with identity transforms.
@@ -280,8 +501,9 @@ async fn process_jsonl_reader<S: SurrealSink>(
 /// * `config` - Configuration for the JSONL import operation
 ///
 /// # Returns
-/// Returns Ok(()) on successful completion, or an error if the sync fails
-pub async fn sync<S: SurrealSink>(surreal: &S, config: Config) -> Result<()> {
+/// Returns the [`ImportReport`] on successful completion, or an error if the
+/// sync fails
+pub async fn sync<S: SurrealSink>(surreal: &S, config: Config) -> Result<ImportReport> {
     let pipeline = Pipeline::new();
     let apply_opts = ApplyOpts::identity();
     sync_with_transforms(surreal, config, &pipeline, &apply_opts).await
@@ -294,12 +516,15 @@ pub async fn sync<S: SurrealSink>(surreal: &S, config: Config) -> Result<()> {
 /// line into a [`Row`], before any Pipeline stages. **Multi-file
 /// imports start a fresh runtime per file** (intentional — no cross-file
 /// pipelining).
+///
+/// Lines that cannot be imported abort the sync unless [`Config::on_error`]
+/// says to skip them; the returned [`ImportReport`] counts those.
 pub async fn sync_with_transforms<S: SurrealSink>(
     surreal: &S,
     config: Config,
     pipeline: &Pipeline,
     apply_opts: &ApplyOpts,
-) -> Result<()> {
+) -> Result<ImportReport> {
     tracing::info!("Starting JSONL migration");
     tracing::info!("Sources to process: {:?}", config.sources);
     tracing::info!("Files to process: {:?}", config.files);
@@ -327,122 +552,41 @@ pub async fn sync_with_transforms<S: SurrealSink>(
     tracing::debug!("Parsed {} conversion rules", rules.len());
 
     let mut total_sources = 0;
+    let mut report = ImportReport::default();
 
-    // Process sources from the new unified interface
-    for source in &config.sources {
-        let resolved_sources = source.resolve().await?;
-
-        // Filter for .jsonl files only
-        let jsonl_sources: Vec<_> = resolved_sources
-            .into_iter()
-            .filter(|s| s.extension() == Some("jsonl"))
-            .collect();
-
-        if jsonl_sources.is_empty() && source.is_directory() {
-            tracing::warn!("No .jsonl files found in directory: {:?}", source);
-        }
-
-        for resolved in jsonl_sources {
-            let source_name = resolved.display_name();
-            let reader = resolved
-                .open(DEFAULT_BUFFER_SIZE)
-                .await
-                .with_context(|| format!("Failed to open JSONL source: {source_name}"))?;
-
-            process_jsonl_reader(
-                surreal,
-                &config,
-                reader,
-                &source_name,
-                &rules,
-                pipeline,
-                apply_opts,
-            )
-            .await?;
-            total_sources += 1;
-        }
-    }
-
-    // Legacy: Process each local JSONL file
-    for file_path in &config.files {
-        let source = FileSource::Local(file_path.clone());
-        let resolved = source.resolve().await?;
-
-        for r in resolved {
-            let source_name = r.display_name();
-            let reader = r
-                .open(DEFAULT_BUFFER_SIZE)
-                .await
-                .context("Failed to open JSONL file")?;
-            process_jsonl_reader(
-                surreal,
-                &config,
-                reader,
-                &source_name,
-                &rules,
-                pipeline,
-                apply_opts,
-            )
-            .await?;
-            total_sources += 1;
-        }
-    }
+    for resolved in resolve_sources(&config).await? {
+        let source_name = resolved.display_name();
+        let reader = resolved
+            .open_with(DEFAULT_BUFFER_SIZE, &config.source_options())
+            .await
+            .with_context(|| format!("Failed to open JSONL source: {source_name}"))?;
 
-    // Legacy: Process each S3 JSONL file
-    for s3_uri in &config.s3_uris {
-        let source = FileSource::parse(s3_uri)?;
-        let resolved = source.resolve().await?;
-
-        for r in resolved {
-            let source_name = r.display_name();
-            let reader = r
-                .open(DEFAULT_BUFFER_SIZE)
-                .await
-                .context("Failed to open S3 JSONL file")?;
-            process_jsonl_reader(
-                surreal,
-                &config,
-                reader,
-                &source_name,
-                &rules,
-                pipeline,
-                apply_opts,
-            )
-            .await?;
-            total_sources += 1;
-        }
-    }
-
-    // Legacy: Process each HTTP/HTTPS JSONL file
-    for http_uri in &config.http_uris {
-        let source = FileSource::parse(http_uri)?;
-        let resolved = source.resolve().await?;
-
-        for r in resolved {
-            let source_name = r.display_name();
-            let reader = r
-                .open(DEFAULT_BUFFER_SIZE)
-                .await
-                .context("Failed to open HTTP/HTTPS JSONL file")?;
-            process_jsonl_reader(
-                surreal,
-                &config,
-                reader,
-                &source_name,
-                &rules,
-                pipeline,
-                apply_opts,
-            )
-            .await?;
-            total_sources += 1;
-        }
+        let source_report = process_jsonl_reader(
+            surreal,
+            &config,
+            reader,
+            &source_name,
+            &rules,
+            pipeline,
+            apply_opts,
+        )
+        .await?;
+        report.skipped += source_report.skipped;
+        report.skipped_lines.extend(source_report.skipped_lines);
+        total_sources += 1;
     }
 
     tracing::info!(
         "JSONL migration completed: processed {} sources",
         total_sources
     );
-    Ok(())
+    if report.skipped > 0 {
+        tracing::warn!(
+            "Skipped {} lines that could not be imported",
+            report.skipped
+        );
+    }
+    Ok(report)
 }
 
 fn convert_json_to_universal_row(
@@ -461,7 +605,7 @@ fn convert_json_to_universal_row(
     };
 
     if let JsonValue::Object(obj) = value {
-        let mut fields: HashMap<String, Value> = HashMap::new();
+        let mut fields: FieldMap = FieldMap::new();
         let mut id_parts: Vec<Option<Value>> = vec![None; effective_id_cols.len()];
 
         for (key, val) in obj {
@@ -482,7 +626,8 @@ fn convert_json_to_universal_row(
                 id_parts[idx] = Some(part);
             } else {
                 let data_type = table_schema.and_then(|ts| ts.get_column_type(key));
-                let v = convert_value_to_universal(val, rules, data_type);
+                let v = convert_at_paths(val, &path_targets(rules, key))
+                    .unwrap_or_else(|| convert_value_to_universal(val, rules, data_type));
                 fields.insert(key.clone(), v);
             }
         }
@@ -502,28 +647,129 @@ fn convert_json_to_universal_row(
     }
 }
 
+/// The Thing reference `rule` makes of `value`, if it is an object of the
+/// rule's type carrying the rule's id field.
+fn reference_for(rule: &ConversionRule, value: &JsonValue) -> Option<Value> {
+    let obj = value.as_object()?;
+    if obj.get(&rule.type_field).and_then(|v| v.as_str()) != Some(rule.type_value.as_str()) {
+        return None;
+    }
+    let id_value = obj.get(&rule.id_field).and_then(|v| v.as_str())?;
+    Some(Value::Thing {
+        table: rule.target_table.clone(),
+        id: Box::new(Value::Text(id_value.to_string())),
+    })
+}
+
+/// Path rules rooted at the top-level field `key`, with the rest of their
+/// path still to walk.
+fn path_targets<'a>(
+    rules: &'a [ConversionRule],
+    key: &str,
+) -> Vec<(&'a [PathSegment], &'a ConversionRule)> {
+    rules
+        .iter()
+        .filter_map(|rule| match rule.path.first() {
+            Some(PathSegment::Field(field)) if field == key => Some((&rule.path[1..], rule)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Convert `value`, replacing the objects the path `targets` lead to with
+/// Thing references.
+///
+/// Objects and arrays along the way become [`Value::Object`] and
+/// [`Value::Array`]. Returns `None` when no reference was found, e.g. the
+/// path does not exist in this record, so the caller converts the value as
+/// usual.
+fn convert_at_paths(
+    value: &JsonValue,
+    targets: &[(&[PathSegment], &ConversionRule)],
+) -> Option<Value> {
+    if targets.is_empty() {
+        return None;
+    }
+    let reference = targets
+        .iter()
+        .filter(|(rest, _)| rest.is_empty())
+        .find_map(|(_, rule)| reference_for(rule, value));
+    if reference.is_some() {
+        return reference;
+    }
+
+    match value {
+        JsonValue::Object(obj) => {
+            let mut converted = false;
+            let fields: FieldMap = obj
+                .iter()
+                .map(|(key, item)| {
+                    let next: Vec<_> = targets
+                        .iter()
+                        .filter_map(|(rest, rule)| match rest.first() {
+                            Some(PathSegment::Field(field)) if field == key => {
+                                Some((&rest[1..], *rule))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    let v = match convert_at_paths(item, &next) {
+                        Some(v) => {
+                            converted = true;
+                            v
+                        }
+                        None => convert_value_inferred(item).value,
+                    };
+                    (key.clone(), v)
+                })
+                .collect();
+            converted.then_some(Value::Object(fields))
+        }
+        JsonValue::Array(items) => {
+            let next: Vec<_> = targets
+                .iter()
+                .filter(|(rest, _)| rest.first() == Some(&PathSegment::Each))
+                .map(|(rest, rule)| (&rest[1..], *rule))
+                .collect();
+            let mut converted = false;
+            let elements: Vec<Value> = items
+                .iter()
+                .map(|item| match convert_at_paths(item, &next) {
+                    Some(v) => {
+                        converted = true;
+                        v
+                    }
+                    None => convert_value_inferred(item).value,
+                })
+                .collect();
+            let element_type = if elements.iter().all(|v| matches!(v, Value::Thing { .. })) {
+                Type::Thing
+            } else {
+                Type::Json
+            };
+            converted.then_some(Value::Array {
+                elements,
+                element_type: Box::new(element_type),
+            })
+        }
+        _ => None,
+    }
+}
+
 /// Convert a JSON value to Value with optional schema type hint
 fn convert_value_to_universal(
     value: &JsonValue,
     rules: &[ConversionRule],
     data_type: Option<&Type>,
 ) -> Value {
-    // First check if this is an object that matches a conversion rule (Thing reference)
-    // Convert Thing references to Text format "table:id"
-    if let JsonValue::Object(obj) = value {
-        if let Some(type_value) = obj.get("type").and_then(|v| v.as_str()) {
-            for rule in rules {
-                if rule.type_value == type_value {
-                    // This object matches the rule, convert to Thing reference as text
-                    if let Some(id_value) = obj.get(&rule.id_field).and_then(|v| v.as_str()) {
-                        return Value::Thing {
-                            table: rule.target_table.clone(),
-                            id: Box::new(Value::Text(id_value.to_string())),
-                        };
-                    }
-                }
-            }
-        }
+    // First check if this is an object that matches a top-level conversion
+    // rule (Thing reference)
+    if let Some(reference) = rules
+        .iter()
+        .filter(|rule| rule.path.is_empty())
+        .find_map(|rule| reference_for(rule, value))
+    {
+        return reference;
     }
 
     // If we have a schema type hint, use types for type-aware conversion
@@ -580,3 +826,281 @@ fn convert_value_inferred(value: &JsonValue) -> TypedValue {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_table_name_ignores_compression_suffix() {
+        assert_eq!(
+            table_name_for_source("s3://bucket/events.jsonl.gz"),
+            "events"
+        );
+        assert_eq!(table_name_for_source("/data/users.jsonl.zst"), "users");
+        assert_eq!(table_name_for_source("/data/users.jsonl"), "users");
+    }
+
+    fn parse_rules(rules: &[&str]) -> Vec<ConversionRule> {
+        rules
+            .iter()
+            .map(|rule| ConversionRule::parse(rule).unwrap())
+            .collect()
+    }
+
+    fn convert(line: &str, rules: &[ConversionRule]) -> Row {
+        let json: JsonValue = serde_json::from_str(line).unwrap();
+        convert_json_to_universal_row(&json, "posts", "id", &[], rules, None, 0).unwrap()
+    }
+
+    fn user_ref(id: &str) -> Value {
+        Value::Thing {
+            table: "users".to_string(),
+            id: Box::new(Value::Text(id.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_nested_reference() {
+        let rules = parse_rules(&[r#"type="user",id users:id,path=author.ref"#]);
+        let row = convert(
+            r#"{"id": "p1", "author": {"role": "editor", "ref": {"type": "user", "id": "u1"}}}"#,
+            &rules,
+        );
+
+        let Some(Value::Object(author)) = row.get_field("author") else {
+            panic!("author should be an object: {:?}", row.get_field("author"));
+        };
+        assert_eq!(author.get("ref"), Some(&user_ref("u1")));
+        assert_eq!(author.get("role"), Some(&Value::Text("editor".to_string())));
+    }
+
+    #[test]
+    fn test_array_of_references() {
+        let rules = parse_rules(&[r#"type="user",id users:id,path=reviewers[].ref"#]);
+        let row = convert(
+            r#"{"id": "p1", "reviewers": [{"ref": {"type": "user", "id": "u1"}}, {"ref": {"type": "user", "id": "u2"}}]}"#,
+            &rules,
+        );
+
+        let Some(Value::Array { elements, .. }) = row.get_field("reviewers") else {
+            panic!(
+                "reviewers should be an array: {:?}",
+                row.get_field("reviewers")
+            );
+        };
+        let refs: Vec<_> = elements
+            .iter()
+            .map(|element| match element {
+                Value::Object(fields) => fields.get("ref").unwrap().clone(),
+                other => panic!("element should be an object: {other:?}"),
+            })
+            .collect();
+        assert_eq!(refs, vec![user_ref("u1"), user_ref("u2")]);
+    }
+
+    #[test]
+    fn test_array_of_direct_references() {
+        let rules = parse_rules(&[r#"type="user",id users:id,path=owners[]"#]);
+        let row = convert(
+            r#"{"id": "p1", "owners": [{"type": "user", "id": "u1"}]}"#,
+            &rules,
+        );
+
+        assert_eq!(
+            row.get_field("owners"),
+            Some(&Value::Array {
+                elements: vec![user_ref("u1")],
+                element_type: Box::new(Type::Thing),
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_path_is_skipped() {
+        let rules = parse_rules(&[r#"type="user",id users:id,path=author.ref"#]);
+        let row = convert(
+            r#"{"id": "p1", "author": {"name": "anonymous"}, "title": "hello"}"#,
+            &rules,
+        );
+
+        // Converted as if no rule applied
+        assert!(matches!(row.get_field("author"), Some(Value::Json(_))));
+        assert_eq!(
+            row.get_field("title"),
+            Some(&Value::Text("hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_path_rule_does_not_apply_at_top_level() {
+        let rules = parse_rules(&[r#"type="user",id users:id,path=author.ref"#]);
+        let row = convert(
+            r#"{"id": "p1", "owner": {"type": "user", "id": "u1"}}"#,
+            &rules,
+        );
+
+        assert!(matches!(row.get_field("owner"), Some(Value::Json(_))));
+    }
+
+    /// Sink that counts the rows it is given.
+    #[derive(Default)]
+    struct CountingSink {
+        rows: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl SurrealSink for CountingSink {
+        async fn write_rows(&self, rows: &[Row]) -> Result<()> {
+            self.rows
+                .fetch_add(rows.len(), std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn write_relations(&self, _relations: &[surreal_sync_core::Relation]) -> Result<()> {
+            Ok(())
+        }
+
+        async fn apply_change(&self, _change: &surreal_sync_core::Change) -> Result<()> {
+            self.rows.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn apply_relation_change(
+            &self,
+            _change: &surreal_sync_core::RelationChange,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn file_with_bad_line() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.jsonl");
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, r#"{{"id": "u1", "name": "Alice"}}"#).unwrap();
+        writeln!(file, r#"{{"id": "u2", "name": "#).unwrap();
+        writeln!(file, r#"{{"id": "u3", "name": "Carol"}}"#).unwrap();
+        file.flush().unwrap();
+        (dir, path)
+    }
+
+    #[tokio::test]
+    async fn test_bad_line_aborts_by_default() {
+        let (_dir, path) = file_with_bad_line();
+        let config = Config {
+            files: vec![path],
+            ..Default::default()
+        };
+
+        let err = sync(&CountingSink::default(), config).await.unwrap_err();
+        assert!(format!("{err:#}").contains("line 2"), "{err:#}");
+    }
+
+    #[tokio::test]
+    async fn test_skip_and_collect_bad_line() {
+        let (_dir, path) = file_with_bad_line();
+        let config = Config {
+            files: vec![path.clone()],
+            on_error: OnError::SkipAndCollect,
+            ..Default::default()
+        };
+
+        let sink = CountingSink::default();
+        let report = sync(&sink, config).await.unwrap();
+
+        assert_eq!(sink.rows.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(report.skipped, 1);
+        let skipped = &report.skipped_lines[0];
+        assert_eq!(skipped.source, path.display().to_string());
+        assert_eq!(skipped.line_number, 2);
+        assert_eq!(skipped.raw_line, r#"{"id": "u2", "name": "#);
+        assert!(skipped.error.contains("Error parsing JSON"));
+    }
+
+    #[tokio::test]
+    async fn test_skip_and_log_counts_only() {
+        let (_dir, path) = file_with_bad_line();
+        let config = Config {
+            files: vec![path],
+            on_error: OnError::SkipAndLog,
+            ..Default::default()
+        };
+
+        let report = sync(&CountingSink::default(), config).await.unwrap();
+        assert_eq!(report.skipped, 1);
+        assert!(report.skipped_lines.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_driver_polls_at_most_batch_size() {
+        const LINES: u64 = 25_000;
+        const BATCH_SIZE: usize = 128;
+        let data: String = (0..LINES)
+            .map(|i| format!("{{\"id\": \"u{i}\", \"n\": {i}}}\n"))
+            .collect();
+        let reader: Box<dyn std::io::Read + Send> =
+            Box::new(std::io::Cursor::new(data.into_bytes()));
+        let mut driver = JsonlStreamDriver {
+            lines: BufReader::new(reader).lines(),
+            table_name: "users".to_string(),
+            id_field: "id".to_string(),
+            id_columns: Vec::new(),
+            rules: Vec::new(),
+            table_schema: None,
+            poll_chunk: BATCH_SIZE,
+            line_count: 0,
+            sunk_count: 0,
+            finished: false,
+            source_name: "users.jsonl".to_string(),
+            on_error: OnError::Abort,
+            report: ImportReport::default(),
+        };
+
+        let mut total = 0u64;
+        let mut peak = 0usize;
+        while !driver.is_finished() {
+            let events = driver.poll_work().await.unwrap();
+            peak = peak.max(events.len());
+            total += events.len() as u64;
+        }
+        assert_eq!(total, LINES);
+        assert_eq!(peak, BATCH_SIZE);
+    }
+
+    #[tokio::test]
+    async fn test_infer_schema_per_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.jsonl");
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(
+            file,
+            r#"{{"id": "u1", "age": 30, "tags": ["a"], "bio": null}}"#
+        )
+        .unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, r#"{{"id": "u2", "age": 31.5, "tags": []}}"#).unwrap();
+        writeln!(file, r#"{{"id": "u3", "age": "unknown"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let config = Config {
+            files: vec![path],
+            ..Default::default()
+        };
+        let schema = infer_schema(&config, 2).await.unwrap();
+        assert_eq!(schema.table_names(), vec!["users"]);
+        let table = schema.get_table("users").unwrap();
+        assert_eq!(table.id.id_type, Type::Text);
+        assert_eq!(table.get_field_type("age"), Some(&Type::Float64));
+        assert_eq!(
+            table.get_field_type("tags"),
+            Some(&Type::Array {
+                element_type: Box::new(Type::Text)
+            })
+        );
+        let bio = table.get_field("bio").unwrap();
+        assert_eq!(bio.field_type, Type::Json);
+        assert!(bio.nullable);
+    }
+}
@@ -4,9 +4,10 @@
 
 use base64::Engine;
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use indexmap::IndexMap;
 use serde_json;
 use std::collections::HashMap;
-use surreal_sync_core::{Type, TypedValue, Value};
+use surreal_sync_core::{FieldMap, Type, TypedValue, Value};
 
 /// Parse an ISO 8601 duration string (PTxS or PTx.xxxxxxxxxS format).
 ///
@@ -74,6 +75,18 @@ fn parse_datetime_string(s: &str) -> Option<DateTime<Utc>> {
     None
 }
 
+/// Decode a base64 binary string, accepting MySQL's `base64:type<N>:` prefix.
+///
+/// MySQL's `JSON_OBJECT` encodes binary and `BIT` values as
+/// `base64:type<N>:<data>`, so audit-table rows carry this form.
+fn decode_base64_binary(s: &str) -> Option<Vec<u8>> {
+    let data = s
+        .strip_prefix("base64:type")
+        .and_then(|rest| rest.split_once(':'))
+        .map_or(s, |(_, data)| data);
+    base64::engine::general_purpose::STANDARD.decode(data).ok()
+}
+
 /// JSON value paired with schema information for type-aware conversion.
 #[derive(Debug, Clone)]
 pub struct JsonValueWithSchema {
@@ -111,6 +124,11 @@ impl From<JsonValueWithSchema> for TypedValue {
                     TypedValue::null(Type::Bool)
                 }
             }
+            // MySQL stores BIT(1) as `base64:type16:AQ==` in JSON_OBJECT
+            (Type::Bool, serde_json::Value::String(s)) => match decode_base64_binary(s) {
+                Some(bytes) => TypedValue::bool(bytes.iter().any(|b| *b != 0)),
+                None => TypedValue::null(Type::Bool),
+            },
 
             // Integer types
             (Type::Int8 { width }, serde_json::Value::Number(n)) => {
@@ -137,6 +155,9 @@ impl From<JsonValueWithSchema> for TypedValue {
             (Type::Int64, serde_json::Value::Number(n)) => {
                 if let Some(i) = n.as_i64() {
                     TypedValue::int64(i)
+                } else if let Some(u) = n.as_u64() {
+                    // Unsigned 64-bit values above i64::MAX stay exact as decimals
+                    TypedValue::decimal(u.to_string(), 20, 0)
                 } else {
                     TypedValue::null(Type::Int64)
                 }
@@ -176,18 +197,14 @@ impl From<JsonValueWithSchema> for TypedValue {
             (Type::Text, serde_json::Value::String(s)) => TypedValue::text(s),
 
             // Binary types - base64 encoded in JSON
-            (Type::Blob, serde_json::Value::String(s)) => {
-                match base64::engine::general_purpose::STANDARD.decode(s) {
-                    Ok(bytes) => TypedValue::blob(bytes),
-                    Err(_) => TypedValue::null(Type::Blob),
-                }
-            }
-            (Type::Bytes, serde_json::Value::String(s)) => {
-                match base64::engine::general_purpose::STANDARD.decode(s) {
-                    Ok(bytes) => TypedValue::bytes(bytes),
-                    Err(_) => TypedValue::null(Type::Bytes),
-                }
-            }
+            (Type::Blob, serde_json::Value::String(s)) => match decode_base64_binary(s) {
+                Some(bytes) => TypedValue::blob(bytes),
+                None => TypedValue::null(Type::Blob),
+            },
+            (Type::Bytes, serde_json::Value::String(s)) => match decode_base64_binary(s) {
+                Some(bytes) => TypedValue::bytes(bytes),
+                None => TypedValue::null(Type::Bytes),
+            },
 
             // UUID
             (Type::Uuid, serde_json::Value::String(s)) => {
@@ -271,6 +288,11 @@ impl From<JsonValueWithSchema> for TypedValue {
                 TypedValue::jsonb(value)
             }
 
+            // Object - nested document with typed fields
+            (Type::Object, serde_json::Value::Object(obj)) => {
+                TypedValue::object(json_object_to_field_map(obj))
+            }
+
             // Array types
             (Type::Array { element_type }, serde_json::Value::Array(arr)) => {
                 let values: Vec<Value> = arr
@@ -335,6 +357,22 @@ fn json_object_to_universal(obj: &serde_json::Map<String, serde_json::Value>) ->
     serde_json::Value::Object(obj.clone())
 }
 
+/// Convert a JSON object to a FieldMap, keeping nested objects as nested
+/// `Value::Object`s.
+fn json_object_to_field_map(obj: &serde_json::Map<String, serde_json::Value>) -> FieldMap {
+    obj.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::Object(nested) => {
+                    Value::Object(json_object_to_field_map(nested))
+                }
+                other => json_value_to_universal(other),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
 /// Convert a JSON array to a Value.
 #[allow(dead_code)]
 fn json_array_to_universal(arr: &[serde_json::Value]) -> serde_json::Value {
@@ -343,10 +381,8 @@ fn json_array_to_universal(arr: &[serde_json::Value]) -> serde_json::Value {
 
 /// Convert a JSON object to a HashMap of Value (for GeoJSON geometry).
 #[allow(dead_code)]
-fn json_object_to_geojson_hashmap(
-    obj: &serde_json::Map<String, serde_json::Value>,
-) -> HashMap<String, Value> {
-    let mut map = HashMap::new();
+fn json_object_to_geojson_hashmap(obj: &serde_json::Map<String, serde_json::Value>) -> FieldMap {
+    let mut map = FieldMap::new();
     for (key, value) in obj {
         map.insert(key.clone(), json_value_to_universal(value));
     }
@@ -569,7 +605,7 @@ pub fn json_to_generated_value_with_config(
             }
         }
         serde_json::Value::Object(obj) => {
-            let map: HashMap<String, Value> = obj
+            let map: FieldMap = obj
                 .into_iter()
                 .map(|(key, val)| {
                     // Build the nested path for this field
@@ -621,12 +657,13 @@ pub fn extract_field(
     }
 }
 
-/// Convert a complete JSON object to a map of TypedValues using schema.
+/// Convert a complete JSON object to a map of TypedValues using schema, in
+/// schema field order.
 pub fn json_object_to_typed_values(
     obj: &serde_json::Map<String, serde_json::Value>,
     schema: &[(String, Type)],
-) -> HashMap<String, TypedValue> {
-    let mut result = HashMap::new();
+) -> IndexMap<String, TypedValue> {
+    let mut result = IndexMap::new();
     for (field_name, sync_type) in schema {
         let tv = extract_field(obj, field_name, sync_type);
         result.insert(field_name.clone(), tv);
@@ -638,7 +675,7 @@ pub fn json_object_to_typed_values(
 pub fn parse_jsonl_line(
     line: &str,
     schema: &[(String, Type)],
-) -> Result<HashMap<String, TypedValue>, serde_json::Error> {
+) -> Result<IndexMap<String, TypedValue>, serde_json::Error> {
     let obj: serde_json::Map<String, serde_json::Value> = serde_json::from_str(line)?;
     Ok(json_object_to_typed_values(&obj, schema))
 }
@@ -736,6 +773,7 @@ mod tests {
     use super::*;
     use chrono::{Datelike, TimeZone, Timelike, Utc};
     use serde_json::json;
+    use surreal_sync_core::test_support::nested_object;
     use surreal_sync_core::GeometryType;
 
     #[test]
@@ -853,6 +891,29 @@ mod tests {
         assert!(matches!(tv.value, Value::Bytes(ref b) if *b == vec![0x01, 0x02, 0x03]));
     }
 
+    #[test]
+    fn test_mysql_bit_from_json_object() {
+        let jv = JsonValueWithSchema::new(json!("base64:type16:AQ=="), Type::Bool);
+        assert_eq!(TypedValue::from(jv).value, Value::Bool(true));
+        let jv = JsonValueWithSchema::new(json!("base64:type16:AA=="), Type::Bool);
+        assert_eq!(TypedValue::from(jv).value, Value::Bool(false));
+        let jv = JsonValueWithSchema::new(json!("base64:type16:/w=="), Type::Bytes);
+        assert_eq!(TypedValue::from(jv).value, Value::Bytes(vec![0xff]));
+    }
+
+    #[test]
+    fn test_int64_above_i64_max_becomes_decimal() {
+        let jv = JsonValueWithSchema::new(json!(u64::MAX), Type::Int64);
+        assert_eq!(
+            TypedValue::from(jv).value,
+            Value::Decimal {
+                value: u64::MAX.to_string(),
+                precision: 20,
+                scale: 0
+            }
+        );
+    }
+
     #[test]
     fn test_uuid_conversion() {
         let jv =
@@ -1275,4 +1336,21 @@ mod tests {
             other => panic!("Expected Array from generic conversion, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_nested_object_round_trip() {
+        let original = nested_object();
+
+        let json = crate::types::JsonValue::from(original.clone()).into_inner();
+        assert_eq!(
+            json,
+            json!({
+                "name": "Alice",
+                "address": { "city": "Paris", "geo": { "lat": 48.85, "lon": 2.35 } }
+            })
+        );
+        let back = TypedValue::from(JsonValueWithSchema::new(json, Type::Object));
+        assert_eq!(back.sync_type, Type::Object);
+        assert_eq!(back.value, original.value);
+    }
 }
@@ -3,7 +3,7 @@
 //! Works with both SurrealDB v2 and v3 servers.
 
 use std::path::PathBuf;
-use surreal_sync_json::from_jsonl::{sync, Config, ConversionRule};
+use surreal_sync_json::from_jsonl::{sync, Config, ConversionRule, OnError};
 use surreal_sync_surreal::version::testing::SurrealDbContainer;
 use surreal_sync_surreal::version::SurrealMajorVersion;
 
@@ -136,12 +136,15 @@ async fn test_jsonl_migration_e2e() -> Result<(), Box<dyn std::error::Error>> {
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         id_field: "id".to_string(),
         id_columns: Vec::new(),
         conversion_rules,
         batch_size: 1000,
         dry_run: false,
         schema: None,
+        on_error: OnError::Abort,
     };
 
     match db.detected_version {
@@ -338,12 +341,15 @@ async fn test_jsonl_with_custom_id_field() -> Result<(), Box<dyn std::error::Err
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         id_field: "item_id".to_string(),
         id_columns: Vec::new(),
         conversion_rules: vec![],
         batch_size: 1000,
         dry_run: false,
         schema: None,
+        on_error: OnError::Abort,
     };
 
     match db.detected_version {
@@ -433,12 +439,15 @@ async fn test_jsonl_with_complex_id_field() {
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         id_field: "timestamp".to_string(),
         id_columns: Vec::new(),
         conversion_rules: vec![],
         batch_size: 1000,
         dry_run: false,
         schema: None,
+        on_error: OnError::Abort,
     };
 
     match db.detected_version {
@@ -558,12 +567,15 @@ async fn test_jsonl_composite_pk_array_record_ids() {
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         id_field: "id".to_string(),
         id_columns: vec!["a".to_string(), "b".to_string()],
         conversion_rules: vec![],
         batch_size: 1000,
         dry_run: false,
         schema: None,
+        on_error: OnError::Abort,
     };
 
     match db.detected_version {
@@ -79,7 +79,7 @@ async fn run_main() -> anyhow::Result<()> {
                     println!("[Topic {topic} Partition {partition}] Message Key: {key:?}",);
 
                     match &message.payload {
-                        Payload::Protobuf(proto_msg) => {
+                        Payload::Protobuf(proto_msg) | Payload::Avro(proto_msg) => {
                             // Extract fields from the decoded message
                             for (field_name, field_value) in &proto_msg.fields {
                                 match field_value {
@@ -128,6 +128,11 @@ async fn run_main() -> anyhow::Result<()> {
                                 }
                             }
                         }
+                        Payload::Json(object) => {
+                            for (field_name, field_value) in object {
+                                println!("Field: {field_name}, JSON Value: {field_value}");
+                            }
+                        }
                     }
 
                     // Increment counter
@@ -0,0 +1,401 @@
+//! Binary Avro decoder.
+//!
+//! Decodes Avro data with its writer schema into the ProtoMessage /
+//! ProtoFieldValue values the protobuf decoder produces, so Avro messages
+//! convert to records through the same code:
+//!
+//! - records and maps become nested messages
+//! - `date` and `timestamp-*` values become `google.protobuf.Timestamp`
+//!   messages
+//! - enums become their symbol and decimals their decimal string
+
+use super::schema::{AvroField, AvroSchema, TimeUnit};
+use crate::from_kafka::error::{Error, Result};
+use crate::types::{
+    ProtoFieldDescriptor, ProtoFieldValue, ProtoMessage, ProtoMessageDescriptor, ProtoType,
+};
+use std::collections::HashMap;
+
+const TIMESTAMP_TYPE: &str = "google.protobuf.Timestamp";
+
+/// Decode an Avro datum whose writer schema is the record `schema`.
+pub fn decode_record(schema: &AvroSchema, data: &[u8]) -> Result<ProtoMessage> {
+    let AvroSchema::Record { name, fields } = schema else {
+        return Err(Error::AvroDecode(format!(
+            "Top-level schema must be a record, got {schema:?}"
+        )));
+    };
+    let mut reader = Reader { data, pos: 0 };
+    let message = reader.record(name, fields)?;
+    if reader.pos != data.len() {
+        return Err(Error::AvroDecode(format!(
+            "{} trailing bytes after record '{name}'",
+            data.len() - reader.pos
+        )));
+    }
+    Ok(message)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let data = self.data;
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| Error::AvroDecode("Unexpected end of data".to_string()))?;
+        let bytes = &data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    /// Zig-zag encoded variable-length `long` (also used for `int`).
+    fn long(&mut self) -> Result<i64> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err(Error::AvroDecode(
+            "Variable-length long is too long".to_string(),
+        ))
+    }
+
+    fn int(&mut self) -> Result<i32> {
+        let value = self.long()?;
+        i32::try_from(value).map_err(|_| Error::AvroDecode(format!("int out of range: {value}")))
+    }
+
+    /// A length, count or index, which must not be negative.
+    fn len(&mut self) -> Result<usize> {
+        let value = self.long()?;
+        usize::try_from(value).map_err(|_| Error::AvroDecode(format!("Invalid length: {value}")))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let bytes = self.bytes()?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|e| Error::AvroDecode(format!("Invalid UTF-8 string: {e}")))
+    }
+
+    /// Read the blocks of an array or map, calling `item` once per item.
+    fn blocks(&mut self, mut item: impl FnMut(&mut Self) -> Result<()>) -> Result<()> {
+        loop {
+            let count = self.long()?;
+            if count == 0 {
+                return Ok(());
+            }
+            if count < 0 {
+                // A negative count is followed by the block's size in bytes
+                self.long()?;
+            }
+            for _ in 0..count.unsigned_abs() {
+                item(self)?;
+            }
+        }
+    }
+
+    fn record(&mut self, name: &str, fields: &[AvroField]) -> Result<ProtoMessage> {
+        let mut values = HashMap::with_capacity(fields.len());
+        for field in fields {
+            values.insert(field.name.clone(), self.value(&field.schema)?);
+        }
+        let descriptors = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| field_descriptor(&field.name, i, &field.schema));
+        Ok(message(name, values, descriptors))
+    }
+
+    fn value(&mut self, schema: &AvroSchema) -> Result<ProtoFieldValue> {
+        Ok(match schema {
+            AvroSchema::Null => ProtoFieldValue::Null,
+            AvroSchema::Boolean => ProtoFieldValue::Bool(self.take(1)?[0] != 0),
+            AvroSchema::Int => ProtoFieldValue::Int32(self.int()?),
+            AvroSchema::Long => ProtoFieldValue::Int64(self.long()?),
+            AvroSchema::Float => ProtoFieldValue::Float(f32::from_le_bytes(self.array()?)),
+            AvroSchema::Double => ProtoFieldValue::Double(f64::from_le_bytes(self.array()?)),
+            AvroSchema::Bytes => ProtoFieldValue::Bytes(self.bytes()?.to_vec()),
+            AvroSchema::String => ProtoFieldValue::String(self.string()?),
+            AvroSchema::Record { name, fields } => {
+                ProtoFieldValue::Message(Box::new(self.record(name, fields)?))
+            }
+            AvroSchema::Enum { name, symbols } => {
+                let index = self.len()?;
+                let symbol = symbols.get(index).ok_or_else(|| {
+                    Error::AvroDecode(format!("Enum '{name}' has no symbol {index}"))
+                })?;
+                ProtoFieldValue::String(symbol.clone())
+            }
+            AvroSchema::Array(items) => {
+                let mut values = Vec::new();
+                self.blocks(|reader| {
+                    values.push(reader.value(items)?);
+                    Ok(())
+                })?;
+                ProtoFieldValue::Repeated(values)
+            }
+            AvroSchema::Map(value_schema) => {
+                let mut values = HashMap::new();
+                let mut descriptors = Vec::new();
+                self.blocks(|reader| {
+                    let key = reader.string()?;
+                    let value = reader.value(value_schema)?;
+                    descriptors.push(field_descriptor(&key, descriptors.len(), value_schema));
+                    values.insert(key, value);
+                    Ok(())
+                })?;
+                ProtoFieldValue::Message(Box::new(message("map", values, descriptors)))
+            }
+            AvroSchema::Union(branches) => {
+                let index = self.len()?;
+                let branch = branches
+                    .get(index)
+                    .ok_or_else(|| Error::AvroDecode(format!("Union has no branch {index}")))?;
+                self.value(branch)?
+            }
+            AvroSchema::Fixed { size, .. } => ProtoFieldValue::Bytes(self.take(*size)?.to_vec()),
+            AvroSchema::Date => timestamp(i64::from(self.int()?) * 86_400, 0),
+            AvroSchema::Timestamp(unit) => {
+                let (per_second, nanos_per_unit) = match unit {
+                    TimeUnit::Millis => (1_000, 1_000_000),
+                    TimeUnit::Micros => (1_000_000, 1_000),
+                    TimeUnit::Nanos => (1_000_000_000, 1),
+                };
+                let value = self.long()?;
+                timestamp(
+                    value.div_euclid(per_second),
+                    (value.rem_euclid(per_second) * nanos_per_unit) as i32,
+                )
+            }
+            AvroSchema::Decimal { scale, fixed_size } => {
+                let bytes = match fixed_size {
+                    Some(size) => self.take(*size)?,
+                    None => self.bytes()?,
+                };
+                ProtoFieldValue::String(decimal_string(bytes, *scale)?)
+            }
+        })
+    }
+}
+
+fn message(
+    name: &str,
+    fields: HashMap<String, ProtoFieldValue>,
+    descriptors: impl IntoIterator<Item = ProtoFieldDescriptor>,
+) -> ProtoMessage {
+    let mut descriptor = ProtoMessageDescriptor {
+        name: name.to_string(),
+        fields: HashMap::new(),
+        field_order: Vec::new(),
+    };
+    for field in descriptors {
+        descriptor.field_order.push(field.name.clone());
+        descriptor.fields.insert(field.name.clone(), field);
+    }
+    ProtoMessage {
+        message_type: name.to_string(),
+        fields,
+        descriptor,
+    }
+}
+
+fn field_descriptor(name: &str, index: usize, schema: &AvroSchema) -> ProtoFieldDescriptor {
+    ProtoFieldDescriptor {
+        name: name.to_string(),
+        number: index as i32 + 1,
+        field_type: proto_type(schema),
+        is_repeated: matches!(schema, AvroSchema::Array(_)),
+        is_optional: matches!(schema, AvroSchema::Union(branches) if branches.contains(&AvroSchema::Null)),
+    }
+}
+
+/// The protobuf type of the values `schema` decodes to.
+fn proto_type(schema: &AvroSchema) -> ProtoType {
+    match schema {
+        AvroSchema::Null => ProtoType::Null,
+        AvroSchema::Boolean => ProtoType::Bool,
+        AvroSchema::Int => ProtoType::Int32,
+        AvroSchema::Long => ProtoType::Int64,
+        AvroSchema::Float => ProtoType::Float,
+        AvroSchema::Double => ProtoType::Double,
+        AvroSchema::Bytes | AvroSchema::Fixed { .. } => ProtoType::Bytes,
+        AvroSchema::String | AvroSchema::Decimal { .. } => ProtoType::String,
+        AvroSchema::Enum { name, .. } => ProtoType::Enum(name.clone()),
+        AvroSchema::Record { name, .. } => ProtoType::Message(name.clone()),
+        AvroSchema::Map(_) => ProtoType::Message("map".to_string()),
+        AvroSchema::Date | AvroSchema::Timestamp(_) => {
+            ProtoType::Message(TIMESTAMP_TYPE.to_string())
+        }
+        AvroSchema::Array(items) => ProtoType::Repeated(Box::new(proto_type(items))),
+        AvroSchema::Union(branches) => {
+            let mut non_null = branches.iter().filter(|b| **b != AvroSchema::Null);
+            match (non_null.next(), non_null.next()) {
+                (Some(only), None) => ProtoType::Optional(Box::new(proto_type(only))),
+                _ => ProtoType::Null,
+            }
+        }
+    }
+}
+
+fn timestamp(seconds: i64, nanos: i32) -> ProtoFieldValue {
+    let fields = HashMap::from([
+        ("seconds".to_string(), ProtoFieldValue::Int64(seconds)),
+        ("nanos".to_string(), ProtoFieldValue::Int32(nanos)),
+    ]);
+    let descriptors = [
+        field_descriptor("seconds", 0, &AvroSchema::Long),
+        field_descriptor("nanos", 1, &AvroSchema::Int),
+    ];
+    ProtoFieldValue::Message(Box::new(message(TIMESTAMP_TYPE, fields, descriptors)))
+}
+
+/// Render a big-endian two's-complement unscaled value with `scale`
+/// fractional digits.
+fn decimal_string(bytes: &[u8], scale: u32) -> Result<String> {
+    if bytes.len() > 16 {
+        return Err(Error::AvroDecode(format!(
+            "Decimal of {} bytes exceeds 128 bits",
+            bytes.len()
+        )));
+    }
+    let negative = bytes.first().is_some_and(|b| b & 0x80 != 0);
+    let unscaled = bytes
+        .iter()
+        .fold(if negative { -1i128 } else { 0 }, |acc, b| {
+            (acc << 8) | i128::from(*b)
+        });
+    let sign = if unscaled < 0 { "-" } else { "" };
+    let digits = unscaled.unsigned_abs().to_string();
+    let scale = scale as usize;
+    if scale == 0 {
+        return Ok(format!("{sign}{digits}"));
+    }
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    Ok(format!("{sign}{int_part}.{frac_part}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zigzag(value: i64) -> Vec<u8> {
+        let mut n = ((value << 1) ^ (value >> 63)) as u64;
+        let mut out = Vec::new();
+        loop {
+            if n < 0x80 {
+                out.push(n as u8);
+                return out;
+            }
+            out.push((n as u8 & 0x7f) | 0x80);
+            n >>= 7;
+        }
+    }
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut out = zigzag(s.len() as i64);
+        out.extend_from_slice(s.as_bytes());
+        out
+    }
+
+    fn user_schema() -> AvroSchema {
+        AvroSchema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "User",
+                "fields": [
+                    {"name": "id", "type": "string"},
+                    {"name": "age", "type": "int"},
+                    {"name": "nickname", "type": ["null", "string"]},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}},
+                    {"name": "created_at", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+                    {"name": "balance", "type": {"type": "bytes", "logicalType": "decimal", "precision": 8, "scale": 2}}
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decode_record() {
+        let mut data = string("user_001");
+        data.extend(zigzag(-30));
+        data.extend(zigzag(1)); // union branch 1: string
+        data.extend(string("al"));
+        data.extend(zigzag(2)); // block of two tags
+        data.extend(string("a"));
+        data.extend(string("b"));
+        data.extend(zigzag(0)); // end of array
+        data.extend(zigzag(1_700_000_000_123));
+        data.extend(zigzag(2)); // two bytes of decimal
+        data.extend([0xfe, 0x0c]); // -500 => -5.00
+
+        let message = decode_record(&user_schema(), &data).unwrap();
+        assert_eq!(message.message_type, "User");
+        assert_eq!(
+            message.descriptor.field_order,
+            ["id", "age", "nickname", "tags", "created_at", "balance"]
+        );
+        assert!(matches!(&message.fields["id"], ProtoFieldValue::String(s) if s == "user_001"));
+        assert!(matches!(message.fields["age"], ProtoFieldValue::Int32(-30)));
+        assert!(matches!(&message.fields["nickname"], ProtoFieldValue::String(s) if s == "al"));
+        assert!(
+            matches!(&message.fields["tags"], ProtoFieldValue::Repeated(tags) if tags.len() == 2)
+        );
+        let ProtoFieldValue::Message(created_at) = &message.fields["created_at"] else {
+            panic!("expected a timestamp message");
+        };
+        assert_eq!(created_at.message_type, TIMESTAMP_TYPE);
+        assert!(matches!(
+            created_at.fields["seconds"],
+            ProtoFieldValue::Int64(1_700_000_000)
+        ));
+        assert!(matches!(
+            created_at.fields["nanos"],
+            ProtoFieldValue::Int32(123_000_000)
+        ));
+        assert!(matches!(&message.fields["balance"], ProtoFieldValue::String(s) if s == "-5.00"));
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_and_trailing_data() {
+        let schema = AvroSchema::parse_str(
+            r#"{"type": "record", "name": "R", "fields": [{"name": "s", "type": "string"}]}"#,
+        )
+        .unwrap();
+        let data = string("hello");
+        assert!(decode_record(&schema, &data[..3]).is_err());
+        let mut trailing = data.clone();
+        trailing.push(0);
+        assert!(decode_record(&schema, &trailing).is_err());
+        assert!(decode_record(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_decimal_string() {
+        assert_eq!(decimal_string(&[0x01, 0xf4], 2).unwrap(), "5.00");
+        assert_eq!(decimal_string(&[0x07], 3).unwrap(), "0.007");
+        assert_eq!(decimal_string(&[0xff], 0).unwrap(), "-1");
+        assert_eq!(decimal_string(&[], 1).unwrap(), "0.0");
+    }
+}
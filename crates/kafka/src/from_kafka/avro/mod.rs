@@ -0,0 +1,50 @@
+//! Avro payloads in the Confluent Schema Registry wire format.
+//!
+//! A framed payload is a zero magic byte, the registry id of its writer
+//! schema (4 bytes, big-endian) and the Avro binary data. Payloads decode
+//! with their writer schema, so messages written before and after a schema
+//! change each decode with the fields they were written with.
+//!
+//! Schema parsing and decoding are always available; the registry client
+//! needs the `schema_registry` feature.
+
+pub mod decoder;
+#[cfg(feature = "schema_registry")]
+pub mod registry;
+pub mod schema;
+
+use crate::from_kafka::error::{Error, Result};
+
+pub use decoder::decode_record;
+#[cfg(feature = "schema_registry")]
+pub use registry::SchemaRegistry;
+pub use schema::{AvroField, AvroSchema, TimeUnit};
+
+/// Magic byte opening a Schema Registry framed payload
+const MAGIC_BYTE: u8 = 0;
+
+/// Split a Schema Registry framed payload into its schema id and Avro data.
+pub fn split_wire_format(payload: &[u8]) -> Result<(u32, &[u8])> {
+    let [MAGIC_BYTE, a, b, c, d, data @ ..] = payload else {
+        return Err(Error::AvroDecode(
+            "Payload is not in the Schema Registry wire format (magic byte + schema id)"
+                .to_string(),
+        ));
+    };
+    Ok((u32::from_be_bytes([*a, *b, *c, *d]), data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_wire_format() {
+        assert_eq!(
+            split_wire_format(&[0, 0, 0, 1, 2, 42, 43]).unwrap(),
+            (258, &[42, 43][..])
+        );
+        assert!(split_wire_format(&[1, 0, 0, 0, 1, 42]).is_err());
+        assert!(split_wire_format(&[0, 0, 0]).is_err());
+    }
+}
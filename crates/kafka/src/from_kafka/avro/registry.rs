@@ -0,0 +1,134 @@
+//! Confluent Schema Registry client with a schema cache.
+
+use super::{decode_record, split_wire_format, AvroSchema};
+use crate::from_kafka::error::{Error, Result};
+use crate::types::ProtoMessage;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, RwLock};
+
+/// Fetches Avro writer schemas by id and caches them for the process.
+///
+/// Registry schema ids are immutable, so a cached schema never goes stale.
+pub struct SchemaRegistry {
+    url: String,
+    http: reqwest::Client,
+    schemas: RwLock<HashMap<u32, Arc<AvroSchema>>>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaResponse {
+    schema: String,
+    /// Absent for Avro schemas
+    schema_type: Option<String>,
+}
+
+impl SchemaRegistry {
+    /// Create a client for the registry at `url` (e.g. `http://localhost:8081`)
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            http: reqwest::Client::new(),
+            schemas: RwLock::default(),
+        }
+    }
+
+    /// Fetch and cache the writer schemas of `payloads` that are not cached
+    /// yet.
+    ///
+    /// Runs before a batch is decoded, so decoding never waits on the
+    /// registry.
+    pub async fn fetch_schemas<'a>(
+        &self,
+        payloads: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Result<()> {
+        let mut missing = BTreeSet::new();
+        {
+            let schemas = self.schemas.read().expect("schema cache lock poisoned");
+            for payload in payloads {
+                let (id, _) = split_wire_format(payload)?;
+                if !schemas.contains_key(&id) {
+                    missing.insert(id);
+                }
+            }
+        }
+        for id in missing {
+            let schema = self.fetch(id).await?;
+            self.schemas
+                .write()
+                .expect("schema cache lock poisoned")
+                .insert(id, Arc::new(schema));
+        }
+        Ok(())
+    }
+
+    async fn fetch(&self, id: u32) -> Result<AvroSchema> {
+        let url = format!("{}/schemas/ids/{id}", self.url);
+        let response: SchemaResponse = self
+            .http
+            .get(&url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| Error::SchemaRegistry(format!("Failed to fetch schema {id}: {e}")))?
+            .json()
+            .await
+            .map_err(|e| Error::SchemaRegistry(format!("Invalid response for schema {id}: {e}")))?;
+        if let Some(schema_type) = response.schema_type.filter(|t| t != "AVRO") {
+            return Err(Error::SchemaRegistry(format!(
+                "Schema {id} is a {schema_type} schema, not Avro"
+            )));
+        }
+        tracing::debug!("Fetched Avro schema {id} from {}", self.url);
+        AvroSchema::parse_str(&response.schema)
+    }
+
+    /// Decode a framed payload with its writer schema, which
+    /// [`fetch_schemas`](Self::fetch_schemas) must have cached.
+    pub fn decode(&self, payload: &[u8]) -> Result<ProtoMessage> {
+        let (id, data) = split_wire_format(payload)?;
+        let schema = self
+            .schemas
+            .read()
+            .expect("schema cache lock poisoned")
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| Error::SchemaRegistry(format!("Schema {id} has not been fetched")))?;
+        decode_record(&schema, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ProtoFieldValue;
+
+    #[test]
+    fn test_decode_uses_each_payloads_writer_schema() {
+        let registry = SchemaRegistry::new("http://localhost:8081/");
+        assert_eq!(registry.url, "http://localhost:8081");
+        let v1 =
+            r#"{"type": "record", "name": "User", "fields": [{"name": "id", "type": "long"}]}"#;
+        let v2 = r#"{"type": "record", "name": "User", "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "email", "type": ["null", "string"], "default": null}
+        ]}"#;
+        {
+            let mut schemas = registry.schemas.write().unwrap();
+            schemas.insert(1, Arc::new(AvroSchema::parse_str(v1).unwrap()));
+            schemas.insert(2, Arc::new(AvroSchema::parse_str(v2).unwrap()));
+        }
+
+        // id 7, written with schema 1
+        let old = registry.decode(&[0, 0, 0, 0, 1, 14]).unwrap();
+        assert_eq!(old.descriptor.field_order, ["id"]);
+        assert!(matches!(old.fields["id"], ProtoFieldValue::Int64(7)));
+
+        // id 7, email "a", written with schema 2
+        let new = registry.decode(&[0, 0, 0, 0, 2, 14, 2, 2, b'a']).unwrap();
+        assert_eq!(new.descriptor.field_order, ["id", "email"]);
+        assert!(matches!(&new.fields["email"], ProtoFieldValue::String(s) if s == "a"));
+
+        assert!(registry.decode(&[0, 0, 0, 0, 3, 14]).is_err());
+    }
+}
@@ -0,0 +1,322 @@
+//! Avro schema parsing.
+//!
+//! Parses the JSON form of an Avro schema, as stored by a schema registry,
+//! into an [`AvroSchema`] the decoder walks.
+
+use crate::from_kafka::error::{Error, Result};
+use serde_json::{Map, Value as Json};
+use std::collections::HashMap;
+
+/// A parsed Avro schema.
+///
+/// Named types are inlined where they are referenced, so recursive types are
+/// not supported. Logical types with a SurrealDB counterpart get their own
+/// variants; other logical types decode as their underlying type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record {
+        /// Full name (`namespace.name`)
+        name: String,
+        fields: Vec<AvroField>,
+    },
+    Enum {
+        name: String,
+        symbols: Vec<String>,
+    },
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Fixed {
+        name: String,
+        size: usize,
+    },
+    /// `int` days since the Unix epoch (`date`)
+    Date,
+    /// `long` time since the Unix epoch (`timestamp-*`, `local-timestamp-*`)
+    Timestamp(TimeUnit),
+    /// Two's-complement unscaled value in `bytes`, or in a `fixed` of
+    /// `fixed_size` bytes (`decimal`)
+    Decimal {
+        scale: u32,
+        fixed_size: Option<usize>,
+    },
+}
+
+/// A field of an Avro record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvroField {
+    pub name: String,
+    pub schema: AvroSchema,
+}
+
+/// Unit of an Avro timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl AvroSchema {
+    /// Parse the JSON text of an Avro schema.
+    pub fn parse_str(schema: &str) -> Result<Self> {
+        let json: Json = serde_json::from_str(schema)
+            .map_err(|e| Error::AvroSchema(format!("Schema is not JSON: {e}")))?;
+        Parser::default().parse(&json, None)
+    }
+}
+
+/// Named types defined so far, by full and by short name.
+#[derive(Default)]
+struct Parser {
+    named: HashMap<String, AvroSchema>,
+}
+
+impl Parser {
+    fn parse(&mut self, json: &Json, namespace: Option<&str>) -> Result<AvroSchema> {
+        match json {
+            Json::String(name) => self.parse_name(name, namespace),
+            Json::Array(branches) => Ok(AvroSchema::Union(
+                branches
+                    .iter()
+                    .map(|branch| self.parse(branch, namespace))
+                    .collect::<Result<_>>()?,
+            )),
+            Json::Object(object) => self.parse_object(object, namespace),
+            other => Err(Error::AvroSchema(format!("Invalid schema: {other}"))),
+        }
+    }
+
+    fn parse_name(&self, name: &str, namespace: Option<&str>) -> Result<AvroSchema> {
+        Ok(match name {
+            "null" => AvroSchema::Null,
+            "boolean" => AvroSchema::Boolean,
+            "int" => AvroSchema::Int,
+            "long" => AvroSchema::Long,
+            "float" => AvroSchema::Float,
+            "double" => AvroSchema::Double,
+            "bytes" => AvroSchema::Bytes,
+            "string" => AvroSchema::String,
+            _ => self
+                .named
+                .get(&full_name(name, namespace))
+                .or_else(|| self.named.get(name))
+                .cloned()
+                .ok_or_else(|| Error::AvroSchema(format!("Unknown type '{name}'")))?,
+        })
+    }
+
+    fn parse_object(
+        &mut self,
+        object: &Map<String, Json>,
+        namespace: Option<&str>,
+    ) -> Result<AvroSchema> {
+        let type_json = object
+            .get("type")
+            .ok_or_else(|| Error::AvroSchema("Schema object without \"type\"".to_string()))?;
+        let Json::String(type_name) = type_json else {
+            // `{"type": {...}}` and `{"type": [...]}` wrap another schema
+            return self.parse(type_json, namespace);
+        };
+        let logical_type = object.get("logicalType").and_then(Json::as_str);
+
+        match type_name.as_str() {
+            "record" | "error" => {
+                let name = full_name(str_attr(object, "name")?, namespace_attr(object, namespace));
+                let inner_namespace = name.rsplit_once('.').map(|(ns, _)| ns.to_string());
+                let fields_json = object
+                    .get("fields")
+                    .and_then(Json::as_array)
+                    .ok_or_else(|| Error::AvroSchema(format!("Record '{name}' has no fields")))?;
+                let mut fields = Vec::with_capacity(fields_json.len());
+                for field in fields_json {
+                    let field = field.as_object().ok_or_else(|| {
+                        Error::AvroSchema(format!("Invalid field in record '{name}'"))
+                    })?;
+                    let field_type = field.get("type").ok_or_else(|| {
+                        Error::AvroSchema(format!("Field without type in record '{name}'"))
+                    })?;
+                    fields.push(AvroField {
+                        name: str_attr(field, "name")?.to_string(),
+                        schema: self.parse(field_type, inner_namespace.as_deref())?,
+                    });
+                }
+                Ok(self.define(name.clone(), AvroSchema::Record { name, fields }))
+            }
+            "enum" => {
+                let name = full_name(str_attr(object, "name")?, namespace_attr(object, namespace));
+                let symbols = object
+                    .get("symbols")
+                    .and_then(Json::as_array)
+                    .ok_or_else(|| Error::AvroSchema(format!("Enum '{name}' has no symbols")))?
+                    .iter()
+                    .map(|symbol| {
+                        symbol.as_str().map(str::to_string).ok_or_else(|| {
+                            Error::AvroSchema(format!("Invalid symbol in enum '{name}'"))
+                        })
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(self.define(name.clone(), AvroSchema::Enum { name, symbols }))
+            }
+            "fixed" => {
+                let name = full_name(str_attr(object, "name")?, namespace_attr(object, namespace));
+                let size = object
+                    .get("size")
+                    .and_then(Json::as_u64)
+                    .ok_or_else(|| Error::AvroSchema(format!("Fixed '{name}' has no size")))?
+                    as usize;
+                let schema = match logical_type {
+                    Some("decimal") => AvroSchema::Decimal {
+                        scale: decimal_scale(object),
+                        fixed_size: Some(size),
+                    },
+                    _ => AvroSchema::Fixed {
+                        name: name.clone(),
+                        size,
+                    },
+                };
+                Ok(self.define(name, schema))
+            }
+            "array" => {
+                let items = object
+                    .get("items")
+                    .ok_or_else(|| Error::AvroSchema("Array without items".to_string()))?;
+                Ok(AvroSchema::Array(Box::new(self.parse(items, namespace)?)))
+            }
+            "map" => {
+                let values = object
+                    .get("values")
+                    .ok_or_else(|| Error::AvroSchema("Map without values".to_string()))?;
+                Ok(AvroSchema::Map(Box::new(self.parse(values, namespace)?)))
+            }
+            primitive => Ok(match (primitive, logical_type) {
+                ("int", Some("date")) => AvroSchema::Date,
+                ("long", Some("timestamp-millis" | "local-timestamp-millis")) => {
+                    AvroSchema::Timestamp(TimeUnit::Millis)
+                }
+                ("long", Some("timestamp-micros" | "local-timestamp-micros")) => {
+                    AvroSchema::Timestamp(TimeUnit::Micros)
+                }
+                ("long", Some("timestamp-nanos" | "local-timestamp-nanos")) => {
+                    AvroSchema::Timestamp(TimeUnit::Nanos)
+                }
+                ("bytes", Some("decimal")) => AvroSchema::Decimal {
+                    scale: decimal_scale(object),
+                    fixed_size: None,
+                },
+                _ => self.parse_name(primitive, namespace)?,
+            }),
+        }
+    }
+
+    /// Register a named type under its full and short names.
+    fn define(&mut self, full_name: String, schema: AvroSchema) -> AvroSchema {
+        if let Some((_, short)) = full_name.rsplit_once('.') {
+            self.named.insert(short.to_string(), schema.clone());
+        }
+        self.named.insert(full_name, schema.clone());
+        schema
+    }
+}
+
+fn str_attr<'a>(object: &'a Map<String, Json>, key: &str) -> Result<&'a str> {
+    object
+        .get(key)
+        .and_then(Json::as_str)
+        .ok_or_else(|| Error::AvroSchema(format!("Missing \"{key}\" in {object:?}")))
+}
+
+fn namespace_attr<'a>(
+    object: &'a Map<String, Json>,
+    enclosing: Option<&'a str>,
+) -> Option<&'a str> {
+    object
+        .get("namespace")
+        .and_then(Json::as_str)
+        .filter(|ns| !ns.is_empty())
+        .or(enclosing)
+}
+
+fn decimal_scale(object: &Map<String, Json>) -> u32 {
+    object.get("scale").and_then(Json::as_u64).unwrap_or(0) as u32
+}
+
+/// `name` qualified with `namespace`, unless it already is.
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') => format!("{namespace}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_record_with_named_types_and_logical_types() {
+        let schema = AvroSchema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "User",
+                "namespace": "com.example",
+                "fields": [
+                    {"name": "id", "type": "string"},
+                    {"name": "created_at", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+                    {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["ACTIVE", "GONE"]}},
+                    {"name": "previous_status", "type": ["null", "Status"]},
+                    {"name": "balance", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let status = AvroSchema::Enum {
+            name: "com.example.Status".to_string(),
+            symbols: vec!["ACTIVE".to_string(), "GONE".to_string()],
+        };
+        let field = |name: &str, schema| AvroField {
+            name: name.to_string(),
+            schema,
+        };
+        assert_eq!(
+            schema,
+            AvroSchema::Record {
+                name: "com.example.User".to_string(),
+                fields: vec![
+                    field("id", AvroSchema::String),
+                    field("created_at", AvroSchema::Timestamp(TimeUnit::Millis)),
+                    field("status", status.clone()),
+                    field(
+                        "previous_status",
+                        AvroSchema::Union(vec![AvroSchema::Null, status])
+                    ),
+                    field(
+                        "balance",
+                        AvroSchema::Decimal {
+                            scale: 2,
+                            fixed_size: None
+                        }
+                    ),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_type_reference() {
+        let err = AvroSchema::parse_str(
+            r#"{"type": "record", "name": "R", "fields": [{"name": "a", "type": "Missing"}]}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Unknown type 'Missing'"), "{err}");
+    }
+}
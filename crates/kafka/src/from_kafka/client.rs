@@ -1,12 +1,14 @@
 //! High-level Kafka client API.
 //!
-//! Provides a Client for managing Kafka consumers with protobuf decoding.
+//! Provides a Client for managing Kafka consumers with protobuf, JSON or Avro
+//! decoding.
 
 use crate::from_kafka::consumer::{Consumer, ConsumerConfig};
 use crate::from_kafka::error::Result;
 use crate::from_kafka::proto::decoder::ProtoDecoder;
 use crate::from_kafka::proto::parser::ProtoParser;
 use crate::types::{Message, ProtoSchema};
+use std::collections::HashMap;
 use std::future::Future;
 use std::path::Path;
 use std::pin::Pin;
@@ -42,6 +44,17 @@ impl Client {
         })
     }
 
+    /// Create a new Kafka client for JSON or Avro payloads, which need no
+    /// .proto schema
+    pub fn without_proto_schema(config: ConsumerConfig) -> Self {
+        Self {
+            schema: Arc::new(ProtoSchema {
+                messages: HashMap::new(),
+            }),
+            config,
+        }
+    }
+
     /// Create a decoder for this client's schema
     pub fn create_decoder(&self) -> ProtoDecoder {
         ProtoDecoder::new((*self.schema).clone())
@@ -2,16 +2,23 @@
 //!
 //! Uses Message and Payload types from `crate::types`.
 
+#[cfg(feature = "schema_registry")]
+use crate::from_kafka::avro::SchemaRegistry;
 use crate::from_kafka::error::{Error, Result};
 use crate::from_kafka::proto::decoder::ProtoDecoder;
 use crate::types::{Message, Payload};
 use rdkafka::config::ClientConfig;
-use rdkafka::consumer::{Consumer as RdkafkaConsumer, StreamConsumer as RdkafkaStreamConsumer};
-use rdkafka::message::{BorrowedMessage as RdkafkaBorrowedMessage, Message as RdkafkaMessage};
+use rdkafka::consumer::{
+    BaseConsumer, Consumer as RdkafkaConsumer, StreamConsumer as RdkafkaStreamConsumer,
+};
+use rdkafka::message::{
+    BorrowedMessage as RdkafkaBorrowedMessage, Headers as _, Message as RdkafkaMessage,
+};
 use rdkafka::{Offset, TopicPartitionList};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
+use surreal_sync_core::{CheckpointValidity, SourceEntity};
 use tokio::sync::Mutex;
 
 /// SASL authentication mechanism
@@ -72,6 +79,39 @@ impl SecurityProtocol {
     }
 }
 
+/// Where a partition without a committed offset starts consuming
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum AutoOffsetReset {
+    /// The oldest retained message
+    #[default]
+    Earliest,
+    /// Only messages produced after the consumer starts
+    Latest,
+}
+
+impl AutoOffsetReset {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoOffsetReset::Earliest => "earliest",
+            AutoOffsetReset::Latest => "latest",
+        }
+    }
+}
+
+/// Encoding of message payloads
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum PayloadEncoding {
+    /// Protobuf messages of `message_type`, decoded with the .proto schema
+    #[default]
+    Protobuf,
+    /// JSON objects whose members are the fields
+    Json,
+    /// Avro records in the Confluent Schema Registry wire format, decoded with
+    /// their writer schema from [`ConsumerConfig::schema_registry_url`].
+    /// Needs the `schema_registry` feature.
+    Avro,
+}
+
 /// Configuration for Kafka consumer
 #[derive(Debug, Clone)]
 pub struct ConsumerConfig {
@@ -92,7 +132,13 @@ pub struct ConsumerConfig {
     /// Protobuf message type name
     ///
     /// Must match a message defined in the provided .proto schema.
+    /// Unused with [`PayloadEncoding::Json`].
     pub message_type: String,
+    /// Encoding of the topic's message payloads
+    pub payload_encoding: PayloadEncoding,
+    /// Schema Registry URL (e.g. `http://localhost:8081`), required for
+    /// [`PayloadEncoding::Avro`]
+    pub schema_registry_url: Option<String>,
     /// Maximum buffer size for peeked messages
     ///
     /// The larger the buffer, the more messages can be peeked without blocking,
@@ -100,14 +146,22 @@ pub struct ConsumerConfig {
     /// Generally, a larger buffer is better for high-throughput scenarios,
     /// in exchange for higher memory usage and higher chance of duplicates on failure.
     pub buffer_size: usize,
-    /// Auto offset reset strategy ("earliest" or "latest")
+    /// Auto offset reset strategy
     ///
     /// "earliest" means the consumer will start from the beginning of the topic
     /// if no committed offsets are found for the consumer group.
     /// "latest" means the consumer will start from the end of the topic.
     ///
     /// Generally, "earliest" is preferred for CDC use cases to avoid missing messages = missing updates.
-    pub auto_offset_reset: String,
+    pub auto_offset_reset: AutoOffsetReset,
+    /// Partitions of `topic` to consume, assigned directly instead of
+    /// subscribing to the topic
+    ///
+    /// When empty, the consumer joins the consumer group and Kafka balances
+    /// the topic's partitions across its members. When set, the consumer reads
+    /// exactly these partitions without group rebalancing, still resuming
+    /// from (and committing to) the group's per-partition offsets.
+    pub partitions: Vec<i32>,
     /// Session timeout in milliseconds
     pub session_timeout_ms: String,
     /// Enable auto commit (should be false for manual offset management)
@@ -131,6 +185,12 @@ pub struct ConsumerConfig {
     pub ssl_key_location: Option<String>,
     /// Password for the client private key (`ssl.key.password`)
     pub ssl_key_password: Option<String>,
+    /// Number of workers decoding the payloads of each received batch
+    ///
+    /// With more than one, a batch is split into contiguous runs that are
+    /// decoded on blocking threads and reassembled in receive order, so
+    /// messages of a partition keep their offset order. 1 decodes inline.
+    pub decode_workers: usize,
 }
 
 impl Default for ConsumerConfig {
@@ -140,8 +200,11 @@ impl Default for ConsumerConfig {
             group_id: "surreal-sync-consumer".to_string(),
             topic: "".to_string(),
             message_type: "".to_string(),
+            payload_encoding: PayloadEncoding::Protobuf,
+            schema_registry_url: None,
             buffer_size: 100,
-            auto_offset_reset: "earliest".to_string(),
+            auto_offset_reset: AutoOffsetReset::Earliest,
+            partitions: Vec::new(),
             session_timeout_ms: "6000".to_string(),
             enable_auto_commit: false,
             sasl_username: None,
@@ -152,6 +215,7 @@ impl Default for ConsumerConfig {
             ssl_certificate_location: None,
             ssl_key_location: None,
             ssl_key_password: None,
+            decode_workers: 1,
         }
     }
 }
@@ -191,70 +255,374 @@ fn ssl_config_entries(config: &ConsumerConfig) -> Vec<(&'static str, &str)> {
     entries
 }
 
-/// Kafka consumer with peek buffer and manual offset management
-pub struct Consumer {
-    consumer: Arc<RdkafkaStreamConsumer>,
-    decoder: Arc<ProtoDecoder>,
-    config: ConsumerConfig,
-    buffer: Arc<Mutex<VecDeque<Message>>>,
+/// Validate `config` and build the rdkafka client configuration for it.
+fn client_config(config: &ConsumerConfig) -> Result<ClientConfig> {
+    if let Some(ref protocol) = config.security_protocol {
+        if protocol.requires_sasl() {
+            if config.sasl_username.is_none() || config.sasl_password.is_none() {
+                return Err(Error::Consumer(format!(
+                    "Security protocol '{}' requires both sasl_username and sasl_password",
+                    protocol.as_str()
+                )));
+            }
+            if config.sasl_mechanism.is_none() {
+                return Err(Error::Consumer(format!(
+                    "Security protocol '{}' requires sasl_mechanism to be set",
+                    protocol.as_str()
+                )));
+            }
+        }
+    }
+
+    validate_mtls_pairing(config)?;
+
+    let mut client_config = ClientConfig::new();
+    client_config
+        .set("bootstrap.servers", &config.brokers)
+        .set("group.id", &config.group_id)
+        .set("enable.auto.commit", config.enable_auto_commit.to_string())
+        .set("auto.offset.reset", config.auto_offset_reset.as_str())
+        .set("session.timeout.ms", &config.session_timeout_ms)
+        .set("enable.partition.eof", "false");
+
+    if let Some(ref protocol) = config.security_protocol {
+        client_config.set("security.protocol", protocol.as_str());
+
+        if protocol.requires_sasl() {
+            client_config
+                .set(
+                    "sasl.mechanism",
+                    config.sasl_mechanism.as_ref().unwrap().as_str(),
+                )
+                .set("sasl.username", config.sasl_username.as_deref().unwrap())
+                .set("sasl.password", config.sasl_password.as_deref().unwrap());
+        }
+    }
+
+    for (key, value) in ssl_config_entries(config) {
+        client_config.set(key, value);
+    }
+
+    Ok(client_config)
 }
 
-impl Consumer {
-    /// Create a new Kafka consumer
-    pub fn new(config: ConsumerConfig, decoder: ProtoDecoder) -> Result<Self> {
-        if let Some(ref protocol) = config.security_protocol {
-            if protocol.requires_sasl() {
-                if config.sasl_username.is_none() || config.sasl_password.is_none() {
-                    return Err(Error::Consumer(format!(
-                        "Security protocol '{}' requires both sasl_username and sasl_password",
-                        protocol.as_str()
-                    )));
-                }
-                if config.sasl_mechanism.is_none() {
-                    return Err(Error::Consumer(format!(
-                        "Security protocol '{}' requires sasl_mechanism to be set",
-                        protocol.as_str()
-                    )));
-                }
+/// Check whether the consumer group's committed offsets on `config.topic` can
+/// still be resumed from.
+///
+/// A committed offset below a partition's low watermark points at messages
+/// retention has already deleted. Partitions without a committed offset start
+/// from `auto_offset_reset` and are not considered stale.
+pub async fn validate_committed_offsets(
+    config: &ConsumerConfig,
+    timeout: Duration,
+) -> Result<CheckpointValidity> {
+    let client_config = client_config(config)?;
+    let topic = config.topic.clone();
+    let assigned = config.partitions.clone();
+    tokio::task::spawn_blocking(move || {
+        let consumer: BaseConsumer = client_config
+            .create()
+            .map_err(|e| Error::Consumer(format!("Failed to create consumer: {e}")))?;
+        let metadata = consumer.fetch_metadata(Some(&topic), timeout)?;
+        let partitions: Vec<i32> = metadata
+            .topics()
+            .iter()
+            .filter(|t| t.name() == topic)
+            .flat_map(|t| t.partitions().iter().map(|p| p.id()))
+            .filter(|id| assigned.is_empty() || assigned.contains(id))
+            .collect();
+        if partitions.is_empty() {
+            return Err(Error::Consumer(format!(
+                "Topic '{topic}' not found or has no partitions"
+            )));
+        }
+
+        let mut tpl = TopicPartitionList::new();
+        for partition in &partitions {
+            tpl.add_partition(&topic, *partition);
+        }
+        let committed = consumer.committed_offsets(tpl, timeout)?;
+
+        let mut positions = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let committed_offset = committed
+                .find_partition(&topic, partition)
+                .and_then(|elem| match elem.offset() {
+                    Offset::Offset(offset) => Some(offset),
+                    _ => None,
+                });
+            let (low, _high) = consumer.fetch_watermarks(&topic, partition, timeout)?;
+            positions.push((partition, committed_offset, low));
+        }
+        Ok(offsets_validity(&topic, &positions))
+    })
+    .await
+    .map_err(|e| Error::Consumer(format!("Offset validation task failed: {e}")))?
+}
+
+/// List the topics visible to `config`'s credentials with approximate message
+/// counts. `config.topic` is ignored.
+///
+/// The count of a topic is the sum of `high - low` watermarks over its
+/// partitions: the messages currently retained, including any a compacted
+/// topic has since removed. Internal topics (`__consumer_offsets`, ...) are
+/// skipped.
+pub async fn list_topics(config: &ConsumerConfig, timeout: Duration) -> Result<Vec<SourceEntity>> {
+    let client_config = client_config(config)?;
+    tokio::task::spawn_blocking(move || {
+        let consumer: BaseConsumer = client_config
+            .create()
+            .map_err(|e| Error::Consumer(format!("Failed to create consumer: {e}")))?;
+        let metadata = consumer.fetch_metadata(None, timeout)?;
+        let mut topics = Vec::new();
+        for topic in metadata.topics() {
+            if topic.name().starts_with("__") {
+                continue;
+            }
+            let mut retained: u64 = 0;
+            for partition in topic.partitions() {
+                let (low, high) =
+                    consumer.fetch_watermarks(topic.name(), partition.id(), timeout)?;
+                retained += (high - low).max(0) as u64;
             }
+            topics.push(SourceEntity::new(topic.name(), Some(retained)));
         }
+        topics.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(topics)
+    })
+    .await
+    .map_err(|e| Error::Consumer(format!("Topic listing task failed: {e}")))?
+}
+
+/// Validity of `(partition, committed offset, low watermark)` positions.
+fn offsets_validity(topic: &str, positions: &[(i32, Option<i64>, i64)]) -> CheckpointValidity {
+    let stale: Vec<String> = positions
+        .iter()
+        .filter_map(|&(partition, committed, low)| match committed {
+            Some(offset) if offset < low => Some(format!(
+                "partition {partition} committed offset {offset} < earliest retained offset {low}"
+            )),
+            _ => None,
+        })
+        .collect();
+    if stale.is_empty() {
+        CheckpointValidity::Valid
+    } else {
+        CheckpointValidity::too_old(format!("topic '{topic}': {}", stale.join(", ")))
+    }
+}
+
+/// Assignment of `partitions` of `topic`, each resuming from the group's
+/// committed offset (or `auto.offset.reset` without one).
+fn partition_assignment(topic: &str, partitions: &[i32]) -> Result<TopicPartitionList> {
+    let mut tpl = TopicPartitionList::new();
+    for &partition in partitions {
+        tpl.add_partition_offset(topic, partition, Offset::Stored)
+            .map_err(|e| Error::Consumer(format!("Failed to add partition {partition}: {e}")))?;
+    }
+    Ok(tpl)
+}
+
+/// The offset to commit for each `(topic, partition)` in `messages`: one past
+/// the highest offset consumed from it, so resuming restores each partition's
+/// exact position.
+fn next_offsets(messages: &[Message]) -> BTreeMap<(&str, i32), i64> {
+    let mut offsets = BTreeMap::new();
+    for message in messages {
+        let next = offsets
+            .entry((message.topic.as_str(), message.partition))
+            .or_insert(message.offset + 1);
+        *next = (*next).max(message.offset + 1);
+    }
+    offsets
+}
+
+/// A received message whose payload is not decoded yet.
+struct RawMessage {
+    payload: Vec<u8>,
+    topic: String,
+    partition: i32,
+    offset: i64,
+    key: Option<Vec<u8>>,
+    timestamp: Option<i64>,
+    headers: Vec<(String, Option<Vec<u8>>)>,
+}
+
+impl RawMessage {
+    fn from_kafka(msg: &RdkafkaBorrowedMessage) -> Result<Self> {
+        let payload = msg
+            .payload()
+            .ok_or_else(|| Error::Consumer("Message has no payload".to_string()))?;
+        Ok(Self {
+            payload: payload.to_vec(),
+            topic: msg.topic().to_string(),
+            partition: msg.partition(),
+            offset: msg.offset(),
+            key: msg.key().map(|k| k.to_vec()),
+            timestamp: msg.timestamp().to_millis(),
+            headers: msg
+                .headers()
+                .map(|headers| {
+                    headers
+                        .iter()
+                        .map(|h| (h.key.to_string(), h.value.map(|v| v.to_vec())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    fn decode(self, decoder: &PayloadDecoder) -> Result<Message> {
+        let payload = decoder.decode(&self)?;
+        Ok(Message {
+            payload,
+            topic: self.topic,
+            partition: self.partition,
+            offset: self.offset,
+            key: self.key,
+            timestamp: self.timestamp,
+            headers: self.headers,
+        })
+    }
+}
+
+/// Decodes message payloads in the configured [`PayloadEncoding`].
+struct PayloadDecoder {
+    encoding: PayloadEncoding,
+    proto: ProtoDecoder,
+    message_type: String,
+    #[cfg(feature = "schema_registry")]
+    registry: Option<SchemaRegistry>,
+}
 
-        validate_mtls_pairing(&config)?;
-
-        let mut client_config = ClientConfig::new();
-        client_config
-            .set("bootstrap.servers", &config.brokers)
-            .set("group.id", &config.group_id)
-            .set("enable.auto.commit", config.enable_auto_commit.to_string())
-            .set("auto.offset.reset", &config.auto_offset_reset)
-            .set("session.timeout.ms", &config.session_timeout_ms)
-            .set("enable.partition.eof", "false");
-
-        if let Some(ref protocol) = config.security_protocol {
-            client_config.set("security.protocol", protocol.as_str());
-
-            if protocol.requires_sasl() {
-                client_config
-                    .set(
-                        "sasl.mechanism",
-                        config.sasl_mechanism.as_ref().unwrap().as_str(),
+impl PayloadDecoder {
+    fn new(config: &ConsumerConfig, proto: ProtoDecoder) -> Result<Self> {
+        #[cfg(feature = "schema_registry")]
+        let registry = match config.payload_encoding {
+            PayloadEncoding::Avro => {
+                let url = config.schema_registry_url.as_deref().ok_or_else(|| {
+                    Error::InvalidConfig(
+                        "Avro payloads require schema_registry_url to be set".to_string(),
                     )
-                    .set("sasl.username", config.sasl_username.as_deref().unwrap())
-                    .set("sasl.password", config.sasl_password.as_deref().unwrap());
+                })?;
+                Some(SchemaRegistry::new(url))
             }
+            _ => None,
+        };
+        #[cfg(not(feature = "schema_registry"))]
+        if config.payload_encoding == PayloadEncoding::Avro {
+            return Err(Error::InvalidConfig(
+                "Avro payloads require surreal-sync-kafka's schema_registry feature".to_string(),
+            ));
+        }
+        Ok(Self {
+            encoding: config.payload_encoding,
+            proto,
+            message_type: config.message_type.clone(),
+            #[cfg(feature = "schema_registry")]
+            registry,
+        })
+    }
+
+    fn decode(&self, raw: &RawMessage) -> Result<Payload> {
+        match self.encoding {
+            PayloadEncoding::Protobuf => Ok(Payload::Protobuf(
+                self.proto.decode(&self.message_type, &raw.payload)?,
+            )),
+            PayloadEncoding::Json => match serde_json::from_slice(&raw.payload) {
+                Ok(serde_json::Value::Object(object)) => Ok(Payload::Json(object)),
+                Ok(other) => Err(Error::JsonDecode(format!(
+                    "expected a JSON object at offset {} of partition {}, got {other}",
+                    raw.offset, raw.partition
+                ))),
+                Err(e) => Err(Error::JsonDecode(e.to_string())),
+            },
+            #[cfg(feature = "schema_registry")]
+            PayloadEncoding::Avro => match &self.registry {
+                Some(registry) => Ok(Payload::Avro(registry.decode(&raw.payload)?)),
+                None => Err(Error::InvalidConfig(
+                    "Avro payloads require schema_registry_url to be set".to_string(),
+                )),
+            },
+            #[cfg(not(feature = "schema_registry"))]
+            PayloadEncoding::Avro => Err(Error::InvalidConfig(
+                "Avro payloads require surreal-sync-kafka's schema_registry feature".to_string(),
+            )),
         }
+    }
+}
 
-        for (key, value) in ssl_config_entries(&config) {
-            client_config.set(key, value);
+/// Decode `raw` on up to `workers` blocking threads, returning the messages
+/// in the order received.
+///
+/// The batch is split into contiguous runs, one per worker, and the decoded
+/// runs are concatenated in order, so each partition's messages stay in
+/// offset order and committing the batch stays correct. The first decode
+/// error fails the whole batch.
+async fn decode_batch(
+    decoder: &Arc<PayloadDecoder>,
+    raw: Vec<RawMessage>,
+    workers: usize,
+) -> Result<Vec<Message>> {
+    if workers <= 1 || raw.len() <= 1 {
+        return raw.into_iter().map(|msg| msg.decode(decoder)).collect();
+    }
+
+    let run_len = raw.len().div_ceil(workers);
+    let mut raw = raw.into_iter();
+    let mut tasks = Vec::with_capacity(workers);
+    loop {
+        let run: Vec<RawMessage> = raw.by_ref().take(run_len).collect();
+        if run.is_empty() {
+            break;
         }
+        let decoder = Arc::clone(decoder);
+        tasks.push(tokio::task::spawn_blocking(move || {
+            run.into_iter()
+                .map(|msg| msg.decode(&decoder))
+                .collect::<Result<Vec<_>>>()
+        }));
+    }
+
+    let mut messages = Vec::new();
+    for task in tasks {
+        let decoded = task
+            .await
+            .map_err(|e| Error::Consumer(format!("Decode worker failed: {e}")))??;
+        messages.extend(decoded);
+    }
+    Ok(messages)
+}
+
+/// Kafka consumer with peek buffer and manual offset management
+pub struct Consumer {
+    consumer: Arc<RdkafkaStreamConsumer>,
+    decoder: Arc<PayloadDecoder>,
+    config: ConsumerConfig,
+    buffer: Arc<Mutex<VecDeque<Message>>>,
+}
+
+impl Consumer {
+    /// Create a new Kafka consumer
+    ///
+    /// `decoder` decodes protobuf payloads; other encodings ignore it.
+    pub fn new(config: ConsumerConfig, decoder: ProtoDecoder) -> Result<Self> {
+        let client_config = client_config(&config)?;
+        let decoder = PayloadDecoder::new(&config, decoder)?;
 
         let consumer: RdkafkaStreamConsumer = client_config
             .create()
             .map_err(|e| Error::Consumer(format!("Failed to create consumer: {e}")))?;
 
-        consumer
-            .subscribe(&[&config.topic])
-            .map_err(|e| Error::Consumer(format!("Failed to subscribe to topic: {e}")))?;
+        if config.partitions.is_empty() {
+            consumer
+                .subscribe(&[&config.topic])
+                .map_err(|e| Error::Consumer(format!("Failed to subscribe to topic: {e}")))?;
+        } else {
+            consumer
+                .assign(&partition_assignment(&config.topic, &config.partitions)?)
+                .map_err(|e| Error::Consumer(format!("Failed to assign partitions: {e}")))?;
+        }
 
         Ok(Self {
             consumer: Arc::new(consumer),
@@ -270,16 +638,16 @@ impl Consumer {
         let mut buffer = self.buffer.lock().await;
 
         // Fill buffer if needed
-        while buffer.len() < count && buffer.len() < self.config.buffer_size {
+        let mut raw = Vec::new();
+        while buffer.len() + raw.len() < count && buffer.len() + raw.len() < self.config.buffer_size
+        {
             match tokio::time::timeout(Duration::from_millis(100), self.consumer.recv()).await {
-                Ok(Ok(msg)) => {
-                    let kafka_msg = self.decode_message(&msg)?;
-                    buffer.push_back(kafka_msg);
-                }
+                Ok(Ok(msg)) => raw.push(RawMessage::from_kafka(&msg)?),
                 Ok(Err(e)) => return Err(Error::Consumer(format!("Error receiving message: {e}"))),
                 Err(_) => break, // Timeout, no more messages available right now
             }
         }
+        buffer.extend(self.decode_batch(raw).await?);
 
         // Return uncommitted messages
         Ok(buffer.iter().take(count).cloned().collect::<Vec<_>>())
@@ -317,9 +685,10 @@ impl Consumer {
         drop(buffer); // Release lock before fetching
 
         // Fetch at least one message with timeout
+        let mut raw = Vec::new();
         if messages.is_empty() {
             match tokio::time::timeout(receive_timeout, self.consumer.recv()).await {
-                Ok(Ok(msg)) => messages.push(self.decode_message(&msg)?),
+                Ok(Ok(msg)) => raw.push(RawMessage::from_kafka(&msg)?),
                 Ok(Err(e)) => return Err(Error::Consumer(format!("Error receiving message: {e}"))),
                 Err(_) => {
                     // Timeout - no messages available, return empty batch
@@ -329,13 +698,14 @@ impl Consumer {
         }
 
         // Try to fetch more with short timeout
-        while messages.len() < max_count {
+        while messages.len() + raw.len() < max_count {
             match tokio::time::timeout(Duration::from_millis(10), self.consumer.recv()).await {
-                Ok(Ok(msg)) => messages.push(self.decode_message(&msg)?),
+                Ok(Ok(msg)) => raw.push(RawMessage::from_kafka(&msg)?),
                 _ => break,
             }
         }
 
+        messages.extend(self.decode_batch(raw).await?);
         Ok(messages)
     }
 
@@ -346,13 +716,9 @@ impl Consumer {
         }
 
         let mut tpl = TopicPartitionList::new();
-        for message in messages {
-            tpl.add_partition_offset(
-                &message.topic,
-                message.partition,
-                Offset::Offset(message.offset + 1),
-            )
-            .map_err(|e| Error::Consumer(format!("Failed to add partition offset: {e}")))?;
+        for ((topic, partition), offset) in next_offsets(messages) {
+            tpl.add_partition_offset(topic, partition, Offset::Offset(offset))
+                .map_err(|e| Error::Consumer(format!("Failed to add partition offset: {e}")))?;
         }
 
         self.consumer
@@ -375,21 +741,16 @@ impl Consumer {
         buffer.clear();
     }
 
-    fn decode_message(&self, msg: &RdkafkaBorrowedMessage) -> Result<Message> {
-        let payload = msg
-            .payload()
-            .ok_or_else(|| Error::Consumer("Message has no payload".to_string()))?;
-
-        let decoded = self.decoder.decode(&self.config.message_type, payload)?;
-
-        Ok(Message {
-            payload: Payload::Protobuf(decoded),
-            topic: msg.topic().to_string(),
-            partition: msg.partition(),
-            offset: msg.offset(),
-            key: msg.key().map(|k| k.to_vec()),
-            timestamp: msg.timestamp().to_millis(),
-        })
+    /// Decode received messages in receive order, on
+    /// [`ConsumerConfig::decode_workers`] workers.
+    async fn decode_batch(&self, raw: Vec<RawMessage>) -> Result<Vec<Message>> {
+        #[cfg(feature = "schema_registry")]
+        if let Some(registry) = &self.decoder.registry {
+            registry
+                .fetch_schemas(raw.iter().map(|msg| msg.payload.as_slice()))
+                .await?;
+        }
+        decode_batch(&self.decoder, raw, self.config.decode_workers).await
     }
 
     /// Get the underlying consumer (for advanced use cases)
@@ -596,6 +957,45 @@ mod tests {
         assert!(entries.contains(&("ssl.key.password", "secret")));
     }
 
+    #[test]
+    fn client_config_forwards_sasl_and_ssl_settings() {
+        let config = ConsumerConfig {
+            brokers: "broker:9093".to_string(),
+            security_protocol: Some(SecurityProtocol::SaslSsl),
+            sasl_mechanism: Some(SaslMechanism::ScramSha512),
+            sasl_username: Some("user".into()),
+            sasl_password: Some("pass".into()),
+            ssl_ca_location: Some("/ca.pem".into()),
+            ..Default::default()
+        };
+        let client_config = client_config(&config).unwrap();
+        assert_eq!(client_config.get("bootstrap.servers"), Some("broker:9093"));
+        assert_eq!(client_config.get("security.protocol"), Some("SASL_SSL"));
+        assert_eq!(client_config.get("sasl.mechanism"), Some("SCRAM-SHA-512"));
+        assert_eq!(client_config.get("sasl.username"), Some("user"));
+        assert_eq!(client_config.get("sasl.password"), Some("pass"));
+        assert_eq!(client_config.get("ssl.ca.location"), Some("/ca.pem"));
+    }
+
+    #[test]
+    fn client_config_without_security_protocol_is_plaintext() {
+        let client_config = client_config(&ConsumerConfig::default()).unwrap();
+        assert_eq!(client_config.get("security.protocol"), None);
+        assert_eq!(client_config.get("sasl.mechanism"), None);
+        assert_eq!(client_config.get("ssl.ca.location"), None);
+    }
+
+    #[test]
+    fn avro_requires_schema_registry_url() {
+        let config = ConsumerConfig {
+            topic: "users".to_string(),
+            payload_encoding: PayloadEncoding::Avro,
+            ..Default::default()
+        };
+        let err = expect_err(Consumer::new(config, dummy_decoder()));
+        assert!(matches!(err, Error::InvalidConfig(_)), "{err}");
+    }
+
     #[test]
     fn ssl_config_ca_only_without_mtls() {
         let config = ConsumerConfig {
@@ -615,4 +1015,178 @@ mod tests {
             messages: HashMap::new(),
         })
     }
+
+    use crate::types::ProtoFieldValue;
+
+    /// Decoder of `User` protobuf messages or of `encoding` payloads.
+    fn payload_decoder(encoding: PayloadEncoding, proto: ProtoDecoder) -> PayloadDecoder {
+        let config = ConsumerConfig {
+            message_type: "User".to_string(),
+            payload_encoding: encoding,
+            ..Default::default()
+        };
+        PayloadDecoder::new(&config, proto).unwrap()
+    }
+
+    fn user_payload(id: i64, name: &str) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        {
+            let mut stream = protobuf::CodedOutputStream::vec(&mut buffer);
+            stream.write_int64(1, id).unwrap();
+            stream.write_string(2, name).unwrap();
+            stream.flush().unwrap();
+        }
+        buffer
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn decode_batch_keeps_partition_order_with_several_workers() {
+        let schema = crate::from_kafka::ProtoParser::from_string(
+            "syntax = \"proto3\"; message User { int64 id = 1; string name = 2; }",
+        )
+        .unwrap();
+        let decoder = Arc::new(payload_decoder(
+            PayloadEncoding::Protobuf,
+            ProtoDecoder::new(schema),
+        ));
+
+        // Three partitions interleaved the way a poll returns them.
+        let raw: Vec<RawMessage> = (0..50i64)
+            .map(|i| RawMessage {
+                payload: user_payload(i, &format!("user-{i}")),
+                topic: "users".to_string(),
+                partition: (i % 3) as i32,
+                offset: i / 3,
+                key: None,
+                timestamp: None,
+                headers: Vec::new(),
+            })
+            .collect();
+        let messages = decode_batch(&decoder, raw, 4).await.unwrap();
+
+        assert_eq!(messages.len(), 50);
+        for (i, message) in messages.iter().enumerate() {
+            let Payload::Protobuf(decoded) = &message.payload else {
+                panic!("expected a protobuf payload");
+            };
+            assert!(
+                matches!(decoded.fields.get("id"), Some(ProtoFieldValue::Int64(id)) if *id == i as i64)
+            );
+            assert!(
+                matches!(decoded.fields.get("name"), Some(ProtoFieldValue::String(name)) if *name == format!("user-{i}"))
+            );
+            assert_eq!(message.partition, (i % 3) as i32);
+        }
+        for partition in 0..3 {
+            let offsets: Vec<i64> = messages
+                .iter()
+                .filter(|m| m.partition == partition)
+                .map(|m| m.offset)
+                .collect();
+            assert!(offsets.windows(2).all(|w| w[0] < w[1]), "{offsets:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn decode_batch_fails_on_undecodable_payload() {
+        let schema = crate::from_kafka::ProtoParser::from_string(
+            "syntax = \"proto3\"; message User { int64 id = 1; }",
+        )
+        .unwrap();
+        let decoder = Arc::new(payload_decoder(
+            PayloadEncoding::Protobuf,
+            ProtoDecoder::new(schema),
+        ));
+        let raw = (0..4)
+            .map(|i| RawMessage {
+                payload: if i == 2 {
+                    vec![0xff]
+                } else {
+                    user_payload(i, "x")
+                },
+                topic: "users".to_string(),
+                partition: 0,
+                offset: i,
+                key: None,
+                timestamp: None,
+                headers: Vec::new(),
+            })
+            .collect();
+        assert!(decode_batch(&decoder, raw, 2).await.is_err());
+    }
+
+    fn json_message(offset: i64, payload: &str) -> RawMessage {
+        RawMessage {
+            payload: payload.as_bytes().to_vec(),
+            topic: "users".to_string(),
+            partition: 0,
+            offset,
+            key: None,
+            timestamp: None,
+            headers: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn decode_batch_decodes_json_objects() {
+        let decoder = Arc::new(payload_decoder(PayloadEncoding::Json, dummy_decoder()));
+        let raw = vec![json_message(0, r#"{"id": 1, "name": "a"}"#)];
+        let messages = decode_batch(&decoder, raw, 1).await.unwrap();
+        let Payload::Json(object) = &messages[0].payload else {
+            panic!("expected a JSON payload");
+        };
+        assert_eq!(object["name"], "a");
+
+        // Only objects have fields to sync
+        let raw = vec![json_message(1, "[1, 2]")];
+        assert!(decode_batch(&decoder, raw, 1).await.is_err());
+    }
+
+    #[test]
+    fn offsets_validity_flags_offsets_behind_low_watermark() {
+        let positions = [(0, Some(120), 100), (1, Some(40), 90), (2, None, 500)];
+        let validity = offsets_validity("orders", &positions);
+        assert_eq!(
+            validity,
+            CheckpointValidity::too_old(
+                "topic 'orders': partition 1 committed offset 40 < earliest retained offset 90"
+            )
+        );
+    }
+
+    #[test]
+    fn offsets_validity_accepts_retained_and_uncommitted_partitions() {
+        let positions = [(0, Some(100), 100), (1, None, 7)];
+        assert!(offsets_validity("orders", &positions).is_valid());
+    }
+
+    #[test]
+    fn next_offsets_commits_one_past_each_partitions_highest_offset() {
+        let message = |partition, offset| Message {
+            payload: Payload::Json(serde_json::Map::new()),
+            topic: "orders".to_string(),
+            partition,
+            offset,
+            key: None,
+            timestamp: None,
+            headers: Vec::new(),
+        };
+        // Sink acknowledgements need not arrive in offset order
+        let messages = [message(0, 7), message(1, 3), message(0, 9), message(0, 8)];
+        assert_eq!(
+            next_offsets(&messages).into_iter().collect::<Vec<_>>(),
+            [(("orders", 0), 10), (("orders", 1), 4)]
+        );
+    }
+
+    #[test]
+    fn partition_assignment_resumes_from_stored_offsets() {
+        let tpl = partition_assignment("orders", &[2, 5]).unwrap();
+        let assigned: Vec<(i32, Offset)> = tpl
+            .elements()
+            .iter()
+            .map(|elem| (elem.partition(), elem.offset()))
+            .collect();
+        assert_eq!(assigned, [(2, Offset::Stored), (5, Offset::Stored)]);
+    }
 }
@@ -12,6 +12,18 @@ pub enum Error {
     #[error("Protobuf decode error: {0}")]
     ProtobufDecode(String),
 
+    #[error("JSON decode error: {0}")]
+    JsonDecode(String),
+
+    #[error("Avro schema error: {0}")]
+    AvroSchema(String),
+
+    #[error("Avro decode error: {0}")]
+    AvroDecode(String),
+
+    #[error("Schema Registry error: {0}")]
+    SchemaRegistry(String),
+
     #[error("Field not found: {0}")]
     FieldNotFound(String),
 
@@ -0,0 +1,277 @@
+//! Mapping Kafka message headers into record fields.
+//!
+//! Headers often carry context the payload does not (tenant id, schema
+//! version, trace id). Each [`HeaderField`] names a header, the record field
+//! it lands in, and the [`HeaderType`] its bytes are decoded as.
+
+use std::str::FromStr;
+
+use surreal_sync_core::Value;
+
+use crate::from_kafka::error::{Error, Result};
+use crate::types::Message;
+
+/// Declared type of a header value.
+///
+/// Numeric, boolean and JSON headers are decoded from their UTF-8 text form,
+/// which is how Kafka clients conventionally write them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeaderType {
+    /// UTF-8 text
+    #[default]
+    String,
+    /// Decimal integer text, stored as a 64-bit integer
+    Int,
+    /// Decimal floating point text
+    Float,
+    /// `true` / `false`
+    Bool,
+    /// JSON document
+    Json,
+    /// Raw bytes, stored unchanged
+    Bytes,
+    /// UUID as text or 16 raw bytes
+    Uuid,
+}
+
+impl FromStr for HeaderType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "string" => Ok(Self::String),
+            "int" => Ok(Self::Int),
+            "float" => Ok(Self::Float),
+            "bool" => Ok(Self::Bool),
+            "json" => Ok(Self::Json),
+            "bytes" => Ok(Self::Bytes),
+            "uuid" => Ok(Self::Uuid),
+            other => Err(format!(
+                "unknown header type '{other}' (expected string, int, float, bool, json, bytes or uuid)"
+            )),
+        }
+    }
+}
+
+/// One header-to-field mapping, parsed from `HEADER[=FIELD][:TYPE]`.
+///
+/// `FIELD` defaults to the header name and `TYPE` to `string`, so
+/// `tenant-id=tenant_id` and `schema-version=schema_version:int` are both
+/// valid. Header names may contain `:` (`ce:type`): the last `:` only starts
+/// a `TYPE` when what follows is a known type name, so a header that itself
+/// ends in one (`x:int`) is written with an explicit type (`x:int:string`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderField {
+    /// Kafka header key
+    pub header: String,
+    /// Target record field
+    pub field: String,
+    /// How the header bytes are decoded
+    pub header_type: HeaderType,
+}
+
+impl FromStr for HeaderField {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (mapping, header_type) = match s.rsplit_once(':') {
+            Some((mapping, ty)) => match ty.parse() {
+                Ok(header_type) => (mapping, header_type),
+                // A ':' in the FIELD part can only introduce a type.
+                Err(e) if mapping.contains('=') => return Err(e),
+                Err(_) => (s, HeaderType::default()),
+            },
+            None => (s, HeaderType::default()),
+        };
+        let (header, field) = mapping.split_once('=').unwrap_or((mapping, mapping));
+        if header.is_empty() || field.is_empty() {
+            return Err(format!(
+                "invalid header mapping '{s}' (expected HEADER[=FIELD][:TYPE])"
+            ));
+        }
+        Ok(Self {
+            header: header.to_string(),
+            field: field.to_string(),
+            header_type,
+        })
+    }
+}
+
+/// What to do when a mapped header is absent from a message (or present with
+/// a null value).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum MissingHeader {
+    /// Leave the field out of the record
+    #[default]
+    Skip,
+    /// Set the field to null
+    Null,
+}
+
+/// Decode the mapped headers of `message` into `(field, value)` pairs.
+///
+/// When a header key repeats, the last occurrence wins.
+pub fn header_field_values(
+    message: &Message,
+    mappings: &[HeaderField],
+    missing: MissingHeader,
+) -> Result<Vec<(String, Value)>> {
+    let mut values = Vec::with_capacity(mappings.len());
+    for mapping in mappings {
+        let raw = message
+            .headers
+            .iter()
+            .rev()
+            .find(|(key, _)| *key == mapping.header)
+            .and_then(|(_, value)| value.as_deref());
+        match raw {
+            Some(bytes) => values.push((
+                mapping.field.clone(),
+                decode_header(&mapping.header, bytes, mapping.header_type)?,
+            )),
+            None if missing == MissingHeader::Null => {
+                values.push((mapping.field.clone(), Value::Null))
+            }
+            None => {}
+        }
+    }
+    Ok(values)
+}
+
+fn decode_header(header: &str, bytes: &[u8], header_type: HeaderType) -> Result<Value> {
+    let invalid = |reason: String| {
+        Error::MessageProcessing(format!(
+            "header '{header}' is not a valid {header_type:?}: {reason}"
+        ))
+    };
+    let text = || std::str::from_utf8(bytes).map_err(|e| invalid(e.to_string()));
+    Ok(match header_type {
+        HeaderType::String => Value::Text(text()?.to_string()),
+        HeaderType::Int => Value::Int64(
+            text()?
+                .trim()
+                .parse()
+                .map_err(|e: std::num::ParseIntError| invalid(e.to_string()))?,
+        ),
+        HeaderType::Float => Value::Float64(
+            text()?
+                .trim()
+                .parse()
+                .map_err(|e: std::num::ParseFloatError| invalid(e.to_string()))?,
+        ),
+        HeaderType::Bool => match text()?.trim().to_ascii_lowercase().as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            other => return Err(invalid(format!("'{other}'"))),
+        },
+        HeaderType::Json => Value::Json(Box::new(
+            serde_json::from_slice(bytes).map_err(|e| invalid(e.to_string()))?,
+        )),
+        HeaderType::Bytes => Value::Bytes(bytes.to_vec()),
+        HeaderType::Uuid => match uuid::Uuid::from_slice(bytes) {
+            Ok(uuid) => Value::Uuid(uuid),
+            Err(_) => Value::Uuid(
+                uuid::Uuid::parse_str(text()?.trim()).map_err(|e| invalid(e.to_string()))?,
+            ),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Payload, ProtoMessage, ProtoMessageDescriptor};
+
+    fn message(headers: &[(&str, Option<&[u8]>)]) -> Message {
+        Message {
+            payload: Payload::Protobuf(ProtoMessage {
+                message_type: "User".to_string(),
+                fields: Default::default(),
+                descriptor: ProtoMessageDescriptor {
+                    name: "User".to_string(),
+                    fields: Default::default(),
+                    field_order: Vec::new(),
+                },
+            }),
+            topic: "t".to_string(),
+            partition: 0,
+            offset: 0,
+            key: None,
+            timestamp: None,
+            headers: headers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.map(|v| v.to_vec())))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_header_field() {
+        assert_eq!(
+            "tenant-id=tenant_id".parse::<HeaderField>().unwrap(),
+            HeaderField {
+                header: "tenant-id".to_string(),
+                field: "tenant_id".to_string(),
+                header_type: HeaderType::String,
+            }
+        );
+        let version: HeaderField = "schema_version:int".parse().unwrap();
+        assert_eq!(version.field, "schema_version");
+        assert_eq!(version.header_type, HeaderType::Int);
+        assert!("=field".parse::<HeaderField>().is_err());
+        assert!("h=f:date".parse::<HeaderField>().is_err());
+
+        let cloud_event: HeaderField = "ce:type=event_type".parse().unwrap();
+        assert_eq!(cloud_event.header, "ce:type");
+        assert_eq!(cloud_event.field, "event_type");
+        assert_eq!(cloud_event.header_type, HeaderType::String);
+        let bare: HeaderField = "ce:time".parse().unwrap();
+        assert_eq!(
+            (bare.header.as_str(), bare.field.as_str()),
+            ("ce:time", "ce:time")
+        );
+        let typed: HeaderField = "ce:seq=seq:int".parse().unwrap();
+        assert_eq!(typed.header, "ce:seq");
+        assert_eq!(typed.header_type, HeaderType::Int);
+        let explicit: HeaderField = "x:int:string".parse().unwrap();
+        assert_eq!(explicit.header, "x:int");
+        assert_eq!(explicit.header_type, HeaderType::String);
+    }
+
+    #[test]
+    fn test_header_field_values_decode_and_missing() {
+        let msg = message(&[
+            ("tenant", Some(b"acme")),
+            ("version", Some(b"1")),
+            ("version", Some(b"3")),
+            ("trace", None),
+        ]);
+        let mappings: Vec<HeaderField> =
+            ["tenant=tenant_id", "version:int", "trace", "absent:bool"]
+                .iter()
+                .map(|m| m.parse().unwrap())
+                .collect();
+
+        let skipped = header_field_values(&msg, &mappings, MissingHeader::Skip).unwrap();
+        assert_eq!(
+            skipped,
+            vec![
+                ("tenant_id".to_string(), Value::Text("acme".to_string())),
+                ("version".to_string(), Value::Int64(3)),
+            ]
+        );
+
+        let nulled = header_field_values(&msg, &mappings, MissingHeader::Null).unwrap();
+        assert_eq!(nulled.len(), 4);
+        assert_eq!(nulled[2], ("trace".to_string(), Value::Null));
+        assert_eq!(nulled[3], ("absent".to_string(), Value::Null));
+    }
+
+    #[test]
+    fn test_header_field_values_rejects_undecodable() {
+        let msg = message(&[("version", Some(b"v1"))]);
+        let mappings = vec!["version:int".parse::<HeaderField>().unwrap()];
+        let err = header_field_values(&msg, &mappings, MissingHeader::Skip).unwrap_err();
+        assert!(err.to_string().contains("header 'version'"), "{err}");
+    }
+}
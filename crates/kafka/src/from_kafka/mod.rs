@@ -1,7 +1,7 @@
 //! Kafka consumer and sync library for surreal-sync.
 //!
 //! This crate provides:
-//! - Kafka consumer with protobuf decoding
+//! - Kafka consumer with protobuf, JSON or Avro (Schema Registry) decoding
 //! - Incremental sync to SurrealDB
 //!
 //! # Features
@@ -14,6 +14,9 @@
 //!
 //! Types come from [`crate::types`]; this module adds decoding and consumer logic.
 
+/// Avro decoding and the Schema Registry client
+pub mod avro;
+
 /// High-level API for spawning consumer tasks
 ///
 /// Takes the consumer config and .proto schema, to create one or more consumers
@@ -25,6 +28,7 @@ pub mod client;
 /// Created by the client given the consumer config and .proto schema.
 pub mod consumer;
 pub mod error;
+pub mod headers;
 pub mod proto;
 pub mod sync;
 
@@ -35,11 +39,17 @@ pub use crate::types::{
 };
 
 // Re-export sync functions
-pub use sync::{run_incremental_sync, run_incremental_sync_with_transforms, Config};
+pub use sync::{
+    run_incremental_sync, run_incremental_sync_with_transforms, validate_checkpoint, Config,
+};
 
 // Re-export consumer types
 pub use client::Client;
-pub use consumer::{Consumer, ConsumerConfig, SaslMechanism, SecurityProtocol};
+pub use consumer::{
+    list_topics, validate_committed_offsets, AutoOffsetReset, Consumer, ConsumerConfig,
+    PayloadEncoding, SaslMechanism, SecurityProtocol,
+};
 pub use error::{Error, Result};
+pub use headers::{header_field_values, HeaderField, HeaderType, MissingHeader};
 pub use proto::decoder::ProtoDecoder;
 pub use proto::parser::ProtoParser;
@@ -1,6 +1,6 @@
 //! Kafka incremental sync to SurrealDB.
 //!
-//! Consumes protobuf-encoded messages from Kafka topics and writes them as
+//! Consumes protobuf-, JSON- or Avro-encoded messages from Kafka topics and writes them as
 //! records to SurrealDB through [`SourceDriver`] + [`run_source_runtime`].
 //!
 //! Offset commit stays with the Kafka consumer group. The runtime window owns
@@ -19,11 +19,13 @@ use async_trait::async_trait;
 use base64::Engine;
 use chrono::{DateTime, Utc};
 use clap::Parser;
-use std::collections::{HashMap, VecDeque};
+use indexmap::IndexMap;
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, Row, TableDefinition, TypedValue, Value};
+use std::time::Duration;
+use surreal_sync_core::{Change, FieldMap, Row, TableDefinition, TypedValue, Value};
+use surreal_sync_core::{CheckpointValidity, SurrealSink};
 use surreal_sync_runtime::{
     run_source_runtime, ApplyOpts, CheckpointPolicy, Pipeline, PositionedEvent, SourceDriver,
     SourceRuntimeOpts, StopReason,
@@ -31,14 +33,18 @@ use surreal_sync_runtime::{
 use tokio::task::JoinHandle;
 use tracing::{debug, info};
 
-use crate::from_kafka::consumer::{Consumer, ConsumerConfig, SaslMechanism, SecurityProtocol};
+use crate::from_kafka::consumer::{
+    validate_committed_offsets, AutoOffsetReset, Consumer, ConsumerConfig, PayloadEncoding,
+    SaslMechanism, SecurityProtocol,
+};
+use crate::from_kafka::headers::{header_field_values, HeaderField, MissingHeader};
 use crate::from_kafka::Client;
 
 /// Configuration for Kafka source.
 #[derive(Debug, Clone, Parser)]
 pub struct Config {
-    /// Proto file path
-    #[clap(long)]
+    /// Proto file path (required for protobuf payloads)
+    #[clap(long, default_value = "")]
     pub proto_path: String,
     /// Kafka brokers (comma-separated or multiple --brokers)
     #[clap(long, value_delimiter = ',', required = true)]
@@ -49,9 +55,17 @@ pub struct Config {
     /// Topic to consume from
     #[clap(long)]
     pub topic: String,
-    /// Protobuf message type name
-    #[clap(long)]
+    /// Protobuf message type name (required for protobuf payloads)
+    #[clap(long, default_value = "")]
     pub message_type: String,
+    /// Encoding of the message payloads. JSON payloads must be objects; their
+    /// members become the record fields.
+    #[clap(long, value_enum, default_value_t = PayloadEncoding::Protobuf)]
+    pub payload_encoding: PayloadEncoding,
+    /// Confluent Schema Registry URL to fetch Avro writer schemas from
+    /// (required for Avro payloads)
+    #[clap(long, env = "KAFKA_SCHEMA_REGISTRY_URL")]
+    pub schema_registry_url: Option<String>,
     /// Maximum buffer size for peeked messages
     #[clap(long, default_value_t = 1000)]
     pub buffer_size: usize,
@@ -61,6 +75,15 @@ pub struct Config {
     /// Number of consumers in the consumer group to spawn
     #[clap(long, default_value_t = 1)]
     pub num_consumers: usize,
+    /// Where partitions without a committed offset for the consumer group
+    /// start consuming
+    #[clap(long, value_enum, default_value_t = AutoOffsetReset::Earliest)]
+    pub auto_offset_reset: AutoOffsetReset,
+    /// Consume only these partitions of the topic (comma-separated), assigned
+    /// directly instead of balanced across the consumer group. Offsets are
+    /// still committed to, and resumed from, the consumer group.
+    #[clap(long, value_delimiter = ',')]
+    pub partitions: Vec<i32>,
     /// Messages to fetch per Kafka poll into the apply window.
     ///
     /// The transform runtime then batches/windows by `--transforms-config`
@@ -69,6 +92,13 @@ pub struct Config {
     /// increase memory and potential duplicate processing on failure.
     #[clap(long, default_value_t = 100)]
     pub kafka_batch_size: usize,
+    /// Threads decoding the protobuf payloads of each poll in parallel.
+    ///
+    /// Messages keep their receive order (and so their per-partition offset
+    /// order), so offsets commit as with one decoder. Raise it when decoding
+    /// large or deeply nested messages keeps one core busy.
+    #[clap(long, default_value = "1", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub decode_workers: usize,
     /// Optional table name to use in SurrealDB (defaults to topic name)
     #[clap(long)]
     pub table_name: Option<String>,
@@ -83,6 +113,15 @@ pub struct Config {
     /// `id_field` when non-empty). Ignored when `use_message_key_as_id` is set.
     #[clap(long, value_delimiter = ',')]
     pub id_columns: Vec<String>,
+    /// Map a message header into a record field, as `HEADER[=FIELD][:TYPE]`
+    /// (repeatable). TYPE is string (default), int, float, bool, json, bytes
+    /// or uuid. A header field replaces a payload field of the same name and
+    /// may be used as (part of) the record ID.
+    #[clap(long = "header-field", value_name = "MAPPING")]
+    pub header_fields: Vec<HeaderField>,
+    /// What to do when a mapped header is absent from a message
+    #[clap(long, value_enum, default_value_t = MissingHeader::Skip)]
+    pub missing_header: MissingHeader,
     /// Maximum number of messages to process before exiting.
     /// When set, the sync will exit immediately after processing this many messages
     /// instead of waiting for the deadline. Useful for loadtest scenarios where
@@ -115,6 +154,68 @@ pub struct Config {
     pub ssl_key_password: Option<String>,
 }
 
+impl Config {
+    /// Check that protobuf payloads come with a schema and message type,
+    /// Avro payloads with a schema registry, and explicit partitions with a
+    /// single consumer.
+    pub fn validate(&self) -> Result<()> {
+        match self.payload_encoding {
+            PayloadEncoding::Protobuf => {
+                if self.proto_path.is_empty() {
+                    anyhow::bail!("--proto-path is required for protobuf payloads");
+                }
+                if self.message_type.is_empty() {
+                    anyhow::bail!("--message-type is required for protobuf payloads");
+                }
+            }
+            PayloadEncoding::Avro => {
+                if self.schema_registry_url.is_none() {
+                    anyhow::bail!("--schema-registry-url is required for Avro payloads");
+                }
+            }
+            PayloadEncoding::Json => {}
+        }
+        if !self.partitions.is_empty() && self.num_consumers > 1 {
+            anyhow::bail!(
+                "--partitions assigns the partitions to a single consumer; use --num-consumers 1"
+            );
+        }
+        Ok(())
+    }
+
+    /// Consumer settings for this source's topic and consumer group.
+    pub fn consumer_config(&self) -> ConsumerConfig {
+        ConsumerConfig {
+            brokers: self.brokers.join(","),
+            group_id: self.group_id.clone(),
+            topic: self.topic.clone(),
+            message_type: self.message_type.clone(),
+            payload_encoding: self.payload_encoding,
+            schema_registry_url: self.schema_registry_url.clone(),
+            buffer_size: self.buffer_size,
+            session_timeout_ms: self.session_timeout_ms.clone(),
+            auto_offset_reset: self.auto_offset_reset,
+            partitions: self.partitions.clone(),
+            sasl_username: self.sasl_username.clone(),
+            sasl_password: self.sasl_password.clone(),
+            sasl_mechanism: self.sasl_mechanism.clone(),
+            security_protocol: self.security_protocol.clone(),
+            ssl_ca_location: self.ssl_ca_location.clone(),
+            ssl_certificate_location: self.ssl_certificate_location.clone(),
+            ssl_key_location: self.ssl_key_location.clone(),
+            ssl_key_password: self.ssl_key_password.clone(),
+            decode_workers: self.decode_workers,
+            ..Default::default()
+        }
+    }
+}
+
+/// Check whether the consumer group's committed offsets (the position this
+/// source resumes from) are still within the topic's retention.
+pub async fn validate_checkpoint(config: &Config) -> Result<CheckpointValidity> {
+    Ok(validate_committed_offsets(&config.consumer_config(), Duration::from_secs(30)).await?)
+}
+
 /// Run incremental sync from Kafka to SurrealDB (identity transforms).
 ///
 /// The sync will run until the deadline is reached. Once the deadline passes,
@@ -153,6 +254,7 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink + Send + Sync +
     pipeline: &Pipeline,
     apply_opts: &ApplyOpts,
 ) -> Result<()> {
+    config.validate()?;
     let duration_until_deadline = deadline.signed_duration_since(Utc::now());
     info!(
         "Starting Kafka incremental sync for message {} from topic {} (deadline in {} seconds)",
@@ -176,25 +278,14 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink + Send + Sync +
         .clone()
         .unwrap_or_else(|| config.topic.clone());
 
-    let consumer_config: ConsumerConfig = ConsumerConfig {
-        brokers: config.brokers.join(","),
-        group_id: config.group_id,
-        topic: config.topic,
-        message_type: config.message_type,
-        buffer_size: config.buffer_size,
-        session_timeout_ms: config.session_timeout_ms,
-        sasl_username: config.sasl_username,
-        sasl_password: config.sasl_password,
-        sasl_mechanism: config.sasl_mechanism,
-        security_protocol: config.security_protocol,
-        ssl_ca_location: config.ssl_ca_location,
-        ssl_certificate_location: config.ssl_certificate_location,
-        ssl_key_location: config.ssl_key_location,
-        ssl_key_password: config.ssl_key_password,
-        ..Default::default()
-    };
+    let consumer_config = config.consumer_config();
 
-    let client = Client::from_proto_file(config.proto_path, consumer_config)?;
+    let client = match config.payload_encoding {
+        PayloadEncoding::Protobuf => Client::from_proto_file(config.proto_path, consumer_config)?,
+        PayloadEncoding::Json | PayloadEncoding::Avro => {
+            Client::without_proto_schema(consumer_config)
+        }
+    };
     info!(
         "Kafka client created successfully: schema={:?}",
         client.schema()
@@ -222,6 +313,8 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink + Send + Sync +
         let use_message_key_as_id = config.use_message_key_as_id;
         let id_field = config.id_field.clone();
         let id_columns = config.id_columns.clone();
+        let header_fields = config.header_fields.clone();
+        let missing_header = config.missing_header;
         let kafka_batch_size = config.kafka_batch_size;
         let handle = tokio::spawn(async move {
             let mut driver = KafkaSourceDriver {
@@ -231,6 +324,8 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink + Send + Sync +
                 use_message_key_as_id,
                 id_field,
                 id_columns,
+                header_fields,
+                missing_header,
                 kafka_batch_size,
                 pending_acks: VecDeque::new(),
                 ready_to_commit: Vec::new(),
@@ -298,6 +393,8 @@ struct KafkaSourceDriver {
     use_message_key_as_id: bool,
     id_field: String,
     id_columns: Vec<String>,
+    header_fields: Vec<HeaderField>,
+    missing_header: MissingHeader,
     kafka_batch_size: usize,
     /// Messages received but not yet noted as sunk (FIFO, poll order).
     pending_acks: VecDeque<Message>,
@@ -349,8 +446,12 @@ impl SourceDriver for KafkaSourceDriver {
         for (offset, message) in messages.into_iter().enumerate() {
             debug!("Received message: {:?}", message);
             let message_key = message.key.clone();
-            let typed_values =
+            let headers = header_field_values(&message, &self.header_fields, self.missing_header)?;
+            let mut typed_values =
                 crate::types::message_to_typed_values(message.clone(), self.table_schema.as_ref())?;
+            for (field, value) in headers {
+                typed_values.insert(field, value.to_typed_value());
+            }
             let row = typed_values_to_universal_row(
                 typed_values,
                 &self.table_name,
@@ -439,7 +540,7 @@ fn row_to_upsert_change(row: Row) -> Change {
 /// Either uses the message key (base64 encoded) as ID, or extracts the ID from
 /// one or more payload fields (`id_columns` when non-empty, else `id_field`).
 fn typed_values_to_universal_row(
-    mut typed_values: HashMap<String, TypedValue>,
+    mut typed_values: IndexMap<String, TypedValue>,
     table_name: &str,
     use_message_key_as_id: bool,
     message_key: Option<&[u8]>,
@@ -462,14 +563,14 @@ fn typed_values_to_universal_row(
         let mut parts = Vec::with_capacity(cols.len());
         for col in &cols {
             let id_typed_value = typed_values
-                .remove(*col)
+                .shift_remove(*col)
                 .ok_or_else(|| anyhow::anyhow!("Message has no '{col}' field"))?;
             parts.push(id_typed_value.value);
         }
         surreal_sync_core::build_composite_record_id(parts)
     };
 
-    let fields: HashMap<String, Value> = typed_values
+    let fields: FieldMap = typed_values
         .into_iter()
         .map(|(k, tv)| (k, tv.value))
         .collect();
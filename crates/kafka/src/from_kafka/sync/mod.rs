@@ -5,4 +5,6 @@
 
 mod incremental;
 
-pub use incremental::{run_incremental_sync, run_incremental_sync_with_transforms, Config};
+pub use incremental::{
+    run_incremental_sync, run_incremental_sync_with_transforms, validate_checkpoint, Config,
+};
@@ -55,6 +55,7 @@ use anyhow::{Context, Result};
 use protobuf::Message;
 use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, TopicReplication};
 use rdkafka::client::DefaultClientContext;
+use rdkafka::message::{Header, OwnedHeaders};
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use rdkafka::ClientConfig;
 use std::time::Duration;
@@ -295,6 +296,17 @@ impl KafkaTestProducer {
 
     /// Publish a user message to Kafka (protobuf encoded)
     pub async fn publish_user(&self, topic: &str, user: &UserMessage) -> Result<()> {
+        self.publish_user_with_headers(topic, user, &[]).await
+    }
+
+    /// Publish a user message to Kafka (protobuf encoded) carrying the given
+    /// message headers
+    pub async fn publish_user_with_headers(
+        &self,
+        topic: &str,
+        user: &UserMessage,
+        headers: &[(&str, &[u8])],
+    ) -> Result<()> {
         let mut proto_user = User::new();
         proto_user.id = user.id.clone();
         proto_user.account_balance = user.account_balance;
@@ -335,7 +347,17 @@ impl KafkaTestProducer {
             .context("Failed to encode user message")?;
         let key = user.id.as_bytes();
 
-        let record = FutureRecord::to(topic).key(key).payload(&payload);
+        let mut owned_headers = OwnedHeaders::new_with_capacity(headers.len());
+        for (header_key, value) in headers {
+            owned_headers = owned_headers.insert(Header {
+                key: header_key,
+                value: Some(*value),
+            });
+        }
+        let record = FutureRecord::to(topic)
+            .key(key)
+            .payload(&payload)
+            .headers(owned_headers);
 
         self.producer
             .send(record, Duration::from_secs(5))
@@ -426,6 +448,29 @@ impl KafkaTestProducer {
         );
         Ok(())
     }
+
+    /// Publish a JSON-encoded message to Kafka
+    pub async fn publish_json(
+        &self,
+        topic: &str,
+        key: &str,
+        value: &serde_json::Value,
+    ) -> Result<()> {
+        let payload = serde_json::to_vec(value).context("Failed to encode JSON message")?;
+
+        let record = FutureRecord::to(topic)
+            .key(key.as_bytes())
+            .payload(&payload);
+
+        self.producer
+            .send(record, Duration::from_secs(5))
+            .await
+            .map_err(|(err, _)| err)
+            .context("Failed to send JSON message to Kafka")?;
+
+        tracing::debug!("Published JSON message: {key}");
+        Ok(())
+    }
 }
 
 /// User message structure for Kafka
@@ -490,6 +535,33 @@ pub struct UserPostRelationMessage {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_connection_options_apply_security_settings() {
+        let options = KafkaConnectionOptions {
+            brokers: "broker:9093".to_string(),
+            security_protocol: Some(SecurityProtocol::SaslSsl),
+            sasl_mechanism: Some(SaslMechanism::Plain),
+            sasl_username: Some("user".to_string()),
+            sasl_password: Some("pass".to_string()),
+            ssl_ca_location: Some("/ca.pem".to_string()),
+            ..Default::default()
+        };
+        let mut config = ClientConfig::new();
+        options.apply_to_client_config(&mut config).unwrap();
+        assert_eq!(config.get("bootstrap.servers"), Some("broker:9093"));
+        assert_eq!(config.get("security.protocol"), Some("SASL_SSL"));
+        assert_eq!(config.get("sasl.mechanism"), Some("PLAIN"));
+        assert_eq!(config.get("sasl.username"), Some("user"));
+        assert_eq!(config.get("sasl.password"), Some("pass"));
+        assert_eq!(config.get("ssl.ca.location"), Some("/ca.pem"));
+
+        let mut config = ClientConfig::new();
+        KafkaConnectionOptions::plaintext("broker:9092")
+            .apply_to_client_config(&mut config)
+            .unwrap();
+        assert_eq!(config.get("security.protocol"), None);
+    }
+
     #[test]
     fn test_user_message_encoding() {
         let user = UserMessage {
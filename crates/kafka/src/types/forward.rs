@@ -491,7 +491,7 @@ mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
     use protobuf::CodedInputStream;
-    use surreal_sync_core::{FieldDefinition, GeneratorConfig, IDDefinition};
+    use surreal_sync_core::{FieldDefinition, FieldMap, GeneratorConfig, IDDefinition};
 
     fn test_table_schema() -> GeneratorTableDefinition {
         GeneratorTableDefinition {
@@ -508,18 +508,27 @@ mod tests {
                         pattern: "user_{index}@test.com".to_string(),
                     },
                     nullable: false,
+                    nullable_elements: false,
+                    indexed: false,
+                    constraints: Default::default(),
                 },
                 FieldDefinition {
                     name: "age".to_string(),
                     field_type: Type::Int32,
                     generator: GeneratorConfig::IntRange { min: 18, max: 80 },
                     nullable: false,
+                    nullable_elements: false,
+                    indexed: false,
+                    constraints: Default::default(),
                 },
                 FieldDefinition {
                     name: "is_active".to_string(),
                     field_type: Type::Bool,
                     generator: GeneratorConfig::WeightedBool { true_weight: 0.8 },
                     nullable: false,
+                    nullable_elements: false,
+                    indexed: false,
+                    constraints: Default::default(),
                 },
             ],
         }
@@ -528,7 +537,7 @@ mod tests {
     #[test]
     fn test_encode_simple_row() {
         let schema = test_table_schema();
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert(
             "email".to_string(),
             Value::Text("test@example.com".to_string()),
@@ -567,11 +576,14 @@ mod tests {
                     end: "2024-12-31T23:59:59Z".to_string(),
                 },
                 nullable: false,
+                nullable_elements: false,
+                indexed: false,
+                constraints: Default::default(),
             }],
         };
 
         let dt = Utc.with_ymd_and_hms(2024, 6, 15, 12, 30, 45).unwrap();
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert("created_at".to_string(), Value::LocalDateTime(dt));
 
         let row = Row::new("events", 0, Value::Int64(1), fields);
@@ -598,10 +610,13 @@ mod tests {
                     max_length: 3,
                 },
                 nullable: false,
+                nullable_elements: false,
+                indexed: false,
+                constraints: Default::default(),
             }],
         };
 
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert(
             "tags".to_string(),
             Value::Array {
@@ -620,7 +635,7 @@ mod tests {
 
     #[test]
     fn test_get_message_key_int() {
-        let row = Row::new("test", 0, Value::Int64(42), HashMap::new());
+        let row = Row::new("test", 0, Value::Int64(42), FieldMap::new());
         let key = get_message_key(&row);
         assert_eq!(key, b"42");
     }
@@ -628,7 +643,7 @@ mod tests {
     #[test]
     fn test_get_message_key_uuid() {
         let uuid = uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let row = Row::new("test", 0, Value::Uuid(uuid), HashMap::new());
+        let row = Row::new("test", 0, Value::Uuid(uuid), FieldMap::new());
         let key = get_message_key(&row);
         assert_eq!(
             String::from_utf8(key).unwrap(),
@@ -638,7 +653,12 @@ mod tests {
 
     #[test]
     fn test_get_message_key_string() {
-        let row = Row::new("test", 0, Value::Text("my-key".to_string()), HashMap::new());
+        let row = Row::new(
+            "test",
+            0,
+            Value::Text("my-key".to_string()),
+            FieldMap::new(),
+        );
         let key = get_message_key(&row);
         assert_eq!(key, b"my-key");
     }
@@ -29,14 +29,19 @@ pub struct Message {
     pub key: Option<Vec<u8>>,
     /// Message timestamp in milliseconds since epoch (if available)
     pub timestamp: Option<i64>,
+    /// Message headers in wire order; a header may have a null value
+    pub headers: Vec<(String, Option<Vec<u8>>)>,
 }
 
 /// Message payload variants.
 ///
-/// Currently only supports protobuf-encoded payloads, but designed
-/// for extensibility to other formats (Avro, JSON, etc.) if needed.
+/// Designed for extensibility to other formats if needed.
 #[derive(Debug, Clone)]
 pub enum Payload {
     /// Protobuf-encoded message
     Protobuf(ProtoMessage),
+    /// JSON-encoded message: a JSON object whose members are the fields
+    Json(serde_json::Map<String, serde_json::Value>),
+    /// Avro record decoded with its writer schema, as protobuf-style values
+    Avro(ProtoMessage),
 }
@@ -6,9 +6,11 @@
 use crate::types::error::{KafkaTypesError, Result};
 use crate::types::proto::{ProtoFieldValue, ProtoType};
 use crate::types::{Message, Payload};
-use std::collections::HashMap;
+use indexmap::IndexMap;
 use surreal_sync_core::{ColumnDefinition, TableDefinition, Type, TypedValue, Value};
-use surreal_sync_json::types::JsonValueWithSchema;
+use surreal_sync_json::types::{
+    json_to_generated_value_with_config, JsonConversionConfig, JsonValueWithSchema,
+};
 use tracing::debug;
 
 /// Convert a Kafka message to TypedValue key-value pairs.
@@ -20,11 +22,17 @@ use tracing::debug;
 /// - JSON/Object fields encoded as strings are parsed and converted to Objects
 /// - Missing array fields are filled with empty arrays
 /// - Type information is preserved from the schema
+/// - Fields are returned in proto definition order, so rows built from them
+///   keep a stable field order
+///
+/// JSON payloads convert schema columns by their declared type and other
+/// fields by their JSON shape. Avro payloads convert like protobuf fields but
+/// carry every field of their writer schema, so no proto3 defaults apply.
 pub fn message_to_typed_values(
     message: Message,
     table_schema: Option<&TableDefinition>,
-) -> Result<HashMap<String, TypedValue>> {
-    let mut kvs = HashMap::new();
+) -> Result<IndexMap<String, TypedValue>> {
+    let mut kvs = IndexMap::new();
 
     match message.payload {
         Payload::Protobuf(msg) => {
@@ -124,6 +132,41 @@ pub fn message_to_typed_values(
                     }
                 }
             }
+
+            // Decoded fields and defaults were added in no particular order;
+            // order them as declared in the proto (unknown fields last).
+            let field_order = &msg.descriptor.field_order;
+            kvs.sort_by_cached_key(|key, _| {
+                field_order
+                    .iter()
+                    .position(|f| f == key)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+        Payload::Avro(msg) => {
+            let mut fields = msg.fields;
+            for key in msg.descriptor.field_order {
+                if let Some(value) = fields.remove(&key) {
+                    let column_schema = table_schema.and_then(|ts| ts.get_column(&key));
+                    let typed_value = proto_to_typed_value_with_schema(value, column_schema)?;
+                    kvs.insert(key, typed_value);
+                }
+            }
+        }
+        Payload::Json(object) => {
+            // Columns of the table schema convert to their declared type;
+            // other fields by JSON shape.
+            let config = JsonConversionConfig::default();
+            for (key, value) in object {
+                let typed_value =
+                    match table_schema.and_then(|ts| ts.get_column(&key)) {
+                        Some(column) => JsonValueWithSchema::new(value, column.column_type.clone())
+                            .to_typed_value(),
+                        None => json_to_generated_value_with_config(value, &key, &config)
+                            .to_typed_value(),
+                    };
+                kvs.insert(key, typed_value);
+            }
         }
     }
 
@@ -348,7 +391,8 @@ fn universal_value_to_json(value: &Value) -> serde_json::Value {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::proto::{ProtoMessage, ProtoMessageDescriptor};
+    use crate::types::proto::{ProtoFieldDescriptor, ProtoMessage, ProtoMessageDescriptor};
+    use std::collections::HashMap;
 
     fn empty_descriptor() -> ProtoMessageDescriptor {
         ProtoMessageDescriptor {
@@ -419,6 +463,148 @@ mod tests {
         assert!(matches!(result.value, Value::Array { ref elements, .. } if elements.len() == 2));
     }
 
+    #[test]
+    fn test_message_fields_follow_proto_definition_order() {
+        let field = |name: &str, number: i32, field_type: ProtoType| ProtoFieldDescriptor {
+            name: name.to_string(),
+            number,
+            field_type,
+            is_repeated: false,
+            is_optional: false,
+        };
+        let order = ["id", "name", "active", "score"];
+        let descriptor = ProtoMessageDescriptor {
+            name: "User".to_string(),
+            fields: HashMap::from([
+                ("id".to_string(), field("id", 1, ProtoType::Int64)),
+                ("name".to_string(), field("name", 2, ProtoType::String)),
+                ("active".to_string(), field("active", 3, ProtoType::Bool)),
+                ("score".to_string(), field("score", 4, ProtoType::Double)),
+            ]),
+            field_order: order.iter().map(|f| f.to_string()).collect(),
+        };
+        // `active` is omitted on the wire (Proto3 default) and filled in.
+        let fields = HashMap::from([
+            ("score".to_string(), ProtoFieldValue::Double(1.5)),
+            ("name".to_string(), ProtoFieldValue::String("a".to_string())),
+            ("id".to_string(), ProtoFieldValue::Int64(1)),
+        ]);
+        let message = Message {
+            payload: Payload::Protobuf(ProtoMessage {
+                message_type: "User".to_string(),
+                fields,
+                descriptor,
+            }),
+            topic: "users".to_string(),
+            partition: 0,
+            offset: 0,
+            key: None,
+            timestamp: None,
+            headers: Vec::new(),
+        };
+
+        let kvs = message_to_typed_values(message, None).unwrap();
+        assert_eq!(kvs.keys().collect::<Vec<_>>(), order);
+    }
+
+    #[test]
+    fn test_json_message_to_typed_values() {
+        let serde_json::Value::Object(object) = serde_json::json!({
+            "id": "user_001",
+            "age": 30,
+            "created_at": "2024-01-02T03:04:05Z",
+            "tags": ["a", "b"],
+        }) else {
+            unreachable!()
+        };
+        let message = Message {
+            payload: Payload::Json(object),
+            topic: "users".to_string(),
+            partition: 0,
+            offset: 0,
+            key: None,
+            timestamp: None,
+            headers: Vec::new(),
+        };
+        let schema = TableDefinition::new(
+            "users",
+            ColumnDefinition::new("id", Type::Text),
+            vec![ColumnDefinition::nullable(
+                "created_at",
+                Type::ZonedDateTime,
+            )],
+        );
+
+        let kvs = message_to_typed_values(message, Some(&schema)).unwrap();
+        assert_eq!(kvs["id"].value, Value::Text("user_001".to_string()));
+        assert_eq!(kvs["age"].value, Value::Int64(30));
+        assert!(matches!(kvs["created_at"].value, Value::ZonedDateTime(_)));
+        assert!(matches!(kvs["tags"].value, Value::Array { .. }));
+    }
+
+    #[test]
+    fn test_avro_message_to_typed_values() {
+        let field = |name: &str, number, field_type| ProtoFieldDescriptor {
+            name: name.to_string(),
+            number,
+            field_type,
+            is_repeated: false,
+            is_optional: false,
+        };
+        let message = Message {
+            payload: Payload::Avro(ProtoMessage {
+                message_type: "User".to_string(),
+                fields: HashMap::from([
+                    ("id".to_string(), ProtoFieldValue::Int64(7)),
+                    ("active".to_string(), ProtoFieldValue::Null),
+                    (
+                        "balance".to_string(),
+                        ProtoFieldValue::String("5.00".to_string()),
+                    ),
+                ]),
+                descriptor: ProtoMessageDescriptor {
+                    name: "User".to_string(),
+                    fields: HashMap::from([
+                        ("id".to_string(), field("id", 1, ProtoType::Int64)),
+                        ("active".to_string(), field("active", 2, ProtoType::Bool)),
+                        (
+                            "balance".to_string(),
+                            field("balance", 3, ProtoType::String),
+                        ),
+                    ]),
+                    field_order: vec![
+                        "id".to_string(),
+                        "active".to_string(),
+                        "balance".to_string(),
+                    ],
+                },
+            }),
+            topic: "users".to_string(),
+            partition: 0,
+            offset: 0,
+            key: None,
+            timestamp: None,
+            headers: Vec::new(),
+        };
+        let schema = TableDefinition::new(
+            "users",
+            ColumnDefinition::new("id", Type::Int64),
+            vec![ColumnDefinition::nullable(
+                "balance",
+                Type::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+            )],
+        );
+
+        let kvs = message_to_typed_values(message, Some(&schema)).unwrap();
+        assert_eq!(kvs.keys().collect::<Vec<_>>(), ["id", "active", "balance"]);
+        // A null Avro field stays null rather than taking a proto3 default
+        assert!(matches!(kvs["active"].value, Value::Null));
+        assert!(matches!(kvs["balance"].value, Value::Decimal { .. }));
+    }
+
     #[test]
     fn test_proto_to_typed_value_null() {
         let value = ProtoFieldValue::Null;
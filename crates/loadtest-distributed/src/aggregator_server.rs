@@ -2,6 +2,13 @@
 //!
 //! This module provides an HTTP server that collects ContainerMetrics from
 //! distributed containers, eliminating the need for shared volumes (ReadWriteMany).
+//!
+//! Endpoints:
+//! - `POST /metrics`: submit one container's `ContainerMetrics`
+//! - `GET /metrics/aggregate`: the aggregate of everything received so far,
+//!   with completion status
+//! - `GET /status`: received vs expected container count
+//! - `GET /health`: liveness
 
 use crate::aggregate_results_from_vec;
 use crate::cli::AggregateServerArgs;
@@ -10,6 +17,8 @@ use anyhow::{Context, Result};
 use serde::Serialize;
 use std::io::{BufRead, BufReader, Read, Write};
 use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -38,6 +47,13 @@ enum AggregatorEvent<'a> {
         expected: usize,
         timeout_seconds: u64,
     },
+    ShutdownRequested {
+        received: usize,
+        expected: usize,
+    },
+    OutputWritten {
+        path: String,
+    },
     ConnectionError {
         addr: String,
         error: String,
@@ -83,6 +99,84 @@ impl CollectedMetrics {
     fn is_complete(&self) -> bool {
         self.containers.len() >= self.expected_count
     }
+
+    fn snapshot(&self) -> AggregateSnapshot {
+        AggregateSnapshot {
+            received: self.count(),
+            expected: self.expected_count,
+            complete: self.is_complete(),
+            report: aggregate_results_from_vec(self.containers.clone()),
+        }
+    }
+}
+
+/// Aggregate of the metrics received so far, as served by
+/// `GET /metrics/aggregate` and written to `--output-file`.
+#[derive(Serialize)]
+struct AggregateSnapshot {
+    received: usize,
+    expected: usize,
+    complete: bool,
+    report: AggregatedReport,
+}
+
+/// Write the snapshot as pretty JSON to `path`.
+fn write_output_file(path: &Path, snapshot: &AggregateSnapshot) -> Result<()> {
+    let json = serde_json::to_string_pretty(snapshot)?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed to write aggregate to {}", path.display()))?;
+    log_jsonl(&AggregatorEvent::OutputWritten {
+        path: path.display().to_string(),
+    });
+    Ok(())
+}
+
+/// Log the final report and, if requested, persist the snapshot to disk.
+fn finish(collected: &CollectedMetrics, output_file: Option<&Path>) -> Result<()> {
+    let snapshot = collected.snapshot();
+    if !collected.containers.is_empty() {
+        log_jsonl(&AggregatorEvent::FinalReport {
+            report: &snapshot.report,
+        });
+    }
+    match output_file {
+        Some(path) => write_output_file(path, &snapshot),
+        None => Ok(()),
+    }
+}
+
+/// Flag that flips once SIGINT or SIGTERM is received.
+fn install_shutdown_flag() -> Arc<AtomicBool> {
+    let flag = Arc::new(AtomicBool::new(false));
+    let set = flag.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        set.store(true, Ordering::SeqCst);
+    });
+    flag
+}
+
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut sigterm =
+            match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+                Ok(s) => s,
+                Err(_) => {
+                    let _ = tokio::signal::ctrl_c().await;
+                    return;
+                }
+            };
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = sigterm.recv() => {}
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
 }
 
 /// Run the aggregator HTTP server.
@@ -105,6 +199,8 @@ pub async fn run_aggregator_server(args: AggregateServerArgs) -> Result<()> {
         .context("Failed to set non-blocking mode")?;
 
     let start = Instant::now();
+    let shutdown = install_shutdown_flag();
+    let output_file = args.output_file.as_deref();
 
     loop {
         // Check if we've collected all expected metrics
@@ -114,12 +210,20 @@ pub async fn run_aggregator_server(args: AggregateServerArgs) -> Result<()> {
                 log_jsonl(&AggregatorEvent::AllMetricsCollected {
                     count: args.expected_containers,
                 });
-                let report = aggregate_results_from_vec(collected.containers.clone());
-                log_jsonl(&AggregatorEvent::FinalReport { report: &report });
-                return Ok(());
+                return finish(&collected, output_file);
             }
         }
 
+        // Stop early on SIGINT/SIGTERM, keeping whatever was received
+        if shutdown.load(Ordering::SeqCst) {
+            let collected = metrics.lock().unwrap();
+            log_jsonl(&AggregatorEvent::ShutdownRequested {
+                received: collected.count(),
+                expected: args.expected_containers,
+            });
+            return finish(&collected, output_file);
+        }
+
         // Check timeout
         if start.elapsed() > timeout {
             let collected = metrics.lock().unwrap();
@@ -128,10 +232,7 @@ pub async fn run_aggregator_server(args: AggregateServerArgs) -> Result<()> {
                 expected: args.expected_containers,
                 timeout_seconds: timeout.as_secs(),
             });
-            if !collected.containers.is_empty() {
-                let report = aggregate_results_from_vec(collected.containers.clone());
-                log_jsonl(&AggregatorEvent::FinalReport { report: &report });
-            }
+            finish(&collected, output_file)?;
             return Err(anyhow::anyhow!(
                 "Timeout: only {}/{} containers reported",
                 collected.count(),
@@ -154,7 +255,7 @@ pub async fn run_aggregator_server(args: AggregateServerArgs) -> Result<()> {
             }
             Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
                 // No connection ready, sleep briefly
-                std::thread::sleep(Duration::from_millis(100));
+                tokio::time::sleep(Duration::from_millis(100)).await;
             }
             Err(e) => {
                 log_jsonl(&AggregatorEvent::ConnectionError {
@@ -218,6 +319,9 @@ fn handle_connection(mut stream: TcpStream, metrics: &Arc<Mutex<CollectedMetrics
                 )
             }
         }
+    } else if request_line.starts_with("GET /metrics/aggregate") {
+        let snapshot = metrics.lock().unwrap().snapshot();
+        ("200 OK", serde_json::to_string(&snapshot)?)
     } else if request_line.starts_with("GET /health") {
         ("200 OK", r#"{"status":"healthy"}"#.to_string())
     } else if request_line.starts_with("GET /status") {
@@ -282,6 +386,7 @@ fn parse_duration(s: &str) -> Result<Duration> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::metrics::PopulateMetrics;
 
     #[test]
     fn test_parse_duration() {
@@ -310,6 +415,90 @@ mod tests {
         assert_eq!(collected.count(), 2);
     }
 
+    /// Serve one raw HTTP request through `handle_connection` and return the
+    /// response body.
+    fn send(metrics: &Arc<Mutex<CollectedMetrics>>, request: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+        let (stream, _) = listener.accept().unwrap();
+        handle_connection(stream, metrics).unwrap();
+        let response = client.join().unwrap();
+        response.split("\r\n\r\n").nth(1).unwrap().to_string()
+    }
+
+    fn post_metrics(metrics: &Arc<Mutex<CollectedMetrics>>, container: &ContainerMetrics) {
+        let body = serde_json::to_string(container).unwrap();
+        let request = format!(
+            "POST /metrics HTTP/1.1\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        assert_eq!(send(metrics, request), r#"{"status":"ok"}"#);
+    }
+
+    fn get_aggregate(metrics: &Arc<Mutex<CollectedMetrics>>) -> serde_json::Value {
+        let body = send(
+            metrics,
+            "GET /metrics/aggregate HTTP/1.1\r\n\r\n".to_string(),
+        );
+        serde_json::from_str(&body).unwrap()
+    }
+
+    fn with_rows(id: &str, rows: u64, rows_per_second: f64) -> ContainerMetrics {
+        let mut metrics = create_test_metrics(id);
+        metrics.metrics = Some(PopulateMetrics {
+            rows_processed: rows,
+            duration_ms: 1000,
+            batch_count: 1,
+            rows_per_second,
+            bytes_written: None,
+        });
+        metrics
+    }
+
+    #[test]
+    fn test_aggregate_endpoint_sums_workers() {
+        let metrics = Arc::new(Mutex::new(CollectedMetrics::new(3)));
+
+        post_metrics(&metrics, &with_rows("populate-1", 100, 50.0));
+        post_metrics(&metrics, &with_rows("populate-2", 250, 125.0));
+
+        let aggregate = get_aggregate(&metrics);
+        assert_eq!(aggregate["received"], 2);
+        assert_eq!(aggregate["expected"], 3);
+        assert_eq!(aggregate["complete"], false);
+        assert_eq!(aggregate["report"]["total_containers"], 2);
+        assert_eq!(aggregate["report"]["total_rows_populated"], 350);
+        assert_eq!(aggregate["report"]["aggregate_rows_per_second"], 175.0);
+
+        post_metrics(&metrics, &with_rows("populate-3", 50, 25.0));
+        let aggregate = get_aggregate(&metrics);
+        assert_eq!(aggregate["complete"], true);
+        assert_eq!(aggregate["report"]["total_rows_populated"], 400);
+    }
+
+    #[test]
+    fn test_write_output_file() {
+        let mut collected = CollectedMetrics::new(2);
+        collected.add(with_rows("populate-1", 100, 50.0));
+        collected.add(with_rows("populate-2", 250, 125.0));
+
+        let path = std::env::temp_dir().join(format!("aggregate-{}.json", uuid::Uuid::new_v4()));
+        finish(&collected, Some(&path)).unwrap();
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(written["complete"], true);
+        assert_eq!(written["report"]["total_rows_populated"], 350);
+    }
+
     fn create_test_metrics(id: &str) -> ContainerMetrics {
         use crate::metrics::EnvironmentInfo;
         use crate::metrics::Operation;
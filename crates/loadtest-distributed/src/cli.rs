@@ -18,7 +18,7 @@ pub struct Cli {
 /// Available commands.
 #[derive(Subcommand)]
 pub enum Commands {
-    /// Generate Docker Compose or Kubernetes configurations
+    /// Generate Docker Compose, Kubernetes or Nomad configurations
     Generate(GenerateArgs),
 
     /// Generate and run (Docker Compose only)
@@ -41,6 +41,8 @@ pub enum OutputFormat {
 pub enum PlatformChoice {
     DockerCompose,
     Kubernetes,
+    Nomad,
+    /// Docker Compose and Kubernetes
     Both,
 }
 
@@ -49,6 +51,7 @@ impl PlatformChoice {
         match self {
             PlatformChoice::DockerCompose => vec![Platform::DockerCompose],
             PlatformChoice::Kubernetes => vec![Platform::Kubernetes],
+            PlatformChoice::Nomad => vec![Platform::Nomad],
             PlatformChoice::Both => vec![Platform::DockerCompose, Platform::Kubernetes],
         }
     }
@@ -231,4 +234,9 @@ pub struct AggregateServerArgs {
     /// Output format for the final report
     #[arg(long, short = 'f', default_value = "table")]
     pub output_format: OutputFormat,
+
+    /// Write the aggregate as JSON to this file once all expected containers
+    /// have reported, or on timeout or shutdown with whatever was received
+    #[arg(long)]
+    pub output_file: Option<PathBuf>,
 }
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 pub enum Platform {
     DockerCompose,
     Kubernetes,
+    Nomad,
 }
 
 impl std::fmt::Display for Platform {
@@ -15,6 +16,7 @@ impl std::fmt::Display for Platform {
         match self {
             Platform::DockerCompose => write!(f, "docker-compose"),
             Platform::Kubernetes => write!(f, "kubernetes"),
+            Platform::Nomad => write!(f, "nomad"),
         }
     }
 }
@@ -422,7 +424,7 @@ fn get_default_connection_string(source_type: SourceType, platform: Platform) ->
             // Kubernetes uses headless services, so we need the pod-specific DNS name
             match platform {
                 Platform::Kubernetes => "kafka-0.kafka:9092".to_string(),
-                Platform::DockerCompose => "kafka:9092".to_string(),
+                Platform::DockerCompose | Platform::Nomad => "kafka:9092".to_string(),
             }
         }
         SourceType::Csv => "/data/csv".to_string(),
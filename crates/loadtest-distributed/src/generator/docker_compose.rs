@@ -15,7 +15,7 @@
 //! - `csv_jsonl`: CSV and JSONL file import (per-file)
 //! - `common`: Shared helper functions
 
-mod common;
+pub(super) mod common;
 mod csv_jsonl;
 mod kafka;
 mod mongodb;
@@ -686,7 +686,7 @@ fn generate_surrealdb_service(config: &ClusterConfig) -> Value {
 }
 
 /// Generate populate service configuration.
-fn generate_populate_service(
+pub(super) fn generate_populate_service(
     config: &ClusterConfig,
     container: &crate::config::ContainerConfig,
 ) -> Value {
@@ -1,10 +1,11 @@
 //! Configuration generators for distributed load testing.
 //!
-//! This module provides generators for Docker Compose and Kubernetes configurations.
+//! This module provides generators for Docker Compose, Kubernetes and Nomad configurations.
 
 pub mod databases;
 pub mod docker_compose;
 pub mod kubernetes;
+pub mod nomad;
 
 use crate::config::ClusterConfig;
 use anyhow::Result;
@@ -20,3 +21,4 @@ pub trait ConfigGenerator {
 
 pub use docker_compose::DockerComposeGenerator;
 pub use kubernetes::KubernetesGenerator;
+pub use nomad::NomadGenerator;
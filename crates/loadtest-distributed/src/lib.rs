@@ -1,11 +1,11 @@
 //! Distributed load testing for surreal-sync.
 //!
 //! This crate provides container-based distributed load testing capabilities
-//! for surreal-sync, supporting both Docker Compose and Kubernetes deployments.
+//! for surreal-sync, supporting Docker Compose, Kubernetes and Nomad deployments.
 //!
 //! ## Features
 //!
-//! - **Docker Compose & Kubernetes**: Generate configurations for both platforms
+//! - **Docker Compose, Kubernetes & Nomad**: Generate configurations for each platform
 //! - **Resource Limits**: Configure CPU and memory limits per container
 //! - **tmpfs Support**: Use memory-backed storage to eliminate I/O bottlenecks
 //! - **Multiple Databases**: Support for MySQL, PostgreSQL, MongoDB, Neo4j, Kafka, CSV, JSONL
@@ -3,8 +3,7 @@
 use crate::generators::generate_value_typed;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
-use std::collections::HashMap;
-use surreal_sync_core::{Row, Schema, Value};
+use surreal_sync_core::{FieldMap, Row, Schema};
 
 /// Error type for generator operations.
 #[derive(Debug, thiserror::Error)]
@@ -94,7 +93,7 @@ impl DataGenerator {
         );
 
         // Generate all fields with type-aware generation
-        let fields: HashMap<String, Value> = table_schema
+        let fields: FieldMap = table_schema
             .fields
             .iter()
             .map(|field| {
@@ -172,7 +171,7 @@ impl ExactSizeIterator for RowIterator<'_> {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use surreal_sync_core::Schema;
+    use surreal_sync_core::{Schema, Value};
 
     fn test_schema() -> Schema {
         let yaml = r#"
@@ -5,6 +5,7 @@
 
 pub mod array;
 pub mod numeric;
+pub mod one_of;
 pub mod pattern;
 pub mod static_value;
 pub mod timestamp;
@@ -62,13 +63,8 @@ pub fn generate_value_typed<R: RngExt>(
 
         GeneratorConfig::WeightedBool { true_weight } => Value::Bool(rng.random_bool(*true_weight)),
 
-        GeneratorConfig::OneOf { values } => {
-            if values.is_empty() {
-                Value::Null
-            } else {
-                let idx = rng.random_range(0..values.len());
-                static_value::yaml_to_generated_value(&values[idx])
-            }
+        GeneratorConfig::OneOf { values, weights } => {
+            one_of::generate_one_of(rng, values, weights.as_deref())
         }
 
         GeneratorConfig::SampleArray {
@@ -0,0 +1,97 @@
+//! Selection from a pool of values.
+
+use super::static_value::yaml_to_generated_value;
+use rand::RngExt;
+use serde_yaml::Value as YamlValue;
+use surreal_sync_core::Value;
+
+/// Pick one of `values`, uniformly or by the parallel relative `weights`.
+///
+/// Weights are expected to be validated at schema load (see
+/// `GeneratorConfig::check`). An empty pool generates null.
+pub fn generate_one_of<R: RngExt>(
+    rng: &mut R,
+    values: &[YamlValue],
+    weights: Option<&[f64]>,
+) -> Value {
+    if values.is_empty() {
+        return Value::Null;
+    }
+    let idx = match weights {
+        Some(weights) => weighted_index(rng, weights),
+        None => rng.random_range(0..values.len()),
+    };
+    yaml_to_generated_value(&values[idx])
+}
+
+/// Index drawn with probability proportional to its weight.
+fn weighted_index<R: RngExt>(rng: &mut R, weights: &[f64]) -> usize {
+    let total: f64 = weights.iter().sum();
+    let mut target = rng.random_range(0.0..total);
+    for (idx, weight) in weights.iter().enumerate() {
+        if target < *weight {
+            return idx;
+        }
+        target -= weight;
+    }
+    // Rounding can leave `target` just above the last cumulative bound
+    weights
+        .iter()
+        .rposition(|w| *w > 0.0)
+        .expect("weights are not all zero")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn pool() -> Vec<YamlValue> {
+        ["active", "pending", "banned"]
+            .into_iter()
+            .map(|s| YamlValue::String(s.to_string()))
+            .collect()
+    }
+
+    fn sequence(seed: u64, weights: Option<&[f64]>) -> Vec<Value> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let values = pool();
+        (0..200)
+            .map(|_| generate_one_of(&mut rng, &values, weights))
+            .collect()
+    }
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let weights = [80.0, 15.0, 5.0];
+        assert_eq!(sequence(42, Some(&weights)), sequence(42, Some(&weights)));
+        assert_eq!(sequence(42, None), sequence(42, None));
+        assert_ne!(sequence(42, Some(&weights)), sequence(43, Some(&weights)));
+    }
+
+    #[test]
+    fn test_weighted_distribution() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let weights = [80.0, 15.0, 5.0];
+        let mut counts = [0usize; 3];
+        for _ in 0..10_000 {
+            counts[weighted_index(&mut rng, &weights)] += 1;
+        }
+        assert!((7_500..8_500).contains(&counts[0]), "{counts:?}");
+        assert!((1_200..1_800).contains(&counts[1]), "{counts:?}");
+        assert!((300..700).contains(&counts[2]), "{counts:?}");
+    }
+
+    #[test]
+    fn test_zero_weight_never_selected() {
+        let values = sequence(1, Some(&[1.0, 0.0, 1.0]));
+        assert!(!values.contains(&Value::Text("pending".to_string())));
+    }
+
+    #[test]
+    fn test_empty_pool_is_null() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(generate_one_of(&mut rng, &[], None), Value::Null);
+    }
+}
@@ -62,7 +62,7 @@
 //! - `decimal_range` - Random decimals in a range
 //! - `timestamp_range` - Random timestamps in a date range
 //! - `weighted_bool` - Boolean with configurable true probability
-//! - `one_of` - Random selection from a list
+//! - `one_of` - Random selection from a list, optionally by `weights`
 //! - `sample_array` - Array of random samples from a pool
 //! - `static` - Static value
 //! - `null` - Null value
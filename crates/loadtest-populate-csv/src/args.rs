@@ -2,6 +2,7 @@
 
 use clap::Args;
 use std::path::PathBuf;
+use surreal_sync_csv::CsvDialect;
 
 // Re-export CommonPopulateArgs for convenience
 pub use loadtest_populate::CommonPopulateArgs;
@@ -13,6 +14,29 @@ pub struct CSVPopulateArgs {
     #[arg(long, short = 'o')]
     pub output_dir: PathBuf,
 
+    /// Field delimiter of the output files
+    #[arg(long, default_value = ",")]
+    pub delimiter: char,
+
+    /// Quote character of the output files
+    #[arg(long, default_value = "\"")]
+    pub quote: char,
+
+    /// Write files without a header row
+    #[arg(long)]
+    pub no_header: bool,
+
     #[command(flatten)]
     pub common: CommonPopulateArgs,
 }
+
+impl CSVPopulateArgs {
+    /// Dialect the output files are written in.
+    pub fn dialect(&self) -> CsvDialect {
+        CsvDialect {
+            delimiter: self.delimiter as u8,
+            quote: self.quote as u8,
+            has_headers: !self.no_header,
+        }
+    }
+}
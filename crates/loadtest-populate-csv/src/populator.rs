@@ -1,7 +1,7 @@
 //! CSV populator for load testing.
 
 use crate::error::CSVPopulatorError;
-use csv::Writer;
+use csv::{Writer, WriterBuilder};
 use loadtest_generator::DataGenerator;
 use std::fs::File;
 use std::io::BufWriter;
@@ -9,6 +9,7 @@ use std::path::Path;
 use std::time::{Duration, Instant};
 use surreal_sync_core::{GeneratorTableDefinition, Row, Schema, TypedValue};
 use surreal_sync_csv::types::CsvValue;
+use surreal_sync_csv::CsvDialect;
 use tracing::{debug, info};
 
 /// Default buffer size for CSV writing.
@@ -64,7 +65,7 @@ impl PopulateMetrics {
 pub struct CSVPopulator {
     schema: Schema,
     generator: DataGenerator,
-    include_header: bool,
+    dialect: CsvDialect,
 }
 
 impl CSVPopulator {
@@ -85,13 +86,20 @@ impl CSVPopulator {
         Self {
             schema,
             generator,
-            include_header: true,
+            dialect: CsvDialect::default(),
         }
     }
 
     /// Set whether to include a header row in the CSV output.
     pub fn with_header(mut self, include_header: bool) -> Self {
-        self.include_header = include_header;
+        self.dialect.has_headers = include_header;
+        self
+    }
+
+    /// Set the delimiter, quote character and header row of the output, so
+    /// it can be imported with the same dialect.
+    pub fn with_dialect(mut self, dialect: CsvDialect) -> Self {
+        self.dialect = dialect;
         self
     }
 
@@ -152,13 +160,13 @@ impl CSVPopulator {
         // Create writer
         let file = File::create(output_path)?;
         let buf_writer = BufWriter::with_capacity(DEFAULT_BUFFER_SIZE, file);
-        let mut writer = Writer::from_writer(buf_writer);
+        let mut writer = self.writer(buf_writer);
 
         let mut generation_time = Duration::ZERO;
         let mut write_time = Duration::ZERO;
 
         // Write header if requested
-        if self.include_header {
+        if self.dialect.has_headers {
             let write_start = Instant::now();
             let headers = get_column_names(&table_schema);
             writer.write_record(&headers)?;
@@ -211,6 +219,13 @@ impl CSVPopulator {
         Ok(metrics)
     }
 
+    fn writer<W: std::io::Write>(&self, inner: W) -> Writer<W> {
+        WriterBuilder::new()
+            .delimiter(self.dialect.delimiter)
+            .quote(self.dialect.quote)
+            .from_writer(inner)
+    }
+
     /// Append rows to an existing CSV file.
     ///
     /// This method continues from the current generator index, useful for
@@ -244,7 +259,7 @@ impl CSVPopulator {
             .append(true)
             .open(output_path)?;
         let buf_writer = BufWriter::with_capacity(DEFAULT_BUFFER_SIZE, file);
-        let mut writer = Writer::from_writer(buf_writer);
+        let mut writer = self.writer(buf_writer);
 
         let mut generation_time = Duration::ZERO;
         let mut write_time = Duration::ZERO;
@@ -446,6 +461,41 @@ tables:
         assert_eq!(lines.len(), 10); // No header, just 10 data rows
     }
 
+    #[test]
+    fn test_populate_tsv() {
+        let schema = test_schema();
+        let mut populator = CSVPopulator::new(schema, 42).with_dialect(CsvDialect::tsv());
+
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("test.tsv");
+        populator.populate("users", &output_path, 3).unwrap();
+
+        let content = std::fs::read_to_string(&output_path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "id\temail\tage");
+        assert_eq!(lines[1].split('\t').count(), 3);
+    }
+
+    #[test]
+    fn test_populate_custom_quote() {
+        let schema = test_schema();
+        let dialect = CsvDialect {
+            delimiter: b'@',
+            quote: b'\'',
+            has_headers: false,
+        };
+        let mut populator = CSVPopulator::new(schema, 42).with_dialect(dialect);
+
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("test.csv");
+        populator.populate("users", &output_path, 1).unwrap();
+
+        // The email contains the delimiter, so it is quoted with `'`
+        let content = std::fs::read_to_string(&output_path).unwrap();
+        assert!(content.contains("@'user_0@example.com'@"), "{content}");
+    }
+
     #[test]
     fn test_deterministic_generation() {
         let schema = test_schema();
@@ -1,6 +1,6 @@
 //! Field comparison logic.
 
-use surreal_sync_core::{GeometryData, Value};
+use surreal_sync_core::{ColumnFilterError, ColumnFilters, GeometryData, Value};
 use surrealdb2::sql::Value as SurrealValue;
 
 /// Result of comparing two values.
@@ -12,10 +12,95 @@ pub enum CompareResult {
     Mismatch { expected: String, actual: String },
     /// Field is missing.
     Missing,
+    /// Float or decimal values differ by more than the configured epsilon.
+    ToleranceExceeded {
+        expected: String,
+        actual: String,
+        delta: f64,
+    },
 }
 
-/// Compare a generated value with a SurrealDB value.
+/// Which fields of each table are compared.
+///
+/// Fields that a transform pipeline rewrites (redacted, hashed, stamped with
+/// the sync time) can never match their generated value; leave them out here
+/// so the rest of the record is still verified. Uses the same per-table
+/// include/exclude rules as the sync's column filters: an include list limits
+/// comparison to the listed fields, and excluded fields are never compared.
+///
+/// Float and decimal fields may also be given an epsilon, for targets that
+/// round or re-encode them on the way in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompareOptions {
+    fields: ColumnFilters,
+    float_epsilon: Option<f64>,
+}
+
+impl CompareOptions {
+    /// Compare every field.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse `table=field1,field2` include and exclude specs.
+    pub fn parse(include: &[String], exclude: &[String]) -> Result<Self, ColumnFilterError> {
+        Ok(Self {
+            fields: ColumnFilters::parse(include, exclude)?,
+            float_epsilon: None,
+        })
+    }
+
+    /// Accept float and decimal values within `epsilon` of the expected value,
+    /// either absolutely or relative to the larger magnitude. `None` keeps the
+    /// fixed per-type tolerances.
+    pub fn with_float_epsilon(mut self, epsilon: Option<f64>) -> Self {
+        self.float_epsilon = epsilon;
+        self
+    }
+
+    /// The configured float epsilon, if any.
+    pub fn float_epsilon(&self) -> Option<f64> {
+        self.float_epsilon
+    }
+
+    /// Compare only `fields` of `table`.
+    pub fn include<I, S>(mut self, table: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields.include(table, fields);
+        self
+    }
+
+    /// Never compare `fields` of `table`.
+    pub fn exclude<I, S>(mut self, table: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields.exclude(table, fields);
+        self
+    }
+
+    /// Whether `field` of `table` is compared.
+    pub fn compares(&self, table: &str, field: &str) -> bool {
+        self.fields.keeps(table, field)
+    }
+}
+
+/// Compare a generated value with a SurrealDB value using the default
+/// [`CompareOptions`].
 pub fn compare_values(expected: &Value, actual: &SurrealValue) -> CompareResult {
+    compare_values_with_options(expected, actual, &CompareOptions::default())
+}
+
+/// Compare a generated value with a SurrealDB value under `options`.
+pub fn compare_values_with_options(
+    expected: &Value,
+    actual: &SurrealValue,
+    options: &CompareOptions,
+) -> CompareResult {
     match (expected, actual) {
         // Null comparison
         (Value::Null, SurrealValue::None) => CompareResult::Match,
@@ -82,27 +167,11 @@ pub fn compare_values(expected: &Value, actual: &SurrealValue) -> CompareResult
         // Float comparison (with tolerance)
         (Value::Float32(e), SurrealValue::Number(n)) => {
             let a = n.as_float();
-            let tolerance = 1e-6_f64;
-            if ((*e as f64) - a).abs() < tolerance {
-                CompareResult::Match
-            } else {
-                CompareResult::Mismatch {
-                    expected: e.to_string(),
-                    actual: a.to_string(),
-                }
-            }
+            compare_floats(*e as f64, a, 1e-6, options, e.to_string())
         }
         (Value::Float64(e), SurrealValue::Number(n)) => {
             let a = n.as_float();
-            let tolerance = 1e-10;
-            if (e - a).abs() < tolerance {
-                CompareResult::Match
-            } else {
-                CompareResult::Mismatch {
-                    expected: e.to_string(),
-                    actual: a.to_string(),
-                }
-            }
+            compare_floats(*e, a, 1e-10, options, e.to_string())
         }
 
         // String comparison
@@ -268,15 +337,7 @@ pub fn compare_values(expected: &Value, actual: &SurrealValue) -> CompareResult
             };
             let actual_f64 = n.as_float();
             // Use tolerance appropriate for 2 decimal places
-            let tolerance = 0.001;
-            if (expected_f64 - actual_f64).abs() < tolerance {
-                CompareResult::Match
-            } else {
-                CompareResult::Mismatch {
-                    expected: e.clone(),
-                    actual: format!("{actual_f64}"),
-                }
-            }
+            compare_floats(expected_f64, actual_f64, 0.001, options, e.clone())
         }
         // Decimal stored as string is NOT valid - must be stored as Number (Decimal or Float)
         // TODO: could consider adding a verifier option to allow this, so that some sources without the schema files
@@ -295,7 +356,7 @@ pub fn compare_values(expected: &Value, actual: &SurrealValue) -> CompareResult
                 };
             }
             for (i, (exp_item, act_item)) in e.iter().zip(a.iter()).enumerate() {
-                match compare_values(exp_item, act_item) {
+                match compare_values_with_options(exp_item, act_item, options) {
                     CompareResult::Match => continue,
                     CompareResult::Mismatch { expected, actual } => {
                         return CompareResult::Mismatch {
@@ -303,6 +364,17 @@ pub fn compare_values(expected: &Value, actual: &SurrealValue) -> CompareResult
                             actual: format!("[{i}]: {actual}"),
                         };
                     }
+                    CompareResult::ToleranceExceeded {
+                        expected,
+                        actual,
+                        delta,
+                    } => {
+                        return CompareResult::ToleranceExceeded {
+                            expected: format!("[{i}]: {expected}"),
+                            actual: format!("[{i}]: {actual}"),
+                            delta,
+                        };
+                    }
                     CompareResult::Missing => {
                         return CompareResult::Missing;
                     }
@@ -397,6 +469,35 @@ pub fn compare_values(expected: &Value, actual: &SurrealValue) -> CompareResult
     }
 }
 
+/// Compare two floats within the epsilon of `options`, or within the fixed
+/// `tolerance` of the type when no epsilon is set.
+fn compare_floats(
+    expected: f64,
+    actual: f64,
+    tolerance: f64,
+    options: &CompareOptions,
+    expected_text: String,
+) -> CompareResult {
+    let delta = (expected - actual).abs();
+    match options.float_epsilon {
+        None if delta < tolerance => CompareResult::Match,
+        None => CompareResult::Mismatch {
+            expected: expected_text,
+            actual: actual.to_string(),
+        },
+        Some(epsilon)
+            if delta <= epsilon || delta <= epsilon * expected.abs().max(actual.abs()) =>
+        {
+            CompareResult::Match
+        }
+        Some(_) => CompareResult::ToleranceExceeded {
+            expected: expected_text,
+            actual: actual.to_string(),
+            delta,
+        },
+    }
+}
+
 /// Compare a serde_json::Value to a SurrealDB Object.
 fn compare_json_to_surreal_object(
     expected: &serde_json::Value,
@@ -548,6 +649,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compare_float_within_epsilon() {
+        let expected = Value::Float64(123.45);
+        let actual = SurrealValue::Number(Number::Float(123.4500001));
+        assert!(matches!(
+            compare_values(&expected, &actual),
+            CompareResult::Mismatch { .. }
+        ));
+
+        let options = CompareOptions::new().with_float_epsilon(Some(1e-6));
+        assert_eq!(
+            compare_values_with_options(&expected, &actual, &options),
+            CompareResult::Match
+        );
+    }
+
+    #[test]
+    fn test_compare_float_exceeding_epsilon_reports_delta() {
+        let options = CompareOptions::new().with_float_epsilon(Some(1e-12));
+        match compare_values_with_options(
+            &Value::Float64(123.45),
+            &SurrealValue::Number(Number::Float(123.4500001)),
+            &options,
+        ) {
+            CompareResult::ToleranceExceeded { delta, .. } => {
+                assert!((delta - 1e-7).abs() < 1e-9, "delta {delta}");
+            }
+            other => panic!("expected ToleranceExceeded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_compare_float_relative_epsilon() {
+        // 1e-6 relative to 1e9 allows a difference of 1000
+        let options = CompareOptions::new().with_float_epsilon(Some(1e-6));
+        assert_eq!(
+            compare_values_with_options(
+                &Value::Float64(1e9),
+                &SurrealValue::Number(Number::Float(1e9 + 500.0)),
+                &options,
+            ),
+            CompareResult::Match
+        );
+    }
+
+    #[test]
+    fn test_compare_decimal_with_epsilon() {
+        let expected = Value::Decimal {
+            value: "123.45".to_string(),
+            precision: 10,
+            scale: 2,
+        };
+        let options = CompareOptions::new().with_float_epsilon(Some(1e-6));
+        assert_eq!(
+            compare_values_with_options(
+                &expected,
+                &SurrealValue::Number(Number::Float(123.4500001)),
+                &options,
+            ),
+            CompareResult::Match
+        );
+        assert!(matches!(
+            compare_values_with_options(
+                &expected,
+                &SurrealValue::Number(Number::Float(123.46)),
+                &options,
+            ),
+            CompareResult::ToleranceExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn test_compare_int_ignores_epsilon() {
+        let options = CompareOptions::new().with_float_epsilon(Some(1.0));
+        assert!(matches!(
+            compare_values_with_options(
+                &Value::Int64(100),
+                &SurrealValue::Number(Number::Int(101)),
+                &options,
+            ),
+            CompareResult::Mismatch { .. }
+        ));
+    }
+
     #[test]
     fn test_compare_string() {
         assert_eq!(
@@ -17,6 +17,10 @@ pub enum VerifyError {
     #[error("Generator error: {0}")]
     Generator(String),
 
+    /// Error reading rows from the migration source.
+    #[error("Source error: {0}")]
+    Source(String),
+
     /// Query error.
     #[error("Query error: {0}")]
     Query(String),
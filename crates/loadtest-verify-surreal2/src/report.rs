@@ -11,6 +11,9 @@ pub struct FieldMismatch {
     pub expected: String,
     /// Actual value.
     pub actual: String,
+    /// How far apart a float or decimal pair was when it missed the
+    /// configured epsilon.
+    pub delta: Option<f64>,
 }
 
 /// Information about a mismatched row.
@@ -50,6 +53,9 @@ pub struct VerificationReport {
     pub missing_rows: Vec<MissingInfo>,
     /// Details of mismatched rows.
     pub mismatched_rows: Vec<MismatchInfo>,
+    /// Schema fields that were not compared (skipped or filtered out by
+    /// [`CompareOptions`](crate::CompareOptions)).
+    pub excluded_fields: Vec<String>,
     /// Total verification time.
     pub total_duration: Duration,
     /// Time spent generating expected data.
@@ -77,6 +83,18 @@ impl VerificationReport {
 
     /// Get a summary string.
     pub fn summary(&self) -> String {
+        let summary = self.outcome();
+        if self.excluded_fields.is_empty() {
+            summary
+        } else {
+            format!(
+                "{summary} (not compared: {})",
+                self.excluded_fields.join(", ")
+            )
+        }
+    }
+
+    fn outcome(&self) -> String {
         if self.is_success() {
             format!(
                 "Verification PASSED: {}/{} rows matched in {:?}",
@@ -152,6 +170,21 @@ mod tests {
         let summary = report.summary();
         assert!(summary.contains("PASSED"));
         assert!(summary.contains("100/100"));
+        assert!(!summary.contains("not compared"));
+    }
+
+    #[test]
+    fn test_report_summary_lists_excluded_fields() {
+        let report = VerificationReport {
+            expected: 10,
+            matched: 10,
+            excluded_fields: vec!["email".to_string(), "updated_at".to_string()],
+            ..Default::default()
+        };
+
+        assert!(report
+            .summary()
+            .ends_with("(not compared: email, updated_at)"));
     }
 
     #[test]
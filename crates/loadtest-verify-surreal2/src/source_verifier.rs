@@ -0,0 +1,338 @@
+//! Verification of SurrealDB records against the migration source.
+//!
+//! [`StreamingVerifier`](crate::StreamingVerifier) regenerates the expected
+//! rows from the load-test schema and seed, so it only applies to synthetic
+//! data. [`SourceComparingVerifier`] instead reads the expected rows from the
+//! source itself, through the same [`RowChunkSource`] readers the full sync
+//! uses (which convert source values with each source's reverse
+//! conversions), and compares every row with the SurrealDB record of the same
+//! id.
+
+use std::time::{Duration, Instant};
+
+use surreal_sync_core::{GeneratorTableDefinition, Schema};
+use surreal_sync_runtime::RowChunkSource;
+use surrealdb2::engine::any::Any;
+use surrealdb2::Surreal;
+use tracing::{debug, info};
+
+use crate::compare::CompareOptions;
+use crate::error::VerifyError;
+use crate::report::{MismatchInfo, MissingInfo, VerificationReport};
+use crate::verifier::{compare_record, format_id, query_record};
+
+/// Verifier that compares SurrealDB records with the rows of the source
+/// table they were migrated from.
+pub struct SourceComparingVerifier {
+    surreal: Surreal<Any>,
+    table_schema: GeneratorTableDefinition,
+    compare_options: CompareOptions,
+    /// Check every `sample_every`th source row (1 checks them all).
+    sample_every: u64,
+    /// Stop after checking this many rows.
+    max_rows: Option<u64>,
+}
+
+impl SourceComparingVerifier {
+    /// Create a verifier for `table_name`, whose field types in `schema`
+    /// decide how the SurrealDB values are read back.
+    pub fn new(
+        surreal: Surreal<Any>,
+        schema: &Schema,
+        table_name: &str,
+    ) -> Result<Self, VerifyError> {
+        let table_schema = schema
+            .get_table(table_name)
+            .ok_or_else(|| VerifyError::TableNotFound(table_name.to_string()))?
+            .clone();
+        Ok(Self {
+            surreal,
+            table_schema,
+            compare_options: CompareOptions::new(),
+            sample_every: 1,
+            max_rows: None,
+        })
+    }
+
+    /// Only compare the fields `options` selects for this table.
+    pub fn with_compare_options(mut self, options: CompareOptions) -> Self {
+        self.compare_options = options;
+        self
+    }
+
+    /// Check only every `n`th source row, starting with the first.
+    ///
+    /// Use this on large tables; the source is still read in full, but only
+    /// the sampled rows are looked up in SurrealDB.
+    pub fn with_sample_every(mut self, n: u64) -> Self {
+        self.sample_every = n.max(1);
+        self
+    }
+
+    /// Stop after checking `n` rows.
+    pub fn with_max_rows(mut self, n: u64) -> Self {
+        self.max_rows = Some(n);
+        self
+    }
+
+    /// Read `source` to the end (or until the row limit) and check each
+    /// sampled row against SurrealDB.
+    ///
+    /// [`VerificationReport::expected`] counts the rows checked, and
+    /// [`MismatchInfo::index`] is the row's position in the source scan.
+    /// Time spent reading the source is reported as the generation time.
+    pub async fn verify_source(
+        &self,
+        source: &mut dyn RowChunkSource,
+    ) -> Result<VerificationReport, VerifyError> {
+        let start_time = Instant::now();
+        let table_name = self.table_schema.name.as_str();
+        let mut report = VerificationReport {
+            excluded_fields: self
+                .table_schema
+                .fields
+                .iter()
+                .filter(|f| !self.compares(&f.name))
+                .map(|f| f.name.clone())
+                .collect(),
+            ..Default::default()
+        };
+
+        info!(
+            "Verifying table '{}' against the source (every {} row(s){})",
+            table_name,
+            self.sample_every,
+            self.max_rows
+                .map(|n| format!(", at most {n}"))
+                .unwrap_or_default()
+        );
+
+        let mut read_time = Duration::ZERO;
+        let mut query_time = Duration::ZERO;
+        let mut compare_time = Duration::ZERO;
+        let mut position = 0u64;
+
+        'chunks: loop {
+            let read_start = Instant::now();
+            let chunk = source
+                .next_chunk()
+                .await
+                .map_err(|e| VerifyError::Source(format!("{e:#}")))?;
+            read_time += read_start.elapsed();
+            let Some(rows) = chunk.filter(|rows| !rows.is_empty()) else {
+                break;
+            };
+
+            for row in rows {
+                let index = position;
+                position += 1;
+                if !index.is_multiple_of(self.sample_every) {
+                    continue;
+                }
+                if self.max_rows.is_some_and(|max| report.expected >= max) {
+                    break 'chunks;
+                }
+                report.expected += 1;
+
+                let query_start = Instant::now();
+                let actual =
+                    query_record(&self.surreal, table_name, &row.id, &self.table_schema).await?;
+                query_time += query_start.elapsed();
+
+                let compare_start = Instant::now();
+                let record_id = format!("{}:{}", table_name, format_id(&row.id));
+                match actual {
+                    Some(actual_record) => {
+                        report.found += 1;
+                        let mismatches = compare_record(
+                            &row,
+                            &actual_record,
+                            &self.table_schema,
+                            &self.compare_options,
+                            |field| self.compares(field),
+                        );
+                        if mismatches.is_empty() {
+                            report.matched += 1;
+                        } else {
+                            report.mismatched += 1;
+                            report.mismatched_rows.push(MismatchInfo {
+                                record_id,
+                                index,
+                                field_mismatches: mismatches,
+                            });
+                        }
+                    }
+                    None => {
+                        report.missing += 1;
+                        report.missing_rows.push(MissingInfo {
+                            expected_id: record_id,
+                            index,
+                        });
+                    }
+                }
+                compare_time += compare_start.elapsed();
+            }
+            debug!(
+                "Checked {} of {} source rows ({} matched, {} missing, {} mismatched)",
+                report.expected, position, report.matched, report.missing, report.mismatched
+            );
+        }
+
+        report.total_duration = start_time.elapsed();
+        report.generation_duration = read_time;
+        report.query_duration = query_time;
+        report.compare_duration = compare_time;
+
+        info!(
+            "Source verification of '{}' complete: {} rows checked in {:?} - {} matched, {} missing, {} mismatched",
+            table_name,
+            report.expected,
+            report.total_duration,
+            report.matched,
+            report.missing,
+            report.mismatched
+        );
+
+        Ok(report)
+    }
+
+    fn compares(&self, field: &str) -> bool {
+        self.compare_options
+            .compares(&self.table_schema.name, field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use surreal_sync_core::{Row, Value};
+
+    fn test_schema() -> Schema {
+        let yaml = r#"
+version: 1
+seed: 42
+tables:
+  - name: users
+    id:
+      type: big_int
+      generator:
+        type: sequential
+        start: 1
+    fields:
+      - name: email
+        type:
+          type: var_char
+          length: 255
+        generator:
+          type: pattern
+          pattern: "user_{index}@example.com"
+      - name: age
+        type: int
+        generator:
+          type: int_range
+          min: 18
+          max: 80
+"#;
+        Schema::from_yaml(yaml).unwrap()
+    }
+
+    /// Rows handed out in fixed-size chunks, as a source reader would.
+    struct VecChunks {
+        rows: Vec<Row>,
+        chunk_size: usize,
+    }
+
+    #[async_trait]
+    impl RowChunkSource for VecChunks {
+        async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Row>>> {
+            if self.rows.is_empty() {
+                return Ok(None);
+            }
+            let n = self.chunk_size.min(self.rows.len());
+            Ok(Some(self.rows.drain(..n).collect()))
+        }
+    }
+
+    fn source_row(id: i64, email: &str, age: i32) -> Row {
+        Row::builder("users", 0, Value::Int64(id))
+            .field("email", Value::Text(email.to_string()))
+            .field("age", Value::Int32(age))
+            .build()
+    }
+
+    async fn surreal_with_users() -> Surreal<Any> {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query(
+                "CREATE users:1 CONTENT { email: 'a@example.com', age: 30 };
+                 CREATE users:2 CONTENT { email: 'b@example.com', age: 41 };
+                 CREATE users:4 CONTENT { email: 'd@example.com', age: 52 };",
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        surreal
+    }
+
+    fn source() -> VecChunks {
+        VecChunks {
+            rows: vec![
+                source_row(1, "a@example.com", 30),
+                source_row(2, "b@example.com", 40),
+                source_row(3, "c@example.com", 35),
+                source_row(4, "d@example.com", 52),
+            ],
+            chunk_size: 3,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_source_rows_that_differ_or_are_missing() {
+        let surreal = surreal_with_users().await;
+        let report = SourceComparingVerifier::new(surreal, &test_schema(), "users")
+            .unwrap()
+            .verify_source(&mut source())
+            .await
+            .unwrap();
+
+        assert_eq!(report.expected, 4);
+        assert_eq!(report.matched, 2);
+        assert_eq!(report.mismatched_rows.len(), 1);
+        let mismatch = &report.mismatched_rows[0];
+        assert_eq!(mismatch.record_id, "users:2");
+        assert_eq!(mismatch.index, 1);
+        assert_eq!(mismatch.field_mismatches.len(), 1);
+        assert_eq!(mismatch.field_mismatches[0].field, "age");
+        assert_eq!(report.missing_rows.len(), 1);
+        assert_eq!(report.missing_rows[0].expected_id, "users:3");
+    }
+
+    #[tokio::test]
+    async fn test_sampling_checks_every_nth_row_up_to_the_limit() {
+        let surreal = surreal_with_users().await;
+        let report = SourceComparingVerifier::new(surreal, &test_schema(), "users")
+            .unwrap()
+            .with_sample_every(2)
+            .verify_source(&mut source())
+            .await
+            .unwrap();
+        // Rows 0 and 2 (users:1 and users:3) are checked.
+        assert_eq!(report.expected, 2);
+        assert_eq!(report.matched, 1);
+        assert_eq!(report.missing, 1);
+        assert!(report.mismatched_rows.is_empty());
+
+        let surreal = surreal_with_users().await;
+        let report = SourceComparingVerifier::new(surreal, &test_schema(), "users")
+            .unwrap()
+            .with_max_rows(1)
+            .verify_source(&mut source())
+            .await
+            .unwrap();
+        assert_eq!(report.expected, 1);
+        assert_eq!(report.matched, 1);
+    }
+}
@@ -1,15 +1,17 @@
 //! Streaming verifier implementation.
 
-use crate::compare::{compare_values, CompareResult};
+use crate::compare::{compare_values_with_options, CompareOptions, CompareResult};
 use crate::error::VerifyError;
 use crate::report::{FieldMismatch, MismatchInfo, MissingInfo, VerificationReport};
 use loadtest_generator::DataGenerator;
 use rust_decimal::Decimal;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use surreal_sync_core::{GeneratorTableDefinition, Row, Schema};
 use surrealdb2::engine::any::Any;
 use surrealdb2::sql::Value as SurrealValue;
 use surrealdb2::Surreal;
+use tokio::task::JoinSet;
 use tracing::{debug, info, warn};
 
 /// Streaming verifier that generates expected data and compares with SurrealDB.
@@ -24,12 +26,14 @@ pub struct StreamingVerifier {
     /// `timestamp_now` generator - the generated value will differ from the
     /// actual synced value since they are produced at different times.
     skip_fields: Vec<String>,
+    /// Per-table field allowlist/denylist for comparison.
+    compare_options: CompareOptions,
 }
 
 /// A record result that we manually extract field-by-field
 /// since SurrealDB's sql::Value doesn't deserialize via serde in the expected way.
 #[derive(Debug)]
-struct RecordResult {
+pub(crate) struct RecordResult {
     #[allow(dead_code)]
     id: surrealdb2::sql::Thing,
     fields: std::collections::HashMap<String, SurrealValue>,
@@ -80,6 +84,7 @@ impl StreamingVerifier {
             generator,
             table_name: table_name.to_string(),
             skip_fields: Vec::new(),
+            compare_options: CompareOptions::new(),
         })
     }
 
@@ -100,6 +105,14 @@ impl StreamingVerifier {
         self
     }
 
+    /// Only compare the fields `options` selects for this table.
+    ///
+    /// Fields left out are listed in [`VerificationReport::excluded_fields`].
+    pub fn with_compare_options(mut self, options: CompareOptions) -> Self {
+        self.compare_options = options;
+        self
+    }
+
     /// Set the starting index for verification (for incremental verification).
     pub fn with_start_index(mut self, index: u64) -> Self {
         self.generator = std::mem::replace(
@@ -140,6 +153,19 @@ impl StreamingVerifier {
             "Starting streaming verification of {} rows for table '{}'",
             count, self.table_name
         );
+        report.excluded_fields = table_schema
+            .fields
+            .iter()
+            .filter(|f| !self.compares(&f.name))
+            .map(|f| f.name.clone())
+            .collect();
+        if !report.excluded_fields.is_empty() {
+            info!(
+                "Not comparing fields of table '{}': {}",
+                self.table_name,
+                report.excluded_fields.join(", ")
+            );
+        }
 
         let mut generation_time = Duration::ZERO;
         let mut query_time = Duration::ZERO;
@@ -156,7 +182,13 @@ impl StreamingVerifier {
 
             // Query SurrealDB for this row
             let query_start = Instant::now();
-            let actual = self.query_row(&expected_row, &table_schema).await?;
+            let actual = query_record(
+                &self.surreal,
+                &self.table_name,
+                &expected_row.id,
+                &table_schema,
+            )
+            .await?;
             query_time += query_start.elapsed();
 
             // Compare
@@ -164,7 +196,13 @@ impl StreamingVerifier {
             match actual {
                 Some(actual_record) => {
                     report.found += 1;
-                    let mismatches = self.compare_row(&expected_row, &actual_record, &table_schema);
+                    let mismatches = compare_record(
+                        &expected_row,
+                        &actual_record,
+                        &table_schema,
+                        &self.compare_options,
+                        |field| self.compares(field),
+                    );
                     if mismatches.is_empty() {
                         report.matched += 1;
                     } else {
@@ -235,317 +273,504 @@ impl StreamingVerifier {
         self.verify_streaming(count).await
     }
 
-    /// Query a single row from SurrealDB by its ID.
-    async fn query_row(
-        &self,
-        expected_row: &Row,
-        table_schema: &GeneratorTableDefinition,
-    ) -> Result<Option<RecordResult>, VerifyError> {
-        use surrealdb2::sql::{Id, Thing};
-
-        // Construct proper Thing based on ID type to match how sync stores records
-        let thing = match &expected_row.id {
-            surreal_sync_core::Value::Uuid(u) => Thing::from((
-                self.table_name.as_str(),
-                Id::Uuid(surrealdb2::sql::Uuid::from(*u)),
-            )),
-            surreal_sync_core::Value::Int64(i) => {
-                Thing::from((self.table_name.as_str(), Id::Number(*i)))
-            }
-            surreal_sync_core::Value::Int32(i) => {
-                Thing::from((self.table_name.as_str(), Id::Number(*i as i64)))
-            }
-            surreal_sync_core::Value::Text(s) => {
-                Thing::from((self.table_name.as_str(), Id::String(s.clone())))
-            }
-            other => {
-                // Fallback for other types - use string representation
-                Thing::from((self.table_name.as_str(), Id::String(format_id(other))))
-            }
+    /// Verify `count` rows like [`verify_streaming`](Self::verify_streaming),
+    /// with up to `concurrency` record lookups in flight.
+    ///
+    /// Expected rows are still generated in order on this verifier's
+    /// generator, so they are the rows populate wrote from its own single
+    /// generator; per-shard seeds would produce a different stream. Only the
+    /// SurrealDB queries and comparisons run concurrently, and missing and
+    /// mismatched rows are reported in index order. Query and compare
+    /// durations are summed over all lookups.
+    pub async fn verify_streaming_parallel(
+        &mut self,
+        count: u64,
+        concurrency: usize,
+    ) -> Result<VerificationReport, VerifyError> {
+        let start_time = Instant::now();
+        let concurrency = concurrency.max(1);
+        let mut report = VerificationReport {
+            expected: count,
+            ..Default::default()
         };
 
-        // First check if the record exists by fetching just the ID
-        let mut response = self
-            .surreal
-            .query("SELECT id FROM $record_id")
-            .bind(("record_id", thing.clone()))
-            .await?;
+        let table_schema = Arc::new(
+            self.schema
+                .get_table(&self.table_name)
+                .ok_or_else(|| VerifyError::TableNotFound(self.table_name.clone()))?
+                .clone(),
+        );
 
-        let ids: Vec<surrealdb2::sql::Thing> = response.take((0, "id"))?;
-        debug!("Query result for {:?}: found {} ids", thing, ids.len());
-        if ids.is_empty() {
-            return Ok(None);
-        }
-
-        // Record exists, now fetch all fields individually
-        let mut fields = std::collections::HashMap::new();
-        for field_schema in &table_schema.fields {
-            let field_name = &field_schema.name;
-            // Query the full record and extract the specific field
-            let mut field_response = self
-                .surreal
-                .query("SELECT * FROM $record_id")
-                .bind(("record_id", thing.clone()))
-                .await?;
-
-            // Try to extract the field value as the appropriate SurrealDB type
-            let value = self
-                .extract_field_value(
-                    &mut field_response,
-                    field_name,
-                    &field_schema.field_type,
-                    &thing,
-                )
-                .await
-                .map_err(|e| {
-                    warn!(
-                        "Failed to extract field '{}' (type {:?}) from record {:?}: {}",
-                        field_name, field_schema.field_type, thing, e
-                    );
-                    e
-                })?;
-            debug!(
-                "Extracted field '{}' with type {:?}: {:?}",
-                field_name, field_schema.field_type, value
+        info!(
+            "Starting parallel verification of {} rows for table '{}' ({} concurrent lookups)",
+            count, self.table_name, concurrency
+        );
+        report.excluded_fields = table_schema
+            .fields
+            .iter()
+            .filter(|f| !self.compares(&f.name))
+            .map(|f| f.name.clone())
+            .collect();
+        if !report.excluded_fields.is_empty() {
+            info!(
+                "Not comparing fields of table '{}': {}",
+                self.table_name,
+                report.excluded_fields.join(", ")
             );
-            if let Some(v) = value {
-                fields.insert(field_name.clone(), v);
+        }
+        let excluded = Arc::new(report.excluded_fields.clone());
+        let options = Arc::new(self.compare_options.clone());
+
+        let mut generation_time = Duration::ZERO;
+        let mut tasks = JoinSet::new();
+
+        for i in 0..count {
+            if tasks.len() >= concurrency {
+                if let Some(joined) = tasks.join_next().await {
+                    record_check(&mut report, joined_check(joined)?);
+                }
             }
+
+            let gen_start = Instant::now();
+            let expected_row = self
+                .generator
+                .next_internal_row(&self.table_name)
+                .map_err(|e| VerifyError::Generator(e.to_string()))?;
+            generation_time += gen_start.elapsed();
+
+            tasks.spawn(check_row(
+                self.surreal.clone(),
+                self.table_name.clone(),
+                Arc::clone(&table_schema),
+                Arc::clone(&options),
+                Arc::clone(&excluded),
+                i,
+                expected_row,
+            ));
+        }
+        while let Some(joined) = tasks.join_next().await {
+            record_check(&mut report, joined_check(joined)?);
         }
 
-        Ok(Some(RecordResult { id: thing, fields }))
+        report.missing_rows.sort_by_key(|row| row.index);
+        report.mismatched_rows.sort_by_key(|row| row.index);
+        report.total_duration = start_time.elapsed();
+        report.generation_duration = generation_time;
+
+        info!(
+            "Verification complete: {} rows verified in {:?} - {} matched, {} missing, {} mismatched",
+            count,
+            report.total_duration,
+            report.matched,
+            report.missing,
+            report.mismatched
+        );
+
+        Ok(report)
     }
 
-    /// Extract a field value from a query response based on its expected type.
-    async fn extract_field_value(
-        &self,
-        response: &mut surrealdb2::Response,
-        field_name: &str,
-        data_type: &surreal_sync_core::Type,
-        record_id: &surrealdb2::sql::Thing,
-    ) -> Result<Option<SurrealValue>, VerifyError> {
-        use surreal_sync_core::Type;
-
-        match data_type {
-            Type::Bool => {
-                let val: Option<bool> = response.take((0, field_name))?;
-                Ok(val.map(SurrealValue::Bool))
-            }
-            Type::Int8 { .. } | Type::Int16 | Type::Int32 | Type::Int64 => {
-                let val: Option<i64> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Number(surrealdb2::sql::Number::Int(v))))
-            }
-            Type::Float32 | Type::Float64 => {
-                let val: Option<f64> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Number(surrealdb2::sql::Number::Float(v))))
+    fn compares(&self, field: &str) -> bool {
+        !self.skip_fields.iter().any(|f| f == field)
+            && self.compare_options.compares(&self.table_name, field)
+    }
+}
+
+/// Result of looking up one expected row, produced by [`check_row`].
+struct RowCheck {
+    index: u64,
+    record_id: String,
+    /// Field mismatches, or `None` if the record is missing.
+    mismatches: Option<Vec<FieldMismatch>>,
+    query_duration: Duration,
+    compare_duration: Duration,
+}
+
+/// Query the record of `expected` and compare it, for
+/// [`StreamingVerifier::verify_streaming_parallel`].
+async fn check_row(
+    surreal: Surreal<Any>,
+    table_name: String,
+    table_schema: Arc<GeneratorTableDefinition>,
+    options: Arc<CompareOptions>,
+    excluded: Arc<Vec<String>>,
+    index: u64,
+    expected: Row,
+) -> Result<RowCheck, VerifyError> {
+    let query_start = Instant::now();
+    let actual = query_record(&surreal, &table_name, &expected.id, &table_schema).await?;
+    let query_duration = query_start.elapsed();
+
+    let compare_start = Instant::now();
+    let mismatches = actual.map(|actual| {
+        compare_record(&expected, &actual, &table_schema, &options, |field| {
+            !excluded.iter().any(|f| f == field)
+        })
+    });
+    Ok(RowCheck {
+        index,
+        record_id: format!("{}:{}", table_name, format_id(&expected.id)),
+        mismatches,
+        query_duration,
+        compare_duration: compare_start.elapsed(),
+    })
+}
+
+/// Unwrap a finished [`check_row`] task.
+fn joined_check(
+    joined: Result<Result<RowCheck, VerifyError>, tokio::task::JoinError>,
+) -> Result<RowCheck, VerifyError> {
+    joined.map_err(|e| VerifyError::Query(format!("Verification task failed: {e}")))?
+}
+
+/// Add one row's result to `report`.
+fn record_check(report: &mut VerificationReport, check: RowCheck) {
+    report.query_duration += check.query_duration;
+    report.compare_duration += check.compare_duration;
+    match check.mismatches {
+        None => {
+            report.missing += 1;
+            report.missing_rows.push(MissingInfo {
+                expected_id: check.record_id,
+                index: check.index,
+            });
+        }
+        Some(mismatches) => {
+            report.found += 1;
+            if mismatches.is_empty() {
+                report.matched += 1;
+            } else {
+                report.mismatched += 1;
+                report.mismatched_rows.push(MismatchInfo {
+                    record_id: check.record_id,
+                    index: check.index,
+                    field_mismatches: mismatches,
+                });
             }
-            Type::Decimal { .. } => {
-                // Decimal fields may be stored as Number::Decimal or Number::Float
-                // Try reading as rust_decimal::Decimal first (works for both V2 and V3 SDKs)
-                match response.take::<Option<Decimal>>((0, field_name)) {
-                    Ok(Some(d)) => Ok(Some(SurrealValue::Number(
-                        surrealdb2::sql::Number::Decimal(d),
-                    ))),
-                    Ok(None) => Ok(None),
-                    Err(_) => {
-                        // Not stored as Decimal - try reading as f64 (for Float storage)
-                        let mut retry_response = self
-                            .surreal
-                            .query("SELECT * FROM $record_id")
-                            .bind(("record_id", record_id.clone()))
-                            .await?;
-                        match retry_response.take::<Option<f64>>((0, field_name)) {
-                            Ok(Some(f)) => Ok(Some(SurrealValue::Number(
-                                surrealdb2::sql::Number::Float(f),
-                            ))),
-                            Ok(None) => Ok(None),
-                            Err(e) => Err(VerifyError::Query(format!(
-                                "Field '{field_name}' is neither Decimal nor Float: {e}"
-                            ))),
-                        }
+        }
+    }
+}
+
+/// Query a single record of `table_name` from SurrealDB by its ID.
+pub(crate) async fn query_record(
+    surreal: &Surreal<Any>,
+    table_name: &str,
+    id: &surreal_sync_core::Value,
+    table_schema: &GeneratorTableDefinition,
+) -> Result<Option<RecordResult>, VerifyError> {
+    use surrealdb2::sql::{Id, Thing};
+
+    // Construct proper Thing based on ID type to match how sync stores records
+    let thing = match id {
+        surreal_sync_core::Value::Uuid(u) => {
+            Thing::from((table_name, Id::Uuid(surrealdb2::sql::Uuid::from(*u))))
+        }
+        surreal_sync_core::Value::Int64(i) => Thing::from((table_name, Id::Number(*i))),
+        surreal_sync_core::Value::Int32(i) => Thing::from((table_name, Id::Number(*i as i64))),
+        surreal_sync_core::Value::Text(s) => Thing::from((table_name, Id::String(s.clone()))),
+        other => {
+            // Fallback for other types - use string representation
+            Thing::from((table_name, Id::String(format_id(other))))
+        }
+    };
+
+    // First check if the record exists by fetching just the ID
+    let mut response = surreal
+        .query("SELECT id FROM $record_id")
+        .bind(("record_id", thing.clone()))
+        .await?;
+
+    let ids: Vec<surrealdb2::sql::Thing> = response.take((0, "id"))?;
+    debug!("Query result for {:?}: found {} ids", thing, ids.len());
+    if ids.is_empty() {
+        return Ok(None);
+    }
+
+    // Record exists, now fetch all fields individually
+    let mut fields = std::collections::HashMap::new();
+    for field_schema in &table_schema.fields {
+        let field_name = &field_schema.name;
+        // Query the full record and extract the specific field
+        let mut field_response = surreal
+            .query("SELECT * FROM $record_id")
+            .bind(("record_id", thing.clone()))
+            .await?;
+
+        // Try to extract the field value as the appropriate SurrealDB type
+        let value = extract_field_value(
+            surreal,
+            &mut field_response,
+            field_name,
+            &field_schema.field_type,
+            &thing,
+        )
+        .await
+        .map_err(|e| {
+            warn!(
+                "Failed to extract field '{}' (type {:?}) from record {:?}: {}",
+                field_name, field_schema.field_type, thing, e
+            );
+            e
+        })?;
+        debug!(
+            "Extracted field '{}' with type {:?}: {:?}",
+            field_name, field_schema.field_type, value
+        );
+        if let Some(v) = value {
+            fields.insert(field_name.clone(), v);
+        }
+    }
+
+    Ok(Some(RecordResult { id: thing, fields }))
+}
+
+/// Extract a field value from a query response based on its expected type.
+async fn extract_field_value(
+    surreal: &Surreal<Any>,
+    response: &mut surrealdb2::Response,
+    field_name: &str,
+    data_type: &surreal_sync_core::Type,
+    record_id: &surrealdb2::sql::Thing,
+) -> Result<Option<SurrealValue>, VerifyError> {
+    use surreal_sync_core::Type;
+
+    match data_type {
+        Type::Bool => {
+            let val: Option<bool> = response.take((0, field_name))?;
+            Ok(val.map(SurrealValue::Bool))
+        }
+        Type::Int8 { .. } | Type::Int16 | Type::Int32 | Type::Int64 => {
+            let val: Option<i64> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Number(surrealdb2::sql::Number::Int(v))))
+        }
+        Type::Float32 | Type::Float64 => {
+            let val: Option<f64> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Number(surrealdb2::sql::Number::Float(v))))
+        }
+        Type::Decimal { .. } => {
+            // Decimal fields may be stored as Number::Decimal or Number::Float
+            // Try reading as rust_decimal::Decimal first (works for both V2 and V3 SDKs)
+            match response.take::<Option<Decimal>>((0, field_name)) {
+                Ok(Some(d)) => Ok(Some(SurrealValue::Number(
+                    surrealdb2::sql::Number::Decimal(d),
+                ))),
+                Ok(None) => Ok(None),
+                Err(_) => {
+                    // Not stored as Decimal - try reading as f64 (for Float storage)
+                    let mut retry_response = surreal
+                        .query("SELECT * FROM $record_id")
+                        .bind(("record_id", record_id.clone()))
+                        .await?;
+                    match retry_response.take::<Option<f64>>((0, field_name)) {
+                        Ok(Some(f)) => Ok(Some(SurrealValue::Number(
+                            surrealdb2::sql::Number::Float(f),
+                        ))),
+                        Ok(None) => Ok(None),
+                        Err(e) => Err(VerifyError::Query(format!(
+                            "Field '{field_name}' is neither Decimal nor Float: {e}"
+                        ))),
                     }
                 }
             }
-            Type::Char { .. } | Type::VarChar { .. } | Type::Text | Type::Enum { .. } => {
-                let val: Option<String> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
-            }
-            Type::Uuid => {
-                // UUIDs can be stored as native UUID type or as string
-                // Try native UUID first, fall back to string
-                let result: Result<Option<uuid::Uuid>, _> = response.take((0, field_name));
-                match result {
-                    Ok(Some(v)) => Ok(Some(SurrealValue::Uuid(surrealdb2::sql::Uuid::from(v)))),
-                    Ok(None) => Ok(None),
-                    Err(_) => {
-                        // Try as string (for JSONL which stores UUIDs as strings)
-                        let mut response2 = self
-                            .surreal
-                            .query(format!("SELECT {field_name} FROM $record_id"))
-                            .bind(("record_id", record_id.clone()))
-                            .await?;
-                        let val: Option<String> = response2.take((0, field_name))?;
-                        Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
-                    }
+        }
+        Type::Char { .. } | Type::VarChar { .. } | Type::Text | Type::Enum { .. } => {
+            let val: Option<String> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
+        }
+        Type::Uuid => {
+            // UUIDs can be stored as native UUID type or as string
+            // Try native UUID first, fall back to string
+            let result: Result<Option<uuid::Uuid>, _> = response.take((0, field_name));
+            match result {
+                Ok(Some(v)) => Ok(Some(SurrealValue::Uuid(surrealdb2::sql::Uuid::from(v)))),
+                Ok(None) => Ok(None),
+                Err(_) => {
+                    // Try as string (for JSONL which stores UUIDs as strings)
+                    let mut response2 = surreal
+                        .query(format!("SELECT {field_name} FROM $record_id"))
+                        .bind(("record_id", record_id.clone()))
+                        .await?;
+                    let val: Option<String> = response2.take((0, field_name))?;
+                    Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
                 }
             }
-            Type::Ulid => {
-                // ULIDs are stored as strings in SurrealDB
-                let val: Option<String> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
-            }
-            Type::LocalDateTime | Type::LocalDateTimeNano | Type::ZonedDateTime => {
-                let val: Option<chrono::DateTime<chrono::Utc>> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Datetime(surrealdb2::sql::Datetime::from(v))))
-            }
-            Type::Date => {
-                let val: Option<String> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
-            }
-            Type::Time => {
-                let val: Option<String> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
-            }
-            Type::Blob | Type::Bytes => {
-                let val: Option<Vec<u8>> = response.take((0, field_name))?;
-                Ok(val.map(|v| SurrealValue::Bytes(surrealdb2::sql::Bytes::from(v))))
-            }
-            Type::Json | Type::Jsonb => {
-                // JSON values are stored as native Objects in SurrealDB
-                // Use serde_json::Value for dynamic JSON extraction
-                let val: Option<serde_json::Value> = response.take((0, field_name))?;
-                Ok(val.map(|v| json_value_to_surreal(&v)))
+        }
+        Type::Ulid => {
+            // ULIDs are stored as strings in SurrealDB
+            let val: Option<String> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
+        }
+        Type::LocalDateTime | Type::LocalDateTimeNano | Type::ZonedDateTime => {
+            let val: Option<chrono::DateTime<chrono::Utc>> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Datetime(surrealdb2::sql::Datetime::from(v))))
+        }
+        Type::Date => {
+            let val: Option<String> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
+        }
+        Type::Time => {
+            let val: Option<String> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Strand(surrealdb2::sql::Strand::from(v))))
+        }
+        Type::Blob | Type::Bytes => {
+            let val: Option<Vec<u8>> = response.take((0, field_name))?;
+            Ok(val.map(|v| SurrealValue::Bytes(surrealdb2::sql::Bytes::from(v))))
+        }
+        Type::Json | Type::Jsonb => {
+            // JSON values are stored as native Objects in SurrealDB
+            // Use serde_json::Value for dynamic JSON extraction
+            let val: Option<serde_json::Value> = response.take((0, field_name))?;
+            Ok(val.map(|v| json_value_to_surreal(&v)))
+        }
+        Type::Array { element_type } => {
+            // Extract array based on element type
+            match element_type.as_ref() {
+                Type::Int32 | Type::Int16 | Type::Int64 => {
+                    let val: Option<Vec<i64>> = response.take((0, field_name))?;
+                    Ok(val.map(|arr| {
+                        let items: Vec<SurrealValue> = arr
+                            .into_iter()
+                            .map(|i| SurrealValue::Number(surrealdb2::sql::Number::Int(i)))
+                            .collect();
+                        SurrealValue::Array(surrealdb2::sql::Array::from(items))
+                    }))
+                }
+                Type::Text | Type::VarChar { .. } | Type::Char { .. } => {
+                    let val: Option<Vec<String>> = response.take((0, field_name))?;
+                    Ok(val.map(|arr| {
+                        let items: Vec<SurrealValue> = arr
+                            .into_iter()
+                            .map(|s| SurrealValue::Strand(surrealdb2::sql::Strand::from(s)))
+                            .collect();
+                        SurrealValue::Array(surrealdb2::sql::Array::from(items))
+                    }))
+                }
+                _ => {
+                    // For other element types, skip for now
+                    Ok(None)
+                }
             }
-            Type::Array { element_type } => {
-                // Extract array based on element type
-                match element_type.as_ref() {
-                    Type::Int32 | Type::Int16 | Type::Int64 => {
-                        let val: Option<Vec<i64>> = response.take((0, field_name))?;
-                        Ok(val.map(|arr| {
-                            let items: Vec<SurrealValue> = arr
-                                .into_iter()
-                                .map(|i| SurrealValue::Number(surrealdb2::sql::Number::Int(i)))
-                                .collect();
-                            SurrealValue::Array(surrealdb2::sql::Array::from(items))
-                        }))
+        }
+        Type::Set { .. } => {
+            // Sets - for now just skip complex set handling
+            Ok(None)
+        }
+        Type::Geometry { .. } => {
+            // Geometry - skip for now
+            Ok(None)
+        }
+        Type::Duration => {
+            // Duration - extract as SurrealDB duration
+            let val: Option<surrealdb2::sql::Duration> = response.take((0, field_name))?;
+            Ok(val.map(SurrealValue::Duration))
+        }
+        Type::Thing => {
+            // Thing - extract as SurrealDB Thing
+            let val: Option<surrealdb2::sql::Thing> = response.take((0, field_name))?;
+            Ok(val.map(SurrealValue::Thing))
+        }
+        Type::Object => {
+            // Object - stored as native Object in SurrealDB
+            let val: Option<serde_json::Value> = response.take((0, field_name))?;
+            Ok(val.map(|v| json_value_to_surreal(&v)))
+        }
+        Type::TimeTz => {
+            // TimeTz - stored as string in SurrealDB
+            let val: Option<String> = response.take((0, field_name))?;
+            Ok(val.map(|s| SurrealValue::Strand(surrealdb2::sql::Strand::from(s))))
+        }
+    }
+}
+
+/// Compare an expected row with an actual SurrealDB record, field by field
+/// over the fields `compares` selects.
+pub(crate) fn compare_record(
+    expected: &Row,
+    actual: &RecordResult,
+    table_schema: &GeneratorTableDefinition,
+    options: &CompareOptions,
+    compares: impl Fn(&str) -> bool,
+) -> Vec<FieldMismatch> {
+    let mut mismatches = Vec::new();
+
+    for field_schema in &table_schema.fields {
+        // Skip fields that are configured to be skipped (e.g., non-deterministic
+        // updated_at) or filtered out by the compare options
+        if !compares(&field_schema.name) {
+            continue;
+        }
+
+        let expected_value = expected.get_field(&field_schema.name);
+        let actual_value = actual.fields.get(&field_schema.name);
+
+        match (expected_value, actual_value) {
+            (Some(exp), Some(act)) => {
+                let result = compare_values_with_options(exp, act, options);
+                debug!(
+                    "Field '{}' comparison: exp={:?}, act={:?}, result={:?}",
+                    field_schema.name, exp, act, result
+                );
+                match result {
+                    CompareResult::Match => {}
+                    CompareResult::Mismatch { expected, actual } => {
+                        mismatches.push(FieldMismatch {
+                            field: field_schema.name.clone(),
+                            expected,
+                            actual,
+                            delta: None,
+                        });
                     }
-                    Type::Text | Type::VarChar { .. } | Type::Char { .. } => {
-                        let val: Option<Vec<String>> = response.take((0, field_name))?;
-                        Ok(val.map(|arr| {
-                            let items: Vec<SurrealValue> = arr
-                                .into_iter()
-                                .map(|s| SurrealValue::Strand(surrealdb2::sql::Strand::from(s)))
-                                .collect();
-                            SurrealValue::Array(surrealdb2::sql::Array::from(items))
-                        }))
+                    CompareResult::ToleranceExceeded {
+                        expected,
+                        actual,
+                        delta,
+                    } => {
+                        mismatches.push(FieldMismatch {
+                            field: field_schema.name.clone(),
+                            expected,
+                            actual,
+                            delta: Some(delta),
+                        });
                     }
-                    _ => {
-                        // For other element types, skip for now
-                        Ok(None)
+                    CompareResult::Missing => {
+                        mismatches.push(FieldMismatch {
+                            field: field_schema.name.clone(),
+                            expected: format!("{exp:?}"),
+                            actual: "MISSING".to_string(),
+                            delta: None,
+                        });
                     }
                 }
             }
-            Type::Set { .. } => {
-                // Sets - for now just skip complex set handling
-                Ok(None)
-            }
-            Type::Geometry { .. } => {
-                // Geometry - skip for now
-                Ok(None)
+            (Some(surreal_sync_core::Value::Null), None) => {
+                // A NULL column reads back as an absent field
             }
-            Type::Duration => {
-                // Duration - extract as SurrealDB duration
-                let val: Option<surrealdb2::sql::Duration> = response.take((0, field_name))?;
-                Ok(val.map(SurrealValue::Duration))
+            (Some(exp), None) => {
+                // Expected field but not found in SurrealDB
+                mismatches.push(FieldMismatch {
+                    field: field_schema.name.clone(),
+                    expected: format!("{exp:?}"),
+                    actual: "MISSING".to_string(),
+                    delta: None,
+                });
             }
-            Type::Thing => {
-                // Thing - extract as SurrealDB Thing
-                let val: Option<surrealdb2::sql::Thing> = response.take((0, field_name))?;
-                Ok(val.map(SurrealValue::Thing))
-            }
-            Type::Object => {
-                // Object - stored as native Object in SurrealDB
-                let val: Option<serde_json::Value> = response.take((0, field_name))?;
-                Ok(val.map(|v| json_value_to_surreal(&v)))
+            (None, Some(act)) => {
+                // Extra field in SurrealDB (this is usually okay, but log it)
+                warn!(
+                    "Extra field '{}' in SurrealDB record: {:?}",
+                    field_schema.name, act
+                );
             }
-            Type::TimeTz => {
-                // TimeTz - stored as string in SurrealDB
-                let val: Option<String> = response.take((0, field_name))?;
-                Ok(val.map(|s| SurrealValue::Strand(surrealdb2::sql::Strand::from(s))))
+            (None, None) => {
+                // Both missing - this is fine (null field)
             }
         }
     }
 
-    /// Compare an expected row with an actual SurrealDB record.
-    fn compare_row(
-        &self,
-        expected: &Row,
-        actual: &RecordResult,
-        table_schema: &GeneratorTableDefinition,
-    ) -> Vec<FieldMismatch> {
-        let mut mismatches = Vec::new();
-
-        for field_schema in &table_schema.fields {
-            // Skip fields that are configured to be skipped (e.g., non-deterministic updated_at)
-            if self.skip_fields.contains(&field_schema.name) {
-                continue;
-            }
-
-            let expected_value = expected.get_field(&field_schema.name);
-            let actual_value = actual.fields.get(&field_schema.name);
-
-            match (expected_value, actual_value) {
-                (Some(exp), Some(act)) => {
-                    let result = compare_values(exp, act);
-                    debug!(
-                        "Field '{}' comparison: exp={:?}, act={:?}, result={:?}",
-                        field_schema.name, exp, act, result
-                    );
-                    match result {
-                        CompareResult::Match => {}
-                        CompareResult::Mismatch { expected, actual } => {
-                            mismatches.push(FieldMismatch {
-                                field: field_schema.name.clone(),
-                                expected,
-                                actual,
-                            });
-                        }
-                        CompareResult::Missing => {
-                            mismatches.push(FieldMismatch {
-                                field: field_schema.name.clone(),
-                                expected: format!("{exp:?}"),
-                                actual: "MISSING".to_string(),
-                            });
-                        }
-                    }
-                }
-                (Some(exp), None) => {
-                    // Expected field but not found in SurrealDB
-                    mismatches.push(FieldMismatch {
-                        field: field_schema.name.clone(),
-                        expected: format!("{exp:?}"),
-                        actual: "MISSING".to_string(),
-                    });
-                }
-                (None, Some(act)) => {
-                    // Extra field in SurrealDB (this is usually okay, but log it)
-                    warn!(
-                        "Extra field '{}' in SurrealDB record: {:?}",
-                        field_schema.name, act
-                    );
-                }
-                (None, None) => {
-                    // Both missing - this is fine (null field)
-                }
-            }
-        }
-
-        mismatches
-    }
+    mismatches
 }
 
 /// Format a Value ID for display.
-fn format_id(value: &surreal_sync_core::Value) -> String {
+pub(crate) fn format_id(value: &surreal_sync_core::Value) -> String {
     match value {
         surreal_sync_core::Value::Uuid(u) => u.to_string(),
         surreal_sync_core::Value::Int64(i) => i.to_string(),
@@ -627,6 +852,131 @@ tables:
         assert!(schema.get_table("users").is_some());
     }
 
+    #[tokio::test]
+    async fn test_excluded_field_is_not_compared() {
+        let schema = test_schema();
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+
+        // Store the generated rows with every email rewritten, as a redact
+        // transform would.
+        let mut generator = DataGenerator::new(schema.clone(), 42);
+        for _ in 0..3 {
+            let row = generator.next_internal_row("users").unwrap();
+            let surreal_sync_core::Value::Uuid(id) = row.id else {
+                panic!("expected a uuid id");
+            };
+            let Some(surreal_sync_core::Value::Int32(age)) = row.get_field("age") else {
+                panic!("expected an int age");
+            };
+            surreal
+                .query("CREATE $record_id CONTENT $content")
+                .bind((
+                    "record_id",
+                    surrealdb2::sql::Thing::from((
+                        "users",
+                        surrealdb2::sql::Id::Uuid(surrealdb2::sql::Uuid::from(id)),
+                    )),
+                ))
+                .bind((
+                    "content",
+                    serde_json::json!({ "email": "<redacted>", "age": age }),
+                ))
+                .await
+                .unwrap()
+                .check()
+                .unwrap();
+        }
+
+        let report = StreamingVerifier::new(surreal.clone(), schema.clone(), 42, "users")
+            .unwrap()
+            .verify_streaming(3)
+            .await
+            .unwrap();
+        assert_eq!(report.mismatched, 3);
+
+        let report = StreamingVerifier::new(surreal, schema, 42, "users")
+            .unwrap()
+            .with_compare_options(CompareOptions::new().exclude("users", ["email"]))
+            .verify_streaming(3)
+            .await
+            .unwrap();
+        assert!(report.is_success(), "{:?}", report.mismatched_rows);
+        assert_eq!(report.matched, 3);
+        assert_eq!(report.excluded_fields, vec!["email".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_parallel_matches_sequential() {
+        let schema = test_schema();
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+
+        // Store 20 generated rows, leaving out row 5 and rewriting the emails
+        // of rows 3 and 11.
+        let mut generator = DataGenerator::new(schema.clone(), 42);
+        for i in 0..20 {
+            let row = generator.next_internal_row("users").unwrap();
+            if i == 5 {
+                continue;
+            }
+            let surreal_sync_core::Value::Uuid(id) = row.id else {
+                panic!("expected a uuid id");
+            };
+            let Some(surreal_sync_core::Value::VarChar { value: email, .. }) =
+                row.get_field("email")
+            else {
+                panic!("expected a varchar email");
+            };
+            let Some(surreal_sync_core::Value::Int32(age)) = row.get_field("age") else {
+                panic!("expected an int age");
+            };
+            let email = if i == 3 || i == 11 {
+                "<redacted>"
+            } else {
+                email.as_str()
+            };
+            surreal
+                .query("CREATE $record_id CONTENT $content")
+                .bind((
+                    "record_id",
+                    surrealdb2::sql::Thing::from((
+                        "users",
+                        surrealdb2::sql::Id::Uuid(surrealdb2::sql::Uuid::from(id)),
+                    )),
+                ))
+                .bind(("content", serde_json::json!({ "email": email, "age": age })))
+                .await
+                .unwrap()
+                .check()
+                .unwrap();
+        }
+
+        let sequential = StreamingVerifier::new(surreal.clone(), schema.clone(), 42, "users")
+            .unwrap()
+            .verify_streaming(20)
+            .await
+            .unwrap();
+        let parallel = StreamingVerifier::new(surreal, schema, 42, "users")
+            .unwrap()
+            .verify_streaming_parallel(20, 4)
+            .await
+            .unwrap();
+
+        for report in [&sequential, &parallel] {
+            assert_eq!(report.matched, 17);
+            assert_eq!(report.found, 19);
+            let missing: Vec<u64> = report.missing_rows.iter().map(|r| r.index).collect();
+            assert_eq!(missing, vec![5]);
+            let mismatched: Vec<u64> = report.mismatched_rows.iter().map(|r| r.index).collect();
+            assert_eq!(mismatched, vec![3, 11]);
+        }
+        assert_eq!(
+            parallel.mismatched_rows[0].record_id,
+            sequential.mismatched_rows[0].record_id
+        );
+    }
+
     #[test]
     fn test_format_id() {
         let uuid = uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
@@ -1,7 +1,9 @@
 //! CLI argument definitions for the loadtest verifier.
 
+use crate::compare::CompareOptions;
 use clap::Args;
 use std::path::PathBuf;
+use surreal_sync_core::ColumnFilterError;
 
 /// Arguments for verifying synced data in SurrealDB.
 #[derive(Args, Clone, Debug)]
@@ -22,6 +24,20 @@ pub struct VerifyArgs {
     #[arg(long, value_delimiter = ',')]
     pub tables: Vec<String>,
 
+    /// Compare only these fields of a table: `table=field1,field2` (repeatable)
+    #[arg(long = "compare-fields", value_name = "TABLE=FIELDS")]
+    pub compare_fields: Vec<String>,
+
+    /// Do not compare these fields of a table, e.g. ones a transform rewrites:
+    /// `table=field1,field2` (repeatable). Wins over `--compare-fields`
+    #[arg(long = "skip-compare-fields", value_name = "TABLE=FIELDS")]
+    pub skip_compare_fields: Vec<String>,
+
+    /// Accept float and decimal fields within this absolute or relative
+    /// difference of the generated value (e.g. 1e-6)
+    #[arg(long)]
+    pub float_epsilon: Option<f64>,
+
     /// SurrealDB endpoint URL
     #[arg(
         long,
@@ -54,3 +70,14 @@ pub struct VerifyArgs {
     #[arg(long)]
     pub aggregator_url: Option<String>,
 }
+
+impl VerifyArgs {
+    /// Parse `--compare-fields` / `--skip-compare-fields` / `--float-epsilon`
+    /// into [`CompareOptions`].
+    pub fn compare_options(&self) -> Result<CompareOptions, ColumnFilterError> {
+        Ok(
+            CompareOptions::parse(&self.compare_fields, &self.skip_compare_fields)?
+                .with_float_epsilon(self.float_epsilon),
+        )
+    }
+}
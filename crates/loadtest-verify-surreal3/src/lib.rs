@@ -1,7 +1,8 @@
 //! Streaming verifier for load testing surreal-sync (SurrealDB v3).
 //!
 //! This crate provides functionality to verify that data synced to SurrealDB v3
-//! matches expected values generated deterministically from the same schema and seed.
+//! matches expected values generated deterministically from the same schema and seed,
+//! or, with [`SourceComparingVerifier3`], the rows read back from the migration source.
 //!
 //! # Example
 //!
@@ -20,10 +21,12 @@ pub mod args;
 pub mod compare;
 pub mod error;
 pub mod report;
+pub mod source_verifier;
 pub mod verifier;
 
 pub use args::VerifyArgs;
-pub use compare::{compare_values, CompareResult};
+pub use compare::{compare_values, compare_values_with_options, CompareOptions, CompareResult};
 pub use error::VerifyError;
 pub use report::{MismatchInfo, VerificationReport};
+pub use source_verifier::SourceComparingVerifier3;
 pub use verifier::StreamingVerifier3;
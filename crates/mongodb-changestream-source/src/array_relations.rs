@@ -0,0 +1,211 @@
+//! Project embedded arrays into SurrealDB relations.
+//!
+//! A `team` document with a `members: [{user_id, role}, ...]` array becomes
+//! one `team -> member -> user` edge per element during full sync.
+
+use std::collections::BTreeMap;
+
+use mongodb::bson::{Bson, Document};
+use serde::Deserialize;
+use surreal_sync_core::{flatten_composite_id, FieldMap, Relation, ThingRef, Type, Value};
+
+use crate::full_sync::{convert_bson_to_universal_value, record_id_from_bson};
+
+/// Emit a relation per element of an array field.
+///
+/// Each edge goes from the parent document's record to a `target_table`
+/// record whose ID is read from the element: the element itself, or its
+/// [`target_id`](Self::target_id) field. Its ID is `parent:target`, so
+/// re-running the sync upserts the same edges and repeated targets in one
+/// array collapse into one edge.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArrayRelation {
+    /// Collection holding the array.
+    pub collection: String,
+    /// Array field on each document.
+    pub field: String,
+    /// Relation (edge) table to write.
+    pub relation: String,
+    /// Table of the records the elements point to.
+    pub target_table: String,
+    /// Element field holding the target ID; unset means the element is the ID.
+    #[serde(default)]
+    pub target_id: Option<String>,
+    /// Element fields copied onto the edge, as `element field -> edge field`.
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+}
+
+impl ArrayRelation {
+    /// Edges of `collection.field` into `target_table`, one per array element.
+    pub fn new(
+        collection: impl Into<String>,
+        field: impl Into<String>,
+        relation: impl Into<String>,
+        target_table: impl Into<String>,
+    ) -> Self {
+        Self {
+            collection: collection.into(),
+            field: field.into(),
+            relation: relation.into(),
+            target_table: target_table.into(),
+            target_id: None,
+            properties: BTreeMap::new(),
+        }
+    }
+
+    /// Read the target ID from this element field.
+    pub fn with_target_id(mut self, field: impl Into<String>) -> Self {
+        self.target_id = Some(field.into());
+        self
+    }
+
+    /// Copy element field `from` onto the edge as `to`.
+    pub fn with_property(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.properties.insert(from.into(), to.into());
+        self
+    }
+
+    /// Relations for one document of [`Self::collection`].
+    ///
+    /// A missing or non-array field yields no edges. Elements without a
+    /// usable target ID are skipped with a warning.
+    pub fn relations_from_document(&self, doc: &Document) -> anyhow::Result<Vec<Relation>> {
+        let parent_id = match doc.get("_id") {
+            Some(id) => record_id_from_bson(id)?,
+            None => anyhow::bail!("Document is missing _id field"),
+        };
+        let Some(Bson::Array(elements)) = doc.get(&self.field) else {
+            return Ok(Vec::new());
+        };
+
+        let mut relations = Vec::with_capacity(elements.len());
+        for (index, element) in elements.iter().enumerate() {
+            let target = match &self.target_id {
+                None => Some(element),
+                Some(key) => match element {
+                    Bson::Document(sub) => sub.get(key),
+                    _ => None,
+                },
+            };
+            let target_id = match target {
+                Some(Bson::Null) | None => None,
+                Some(bson) => record_id_from_bson(bson).ok(),
+            };
+            let Some(target_id) = target_id else {
+                tracing::warn!(
+                    "Skipping {}.{}[{index}] of {parent_id:?}: no usable target ID",
+                    self.collection,
+                    self.field
+                );
+                continue;
+            };
+
+            let mut data = FieldMap::new();
+            if let Bson::Document(sub) = element {
+                for (from, to) in &self.properties {
+                    if let Some(value) = sub.get(from) {
+                        data.insert(to.clone(), convert_bson_to_universal_value(value.clone())?);
+                    }
+                }
+            }
+
+            let id = flatten_composite_id(
+                Value::Array {
+                    elements: vec![parent_id.clone(), target_id.clone()],
+                    element_type: Box::new(Type::Text),
+                },
+                ":",
+            );
+            relations.push(Relation::new(
+                self.relation.clone(),
+                id,
+                ThingRef::new(self.collection.clone(), parent_id.clone()),
+                ThingRef::new(self.target_table.clone(), target_id),
+                data,
+            ));
+        }
+        Ok(relations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+
+    fn endpoint(thing: &ThingRef) -> (&str, Value) {
+        (thing.table.as_str(), thing.id.clone())
+    }
+
+    #[test]
+    fn members_array_becomes_member_edges() {
+        let spec = ArrayRelation::new("team", "members", "member", "user")
+            .with_target_id("user_id")
+            .with_property("role", "role");
+        let team = doc! {
+            "_id": "core",
+            "name": "Core",
+            "members": [
+                { "user_id": "alice", "role": "lead", "email": "a@example.com" },
+                { "user_id": 42_i64 },
+                { "role": "orphan" },
+            ],
+        };
+
+        let edges = spec.relations_from_document(&team).unwrap();
+        assert_eq!(edges.len(), 2, "the element without user_id is skipped");
+
+        assert_eq!(edges[0].relation_type, "member");
+        assert_eq!(edges[0].id, Value::Text("core:alice".into()));
+        assert_eq!(
+            endpoint(&edges[0].input),
+            ("team", Value::Text("core".into()))
+        );
+        assert_eq!(
+            endpoint(&edges[0].output),
+            ("user", Value::Text("alice".into()))
+        );
+        assert_eq!(edges[0].data.len(), 1);
+        assert_eq!(edges[0].data["role"], Value::Text("lead".into()));
+
+        assert_eq!(edges[1].id, Value::Text("core:42".into()));
+        assert_eq!(endpoint(&edges[1].output), ("user", Value::Int64(42)));
+        assert!(edges[1].data.is_empty());
+    }
+
+    #[test]
+    fn scalar_elements_are_target_ids() {
+        let spec = ArrayRelation::new("post", "tags", "tagged", "tag");
+        let post = doc! { "_id": 1_i32, "tags": ["rust", "db"] };
+        let edges = spec.relations_from_document(&post).unwrap();
+        let targets: Vec<_> = edges.iter().map(|e| endpoint(&e.output)).collect();
+        assert_eq!(
+            targets,
+            vec![
+                ("tag", Value::Text("rust".into())),
+                ("tag", Value::Text("db".into())),
+            ]
+        );
+
+        let untagged = doc! { "_id": 2_i32 };
+        assert!(spec.relations_from_document(&untagged).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parses_from_json() {
+        let spec: ArrayRelation = serde_json::from_str(
+            r#"{"collection": "team", "field": "members", "relation": "member",
+                "target_table": "user", "target_id": "user_id",
+                "properties": {"role": "role"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            spec,
+            ArrayRelation::new("team", "members", "member", "user")
+                .with_target_id("user_id")
+                .with_property("role", "role")
+        );
+    }
+}
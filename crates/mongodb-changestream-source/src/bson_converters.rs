@@ -0,0 +1,274 @@
+//! Caller-registered BSON conversions for app-specific encodings.
+//!
+//! Applications sometimes store values the generic conversion cannot
+//! interpret: money as a `{amount, currency}` sub-document, a legacy date
+//! kept as a string, and so on. A [`BsonConverters`] registry maps a field
+//! path (`collection.field`, or `collection.parent.child` for fields of
+//! sub-documents) to a function from the raw [`Bson`] to a [`Value`]. Full
+//! and incremental sync consult it before the default conversion.
+//!
+//! Paths do not name array positions: a converter registered for
+//! `orders.items.price` applies to the `price` of every sub-document in the
+//! `items` array.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use mongodb::bson::{Bson, Document};
+use surreal_sync_core::{FieldMap, TableDefinition, Type, Value};
+
+use crate::full_sync::convert_bson_to_universal_value_with_schema;
+
+/// A custom conversion for one field path.
+pub type BsonConverterFn = dyn Fn(&Bson) -> anyhow::Result<Value> + Send + Sync;
+
+/// Registry of custom BSON conversions keyed by `collection.field` path.
+#[derive(Clone, Default)]
+pub struct BsonConverters {
+    by_path: HashMap<String, Arc<BsonConverterFn>>,
+}
+
+impl std::fmt::Debug for BsonConverters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut paths: Vec<&String> = self.by_path.keys().collect();
+        paths.sort();
+        f.debug_struct("BsonConverters")
+            .field("paths", &paths)
+            .finish()
+    }
+}
+
+impl BsonConverters {
+    /// An empty registry (default conversion everywhere).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `converter` for `path` (`collection.field`, dot-separated for
+    /// nested fields), replacing any converter already registered there.
+    pub fn register<F>(mut self, path: impl Into<String>, converter: F) -> Self
+    where
+        F: Fn(&Bson) -> anyhow::Result<Value> + Send + Sync + 'static,
+    {
+        self.by_path.insert(path.into(), Arc::new(converter));
+        self
+    }
+
+    /// Whether no converter is registered.
+    pub fn is_empty(&self) -> bool {
+        self.by_path.is_empty()
+    }
+
+    /// Converter registered for `path`, if any.
+    pub fn get(&self, path: &str) -> Option<&BsonConverterFn> {
+        self.by_path.get(path).map(|f| f.as_ref())
+    }
+
+    /// Whether any converter is registered below `path` (for a nested field).
+    fn has_nested(&self, path: &str) -> bool {
+        self.by_path.keys().any(|p| {
+            p.len() > path.len() && p.starts_with(path) && p[path.len()..].starts_with('.')
+        })
+    }
+
+    /// Convert the field at `path` (`collection.field...`), using a
+    /// registered converter when there is one and the default conversion
+    /// (schema-aware when `field_type` is known) otherwise.
+    ///
+    /// Sub-documents, and arrays of them, with converters registered below
+    /// them are converted field by field so the nested converters apply.
+    pub fn convert(
+        &self,
+        path: &str,
+        value: Bson,
+        field_type: Option<&Type>,
+    ) -> anyhow::Result<Value> {
+        self.convert_at(path, value, field_type, None)
+    }
+
+    /// Like [`convert`](Self::convert), with field types taken from `table`:
+    /// the field's own column, and for nested fields the column named by
+    /// their dotted path below the collection (e.g. `profile.joined`).
+    pub fn convert_with_schema(
+        &self,
+        path: &str,
+        value: Bson,
+        table: Option<&TableDefinition>,
+    ) -> anyhow::Result<Value> {
+        let field_type = column_type(table, path);
+        self.convert_at(path, value, field_type, table)
+    }
+
+    fn convert_at(
+        &self,
+        path: &str,
+        value: Bson,
+        field_type: Option<&Type>,
+        table: Option<&TableDefinition>,
+    ) -> anyhow::Result<Value> {
+        if let Some(converter) = self.get(path) {
+            return converter(&value)
+                .map_err(|e| anyhow::anyhow!("custom BSON converter for '{path}' failed: {e}"));
+        }
+        self.convert_nested(path, value, field_type, table)
+    }
+
+    /// Default conversion of `value`, descending into sub-documents and
+    /// array elements when converters are registered below `path`.
+    fn convert_nested(
+        &self,
+        path: &str,
+        value: Bson,
+        field_type: Option<&Type>,
+        table: Option<&TableDefinition>,
+    ) -> anyhow::Result<Value> {
+        match value {
+            Bson::Document(doc) if self.has_nested(path) && !is_dbref(&doc) => {
+                let mut fields = FieldMap::with_capacity(doc.len());
+                for (key, value) in doc {
+                    let child = format!("{path}.{key}");
+                    let v = self.convert_at(&child, value, column_type(table, &child), table)?;
+                    fields.insert(key, v);
+                }
+                Ok(Value::Object(fields))
+            }
+            Bson::Array(items) if self.has_nested(path) => {
+                let element_type = match field_type {
+                    Some(Type::Array { element_type }) => Some(element_type.as_ref()),
+                    _ => None,
+                };
+                let elements = items
+                    .into_iter()
+                    .map(|item| self.convert_nested(path, item, element_type, table))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Value::Array {
+                    elements,
+                    element_type: Box::new(element_type.cloned().unwrap_or(Type::Json)),
+                })
+            }
+            value => convert_bson_to_universal_value_with_schema(value, field_type),
+        }
+    }
+}
+
+/// Type of the column `path` names below its collection, if `table` has one.
+fn column_type<'a>(table: Option<&'a TableDefinition>, path: &str) -> Option<&'a Type> {
+    let (_, column) = path.split_once('.')?;
+    table?.get_column_type(column)
+}
+
+fn is_dbref(doc: &Document) -> bool {
+    doc.contains_key("$ref") && doc.contains_key("$id")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+    use surreal_sync_core::ColumnDefinition;
+
+    /// `{amount: "12.34", currency: "EUR"}` → decimal amount.
+    fn money(value: &Bson) -> anyhow::Result<Value> {
+        let doc = value
+            .as_document()
+            .ok_or_else(|| anyhow::anyhow!("money must be a document"))?;
+        let amount = match doc.get("amount") {
+            Some(Bson::String(s)) => s.clone(),
+            Some(Bson::Decimal128(d)) => d.to_string(),
+            other => anyhow::bail!("unsupported money amount {other:?}"),
+        };
+        amount.parse::<f64>()?;
+        let scale = amount.split_once('.').map_or(0, |(_, frac)| frac.len());
+        Ok(Value::decimal(amount, 38, scale as u8))
+    }
+
+    #[test]
+    fn test_custom_converter_for_money_subdocument() {
+        let converters = BsonConverters::new().register("orders.total", money);
+        let total = doc! { "amount": "12.34", "currency": "EUR" };
+
+        let converted = converters
+            .convert("orders.total", Bson::Document(total.clone()), None)
+            .unwrap();
+        assert_eq!(converted, Value::decimal("12.34", 38, 2));
+
+        // Other collections and fields keep the default conversion.
+        let default = converters
+            .convert("invoices.total", Bson::Document(total), None)
+            .unwrap();
+        assert!(matches!(default, Value::Object(ref o) if o.len() == 2));
+    }
+
+    #[test]
+    fn test_nested_converter_and_errors() {
+        let legacy_date = |value: &Bson| -> anyhow::Result<Value> {
+            let s = value
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("expected a string"))?;
+            let date = chrono::NaiveDate::parse_from_str(s, "%d/%m/%Y")?;
+            Ok(Value::Date(date.and_time(chrono::NaiveTime::MIN).and_utc()))
+        };
+        let converters = BsonConverters::new().register("users.profile.joined", legacy_date);
+
+        let profile = doc! { "joined": "02/01/2020", "nick": "al" };
+        let Value::Object(fields) = converters
+            .convert("users.profile", Bson::Document(profile), None)
+            .unwrap()
+        else {
+            panic!("expected an object");
+        };
+        assert_eq!(
+            fields.get("joined"),
+            Some(&Value::Date("2020-01-02T00:00:00Z".parse().unwrap()))
+        );
+        assert_eq!(fields.get("nick"), Some(&Value::Text("al".to_string())));
+
+        let err = converters
+            .convert("users.profile", Bson::Document(doc! { "joined": 5 }), None)
+            .unwrap_err();
+        assert!(err.to_string().contains("users.profile.joined"), "{err}");
+    }
+
+    #[test]
+    fn test_nested_converters_apply_inside_arrays_with_schema_types() {
+        let converters = BsonConverters::new().register("orders.items.price", money);
+        let table = TableDefinition::new(
+            "orders",
+            ColumnDefinition::new("_id", Type::Text),
+            vec![
+                ColumnDefinition::new(
+                    "items",
+                    Type::Array {
+                        element_type: Box::new(Type::Object),
+                    },
+                ),
+                ColumnDefinition::new("items.qty", Type::Int32),
+            ],
+        );
+        let items = Bson::Array(vec![
+            Bson::Document(doc! { "price": {"amount": "1.50"}, "qty": 2 }),
+            Bson::Document(doc! { "price": {"amount": "3"}, "qty": 1 }),
+        ]);
+
+        let Value::Array {
+            elements,
+            element_type,
+        } = converters
+            .convert_with_schema("orders.items", items, Some(&table))
+            .unwrap()
+        else {
+            panic!("expected an array");
+        };
+        assert_eq!(*element_type, Type::Object);
+        let Value::Object(first) = &elements[0] else {
+            panic!("expected an object");
+        };
+        assert_eq!(first.get("price"), Some(&Value::decimal("1.50", 38, 2)));
+        // The nested column type from the schema still applies.
+        assert_eq!(first.get("qty"), Some(&Value::Int32(2)));
+        let Value::Object(second) = &elements[1] else {
+            panic!("expected an object");
+        };
+        assert_eq!(second.get("price"), Some(&Value::decimal("3", 38, 0)));
+    }
+}
@@ -6,20 +6,51 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use mongodb::{
+    change_stream::event::ResumeToken,
+    error::ErrorKind,
     options::{ChangeStreamOptions, FullDocumentType},
     Client as MongoClient,
 };
 use serde::{Deserialize, Serialize};
+use surreal_sync_core::CheckpointValidity;
 
 /// MongoDB-specific checkpoint containing resume token and timestamp
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MongoDBCheckpoint {
-    /// Resume token for MongoDB change stream
+    /// Resume token for MongoDB change stream (BSON bytes, base64 in JSON)
+    #[serde(with = "resume_token_base64")]
     pub resume_token: Vec<u8>,
     /// Timestamp when checkpoint was created
     pub timestamp: DateTime<Utc>,
 }
 
+/// Serde for [`MongoDBCheckpoint::resume_token`]: a base64 string, while
+/// still reading checkpoint files written as a plain byte array.
+mod resume_token_base64 {
+    use base64::{engine::general_purpose, Engine as _};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Encoded {
+        Base64(String),
+        Bytes(Vec<u8>),
+    }
+
+    pub fn serialize<S: Serializer>(token: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&general_purpose::STANDARD.encode(token))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        match Encoded::deserialize(deserializer)? {
+            Encoded::Base64(encoded) => general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(|e| serde::de::Error::custom(format!("Invalid base64 resume token: {e}"))),
+            Encoded::Bytes(bytes) => Ok(bytes),
+        }
+    }
+}
+
 impl surreal_sync_core::Checkpoint for MongoDBCheckpoint {
     const DATABASE_TYPE: &'static str = "mongodb";
 
@@ -84,6 +115,30 @@ pub async fn get_resume_token(client: &MongoClient, database: &str) -> Result<Ve
     ))
 }
 
+/// Cluster time encoded in a serialized resume token.
+///
+/// A token's `_data` is a hex KeyString that starts with the event's cluster
+/// time: the Timestamp type byte `0x82`, then the seconds and increment as
+/// big-endian `u32`s. A snapshot read at that time sees exactly the writes a
+/// change stream resumed after the token will not replay.
+pub fn resume_token_cluster_time(resume_token: &[u8]) -> Result<bson::Timestamp> {
+    let token = bson::from_slice::<bson::Document>(resume_token)
+        .map_err(|e| anyhow::anyhow!("Failed to deserialize resume token: {e}"))?;
+    let data = token
+        .get_str("_data")
+        .map_err(|_| anyhow::anyhow!("Resume token has no hex _data field"))?;
+    let field = |range: std::ops::Range<usize>| {
+        data.get(range)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+    };
+    match (data.get(..2), field(2..10), field(10..18)) {
+        (Some(tag), Some(time), Some(increment)) if tag.eq_ignore_ascii_case("82") => {
+            Ok(bson::Timestamp { time, increment })
+        }
+        _ => anyhow::bail!("Resume token '{data}' does not start with a cluster time"),
+    }
+}
+
 /// Get current checkpoint from MongoDB
 ///
 /// This is a GENERATION operation - it queries MongoDB for the current resume token
@@ -99,6 +154,54 @@ pub async fn get_current_checkpoint(
     })
 }
 
+/// Server error codes meaning a resume token has rolled off the oplog:
+/// `CappedPositionLost`, `ChangeStreamFatalError` and `ChangeStreamHistoryLost`.
+const HISTORY_LOST_CODES: [i32; 3] = [136, 280, 286];
+
+/// Whether `error` means the resume token is no longer in the oplog.
+pub(crate) fn is_history_lost(error: &mongodb::error::Error) -> bool {
+    matches!(*error.kind, ErrorKind::Command(ref command) if HISTORY_LOST_CODES.contains(&command.code))
+}
+
+/// Check whether a change stream can still resume from `checkpoint`.
+///
+/// Opens a change stream with `resumeAfter` and pulls one batch; the server
+/// rejects the token once its position is no longer in the oplog window.
+pub async fn validate_checkpoint(
+    client: &MongoClient,
+    database: &str,
+    checkpoint: &MongoDBCheckpoint,
+) -> Result<CheckpointValidity> {
+    let resume_token = bson::from_slice::<ResumeToken>(&checkpoint.resume_token)
+        .map_err(|e| anyhow::anyhow!("Failed to deserialize resume token: {e}"))?;
+    let mut options = ChangeStreamOptions::default();
+    options.resume_after = Some(resume_token);
+
+    let resumed = async {
+        let mut change_stream = client
+            .database(database)
+            .watch()
+            .with_options(options)
+            .await?;
+        change_stream.next_if_any().await?;
+        Ok::<_, mongodb::error::Error>(())
+    }
+    .await;
+
+    match resumed {
+        Ok(()) => Ok(CheckpointValidity::Valid),
+        Err(e) => match *e.kind {
+            ErrorKind::Command(ref command) if is_history_lost(&e) => {
+                Ok(CheckpointValidity::too_old(format!(
+                    "resume token is no longer in the oplog ({}: {})",
+                    command.code_name, command.message
+                )))
+            }
+            _ => Err(e.into()),
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +210,20 @@ mod tests {
     use surreal_sync_runtime::checkpoint_fs::FilesystemStore;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_resume_token_cluster_time() {
+        let token = bson::to_vec(&bson::doc! {
+            "_data": "8266F1A2B3000000042B022C0100296E5A1004"
+        })
+        .unwrap();
+        let time = resume_token_cluster_time(&token).unwrap();
+        assert_eq!(time.time, 0x66F1_A2B3);
+        assert_eq!(time.increment, 4);
+
+        let not_a_time = bson::to_vec(&bson::doc! { "_data": "4600" }).unwrap();
+        assert!(resume_token_cluster_time(&not_a_time).is_err());
+    }
+
     #[test]
     fn test_mongodb_checkpoint_cli_string_roundtrip() {
         // Create a checkpoint with realistic data
@@ -200,6 +317,26 @@ mod tests {
         assert_eq!(original.resume_token, loaded.resume_token);
     }
 
+    #[test]
+    fn test_mongodb_checkpoint_json_resume_token_base64() {
+        let checkpoint = MongoDBCheckpoint {
+            resume_token: vec![1, 2, 3, 250],
+            timestamp: Utc::now(),
+        };
+        let json = serde_json::to_value(&checkpoint).unwrap();
+        assert_eq!(json["resume_token"], "AQID+g==");
+        let decoded: MongoDBCheckpoint = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, checkpoint);
+
+        // Checkpoint files written before the token was base64-encoded
+        let legacy: MongoDBCheckpoint = serde_json::from_value(serde_json::json!({
+            "resume_token": [1, 2, 3, 250],
+            "timestamp": "2024-01-01T00:00:00Z",
+        }))
+        .unwrap();
+        assert_eq!(legacy.resume_token, vec![1, 2, 3, 250]);
+    }
+
     #[test]
     fn test_mongodb_checkpoint_empty_resume_token() {
         // Empty resume token should be rejected
@@ -2,15 +2,21 @@
 //!
 //! This module provides full synchronization from MongoDB to SurrealDB.
 
-use mongodb::{bson::doc, options::ClientOptions, Client as MongoClient};
+use mongodb::{
+    bson::{doc, Document, Timestamp},
+    options::ClientOptions,
+    Client as MongoClient, ClientSession,
+};
 use mongodb_types::BsonValueWithSchema;
-use std::collections::HashMap;
 use std::time::Duration;
-use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{Checkpoint, CheckpointStore, SyncManager, SyncPhase};
-use surreal_sync_core::{DatabaseSchema, Row, Type, Value};
+use surreal_sync_core::{ColumnFilter, ColumnFilters, SurrealSink};
+use surreal_sync_core::{DatabaseSchema, FieldMap, Row, Type, Value};
 use surreal_sync_runtime::{ApplyOpts, Pipeline};
 
+use crate::projection::merge_find_projection;
+use crate::{ArrayRelation, BsonConverters};
+
 /// Source database connection options (MongoDB-specific, library type without clap)
 #[derive(Clone, Debug)]
 pub struct SourceOpts {
@@ -18,6 +24,65 @@ pub struct SourceOpts {
     pub source_database: Option<String>,
     /// Collections to sync (empty means all collections)
     pub collections: Vec<String>,
+    /// Custom conversions for app-specific field encodings, consulted before
+    /// the default BSON conversion in full and incremental sync
+    pub bson_converters: BsonConverters,
+    /// Per-collection field include/exclude lists, pushed into the full-sync
+    /// `find` as a projection. `_id` is always read.
+    pub column_filters: ColumnFilters,
+    /// Embedded arrays written as relations after their collection's
+    /// documents (full sync only)
+    pub array_relations: Vec<ArrayRelation>,
+    /// Only sync these collections (empty means all collections). Applies to
+    /// full sync and to the change stream.
+    pub include_collections: Vec<String>,
+    /// Never sync these collections, even when listed in `include_collections`
+    pub exclude_collections: Vec<String>,
+    /// Projection applied to every collection (e.g. `{ "blob": 0 }`): pushed
+    /// into the full-sync `find`, and applied to changed documents after
+    /// decode in the change stream. `_id` is always kept.
+    pub projection: Option<Document>,
+}
+
+impl SourceOpts {
+    /// Collection include/exclude filter built from
+    /// `include_collections` and `exclude_collections`
+    pub fn collection_filter(&self) -> CollectionFilter {
+        CollectionFilter {
+            include: self.include_collections.clone(),
+            exclude: self.exclude_collections.clone(),
+        }
+    }
+}
+
+/// Which collections full sync migrates and the change stream reports
+#[derive(Clone, Debug, Default)]
+pub struct CollectionFilter {
+    /// Collections to keep (empty keeps every collection)
+    pub include: Vec<String>,
+    /// Collections to drop; wins over `include`
+    pub exclude: Vec<String>,
+}
+
+impl CollectionFilter {
+    /// Whether `collection` passes the filter
+    pub fn allows(&self, collection: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|c| c == collection))
+            && !self.exclude.iter().any(|c| c == collection)
+    }
+
+    /// Change stream `$match` stage on `ns.coll`, or `None` when the filter
+    /// keeps every collection
+    pub fn change_stream_match(&self) -> Option<Document> {
+        let mut condition = Document::new();
+        if !self.include.is_empty() {
+            condition.insert("$in", self.include.clone());
+        }
+        if !self.exclude.is_empty() {
+            condition.insert("$nin", self.exclude.clone());
+        }
+        (!condition.is_empty()).then(|| doc! { "$match": { "ns.coll": condition } })
+    }
 }
 
 /// Sync options (non-connection related)
@@ -27,6 +92,14 @@ pub struct SyncOpts {
     pub dry_run: bool,
     /// Optional schema for type-aware conversion (e.g., Decimal fields)
     pub schema: Option<DatabaseSchema>,
+    /// Full-sync BSON conversion workers running alongside the cursor read
+    /// (clamped to at least 1). Output order is unchanged.
+    pub conversion_workers: usize,
+    /// Read every collection at the cluster time of the change-stream token
+    /// captured before the scan, so FullSyncStart and FullSyncEnd are that
+    /// one token and incremental sync neither misses nor replays a change.
+    /// Needs MongoDB 5.0+ snapshot reads.
+    pub consistent_snapshot: bool,
 }
 
 /// Parse an ISO 8601 duration string (PTxS or PTx.xxxxxxxxxS format).
@@ -47,6 +120,39 @@ fn try_parse_iso8601_duration(s: &str) -> Option<std::time::Duration> {
     }
 }
 
+/// `find` projection for a filtered collection.
+///
+/// MongoDB projections cannot mix inclusion and exclusion, so an include list
+/// becomes an inclusion projection (minus excluded fields) and an exclude-only
+/// filter becomes an exclusion projection. `_id` always stays: it is the
+/// record id.
+fn find_projection(filter: &ColumnFilter) -> Option<Document> {
+    let mut projection = Document::new();
+    match &filter.include {
+        Some(include) => {
+            let mut fields: Vec<&String> =
+                include.iter().filter(|field| filter.keeps(field)).collect();
+            fields.sort();
+            projection.insert("_id", 1);
+            for field in fields {
+                projection.insert(field.as_str(), 1);
+            }
+        }
+        None => {
+            let mut fields: Vec<&String> = filter
+                .exclude
+                .iter()
+                .filter(|field| field.as_str() != "_id")
+                .collect();
+            fields.sort();
+            for field in fields {
+                projection.insert(field.as_str(), 0);
+            }
+        }
+    }
+    (!projection.is_empty()).then_some(projection)
+}
+
 /// Simple migration entry point (no checkpoint support)
 pub async fn migrate_from_mongodb<S: SurrealSink>(
     surreal: &S,
@@ -140,8 +246,42 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     tracing::debug!("Using MongoDB database: {}", source_db_name);
     let mongo_db = mongo_client.database(&source_db_name);
 
+    // A consistent snapshot reads at the cluster time of the start token, so
+    // the same token is both the start and the end checkpoint.
+    let snapshot_start = if sync_opts.consistent_snapshot {
+        let resume_token =
+            crate::checkpoint::get_resume_token(&mongo_client, &source_db_name).await?;
+        let snapshot_time = crate::checkpoint::resume_token_cluster_time(&resume_token)?;
+        tracing::info!(
+            "Reading a consistent snapshot at cluster time {}.{}",
+            snapshot_time.time,
+            snapshot_time.increment
+        );
+        Some((
+            snapshot_time,
+            crate::checkpoint::MongoDBCheckpoint {
+                resume_token,
+                timestamp: chrono::Utc::now(),
+            },
+        ))
+    } else {
+        None
+    };
+    let snapshot_time = snapshot_start.as_ref().map(|(time, _)| *time);
+
     // Emit checkpoint t1 (before full sync starts) if configured
-    let _checkpoint_t1 = if let Some(manager) = sync_manager {
+    let _checkpoint_t1 = if let Some((_, checkpoint)) = &snapshot_start {
+        if let Some(manager) = sync_manager {
+            manager
+                .emit_checkpoint(checkpoint, SyncPhase::FullSyncStart)
+                .await?;
+            tracing::info!(
+                "Emitted full sync start checkpoint (t1): {}",
+                checkpoint.to_cli_string()
+            );
+        }
+        Some(checkpoint.clone())
+    } else if let Some(manager) = sync_manager {
         // Get current resume token from MongoDB before creating source
         let initial_resume_token =
             crate::checkpoint::get_resume_token(&mongo_client, &source_db_name).await?;
@@ -177,6 +317,18 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     tracing::info!("Found {} collections in MongoDB", collection_names.len());
     tracing::debug!("Collections: {:?}", collection_names);
 
+    let collection_filter = from_opts.collection_filter();
+    let collection_names: Vec<String> = collection_names
+        .into_iter()
+        .filter(|name| {
+            let allowed = collection_filter.allows(name);
+            if !allowed {
+                tracing::info!("Skipping filtered-out collection: {name}");
+            }
+            allowed
+        })
+        .collect();
+
     let mut total_migrated = 0;
 
     for collection_name in collection_names {
@@ -187,7 +339,16 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
 
         // Count total documents in collection
         tracing::debug!("Counting documents in collection: {}", collection_name);
-        let total_docs = collection.count_documents(doc! {}).await?;
+        let total_docs = match snapshot_time {
+            Some(time) => {
+                let mut session = snapshot_session(&mongo_client, time).await?;
+                collection
+                    .count_documents(doc! {})
+                    .session(&mut session)
+                    .await?
+            }
+            None => collection.count_documents(doc! {}).await?,
+        };
         tracing::info!(
             "Collection '{}' contains {} documents",
             collection_name,
@@ -201,7 +362,22 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
         // Process documents through a long-lived RowChunkDriver so the next
         // cursor read can overlap prior-chunk transform/sink when max_in_flight > 1.
         tracing::debug!("Creating cursor for collection: {}", collection_name);
-        let cursor = collection.find(doc! {}).await?;
+        let projection = merge_find_projection(
+            from_opts.projection.as_ref(),
+            from_opts
+                .column_filters
+                .for_table(&collection_name)
+                .and_then(find_projection),
+            &collection_name,
+        )?;
+        let cursor = DocumentCursor::open(
+            &mongo_client,
+            &collection,
+            doc! {},
+            projection,
+            snapshot_time,
+        )
+        .await?;
         tracing::debug!(
             "Cursor created successfully for collection: {}",
             collection_name
@@ -210,7 +386,7 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
         if sync_opts.dry_run {
             let mut cursor = cursor;
             let mut processed = 0usize;
-            while cursor.advance().await? {
+            while cursor.next_document().await?.is_some() {
                 processed += 1;
             }
             total_migrated += processed;
@@ -225,68 +401,71 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
         use async_trait::async_trait;
         use std::sync::Arc;
         use surreal_sync_runtime::{
-            run_source_runtime_with, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
+            run_source_runtime_with, ConvertingRowChunks, RawChunkSource, RowChunkDriver,
+            SourceRuntimeOpts,
         };
 
-        struct MongoCursorChunks<'a> {
-            cursor: mongodb::Cursor<mongodb::bson::Document>,
+        struct MongoCursorReader {
+            cursor: DocumentCursor,
             collection_name: String,
             batch_size: usize,
-            schema: Option<&'a DatabaseSchema>,
-            next_index: u64,
-            exhausted: bool,
+            read: u64,
         }
 
         #[async_trait]
-        impl RowChunkSource for MongoCursorChunks<'_> {
-            async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Row>>> {
-                if self.exhausted {
-                    return Ok(None);
-                }
+        impl RawChunkSource for MongoCursorReader {
+            type Raw = mongodb::bson::Document;
+
+            async fn next_raw_chunk(&mut self) -> anyhow::Result<Option<Vec<Self::Raw>>> {
                 let mut batch = Vec::with_capacity(self.batch_size);
                 while batch.len() < self.batch_size {
-                    if !self.cursor.advance().await? {
-                        self.exhausted = true;
-                        break;
-                    }
-                    let doc_owned: mongodb::bson::Document = self.cursor.current().try_into()?;
-                    if std::env::var("SURREAL_SYNC_DEBUG").is_ok() {
-                        tracing::debug!("BSON document: {:?}", doc_owned);
+                    match self.cursor.next_document().await? {
+                        Some(doc) => batch.push(doc),
+                        None => break,
                     }
-                    let row = convert_bson_document_to_record_with_schema(
-                        doc_owned,
-                        &self.collection_name,
-                        self.next_index,
-                        self.schema,
-                    )?;
-                    if std::env::var("SURREAL_SYNC_DEBUG").is_ok() {
-                        tracing::debug!("Final document for SurrealDB: {row:?}");
-                    }
-                    self.next_index = self.next_index.saturating_add(1);
-                    batch.push(row);
                 }
                 if batch.is_empty() {
-                    self.exhausted = true;
                     return Ok(None);
                 }
+                self.read = self.read.saturating_add(batch.len() as u64);
                 tracing::info!(
                     "Read {} documents from '{}' (index {})",
                     batch.len(),
                     self.collection_name,
-                    self.next_index
+                    self.read
                 );
                 Ok(Some(batch))
             }
         }
 
-        let chunks = MongoCursorChunks {
+        let reader = MongoCursorReader {
             cursor,
             collection_name: collection_name.clone(),
             batch_size: sync_opts.batch_size.max(1),
-            schema: sync_opts.schema.as_ref(),
-            next_index: 0,
-            exhausted: false,
+            read: 0,
         };
+        // BSON conversion is CPU-bound: run it on `conversion_workers`
+        // blocking workers while the reader fetches the next batch.
+        let schema = sync_opts.schema.clone();
+        let converters = from_opts.bson_converters.clone();
+        let table = collection_name.clone();
+        let chunks =
+            ConvertingRowChunks::spawn(reader, sync_opts.conversion_workers, move |doc, index| {
+                if std::env::var("SURREAL_SYNC_DEBUG").is_ok() {
+                    tracing::debug!("BSON document: {:?}", doc);
+                }
+                let row = convert_bson_document_to_record_with_converters(
+                    doc,
+                    &table,
+                    index,
+                    schema.as_ref(),
+                    &converters,
+                )?;
+                if std::env::var("SURREAL_SYNC_DEBUG").is_ok() {
+                    tracing::debug!("Final document for SurrealDB: {row:?}");
+                }
+                Ok(row)
+            });
         let mut driver = RowChunkDriver::new(chunks);
         let transformer = Arc::new(pipeline.clone());
         let runtime_opts = SourceRuntimeOpts::new();
@@ -300,10 +479,43 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
             collection_name,
             processed
         );
+
+        for spec in from_opts
+            .array_relations
+            .iter()
+            .filter(|spec| spec.collection == collection_name)
+        {
+            let written = migrate_array_relation(
+                surreal,
+                &mongo_client,
+                &collection,
+                snapshot_time,
+                spec,
+                sync_opts.batch_size,
+                pipeline,
+                apply_opts,
+            )
+            .await?;
+            tracing::info!(
+                "Completed '{}' relations from '{}.{}': {} edges",
+                spec.relation,
+                collection_name,
+                spec.field,
+                written
+            );
+        }
     }
 
     // Emit checkpoint t2 (after full sync completes) if configured
-    if let Some(manager) = sync_manager {
+    if let (Some(manager), Some((_, checkpoint))) = (sync_manager, &snapshot_start) {
+        manager
+            .emit_checkpoint(checkpoint, SyncPhase::FullSyncEnd)
+            .await?;
+        tracing::info!(
+            "Emitted full sync end checkpoint (t2), equal to the snapshot start: {}",
+            checkpoint.to_cli_string()
+        );
+    } else if let Some(manager) = sync_manager {
         // Get current checkpoint after migration
         let database_name = from_opts
             .source_database
@@ -338,6 +550,134 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     Ok(())
 }
 
+/// A `find` cursor, bound to its own snapshot session when the full sync
+/// reads at a fixed cluster time. Sessions sharing a `snapshot_time` see the
+/// same data, so each collection can open one independently.
+enum DocumentCursor {
+    Plain(Box<mongodb::Cursor<Document>>),
+    Snapshot(Box<(mongodb::SessionCursor<Document>, ClientSession)>),
+}
+
+impl DocumentCursor {
+    async fn open(
+        client: &MongoClient,
+        collection: &mongodb::Collection<Document>,
+        filter: Document,
+        projection: Option<Document>,
+        snapshot_time: Option<Timestamp>,
+    ) -> anyhow::Result<Self> {
+        use mongodb::action::Action;
+
+        match snapshot_time {
+            Some(time) => {
+                let mut session = snapshot_session(client, time).await?;
+                let cursor = collection
+                    .find(filter)
+                    .optional(projection, |find, projection| find.projection(projection))
+                    .session(&mut session)
+                    .await?;
+                Ok(Self::Snapshot(Box::new((cursor, session))))
+            }
+            None => {
+                let cursor = collection
+                    .find(filter)
+                    .optional(projection, |find, projection| find.projection(projection))
+                    .await?;
+                Ok(Self::Plain(Box::new(cursor)))
+            }
+        }
+    }
+
+    async fn next_document(&mut self) -> anyhow::Result<Option<Document>> {
+        let advanced = match self {
+            Self::Plain(cursor) => cursor.advance().await?,
+            Self::Snapshot(snapshot) => {
+                let (cursor, session) = snapshot.as_mut();
+                cursor.advance(session).await?
+            }
+        };
+        if !advanced {
+            return Ok(None);
+        }
+        let current = match self {
+            Self::Plain(cursor) => cursor.current(),
+            Self::Snapshot(snapshot) => snapshot.0.current(),
+        };
+        Ok(Some(current.try_into()?))
+    }
+}
+
+/// Start a session whose reads all observe the data as of `snapshot_time`.
+async fn snapshot_session(
+    client: &MongoClient,
+    snapshot_time: Timestamp,
+) -> anyhow::Result<ClientSession> {
+    Ok(client
+        .start_session()
+        .snapshot(true)
+        .snapshot_time(snapshot_time)
+        .await?)
+}
+
+/// Write the relations of one [`ArrayRelation`] with a second cursor over the
+/// collection, reading only `_id` and the array field.
+#[allow(clippy::too_many_arguments)]
+async fn migrate_array_relation<S: SurrealSink>(
+    surreal: &S,
+    client: &MongoClient,
+    collection: &mongodb::Collection<Document>,
+    snapshot_time: Option<Timestamp>,
+    spec: &ArrayRelation,
+    batch_size: usize,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> anyhow::Result<u64> {
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use surreal_sync_core::Relation;
+    use surreal_sync_runtime::{
+        run_source_runtime_with, RelationChunkDriver, RelationChunkSource, SourceRuntimeOpts,
+    };
+
+    struct ArrayRelationChunks<'a> {
+        cursor: DocumentCursor,
+        spec: &'a ArrayRelation,
+        batch_size: usize,
+    }
+
+    #[async_trait]
+    impl RelationChunkSource for ArrayRelationChunks<'_> {
+        async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Relation>>> {
+            let mut batch = Vec::with_capacity(self.batch_size);
+            while batch.len() < self.batch_size {
+                let Some(doc) = self.cursor.next_document().await? else {
+                    break;
+                };
+                batch.extend(self.spec.relations_from_document(&doc)?);
+            }
+            Ok((!batch.is_empty()).then_some(batch))
+        }
+    }
+
+    let cursor = DocumentCursor::open(
+        client,
+        collection,
+        doc! { spec.field.as_str(): { "$type": "array" } },
+        Some(doc! { "_id": 1, spec.field.as_str(): 1 }),
+        snapshot_time,
+    )
+    .await?;
+    let mut driver = RelationChunkDriver::new(ArrayRelationChunks {
+        cursor,
+        spec,
+        batch_size: batch_size.max(1),
+    });
+    let transformer = Arc::new(pipeline.clone());
+    let runtime_opts = SourceRuntimeOpts::new();
+    run_source_runtime_with(&mut driver, surreal, transformer, apply_opts, &runtime_opts).await?;
+    Ok(driver.sunk_count())
+}
+
 /// Convert BSON values directly to Value (without schema)
 pub fn convert_bson_to_universal_value(bson_value: mongodb::bson::Bson) -> anyhow::Result<Value> {
     convert_bson_to_universal_value_with_schema(bson_value, None)
@@ -352,7 +692,8 @@ pub fn convert_bson_to_universal_value_with_schema(
 
     // If we have schema information, use the schema-aware converter from mongodb-types
     if let Some(sync_type) = field_type {
-        let typed_value = BsonValueWithSchema::new(bson_value, sync_type.clone()).to_typed_value();
+        let typed_value =
+            BsonValueWithSchema::new(bson_value, sync_type.clone()).try_to_typed_value()?;
         return Ok(typed_value.value);
     }
 
@@ -404,7 +745,7 @@ pub fn convert_bson_to_universal_value_with_schema(
                 })
             } else {
                 // Regular document - convert recursively
-                let mut obj = HashMap::new();
+                let mut obj = FieldMap::new();
                 for (key, val) in doc {
                     let v = convert_bson_to_universal_value(val)?;
                     obj.insert(key, v);
@@ -426,14 +767,14 @@ pub fn convert_bson_to_universal_value_with_schema(
         }
         Bson::JavaScriptCode(code) => Ok(Value::Text(code)),
         Bson::JavaScriptCodeWithScope(code_with_scope) => {
-            let mut scope_obj = HashMap::new();
+            let mut scope_obj = FieldMap::new();
             for (key, val) in code_with_scope.scope {
                 let v = convert_bson_to_universal_value(val)?;
                 scope_obj.insert(key, v);
             }
             let scope = Value::Object(scope_obj);
             let code = Value::Text(code_with_scope.code);
-            let mut result_obj = HashMap::new();
+            let mut result_obj = FieldMap::new();
             result_obj.insert("$code".to_string(), code);
             result_obj.insert("$scope".to_string(), scope);
             Ok(Value::Object(result_obj))
@@ -441,18 +782,13 @@ pub fn convert_bson_to_universal_value_with_schema(
         Bson::Int32(i) => Ok(Value::Int64(i as i64)),
         Bson::Int64(i) => Ok(Value::Int64(i)),
         Bson::Timestamp(ts) => {
-            // MongoDB Timestamp.time is seconds since Unix epoch
-            let seconds = ts.time as i64;
-            // To keep the ordering across timestamps, we exploit the increment component as the nanoseconds.
-            let assumed_ns = ts.increment;
-            if let Some(datetime) = chrono::DateTime::from_timestamp(seconds, assumed_ns) {
-                // MongoDB timestamps are in UTC, so use ZonedDateTime
-                Ok(Value::ZonedDateTime(datetime))
-            } else {
-                Err(anyhow::anyhow!(
-                    "Failed to convert MongoDB timestamp to datetime"
-                ))
-            }
+            // The internal oplog timestamp (seconds + ordinal increment) is not a
+            // point in time like `Bson::DateTime`, so keep both components as they
+            // appear in Extended JSON: `{"t": seconds, "i": increment}`.
+            let mut result_obj = FieldMap::new();
+            result_obj.insert("t".to_string(), Value::Int64(ts.time as i64));
+            result_obj.insert("i".to_string(), Value::Int64(ts.increment as i64));
+            Ok(Value::Object(result_obj))
         }
         Bson::Binary(binary) => Ok(Value::Bytes(binary.bytes)),
         Bson::ObjectId(oid) => Ok(Value::Text(oid.to_string())),
@@ -471,12 +807,12 @@ pub fn convert_bson_to_universal_value_with_schema(
         }
         Bson::Undefined => Ok(Value::Null), // Map undefined to null
         Bson::MaxKey => {
-            let mut mk = HashMap::new();
+            let mut mk = FieldMap::new();
             mk.insert("$maxKey".to_string(), Value::Int64(1));
             Ok(Value::Object(mk))
         }
         Bson::MinKey => {
-            let mut mk = HashMap::new();
+            let mut mk = FieldMap::new();
             mk.insert("$minKey".to_string(), Value::Int64(1));
             Ok(Value::Object(mk))
         }
@@ -488,36 +824,62 @@ pub fn convert_bson_to_universal_value_with_schema(
     }
 }
 
+/// Convert an `_id` (or a value referring to one) to a record ID
+pub(crate) fn record_id_from_bson(id_bson: &mongodb::bson::Bson) -> anyhow::Result<Value> {
+    match id_bson {
+        mongodb::bson::Bson::ObjectId(oid) => Ok(Value::Text(oid.to_string())),
+        mongodb::bson::Bson::String(s) => Ok(Value::Text(s.clone())),
+        mongodb::bson::Bson::Int32(i) => Ok(Value::Int64(*i as i64)),
+        mongodb::bson::Bson::Int64(i) => Ok(Value::Int64(*i)),
+        _ => anyhow::bail!("Unsupported _id type in MongoDB document: {id_bson:?}"),
+    }
+}
+
 /// Converts a BSON document containing _id to a Row with optional schema
 pub fn convert_bson_document_to_record_with_schema(
     doc: mongodb::bson::Document,
     collection_name: &str,
     row_index: u64,
     schema: Option<&DatabaseSchema>,
+) -> anyhow::Result<Row> {
+    convert_bson_document_to_record_with_converters(
+        doc,
+        collection_name,
+        row_index,
+        schema,
+        &BsonConverters::new(),
+    )
+}
+
+/// Converts a BSON document containing _id to a Row, applying `converters`
+/// registered for its fields before the (schema-aware) default conversion
+pub fn convert_bson_document_to_record_with_converters(
+    doc: mongodb::bson::Document,
+    collection_name: &str,
+    row_index: u64,
+    schema: Option<&DatabaseSchema>,
+    converters: &BsonConverters,
 ) -> anyhow::Result<Row> {
     // Get table schema for field type lookup
     let table_def = schema.and_then(|s| s.get_table(collection_name));
 
     // Extract MongoDB _id and convert to Value
     let id_value = if let Some(id_bson) = doc.get("_id") {
-        match id_bson {
-            mongodb::bson::Bson::ObjectId(oid) => Value::Text(oid.to_string()),
-            mongodb::bson::Bson::String(s) => Value::Text(s.clone()),
-            mongodb::bson::Bson::Int32(i) => Value::Int64(*i as i64),
-            mongodb::bson::Bson::Int64(i) => Value::Int64(*i),
-            _ => anyhow::bail!("Unsupported _id type in MongoDB document: {id_bson:?}"),
-        }
+        record_id_from_bson(id_bson)?
     } else {
         anyhow::bail!("Document is missing _id field");
     };
 
     // Convert remaining fields (excluding _id) with schema-aware conversion
-    let mut fields = HashMap::new();
+    let mut fields = FieldMap::new();
     for (key, value) in doc {
         if key != "_id" {
-            // Look up field type from schema if available
-            let field_type = table_def.and_then(|t| t.get_column_type(&key));
-            let v = convert_bson_to_universal_value_with_schema(value, field_type)?;
+            // Field types (including nested ones) come from the schema if available
+            let v = converters.convert_with_schema(
+                &format!("{collection_name}.{key}"),
+                value,
+                table_def,
+            )?;
             fields.insert(key, v);
         }
     }
@@ -529,3 +891,70 @@ pub fn convert_bson_document_to_record_with_schema(
         fields,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::Bson;
+
+    #[test]
+    fn test_find_projection() {
+        let mut filters = ColumnFilters::new();
+        filters
+            .include("users", ["name", "email"])
+            .exclude("users", ["email"])
+            .exclude("posts", ["body", "_id"]);
+
+        assert_eq!(
+            find_projection(filters.for_table("users").unwrap()),
+            Some(doc! { "_id": 1, "name": 1 })
+        );
+        assert_eq!(
+            find_projection(filters.for_table("posts").unwrap()),
+            Some(doc! { "body": 0 })
+        );
+        assert_eq!(find_projection(&ColumnFilter::default()), None);
+    }
+
+    #[test]
+    fn test_collection_filter() {
+        let all = CollectionFilter::default();
+        assert!(all.allows("events"));
+        assert_eq!(all.change_stream_match(), None);
+
+        let filter = CollectionFilter {
+            include: vec!["users".to_string(), "events".to_string()],
+            exclude: vec!["events".to_string()],
+        };
+        assert!(filter.allows("users"));
+        assert!(!filter.allows("events"));
+        assert!(!filter.allows("orders"));
+        assert_eq!(
+            filter.change_stream_match(),
+            Some(
+                doc! { "$match": { "ns.coll": { "$in": ["users", "events"], "$nin": ["events"] } } }
+            )
+        );
+    }
+
+    #[test]
+    fn test_bson_timestamp_keeps_seconds_and_increment() {
+        let ts = Bson::Timestamp(mongodb::bson::Timestamp {
+            time: 1_672_531_200,
+            increment: 7,
+        });
+        let Value::Object(obj) = convert_bson_to_universal_value(ts).unwrap() else {
+            panic!("BSON Timestamp should convert to an object");
+        };
+        assert_eq!(obj.len(), 2);
+        assert_eq!(obj["t"], Value::Int64(1_672_531_200));
+        assert_eq!(obj["i"], Value::Int64(7));
+
+        // A BSON Date stays a datetime.
+        let date = Bson::DateTime(mongodb::bson::DateTime::from_millis(1_672_531_200_000));
+        assert!(matches!(
+            convert_bson_to_universal_value(date).unwrap(),
+            Value::ZonedDateTime(_)
+        ));
+    }
+}
@@ -3,8 +3,9 @@
 //! Resume tokens are the source position. Idle-stop (no events for a timeout)
 //! and wall-clock deadline match the earlier Change Streams incremental loop.
 
-use crate::checkpoint::MongoDBCheckpoint;
-use crate::{convert_bson_to_universal_value, SourceOpts};
+use crate::checkpoint::{is_history_lost, MongoDBCheckpoint};
+use crate::projection::{apply_projection, projection_includes};
+use crate::{BsonConverters, CollectionFilter, SourceOpts};
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use bson::Document;
@@ -15,11 +16,11 @@ use mongodb::{
     options::{ChangeStreamOptions, FullDocumentType},
     Client,
 };
-use std::collections::HashMap;
+use std::future::IntoFuture;
 use std::sync::Arc;
 use std::time::Duration;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, ChangeOp, Value};
+use surreal_sync_core::{Change, ChangeOp, FieldMap, Value};
 use surreal_sync_runtime::{
     ApplyOpts, CheckpointPolicy, Pipeline, PositionedEvent, SourceDriver, SourceRuntimeOpts,
     StopReason,
@@ -63,16 +64,20 @@ pub trait ChangeStream: Send + Sync {
     fn checkpoint(&self) -> Option<MongoDBCheckpoint>;
 }
 
-/// Convert a BSON document directly to a Value map
-fn bson_doc_to_universal_values(doc: Document) -> Result<HashMap<String, Value>> {
-    let mut map = HashMap::new();
+/// Convert a BSON document of `collection` directly to a Value map
+fn bson_doc_to_universal_values(
+    doc: Document,
+    collection: &str,
+    converters: &BsonConverters,
+) -> Result<FieldMap> {
+    let mut map = FieldMap::new();
 
     for (key, value) in doc {
         // Skip _id field - it's used as the record ID
         if key == "_id" {
             continue;
         }
-        let v = convert_bson_to_universal_value(value)?;
+        let v = converters.convert(&format!("{collection}.{key}"), value, None)?;
         map.insert(key, v);
     }
 
@@ -88,23 +93,26 @@ pub struct MongodbIncrementalSource {
     resume_token: Arc<Mutex<Vec<u8>>>,
     /// Last token observed while fetching (may be ahead of [`Self::resume_token`]).
     seen_token: Arc<Mutex<Vec<u8>>>,
+    /// Custom conversions applied to changed documents.
+    converters: BsonConverters,
+    /// Collections whose changes are reported (pushed into the stream as `$match`).
+    collection_filter: CollectionFilter,
+    /// Projection applied to changed documents after decode.
+    projection: Option<Document>,
+    /// Where to start when the resume token is unusable (see
+    /// [`Self::with_fallback_start_time`]).
+    fallback_start: Option<DateTime<Utc>>,
 }
 
 impl MongodbIncrementalSource {
     /// Create a new MongoDB incremental source with initial resume token
+    ///
+    /// The token is checked when the stream is opened by [`Self::get_changes`].
     pub async fn new(
         connection_string: &str,
         database: &str,
         initial_resume_token: Vec<u8>,
     ) -> Result<Self> {
-        // Validate the resume token by trying to deserialize it
-        bson::from_slice::<ResumeToken>(&initial_resume_token).map_err(|e| {
-            anyhow::anyhow!(
-                "Invalid resume token provided to MongoDB source constructor: {e}. \
-                The token may be corrupted or from an incompatible MongoDB version.",
-            )
-        })?;
-
         // Connect to MongoDB
         let client = Client::with_uri_str(connection_string).await?;
 
@@ -113,9 +121,45 @@ impl MongodbIncrementalSource {
             database: database.to_string(),
             resume_token: Arc::new(Mutex::new(initial_resume_token.clone())),
             seen_token: Arc::new(Mutex::new(initial_resume_token)),
+            converters: BsonConverters::new(),
+            collection_filter: CollectionFilter::default(),
+            projection: None,
+            fallback_start: None,
         })
     }
 
+    /// Apply `converters` to the fields of changed documents.
+    pub fn with_bson_converters(mut self, converters: BsonConverters) -> Self {
+        self.converters = converters;
+        self
+    }
+
+    /// Only report changes to collections that pass `filter`.
+    pub fn with_collection_filter(mut self, filter: CollectionFilter) -> Self {
+        self.collection_filter = filter;
+        self
+    }
+
+    /// Project changed documents with `projection` (see [`SourceOpts::projection`]).
+    pub fn with_projection(mut self, projection: Option<Document>) -> Result<Self> {
+        if let Some(projection) = &projection {
+            projection_includes(projection)?;
+        }
+        self.projection = projection;
+        Ok(self)
+    }
+
+    /// Start from `timestamp` when the resume token cannot be used.
+    ///
+    /// Without a fallback an invalid token, or one that has rolled off the
+    /// oplog (`ChangeStreamHistoryLost`), fails the sync. With one, the
+    /// stream opens with `startAtOperationTime` at `timestamp` and a warning
+    /// is logged; changes at that second may be applied again.
+    pub fn with_fallback_start_time(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.fallback_start = Some(timestamp);
+        self
+    }
+
     /// Get the current resume token from MongoDB
     ///
     /// This creates a change stream and immediately gets its resume token
@@ -193,49 +237,101 @@ impl MongodbIncrementalSource {
         let database = self.client.database(&self.database);
 
         // Build change stream options
-        let mut options = ChangeStreamOptions::builder()
+        let options = ChangeStreamOptions::builder()
             .full_document(Some(FullDocumentType::UpdateLookup))
             .build();
 
-        // If we have a checkpoint with a resume token, use it to resume the stream
-        if let Some(checkpoint) = checkpoint {
-            // Deserialize the token bytes back to a ResumeToken
-            // ResumeToken implements Deserialize, so we can deserialize it directly from BSON
-            let resume_token = bson::from_slice::<ResumeToken>(&checkpoint.resume_token)
-                .map_err(|e| {
-                    // We fail fast here to prevent silent data loss. If we cannot deserialize
-                    // the resume token, starting from "current position" would skip all changes
-                    // between the checkpoint time and now. This could result in missing critical
-                    // data updates. By failing fast, we force operator intervention to either:
-                    // 1. Provide a valid checkpoint
-                    // 2. Explicitly start without a checkpoint (understanding the implications)
-                    // 3. Perform a full sync to ensure consistency
-                    anyhow!(
-                        "Failed to deserialize resume token - refusing to start to prevent data loss. \
-                        Error: {e}. The resume token may be corrupted or from an incompatible MongoDB version. \
-                        Options: (1) Start without a checkpoint if data loss is acceptable, \
-                        (2) Perform a full sync first, or (3) Provide a valid checkpoint.",
-                    )
-                })?;
-
-            options.resume_after = Some(resume_token);
-            info!("Resuming change stream from saved checkpoint");
-        }
+        // Create the change stream, filtered server-side to the synced collections
+        let pipeline: Vec<Document> = self
+            .collection_filter
+            .change_stream_match()
+            .into_iter()
+            .collect();
+        let watch = |options: ChangeStreamOptions| {
+            database
+                .watch()
+                .pipeline(pipeline.clone())
+                .with_options(options)
+                .into_future()
+        };
 
-        // Create the change stream
-        let change_stream = database.watch().with_options(options).await?;
+        // If we have a checkpoint with a resume token, use it to resume the stream
+        let change_stream = match checkpoint {
+            None => watch(options).await?,
+            Some(checkpoint) => {
+                // Deserialize the token bytes back to a ResumeToken
+                // ResumeToken implements Deserialize, so we can deserialize it directly from BSON
+                match bson::from_slice::<ResumeToken>(&checkpoint.resume_token) {
+                    Ok(resume_token) => {
+                        let mut resume_options = options.clone();
+                        resume_options.resume_after = Some(resume_token);
+                        info!("Resuming change stream from saved checkpoint");
+                        match watch(resume_options).await {
+                            Ok(stream) => stream,
+                            Err(e) if is_history_lost(&e) => {
+                                let start = self.fallback_start.ok_or_else(|| {
+                                    anyhow!(
+                                        "Resume token is no longer in the oplog: {e}. \
+                                        Perform a full sync or start from a newer checkpoint."
+                                    )
+                                })?;
+                                warn!(
+                                    "Resume token is no longer in the oplog ({e}); \
+                                    falling back to the checkpoint timestamp {start}"
+                                );
+                                watch(Self::start_at(options, start)).await?
+                            }
+                            Err(e) => return Err(e.into()),
+                        }
+                    }
+                    Err(e) => {
+                        let Some(start) = self.fallback_start else {
+                            // We fail fast here to prevent silent data loss. If we cannot deserialize
+                            // the resume token, starting from "current position" would skip all changes
+                            // between the checkpoint time and now. This could result in missing critical
+                            // data updates. By failing fast, we force operator intervention to either:
+                            // 1. Provide a valid checkpoint
+                            // 2. Explicitly start without a checkpoint (understanding the implications)
+                            // 3. Perform a full sync to ensure consistency
+                            return Err(anyhow!(
+                                "Failed to deserialize resume token - refusing to start to prevent data loss. \
+                                Error: {e}. The resume token may be corrupted or from an incompatible MongoDB version. \
+                                Options: (1) Start without a checkpoint if data loss is acceptable, \
+                                (2) Perform a full sync first, or (3) Provide a valid checkpoint.",
+                            ));
+                        };
+                        warn!(
+                            "Failed to deserialize resume token ({e}); \
+                            falling back to the checkpoint timestamp {start}"
+                        );
+                        watch(Self::start_at(options, start)).await?
+                    }
+                }
+            }
+        };
         let database_name = self.database.clone();
         let seen_token = self.seen_token.clone();
+        let converters = self.converters.clone();
+        let projection = self.projection.clone();
 
         // Convert MongoDB change stream to our ChangeEvent stream
         let stream = change_stream
             .map(move |result| {
                 let database_name = database_name.clone();
                 let seen_token = seen_token.clone();
+                let converters = converters.clone();
+                let projection = projection.clone();
                 async move {
                     match result {
                         Ok(event) => {
-                            Self::convert_change_event(event, &database_name, seen_token).await
+                            Self::convert_change_event(
+                                event,
+                                &database_name,
+                                seen_token,
+                                &converters,
+                                projection.as_ref(),
+                            )
+                            .await
                         }
                         Err(e) => Err(anyhow!("MongoDB change stream error: {e}")),
                     }
@@ -250,11 +346,22 @@ impl MongodbIncrementalSource {
         Ok(boxed_stream)
     }
 
+    /// `options` starting at the cluster time of `timestamp`.
+    fn start_at(mut options: ChangeStreamOptions, timestamp: DateTime<Utc>) -> ChangeStreamOptions {
+        options.start_at_operation_time = Some(bson::Timestamp {
+            time: timestamp.timestamp().clamp(0, u32::MAX as i64) as u32,
+            increment: 0,
+        });
+        options
+    }
+
     /// Convert MongoDB change event to our Change
     async fn convert_change_event(
         event: ChangeStreamEvent<Document>,
         _database_name: &str,
         seen_token: Arc<Mutex<Vec<u8>>>,
+        converters: &BsonConverters,
+        projection: Option<&Document>,
     ) -> Result<Change> {
         // Track the fetch-time resume token separately from the sink-safe bookmark.
         if let Ok(token_bytes) = bson::to_vec(&event.id) {
@@ -301,13 +408,47 @@ impl MongodbIncrementalSource {
         let data = match operation {
             ChangeOp::Delete => None,
             _ => {
-                let d = event.full_document.unwrap();
-                Some(bson_doc_to_universal_values(d)?)
+                let mut d = event.full_document.unwrap();
+                if let Some(projection) = projection {
+                    apply_projection(&mut d, projection)?;
+                }
+                Some(bson_doc_to_universal_values(d, &collection, converters)?)
             }
         };
 
-        Ok(Change::new(operation, collection, id_value, data))
+        let change = Change::new(operation, collection, id_value, data);
+        Ok(match event.update_description {
+            Some(description) => change.with_changed_fields(changed_top_level_fields(&description)),
+            None => change,
+        })
+    }
+}
+
+/// Top-level fields touched by an `update` event (set, removed or
+/// truncated), deduplicated in first-seen order.
+fn changed_top_level_fields(
+    description: &mongodb::change_stream::event::UpdateDescription,
+) -> Vec<String> {
+    let paths = description
+        .updated_fields
+        .keys()
+        .map(String::as_str)
+        .chain(description.removed_fields.iter().map(String::as_str))
+        .chain(
+            description
+                .truncated_arrays
+                .iter()
+                .flatten()
+                .map(|truncated| truncated.field.as_str()),
+        );
+    let mut fields: Vec<String> = Vec::new();
+    for path in paths {
+        let field = path.split('.').next().unwrap_or(path);
+        if !fields.iter().any(|f| f == field) {
+            fields.push(field.to_string());
+        }
     }
+    fields
 }
 
 // Type alias for complex MongoDB change stream type
@@ -414,7 +555,11 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
         &source_database,
         from_checkpoint.resume_token.clone(),
     )
-    .await?;
+    .await?
+    .with_bson_converters(from_opts.bson_converters.clone())
+    .with_collection_filter(from_opts.collection_filter())
+    .with_projection(from_opts.projection.clone())?
+    .with_fallback_start_time(from_checkpoint.timestamp);
 
     let stream = source.get_changes().await?;
     let resume_token = source.resume_token_handle();
@@ -487,6 +632,12 @@ struct MongodbChangeStreamDriver<'a> {
 impl SourceDriver for MongodbChangeStreamDriver<'_> {
     type Position = Vec<u8>;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        bson::from_slice::<bson::Document>(position)
+            .ok()
+            .map(|token| token.to_string())
+    }
+
     async fn poll_work(&mut self) -> Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() || self.finished {
             return Ok(Vec::new());
@@ -592,4 +743,19 @@ mod tests {
         assert_eq!(checkpoint.resume_token, token);
         assert_eq!(checkpoint.timestamp, timestamp);
     }
+
+    #[test]
+    fn test_update_description_changed_top_level_fields() {
+        let description: mongodb::change_stream::event::UpdateDescription =
+            bson::from_document(bson::doc! {
+                "updatedFields": { "profile.name": "Ann", "age": 31, "profile.city": "Oslo" },
+                "removedFields": ["nickname", "profile.zip"],
+                "truncatedArrays": [{ "field": "tags", "newSize": 1 }],
+            })
+            .unwrap();
+        assert_eq!(
+            changed_top_level_fields(&description),
+            vec!["profile", "age", "nickname", "tags"]
+        );
+    }
 }
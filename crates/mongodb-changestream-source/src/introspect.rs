@@ -0,0 +1,105 @@
+//! Collection discovery and size estimates for MongoDB sources.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::TryStreamExt;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::results::CollectionType;
+use mongodb::{options::ClientOptions, Client as MongoClient, Database};
+use surreal_sync_core::{MigrationEstimate, SourceEntity, TableEstimate};
+
+use crate::SourceOpts;
+
+/// List the collections of the source database with approximate document
+/// counts.
+///
+/// Counts use `estimatedDocumentCount`, which reads collection metadata
+/// instead of scanning. Views and `system.*` collections are skipped.
+pub async fn list_collections(opts: &SourceOpts) -> Result<Vec<SourceEntity>> {
+    let database = connect_database(opts).await?;
+    let specs: Vec<_> = database.list_collections().await?.try_collect().await?;
+    let mut collections = Vec::new();
+    for spec in specs {
+        if spec.collection_type == CollectionType::View || spec.name.starts_with("system.") {
+            continue;
+        }
+        let approx_count = database
+            .collection::<mongodb::bson::Document>(&spec.name)
+            .estimated_document_count()
+            .await?;
+        collections.push(SourceEntity::new(spec.name, Some(approx_count)));
+    }
+    collections.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(collections)
+}
+
+/// Count what a full sync would move: the collections in `opts.collections`
+/// (empty means every collection [`list_collections`] reports) that pass
+/// [`SourceOpts::collection_filter`].
+///
+/// Counts use `estimatedDocumentCount`; sizes extrapolate the collection's
+/// `avgObjSize` from `$collStats`, and are omitted when the user may not
+/// read storage statistics.
+pub async fn estimate_collections(opts: &SourceOpts) -> Result<MigrationEstimate> {
+    let database = connect_database(opts).await?;
+    let names = if opts.collections.is_empty() {
+        list_collections(opts)
+            .await?
+            .into_iter()
+            .map(|c| c.name)
+            .collect()
+    } else {
+        opts.collections.clone()
+    };
+    let filter = opts.collection_filter();
+    let names: Vec<String> = names.into_iter().filter(|n| filter.allows(n)).collect();
+
+    let mut estimates = Vec::with_capacity(names.len());
+    for name in names {
+        let collection = database.collection::<Document>(&name);
+        let count = collection.estimated_document_count().await?;
+        let avg_obj_size = match collection
+            .aggregate(vec![doc! { "$collStats": { "storageStats": {} } }])
+            .await
+        {
+            Ok(cursor) => cursor
+                .try_collect::<Vec<_>>()
+                .await
+                .ok()
+                .and_then(|stats| avg_obj_size(stats.first()?)),
+            Err(e) => {
+                tracing::debug!("No storage statistics for collection {name}: {e}");
+                None
+            }
+        };
+        estimates.push(TableEstimate::new(name, count, false).with_avg_row_bytes(avg_obj_size));
+    }
+
+    Ok(MigrationEstimate::new("mongodb", estimates))
+}
+
+async fn connect_database(opts: &SourceOpts) -> Result<Database> {
+    let mut mongo_options = ClientOptions::parse(&opts.source_uri).await?;
+    mongo_options.connect_timeout = Some(Duration::from_secs(10));
+    mongo_options.server_selection_timeout = Some(Duration::from_secs(10));
+    let client = MongoClient::with_options(mongo_options)?;
+
+    let database_name = opts
+        .source_database
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("MongoDB source database name is required"))?;
+    Ok(client.database(database_name))
+}
+
+/// `storageStats.avgObjSize` of a `$collStats` result. Absent for empty
+/// collections.
+fn avg_obj_size(stats: &Document) -> Option<f64> {
+    match stats.get_document("storageStats").ok()?.get("avgObjSize")? {
+        Bson::Int32(n) => Some(f64::from(*n)),
+        Bson::Int64(n) => Some(*n as f64),
+        Bson::Double(n) => Some(*n),
+        _ => None,
+    }
+}
@@ -1,20 +1,37 @@
 //! MongoDB sync utilities for surreal-sync
 //!
-//! Provides full and incremental sync from MongoDB to SurrealDB.
+//! Provides full and incremental sync from MongoDB to SurrealDB, plus a
+//! recompute-and-diff mode for aggregation-derived tables.
 
+mod array_relations;
+mod bson_converters;
 pub mod checkpoint;
 mod full_sync;
 mod incremental_sync;
+mod introspect;
+mod projection;
+mod recompute;
 
+pub use array_relations::ArrayRelation;
+pub use bson_converters::{BsonConverterFn, BsonConverters};
 pub use full_sync::{
-    convert_bson_document_to_record_with_schema, convert_bson_to_universal_value,
-    convert_bson_to_universal_value_with_schema, migrate_from_mongodb, run_full_sync,
-    run_full_sync_with_transforms, SourceOpts, SyncOpts,
+    convert_bson_document_to_record_with_converters, convert_bson_document_to_record_with_schema,
+    convert_bson_to_universal_value, convert_bson_to_universal_value_with_schema,
+    migrate_from_mongodb, run_full_sync, run_full_sync_with_transforms, CollectionFilter,
+    SourceOpts, SyncOpts,
 };
 pub use incremental_sync::{
     run_incremental_sync, run_incremental_sync_with_transforms, MongoChangeStream,
     MongodbIncrementalSource, ReplicationTailOptions,
 };
+pub use introspect::{estimate_collections, list_collections};
+pub use recompute::{
+    diff_and_apply, recompute_once, row_hash, run_recompute, RecomputeOpts, RecomputeStats,
+    RecomputeView, SOURCE_HASH_FIELD,
+};
 
 // Re-export checkpoint types from this crate
-pub use checkpoint::{get_current_checkpoint, get_resume_token, MongoDBCheckpoint};
+pub use checkpoint::{
+    get_current_checkpoint, get_resume_token, resume_token_cluster_time, validate_checkpoint,
+    MongoDBCheckpoint,
+};
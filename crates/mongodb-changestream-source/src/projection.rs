@@ -0,0 +1,187 @@
+//! Source-wide MongoDB projections ([`SourceOpts::projection`]).
+//!
+//! Full sync pushes the projection into `find`. Change streams cannot
+//! reliably project `fullDocument`, so changed documents are projected
+//! after decode with [`apply_projection`] instead.
+//!
+//! [`SourceOpts::projection`]: crate::SourceOpts::projection
+
+use anyhow::{bail, Result};
+use mongodb::bson::{Bson, Document};
+
+/// Whether `projection` keeps only its listed fields (`true`) or drops them
+/// (`false`).
+///
+/// Only `0`/`1`/`true`/`false` values are supported, and, as in MongoDB,
+/// inclusion and exclusion cannot be mixed except for `_id`.
+pub(crate) fn projection_includes(projection: &Document) -> Result<bool> {
+    let mut kind = None;
+    for (field, value) in projection {
+        if field == "_id" {
+            continue;
+        }
+        let include = match value {
+            Bson::Boolean(b) => *b,
+            Bson::Int32(i) => *i != 0,
+            Bson::Int64(i) => *i != 0,
+            Bson::Double(d) => *d != 0.0,
+            other => bail!("Unsupported projection for field '{field}': {other} (use 0 or 1)"),
+        };
+        if kind.is_some_and(|kind| kind != include) {
+            bail!("Projection cannot mix included and excluded fields (other than _id)");
+        }
+        kind = Some(include);
+    }
+    Ok(kind.unwrap_or(false))
+}
+
+/// `find` projection for a collection: the source-wide `projection`
+/// combined with the collection's column-filter projection.
+///
+/// Two exclusion projections merge; any other combination is rejected
+/// rather than guessing which fields the user meant to keep.
+pub(crate) fn merge_find_projection(
+    projection: Option<&Document>,
+    column_filter: Option<Document>,
+    collection: &str,
+) -> Result<Option<Document>> {
+    match (projection, column_filter) {
+        (None, column_filter) => Ok(column_filter),
+        (Some(projection), None) => Ok(Some(projection.clone())),
+        (Some(projection), Some(mut column_filter)) => {
+            if projection_includes(projection)? || projection_includes(&column_filter)? {
+                bail!(
+                    "Collection '{collection}' has both a projection and column filters; \
+                     they can only be combined when both exclude fields"
+                );
+            }
+            for (field, value) in projection {
+                column_filter.insert(field.clone(), value.clone());
+            }
+            Ok(Some(column_filter))
+        }
+    }
+}
+
+/// Project a decoded document in place, as `find` would have.
+///
+/// Dotted paths address fields of embedded documents. `_id` is always kept:
+/// it is the record id.
+pub(crate) fn apply_projection(doc: &mut Document, projection: &Document) -> Result<()> {
+    let include = projection_includes(projection)?;
+    let paths = projection.keys().filter(|field| field.as_str() != "_id");
+    if include {
+        let mut kept = Document::new();
+        if let Some(id) = doc.remove("_id") {
+            kept.insert("_id", id);
+        }
+        for path in paths {
+            if let Some(value) = take_path(doc, path) {
+                insert_path(&mut kept, path, value);
+            }
+        }
+        *doc = kept;
+    } else {
+        for path in paths {
+            take_path(doc, path);
+        }
+    }
+    Ok(())
+}
+
+fn take_path(doc: &mut Document, path: &str) -> Option<Bson> {
+    match path.split_once('.') {
+        None => doc.remove(path),
+        Some((head, rest)) => match doc.get_mut(head)? {
+            Bson::Document(inner) => take_path(inner, rest),
+            _ => None,
+        },
+    }
+}
+
+fn insert_path(doc: &mut Document, path: &str, value: Bson) {
+    match path.split_once('.') {
+        None => {
+            doc.insert(path, value);
+        }
+        Some((head, rest)) => {
+            if !matches!(doc.get(head), Some(Bson::Document(_))) {
+                doc.insert(head, Document::new());
+            }
+            if let Some(Bson::Document(inner)) = doc.get_mut(head) {
+                insert_path(inner, rest, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_bson_document_to_record_with_schema;
+    use mongodb::bson::spec::BinarySubtype;
+    use mongodb::bson::{doc, Binary};
+
+    fn blob_doc() -> Document {
+        let blob = Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![0; 1024],
+        };
+        doc! {
+            "_id": "1",
+            "name": "report",
+            "blob": blob,
+            "meta": { "size": 1024, "thumbnail": "abc" },
+        }
+    }
+
+    #[test]
+    fn test_exclusion_projection_drops_blob_from_record() {
+        let mut doc = blob_doc();
+        apply_projection(&mut doc, &doc! { "blob": 0, "meta.thumbnail": 0 }).unwrap();
+        assert_eq!(
+            doc,
+            doc! { "_id": "1", "name": "report", "meta": { "size": 1024 } }
+        );
+
+        let row = convert_bson_document_to_record_with_schema(doc, "files", 0, None).unwrap();
+        assert!(row.fields.get("blob").is_none());
+        assert!(row.fields.get("name").is_some());
+    }
+
+    #[test]
+    fn test_inclusion_projection_keeps_id() {
+        let mut doc = blob_doc();
+        apply_projection(&mut doc, &doc! { "name": 1, "meta.size": 1, "_id": 0 }).unwrap();
+        assert_eq!(
+            doc,
+            doc! { "_id": "1", "name": "report", "meta": { "size": 1024 } }
+        );
+    }
+
+    #[test]
+    fn test_invalid_projections() {
+        assert!(projection_includes(&doc! { "a": 1, "b": 0 }).is_err());
+        assert!(projection_includes(&doc! { "a": { "$slice": 1 } }).is_err());
+        assert!(!projection_includes(&doc! { "_id": 1, "a": 0 }).unwrap());
+    }
+
+    #[test]
+    fn test_merge_find_projection() {
+        let projection = doc! { "blob": 0 };
+        assert_eq!(
+            merge_find_projection(Some(&projection), Some(doc! { "secret": 0 }), "files").unwrap(),
+            Some(doc! { "secret": 0, "blob": 0 })
+        );
+        assert_eq!(
+            merge_find_projection(None, Some(doc! { "secret": 0 }), "files").unwrap(),
+            Some(doc! { "secret": 0 })
+        );
+        assert!(merge_find_projection(
+            Some(&projection),
+            Some(doc! { "_id": 1, "name": 1 }),
+            "files"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,380 @@
+//! MongoDB "recompute and diff" incremental mode
+//!
+//! For sources where change streams are not an option (derived data, views
+//! built by an aggregation), each pass re-runs the configured aggregation
+//! and merge-joins its output, sorted by id, with the target table read back
+//! in id order. Only the difference is written: new ids are inserted, ids
+//! whose content hash changed are updated and ids the aggregation no longer
+//! produces are deleted. Both sides are read in pages, so memory stays
+//! bounded by the batch size whatever the table size.
+//!
+//! The content hash of every written record is stored in
+//! [`SOURCE_HASH_FIELD`]. Records written by another mode have no hash and
+//! are rewritten on the first pass.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+use futures::{Stream, StreamExt, TryStreamExt};
+use mongodb::{
+    bson::{doc, Document},
+    options::ClientOptions,
+    Client as MongoClient,
+};
+use sha2::{Digest, Sha256};
+use surreal_sync_core::{Change, Row, SurrealSink, Value};
+
+use crate::{convert_bson_document_to_record_with_converters, BsonConverters};
+
+/// Field holding the hash of the record content as last written by a
+/// recompute pass.
+pub const SOURCE_HASH_FIELD: &str = "_source_hash";
+
+/// Helper fields added to the aggregation output to sort it in SurrealDB id
+/// order; removed again before the documents leave the server.
+const SORT_KIND_FIELD: &str = "__surreal_sync_kind";
+const SORT_KEY_FIELD: &str = "__surreal_sync_key";
+
+/// One target table and the aggregation that computes it.
+#[derive(Clone, Debug)]
+pub struct RecomputeView {
+    /// Target SurrealDB table
+    pub table: String,
+    /// Collection the aggregation runs on
+    pub collection: String,
+    /// Aggregation stages; empty reads the collection as-is
+    pub pipeline: Vec<Document>,
+}
+
+/// Recompute mode options.
+#[derive(Clone, Debug)]
+pub struct RecomputeOpts {
+    pub source_uri: String,
+    pub source_database: String,
+    pub views: Vec<RecomputeView>,
+    /// Rows per target page read and per write batch
+    pub batch_size: usize,
+    /// Pause between the end of one pass and the start of the next
+    pub interval: Duration,
+    /// Run a single pass and return
+    pub once: bool,
+    pub bson_converters: BsonConverters,
+}
+
+/// What a recompute pass wrote.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RecomputeStats {
+    pub inserted: u64,
+    pub updated: u64,
+    pub deleted: u64,
+    pub unchanged: u64,
+}
+
+impl RecomputeStats {
+    fn add(&mut self, other: RecomputeStats) {
+        self.inserted += other.inserted;
+        self.updated += other.updated;
+        self.deleted += other.deleted;
+        self.unchanged += other.unchanged;
+    }
+}
+
+/// Run recompute passes every `opts.interval` until an error, or once when
+/// `opts.once` is set.
+pub async fn run_recompute<S: SurrealSink + ?Sized>(
+    sink: &S,
+    opts: &RecomputeOpts,
+) -> anyhow::Result<()> {
+    loop {
+        let stats = recompute_once(sink, opts).await?;
+        tracing::info!(
+            "Recompute pass done: {} inserted, {} updated, {} deleted, {} unchanged",
+            stats.inserted,
+            stats.updated,
+            stats.deleted,
+            stats.unchanged
+        );
+        if opts.once {
+            return Ok(());
+        }
+        tokio::time::sleep(opts.interval).await;
+    }
+}
+
+/// Recompute every view once and apply the difference to the target.
+pub async fn recompute_once<S: SurrealSink + ?Sized>(
+    sink: &S,
+    opts: &RecomputeOpts,
+) -> anyhow::Result<RecomputeStats> {
+    let mut mongo_options = ClientOptions::parse(&opts.source_uri).await?;
+    mongo_options.connect_timeout = Some(Duration::from_secs(10));
+    mongo_options.server_selection_timeout = Some(Duration::from_secs(10));
+    let client = MongoClient::with_options(mongo_options)?;
+    let db = client.database(&opts.source_database);
+
+    let mut total = RecomputeStats::default();
+    for view in &opts.views {
+        let collection = db.collection::<Document>(&view.collection);
+        let cursor = collection
+            .aggregate(sorted_pipeline(&view.pipeline))
+            .allow_disk_use(true)
+            .await?;
+        let table = view.table.as_str();
+        let rows = cursor.map_err(anyhow::Error::from).enumerate().map(
+            |(index, doc)| -> anyhow::Result<Row> {
+                convert_bson_document_to_record_with_converters(
+                    doc?,
+                    table,
+                    index as u64,
+                    None,
+                    &opts.bson_converters,
+                )
+            },
+        );
+        let stats = diff_and_apply(sink, table, rows, opts.batch_size).await?;
+        tracing::info!(
+            "Recomputed '{}' from '{}': {} inserted, {} updated, {} deleted, {} unchanged",
+            table,
+            view.collection,
+            stats.inserted,
+            stats.updated,
+            stats.deleted,
+            stats.unchanged
+        );
+        total.add(stats);
+    }
+    Ok(total)
+}
+
+/// `pipeline` followed by the stages sorting its output in SurrealDB id
+/// order: numeric ids first, by value, then every other id by its string
+/// form (`ObjectId`s by their hex string, matching the converted record id).
+fn sorted_pipeline(pipeline: &[Document]) -> Vec<Document> {
+    let mut stages = pipeline.to_vec();
+    stages.push(doc! {
+        "$addFields": {
+            SORT_KIND_FIELD: { "$cond": [{ "$isNumber": "$_id" }, 0, 1] },
+            SORT_KEY_FIELD: {
+                "$cond": [{ "$isNumber": "$_id" }, "$_id", { "$toString": "$_id" }]
+            },
+        }
+    });
+    stages.push(doc! { "$sort": { SORT_KIND_FIELD: 1, SORT_KEY_FIELD: 1 } });
+    stages.push(doc! { "$unset": [SORT_KIND_FIELD, SORT_KEY_FIELD] });
+    stages
+}
+
+/// Merge-join `rows` (sorted by id) with the records of `table` and write
+/// the difference to `sink`.
+pub async fn diff_and_apply<S, R>(
+    sink: &S,
+    table: &str,
+    rows: R,
+    batch_size: usize,
+) -> anyhow::Result<RecomputeStats>
+where
+    S: SurrealSink + ?Sized,
+    R: Stream<Item = anyhow::Result<Row>>,
+{
+    let batch_size = batch_size.max(1);
+    let mut target = TargetScan::new(sink, table, batch_size);
+    let mut stats = RecomputeStats::default();
+    let mut pending = Vec::with_capacity(batch_size);
+    let mut previous: Option<Value> = None;
+
+    futures::pin_mut!(rows);
+    while let Some(row) = rows.next().await {
+        let mut row = row?;
+        if let Some(prev) = &previous {
+            if compare_ids(prev, &row.id)? != Ordering::Less {
+                anyhow::bail!(
+                    "Recomputed rows of '{table}' are not sorted by id: {:?} follows {:?}",
+                    row.id,
+                    prev
+                );
+            }
+        }
+        previous = Some(row.id.clone());
+        let hash = row_hash(&row)?;
+
+        // Target records sorting before this row were not recomputed.
+        while let Some((id, _)) = target.peek().await? {
+            if compare_ids(id, &row.id)? != Ordering::Less {
+                break;
+            }
+            let (id, _) = target.pop().expect("peeked");
+            sink.apply_change(&Change::delete(table, id)).await?;
+            stats.deleted += 1;
+        }
+
+        match target.peek().await? {
+            Some((id, stored)) if compare_ids(id, &row.id)? == Ordering::Equal => {
+                let unchanged = stored.as_deref() == Some(hash.as_str());
+                target.pop();
+                if unchanged {
+                    stats.unchanged += 1;
+                    continue;
+                }
+                stats.updated += 1;
+            }
+            _ => stats.inserted += 1,
+        }
+
+        row.fields
+            .insert(SOURCE_HASH_FIELD.to_string(), Value::Text(hash));
+        pending.push(row);
+        if pending.len() >= batch_size {
+            sink.write_rows(&pending).await?;
+            pending.clear();
+        }
+    }
+
+    while let Some((id, _)) = target.pop_next().await? {
+        sink.apply_change(&Change::delete(table, id)).await?;
+        stats.deleted += 1;
+    }
+    if !pending.is_empty() {
+        sink.write_rows(&pending).await?;
+    }
+    Ok(stats)
+}
+
+/// Hash of the record content: its fields in name order, without the
+/// stored hash itself.
+pub fn row_hash(row: &Row) -> anyhow::Result<String> {
+    let fields: BTreeMap<&String, &Value> = row
+        .fields
+        .iter()
+        .filter(|(name, _)| name.as_str() != SOURCE_HASH_FIELD)
+        .collect();
+    let json = serde_json::to_vec(&fields)?;
+    Ok(format!("{:x}", Sha256::digest(json)))
+}
+
+/// SurrealDB record id order for the id types a MongoDB `_id` converts to:
+/// integers by value, then strings bytewise.
+fn compare_ids(a: &Value, b: &Value) -> anyhow::Result<Ordering> {
+    match (a, b) {
+        (Value::Int64(x), Value::Int64(y)) => Ok(x.cmp(y)),
+        (Value::Int64(_), Value::Text(_)) => Ok(Ordering::Less),
+        (Value::Text(_), Value::Int64(_)) => Ok(Ordering::Greater),
+        (Value::Text(x), Value::Text(y)) => Ok(x.as_bytes().cmp(y.as_bytes())),
+        _ => anyhow::bail!("Recompute supports integer and string ids only, got {a:?} and {b:?}"),
+    }
+}
+
+/// Records of the target table read page by page in id order.
+struct TargetScan<'a, S: ?Sized> {
+    sink: &'a S,
+    table: &'a str,
+    page_size: usize,
+    page: VecDeque<(Value, Option<String>)>,
+    last: Option<Value>,
+    exhausted: bool,
+}
+
+impl<'a, S: SurrealSink + ?Sized> TargetScan<'a, S> {
+    fn new(sink: &'a S, table: &'a str, page_size: usize) -> Self {
+        Self {
+            sink,
+            table,
+            page_size,
+            page: VecDeque::new(),
+            last: None,
+            exhausted: false,
+        }
+    }
+
+    async fn peek(&mut self) -> anyhow::Result<Option<&(Value, Option<String>)>> {
+        if self.page.is_empty() && !self.exhausted {
+            let page = self
+                .sink
+                .scan_field(
+                    self.table,
+                    SOURCE_HASH_FIELD,
+                    self.last.as_ref(),
+                    self.page_size,
+                )
+                .await?;
+            self.exhausted = page.len() < self.page_size;
+            for (id, _) in &page {
+                if let Some(last) = &self.last {
+                    if compare_ids(last, id)? != Ordering::Less {
+                        anyhow::bail!(
+                            "Records of '{}' were not returned in id order: {id:?} follows {last:?}",
+                            self.table
+                        );
+                    }
+                }
+                self.last = Some(id.clone());
+            }
+            self.page = page.into();
+        }
+        Ok(self.page.front())
+    }
+
+    fn pop(&mut self) -> Option<(Value, Option<String>)> {
+        self.page.pop_front()
+    }
+
+    async fn pop_next(&mut self) -> anyhow::Result<Option<(Value, Option<String>)>> {
+        self.peek().await?;
+        Ok(self.pop())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_core::FieldMap;
+    use surreal_sync_surreal::Surreal2Sink;
+
+    fn row(id: i64, name: &str) -> Row {
+        let mut fields = FieldMap::new();
+        fields.insert("name".to_string(), Value::Text(name.to_string()));
+        Row::new("people", id as u64, Value::Int64(id), fields)
+    }
+
+    async fn recompute(sink: &Surreal2Sink, rows: Vec<Row>) -> RecomputeStats {
+        let rows = futures::stream::iter(rows.into_iter().map(Ok));
+        diff_and_apply(sink, "people", rows, 1).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_recompute_removes_stale_record() {
+        let client = surrealdb::engine::any::connect("mem://").await.unwrap();
+        client.use_ns("test").use_db("test").await.unwrap();
+        let sink = Surreal2Sink::new(client.clone());
+
+        let first = recompute(&sink, vec![row(1, "ada"), row(2, "bob"), row(3, "cy")]).await;
+        assert_eq!(first.inserted, 3);
+
+        let second = recompute(&sink, vec![row(1, "ada"), row(3, "cyd")]).await;
+        assert_eq!(
+            second,
+            RecomputeStats {
+                inserted: 0,
+                updated: 1,
+                deleted: 1,
+                unchanged: 1,
+            }
+        );
+
+        let mut response = client
+            .query("SELECT id, name FROM people ORDER BY id")
+            .await
+            .unwrap();
+        let names: Vec<String> = response.take((0, "name")).unwrap();
+        assert_eq!(names, vec!["ada", "cyd"]);
+    }
+
+    #[test]
+    fn test_compare_ids_follows_surreal_order() {
+        assert_eq!(
+            compare_ids(&Value::Int64(10), &Value::Text("1".into())).unwrap(),
+            Ordering::Less
+        );
+        assert!(compare_ids(&Value::Bool(true), &Value::Int64(1)).is_err());
+    }
+}
@@ -4,8 +4,8 @@
 
 use bson::Bson;
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
-use surreal_sync_core::{Type, TypedValue, Value};
+use indexmap::IndexMap;
+use surreal_sync_core::{FieldMap, Type, TypedValue, TypedValueError, Value};
 
 /// Parse an ISO 8601 duration string (PTxS or PTx.xxxxxxxxxS format).
 ///
@@ -50,6 +50,20 @@ impl BsonValueWithSchema {
     pub fn to_typed_value(&self) -> TypedValue {
         TypedValue::from(self.clone())
     }
+
+    /// [`to_typed_value`](Self::to_typed_value), failing when a decimal does
+    /// not fit its type's precision and scale (see [`TypedValue::try_decimal`]).
+    pub fn try_to_typed_value(&self) -> Result<TypedValue, TypedValueError> {
+        let typed = self.to_typed_value();
+        match &typed.value {
+            Value::Decimal {
+                value,
+                precision,
+                scale,
+            } => TypedValue::try_decimal(value.as_str(), *precision, *scale),
+            _ => Ok(typed),
+        }
+    }
 }
 
 impl From<BsonValueWithSchema> for TypedValue {
@@ -188,6 +202,9 @@ impl From<BsonValueWithSchema> for TypedValue {
                 }
             }
 
+            // Object - nested document with typed fields
+            (Type::Object, Bson::Document(doc)) => TypedValue::object(bson_doc_to_hashmap(doc)),
+
             // Array types
             (Type::Array { element_type }, Bson::Array(arr)) => {
                 let values: Vec<Value> = arr
@@ -262,12 +279,16 @@ impl From<BsonValueWithSchema> for TypedValue {
     }
 }
 
-/// Convert a BSON document to a HashMap of Value.
-#[allow(dead_code)]
-fn bson_doc_to_hashmap(doc: &bson::Document) -> HashMap<String, Value> {
-    let mut map = HashMap::new();
+/// Convert a BSON document to a HashMap of Value, keeping subdocuments as
+/// nested `Value::Object`s.
+fn bson_doc_to_hashmap(doc: &bson::Document) -> FieldMap {
+    let mut map = FieldMap::new();
     for (key, value) in doc {
-        map.insert(key.clone(), bson_to_generated_value(value));
+        let value = match value {
+            Bson::Document(sub) => Value::Object(bson_doc_to_hashmap(sub)),
+            other => bson_to_generated_value(other),
+        };
+        map.insert(key.clone(), value);
     }
     map
 }
@@ -297,7 +318,6 @@ fn bson_to_json(value: &Bson) -> serde_json::Value {
 }
 
 /// Convert a BSON value to Value (without type context).
-#[allow(dead_code)]
 fn bson_to_generated_value(value: &Bson) -> Value {
     match value {
         Bson::Null => Value::Null,
@@ -368,12 +388,13 @@ pub fn extract_field(doc: &bson::Document, field: &str, sync_type: &Type) -> Typ
     }
 }
 
-/// Convert a complete BSON document to a map of TypedValues using schema.
+/// Convert a complete BSON document to a map of TypedValues using schema,
+/// in schema field order.
 pub fn document_to_typed_values(
     doc: &bson::Document,
     schema: &[(String, Type)],
-) -> HashMap<String, TypedValue> {
-    let mut result = HashMap::new();
+) -> IndexMap<String, TypedValue> {
+    let mut result = IndexMap::new();
     for (field_name, sync_type) in schema {
         let tv = extract_field(doc, field_name, sync_type);
         result.insert(field_name.clone(), tv);
@@ -386,6 +407,7 @@ mod tests {
     use super::*;
     use bson::{doc, DateTime as BsonDateTime};
     use chrono::{Datelike, TimeZone, Utc};
+    use surreal_sync_core::test_support::nested_object;
     use surreal_sync_core::GeometryType;
 
     #[test]
@@ -451,6 +473,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_to_typed_value_checks_decimal_precision() {
+        let decimal = |s: &str| {
+            BsonValueWithSchema::new(
+                Bson::String(s.to_string()),
+                Type::Decimal {
+                    precision: 5,
+                    scale: 2,
+                },
+            )
+            .try_to_typed_value()
+        };
+        assert!(decimal("999.99").is_ok());
+        assert!(decimal("1000.00").is_err());
+    }
+
     #[test]
     fn test_string_conversion() {
         let bv = BsonValueWithSchema::new(Bson::String("hello world".to_string()), Type::Text);
@@ -720,4 +758,21 @@ mod tests {
             panic!("Expected String for ObjectId");
         }
     }
+
+    #[test]
+    fn test_nested_object_round_trip() {
+        let original = nested_object();
+
+        let bson = crate::BsonValue::from(original.clone()).into_inner();
+        assert_eq!(
+            bson,
+            Bson::Document(doc! {
+                "name": "Alice",
+                "address": { "city": "Paris", "geo": { "lat": 48.85, "lon": 2.35 } }
+            })
+        );
+        let back = TypedValue::from(BsonValueWithSchema::new(bson, Type::Object));
+        assert_eq!(back.sync_type, Type::Object);
+        assert_eq!(back.value, original.value);
+    }
 }
@@ -6,7 +6,8 @@ use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
 use surreal_sync_core::{Type, TypedValue, Value};
 
 use crate::reverse::{
-    json_to_typed_value_with_config, ConversionError, JsonConversionConfig, RowConversionConfig,
+    json_to_typed_value_with_config, unsigned_bigint_to_typed_value, ConversionError,
+    JsonConversionConfig, RowConversionConfig,
 };
 
 /// Schema/metadata for one binlog column when converting a [`CellValue`].
@@ -51,6 +52,10 @@ impl BinlogColumnMeta {
         self.column_type == MYSQL_TYPE_TINY && self.boolean_hint
     }
 
+    fn is_boolean_bit(&self) -> bool {
+        matches!(self.metadata, ColumnMetadata::Bit { length_bits: 1 })
+    }
+
     fn max_string_length(&self) -> u16 {
         match &self.metadata {
             ColumnMetadata::String { max_length } | ColumnMetadata::EnumSet { max_length } => {
@@ -116,12 +121,22 @@ fn binlog_cell_to_typed_value(
                     return Ok(TypedValue::bool(true));
                 }
             }
+            if column.unsigned {
+                return Ok(TypedValue::int16(i as i16));
+            }
             Ok(TypedValue::int8(i as i8, 4))
         }
+        MYSQL_TYPE_SHORT if column.unsigned => {
+            Ok(TypedValue::int32(extract_signed_int(cell)? as i32))
+        }
         MYSQL_TYPE_SHORT => Ok(TypedValue::int16(extract_signed_int(cell)? as i16)),
+        MYSQL_TYPE_LONG if column.unsigned => Ok(TypedValue::int64(extract_signed_int(cell)?)),
         MYSQL_TYPE_INT24 | MYSQL_TYPE_LONG => {
             Ok(TypedValue::int32(extract_signed_int(cell)? as i32))
         }
+        MYSQL_TYPE_LONGLONG if column.unsigned => {
+            Ok(unsigned_bigint_to_typed_value(extract_unsigned_int(cell)?))
+        }
         MYSQL_TYPE_LONGLONG => Ok(TypedValue::int64(extract_signed_int(cell)?)),
         MYSQL_TYPE_FLOAT => Ok(TypedValue::float32(extract_float(cell)? as f32)),
         MYSQL_TYPE_DOUBLE => Ok(TypedValue::float64(extract_float(cell)?)),
@@ -132,7 +147,8 @@ fn binlog_cell_to_typed_value(
                 CellValue::String(s) => s.clone(),
                 other => return Err(type_mismatch("decimal", other)),
             };
-            Ok(TypedValue::decimal(s, precision, scale))
+            TypedValue::try_decimal(s, precision, scale)
+                .map_err(|e| ConversionError::InvalidDecimal(e.to_string()))
         }
         MYSQL_TYPE_STRING => {
             let s = extract_string(cell)?;
@@ -245,8 +261,8 @@ fn binlog_cell_to_typed_value(
         }
         MYSQL_TYPE_BIT => {
             let bytes = extract_bytes(cell)?;
-            if bytes.len() == 1 && bytes[0] <= 1 {
-                Ok(TypedValue::bool(bytes[0] == 1))
+            if column.is_boolean_bit() {
+                Ok(TypedValue::bool(bytes.iter().any(|b| *b != 0)))
             } else {
                 Ok(TypedValue::bytes(bytes))
             }
@@ -263,8 +279,11 @@ fn column_type_to_sync_type(column: &BinlogColumnMeta) -> Type {
         return Type::Bool;
     }
     match column.column_type {
+        MYSQL_TYPE_TINY if column.unsigned => Type::Int16,
         MYSQL_TYPE_TINY => Type::Int8 { width: 4 },
+        MYSQL_TYPE_SHORT if column.unsigned => Type::Int32,
         MYSQL_TYPE_SHORT => Type::Int16,
+        MYSQL_TYPE_LONG if column.unsigned => Type::Int64,
         MYSQL_TYPE_INT24 | MYSQL_TYPE_LONG => Type::Int32,
         MYSQL_TYPE_LONGLONG => Type::Int64,
         MYSQL_TYPE_FLOAT => Type::Float32,
@@ -297,6 +316,7 @@ fn column_type_to_sync_type(column: &BinlogColumnMeta) -> Type {
         MYSQL_TYPE_GEOMETRY => Type::Geometry {
             geometry_type: surreal_sync_core::GeometryType::Point,
         },
+        MYSQL_TYPE_BIT if column.is_boolean_bit() => Type::Bool,
         MYSQL_TYPE_BIT => Type::Bytes,
         _ => Type::Text,
     }
@@ -886,6 +906,17 @@ fn extract_signed_int(cell: &CellValue) -> Result<i64, ConversionError> {
     }
 }
 
+fn extract_unsigned_int(cell: &CellValue) -> Result<u64, ConversionError> {
+    match cell {
+        CellValue::UInt(u) => Ok(*u),
+        CellValue::Int(i) if *i >= 0 => Ok(*i as u64),
+        CellValue::String(s) => s
+            .parse()
+            .map_err(|_| type_mismatch("unsigned integer", cell)),
+        other => Err(type_mismatch("unsigned integer", other)),
+    }
+}
+
 fn extract_float(cell: &CellValue) -> Result<f64, ConversionError> {
     match cell {
         CellValue::Float(f) => Ok(f64::from(*f)),
@@ -1564,6 +1595,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bit_wider_than_one_stays_bytes_at_boundary() {
+        // BIT(8) holding 1 is a byte, not a boolean
+        let column = col(
+            "bits",
+            MYSQL_TYPE_BIT,
+            ColumnMetadata::Bit { length_bits: 8 },
+        );
+        assert_eq!(
+            convert(&CellValue::Bit(vec![1]), &column),
+            Value::Bytes(vec![1])
+        );
+    }
+
+    #[test]
+    fn test_unsigned_bigint_boundaries() {
+        let column = col("id", MYSQL_TYPE_LONGLONG, ColumnMetadata::None).with_unsigned(true);
+        assert_eq!(
+            convert(&CellValue::UInt(i64::MAX as u64), &column),
+            Value::Int64(i64::MAX)
+        );
+        assert_eq!(
+            convert(&CellValue::UInt(u64::MAX), &column),
+            Value::Decimal {
+                value: u64::MAX.to_string(),
+                precision: 20,
+                scale: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_unsigned_int_widening() {
+        let tiny = col("t", MYSQL_TYPE_TINY, ColumnMetadata::None).with_unsigned(true);
+        assert_eq!(convert(&CellValue::UInt(255), &tiny), Value::Int16(255));
+        let short = col("s", MYSQL_TYPE_SHORT, ColumnMetadata::None).with_unsigned(true);
+        assert_eq!(
+            convert(&CellValue::UInt(65535), &short),
+            Value::Int32(65535)
+        );
+        let long = col("l", MYSQL_TYPE_LONG, ColumnMetadata::None).with_unsigned(true);
+        assert_eq!(
+            convert(&CellValue::UInt(u64::from(u32::MAX)), &long),
+            Value::Int64(i64::from(u32::MAX))
+        );
+    }
+
     #[test]
     fn test_geometry_column() {
         use base64::Engine;
@@ -35,6 +35,16 @@ impl BinlogPosition {
     }
 }
 
+impl std::fmt::Display for BinlogPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinlogPosition::FilePos { file, pos } => write!(f, "{file}:{pos}"),
+            BinlogPosition::MySqlGtid { executed } => write!(f, "{executed}"),
+            BinlogPosition::MariaDbGtid { executed } => write!(f, "{executed}"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ColumnMetadata {
     None,
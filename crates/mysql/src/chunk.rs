@@ -1,10 +1,13 @@
 //! Shared MySQL table-read helpers used by trigger and binlog sources.
 
-use crate::{row_to_typed_values_with_config, RowConversionConfig};
+use crate::{row_to_typed_values_with_config, MySQLValue, RowConversionConfig};
 use anyhow::Result;
 use mysql_async::{prelude::*, Params, Row as MysqlRow, Value as MysqlValue};
 use std::collections::HashMap;
-use surreal_sync_core::{Row, Type, Value};
+use surreal_sync_core::{
+    keyset_after_predicate, keyset_order_by, ColumnFilter, ColumnFilters, FieldMap, KeysetColumn,
+    NullsOrder, Row, Type, Value,
+};
 use tracing::debug;
 
 /// Get primary key column names for a table, in key ordinal order (returns an
@@ -40,6 +43,61 @@ pub struct TableChunk {
     pub last_pk: Option<Vec<Value>>,
 }
 
+/// Columns a keyset read selects when a [`ColumnFilters`] narrows the table
+/// (column push-down), from [`get_read_columns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadColumns {
+    /// Columns to `SELECT`, in table order. Includes the key columns.
+    pub select: Vec<String>,
+    /// Filter applied to converted fields, dropping key columns that were
+    /// read only for record ids and cursors.
+    pub filter: ColumnFilter,
+}
+
+impl ReadColumns {
+    fn select_list(&self) -> String {
+        self.select
+            .iter()
+            .map(|c| format!("`{}`", c.replace('`', "``")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Columns of `table` to read when `filters` narrows it.
+///
+/// Returns `None` (read every column) when the table has no filter. `keep`
+/// columns (primary and sort key) are selected even when filtered out,
+/// because record ids and keyset cursors are built from them.
+pub async fn get_read_columns(
+    conn: &mut mysql_async::Conn,
+    database: &str,
+    table: &str,
+    filters: &ColumnFilters,
+    keep: &[String],
+) -> Result<Option<ReadColumns>> {
+    let Some(filter) = filters.for_table(table) else {
+        return Ok(None);
+    };
+    let columns: Vec<String> = conn
+        .exec(
+            "SELECT COLUMN_NAME FROM INFORMATION_SCHEMA.COLUMNS \
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? ORDER BY ORDINAL_POSITION",
+            (database, table),
+        )
+        .await?;
+    let select: Vec<String> = filters
+        .select_columns(table, &columns, keep)
+        .into_iter()
+        .cloned()
+        .collect();
+    debug!("Reading columns {select:?} of table {table}");
+    Ok(Some(ReadColumns {
+        select,
+        filter: filter.clone(),
+    }))
+}
+
 /// Read a single primary-key-ordered chunk of a table using keyset pagination.
 ///
 /// Rows are ordered by the table's primary key column(s). When `after` is
@@ -65,39 +123,81 @@ pub async fn read_table_chunk(
             "Table '{table_name}' has no primary key columns; keyset chunk reads require a primary key"
         ));
     }
+    let key: Vec<KeysetColumn> = pk_columns.iter().map(KeysetColumn::new).collect();
+    read_table_chunk_by_key(
+        conn, table_name, pk_columns, &key, after, limit, 0, config, None,
+    )
+    .await
+}
 
-    let order_by = pk_columns.join(", ");
+/// Keyset-paginated read of a table ordered by an arbitrary (composite) sort key.
+///
+/// Like [`read_table_chunk`], but pages on `key` instead of the primary key, so
+/// composite-key tables (e.g. `(tenant_id, created_at)`) and tables without a
+/// primary key avoid LIMIT/OFFSET scans. NULL key values sort first (MySQL's
+/// ascending default) and the continuation predicate is expanded so they are
+/// neither skipped nor repeated. The key must be unique over the table for
+/// reads to be gap- and duplicate-free.
+///
+/// Record ids still come from `pk_columns`; when the table has no primary key
+/// (`pk_columns` empty) ids are synthetic `Int64(row_index_base + i)` values and
+/// every column is kept as a field, matching the LIMIT/OFFSET path.
+///
+/// `columns` (from [`get_read_columns`]) narrows the `SELECT` list and drops
+/// filtered-out key columns from the fields; `None` reads every column.
+#[allow(clippy::too_many_arguments)]
+pub async fn read_table_chunk_by_key(
+    conn: &mut mysql_async::Conn,
+    table_name: &str,
+    pk_columns: &[String],
+    key: &[KeysetColumn],
+    after: Option<&[Value]>,
+    limit: usize,
+    row_index_base: u64,
+    config: &RowConversionConfig,
+    columns: Option<&ReadColumns>,
+) -> Result<TableChunk> {
+    if key.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Table '{table_name}' has no keyset columns; keyset chunk reads require a sort key"
+        ));
+    }
+
+    let order_by = keyset_order_by(key, NullsOrder::First, false);
 
     let (where_clause, bind_values): (String, Vec<MysqlValue>) = match after {
         Some(cursor) => {
-            if cursor.len() != pk_columns.len() {
+            if cursor.len() != key.len() {
                 return Err(anyhow::anyhow!(
-                    "Keyset cursor length ({}) does not match primary key column count ({}) for table '{table_name}'",
+                    "Keyset cursor length ({}) does not match key column count ({}) for table '{table_name}'",
                     cursor.len(),
-                    pk_columns.len()
+                    key.len()
                 ));
             }
-            let placeholders = vec!["?"; pk_columns.len()];
-            let clause = if pk_columns.len() == 1 {
-                format!("WHERE {} > ?", pk_columns[0])
-            } else {
-                format!(
-                    "WHERE ({}) > ({})",
-                    pk_columns.join(", "),
-                    placeholders.join(", ")
-                )
+            // With NULLS FIRST some row can always follow a cursor, but stay
+            // defensive in case the predicate reports the end of the order.
+            let Some(predicate) =
+                keyset_after_predicate(key, cursor, NullsOrder::First, |_| "?".to_string())
+            else {
+                return Ok(TableChunk {
+                    rows: Vec::new(),
+                    last_pk: None,
+                });
             };
-            let values = cursor
+            let values = predicate
+                .binds
                 .iter()
-                .map(pk_value_to_mysql_value)
+                .map(|&i| pk_value_to_mysql_value(&cursor[i]))
                 .collect::<Result<Vec<_>>>()?;
-            (clause, values)
+            (format!("WHERE {}", predicate.sql), values)
         }
         None => (String::new(), Vec::new()),
     };
 
-    let query =
-        format!("SELECT * FROM {table_name} {where_clause} ORDER BY {order_by} LIMIT {limit}");
+    let select = columns.map_or_else(|| "*".to_string(), ReadColumns::select_list);
+    let query = format!(
+        "SELECT {select} FROM {table_name} {where_clause} ORDER BY {order_by} LIMIT {limit}"
+    );
     debug!("Chunk-reading table {table_name} with: {query}");
 
     let rows: Vec<MysqlRow> = conn
@@ -108,42 +208,128 @@ pub async fn read_table_chunk(
     let mut out = Vec::with_capacity(rows.len());
     let mut last_pk: Option<Vec<Value>> = None;
 
-    for (row_index, row) in rows.iter().enumerate() {
+    for (i, row) in rows.iter().enumerate() {
+        let row_index = row_index_base + i as u64;
         let typed_values = row_to_typed_values_with_config(row, config)?;
-        let values: HashMap<String, Value> = typed_values
+        let values: FieldMap = typed_values
             .into_iter()
             .map(|(k, tv)| (k, tv.value))
             .collect();
 
-        let id = extract_primary_key_value(&values, pk_columns)?;
-
-        let mut cursor_values = Vec::with_capacity(pk_columns.len());
-        for col in pk_columns {
-            let v = values.get(col).cloned().ok_or_else(|| {
+        let mut cursor_values = Vec::with_capacity(key.len());
+        for col in key {
+            let v = values.get(&col.name).cloned().ok_or_else(|| {
                 anyhow::anyhow!(
-                    "Primary key column '{col}' not found in row for table '{table_name}'"
+                    "Keyset column '{}' not found in row for table '{table_name}'",
+                    col.name
                 )
             })?;
             cursor_values.push(v);
         }
         last_pk = Some(cursor_values);
 
-        let fields: HashMap<String, Value> = values
-            .into_iter()
-            .filter(|(k, _)| !pk_columns.contains(k))
-            .collect();
+        let (id, mut fields) = if pk_columns.is_empty() {
+            (Value::Int64(row_index as i64), values)
+        } else {
+            let id = extract_primary_key_value(&values, pk_columns)?;
+            let fields: FieldMap = values
+                .into_iter()
+                .filter(|(k, _)| !pk_columns.contains(k))
+                .collect();
+            (id, fields)
+        };
+        if let Some(columns) = columns {
+            columns.filter.retain(&mut fields);
+        }
 
-        out.push(Row::new(
-            table_name.to_string(),
-            row_index as u64,
-            id,
-            fields,
-        ));
+        out.push(Row::new(table_name.to_string(), row_index, id, fields));
     }
 
     Ok(TableChunk { rows: out, last_pk })
 }
 
+/// Resolve user-specified keyset sort columns for a table, probing nullability
+/// from INFORMATION_SCHEMA. Unknown columns are an error.
+pub async fn get_keyset_columns(
+    conn: &mut mysql_async::Conn,
+    database: &str,
+    table: &str,
+    columns: &[String],
+) -> Result<Vec<KeysetColumn>> {
+    let rows: Vec<MysqlRow> = conn
+        .exec(
+            "SELECT COLUMN_NAME, IS_NULLABLE FROM INFORMATION_SCHEMA.COLUMNS \
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+            (database, table),
+        )
+        .await?;
+
+    let nullable: HashMap<String, bool> = rows
+        .into_iter()
+        .filter_map(|row| {
+            let name = row.get::<String, _>("COLUMN_NAME")?;
+            let is_nullable = row.get::<String, _>("IS_NULLABLE")?;
+            Some((name, is_nullable == "YES"))
+        })
+        .collect();
+
+    columns
+        .iter()
+        .map(|col| match nullable.get(col) {
+            Some(false) => Ok(KeysetColumn::new(col)),
+            Some(true) => Ok(KeysetColumn::nullable(col)),
+            None => Err(anyhow::anyhow!(
+                "Keyset column '{col}' does not exist in table '{table}'"
+            )),
+        })
+        .collect()
+}
+
+/// Resolve user-specified record-ID columns for a table (`--id-columns`).
+/// Unknown and nullable columns are an error, since every row needs a value
+/// for each of them to build its record ID.
+pub async fn get_id_columns(
+    conn: &mut mysql_async::Conn,
+    database: &str,
+    table: &str,
+    columns: &[String],
+) -> Result<Vec<String>> {
+    let rows: Vec<MysqlRow> = conn
+        .exec(
+            "SELECT COLUMN_NAME, IS_NULLABLE FROM INFORMATION_SCHEMA.COLUMNS \
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+            (database, table),
+        )
+        .await?;
+
+    let nullable: HashMap<String, bool> = rows
+        .into_iter()
+        .filter_map(|row| {
+            let name = row.get::<String, _>("COLUMN_NAME")?;
+            let is_nullable = row.get::<String, _>("IS_NULLABLE")?;
+            Some((name, is_nullable == "YES"))
+        })
+        .collect();
+
+    for col in columns {
+        match nullable.get(col) {
+            Some(false) => {}
+            Some(true) => {
+                return Err(anyhow::anyhow!(
+                    "Id column '{col}' of table '{table}' is nullable; \
+                     record ID columns must be NOT NULL"
+                ))
+            }
+            None => {
+                return Err(anyhow::anyhow!(
+                    "Id column '{col}' does not exist in table '{table}'"
+                ))
+            }
+        }
+    }
+    Ok(columns.to_vec())
+}
+
 /// Convert a primary-key `Value` into a MySQL bind value for keyset
 /// pagination.
 fn pk_value_to_mysql_value(value: &Value) -> Result<MysqlValue> {
@@ -156,6 +342,10 @@ fn pk_value_to_mysql_value(value: &Value) -> Result<MysqlValue> {
         Value::VarChar { value, .. } => MysqlValue::Bytes(value.clone().into_bytes()),
         Value::Char { value, .. } => MysqlValue::Bytes(value.clone().into_bytes()),
         Value::Uuid(v) => MysqlValue::Bytes(v.to_string().into_bytes()),
+        Value::Date(_)
+        | Value::LocalDateTime(_)
+        | Value::LocalDateTimeNano(_)
+        | Value::ZonedDateTime(_) => MySQLValue::from(value.clone()).into_inner(),
         other => {
             return Err(anyhow::anyhow!(
                 "Unsupported primary key value type for keyset pagination: {other:?}"
@@ -165,10 +355,7 @@ fn pk_value_to_mysql_value(value: &Value) -> Result<MysqlValue> {
 }
 
 /// Extract primary key value from values map
-fn extract_primary_key_value(
-    values: &HashMap<String, Value>,
-    pk_columns: &[String],
-) -> Result<Value> {
+fn extract_primary_key_value(values: &FieldMap, pk_columns: &[String]) -> Result<Value> {
     if pk_columns.is_empty() {
         return Err(anyhow::anyhow!(
             "Table has no primary key defined - primary key is required for sync"
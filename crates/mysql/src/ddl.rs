@@ -124,6 +124,26 @@ impl ToDdl for MySQLDdl {
 }
 
 impl MySQLDdl {
+    /// Convert a Type to an `UNSIGNED` MySQL column type.
+    ///
+    /// Integer types keep their width and gain the `UNSIGNED` attribute.
+    /// `DECIMAL(20,0)`, which is how values above `i64::MAX` from a
+    /// `BIGINT UNSIGNED` column are represented, maps back to
+    /// `BIGINT UNSIGNED`. Other types have no unsigned form and fall back
+    /// to [`ToDdl::to_ddl`].
+    pub fn to_unsigned_ddl(&self, ext_type: &Type) -> String {
+        match ext_type {
+            Type::Int8 { .. } | Type::Int16 | Type::Int32 | Type::Int64 => {
+                format!("{} UNSIGNED", self.to_ddl(ext_type))
+            }
+            Type::Decimal {
+                precision: 20,
+                scale: 0,
+            } => "BIGINT UNSIGNED".to_string(),
+            _ => self.to_ddl(ext_type),
+        }
+    }
+
     /// Generate a CREATE TABLE statement with a primary key.
     pub fn to_create_table_with_pk(
         &self,
@@ -233,6 +253,33 @@ mod tests {
         assert_eq!(ddl.to_ddl(&Type::Int64), "BIGINT");
     }
 
+    #[test]
+    fn test_unsigned_integer_ddl() {
+        let ddl = MySQLDdl;
+        assert_eq!(
+            ddl.to_unsigned_ddl(&Type::Int8 { width: 3 }),
+            "TINYINT(3) UNSIGNED"
+        );
+        assert_eq!(ddl.to_unsigned_ddl(&Type::Int16), "SMALLINT UNSIGNED");
+        assert_eq!(ddl.to_unsigned_ddl(&Type::Int32), "INT UNSIGNED");
+        assert_eq!(ddl.to_unsigned_ddl(&Type::Int64), "BIGINT UNSIGNED");
+        assert_eq!(
+            ddl.to_unsigned_ddl(&Type::Decimal {
+                precision: 20,
+                scale: 0
+            }),
+            "BIGINT UNSIGNED"
+        );
+        assert_eq!(
+            ddl.to_unsigned_ddl(&Type::Decimal {
+                precision: 10,
+                scale: 2
+            }),
+            "DECIMAL(10,2)"
+        );
+        assert_eq!(ddl.to_unsigned_ddl(&Type::Text), "TEXT");
+    }
+
     #[test]
     fn test_float_ddl() {
         let ddl = MySQLDdl;
@@ -250,6 +297,13 @@ mod tests {
             }),
             "DECIMAL(10,2)"
         );
+        assert_eq!(
+            ddl.to_ddl(&Type::Decimal {
+                precision: 38,
+                scale: 10
+            }),
+            "DECIMAL(38,10)"
+        );
     }
 
     #[test]
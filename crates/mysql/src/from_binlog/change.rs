@@ -8,7 +8,7 @@ use crate::{
     RowConversionConfig,
 };
 use anyhow::{anyhow, Result};
-use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, Type, Value};
+use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, FieldMap, Type, Value};
 
 pub fn cdc_to_change(
     change: &CdcChange,
@@ -25,12 +25,19 @@ pub fn cdc_to_change(
     let config = row_conversion_config(schema, &change.table, json_columns);
 
     let update_merged;
+    let mut changed_columns = None;
     let (op, cells): (ChangeOp, &[CellValue]) = match &change.operation {
         RowChange::Insert(values) => (ChangeOp::Create, values.as_slice()),
         RowChange::Update { before, after } => {
             let pk_indices = pk_column_indices(column_names, &pk_columns);
             update_merged = merge_update_cells(before, after, &pk_indices)
                 .map_err(|e| anyhow!("apply partial JSON update: {e}"))?;
+            changed_columns = Some(changed_column_names(
+                before,
+                &update_merged,
+                column_names,
+                &pk_columns,
+            ));
             (ChangeOp::Update, update_merged.as_slice())
         }
         RowChange::Delete(values) => (ChangeOp::Delete, values.as_slice()),
@@ -52,7 +59,7 @@ pub fn cdc_to_change(
         ));
     }
 
-    let mut values = HashMap::new();
+    let mut values = FieldMap::new();
     for ((col_def, cell), column_name) in table_map
         .columns
         .iter()
@@ -71,14 +78,34 @@ pub fn cdc_to_change(
     let data = if op == ChangeOp::Delete {
         None
     } else {
-        let fields: HashMap<String, Value> = values
+        let fields: FieldMap = values
             .into_iter()
             .filter(|(name, _)| !pk_columns.contains(name))
             .collect();
         Some(fields)
     };
 
-    Ok(Change::new(op, change.table.clone(), id, data))
+    let change = Change::new(op, change.table.clone(), id, data);
+    Ok(match changed_columns {
+        Some(changed) => change.with_changed_fields(changed),
+        None => change,
+    })
+}
+
+/// Non-key columns whose merged after-image cell differs from the before image.
+fn changed_column_names(
+    before: &[CellValue],
+    merged: &[CellValue],
+    column_names: &[String],
+    pk_columns: &[String],
+) -> Vec<String> {
+    before
+        .iter()
+        .zip(merged)
+        .zip(column_names)
+        .filter(|((prev, next), name)| prev != next && !pk_columns.contains(name))
+        .map(|(_, name)| name.clone())
+        .collect()
 }
 
 fn table_pk_columns(table_def: &surreal_sync_core::TableDefinition) -> Vec<String> {
@@ -258,7 +285,7 @@ fn merge_update_cells(
         .collect()
 }
 
-fn extract_primary_key(values: &HashMap<String, Value>, pk_columns: &[String]) -> Result<Value> {
+fn extract_primary_key(values: &FieldMap, pk_columns: &[String]) -> Result<Value> {
     if pk_columns.is_empty() {
         return Err(anyhow!("table has no primary key"));
     }
@@ -384,4 +411,21 @@ mod tests {
             other => panic!("expected Set, got {other:?}"),
         }
     }
+
+    #[test]
+    fn update_lists_only_changed_non_key_columns() {
+        let names: Vec<String> = ["id", "name", "age"].map(String::from).to_vec();
+        let before = [
+            CellValue::Int(1),
+            CellValue::String("bob".into()),
+            CellValue::Int(30),
+        ];
+        let after = [CellValue::Int(1), CellValue::Null, CellValue::Int(31)];
+        // A NULL after-image cell (minimal row image) keeps the old value.
+        let merged = merge_update_cells(&before, &after, &[0]).unwrap();
+        assert_eq!(
+            changed_column_names(&before, &merged, &names, &["id".to_string()]),
+            vec!["age".to_string()]
+        );
+    }
 }
@@ -18,6 +18,11 @@ impl surreal_sync_core::Checkpoint for BinlogCheckpoint {
 
     fn to_cli_string(&self) -> String {
         match &self.position {
+            // The bare `file:pos` form, as `SHOW BINARY LOG STATUS` prints it,
+            // when it parses back unambiguously.
+            BinlogPosition::FilePos { file, pos } if is_bare_file_name(file) => {
+                format!("{file}:{pos}")
+            }
             BinlogPosition::FilePos { file, pos } => format!("file:{file}:{pos}"),
             BinlogPosition::MySqlGtid { executed } => format!("gtid:{executed}"),
             // Use the compact connect-state form (comma, no space) so the string
@@ -30,6 +35,13 @@ impl surreal_sync_core::Checkpoint for BinlogCheckpoint {
 
     fn from_cli_string(s: &str) -> Result<Self> {
         let s = s.strip_prefix("mysql-binlog:").unwrap_or(s);
+        if let Some(position) = parse_bare_file_pos(s) {
+            return Ok(Self {
+                flavor: Flavor::MySql,
+                position,
+                timestamp: Utc::now(),
+            });
+        }
         let rest = s
             .strip_prefix("file:")
             .map(|body| (Flavor::MySql, body, "file"))
@@ -84,6 +96,24 @@ impl surreal_sync_core::Checkpoint for BinlogCheckpoint {
     }
 }
 
+/// Parse a bare `mysql-bin.000042:12345` file position, as printed by
+/// `SHOW BINARY LOG STATUS`.
+///
+/// Binlog file names always carry a `.NNNNNN` suffix, which keeps the bare
+/// form apart from a MySQL GTID set (`uuid:1-107`).
+fn parse_bare_file_pos(s: &str) -> Option<BinlogPosition> {
+    let (file, pos) = s.rsplit_once(':')?;
+    if !is_bare_file_name(file) {
+        return None;
+    }
+    Some(BinlogPosition::file_pos(file, pos.parse().ok()?))
+}
+
+/// Whether `file` can be written without the `file:` prefix.
+fn is_bare_file_name(file: &str) -> bool {
+    !file.contains(':') && file.contains('.')
+}
+
 /// Capture the current binlog client position as a checkpoint.
 pub fn get_current_checkpoint(
     client: &crate::binlog_protocol::BinlogClient,
@@ -151,7 +181,7 @@ mod tests {
             timestamp: Utc::now(),
         };
         let cli = original.to_cli_string();
-        assert_eq!(cli, "file:mysql-bin.000003:195");
+        assert_eq!(cli, "mysql-bin.000003:195");
         let decoded = BinlogCheckpoint::from_cli_string(&cli).unwrap();
         assert_eq!(decoded.position, original.position);
     }
@@ -274,6 +304,39 @@ mod tests {
         assert!(matches!(decoded.position, BinlogPosition::MySqlGtid { .. }));
     }
 
+    #[test]
+    fn bare_file_checkpoint_is_accepted() {
+        let decoded = BinlogCheckpoint::from_cli_string("mysql-bin.000042:12345").unwrap();
+        assert_eq!(
+            decoded.position,
+            BinlogPosition::file_pos("mysql-bin.000042", 12345)
+        );
+        assert_eq!(decoded.to_cli_string(), "mysql-bin.000042:12345");
+        assert_eq!(
+            BinlogCheckpoint::from_cli_string(&decoded.to_cli_string())
+                .unwrap()
+                .position,
+            decoded.position
+        );
+        assert!(BinlogCheckpoint::from_cli_string("mysql-bin.000042:abc").is_err());
+    }
+
+    #[test]
+    fn file_checkpoint_without_extension_keeps_prefix() {
+        let checkpoint = BinlogCheckpoint {
+            flavor: Flavor::MySql,
+            position: BinlogPosition::file_pos("binlog", 4),
+            timestamp: Utc::now(),
+        };
+        assert_eq!(checkpoint.to_cli_string(), "file:binlog:4");
+        assert_eq!(
+            BinlogCheckpoint::from_cli_string(&checkpoint.to_cli_string())
+                .unwrap()
+                .position,
+            checkpoint.position
+        );
+    }
+
     #[test]
     fn invalid_checkpoint_is_rejected() {
         assert!(BinlogCheckpoint::from_cli_string("not-a-checkpoint").is_err());
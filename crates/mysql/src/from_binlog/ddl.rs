@@ -1,4 +1,8 @@
+use std::collections::HashMap;
+
 use crate::binlog_protocol::QueryEvent;
+use crate::mysql_column_to_universal_type;
+use surreal_sync_core::{ColumnDefinition, DatabaseSchema, Type};
 
 pub(crate) fn is_table_affecting_ddl(query: &QueryEvent, database: &str) -> bool {
     if !query.database.is_empty() && !query.database.eq_ignore_ascii_case(database) {
@@ -102,6 +106,249 @@ fn parse_rename_pairs(tokens: &[String], upper: &[String]) -> Vec<TableRename> {
     out
 }
 
+/// Where `ALTER TABLE ... ADD COLUMN` puts the new column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ColumnPosition {
+    Last,
+    First,
+    After(String),
+}
+
+/// A column added by `ALTER TABLE ... ADD [COLUMN]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ColumnAdd {
+    pub name: String,
+    /// Base type as `INFORMATION_SCHEMA.COLUMNS.DATA_TYPE` reports it (`varchar`).
+    pub data_type: String,
+    /// Full type as `COLUMN_TYPE` reports it (`varchar(16)`, `int unsigned`).
+    pub column_type: String,
+    pub position: ColumnPosition,
+}
+
+/// The columns an `ALTER TABLE` adds to `table`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ColumnAdds {
+    pub table: String,
+    pub columns: Vec<ColumnAdd>,
+}
+
+/// Extract the added columns from an `ALTER TABLE` that only adds columns.
+///
+/// Returns `None` for any other statement, including an `ALTER TABLE` that
+/// also drops, modifies or renames something, or adds an index: those need a
+/// full catalog reload. `ALGORITHM=` and `LOCK=` clauses are ignored. The
+/// parenthesised `ADD COLUMN (a int, b int)` form is not recognised.
+pub(crate) fn parse_column_adds(sql: &str) -> Option<ColumnAdds> {
+    let rest = strip_keyword(strip_leading_comments(sql), "ALTER")?;
+    let rest = strip_keyword(rest, "TABLE")?;
+    let (table, rest) = next_word(rest)?;
+    let mut columns = Vec::new();
+    for clause in split_top_level(rest, |ch| ch == ',') {
+        let upper = clause.to_ascii_uppercase();
+        if upper.starts_with("ALGORITHM") || upper.starts_with("LOCK") {
+            continue;
+        }
+        columns.push(parse_add_column_clause(clause)?);
+    }
+    (!columns.is_empty()).then(|| ColumnAdds {
+        table: strip_qualifier(table),
+        columns,
+    })
+}
+
+/// Parse `ADD [COLUMN] name type [attributes] [FIRST | AFTER col]`.
+fn parse_add_column_clause(clause: &str) -> Option<ColumnAdd> {
+    let rest = strip_keyword(clause, "ADD")?;
+    let (rest, explicit) = match strip_keyword(rest, "COLUMN") {
+        Some(rest) => (rest, true),
+        None => (rest, false),
+    };
+    let (name, rest) = next_word(rest)?;
+    // Without COLUMN, `ADD INDEX ...`, `ADD PRIMARY KEY ...` and friends add
+    // something other than a column.
+    if !explicit
+        && matches!(
+            name.to_ascii_uppercase().as_str(),
+            "INDEX"
+                | "KEY"
+                | "UNIQUE"
+                | "PRIMARY"
+                | "CONSTRAINT"
+                | "FOREIGN"
+                | "FULLTEXT"
+                | "SPATIAL"
+                | "CHECK"
+                | "PARTITION"
+        )
+    {
+        return None;
+    }
+    if name.starts_with('(') {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    let base_len = rest
+        .find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_')
+        .unwrap_or(rest.len());
+    if base_len == 0 {
+        return None;
+    }
+    let data_type = rest[..base_len].to_ascii_lowercase();
+    let mut rest = &rest[base_len..];
+    let mut column_type = data_type.clone();
+    if rest.starts_with('(') {
+        let args = split_top_level(rest, char::is_whitespace)
+            .into_iter()
+            .next()?;
+        column_type.push_str(args);
+        rest = &rest[args.len()..];
+    }
+
+    let words = split_top_level(rest, char::is_whitespace);
+    let upper: Vec<String> = words.iter().map(|w| w.to_ascii_uppercase()).collect();
+    for flag in ["UNSIGNED", "ZEROFILL"] {
+        if upper.iter().any(|w| w == flag) {
+            column_type.push(' ');
+            column_type.push_str(&flag.to_ascii_lowercase());
+        }
+    }
+    let position = match upper.iter().position(|w| w == "FIRST" || w == "AFTER") {
+        Some(at) if upper[at] == "FIRST" => ColumnPosition::First,
+        Some(at) => ColumnPosition::After(unquote(words.get(at + 1)?)),
+        None => ColumnPosition::Last,
+    };
+
+    Some(ColumnAdd {
+        name: unquote(name),
+        data_type,
+        column_type,
+        position,
+    })
+}
+
+/// Add `adds` to the cached schema and ordinal column names of its table, so
+/// row events after the `ALTER TABLE` convert against the columns the binlog
+/// had at that point rather than the catalog's current ones.
+///
+/// Columns already present (the cache was read after the DDL ran) are
+/// skipped. Returns `false`, leaving everything untouched, when the table is
+/// not in `schema` or an `AFTER` column is unknown; the caller then reloads
+/// the catalog instead.
+pub(crate) fn apply_column_adds(
+    adds: &ColumnAdds,
+    schema: &mut DatabaseSchema,
+    column_names: &mut Vec<String>,
+    json_columns: &mut HashMap<String, Vec<String>>,
+) -> bool {
+    let Some(table_def) = schema.get_table(&adds.table) else {
+        return false;
+    };
+    let mut table_def = table_def.clone();
+    let mut names = column_names.clone();
+    let mut new_json = Vec::new();
+    for add in &adds.columns {
+        if names.contains(&add.name) {
+            continue;
+        }
+        let at = match &add.position {
+            ColumnPosition::Last => names.len(),
+            ColumnPosition::First => 0,
+            ColumnPosition::After(col) => match names.iter().position(|n| n == col) {
+                Some(i) => i + 1,
+                None => return false,
+            },
+        };
+        names.insert(at, add.name.clone());
+
+        let (precision, scale) = numeric_precision_scale(&add.column_type);
+        let column_type =
+            mysql_column_to_universal_type(&add.data_type, &add.column_type, precision, scale);
+        if column_type == Type::Json {
+            new_json.push(add.name.clone());
+        }
+        table_def
+            .columns
+            .push(ColumnDefinition::new(add.name.clone(), column_type));
+    }
+
+    if let Some(slot) = schema.get_table_mut(&adds.table) {
+        *slot = table_def;
+    }
+    *column_names = names;
+    if !new_json.is_empty() {
+        json_columns
+            .entry(adds.table.clone())
+            .or_default()
+            .extend(new_json);
+    }
+    true
+}
+
+/// `(precision, scale)` of a `decimal(p,s)` style type; `None` where absent.
+fn numeric_precision_scale(column_type: &str) -> (Option<u32>, Option<u32>) {
+    let Some(args) = column_type
+        .split_once('(')
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .map(|(args, _)| args)
+    else {
+        return (None, None);
+    };
+    let mut parts = args.split(',').map(|p| p.trim().parse::<u32>().ok());
+    (parts.next().flatten(), parts.next().flatten())
+}
+
+/// Strip a leading case-insensitive keyword followed by whitespace (or the end).
+fn strip_keyword<'a>(sql: &'a str, keyword: &str) -> Option<&'a str> {
+    let sql = sql.trim_start();
+    let head = sql.get(..keyword.len())?;
+    let rest = &sql[keyword.len()..];
+    (head.eq_ignore_ascii_case(keyword)
+        && rest
+            .chars()
+            .next()
+            .is_none_or(|ch| !ch.is_ascii_alphanumeric() && ch != '_'))
+    .then_some(rest)
+}
+
+/// The next whitespace-delimited word (quotes respected) and what follows it.
+fn next_word(sql: &str) -> Option<(&str, &str)> {
+    let sql = sql.trim_start();
+    let word = split_top_level(sql, char::is_whitespace)
+        .into_iter()
+        .next()?;
+    Some((word, &sql[word.len()..]))
+}
+
+/// Split on `is_sep` outside quotes and parentheses, dropping empty pieces.
+fn split_top_level(sql: &str, is_sep: impl Fn(char) -> bool) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, ch) in sql.char_indices() {
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some(_) => {}
+            None if matches!(ch, '\'' | '"' | '`') => quote = Some(ch),
+            None if ch == '(' => depth += 1,
+            None if ch == ')' => depth = depth.saturating_sub(1),
+            None if depth == 0 && is_sep(ch) => {
+                pieces.push(sql[start..i].trim());
+                start = i + ch.len_utf8();
+            }
+            None => {}
+        }
+    }
+    pieces.push(sql[start..].trim());
+    pieces.retain(|p| !p.is_empty());
+    pieces
+}
+
+fn unquote(name: &str) -> String {
+    name.trim_matches(|c| c == '`' || c == '"').to_string()
+}
+
 fn strip_qualifier(name: &str) -> String {
     let unquoted = name.trim_matches(|c| c == '`' || c == '"');
     match unquoted.rsplit_once('.') {
@@ -280,4 +527,146 @@ mod tests {
         assert!(parse_table_renames("ALTER TABLE t RENAME COLUMN a TO b").is_empty());
         assert!(parse_table_renames("ALTER TABLE t RENAME INDEX i TO j").is_empty());
     }
+
+    #[test]
+    fn parses_add_column_forms() {
+        assert_eq!(
+            parse_column_adds(
+                "ALTER TABLE `app`.`widgets` ADD COLUMN `color` varchar(16) NOT NULL \
+                 DEFAULT 'red, or first' AFTER `id`, ALGORITHM=INSTANT"
+            ),
+            Some(ColumnAdds {
+                table: "widgets".into(),
+                columns: vec![ColumnAdd {
+                    name: "color".into(),
+                    data_type: "varchar".into(),
+                    column_type: "varchar(16)".into(),
+                    position: ColumnPosition::After("id".into()),
+                }],
+            })
+        );
+
+        let adds = parse_column_adds(
+            "alter table widgets add price DECIMAL(10, 2) unsigned first, add column tags json",
+        )
+        .expect("two added columns");
+        assert_eq!(adds.columns[0].name, "price");
+        assert_eq!(adds.columns[0].column_type, "decimal(10, 2) unsigned");
+        assert_eq!(adds.columns[0].position, ColumnPosition::First);
+        assert_eq!(adds.columns[1].data_type, "json");
+        assert_eq!(adds.columns[1].position, ColumnPosition::Last);
+    }
+
+    #[test]
+    fn other_alter_table_clauses_are_not_column_adds() {
+        for sql in [
+            "ALTER TABLE widgets ADD INDEX idx_color (color)",
+            "ALTER TABLE widgets ADD UNIQUE KEY (color)",
+            "ALTER TABLE widgets ADD COLUMN c int, DROP COLUMN d",
+            "ALTER TABLE widgets DROP COLUMN d",
+            "ALTER TABLE widgets RENAME COLUMN a TO b",
+            "ALTER TABLE widgets ADD COLUMN (a int, b int)",
+            "CREATE TABLE widgets (id int)",
+        ] {
+            assert_eq!(parse_column_adds(sql), None, "{sql}");
+        }
+    }
+
+    #[test]
+    fn insert_after_add_column_converts_the_new_column() {
+        use crate::binlog_protocol::column_types::{MYSQL_TYPE_LONG, MYSQL_TYPE_VARCHAR};
+        use crate::binlog_protocol::{
+            BinlogPosition, CdcChange, CellValue, ColumnDef, ColumnMetadata, RowChange,
+            TableMapEvent,
+        };
+        use surreal_sync_core::{TableDefinition, Value};
+
+        let mut schema = DatabaseSchema::new(vec![TableDefinition::new(
+            "widgets",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![ColumnDefinition::new("name", Type::VarChar { length: 32 })],
+        )]);
+        let mut names = vec!["id".to_string(), "name".to_string()];
+        let mut json_columns = HashMap::new();
+
+        let adds = parse_column_adds(
+            "ALTER TABLE widgets ADD COLUMN color varchar(16) AFTER id, ADD COLUMN meta json",
+        )
+        .unwrap();
+        assert!(apply_column_adds(
+            &adds,
+            &mut schema,
+            &mut names,
+            &mut json_columns
+        ));
+        assert_eq!(names, ["id", "color", "name", "meta"]);
+        assert_eq!(
+            schema.get_column_type("widgets", "color").unwrap(),
+            &Type::VarChar { length: 16 }
+        );
+        assert_eq!(json_columns["widgets"], ["meta"]);
+
+        let varchar =
+            |max_length| ColumnDef::new(MYSQL_TYPE_VARCHAR, ColumnMetadata::String { max_length });
+        let table_map = TableMapEvent {
+            table_id: 7,
+            database: "app".into(),
+            table: "widgets".into(),
+            columns: vec![
+                ColumnDef::new(MYSQL_TYPE_LONG, ColumnMetadata::None),
+                varchar(64),
+                varchar(128),
+                varchar(1024),
+            ],
+        };
+        let insert = CdcChange {
+            position: BinlogPosition::file_pos("mysql-bin.000001", 120),
+            database: "app".into(),
+            table: "widgets".into(),
+            operation: RowChange::Insert(vec![
+                CellValue::Int(1),
+                CellValue::String("blue".into()),
+                CellValue::String("bolt".into()),
+                CellValue::Null,
+            ]),
+            xid: None,
+            gtid: None,
+        };
+
+        let change =
+            crate::from_binlog::cdc_to_change(&insert, &table_map, &names, &schema, &json_columns)
+                .unwrap();
+        let fields = change.fields.expect("insert carries fields");
+        assert_eq!(change.id, Value::Int32(1));
+        assert_eq!(fields["color"].as_str(), Some("blue"));
+        assert_eq!(fields["name"].as_str(), Some("bolt"));
+        assert!(fields["meta"].is_null());
+    }
+
+    #[test]
+    fn add_column_after_unknown_column_needs_a_refresh() {
+        let mut schema = DatabaseSchema::new(vec![surreal_sync_core::TableDefinition::new(
+            "widgets",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![],
+        )]);
+        let mut names = vec!["id".to_string()];
+        let adds = parse_column_adds("ALTER TABLE widgets ADD c int AFTER missing").unwrap();
+        assert!(!apply_column_adds(
+            &adds,
+            &mut schema,
+            &mut names,
+            &mut HashMap::new()
+        ));
+        assert_eq!(names, ["id"]);
+        assert!(schema.get_table("widgets").unwrap().columns.is_empty());
+
+        let adds = parse_column_adds("ALTER TABLE gadgets ADD c int").unwrap();
+        assert!(!apply_column_adds(
+            &adds,
+            &mut schema,
+            &mut names,
+            &mut HashMap::new()
+        ));
+    }
 }
@@ -6,7 +6,7 @@
 use clap::{Args, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
-use surreal_sync_runtime::SurrealCliOpts as SurrealOpts;
+use surreal_sync_runtime::{ColumnFilterCliOpts, SurrealCliOpts as SurrealOpts};
 
 /// Full-sync strategy for sources that support interleaved snapshot.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, ValueEnum)]
@@ -22,6 +22,13 @@ pub enum SyncStrategy {
     /// (unbounded retention). Opt-out for tables without a usable primary key
     /// or when writing watermark rows to the source is not allowed.
     SequentialSnapshot,
+    /// One transactionally consistent read of every table at exactly the
+    /// position streaming starts from, so the handoff neither misses nor
+    /// replays a change. MySQL holds `FLUSH TABLES WITH READ LOCK` only while
+    /// the snapshot transaction opens; PostgreSQL (pgoutput) reads the
+    /// snapshot exported when the replication slot is created. The source log
+    /// is pinned for the whole snapshot.
+    ConsistentSnapshot,
 }
 
 /// Default chunk size for the watermark snapshot (matches Debezium's
@@ -225,6 +232,9 @@ pub struct SyncArgs {
     #[arg(long, value_name = "PATH")]
     pub transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    pub columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     pub surreal: SurrealOpts,
 }
@@ -6,7 +6,7 @@
 //! define transforms, and call [`run`]:
 //!
 //! ```ignore
-//! use surreal_sync_mysql::from_binlog::{run, FlattenId, InPlaceTransform, Value};
+//! use surreal_sync_mysql::from_binlog::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 //! use surreal_sync_surreal::Surreal3Sink;
 //!
 //! #[tokio::main]
@@ -44,13 +44,14 @@ use surreal_sync_runtime::{ApplyOpts, SnapshotTransforms};
 use tokio_util::sync::CancellationToken;
 
 use crate::from_binlog::{
-    new_mysql_pool_with_ssl, request_snapshot, run_full_sync_cancellable_with_transforms,
+    new_mysql_pool_with_ssl, request_snapshot, run_consistent_snapshot_full_sync_with_transforms,
+    run_full_sync_cancellable_with_transforms, run_initial_consistent_snapshot_with_transforms,
     run_initial_interleaved_snapshot_with_transforms,
     run_interleaved_snapshot_full_sync_with_transforms, run_replication_tail_with_transforms,
     BinlogCheckpoint, InterleavedFullSyncOptions, ReplicationTailOptions, SourceOpts, SyncOpts,
 };
 
-pub use surreal_sync_core::Value;
+pub use surreal_sync_core::{FieldMap, Value};
 pub use surreal_sync_runtime::SinkWithCheckpoints;
 pub use surreal_sync_runtime::{FlattenId, InPlaceTransform, Pipeline};
 
@@ -292,6 +293,19 @@ where
             tracing::info!("Sequential binlog full sync completed");
             Ok(None)
         }
+        SyncStrategy::ConsistentSnapshot => {
+            run_consistent_snapshot_full_sync_with_transforms(
+                sink,
+                source_opts,
+                sync_opts,
+                manager,
+                &cancel,
+                &transforms.pipeline,
+                &transforms.apply_opts,
+            )
+            .await?;
+            Ok(None)
+        }
     }
 }
 
@@ -341,12 +355,17 @@ pub async fn run_sync_with_manager<S: SurrealSink, St: CheckpointStore>(
     binlog_orchestrate(sink, args, cancel, checkpoint_manager, pipeline, apply_opts).await
 }
 
-/// Load `--transforms-config` (if any), append Rust [`InPlaceTransform`] stages,
+/// Load `--transforms-config` (if any), append Rust [`InPlaceTransform`] stages
+/// and the `--include-columns` / `--exclude-columns` stage,
 /// apply the ApplyOpts upgrade rule, connect `S`, then [`run_sync`].
 pub async fn run_sync_with_extra_transforms<S: SinkWithCheckpoints>(
     args: SyncArgs,
     extra: impl IntoIterator<Item = Box<dyn InPlaceTransform>>,
 ) -> anyhow::Result<()> {
+    let columns = args.columns.to_filters()?.into_stage();
+    let extra = extra
+        .into_iter()
+        .chain(columns.map(|c| Box::new(c) as Box<dyn InPlaceTransform>));
     let (pipeline, apply_opts) = merge_inplace_boxed(args.transforms_config.as_deref(), extra)?;
     let config = args
         .surreal
@@ -466,6 +485,34 @@ where
                     .await?;
                     None
                 }
+                SyncStrategy::ConsistentSnapshot => {
+                    let Some(stream_start) = run_initial_consistent_snapshot_with_transforms(
+                        sink,
+                        &source_opts,
+                        &sync_opts,
+                        checkpoint_manager,
+                        &cancel,
+                        &transforms.pipeline,
+                        &transforms.apply_opts,
+                    )
+                    .await?
+                    else {
+                        tracing::info!(
+                            "Sync cancelled during consistent snapshot; the next run re-takes it"
+                        );
+                        return Ok(());
+                    };
+                    return run_replication_tail_with_transforms(
+                        sink,
+                        source_opts,
+                        stream_start,
+                        stream_options,
+                        checkpoint_manager,
+                        &transforms.pipeline,
+                        &transforms.apply_opts,
+                    )
+                    .await;
+                }
             };
 
             if let Some(outcome) = interleaved_outcome {
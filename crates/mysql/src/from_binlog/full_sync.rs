@@ -4,12 +4,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::{row_to_typed_values_with_config, RowConversionConfig};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use mysql_async::{prelude::*, Pool, Row as MysqlRow};
 use surreal_sync_core::Checkpoint;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{CheckpointStore, SyncManager, SyncPhase};
+use surreal_sync_core::{CheckpointStore, FieldMap, SyncManager, SyncPhase};
 use surreal_sync_core::{Row, Value};
 use surreal_sync_runtime::{
     run_source_runtime_with, ApplyOpts, Pipeline, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
@@ -130,20 +130,228 @@ pub async fn run_full_sync_cancellable_with_transforms<S: SurrealSink, CS: Check
     let tables = get_user_tables(&mut conn, &database, from_opts).await?;
     info!("Found {} tables to migrate", tables.len());
 
+    let Some(total_migrated) = migrate_tables(
+        &mut conn,
+        surreal,
+        &tables,
+        sync_opts,
+        &schema_info,
+        cancel,
+        pipeline,
+        apply_opts,
+    )
+    .await?
+    else {
+        drop(conn);
+        pool.disconnect().await?;
+        return Ok(());
+    };
+
+    if let Some(manager) = sync_manager {
+        // Capture the real master position after the dump so `incremental` resumes
+        // exactly where the snapshot ended.
+        let checkpoint = capture_binlog_checkpoint(&pool, flavor).await?;
+        manager
+            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncEnd)
+            .await?;
+        info!(
+            "Emitted full sync end checkpoint (t2): {}",
+            checkpoint.to_cli_string()
+        );
+
+        let table_names = tables.to_vec();
+        let existing = read_catch_up_progress(manager).await?;
+        let mut progress = existing.unwrap_or_else(|| CatchUpProgress::new(checkpoint.clone()));
+        progress.merge_tables(&table_names, CoverageKind::Initial, &checkpoint);
+        emit_catch_up_progress(manager, &progress).await?;
+    }
+
+    drop(conn);
+    pool.disconnect().await?;
+    info!("MySQL binlog full sync completed: {total_migrated} total records migrated");
+    Ok(())
+}
+
+/// Consistent-snapshot full sync: every table is read inside one
+/// `START TRANSACTION WITH CONSISTENT SNAPSHOT` opened while a brief
+/// `FLUSH TABLES WITH READ LOCK` pins the binlog position. FullSyncStart and
+/// FullSyncEnd are that same position, so streaming from the returned
+/// checkpoint neither misses nor replays a change.
+///
+/// The global read lock is held only until the transaction is open (the
+/// RELOAD privilege is required, or `FLUSH_TABLES` on MySQL 8.0.23+); the
+/// binlog is pinned from the captured position for the whole read. Returns
+/// `None` when cancelled, without emitting `FullSyncEnd`, so a later run
+/// re-takes the snapshot.
+pub async fn run_consistent_snapshot_full_sync_with_transforms<
+    S: SurrealSink,
+    CS: CheckpointStore,
+>(
+    surreal: &S,
+    from_opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+    sync_manager: Option<&SyncManager<CS>>,
+    cancel: &tokio_util::sync::CancellationToken,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<Option<BinlogCheckpoint>> {
+    info!("Starting MySQL binlog consistent snapshot full sync to SurrealDB");
+
+    let pool = new_mysql_pool_with_ssl(&from_opts.connection_string, &from_opts.ssl).await?;
+    let database = resolve_database(&pool, from_opts).await?;
+    let mut conn = get_pool_conn(&pool, &from_opts.connection_string).await?;
+    use_database(&mut conn, &database).await?;
+    let flavor = connect_binlog_client(from_opts).await?.flavor();
+
+    conn.query_drop("FLUSH TABLES WITH READ LOCK")
+        .await
+        .context(
+            "FLUSH TABLES WITH READ LOCK failed (the consistent snapshot needs the RELOAD \
+             privilege); use --strategy interleaved-snapshot or sequential-snapshot instead",
+        )?;
+    let opened = async {
+        let checkpoint = capture_binlog_checkpoint(&pool, flavor).await?;
+        conn.query_drop("SET SESSION TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+            .await?;
+        conn.query_drop("START TRANSACTION WITH CONSISTENT SNAPSHOT, READ ONLY")
+            .await?;
+        Ok::<_, anyhow::Error>(checkpoint)
+    }
+    .await;
+    conn.query_drop("UNLOCK TABLES").await?;
+    let checkpoint = opened?;
+    info!(
+        "Consistent snapshot opened at {}",
+        checkpoint.to_cli_string()
+    );
+
+    if let Some(manager) = sync_manager {
+        manager
+            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncStart)
+            .await?;
+    }
+
+    let schema_info = collect_schema_info(&mut conn, &database).await?;
+    let tables = get_user_tables(&mut conn, &database, from_opts).await?;
+    info!("Found {} tables to migrate", tables.len());
+
+    let Some(total_migrated) = migrate_tables(
+        &mut conn,
+        surreal,
+        &tables,
+        sync_opts,
+        &schema_info,
+        cancel,
+        pipeline,
+        apply_opts,
+    )
+    .await?
+    else {
+        drop(conn);
+        pool.disconnect().await?;
+        return Ok(None);
+    };
+    conn.query_drop("COMMIT").await?;
+
+    if let Some(manager) = sync_manager {
+        manager
+            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncEnd)
+            .await?;
+        let existing = read_catch_up_progress(manager).await?;
+        let mut progress = existing.unwrap_or_else(|| CatchUpProgress::new(checkpoint.clone()));
+        progress.merge_tables(&tables, CoverageKind::Initial, &checkpoint);
+        emit_catch_up_progress(manager, &progress).await?;
+    }
+
+    drop(conn);
+    pool.disconnect().await?;
+    info!(
+        "MySQL binlog consistent snapshot completed: {total_migrated} total records migrated; \
+         streaming starts at {}",
+        checkpoint.to_cli_string()
+    );
+    Ok(Some(checkpoint))
+}
+
+/// `snapshot_mode=initial` entry for [`run_consistent_snapshot_full_sync_with_transforms`].
+///
+/// When a previous run already recorded `FullSyncEnd` and covered every
+/// requested table, the snapshot is skipped and the latest saved position is
+/// returned, so a restart goes straight back to streaming. Otherwise the
+/// snapshot is (re-)taken. Returns the stream start, or `None` when cancelled.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_initial_consistent_snapshot_with_transforms<
+    S: SurrealSink,
+    CS: CheckpointStore,
+>(
+    surreal: &S,
+    from_opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+    sync_manager: Option<&SyncManager<CS>>,
+    cancel: &tokio_util::sync::CancellationToken,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<Option<BinlogCheckpoint>> {
+    use crate::from_binlog::catch_up::{max_binlog_checkpoint, tables_pending_snapshot};
+    use crate::from_binlog::watermark_source::BinlogWatermarkSource;
+
+    if let Some(manager) = sync_manager {
+        if let Ok(end) = manager
+            .read_checkpoint::<BinlogCheckpoint>(SyncPhase::FullSyncEnd)
+            .await
+        {
+            if let Some(progress) = read_catch_up_progress(manager).await? {
+                let requested =
+                    BinlogWatermarkSource::resolve_snapshot_table_names(from_opts).await?;
+                if tables_pending_snapshot(&requested, &progress).is_empty() {
+                    let resume = max_binlog_checkpoint(&[progress.position, end]);
+                    info!(
+                        "Consistent snapshot already completed; streaming from {}",
+                        resume.to_cli_string()
+                    );
+                    return Ok(Some(resume));
+                }
+            }
+        }
+    }
+
+    run_consistent_snapshot_full_sync_with_transforms(
+        surreal,
+        from_opts,
+        sync_opts,
+        sync_manager,
+        cancel,
+        pipeline,
+        apply_opts,
+    )
+    .await
+}
+
+/// Copy `tables` in order on one connection. Returns `None` when cancelled
+/// between tables; the caller then leaves `FullSyncEnd` unwritten.
+#[allow(clippy::too_many_arguments)]
+async fn migrate_tables<S: SurrealSink>(
+    conn: &mut mysql_async::Conn,
+    surreal: &S,
+    tables: &[String],
+    sync_opts: &SyncOpts,
+    schema_info: &HashMap<String, TableSchemaInfo>,
+    cancel: &tokio_util::sync::CancellationToken,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<Option<usize>> {
     let mut total_migrated = 0;
-    for table_name in &tables {
+    for table_name in tables {
         if cancel.is_cancelled() {
             info!(
                 "Cancellation requested during full sync; stopping before table '{table_name}'. \
                  Resume point remains the FullSyncStart position."
             );
-            drop(conn);
-            pool.disconnect().await?;
-            return Ok(());
+            return Ok(None);
         }
         info!("Migrating table: {table_name}");
         let count = migrate_table(
-            &mut conn,
+            conn,
             surreal,
             table_name,
             sync_opts,
@@ -160,35 +368,10 @@ pub async fn run_full_sync_cancellable_with_transforms<S: SurrealSink, CS: Check
                 "Cancellation requested after table '{table_name}'; stopping full sync. \
                  Resume point remains the FullSyncStart position."
             );
-            drop(conn);
-            pool.disconnect().await?;
-            return Ok(());
+            return Ok(None);
         }
     }
-
-    if let Some(manager) = sync_manager {
-        // Capture the real master position after the dump so `incremental` resumes
-        // exactly where the snapshot ended.
-        let checkpoint = capture_binlog_checkpoint(&pool, flavor).await?;
-        manager
-            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncEnd)
-            .await?;
-        info!(
-            "Emitted full sync end checkpoint (t2): {}",
-            checkpoint.to_cli_string()
-        );
-
-        let table_names = tables.to_vec();
-        let existing = read_catch_up_progress(manager).await?;
-        let mut progress = existing.unwrap_or_else(|| CatchUpProgress::new(checkpoint.clone()));
-        progress.merge_tables(&table_names, CoverageKind::Initial, &checkpoint);
-        emit_catch_up_progress(manager, &progress).await?;
-    }
-
-    drop(conn);
-    pool.disconnect().await?;
-    info!("MySQL binlog full sync completed: {total_migrated} total records migrated");
-    Ok(())
+    Ok(Some(total_migrated))
 }
 
 async fn collect_schema_info(
@@ -478,7 +661,7 @@ async fn migrate_table<S: SurrealSink>(
             for (i, row) in rows.iter().enumerate() {
                 let row_index = (self.offset + i) as u64;
                 let typed_values = row_to_typed_values_with_config(row, self.config)?;
-                let values: HashMap<String, Value> = typed_values
+                let values: FieldMap = typed_values
                     .into_iter()
                     .map(|(k, tv)| (k, tv.value))
                     .collect();
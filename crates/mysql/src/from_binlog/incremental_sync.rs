@@ -346,9 +346,33 @@ where
         Ok(())
     }
 
+    /// Apply an add-column-only `ALTER TABLE` to the cached metadata in place,
+    /// so it matches the binlog position rather than the current catalog.
+    /// `false` when `sql` is some other DDL or the cache can't take it.
+    async fn apply_column_adds(&mut self, sql: &str) -> Result<bool> {
+        let Some(adds) = crate::from_binlog::ddl::parse_column_adds(sql) else {
+            return Ok(false);
+        };
+        let mut names = match self.column_names_cache.get(&adds.table) {
+            Some(names) => names.clone(),
+            None => get_table_column_names_ordinal(&mut self.conn, &adds.table).await?,
+        };
+        if !crate::from_binlog::ddl::apply_column_adds(
+            &adds,
+            &mut self.schema,
+            &mut names,
+            &mut self.json_columns,
+        ) {
+            return Ok(false);
+        }
+        self.column_names_cache.insert(adds.table, names);
+        Ok(true)
+    }
+
     /// Convert raw binlog events into positioned changes. Table-affecting DDL
     /// refreshes schema metadata inline (same as the pre-SourceDriver loop) so
-    /// subsequent row events in the same batch see the new catalog.
+    /// subsequent row events in the same batch see the new catalog; an
+    /// `ALTER TABLE` that only adds columns is applied to the cache directly.
     async fn consume_events(
         &mut self,
         events: Vec<RawEvent>,
@@ -356,15 +380,19 @@ where
         let mut out = Vec::new();
         for event in events {
             match event.body {
-                EventBody::Query(query) => {
-                    if crate::from_binlog::ddl::is_table_affecting_ddl(&query, &self.database) {
-                        info!("Refreshing MySQL schema metadata after DDL: {}", query.sql);
-                        apply_renames_to_filter(
-                            &mut self.table_filter,
-                            &crate::from_binlog::ddl::parse_table_renames(&query.sql),
-                        );
-                        self.refresh_schema_metadata().await?;
+                EventBody::Query(query)
+                    if crate::from_binlog::ddl::is_table_affecting_ddl(&query, &self.database) =>
+                {
+                    if self.apply_column_adds(&query.sql).await? {
+                        info!("Added columns to MySQL schema metadata: {}", query.sql);
+                        continue;
                     }
+                    info!("Refreshing MySQL schema metadata after DDL: {}", query.sql);
+                    apply_renames_to_filter(
+                        &mut self.table_filter,
+                        &crate::from_binlog::ddl::parse_table_renames(&query.sql),
+                    );
+                    self.refresh_schema_metadata().await?;
                 }
                 EventBody::TableMap(tm) => {
                     self.table_maps.insert(tm.table_id, tm);
@@ -449,6 +477,10 @@ where
 {
     type Position = BinlogPosition;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        Some(format!("{position}"))
+    }
+
     async fn poll_work(&mut self) -> Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() {
             return Ok(Vec::new());
@@ -2,11 +2,11 @@
 //!
 //! # Embed surface
 //!
-//! Only [`run`], [`FlattenId`], [`InPlaceTransform`], and [`Value`] are the
-//! supported embed API:
+//! Only [`run`], [`FlattenId`], [`InPlaceTransform`], [`FieldMap`] and [`Value`]
+//! are the supported embed API:
 //!
 //! ```ignore
-//! use surreal_sync_mysql::from_binlog::{run, FlattenId, InPlaceTransform, Value};
+//! use surreal_sync_mysql::from_binlog::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 //! use surreal_sync_surreal::Surreal3Sink;
 //!
 //! run::<Surreal3Sink>([Box::new(FlattenId::default()) as Box<dyn InPlaceTransform>]).await?;
@@ -40,8 +40,9 @@ pub use checkpoint::{get_current_checkpoint, BinlogCheckpoint, BinlogReconciliat
 pub use client::{connect_binlog_client, new_mysql_pool_with_ssl, parse_mysql_uri};
 pub use flavor::Flavor;
 pub use full_sync::{
-    capture_head_checkpoint, run_full_sync, run_full_sync_cancellable,
-    run_full_sync_cancellable_with_transforms,
+    capture_head_checkpoint, run_consistent_snapshot_full_sync_with_transforms, run_full_sync,
+    run_full_sync_cancellable, run_full_sync_cancellable_with_transforms,
+    run_initial_consistent_snapshot_with_transforms,
 };
 pub use incremental_sync::{
     run_replication_tail, run_replication_tail_with_checkpoints,
@@ -75,7 +76,7 @@ pub struct SyncOpts {
 }
 
 /// Public embed surface — only these four items are the supported embed API.
-pub use embed::{run, FlattenId, InPlaceTransform, Value};
+pub use embed::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 
 /// Stock CLI argv helpers (`run_sync`, clap args). Not part of the embed API.
 #[doc(hidden)]
@@ -348,6 +348,27 @@ impl BinlogWatermarkSource {
         }
     }
 
+    /// Apply an add-column-only `ALTER TABLE` on a tracked table to the cached
+    /// metadata in place; `false` when a full refresh is needed instead.
+    fn apply_column_adds(&mut self, sql: &str) -> bool {
+        let Some(adds) = crate::from_binlog::ddl::parse_column_adds(sql) else {
+            return false;
+        };
+        let Some(names) = self.column_names_by_table.get_mut(&adds.table) else {
+            return false;
+        };
+        if !crate::from_binlog::ddl::apply_column_adds(
+            &adds,
+            &mut self.schema,
+            names,
+            &mut self.json_columns,
+        ) {
+            return false;
+        }
+        self.conversion_by_table = conversions_from_schema(&self.schema, &self.json_columns);
+        true
+    }
+
     async fn refresh_schema_metadata(&mut self) -> Result<()> {
         let mut conn = self.pool.get_conn().await?;
         use_database(&mut conn, &self.database).await?;
@@ -451,17 +472,24 @@ impl WatermarkSource for BinlogWatermarkSource {
         let mut out = Vec::new();
         for event in events {
             match event.body {
-                EventBody::Query(query) => {
-                    if crate::from_binlog::ddl::is_table_affecting_ddl(&query, &self.database) {
+                EventBody::Query(query)
+                    if crate::from_binlog::ddl::is_table_affecting_ddl(&query, &self.database) =>
+                {
+                    if self.apply_column_adds(&query.sql) {
                         info!(
-                            "Refreshing MySQL binlog watermark metadata after DDL: {}",
+                            "Added columns to MySQL binlog watermark metadata: {}",
                             query.sql
                         );
-                        self.apply_table_renames(&crate::from_binlog::ddl::parse_table_renames(
-                            &query.sql,
-                        ));
-                        self.refresh_schema_metadata().await?;
+                        continue;
                     }
+                    info!(
+                        "Refreshing MySQL binlog watermark metadata after DDL: {}",
+                        query.sql
+                    );
+                    self.apply_table_renames(&crate::from_binlog::ddl::parse_table_renames(
+                        &query.sql,
+                    ));
+                    self.refresh_schema_metadata().await?;
                 }
                 EventBody::TableMap(tm) => {
                     self.table_maps.insert(tm.table_id, tm);
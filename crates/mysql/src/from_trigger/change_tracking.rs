@@ -133,6 +133,36 @@ fn build_row_id_expr(row_alias: &str, pk_columns: &[String]) -> String {
     }
 }
 
+/// Rebuild a `row_id` in the format [`build_row_id_expr`] records from an
+/// audit row's JSON data.
+///
+/// Triggers always record the physical primary key; tables whose record ID
+/// comes from `id_column_overrides` read their key columns from the row data
+/// instead. A missing or `NULL` key column is an error.
+pub(crate) fn row_id_from_data(
+    table_name: &str,
+    id_columns: &[String],
+    data: &serde_json::Map<String, serde_json::Value>,
+) -> Result<String> {
+    let mut parts = Vec::with_capacity(id_columns.len());
+    for column in id_columns {
+        match data.get(column) {
+            None | Some(serde_json::Value::Null) => {
+                return Err(anyhow!(
+                    "id column '{column}' of table '{table_name}' is NULL; \
+                     record ID columns must be NOT NULL"
+                ))
+            }
+            Some(value) => parts.push(value.clone()),
+        }
+    }
+    Ok(match parts.as_slice() {
+        [serde_json::Value::String(s)] => s.clone(),
+        [single] => single.to_string(),
+        _ => serde_json::Value::Array(parts).to_string(),
+    })
+}
+
 /// Create INSERT, UPDATE, DELETE triggers for a specific table.
 ///
 /// `pk_columns` are the table's actual primary key column(s); they determine
@@ -207,3 +237,32 @@ pub async fn create_triggers_for_table(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn data(value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_row_id_from_data_matches_trigger_format() {
+        let row = data(json!({"id": 7, "email": "a@example.com", "tenant": 3}));
+        let email = ["email".to_string()];
+        assert_eq!(
+            row_id_from_data("users", &email, &row).unwrap(),
+            "a@example.com"
+        );
+        let composite = ["tenant".to_string(), "email".to_string()];
+        assert_eq!(
+            row_id_from_data("users", &composite, &row).unwrap(),
+            r#"[3,"a@example.com"]"#
+        );
+
+        let null = data(json!({"id": 8, "email": null}));
+        let err = row_id_from_data("users", &email, &null).unwrap_err();
+        assert!(err.to_string().contains("'email' of table 'users' is NULL"));
+    }
+}
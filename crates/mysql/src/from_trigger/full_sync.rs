@@ -4,7 +4,10 @@
 //! MySQL MysqlRow → TypedValue (surreal-sync-mysql) → Row (sync-core) → SurrealDB (surreal sink)
 
 use crate::from_trigger::{SourceOpts, SyncOpts};
-use crate::{get_primary_key_columns, read_table_chunk};
+use crate::{
+    get_id_columns, get_keyset_columns, get_primary_key_columns, get_read_columns,
+    read_table_chunk_by_key, ReadColumns,
+};
 use crate::{row_to_typed_values_with_config, RowConversionConfig};
 use anyhow::Result;
 use async_trait::async_trait;
@@ -12,8 +15,8 @@ use mysql_async::{prelude::*, Row as MysqlRow};
 use std::collections::HashMap;
 use std::sync::Arc;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Checkpoint, CheckpointStore, SyncManager, SyncPhase};
-use surreal_sync_core::{Row, Value};
+use surreal_sync_core::{Checkpoint, CheckpointStore, FieldMap, SyncManager, SyncPhase};
+use surreal_sync_core::{KeysetColumn, Row, Value};
 use surreal_sync_runtime::{
     run_source_runtime_with, ApplyOpts, Pipeline, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
 };
@@ -131,6 +134,13 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     let tables = get_user_tables(&mut conn, &database_name).await?;
 
     info!("Found {} tables to migrate", tables.len());
+    if let Some(table) = from_opts
+        .id_column_overrides
+        .keys()
+        .find(|table| !tables.contains(table))
+    {
+        anyhow::bail!("id-columns override for unknown table '{table}'");
+    }
 
     let mut total_migrated = 0;
 
@@ -147,6 +157,9 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
             &MigrateTableOpts {
                 sync_opts,
                 json_path_overrides: &boolean_paths,
+                id_columns: &from_opts.id_column_overrides,
+                keyset_columns: &from_opts.keyset_columns,
+                column_filters: &from_opts.column_filters,
                 pipeline,
                 apply_opts,
             },
@@ -292,6 +305,12 @@ struct MigrateTableOpts<'a> {
     /// Reserved for JSON path overrides (currently unused by trigger full sync).
     #[allow(dead_code)]
     json_path_overrides: &'a [String],
+    /// Per-table record-ID columns overriding the primary key.
+    id_columns: &'a surreal_sync_core::IdColumnOverrides,
+    /// Per-table keyset sort columns overriding the primary key.
+    keyset_columns: &'a surreal_sync_core::KeysetColumns,
+    /// Per-table column include/exclude lists pushed into keyset reads.
+    column_filters: &'a surreal_sync_core::ColumnFilters,
     pipeline: &'a Pipeline,
     apply_opts: &'a ApplyOpts,
 }
@@ -304,9 +323,12 @@ async fn migrate_table<S: SurrealSink>(
     schema_info: Option<&TableSchemaInfo>,
     opts: &MigrateTableOpts<'_>,
 ) -> Result<usize> {
-    // Get primary key columns for this table
+    // Get primary key columns for this table (or the configured id columns)
     let database = get_current_database(conn).await?;
-    let pk_columns = get_primary_key_columns(conn, &database, table_name).await?;
+    let pk_columns = match opts.id_columns.get(table_name) {
+        Some(columns) => get_id_columns(conn, &database, table_name, columns).await?,
+        None => get_primary_key_columns(conn, &database, table_name).await?,
+    };
 
     debug!("Table {} primary key columns: {:?}", table_name, pk_columns);
 
@@ -334,18 +356,33 @@ async fn migrate_table<S: SurrealSink>(
 
     let batch_size = opts.sync_opts.batch_size.max(1);
 
-    if !pk_columns.is_empty() {
+    // A configured sort key takes precedence over the primary key, and lets
+    // tables without a primary key avoid LIMIT/OFFSET scans.
+    let key = match opts.keyset_columns.get(table_name) {
+        Some(columns) => get_keyset_columns(conn, &database, table_name, columns).await?,
+        None => pk_columns.iter().map(KeysetColumn::new).collect(),
+    };
+
+    if !key.is_empty() {
+        let mut read_key = pk_columns.clone();
+        read_key.extend(key.iter().map(|c| c.name.clone()));
+        let columns =
+            get_read_columns(conn, &database, table_name, opts.column_filters, &read_key).await?;
+
         if opts.sync_opts.dry_run {
             let mut total_processed = 0usize;
             let mut after: Option<Vec<Value>> = None;
             loop {
-                let chunk = read_table_chunk(
+                let chunk = read_table_chunk_by_key(
                     conn,
                     table_name,
                     &pk_columns,
+                    &key,
                     after.as_deref(),
                     batch_size,
+                    total_processed as u64,
                     &config,
+                    columns.as_ref(),
                 )
                 .await?;
                 if chunk.rows.is_empty() {
@@ -366,9 +403,12 @@ async fn migrate_table<S: SurrealSink>(
             conn: &'a mut mysql_async::Conn,
             table_name: &'a str,
             pk_columns: &'a [String],
+            key: &'a [KeysetColumn],
             after: Option<Vec<Value>>,
             batch_size: usize,
+            base: u64,
             config: &'a RowConversionConfig,
+            columns: Option<&'a ReadColumns>,
             exhausted: bool,
         }
 
@@ -378,13 +418,16 @@ async fn migrate_table<S: SurrealSink>(
                 if self.exhausted {
                     return Ok(None);
                 }
-                let chunk = read_table_chunk(
+                let chunk = read_table_chunk_by_key(
                     self.conn,
                     self.table_name,
                     self.pk_columns,
+                    self.key,
                     self.after.as_deref(),
                     self.batch_size,
+                    self.base,
                     self.config,
+                    self.columns,
                 )
                 .await?;
                 if chunk.rows.is_empty() {
@@ -392,6 +435,7 @@ async fn migrate_table<S: SurrealSink>(
                     return Ok(None);
                 }
                 let n = chunk.rows.len();
+                self.base += n as u64;
                 self.after = chunk.last_pk;
                 if n < self.batch_size {
                     self.exhausted = true;
@@ -404,9 +448,12 @@ async fn migrate_table<S: SurrealSink>(
             conn,
             table_name,
             pk_columns: &pk_columns,
+            key: &key,
             after: None,
             batch_size,
+            base: 0,
             config: &config,
+            columns: columns.as_ref(),
             exhausted: false,
         };
         let mut driver = RowChunkDriver::new(chunks);
@@ -480,7 +527,7 @@ async fn migrate_table<S: SurrealSink>(
             for (i, row) in rows.iter().enumerate() {
                 let row_index = (self.offset + i) as u64;
                 let typed_values = row_to_typed_values_with_config(row, self.config)?;
-                let values: HashMap<String, Value> = typed_values
+                let values: FieldMap = typed_values
                     .into_iter()
                     .map(|(k, tv)| (k, tv.value))
                     .collect();
@@ -22,11 +22,27 @@ use tracing::{debug, info, warn};
 pub struct ReplicationTailOptions {
     pub deadline: DateTime<Utc>,
     pub until: Option<MySQLCheckpoint>,
+    /// Delete audit rows once SurrealDB has applied them (see
+    /// [`MySQLIncrementalSource::cleanup_applied_changes`]).
+    ///
+    /// [`MySQLIncrementalSource::cleanup_applied_changes`]:
+    /// super::source::MySQLIncrementalSource::cleanup_applied_changes
+    pub auto_cleanup: bool,
 }
 
 impl ReplicationTailOptions {
     pub fn stream(deadline: DateTime<Utc>, until: Option<MySQLCheckpoint>) -> Self {
-        Self { deadline, until }
+        Self {
+            deadline,
+            until,
+            auto_cleanup: false,
+        }
+    }
+
+    /// Builder: delete applied audit rows after each batch.
+    pub fn with_auto_cleanup(mut self, auto_cleanup: bool) -> Self {
+        self.auto_cleanup = auto_cleanup;
+        self
     }
 }
 
@@ -80,6 +96,7 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
 
     let mut driver = MysqlTriggerSourceDriver {
         stream,
+        cleanup_source: options.auto_cleanup.then_some(&source),
         options: &options,
         until_reached: false,
         finished: false,
@@ -123,6 +140,8 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
 
 struct MysqlTriggerSourceDriver<'a> {
     stream: Box<dyn super::source::ChangeStream>,
+    /// Set when applied audit rows are deleted as the watermark advances
+    cleanup_source: Option<&'a super::source::MySQLIncrementalSource>,
     options: &'a ReplicationTailOptions,
     until_reached: bool,
     finished: bool,
@@ -133,6 +152,10 @@ struct MysqlTriggerSourceDriver<'a> {
 impl SourceDriver for MysqlTriggerSourceDriver<'_> {
     type Position = i64;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        Some(format!("sequence_id {position}"))
+    }
+
     async fn poll_work(&mut self) -> Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() || self.finished {
             return Ok(Vec::new());
@@ -167,6 +190,18 @@ impl SourceDriver for MysqlTriggerSourceDriver<'_> {
 
     async fn advance_watermark(&mut self, position: Self::Position) -> Result<()> {
         self.stream.commit_sunk(position);
+        // The runtime only advances past changes the sink has written, and
+        // this source keeps no separate checkpoint to commit first: SurrealDB
+        // is the durable record of everything up to `position`.
+        if let Some(source) = self.cleanup_source {
+            let deleted = source
+                .cleanup_applied_changes(&MySQLCheckpoint {
+                    sequence_id: position,
+                    timestamp: Utc::now(),
+                })
+                .await?;
+            debug!("Deleted {deleted} applied audit rows up to sequence_id {position}");
+        }
         Ok(())
     }
 
@@ -20,7 +20,7 @@ use std::sync::Mutex;
 use anyhow::{anyhow, Result};
 use mysql_async::{prelude::*, Pool, Row as MysqlRow, Value as MysqlValue};
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, Row, Type, Value};
+use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, FieldMap, Row, Type, Value};
 use surreal_sync_json::types::{convert_id_to_value, JsonValueWithSchema};
 use surreal_sync_runtime::{
     run_interleaved_snapshot_with_transforms, InterleavedSnapshotConfig, InterleavedSnapshotResult,
@@ -29,7 +29,7 @@ use surreal_sync_runtime::{
 };
 use uuid::Uuid;
 
-use crate::from_trigger::change_tracking::setup_mysql_change_tracking;
+use crate::from_trigger::change_tracking::{row_id_from_data, setup_mysql_change_tracking};
 use crate::from_trigger::checkpoint::get_current_checkpoint;
 use crate::from_trigger::schema::collect_mysql_database_schema;
 use crate::RowConversionConfig;
@@ -88,6 +88,9 @@ pub struct MySqlWatermarkSource {
     /// was not part of the initial snapshot set.
     pk_by_table: Mutex<HashMap<String, Vec<String>>>,
     conversion_by_table: HashMap<String, TableConversion>,
+    /// Tables whose record ID is built from the audit row data rather than
+    /// `row_id` (which always holds the physical primary key).
+    id_column_overrides: surreal_sync_core::IdColumnOverrides,
     /// Highest audit `sequence_id` delivered through [`Self::next_reconciliation_events`]
     /// so far. Reads are resumed strictly after this, and it is the position the
     /// loop checkpoints / frees up to.
@@ -170,6 +173,7 @@ impl MySqlWatermarkSource {
             tables,
             pk_by_table: Mutex::new(pk_by_table),
             conversion_by_table,
+            id_column_overrides,
             last_sequence_id: AtomicI64::new(starting),
             watermarks: Mutex::new(WatermarkIds::default()),
         })
@@ -244,9 +248,9 @@ impl MySqlWatermarkSource {
         table: &str,
         pk_columns: &[String],
         json: serde_json::Map<String, serde_json::Value>,
-    ) -> HashMap<String, Value> {
+    ) -> FieldMap {
         let table_def = self.schema.get_table(table);
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         for (key, value) in json {
             if pk_columns.iter().any(|c| c == &key) {
                 continue;
@@ -346,7 +350,7 @@ impl WatermarkSource for MySqlWatermarkSource {
         let rows: Vec<MysqlRow> = conn
             .exec(
                 format!(
-                    "SELECT sequence_id, table_name, operation, row_id, new_data \
+                    "SELECT sequence_id, table_name, operation, row_id, new_data, old_data \
                      FROM {AUDIT_TABLE} WHERE sequence_id > ? ORDER BY sequence_id LIMIT 500"
                 ),
                 (last,),
@@ -365,8 +369,9 @@ impl WatermarkSource for MySqlWatermarkSource {
             let sequence_id: i64 = row.get(0).ok_or_else(|| anyhow!("missing sequence_id"))?;
             let table_name: String = row.get(1).ok_or_else(|| anyhow!("missing table_name"))?;
             let operation: String = row.get(2).ok_or_else(|| anyhow!("missing operation"))?;
-            let row_id: String = row.get(3).ok_or_else(|| anyhow!("missing row_id"))?;
+            let mut row_id: String = row.get(3).ok_or_else(|| anyhow!("missing row_id"))?;
             let new_data: Option<MysqlValue> = row.get(4);
+            let old_data: Option<MysqlValue> = row.get(5);
 
             if sequence_id > max_seq {
                 max_seq = sequence_id;
@@ -399,8 +404,6 @@ impl WatermarkSource for MySqlWatermarkSource {
                     .clone()
             };
 
-            let (pk, id) = self.pk_and_id(&table_name, &pk_columns, &row_id)?;
-
             let (op, data) = match operation.as_str() {
                 "INSERT" => (ChangeOp::Create, parse_new_data(new_data)?),
                 "UPDATE" => (ChangeOp::Update, parse_new_data(new_data)?),
@@ -408,6 +411,33 @@ impl WatermarkSource for MySqlWatermarkSource {
                 other => return Err(anyhow!("unknown audit operation '{other}'")),
             };
 
+            if self.id_column_overrides.contains_key(&table_name) {
+                let old_row_id = parse_new_data(old_data)?
+                    .map(|old| row_id_from_data(&table_name, &pk_columns, &old))
+                    .transpose()?;
+                let current = match &data {
+                    Some(new) => Some(row_id_from_data(&table_name, &pk_columns, new)?),
+                    None => old_row_id.clone(),
+                };
+                row_id = current.ok_or_else(|| {
+                    anyhow!("missing row data to build the record ID for table '{table_name}'")
+                })?;
+                // An update that changes the id columns moves the record.
+                if let (ChangeOp::Update, Some(old_row_id)) = (op, old_row_id) {
+                    if old_row_id != row_id {
+                        let (pk, id) = self.pk_and_id(&table_name, &pk_columns, &old_row_id)?;
+                        events.push(ReconciliationEvent {
+                            position: sequence_id,
+                            table: table_name.clone(),
+                            pk,
+                            change: Change::new(ChangeOp::Delete, table_name.clone(), id, None),
+                        });
+                    }
+                }
+            }
+
+            let (pk, id) = self.pk_and_id(&table_name, &pk_columns, &row_id)?;
+
             let data = data.map(|obj| self.fields_from_new_data(&table_name, &pk_columns, obj));
 
             let change = Change::new(op, table_name.clone(), id, data);
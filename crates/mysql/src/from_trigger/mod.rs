@@ -1,7 +1,8 @@
 //! MySQL trigger-based sync for surreal-sync
 //!
 //! Uses audit table + triggers for change capture. Works with MySQL 5.6+.
-//! Enable feature `from_binlog` for the binlog CDC origin.
+//! Enable feature `from_binlog` for the binlog CDC origin, which reads row
+//! events instead and avoids the audit-table write amplification.
 
 mod change_tracking;
 pub mod checkpoint;
@@ -48,6 +49,12 @@ pub struct SourceOpts {
     pub mysql_boolean_paths: Option<Vec<String>>,
     /// Optional per-table primary-key overrides (`table → ordered columns`).
     pub id_column_overrides: surreal_sync_core::IdColumnOverrides,
+    /// Per-table keyset sort columns for chunked full-sync reads, e.g. a
+    /// composite `(tenant_id, created_at)` key (`table → ordered columns`).
+    pub keyset_columns: surreal_sync_core::KeysetColumns,
+    /// Per-table column include/exclude lists. Keyset reads select only the
+    /// kept columns; key columns are always read for ids and cursors.
+    pub column_filters: surreal_sync_core::ColumnFilters,
     /// TLS mode for the SQL connection pool
     pub ssl: SslMode,
 }
@@ -19,7 +19,8 @@ pub async fn collect_mysql_database_schema(
 
     // First, collect all columns with their types
     let columns_query = "
-        SELECT TABLE_NAME, COLUMN_NAME, DATA_TYPE, COLUMN_TYPE, NUMERIC_PRECISION, NUMERIC_SCALE
+        SELECT TABLE_NAME, COLUMN_NAME, DATA_TYPE, COLUMN_TYPE, NUMERIC_PRECISION, NUMERIC_SCALE,
+            IS_NULLABLE
         FROM INFORMATION_SCHEMA.COLUMNS
         WHERE TABLE_SCHEMA = DATABASE()
         ORDER BY TABLE_NAME, ORDINAL_POSITION";
@@ -61,7 +62,7 @@ pub async fn collect_mysql_database_schema(
     }
 
     // Build tables with columns
-    let mut table_columns: HashMap<String, Vec<(String, Type)>> = HashMap::new();
+    let mut table_columns: HashMap<String, Vec<ColumnDefinition>> = HashMap::new();
 
     for row in column_rows {
         let table_name: String = row
@@ -78,6 +79,7 @@ pub async fn collect_mysql_database_schema(
             .ok_or_else(|| anyhow::anyhow!("Missing column type"))?;
         let precision: Option<u32> = row.get::<Option<u32>, _>(4).unwrap_or(None);
         let scale: Option<u32> = row.get::<Option<u32>, _>(5).unwrap_or(None);
+        let is_nullable: String = row.get(6).unwrap_or_default();
 
         let is_json = json_columns
             .get(&table_name)
@@ -91,7 +93,11 @@ pub async fn collect_mysql_database_schema(
         table_columns
             .entry(table_name)
             .or_default()
-            .push((column_name, universal_type));
+            .push(if is_nullable == "YES" {
+                ColumnDefinition::nullable(column_name, universal_type)
+            } else {
+                ColumnDefinition::new(column_name, universal_type)
+            });
     }
 
     // Build TableDefinition for each table
@@ -109,11 +115,11 @@ pub async fn collect_mysql_database_schema(
         let mut primary_key: Option<ColumnDefinition> = None;
         let mut other_columns = Vec::new();
 
-        for (col_name, col_type) in columns {
-            if col_name == pk_col_name {
-                primary_key = Some(ColumnDefinition::new(col_name, col_type));
+        for column in columns {
+            if column.name == pk_col_name {
+                primary_key = Some(column);
             } else {
-                other_columns.push(ColumnDefinition::new(col_name, col_type));
+                other_columns.push(column);
             }
         }
 
@@ -19,15 +19,15 @@
 //! Changes are captured by creating audit tables that track INSERT, UPDATE, and DELETE operations.
 //! Database triggers automatically populate these tables when data changes occur.
 
-use std::collections::HashMap;
-
+use super::change_tracking::row_id_from_data;
 use super::checkpoint::MySQLCheckpoint;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use chrono::Utc;
+use indexmap::IndexMap;
 use log::info;
 use mysql_async::{prelude::*, Conn, Pool, Row as MysqlRow, Value as MysqlValue};
-use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, Type, TypedValue, Value};
+use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, FieldMap, Type, TypedValue, Value};
 use surreal_sync_json::types::{
     convert_id_to_value, convert_id_with_database_schema, JsonValueWithSchema,
 };
@@ -120,6 +120,21 @@ impl MySQLIncrementalSource {
             id_column_overrides,
         }
     }
+
+    /// Delete audit rows up to and including `up_to_checkpoint`.
+    ///
+    /// Deleted changes cannot be replayed, so only pass a checkpoint whose
+    /// changes SurrealDB has already applied. Returns the number of rows
+    /// deleted.
+    pub async fn cleanup_applied_changes(&self, up_to_checkpoint: &MySQLCheckpoint) -> Result<u64> {
+        let mut conn = self.pool.get_conn().await?;
+        conn.exec_drop(
+            "DELETE FROM surreal_sync_changes WHERE sequence_id <= ?",
+            (up_to_checkpoint.sequence_id,),
+        )
+        .await?;
+        Ok(conn.affected_rows())
+    }
 }
 
 #[async_trait]
@@ -152,6 +167,7 @@ impl IncrementalSource for MySQLIncrementalSource {
             self.server_id,
             starting_sequence_id,
             self.database_schema.clone(),
+            self.id_column_overrides.clone(),
         )
         .await?;
 
@@ -185,6 +201,9 @@ pub struct MySQLChangeStream {
     /// Highest sequence_id successfully sunk (authoritative resume watermark).
     sunk_sequence_id: i64,
     database_schema: Option<DatabaseSchema>,
+    /// Tables whose record ID is built from the row data rather than the
+    /// audit `row_id` (which always holds the physical primary key).
+    id_column_overrides: surreal_sync_core::IdColumnOverrides,
 }
 
 impl MySQLChangeStream {
@@ -193,6 +212,7 @@ impl MySQLChangeStream {
         server_id: u32,
         starting_sequence_id: i64,
         database_schema: Option<DatabaseSchema>,
+        id_column_overrides: surreal_sync_core::IdColumnOverrides,
     ) -> Result<Self> {
         let connection = pool.get_conn().await?;
 
@@ -204,6 +224,7 @@ impl MySQLChangeStream {
             read_sequence_id: starting_sequence_id,
             sunk_sequence_id: starting_sequence_id,
             database_schema,
+            id_column_overrides,
         })
     }
 
@@ -241,15 +262,15 @@ impl MySQLChangeStream {
         Ok(jvs.to_typed_value())
     }
 
-    /// Convert JSON object to HashMap of TypedValue
+    /// Convert JSON object to IndexMap of TypedValue
     fn json_object_to_typed_values(
         &self,
         obj: serde_json::Map<String, serde_json::Value>,
         table_name: &str,
         pk_columns: &[String],
         row_id: &str,
-    ) -> Result<HashMap<String, TypedValue>> {
-        let mut result = HashMap::new();
+    ) -> Result<IndexMap<String, TypedValue>> {
+        let mut result = IndexMap::new();
 
         for (key, val) in obj {
             // Skip primary-key columns — they form the SurrealDB record ID.
@@ -375,8 +396,8 @@ impl MySQLChangeStream {
             let sequence_id: i64 = row.get(0).ok_or_else(|| anyhow!("Missing sequence_id"))?;
             let table_name: String = row.get(1).ok_or_else(|| anyhow!("Missing table_name"))?;
             let operation: String = row.get(2).ok_or_else(|| anyhow!("Missing operation"))?;
-            let row_id: String = row.get(3).ok_or_else(|| anyhow!("Missing row_id"))?;
-            let _old_data: Option<MysqlValue> = row.get(4);
+            let mut row_id: String = row.get(3).ok_or_else(|| anyhow!("Missing row_id"))?;
+            let old_data: Option<MysqlValue> = row.get(4);
             let new_data: Option<MysqlValue> = row.get(5);
 
             let op = match operation.as_str() {
@@ -390,7 +411,36 @@ impl MySQLChangeStream {
 
             // Convert JSON data to Value map using TypedValue conversion flow
             let pk_columns = self.pk_columns_for(&table_name);
-            let universal_data: Option<HashMap<String, Value>> = match operation.as_str() {
+
+            if self.id_column_overrides.contains_key(&table_name) {
+                let old_row_id = audit_json_object(&old_data)
+                    .map(|data| row_id_from_data(&table_name, &pk_columns, &data))
+                    .transpose()?;
+                let current = if op == ChangeOp::Delete {
+                    old_row_id.clone()
+                } else {
+                    audit_json_object(&new_data)
+                        .map(|data| row_id_from_data(&table_name, &pk_columns, &data))
+                        .transpose()?
+                };
+                row_id = current.ok_or_else(|| {
+                    anyhow!("Missing row data to build the record ID for table '{table_name}'")
+                })?;
+                // An update that changes the id columns moves the record.
+                // The delete takes the previous sequence_id so a restart after
+                // sinking it still re-reads this update.
+                if let (ChangeOp::Update, Some(old_row_id)) = (op, old_row_id) {
+                    if old_row_id != row_id {
+                        let old_id =
+                            self.row_id_to_universal(&old_row_id, &table_name, &pk_columns)?;
+                        changes.push((
+                            sequence_id - 1,
+                            Change::new(ChangeOp::Delete, table_name.clone(), old_id, None),
+                        ));
+                    }
+                }
+            }
+            let universal_data: Option<FieldMap> = match operation.as_str() {
                 "INSERT" | "UPDATE" => {
                     if let Some(MysqlValue::Bytes(json_data)) = new_data {
                         if let Ok(json_value) =
@@ -411,7 +461,7 @@ impl MySQLChangeStream {
 
                             match json_value {
                                 serde_json::Value::Object(map) => {
-                                    // Step 1: JSON → HashMap<String, TypedValue>
+                                    // Step 1: JSON → IndexMap<String, TypedValue>
                                     let typed_values = self.json_object_to_typed_values(
                                         map,
                                         &table_name,
@@ -419,8 +469,8 @@ impl MySQLChangeStream {
                                         &row_id,
                                     )?;
 
-                                    // Step 2: HashMap<String, TypedValue> → HashMap<String, Value>
-                                    let universal_map: HashMap<String, Value> = typed_values
+                                    // Step 2: IndexMap<String, TypedValue> → FieldMap
+                                    let universal_map: FieldMap = typed_values
                                         .into_iter()
                                         .map(|(k, tv)| (k, tv.value))
                                         .collect();
@@ -461,6 +511,19 @@ impl MySQLChangeStream {
     }
 }
 
+/// Parse an audit `old_data` / `new_data` column into its JSON object.
+fn audit_json_object(
+    value: &Option<MysqlValue>,
+) -> Option<serde_json::Map<String, serde_json::Value>> {
+    match value {
+        Some(MysqlValue::Bytes(bytes)) => match serde_json::from_slice(bytes) {
+            Ok(serde_json::Value::Object(map)) => Some(map),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[async_trait]
 impl ChangeStream for MySQLChangeStream {
     async fn next(&mut self) -> Option<Result<Change>> {
@@ -21,7 +21,9 @@
 //! - `reverse`: Convert MySQL values → `TypedValue` (for reading data)
 //! - `ddl`: Generate MySQL DDL from `Type`
 //! - `schema`: MySQL column type to Type conversion
-//! - `chunk`: Primary-key keyset pagination reads
+//! - `chunk`: Primary-key and composite sort-key keyset pagination reads
+//! - `introspect`: List syncable tables with approximate row counts and
+//!   estimate full-sync size
 //! - `json_columns`: Detect JSON columns on MySQL and MariaDB
 //! - `ssl`: Shared TLS mode types and `mysql_async` pool helpers
 //! - `binlog_protocol` (feature): MySQL/MariaDB ROW-format binlog replication protocol
@@ -34,7 +36,7 @@
 //! origin module (not the crate root):
 //!
 //! ```rust,ignore
-//! use surreal_sync_mysql::from_binlog::{run, FlattenId, InPlaceTransform, Value};
+//! use surreal_sync_mysql::from_binlog::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 //! use surreal_sync_surreal::Surreal3Sink;
 //!
 //! run::<Surreal3Sink>([/* transforms */]).await?;
@@ -66,6 +68,7 @@ pub mod binlog_protocol;
 pub mod chunk;
 pub mod ddl;
 pub mod forward;
+pub mod introspect;
 pub mod json_columns;
 pub mod reverse;
 pub mod schema;
@@ -81,9 +84,13 @@ pub mod from_trigger;
 pub use binlog::{
     apply_mysql_json_diffs_to_cell, binlog_cell_to_universal_value, BinlogColumnMeta,
 };
-pub use chunk::{get_primary_key_columns, read_table_chunk, TableChunk};
+pub use chunk::{
+    get_id_columns, get_keyset_columns, get_primary_key_columns, get_read_columns,
+    read_table_chunk, read_table_chunk_by_key, ReadColumns, TableChunk,
+};
 pub use ddl::{MySQLDdl, ToDdl};
 pub use forward::MySQLValue;
+pub use introspect::{estimate_tables, list_tables};
 pub use json_columns::{get_json_columns, json_object_value_expr};
 pub use reverse::{
     json_to_generated_value_with_config, json_to_typed_value_with_config, row_to_typed_values,
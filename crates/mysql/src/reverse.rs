@@ -20,6 +20,7 @@
 //! schema-aware conversion functions that accept `TableSchema` information.
 
 use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+use indexmap::IndexMap;
 use mysql_async::consts::{ColumnFlags, ColumnType};
 use mysql_async::Value as MysqlAsyncValue;
 use surreal_sync_core::{Type, TypedValue, Value};
@@ -68,6 +69,8 @@ pub enum ConversionError {
     InvalidDateTime(String),
     #[error("Invalid UUID: {0}")]
     InvalidUuid(String),
+    #[error("Invalid decimal: {0}")]
+    InvalidDecimal(String),
     #[error("Invalid JSON number: cannot represent {value} as i64 or f64")]
     InvalidJsonNumber { value: String },
     #[error("Invalid JSON: {0}")]
@@ -124,6 +127,22 @@ impl MySQLValueWithSchema {
         self.boolean_hint || self.column_length == Some(1)
     }
 
+    /// Check if the column carries MySQL's `UNSIGNED` attribute.
+    fn is_unsigned(&self) -> bool {
+        self.column_flags.contains(ColumnFlags::UNSIGNED_FLAG)
+    }
+
+    /// Check if this `BIT` column should be treated as boolean.
+    ///
+    /// `BIT(1)` is boolean. When the width is unknown, a single byte holding
+    /// 0 or 1 is treated as boolean, which matches what `BIT(1)` returns.
+    fn is_boolean_bit(&self, bytes: &[u8]) -> bool {
+        match self.column_length {
+            Some(bits) => bits == 1,
+            None => bytes.len() == 1 && bytes[0] <= 1,
+        }
+    }
+
     /// Convert to TypedValue.
     pub fn to_typed_value(self) -> Result<TypedValue, ConversionError> {
         TypedValue::try_from(self)
@@ -158,6 +177,11 @@ impl TryFrom<MySQLValueWithSchema> for TypedValue {
                     // Values other than 0/1 stay as integer even for boolean columns
                 }
 
+                // TINYINT UNSIGNED goes up to 255, which does not fit in i8
+                if mv.is_unsigned() {
+                    return Ok(TypedValue::int16(extract_uint(&mv.value)? as i16));
+                }
+
                 Ok(TypedValue::int8(
                     i as i8,
                     mv.column_length.unwrap_or(4) as u8,
@@ -165,16 +189,30 @@ impl TryFrom<MySQLValueWithSchema> for TypedValue {
             }
 
             MYSQL_TYPE_SHORT => {
+                if mv.is_unsigned() {
+                    return Ok(TypedValue::int32(extract_uint(&mv.value)? as i32));
+                }
                 let i = extract_int(&mv.value)?;
                 Ok(TypedValue::int16(i as i16))
             }
 
-            MYSQL_TYPE_INT24 | MYSQL_TYPE_LONG => {
+            MYSQL_TYPE_INT24 => {
+                let i = extract_int(&mv.value)?;
+                Ok(TypedValue::int32(i as i32))
+            }
+
+            MYSQL_TYPE_LONG => {
+                if mv.is_unsigned() {
+                    return Ok(TypedValue::int64(extract_uint(&mv.value)? as i64));
+                }
                 let i = extract_int(&mv.value)?;
                 Ok(TypedValue::int32(i as i32))
             }
 
             MYSQL_TYPE_LONGLONG => {
+                if mv.is_unsigned() {
+                    return Ok(unsigned_bigint_to_typed_value(extract_uint(&mv.value)?));
+                }
                 let i = extract_int(&mv.value)?;
                 Ok(TypedValue::int64(i))
             }
@@ -195,7 +233,8 @@ impl TryFrom<MySQLValueWithSchema> for TypedValue {
                 let s = extract_string(&mv.value)?;
                 let precision = mv.precision.unwrap_or(10);
                 let scale = mv.scale.unwrap_or(0);
-                Ok(TypedValue::decimal(s, precision, scale))
+                TypedValue::try_decimal(s, precision, scale)
+                    .map_err(|e| ConversionError::InvalidDecimal(e.to_string()))
             }
 
             // String types
@@ -324,9 +363,9 @@ impl TryFrom<MySQLValueWithSchema> for TypedValue {
             // Bit
             MYSQL_TYPE_BIT => {
                 let bytes = extract_bytes(&mv.value)?;
-                // For BIT(1), treat as boolean
-                if bytes.len() == 1 && bytes[0] <= 1 {
-                    Ok(TypedValue::bool(bytes[0] == 1))
+                // For BIT(1), treat as boolean; wider BIT(n) keeps its bytes
+                if mv.is_boolean_bit(&bytes) {
+                    Ok(TypedValue::bool(bytes.iter().any(|b| *b != 0)))
                 } else {
                     Ok(TypedValue::bytes(bytes))
                 }
@@ -345,13 +384,17 @@ fn column_type_to_sync_type(col_type: ColumnType, mv: &MySQLValueWithSchema) ->
             // Check if this is a boolean column
             if mv.is_boolean_column() {
                 Type::Bool
+            } else if mv.is_unsigned() {
+                Type::Int16
             } else {
                 Type::Int8 {
                     width: mv.column_length.unwrap_or(4) as u8,
                 }
             }
         }
+        MYSQL_TYPE_SHORT if mv.is_unsigned() => Type::Int32,
         MYSQL_TYPE_SHORT => Type::Int16,
+        MYSQL_TYPE_LONG if mv.is_unsigned() => Type::Int64,
         MYSQL_TYPE_INT24 | MYSQL_TYPE_LONG => Type::Int32,
         MYSQL_TYPE_LONGLONG => Type::Int64,
         MYSQL_TYPE_FLOAT => Type::Float32,
@@ -384,11 +427,57 @@ fn column_type_to_sync_type(col_type: ColumnType, mv: &MySQLValueWithSchema) ->
         MYSQL_TYPE_GEOMETRY => Type::Geometry {
             geometry_type: surreal_sync_core::GeometryType::Point,
         },
+        MYSQL_TYPE_BIT if mv.column_length == Some(1) => Type::Bool,
         MYSQL_TYPE_BIT => Type::Bytes,
         _ => Type::Text,
     }
 }
 
+/// `DECIMAL(M,D)` of a result-set column. Its reported length counts a sign
+/// (unless unsigned) and a decimal point (when `D > 0`) besides the `M`
+/// digits.
+fn decimal_precision_scale(column: &mysql_async::Column) -> (u8, u8) {
+    let scale = column.decimals();
+    let mut length = column.column_length();
+    if scale > 0 {
+        length = length.saturating_sub(1);
+    }
+    if !column.flags().contains(ColumnFlags::UNSIGNED_FLAG) {
+        length = length.saturating_sub(1);
+    }
+    (length.min(u8::MAX as u32) as u8, scale)
+}
+
+/// Convert a `BIGINT UNSIGNED` value without wrapping.
+///
+/// Values up to `i64::MAX` stay `Int64`. Larger values cannot be represented
+/// by the signed universal integer, so they become an exact `DECIMAL(20,0)`.
+pub fn unsigned_bigint_to_typed_value(value: u64) -> TypedValue {
+    match i64::try_from(value) {
+        Ok(i) => TypedValue::int64(i),
+        Err(_) => TypedValue::decimal(value.to_string(), 20, 0),
+    }
+}
+
+/// Extract unsigned integer from MySQL MysqlAsyncValue.
+fn extract_uint(value: &MysqlAsyncValue) -> Result<u64, ConversionError> {
+    match value {
+        MysqlAsyncValue::UInt(u) => Ok(*u),
+        MysqlAsyncValue::Int(i) if *i >= 0 => Ok(*i as u64),
+        MysqlAsyncValue::Bytes(b) => {
+            let s = String::from_utf8(b.clone())?;
+            s.parse().map_err(|_| ConversionError::TypeMismatch {
+                expected: "unsigned integer".to_string(),
+                actual: value.clone(),
+            })
+        }
+        _ => Err(ConversionError::TypeMismatch {
+            expected: "unsigned integer".to_string(),
+            actual: value.clone(),
+        }),
+    }
+}
+
 /// Extract integer from MySQL MysqlAsyncValue.
 fn extract_int(value: &MysqlAsyncValue) -> Result<i64, ConversionError> {
     match value {
@@ -624,12 +713,12 @@ fn convert_json_column(
 /// * `json_config` - Optional configuration for JSON field conversions
 ///
 /// # Returns
-/// A HashMap mapping column names to TypedValues
+/// An IndexMap mapping column names to TypedValues, in result-set column order
 pub fn row_to_typed_values(
     row: &mysql_async::Row,
     boolean_columns: Option<&[&str]>,
     json_config: Option<&JsonConversionConfig>,
-) -> Result<std::collections::HashMap<String, TypedValue>, ConversionError> {
+) -> Result<IndexMap<String, TypedValue>, ConversionError> {
     row_to_typed_values_with_config(
         row,
         &RowConversionConfig {
@@ -643,7 +732,7 @@ pub fn row_to_typed_values(
     )
 }
 
-/// Convert a MySQL row to a HashMap of TypedValues with full configuration.
+/// Convert a MySQL row to an IndexMap of TypedValues with full configuration.
 ///
 /// This function provides more control over type conversion including SET columns.
 ///
@@ -652,13 +741,13 @@ pub fn row_to_typed_values(
 /// * `config` - Configuration for boolean, SET, and JSON conversions
 ///
 /// # Returns
-/// A HashMap mapping column names to TypedValues
+/// An IndexMap mapping column names to TypedValues, in result-set column order
 pub fn row_to_typed_values_with_config(
     row: &mysql_async::Row,
     config: &RowConversionConfig,
-) -> Result<std::collections::HashMap<String, TypedValue>, ConversionError> {
+) -> Result<IndexMap<String, TypedValue>, ConversionError> {
     let columns = row.columns();
-    let mut result = std::collections::HashMap::new();
+    let mut result = IndexMap::new();
 
     for (index, column) in columns.iter().enumerate() {
         let column_name = column.name_str().to_string();
@@ -709,8 +798,19 @@ pub fn row_to_typed_values_with_config(
         }
 
         // Standard conversion with optional boolean hint
-        let mv = MySQLValueWithSchema::new(raw_value, column_type, column_flags)
+        let mut mv = MySQLValueWithSchema::new(raw_value, column_type, column_flags)
             .with_boolean_hint(is_boolean);
+        if column_type == ColumnType::MYSQL_TYPE_BIT {
+            // BIT(n) reports its width in bits as the column length
+            mv = mv.with_length(column.column_length());
+        }
+        if matches!(
+            column_type,
+            ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL
+        ) {
+            let (precision, scale) = decimal_precision_scale(column);
+            mv = mv.with_precision(precision, scale);
+        }
 
         let typed_value = mv.to_typed_value()?;
         result.insert(column_name, typed_value);
@@ -856,6 +956,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decimal_must_fit_precision() {
+        let decimal = |digits: &[u8]| {
+            MySQLValueWithSchema::new(
+                MysqlAsyncValue::Bytes(digits.to_vec()),
+                ColumnType::MYSQL_TYPE_NEWDECIMAL,
+                ColumnFlags::empty(),
+            )
+            .with_precision(5, 2)
+            .to_typed_value()
+        };
+        assert!(decimal(b"-999.99").is_ok());
+        let err = decimal(b"1000.00").unwrap_err();
+        assert!(matches!(err, ConversionError::InvalidDecimal(_)), "{err}");
+    }
+
     #[test]
     fn test_blob_conversion() {
         let binary_data = vec![0x00, 0x01, 0x02, 0xFF];
@@ -1440,6 +1556,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bit_width_decides_boolean() {
+        // BIT(8) holding 1 stays bytes once the width is known
+        let mv = MySQLValueWithSchema::new(
+            MysqlAsyncValue::Bytes(vec![1]),
+            ColumnType::MYSQL_TYPE_BIT,
+            ColumnFlags::empty(),
+        )
+        .with_length(8);
+        let tv = mv.to_typed_value().unwrap();
+        assert!(matches!(tv.sync_type, Type::Bytes));
+        assert_eq!(tv.value, Value::Bytes(vec![1]));
+
+        // A NULL BIT(1) is typed as boolean
+        let mv = MySQLValueWithSchema::new(
+            MysqlAsyncValue::NULL,
+            ColumnType::MYSQL_TYPE_BIT,
+            ColumnFlags::empty(),
+        )
+        .with_length(1);
+        let tv = mv.to_typed_value().unwrap();
+        assert!(matches!(tv.sync_type, Type::Bool));
+    }
+
+    #[test]
+    fn test_unsigned_bigint_boundaries() {
+        let unsigned = |v: MysqlAsyncValue| {
+            MySQLValueWithSchema::new(
+                v,
+                ColumnType::MYSQL_TYPE_LONGLONG,
+                ColumnFlags::UNSIGNED_FLAG,
+            )
+            .to_typed_value()
+            .unwrap()
+        };
+
+        let tv = unsigned(MysqlAsyncValue::UInt(i64::MAX as u64));
+        assert_eq!(tv.value, Value::Int64(i64::MAX));
+
+        let tv = unsigned(MysqlAsyncValue::UInt(i64::MAX as u64 + 1));
+        assert!(matches!(
+            tv.sync_type,
+            Type::Decimal {
+                precision: 20,
+                scale: 0
+            }
+        ));
+        assert_eq!(
+            tv.value,
+            Value::Decimal {
+                value: "9223372036854775808".to_string(),
+                precision: 20,
+                scale: 0
+            }
+        );
+
+        // Text protocol returns the digits as bytes
+        let tv = unsigned(MysqlAsyncValue::Bytes(u64::MAX.to_string().into_bytes()));
+        assert_eq!(
+            tv.value,
+            Value::Decimal {
+                value: "18446744073709551615".to_string(),
+                precision: 20,
+                scale: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_unsigned_int_widening() {
+        let unsigned = |v: u64, column_type: ColumnType| {
+            MySQLValueWithSchema::new(
+                MysqlAsyncValue::UInt(v),
+                column_type,
+                ColumnFlags::UNSIGNED_FLAG,
+            )
+            .to_typed_value()
+            .unwrap()
+        };
+        assert_eq!(
+            unsigned(255, ColumnType::MYSQL_TYPE_TINY).value,
+            Value::Int16(255)
+        );
+        assert_eq!(
+            unsigned(65535, ColumnType::MYSQL_TYPE_SHORT).value,
+            Value::Int32(65535)
+        );
+        assert_eq!(
+            unsigned(u64::from(u32::MAX), ColumnType::MYSQL_TYPE_LONG).value,
+            Value::Int64(i64::from(u32::MAX))
+        );
+    }
+
     #[test]
     fn test_geometry_column() {
         use base64::Engine;
@@ -33,14 +33,29 @@ use surreal_sync_core::Type;
 /// // TINYINT(1) is treated as boolean in MySQL
 /// let ut = mysql_column_to_universal_type("TINYINT", "tinyint(1)", None, None);
 /// assert_eq!(ut, Type::Bool);
+///
+/// // Unsigned integers widen so their full range fits
+/// let ut = mysql_column_to_universal_type("INT", "int(10) unsigned", None, None);
+/// assert_eq!(ut, Type::Int64);
 /// ```
+///
+/// `BIGINT UNSIGNED` stays `Int64`; values above `i64::MAX` are converted to
+/// an exact decimal value by the row readers instead of wrapping negative.
 pub fn mysql_column_to_universal_type(
     data_type: &str,
     column_type: &str,
     precision: Option<u32>,
     scale: Option<u32>,
 ) -> Type {
+    let unsigned = column_type.to_lowercase().contains("unsigned");
     match data_type.to_uppercase().as_str() {
+        // Unsigned integers widen to the next signed type
+        "TINYINT" if unsigned && !column_type.to_lowercase().starts_with("tinyint(1)") => {
+            Type::Int16
+        }
+        "SMALLINT" if unsigned => Type::Int32,
+        "INT" | "INTEGER" if unsigned => Type::Int64,
+
         // Numeric types
         "TINYINT" => {
             // TINYINT(1) is commonly used for boolean in MySQL
@@ -68,6 +83,12 @@ pub fn mysql_column_to_universal_type(
         // Boolean
         "BOOLEAN" | "BOOL" => Type::Bool,
 
+        // BIT(1) is a flag; wider BIT(n) columns keep their raw bytes
+        "BIT" => match extract_length_from_column_type(column_type) {
+            Some(1) | None => Type::Bool,
+            Some(_) => Type::Bytes,
+        },
+
         // String types
         "VARCHAR" => {
             // Try to extract length from column_type
@@ -193,6 +214,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mysql_unsigned_int_types() {
+        assert_eq!(
+            mysql_column_to_universal_type("TINYINT", "tinyint(3) unsigned", None, None),
+            Type::Int16
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("SMALLINT", "smallint unsigned", None, None),
+            Type::Int32
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("MEDIUMINT", "mediumint unsigned", None, None),
+            Type::Int32
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("INT", "int(10) unsigned", None, None),
+            Type::Int64
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("BIGINT", "bigint(20) unsigned", None, None),
+            Type::Int64
+        );
+    }
+
+    #[test]
+    fn test_mysql_bit_types() {
+        assert_eq!(
+            mysql_column_to_universal_type("BIT", "bit(1)", None, None),
+            Type::Bool
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("BIT", "bit", None, None),
+            Type::Bool
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("BIT", "bit(2)", None, None),
+            Type::Bytes
+        );
+        assert_eq!(
+            mysql_column_to_universal_type("BIT", "bit(64)", None, None),
+            Type::Bytes
+        );
+    }
+
     #[test]
     fn test_mysql_tinyint1_bool() {
         // TINYINT(1) is treated as boolean in MySQL
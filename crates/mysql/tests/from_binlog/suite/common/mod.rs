@@ -2,12 +2,12 @@
 #![allow(clippy::uninlined_format_args)]
 #![allow(dead_code)]
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 use anyhow::Result;
 use mysql_async::{prelude::*, Pool};
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, Relation, RelationChange, Row, Value};
+use surreal_sync_core::{Change, FieldMap, Relation, RelationChange, Row, Value};
 use surreal_sync_mysql::from_binlog::{
     request_snapshot, BinlogWatermarkSource, SourceOpts, SIGNAL_TABLE,
 };
@@ -45,7 +45,7 @@ pub fn init_logging() {
         .try_init();
 }
 
-type SinkState = BTreeMap<String, BTreeMap<String, HashMap<String, Value>>>;
+type SinkState = BTreeMap<String, BTreeMap<String, FieldMap>>;
 
 #[derive(Default)]
 pub struct MemSink {
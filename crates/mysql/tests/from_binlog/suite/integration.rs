@@ -809,7 +809,7 @@ async fn sequential_full_sync_captures_real_master_position() -> Result<()> {
     let end: BinlogCheckpoint = manager.read_checkpoint(SyncPhase::FullSyncEnd).await?;
     assert_ne!(
         end.to_cli_string(),
-        "file:mysql-bin.000001:4",
+        "mysql-bin.000001:4",
         "FullSyncEnd must capture the real master position, not the placeholder"
     );
 
@@ -1280,13 +1280,12 @@ async fn drain_items_events(client: &mut BinlogClient) -> Result<()> {
                 EventBody::TableMap(tm) => {
                     table_maps.insert(tm.table_id, tm);
                 }
-                EventBody::Rows(rows) => {
+                EventBody::Rows(rows)
                     if table_maps
                         .get(&rows.table_id)
-                        .is_some_and(|tm| tm.table == "widgets" || tm.table == "items")
-                    {
-                        saw_target_row = true;
-                    }
+                        .is_some_and(|tm| tm.table == "widgets" || tm.table == "items") =>
+                {
+                    saw_target_row = true;
                 }
                 EventBody::Xid(_) if saw_target_row => {
                     client.commit(client.current_position());
@@ -0,0 +1,134 @@
+//! Audit table cleanup for the MySQL trigger source: `auto_cleanup` deletes
+//! `surreal_sync_changes` rows once SurrealDB has applied them.
+
+use std::sync::Mutex;
+
+use anyhow::Result;
+use mysql_async::prelude::*;
+use surreal_sync_core::{Change, Row, SurrealSink};
+use surreal_sync_mysql::from_trigger::{
+    run_incremental_sync_with_transforms, setup_mysql_change_tracking, MySQLCheckpoint,
+    MySQLIncrementalSource, ReplicationTailOptions, SourceOpts,
+};
+use surreal_sync_runtime::{ApplyOpts, Pipeline};
+
+mod common;
+
+#[derive(Default)]
+struct CountingSink {
+    changes: Mutex<usize>,
+}
+
+#[async_trait::async_trait]
+impl SurrealSink for CountingSink {
+    async fn write_rows(&self, rows: &[Row]) -> anyhow::Result<()> {
+        *self.changes.lock().expect("lock") += rows.len();
+        Ok(())
+    }
+
+    async fn write_relations(
+        &self,
+        _relations: &[surreal_sync_core::Relation],
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn apply_change(&self, _change: &Change) -> anyhow::Result<()> {
+        *self.changes.lock().expect("lock") += 1;
+        Ok(())
+    }
+
+    async fn apply_relation_change(
+        &self,
+        _change: &surreal_sync_core::RelationChange,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+async fn audit_sequence_ids(conn: &mut mysql_async::Conn) -> Result<Vec<i64>> {
+    Ok(conn
+        .query("SELECT sequence_id FROM surreal_sync_changes ORDER BY sequence_id")
+        .await?)
+}
+
+#[tokio::test]
+async fn auto_cleanup_deletes_applied_audit_rows() -> Result<()> {
+    common::init_logging();
+
+    let name = format!("mysql-trigger-cleanup-{}", std::process::id());
+    let mut container = surreal_sync_mysql::from_trigger::testing::MySQLContainer::new(&name);
+    container.start()?;
+    container.wait_until_ready(60).await?;
+
+    let pool = surreal_sync_mysql::from_trigger::new_mysql_pool(&container.connection_string)?;
+    let mut conn = pool.get_conn().await?;
+    conn.query_drop("DROP TABLE IF EXISTS people").await?;
+    conn.query_drop("DROP TABLE IF EXISTS surreal_sync_changes")
+        .await?;
+    conn.query_drop("CREATE TABLE people (id INT PRIMARY KEY, name VARCHAR(64))")
+        .await?;
+    setup_mysql_change_tracking(&mut conn, "testdb").await?;
+    conn.query_drop("INSERT INTO people (id, name) VALUES (1, 'alice'), (2, 'bob')")
+        .await?;
+    conn.query_drop("INSERT INTO people (id, name) VALUES (3, 'carol')")
+        .await?;
+    let sequence_ids = audit_sequence_ids(&mut conn).await?;
+    assert_eq!(sequence_ids.len(), 3);
+
+    // Manual cleanup only removes rows up to the given checkpoint.
+    let source = MySQLIncrementalSource::new(pool.clone(), 0);
+    let deleted = source
+        .cleanup_applied_changes(&MySQLCheckpoint {
+            sequence_id: sequence_ids[0],
+            timestamp: chrono::Utc::now(),
+        })
+        .await?;
+    assert_eq!(deleted, 1);
+    assert_eq!(audit_sequence_ids(&mut conn).await?, sequence_ids[1..]);
+
+    // Without auto_cleanup the audit table is left untouched.
+    let source_opts = SourceOpts {
+        source_uri: container.connection_string.clone(),
+        source_database: Some("testdb".to_string()),
+        tables: vec!["people".to_string()],
+        mysql_boolean_paths: None,
+        id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
+        ssl: Default::default(),
+    };
+    let from_checkpoint = MySQLCheckpoint {
+        sequence_id: sequence_ids[0],
+        timestamp: chrono::Utc::now(),
+    };
+    let sink = CountingSink::default();
+    run_incremental_sync_with_transforms(
+        &sink,
+        source_opts.clone(),
+        from_checkpoint.clone(),
+        ReplicationTailOptions::stream(chrono::Utc::now() + chrono::Duration::seconds(10), None),
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+    )
+    .await?;
+    assert_eq!(*sink.changes.lock().expect("lock"), 2);
+    assert_eq!(audit_sequence_ids(&mut conn).await?, sequence_ids[1..]);
+
+    // With auto_cleanup every applied row is deleted.
+    let sink = CountingSink::default();
+    run_incremental_sync_with_transforms(
+        &sink,
+        source_opts,
+        from_checkpoint,
+        ReplicationTailOptions::stream(chrono::Utc::now() + chrono::Duration::seconds(10), None)
+            .with_auto_cleanup(true),
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+    )
+    .await?;
+    assert_eq!(*sink.changes.lock().expect("lock"), 2);
+    assert!(audit_sequence_ids(&mut conn).await?.is_empty());
+
+    Ok(())
+}
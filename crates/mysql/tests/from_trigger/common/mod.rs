@@ -11,12 +11,12 @@
 #![allow(clippy::uninlined_format_args)]
 #![allow(dead_code)]
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 use anyhow::Result;
 use mysql_async::{prelude::*, Pool};
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, Relation, RelationChange, Row, Value};
+use surreal_sync_core::{Change, FieldMap, Relation, RelationChange, Row, Value};
 use surreal_sync_mysql::from_trigger::testing::MySQLContainer;
 use surreal_sync_mysql::from_trigger::{read_table_chunk, MySqlWatermarkSource};
 use surreal_sync_mysql::RowConversionConfig;
@@ -56,7 +56,7 @@ pub fn init_logging() {
 /// In-memory [`SurrealSink`] that records the latest state per record, keyed by
 /// the serialized record id, so tests can assert parity against the source.
 /// table -> (serialized id -> non-key field map)
-type SinkState = BTreeMap<String, BTreeMap<String, HashMap<String, Value>>>;
+type SinkState = BTreeMap<String, BTreeMap<String, FieldMap>>;
 
 #[derive(Default)]
 pub struct MemSink {
@@ -0,0 +1,120 @@
+//! Composite sort-key keyset pagination — `(tenant_id, created_at)` with a
+//! nullable second column must page completely, without gaps or duplicates.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use mysql_async::prelude::*;
+use surreal_sync_core::{KeysetColumn, Row, Value};
+use surreal_sync_mysql::from_trigger::testing::MySQLContainer;
+use surreal_sync_mysql::{get_keyset_columns, read_table_chunk_by_key, RowConversionConfig};
+
+fn note_of_row(row: &Row) -> String {
+    match row.fields.get("note") {
+        Some(Value::VarChar { value, .. } | Value::Text(value)) => value.clone(),
+        other => panic!("unexpected note: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn composite_key_chunks_are_complete_gap_free_and_duplicate_free() -> Result<()> {
+    let mut container = MySQLContainer::new("test-composite-keyset");
+    container.start()?;
+    container.wait_until_ready(30).await?;
+    let pool = container.get_pool()?;
+    let mut conn = pool.get_conn().await?;
+
+    // Several tenants share timestamps, and some rows have a NULL timestamp, so
+    // neither column alone is unique and NULL handling is exercised.
+    conn.query_drop(
+        "CREATE TABLE events (tenant_id INT NOT NULL, created_at DATETIME NULL, \
+         note VARCHAR(32) NOT NULL)",
+    )
+    .await?;
+    conn.query_drop(
+        "INSERT INTO events (tenant_id, created_at, note) VALUES \
+         (2, '2024-01-02 00:00:00', 'b2'), \
+         (1, NULL, 'a-null'), \
+         (1, '2024-01-01 00:00:00', 'a1'), \
+         (3, NULL, 'c-null'), \
+         (2, '2024-01-01 00:00:00', 'b1'), \
+         (1, '2024-01-03 00:00:00', 'a3'), \
+         (2, NULL, 'b-null'), \
+         (3, '2024-01-01 00:00:00', 'c1'), \
+         (1, '2024-01-02 00:00:00', 'a2')",
+    )
+    .await?;
+    // MySQL sorts NULLs first in ascending order.
+    let expected = [
+        "a-null", "a1", "a2", "a3", "b-null", "b1", "b2", "c-null", "c1",
+    ];
+
+    let key = get_keyset_columns(
+        &mut conn,
+        "testdb",
+        "events",
+        &["tenant_id".to_string(), "created_at".to_string()],
+    )
+    .await?;
+    assert_eq!(
+        key,
+        vec![
+            KeysetColumn::new("tenant_id"),
+            KeysetColumn::nullable("created_at")
+        ]
+    );
+    assert!(
+        get_keyset_columns(&mut conn, "testdb", "events", &["missing".to_string()])
+            .await
+            .is_err(),
+        "unknown keyset column should be rejected"
+    );
+
+    let config = RowConversionConfig::default();
+    for limit in 1..=4 {
+        let mut notes = Vec::new();
+        let mut after: Option<Vec<Value>> = None;
+        let mut base = 0u64;
+        loop {
+            let chunk = read_table_chunk_by_key(
+                &mut conn,
+                "events",
+                &[],
+                &key,
+                after.as_deref(),
+                limit,
+                base,
+                &config,
+                None,
+            )
+            .await?;
+            if chunk.rows.is_empty() {
+                break;
+            }
+            for row in &chunk.rows {
+                assert_eq!(row.id, Value::Int64(base as i64));
+                base += 1;
+                notes.push(note_of_row(row));
+            }
+            let n = chunk.rows.len();
+            after = chunk.last_pk;
+            if n < limit {
+                break;
+            }
+        }
+
+        let unique: HashSet<_> = notes.iter().collect();
+        assert_eq!(
+            unique.len(),
+            notes.len(),
+            "duplicate rows with limit {limit}: {notes:?}"
+        );
+        assert_eq!(
+            notes, expected,
+            "incomplete or out-of-order read with limit {limit}"
+        );
+    }
+
+    container.stop()?;
+    Ok(())
+}
@@ -130,6 +130,8 @@ async fn identity_and_external_mutate_incremental() -> Result<()> {
         tables: vec!["people".to_string()],
         mysql_boolean_paths: None,
         id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
         ssl: Default::default(),
     };
     let from_checkpoint = MySQLCheckpoint {
@@ -233,6 +235,8 @@ async fn composite_pk_entity_writes_surreal_array_record_ids() -> Result<()> {
         tables: vec!["ledger".to_string()],
         mysql_boolean_paths: None,
         id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
         ssl: Default::default(),
     };
     let sync_opts = surreal_sync_mysql::from_trigger::SyncOpts {
@@ -339,6 +343,8 @@ async fn flatten_id_toml_writes_surreal_text_record_ids() -> Result<()> {
         tables: vec!["ledger".to_string()],
         mysql_boolean_paths: None,
         id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
         ssl: Default::default(),
     };
     let sync_opts = surreal_sync_mysql::from_trigger::SyncOpts {
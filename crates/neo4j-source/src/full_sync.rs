@@ -2,11 +2,11 @@
 //!
 //! This module provides full synchronization from Neo4j to SurrealDB.
 
+use futures::{StreamExt, TryStreamExt};
 use neo4rs::{ConfigBuilder, Graph, Query};
-use std::collections::HashMap;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Relation, Row, ThingRef, Value};
+use surreal_sync_core::{FieldMap, Relation, Row, ThingRef, Value};
 use surreal_sync_runtime::{ApplyOpts, Pipeline};
 
 use crate::neo4j_checkpoint::Neo4jCheckpoint;
@@ -21,6 +21,13 @@ pub struct SourceOpts {
     pub source_password: Option<String>,
     /// Labels to sync (empty means all labels)
     pub labels: Vec<String>,
+    /// Full sync only: migrate nodes carrying one of these labels, and only
+    /// relationships between such nodes. Takes precedence over `labels`;
+    /// `None` or empty applies no allowlist.
+    pub include_labels: Option<Vec<String>>,
+    /// Full sync only: skip nodes carrying any of these labels, and
+    /// relationships touching them.
+    pub exclude_labels: Option<Vec<String>>,
     pub neo4j_timezone: String,
     pub neo4j_json_properties: Option<Vec<String>>,
     /// Property name for change tracking (e.g., "updated_at")
@@ -36,6 +43,11 @@ pub struct SourceOpts {
     pub id_property: String,
     /// Optional composite database constituent to prefix queries with `USE <constituent>`
     pub composite_constituent: Option<String>,
+    /// Edge table to write each relationship type to (e.g. `ACTED_IN` ->
+    /// `appeared_in`). Types without an entry are lowercased (`ACTED_IN` ->
+    /// `acted_in`), which is also what happens when the map is empty, so
+    /// leaving it empty keeps the existing table names.
+    pub relation_table_map: HashMap<String, String>,
 }
 
 /// Sync options (non-connection related)
@@ -43,8 +55,15 @@ pub struct SourceOpts {
 pub struct SyncOpts {
     pub batch_size: usize,
     pub dry_run: bool,
+    /// Number of labels whose nodes are migrated in parallel during full
+    /// sync (0 or 1 means one label at a time). Relationships are migrated
+    /// once all labels finish.
+    pub concurrency: usize,
 }
 
+/// neo4rs' default connection pool size
+const DEFAULT_MAX_CONNECTIONS: usize = 16;
+
 /// Parsed configuration for Neo4j JSON-to-object conversion
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Neo4jJsonProperty {
@@ -105,6 +124,54 @@ impl Neo4jConversionContext {
     }
 }
 
+/// Node label allowlist / denylist applied by full sync.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LabelFilter {
+    include: Option<HashSet<String>>,
+    exclude: HashSet<String>,
+}
+
+impl LabelFilter {
+    pub(crate) fn new(opts: &SourceOpts) -> Self {
+        let include = opts
+            .include_labels
+            .as_ref()
+            .filter(|labels| !labels.is_empty())
+            .map(|labels| labels.iter().cloned().collect());
+        let exclude = opts.exclude_labels.iter().flatten().cloned().collect();
+        Self { include, exclude }
+    }
+
+    /// Whether the filter can skip anything.
+    fn is_active(&self) -> bool {
+        self.include.is_some() || !self.exclude.is_empty()
+    }
+
+    fn excludes(&self, label: &str) -> bool {
+        self.exclude.contains(label)
+    }
+
+    /// Whether a node with these labels is migrated.
+    fn allows_node(&self, labels: &[String]) -> bool {
+        let included = match &self.include {
+            Some(include) => labels.iter().any(|label| include.contains(label)),
+            None => true,
+        };
+        included && !labels.iter().any(|label| self.excludes(label))
+    }
+
+    /// Whether both endpoints of a relationship are migrated.
+    fn allows_relation(&self, relation: &Neo4jRelation) -> bool {
+        !self.is_active()
+            || (self.allows_node(&relation.start_labels) && self.allows_node(&relation.end_labels))
+    }
+}
+
+/// Quote a label or relationship type for use in a Cypher pattern.
+fn cypher_name(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
 /// Prefix a Cypher query with `USE <constituent>` for composite database access.
 pub fn with_use_clause(query: &str, constituent: &Option<String>) -> String {
     match constituent {
@@ -185,6 +252,8 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
             .source_database
             .clone()
             .unwrap_or_else(|| "neo4j".to_string()))
+        // Every label migrated in parallel holds a pooled connection
+        .max_connections(sync_opts.concurrency.max(DEFAULT_MAX_CONNECTIONS))
         .build()?;
 
     let graph = Graph::connect(config)?;
@@ -330,11 +399,21 @@ async fn migrate_neo4j_nodes<S: SurrealSink>(
 )> {
     tracing::info!("Starting Neo4j nodes migration");
 
-    let mut min_timestamp: Option<chrono::DateTime<chrono::Utc>> = None;
-    let mut max_timestamp: Option<chrono::DateTime<chrono::Utc>> = None;
+    let filter = LabelFilter::new(from_opts);
 
     // Determine which labels to process
-    let labels_to_process: Vec<String> = if from_opts.labels.is_empty() {
+    let labels_to_process: Vec<String> = if let Some(include) = from_opts
+        .include_labels
+        .as_ref()
+        .filter(|labels| !labels.is_empty())
+    {
+        tracing::info!(
+            "Including {} specified labels: {:?}",
+            include.len(),
+            include
+        );
+        include.to_vec()
+    } else if from_opts.labels.is_empty() {
         // No filter - get all distinct labels from the database
         let label_query = Query::new(with_use_clause(
             "MATCH (n) RETURN DISTINCT labels(n) as labels",
@@ -361,97 +440,182 @@ async fn migrate_neo4j_nodes<S: SurrealSink>(
         );
         from_opts.labels.to_vec()
     };
+    let labels_to_process: Vec<String> = labels_to_process
+        .into_iter()
+        .filter(|label| {
+            let excluded = filter.excludes(label);
+            if excluded {
+                tracing::info!("Skipping excluded label: {label}");
+            }
+            !excluded
+        })
+        .collect();
+
+    // Each label streams from its own pooled Bolt connection; the first
+    // failure drops the stream, cancelling the labels still in flight.
+    let concurrency = sync_opts.concurrency.max(1);
+    if concurrency > 1 {
+        tracing::info!(
+            "Migrating {} labels with concurrency {concurrency}",
+            labels_to_process.len()
+        );
+    }
+    let results: Vec<LabelMigration> = futures::stream::iter(labels_to_process)
+        .map(|label| {
+            migrate_neo4j_label(
+                graph, surreal, sync_opts, ctx, from_opts, &filter, pipeline, apply_opts, label,
+            )
+        })
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await?;
 
     let mut total_migrated = 0;
+    let mut min_timestamp: Option<chrono::DateTime<chrono::Utc>> = None;
+    let mut max_timestamp: Option<chrono::DateTime<chrono::Utc>> = None;
+    for (processed, min, max) in results {
+        total_migrated += processed;
+        min_timestamp = [min_timestamp, min].into_iter().flatten().min();
+        max_timestamp = [max_timestamp, max].into_iter().flatten().max();
+    }
 
-    // Process each label separately to create proper SurrealDB tables
-    for label in labels_to_process {
-        tracing::info!("Migrating nodes with label: {}", label);
+    tracing::info!(
+        "Completed Neo4j nodes migration: {} total nodes",
+        total_migrated
+    );
 
-        let node_query = Query::new(with_use_clause(
-            "MATCH (n) WHERE $label IN labels(n) RETURN n, id(n) as node_id",
-            &from_opts.composite_constituent,
-        ))
-        .param("label", label.clone());
-        let node_result = graph.execute(node_query).await?;
+    if let (Some(min), Some(max)) = (min_timestamp, max_timestamp) {
+        tracing::info!(
+            "Node timestamp range: {} to {}",
+            min.to_rfc3339(),
+            max.to_rfc3339()
+        );
+    }
 
-        if sync_opts.dry_run {
-            let mut node_result = node_result;
-            let mut processed = 0usize;
-            while let Some(row) = node_result.next().await? {
-                let universal_row =
-                    convert_neo4j_row_to_universal_row(&row, &label, ctx, &from_opts.id_property)?;
-                if let Some(Value::LocalDateTime(ts)) =
-                    universal_row.get_field(&from_opts.change_tracking_property)
-                {
-                    min_timestamp = Some(min_timestamp.map_or(*ts, |min| min.min(*ts)));
-                    max_timestamp = Some(max_timestamp.map_or(*ts, |max| max.max(*ts)));
-                }
-                processed += 1;
+    Ok((total_migrated, min_timestamp, max_timestamp))
+}
+
+/// Nodes migrated for one label, with the min/max change-tracking timestamps seen
+type LabelMigration = (
+    usize,
+    Option<chrono::DateTime<chrono::Utc>>,
+    Option<chrono::DateTime<chrono::Utc>>,
+);
+
+/// Migrate the nodes of a single label
+#[allow(clippy::too_many_arguments)]
+async fn migrate_neo4j_label<S: SurrealSink>(
+    graph: &Graph,
+    surreal: &S,
+    sync_opts: &SyncOpts,
+    ctx: &Neo4jConversionContext,
+    from_opts: &SourceOpts,
+    filter: &LabelFilter,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+    label: String,
+) -> anyhow::Result<LabelMigration> {
+    let mut min_timestamp: Option<chrono::DateTime<chrono::Utc>> = None;
+    let mut max_timestamp: Option<chrono::DateTime<chrono::Utc>> = None;
+
+    tracing::info!("Migrating nodes with label: {}", label);
+
+    // Nodes also carrying an excluded label are skipped
+    let exclude_clause = if filter.exclude.is_empty() {
+        ""
+    } else {
+        " WHERE none(l IN labels(n) WHERE l IN $exclude_labels)"
+    };
+    let node_query = Query::new(with_use_clause(
+        &format!(
+            "MATCH (n:{}){exclude_clause} RETURN n, id(n) as node_id",
+            cypher_name(&label)
+        ),
+        &from_opts.composite_constituent,
+    ))
+    .param(
+        "exclude_labels",
+        filter.exclude.iter().cloned().collect::<Vec<_>>(),
+    );
+    let node_result = graph.execute(node_query).await?;
+
+    if sync_opts.dry_run {
+        let mut node_result = node_result;
+        let mut processed = 0usize;
+        while let Some(row) = node_result.next().await? {
+            let universal_row =
+                convert_neo4j_row_to_universal_row(&row, &label, ctx, &from_opts.id_property)?;
+            if let Some(Value::LocalDateTime(ts)) =
+                universal_row.get_field(&from_opts.change_tracking_property)
+            {
+                min_timestamp = Some(min_timestamp.map_or(*ts, |min| min.min(*ts)));
+                max_timestamp = Some(max_timestamp.map_or(*ts, |max| max.max(*ts)));
             }
-            total_migrated += processed;
-            tracing::info!("Dry-run scanned label '{label}': {processed} nodes",);
-            continue;
+            processed += 1;
         }
+        tracing::info!("Dry-run scanned label '{label}': {processed} nodes",);
+        return Ok((processed, min_timestamp, max_timestamp));
+    }
 
-        use async_trait::async_trait;
-        use std::sync::Arc;
-        use surreal_sync_runtime::{
-            run_source_runtime_with, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
-        };
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use surreal_sync_runtime::{
+        run_source_runtime_with, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
+    };
 
-        struct Neo4jNodeChunks<'a> {
-            result: neo4rs::DetachedRowStream,
-            label: String,
-            ctx: &'a Neo4jConversionContext,
-            id_property: &'a str,
-            tracking_property: &'a str,
-            batch_size: usize,
-            min_timestamp: &'a mut Option<chrono::DateTime<chrono::Utc>>,
-            max_timestamp: &'a mut Option<chrono::DateTime<chrono::Utc>>,
-            exhausted: bool,
-        }
+    struct Neo4jNodeChunks<'a> {
+        result: neo4rs::DetachedRowStream,
+        label: String,
+        ctx: &'a Neo4jConversionContext,
+        id_property: &'a str,
+        tracking_property: &'a str,
+        batch_size: usize,
+        min_timestamp: &'a mut Option<chrono::DateTime<chrono::Utc>>,
+        max_timestamp: &'a mut Option<chrono::DateTime<chrono::Utc>>,
+        exhausted: bool,
+    }
 
-        #[async_trait]
-        impl RowChunkSource for Neo4jNodeChunks<'_> {
-            async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Row>>> {
-                if self.exhausted {
-                    return Ok(None);
-                }
-                let mut batch = Vec::with_capacity(self.batch_size);
-                while batch.len() < self.batch_size {
-                    match self.result.next().await? {
-                        None => {
-                            self.exhausted = true;
-                            break;
-                        }
-                        Some(row) => {
-                            let universal_row = convert_neo4j_row_to_universal_row(
-                                &row,
-                                &self.label,
-                                self.ctx,
-                                self.id_property,
-                            )?;
-                            if let Some(Value::LocalDateTime(ts)) =
-                                universal_row.get_field(self.tracking_property)
-                            {
-                                *self.min_timestamp =
-                                    Some(self.min_timestamp.map_or(*ts, |min| min.min(*ts)));
-                                *self.max_timestamp =
-                                    Some(self.max_timestamp.map_or(*ts, |max| max.max(*ts)));
-                            }
-                            batch.push(universal_row);
+    #[async_trait]
+    impl RowChunkSource for Neo4jNodeChunks<'_> {
+        async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Row>>> {
+            if self.exhausted {
+                return Ok(None);
+            }
+            let mut batch = Vec::with_capacity(self.batch_size);
+            while batch.len() < self.batch_size {
+                match self.result.next().await? {
+                    None => {
+                        self.exhausted = true;
+                        break;
+                    }
+                    Some(row) => {
+                        let universal_row = convert_neo4j_row_to_universal_row(
+                            &row,
+                            &self.label,
+                            self.ctx,
+                            self.id_property,
+                        )?;
+                        if let Some(Value::LocalDateTime(ts)) =
+                            universal_row.get_field(self.tracking_property)
+                        {
+                            *self.min_timestamp =
+                                Some(self.min_timestamp.map_or(*ts, |min| min.min(*ts)));
+                            *self.max_timestamp =
+                                Some(self.max_timestamp.map_or(*ts, |max| max.max(*ts)));
                         }
+                        batch.push(universal_row);
                     }
                 }
-                if batch.is_empty() {
-                    self.exhausted = true;
-                    return Ok(None);
-                }
-                Ok(Some(batch))
             }
+            if batch.is_empty() {
+                self.exhausted = true;
+                return Ok(None);
+            }
+            Ok(Some(batch))
         }
+    }
 
+    let processed = {
         let chunks = Neo4jNodeChunks {
             result: node_result,
             label: label.clone(),
@@ -468,26 +632,12 @@ async fn migrate_neo4j_nodes<S: SurrealSink>(
         let runtime_opts = SourceRuntimeOpts::new();
         run_source_runtime_with(&mut driver, surreal, transformer, apply_opts, &runtime_opts)
             .await?;
-        let processed = driver.sunk_count() as usize;
-        total_migrated += processed;
-
-        tracing::info!("Completed migration of label '{label}': {processed} nodes",);
-    }
+        driver.sunk_count() as usize
+    };
 
-    tracing::info!(
-        "Completed Neo4j nodes migration: {} total nodes",
-        total_migrated
-    );
+    tracing::info!("Completed migration of label '{label}': {processed} nodes",);
 
-    if let (Some(min), Some(max)) = (min_timestamp, max_timestamp) {
-        tracing::info!(
-            "Node timestamp range: {} to {}",
-            min.to_rfc3339(),
-            max.to_rfc3339()
-        );
-    }
-
-    Ok((total_migrated, min_timestamp, max_timestamp))
+    Ok((processed, min_timestamp, max_timestamp))
 }
 
 /// Migrate all relationships from Neo4j to SurrealDB
@@ -526,7 +676,9 @@ async fn migrate_neo4j_relationships<S: SurrealSink>(
     tracing::info!("Found {} distinct relationship types", all_types.len());
     tracing::debug!("Relationship types: {:?}", all_types);
 
+    let filter = LabelFilter::new(from_opts);
     let mut total_migrated = 0;
+    let mut skipped = 0usize;
 
     // Process each relationship type separately
     for rel_type in all_types {
@@ -549,7 +701,16 @@ async fn migrate_neo4j_relationships<S: SurrealSink>(
             let mut rel_result = rel_result;
             let mut processed = 0usize;
             while let Some(row) = rel_result.next().await? {
-                let r = row_to_relation(&row, Some(rel_type.clone()), None)?;
+                let r = row_to_relation(
+                    &row,
+                    Some(rel_type.clone()),
+                    None,
+                    &from_opts.relation_table_map,
+                )?;
+                if !filter.allows_relation(&r) {
+                    skipped += 1;
+                    continue;
+                }
                 let universal_relation = r.to_universal_relation(ctx)?;
                 if let Some(Value::LocalDateTime(ts)) = universal_relation
                     .data
@@ -577,6 +738,9 @@ async fn migrate_neo4j_relationships<S: SurrealSink>(
             result: neo4rs::DetachedRowStream,
             rel_type: String,
             ctx: &'a Neo4jConversionContext,
+            relation_table_map: &'a HashMap<String, String>,
+            filter: &'a LabelFilter,
+            skipped: &'a mut usize,
             tracking_property: &'a str,
             batch_size: usize,
             min_timestamp: &'a mut Option<chrono::DateTime<chrono::Utc>>,
@@ -598,7 +762,16 @@ async fn migrate_neo4j_relationships<S: SurrealSink>(
                             break;
                         }
                         Some(row) => {
-                            let r = row_to_relation(&row, Some(self.rel_type.clone()), None)?;
+                            let r = row_to_relation(
+                                &row,
+                                Some(self.rel_type.clone()),
+                                None,
+                                self.relation_table_map,
+                            )?;
+                            if !self.filter.allows_relation(&r) {
+                                *self.skipped += 1;
+                                continue;
+                            }
                             let universal_relation = r.to_universal_relation(self.ctx)?;
                             if let Some(Value::LocalDateTime(ts)) =
                                 universal_relation.data.get(self.tracking_property)
@@ -624,6 +797,9 @@ async fn migrate_neo4j_relationships<S: SurrealSink>(
             result: rel_result,
             rel_type: rel_type.clone(),
             ctx,
+            relation_table_map: &from_opts.relation_table_map,
+            filter: &filter,
+            skipped: &mut skipped,
             tracking_property: &from_opts.change_tracking_property,
             batch_size: sync_opts.batch_size.max(1),
             min_timestamp: &mut min_timestamp,
@@ -647,6 +823,11 @@ async fn migrate_neo4j_relationships<S: SurrealSink>(
         "Completed Neo4j relationships migration: {} total relationships",
         total_migrated
     );
+    if skipped > 0 {
+        tracing::warn!(
+            "Skipped {skipped} relationships whose start or end node is filtered out by label"
+        );
+    }
 
     if let (Some(min), Some(max)) = (min_timestamp, max_timestamp) {
         tracing::info!(
@@ -713,7 +894,7 @@ fn convert_neo4j_row_to_universal_row(
     // Create proper ID for the record
     // Neo4j stores all properties as bolt types - integers are preserved as Integer,
     // strings as String, etc. We convert each type to the appropriate ID type.
-    let id = match data.remove(id_property) {
+    let id = match data.shift_remove(id_property) {
         Some(universal) => match universal {
             Value::Text(s) => {
                 if let Ok(n) = s.parse::<i64>() {
@@ -745,8 +926,8 @@ fn convert_neo4j_node_to_universal_kvs(
     node_id: i64,
     label: &str,
     ctx: &Neo4jConversionContext,
-) -> anyhow::Result<HashMap<String, Value>> {
-    let mut kvs = HashMap::new();
+) -> anyhow::Result<FieldMap> {
+    let mut kvs = FieldMap::new();
 
     // Add neo4j_id as a field (preserve original Neo4j ID)
     kvs.insert("neo4j_id".to_string(), Value::Int64(node_id));
@@ -785,6 +966,8 @@ fn convert_neo4j_node_to_universal_kvs(
 #[derive(Debug, Clone)]
 pub struct Neo4jRelation {
     pub rel_type: String,
+    /// SurrealDB edge table, resolved from `rel_type` by [`relation_table_name`]
+    pub table: String,
     pub id: i64,
     pub start_labels: Vec<String>,
     pub start_node_id: i64,
@@ -816,7 +999,7 @@ impl Neo4jRelation {
             resolve_node_id(self.end_prop_id.as_ref(), self.end_node_id, ctx)?,
         );
 
-        let mut data = HashMap::new();
+        let mut data = FieldMap::new();
         for k in self.relationship.keys() {
             let value = self.relationship.get::<neo4rs::BoltType>(k)?;
             // Relationships don't have labels, so we can't use JSON-to-object for them
@@ -824,21 +1007,25 @@ impl Neo4jRelation {
             data.insert(k.to_string(), v);
         }
 
-        Ok(Relation::new(
-            self.rel_type.to_lowercase(),
-            id,
-            input,
-            output,
-            data,
-        ))
+        Ok(Relation::new(self.table.clone(), id, input, output, data))
     }
 }
 
 // If rel_type is provided, this function skips the type extraction from the relationship
+/// Edge table for a relationship type: its `relation_table_map` entry, or the
+/// lowercased type when there is none.
+pub fn relation_table_name(rel_type: &str, relation_table_map: &HashMap<String, String>) -> String {
+    relation_table_map
+        .get(rel_type)
+        .cloned()
+        .unwrap_or_else(|| rel_type.to_lowercase())
+}
+
 pub fn row_to_relation(
     row: &neo4rs::Row,
     rel_type: Option<String>,
     change_tracking_property: Option<String>,
+    relation_table_map: &HashMap<String, String>,
 ) -> anyhow::Result<Neo4jRelation> {
     let relationship: neo4rs::Relation = row.get("r")?;
     let rel_id: i64 = row.get("rel_id")?;
@@ -876,6 +1063,7 @@ pub fn row_to_relation(
     };
 
     anyhow::Ok(Neo4jRelation {
+        table: relation_table_name(&rel_type, relation_table_map),
         rel_type,
         id: rel_id,
         start_labels,
@@ -1369,4 +1557,55 @@ mod tests {
         let result = with_use_clause("MATCH (n) RETURN n", &constituent);
         assert_eq!(result, "USE composite.db1 MATCH (n) RETURN n");
     }
+
+    fn label_filter(include: Option<&[&str]>, exclude: Option<&[&str]>) -> LabelFilter {
+        let to_vec = |labels: &[&str]| labels.iter().map(|l| l.to_string()).collect();
+        LabelFilter {
+            include: include.map(to_vec),
+            exclude: exclude.map(to_vec).unwrap_or_default(),
+        }
+    }
+
+    fn labels(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_label_filter_inactive_allows_everything() {
+        let filter = label_filter(None, None);
+        assert!(!filter.is_active());
+        assert!(filter.allows_node(&labels(&["Person"])));
+        assert!(filter.allows_node(&[]));
+    }
+
+    #[test]
+    fn test_label_filter_include_and_exclude() {
+        let filter = label_filter(Some(&["Person", "Movie"]), Some(&["Archived"]));
+        assert!(filter.is_active());
+        assert!(filter.allows_node(&labels(&["Person"])));
+        assert!(filter.allows_node(&labels(&["Actor", "Person"])));
+        assert!(!filter.allows_node(&labels(&["Genre"])));
+        // An excluded label wins over an included one
+        assert!(!filter.allows_node(&labels(&["Person", "Archived"])));
+
+        let filter = label_filter(None, Some(&["Archived"]));
+        assert!(filter.allows_node(&labels(&["Genre"])));
+        assert!(!filter.allows_node(&labels(&["Archived"])));
+    }
+
+    #[test]
+    fn test_relation_table_name_mapping_and_fallback() {
+        let mut map = HashMap::new();
+        assert_eq!(relation_table_name("ACTED_IN", &map), "acted_in");
+
+        map.insert("ACTED_IN".to_string(), "appeared_in".to_string());
+        assert_eq!(relation_table_name("ACTED_IN", &map), "appeared_in");
+        assert_eq!(relation_table_name("DIRECTED", &map), "directed");
+    }
+
+    #[test]
+    fn test_cypher_name_escapes_backticks() {
+        assert_eq!(cypher_name("Person"), "`Person`");
+        assert_eq!(cypher_name("We`ird"), "`We``ird`");
+    }
 }
@@ -23,7 +23,7 @@ use neo4rs::{Graph, Query};
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, Relation, RelationChange, Row, Type, Value};
+use surreal_sync_core::{Change, FieldMap, Relation, RelationChange, Row, Type, Value};
 use surreal_sync_runtime::{
     ApplyOpts, CheckpointPolicy, Pipeline, PositionedEvent, SourceDriver, SourceRuntimeOpts,
     StopReason,
@@ -74,10 +74,13 @@ pub struct Neo4jIncrementalSource {
     current_timestamp: i64,
     /// Optional composite database constituent for `USE` clause
     composite_constituent: Option<String>,
+    /// Edge table overrides per relationship type
+    relation_table_map: HashMap<String, String>,
 }
 
 impl Neo4jIncrementalSource {
     /// Create a new Neo4j incremental source
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         graph: Graph,
         neo4j_timezone: String,
@@ -86,6 +89,7 @@ impl Neo4jIncrementalSource {
         id_property: String,
         initial_timestamp: i64,
         composite_constituent: Option<String>,
+        relation_table_map: HashMap<String, String>,
     ) -> anyhow::Result<Self> {
         let ctx = Neo4jConversionContext::new(neo4j_timezone, neo4j_json_properties)?;
         Ok(Neo4jIncrementalSource {
@@ -96,6 +100,7 @@ impl Neo4jIncrementalSource {
             id_property,
             current_timestamp: initial_timestamp,
             composite_constituent,
+            relation_table_map,
         })
     }
 
@@ -126,6 +131,7 @@ impl Neo4jIncrementalSource {
             self.id_property.clone(),
             self.ctx.clone(),
             self.composite_constituent.clone(),
+            self.relation_table_map.clone(),
         )))
     }
 
@@ -169,6 +175,8 @@ pub struct Neo4jChangeStream {
     finished: bool,
     /// Optional composite database constituent for `USE` clause
     composite_constituent: Option<String>,
+    /// Edge table overrides per relationship type
+    relation_table_map: HashMap<String, String>,
 }
 
 impl Neo4jChangeStream {
@@ -179,6 +187,7 @@ impl Neo4jChangeStream {
         id_property: String,
         ctx: Neo4jConversionContext,
         composite_constituent: Option<String>,
+        relation_table_map: HashMap<String, String>,
     ) -> Self {
         let from_ts = from_checkpoint.timestamp.timestamp_millis();
         Neo4jChangeStream {
@@ -195,6 +204,7 @@ impl Neo4jChangeStream {
             change_buffer: VecDeque::new(),
             finished: false,
             composite_constituent,
+            relation_table_map,
         }
     }
 
@@ -262,7 +272,7 @@ impl Neo4jChangeStream {
             }
 
             // Convert node to universal data
-            let mut fields: HashMap<String, Value> = HashMap::new();
+            let mut fields: FieldMap = FieldMap::new();
             fields.insert("neo4j_id".to_string(), Value::Int64(node_id));
             record_id = Value::Int64(node_id);
 
@@ -349,8 +359,12 @@ impl Neo4jChangeStream {
 
         while let Some(row) = rel_result.next().await? {
             // Convert relationship to universal data
-            let r =
-                crate::row_to_relation(&row, None, Some(self.change_tracking_property.clone()))?;
+            let r = crate::row_to_relation(
+                &row,
+                None,
+                Some(self.change_tracking_property.clone()),
+                &self.relation_table_map,
+            )?;
 
             let rel_id: i64 = row.get("rel_id")?;
             if r.updated_at > max_checkpoint {
@@ -620,6 +634,7 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
         from_opts.id_property.clone(),
         initial_timestamp,
         from_opts.composite_constituent.clone(),
+        from_opts.relation_table_map.clone(),
     )?;
 
     let mut stream = source.get_changes_from(&from_checkpoint).await?;
@@ -758,6 +773,13 @@ struct Neo4jSourceDriver<'a> {
 impl SourceDriver for Neo4jSourceDriver<'_> {
     type Position = Neo4jApplyPos;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        Some(format!(
+            "timestamp_millis {}, node {}, rel {}",
+            position.timestamp_millis, position.after_node_id, position.after_rel_id
+        ))
+    }
+
     async fn poll_work(&mut self) -> anyhow::Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() || self.finished {
             return Ok(Vec::new());
@@ -824,10 +846,7 @@ impl SourceDriver for Neo4jSourceDriver<'_> {
     }
 }
 
-fn tracking_millis_from_fields(
-    fields: &HashMap<String, Value>,
-    tracking_property: &str,
-) -> Option<i64> {
+fn tracking_millis_from_fields(fields: &FieldMap, tracking_property: &str) -> Option<i64> {
     match fields.get(tracking_property) {
         Some(Value::LocalDateTime(ts)) | Some(Value::ZonedDateTime(ts)) => {
             Some(ts.timestamp_millis())
@@ -0,0 +1,119 @@
+//! Label discovery and size estimates for Neo4j sources.
+
+use anyhow::Result;
+use neo4rs::Query;
+use surreal_sync_core::{MigrationEstimate, SourceEntity, TableEstimate};
+
+use crate::full_sync::with_use_clause;
+use crate::{new_neo4j_client, SourceOpts};
+
+/// List the node labels of the source database with node counts.
+///
+/// Per-label counts (`MATCH (n:Label) RETURN count(n)`) are answered from
+/// Neo4j's count store without scanning nodes.
+pub async fn list_labels(opts: &SourceOpts) -> Result<Vec<SourceEntity>> {
+    let graph = new_neo4j_client(opts).await?;
+
+    let mut result = graph
+        .execute(Query::new(with_use_clause(
+            "CALL db.labels() YIELD label RETURN label ORDER BY label",
+            &opts.composite_constituent,
+        )))
+        .await?;
+    let mut label_names = Vec::new();
+    while let Some(row) = result.next().await? {
+        label_names.push(row.get::<String>("label")?);
+    }
+
+    let mut labels = Vec::with_capacity(label_names.len());
+    for label in label_names {
+        let count_query = format!(
+            "MATCH (n:`{}`) RETURN count(n) AS count",
+            label.replace('`', "``")
+        );
+        let mut result = graph
+            .execute(Query::new(with_use_clause(
+                &count_query,
+                &opts.composite_constituent,
+            )))
+            .await?;
+        let approx_count = match result.next().await? {
+            Some(row) => Some(row.get::<i64>("count")?.max(0) as u64),
+            None => None,
+        };
+        labels.push(SourceEntity::new(label, approx_count));
+    }
+
+    Ok(labels)
+}
+
+/// Count what a full sync would move: the nodes of each label in
+/// `opts.include_labels` or `opts.labels` (empty means every label), less
+/// `opts.exclude_labels`, plus the relationships of every type.
+///
+/// Counts come from Neo4j's count store and are exact. Neo4j does not report
+/// per-label storage sizes, so the estimate carries no byte sizes.
+/// Relationship counts include those a label filter would skip.
+pub async fn estimate_graph(opts: &SourceOpts) -> Result<MigrationEstimate> {
+    let excluded = |label: &str| opts.exclude_labels.iter().flatten().any(|l| l == label);
+    let labels = match opts.include_labels.as_ref().filter(|l| !l.is_empty()) {
+        Some(include) => include,
+        None => &opts.labels,
+    };
+    let mut estimates: Vec<TableEstimate> = if labels.is_empty() {
+        list_labels(opts)
+            .await?
+            .into_iter()
+            .filter(|label| !excluded(&label.name))
+            .map(|label| TableEstimate::new(label.name, label.approx_count.unwrap_or(0), true))
+            .collect()
+    } else {
+        let graph = new_neo4j_client(opts).await?;
+        let mut estimates = Vec::with_capacity(labels.len());
+        for label in labels.iter().filter(|label| !excluded(label)) {
+            let query = format!(
+                "MATCH (n:`{}`) RETURN count(n) AS count",
+                label.replace('`', "``")
+            );
+            let count = single_count(&graph, &query, opts).await?;
+            estimates.push(TableEstimate::new(label, count, true));
+        }
+        estimates
+    };
+
+    let graph = new_neo4j_client(opts).await?;
+    let mut result = graph
+        .execute(Query::new(with_use_clause(
+            "CALL db.relationshipTypes() YIELD relationshipType \
+             RETURN relationshipType ORDER BY relationshipType",
+            &opts.composite_constituent,
+        )))
+        .await?;
+    let mut rel_types = Vec::new();
+    while let Some(row) = result.next().await? {
+        rel_types.push(row.get::<String>("relationshipType")?);
+    }
+    for rel_type in rel_types {
+        let query = format!(
+            "MATCH ()-[r:`{}`]->() RETURN count(r) AS count",
+            rel_type.replace('`', "``")
+        );
+        let count = single_count(&graph, &query, opts).await?;
+        estimates.push(TableEstimate::new(rel_type, count, true));
+    }
+
+    Ok(MigrationEstimate::new("neo4j", estimates))
+}
+
+async fn single_count(graph: &neo4rs::Graph, query: &str, opts: &SourceOpts) -> Result<u64> {
+    let mut result = graph
+        .execute(Query::new(with_use_clause(
+            query,
+            &opts.composite_constituent,
+        )))
+        .await?;
+    Ok(match result.next().await? {
+        Some(row) => row.get::<i64>("count")?.max(0) as u64,
+        None => 0,
+    })
+}
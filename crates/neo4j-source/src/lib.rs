@@ -4,12 +4,13 @@
 
 mod full_sync;
 mod incremental_sync;
+mod introspect;
 pub mod neo4j_checkpoint;
 mod neo4j_client;
 pub mod testing;
 
 pub use full_sync::{
-    convert_neo4j_type_to_universal_value, row_to_relation, run_full_sync,
+    convert_neo4j_type_to_universal_value, relation_table_name, row_to_relation, run_full_sync,
     run_full_sync_with_transforms, Neo4jConversionContext, Neo4jJsonProperty, Neo4jRelation,
     SourceOpts, SyncOpts,
 };
@@ -17,5 +18,6 @@ pub use incremental_sync::{
     apply_incremental_changes, run_incremental_sync, run_incremental_sync_with_transforms,
     Neo4jChangeStream, Neo4jIncrementalSource, ReplicationTailOptions,
 };
+pub use introspect::{estimate_graph, list_labels};
 pub use neo4j_checkpoint::Neo4jCheckpoint;
 pub use neo4j_client::new_neo4j_client;
@@ -171,6 +171,8 @@ fn source_opts(container: &Neo4jContainer) -> SourceOpts {
         source_username: Some(container.username.clone()),
         source_password: Some(container.password.clone()),
         labels: vec!["Person".to_string()],
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: "UTC".to_string(),
         neo4j_json_properties: None,
         change_tracking_property: "updated_at".to_string(),
@@ -178,6 +180,7 @@ fn source_opts(container: &Neo4jContainer) -> SourceOpts {
         allow_empty_tracking_timestamp: false,
         id_property: "id".to_string(),
         composite_constituent: None,
+        relation_table_map: Default::default(),
     }
 }
 
@@ -218,6 +221,7 @@ async fn incremental_applies_nodes_before_relations_in_batch() -> Result<()> {
     let sync_opts = SyncOpts {
         batch_size: 100,
         dry_run: false,
+        concurrency: 1,
     };
     let sink = CaptureSink::new();
     let pipeline = Pipeline::new();
@@ -319,6 +323,7 @@ async fn identity_and_external_mutate_incremental() -> Result<()> {
     let sync_opts = SyncOpts {
         batch_size: 100,
         dry_run: false,
+        concurrency: 1,
     };
     let checkpoint = Neo4jCheckpoint::at(t1);
     let deadline = chrono::Utc::now() + chrono::Duration::seconds(30);
@@ -449,6 +454,7 @@ async fn external_mutate_full_sync_rows_and_relations() -> Result<()> {
     let sync_opts = SyncOpts {
         batch_size: 100,
         dry_run: false,
+        concurrency: 1,
     };
     run_full_sync_with_transforms::<_, surreal_sync_core::NullStore>(
         &sink,
@@ -29,6 +29,11 @@ pub enum Neo4jTypesError {
     #[error("Neo4j {bolt_type} cannot be converted to a property value")]
     UnsupportedBoltType { bolt_type: String },
 
+    /// Point SRID that is not a Neo4j coordinate reference system of the
+    /// point's dimensionality.
+    #[error("Unsupported SRID {srid} for a {dimensions}D point. Expected 7203 or 4326 (2D), 9157 or 4979 (3D)")]
+    UnsupportedSrid { srid: i64, dimensions: usize },
+
     /// JSON parse error when converting string to JSON object.
     #[error("JSON parse error for property '{property}': {error}")]
     JsonParseError { property: String, error: String },
@@ -152,10 +152,14 @@ pub fn universal_to_cypher_literal(value: &Value, parent_type: Option<&Type>) ->
             Ok(format!("[{}]", element_strs.join(", ")))
         }
 
-        // Geometry type - store as GeoJSON string
+        // Geometry type - points that carry a Neo4j SRID become native points,
+        // anything else is stored as a GeoJSON string
         Value::Geometry { data, .. } => {
             use surreal_sync_core::GeometryData;
             let GeometryData(json) = data;
+            if let Some(point) = neo4j_point_literal(json)? {
+                return Ok(point);
+            }
             let json_str =
                 serde_json::to_string(&json).map_err(|e| Neo4jTypesError::JsonParseError {
                     property: "geometry".to_string(),
@@ -235,6 +239,39 @@ pub fn universal_to_cypher_literal(value: &Value, parent_type: Option<&Type>) ->
     }
 }
 
+/// Render a GeoJSON Point carrying an `srid` (as produced by reverse
+/// conversion of Neo4j points) as a Cypher `point({...})` literal.
+///
+/// Returns `None` for other geometries and for points without an SRID.
+fn neo4j_point_literal(json: &serde_json::Value) -> Result<Option<String>> {
+    if json.get("type").and_then(|t| t.as_str()) != Some("Point") {
+        return Ok(None);
+    }
+    let Some(srid) = json.get("srid").and_then(|s| s.as_i64()) else {
+        return Ok(None);
+    };
+    let Some(coordinates) = json
+        .get("coordinates")
+        .and_then(|c| c.as_array())
+        .and_then(|c| c.iter().map(|v| v.as_f64()).collect::<Option<Vec<f64>>>())
+    else {
+        return Ok(None);
+    };
+    let axes: &[&str] = match coordinates.len() {
+        2 => &["x", "y"],
+        3 => &["x", "y", "z"],
+        _ => return Ok(None),
+    };
+
+    let mut fields = Vec::with_capacity(axes.len() + 1);
+    for (axis, value) in axes.iter().zip(coordinates) {
+        let value = universal_to_cypher_literal(&Value::Float64(value), None)?;
+        fields.push(format!("{axis}: {value}"));
+    }
+    fields.push(format!("srid: {srid}"));
+    Ok(Some(format!("point({{{}}})", fields.join(", "))))
+}
+
 /// Escape a string for Neo4j Cypher.
 ///
 /// Handles single quotes, double quotes, backslashes, and control characters.
@@ -313,42 +313,13 @@ fn convert_bolt_to_universal_value_with_type(
         }
 
         BoltType::Point2D(point) => {
-            // Convert to GeoJSON Point
-            let geojson = serde_json::json!({
-                "type": "Point",
-                "coordinates": [point.x.value, point.y.value],
-                "srid": point.sr_id.value
-            });
-
-            Ok((
-                Value::Geometry {
-                    geometry_type: GeometryType::Point,
-                    data: surreal_sync_core::values::GeometryData(geojson),
-                },
-                Type::Geometry {
-                    geometry_type: GeometryType::Point,
-                },
-            ))
+            point_to_geometry(point.sr_id.value, vec![point.x.value, point.y.value])
         }
 
-        BoltType::Point3D(point) => {
-            // Convert to GeoJSON Point with elevation
-            let geojson = serde_json::json!({
-                "type": "Point",
-                "coordinates": [point.x.value, point.y.value, point.z.value],
-                "srid": point.sr_id.value
-            });
-
-            Ok((
-                Value::Geometry {
-                    geometry_type: GeometryType::Point,
-                    data: surreal_sync_core::values::GeometryData(geojson),
-                },
-                Type::Geometry {
-                    geometry_type: GeometryType::Point,
-                },
-            ))
-        }
+        BoltType::Point3D(point) => point_to_geometry(
+            point.sr_id.value,
+            vec![point.x.value, point.y.value, point.z.value],
+        ),
 
         // These types cannot be converted to property values
         BoltType::Node(_) => Err(Neo4jTypesError::UnsupportedBoltType {
@@ -370,6 +341,45 @@ fn convert_bolt_to_universal_value_with_type(
 }
 
 /// Infer the Type from an Value.
+/// SRIDs of Neo4j's 2D coordinate reference systems: cartesian and WGS-84.
+pub const POINT_2D_SRIDS: [i64; 2] = [7203, 4326];
+
+/// SRIDs of Neo4j's 3D coordinate reference systems: cartesian-3d and WGS-84-3d.
+pub const POINT_3D_SRIDS: [i64; 2] = [9157, 4979];
+
+/// Convert a Neo4j point to a GeoJSON Point geometry that keeps its SRID.
+///
+/// The SRID must belong to a coordinate reference system with as many
+/// dimensions as the point, so a Z coordinate is never silently dropped.
+fn point_to_geometry(srid: i64, coordinates: Vec<f64>) -> Result<(Value, Type)> {
+    let expected: &[i64] = match coordinates.len() {
+        2 => &POINT_2D_SRIDS,
+        _ => &POINT_3D_SRIDS,
+    };
+    if !expected.contains(&srid) {
+        return Err(Neo4jTypesError::UnsupportedSrid {
+            srid,
+            dimensions: coordinates.len(),
+        });
+    }
+
+    let geojson = serde_json::json!({
+        "type": "Point",
+        "coordinates": coordinates,
+        "srid": srid
+    });
+
+    Ok((
+        Value::Geometry {
+            geometry_type: GeometryType::Point,
+            data: surreal_sync_core::values::GeometryData(geojson),
+        },
+        Type::Geometry {
+            geometry_type: GeometryType::Point,
+        },
+    ))
+}
+
 fn infer_element_type(value: &Value) -> Type {
     match value {
         Value::Null => Type::Text,
@@ -811,7 +821,95 @@ mod tests {
         }
     }
 
-    // Point2D and Point3D tests are skipped here because BoltPoint2D/3D don't have
-    // public constructors for testing. These types are tested in E2E integration tests
-    // with actual Neo4j data in tests/neo4j/.
+    fn bolt_point_2d(srid: i64, x: f64, y: f64) -> BoltType {
+        BoltType::Point2D(neo4rs::BoltPoint2D {
+            sr_id: BoltInteger::new(srid),
+            x: BoltFloat::new(x),
+            y: BoltFloat::new(y),
+        })
+    }
+
+    fn bolt_point_3d(srid: i64, x: f64, y: f64, z: f64) -> BoltType {
+        BoltType::Point3D(neo4rs::BoltPoint3D {
+            sr_id: BoltInteger::new(srid),
+            x: BoltFloat::new(x),
+            y: BoltFloat::new(y),
+            z: BoltFloat::new(z),
+        })
+    }
+
+    #[test]
+    fn test_point_2d_conversion() {
+        let config = ConversionConfig::default();
+        for srid in POINT_2D_SRIDS {
+            let result =
+                convert_bolt_to_typed_value(bolt_point_2d(srid, -73.97, 40.77), &config).unwrap();
+            assert_eq!(
+                result.sync_type,
+                Type::Geometry {
+                    geometry_type: GeometryType::Point
+                }
+            );
+            let Value::Geometry { data, .. } = result.value else {
+                panic!("Expected Geometry value");
+            };
+            assert_eq!(
+                data.0,
+                serde_json::json!({"type": "Point", "coordinates": [-73.97, 40.77], "srid": srid})
+            );
+        }
+    }
+
+    #[test]
+    fn test_point_3d_conversion_keeps_z() {
+        let config = ConversionConfig::default();
+        for srid in POINT_3D_SRIDS {
+            let value =
+                convert_bolt_to_universal_value(bolt_point_3d(srid, 1.0, 2.0, 3.5), &config)
+                    .unwrap();
+            let Value::Geometry { data, .. } = value else {
+                panic!("Expected Geometry value");
+            };
+            assert_eq!(data.0["coordinates"], serde_json::json!([1.0, 2.0, 3.5]));
+            assert_eq!(data.0["srid"], serde_json::json!(srid));
+        }
+    }
+
+    #[test]
+    fn test_point_unknown_srid_errors() {
+        let config = ConversionConfig::default();
+        assert!(matches!(
+            convert_bolt_to_universal_value(bolt_point_2d(3857, 1.0, 2.0), &config),
+            Err(Neo4jTypesError::UnsupportedSrid {
+                srid: 3857,
+                dimensions: 2
+            })
+        ));
+        // A 3D point tagged with a 2D SRID must not lose its Z coordinate
+        assert!(matches!(
+            convert_bolt_to_universal_value(bolt_point_3d(4326, 1.0, 2.0, 3.0), &config),
+            Err(Neo4jTypesError::UnsupportedSrid {
+                srid: 4326,
+                dimensions: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_point_round_trip_to_cypher() {
+        let config = ConversionConfig::default();
+        let value =
+            convert_bolt_to_universal_value(bolt_point_2d(4326, 1.5, 2.5), &config).unwrap();
+        assert_eq!(
+            crate::universal_to_cypher_literal(&value, None).unwrap(),
+            "point({x: 1.5, y: 2.5, srid: 4326})"
+        );
+
+        let value =
+            convert_bolt_to_universal_value(bolt_point_3d(9157, 1.5, 2.5, -3.5), &config).unwrap();
+        assert_eq!(
+            crate::universal_to_cypher_literal(&value, None).unwrap(),
+            "point({x: 1.5, y: 2.5, z: -3.5, srid: 9157})"
+        );
+    }
 }
@@ -3,19 +3,25 @@
 //! This module provides utilities for discovering PostgreSQL database structure.
 
 use anyhow::Result;
+use surreal_sync_core::{MigrationEstimate, SourceEntity, TableEstimate};
 use tokio_postgres::Client;
 
 /// Get list of user tables from PostgreSQL (excluding audit tables)
 pub async fn get_user_tables(client: &Client, _database: &str) -> Result<Vec<String>> {
+    get_schema_tables(client, "public").await
+}
+
+/// List the user tables of `schema` (excluding audit tables), by name.
+pub async fn get_schema_tables(client: &Client, schema: &str) -> Result<Vec<String>> {
     let query = "
         SELECT tablename
         FROM pg_tables
-        WHERE schemaname = 'public'
+        WHERE schemaname = $1
         AND tablename NOT LIKE 'surreal_sync_%'
         ORDER BY tablename
     ";
 
-    let rows = client.query(query, &[]).await?;
+    let rows = client.query(query, &[&schema]).await?;
     let tables: Vec<String> = rows
         .iter()
         .map(|row| row.get::<_, String>("tablename"))
@@ -23,3 +29,109 @@ pub async fn get_user_tables(client: &Client, _database: &str) -> Result<Vec<Str
 
     Ok(tables)
 }
+
+/// The schema unqualified table names resolve to on this connection
+/// (`current_schema()`, i.e. the first existing `search_path` entry), or
+/// `public` when the search path names no existing schema.
+pub async fn current_schema(client: &Client) -> Result<String> {
+    let schema: Option<String> = client
+        .query_one("SELECT current_schema()::text", &[])
+        .await?
+        .get(0);
+    Ok(schema.unwrap_or_else(|| "public".to_string()))
+}
+
+/// List the syncable tables of the `public` schema with approximate row counts.
+///
+/// Counts come from the planner statistics (`pg_class.reltuples`), so they
+/// are as fresh as the last `ANALYZE`/autovacuum and `None` for tables never
+/// analyzed. Audit tables created by surreal-sync are excluded.
+pub async fn list_tables(client: &Client) -> Result<Vec<SourceEntity>> {
+    let query = "
+        SELECT t.table_name::text AS table_name,
+               c.reltuples::float8 AS reltuples
+        FROM information_schema.tables t
+        LEFT JOIN pg_catalog.pg_namespace n ON n.nspname = t.table_schema
+        LEFT JOIN pg_catalog.pg_class c
+            ON c.relnamespace = n.oid AND c.relname = t.table_name
+        WHERE t.table_schema = 'public'
+        AND t.table_type = 'BASE TABLE'
+        AND t.table_name NOT LIKE 'surreal_sync_%'
+        ORDER BY t.table_name
+    ";
+
+    let rows = client.query(query, &[]).await?;
+    let tables = rows
+        .iter()
+        .map(|row| {
+            let reltuples: Option<f64> = row.get("reltuples");
+            // reltuples is -1 until the table is first analyzed (PG 14+).
+            let approx_count = reltuples.filter(|n| *n >= 0.0).map(|n| n as u64);
+            SourceEntity::new(row.get::<_, String>("table_name"), approx_count)
+        })
+        .collect();
+
+    Ok(tables)
+}
+
+/// Count what a full sync of `tables` in `schema` would move (empty `tables`
+/// means every user table of the schema), without reading any rows.
+///
+/// Counts are `count(*)` unless `approximate` is set, in which case the
+/// planner's `reltuples` estimate is used (falling back to `count(*)` for
+/// tables never analyzed). Sizes extrapolate the average row size of the
+/// table's heap and TOAST storage (`pg_table_size / reltuples`).
+pub async fn estimate_tables(
+    client: &Client,
+    schema: &str,
+    tables: &[String],
+    approximate: bool,
+) -> Result<MigrationEstimate> {
+    let tables = if tables.is_empty() {
+        get_schema_tables(client, schema).await?
+    } else {
+        tables.to_vec()
+    };
+
+    let mut estimates = Vec::with_capacity(tables.len());
+    for table in tables {
+        let stats = client
+            .query_opt(
+                "SELECT c.reltuples::float8, pg_table_size(c.oid)::int8 \
+                 FROM pg_catalog.pg_class c \
+                 JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+                 WHERE n.nspname = $1 AND c.relname = $2",
+                &[&schema, &table],
+            )
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("table {schema}.{table} does not exist"))?;
+        let reltuples: f64 = stats.get(0);
+        let table_bytes: i64 = stats.get(1);
+        // reltuples is -1 until the table is first analyzed (PG 14+).
+        let avg_row_bytes = (reltuples > 0.0).then(|| table_bytes as f64 / reltuples);
+
+        let estimate = if approximate && reltuples >= 0.0 {
+            TableEstimate::new(&table, reltuples as u64, false)
+        } else {
+            let count: i64 = client
+                .query_one(
+                    &format!(
+                        "SELECT count(*) FROM {}.{}",
+                        quote_ident(schema),
+                        quote_ident(&table)
+                    ),
+                    &[],
+                )
+                .await?
+                .get(0);
+            TableEstimate::new(&table, count.max(0) as u64, true)
+        };
+        estimates.push(estimate.with_avg_row_bytes(avg_row_bytes));
+    }
+
+    Ok(MigrationEstimate::new("postgresql", estimates))
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
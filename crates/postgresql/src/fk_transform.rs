@@ -3,20 +3,21 @@
 //! Converts FK column values to SurrealDB record links (`Value::Thing`)
 //! and builds `Relation` instances from join-table rows.
 
-use std::collections::HashMap;
-use surreal_sync_core::{ForeignKeyDefinition, Relation, TableDefinition, ThingRef, Value};
+use surreal_sync_core::{
+    FieldMap, ForeignKeyDefinition, Relation, TableDefinition, ThingRef, Value,
+};
 
 /// Transform FK column values in a field map to record links.
 ///
 /// For each FK on the table, the raw value (e.g. `Int64(1)`) is wrapped
 /// as `Value::Thing { table: referenced_table, id: raw_value }`.
 /// Null values are left as `Value::Null`.
-pub fn transform_fk_values(fields: &mut HashMap<String, Value>, table_def: &TableDefinition) {
+pub fn transform_fk_values(fields: &mut FieldMap, table_def: &TableDefinition) {
     for fk in &table_def.foreign_keys {
         // Only handle single-column FKs for record link conversion
         if fk.columns.len() == 1 {
             let col = &fk.columns[0];
-            if let Some(value) = fields.remove(col) {
+            if let Some(value) = fields.shift_remove(col) {
                 let transformed = match value {
                     Value::Null => Value::Null,
                     other => Value::Thing {
@@ -37,7 +38,7 @@ pub fn transform_fk_values(fields: &mut HashMap<String, Value>, table_def: &Tabl
 pub fn build_relation_from_row(
     table_name: &str,
     id: Value,
-    mut fields: HashMap<String, Value>,
+    mut fields: FieldMap,
     in_fk: &ForeignKeyDefinition,
     out_fk: &ForeignKeyDefinition,
 ) -> Relation {
@@ -63,7 +64,7 @@ pub fn build_relation_from_row(
 pub fn build_relation_from_change(
     table_name: &str,
     id: Value,
-    mut data: HashMap<String, Value>,
+    mut data: FieldMap,
     in_fk: &ForeignKeyDefinition,
     out_fk: &ForeignKeyDefinition,
 ) -> Relation {
@@ -82,7 +83,7 @@ pub fn build_relation_from_change(
 /// same order as the PK was defined. We match FK column names against
 /// the table's composite PK order.
 fn inject_missing_fk_from_composite_id(
-    data: &mut HashMap<String, Value>,
+    data: &mut FieldMap,
     in_fk: &ForeignKeyDefinition,
     out_fk: &ForeignKeyDefinition,
     id_elements: &[Value],
@@ -113,14 +114,14 @@ fn flatten_composite_id(id: Value) -> Value {
 /// Extract and remove the FK value from the fields map.
 /// For single-column FKs, returns the value directly.
 /// For multi-column FKs, returns an array of values.
-fn extract_fk_value(fields: &mut HashMap<String, Value>, fk: &ForeignKeyDefinition) -> Value {
+fn extract_fk_value(fields: &mut FieldMap, fk: &ForeignKeyDefinition) -> Value {
     if fk.columns.len() == 1 {
-        fields.remove(&fk.columns[0]).unwrap_or(Value::Null)
+        fields.shift_remove(&fk.columns[0]).unwrap_or(Value::Null)
     } else {
         let vals: Vec<Value> = fk
             .columns
             .iter()
-            .map(|c| fields.remove(c).unwrap_or(Value::Null))
+            .map(|c| fields.shift_remove(c).unwrap_or(Value::Null))
             .collect();
         Value::Array {
             elements: vals,
@@ -157,7 +158,7 @@ mod tests {
         }];
         let table = make_table_with_fks(fks);
 
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert("title".to_string(), Value::Text("Rust".to_string()));
         fields.insert("author_id".to_string(), Value::Int32(42));
 
@@ -183,7 +184,7 @@ mod tests {
         }];
         let table = make_table_with_fks(fks);
 
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert("author_id".to_string(), Value::Null);
 
         transform_fk_values(&mut fields, &table);
@@ -194,7 +195,7 @@ mod tests {
     #[test]
     fn test_transform_fk_values_non_fk_field_unchanged() {
         let table = make_table_with_fks(vec![]);
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert("title".to_string(), Value::Text("Go".to_string()));
 
         transform_fk_values(&mut fields, &table);
@@ -228,7 +229,7 @@ mod tests {
         );
         td.foreign_keys = fks;
 
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert("author_id".to_string(), Value::Int32(1));
         fields.insert("editor_id".to_string(), Value::Int32(2));
 
@@ -259,7 +260,7 @@ mod tests {
             referenced_columns: vec!["id".to_string()],
         };
 
-        let mut fields = HashMap::new();
+        let mut fields = FieldMap::new();
         fields.insert("book_id".to_string(), Value::Int32(10));
         fields.insert("tag_id".to_string(), Value::Int32(20));
         fields.insert(
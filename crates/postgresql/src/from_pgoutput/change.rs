@@ -1,13 +1,11 @@
 //! Convert pgoutput CDC changes into sync-core universal changes.
 
-use std::collections::HashMap;
-
 use crate::pgoutput_protocol::{CdcChange, RelationMeta, RowChange};
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use pg_walstream::RowData;
 use postgres_types::Type as PgType;
-use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, Type, Value};
+use surreal_sync_core::{Change, ChangeOp, DatabaseSchema, FieldMap, Type, Value};
 
 pub fn cdc_to_change(
     change: &CdcChange,
@@ -31,7 +29,7 @@ pub fn cdc_to_change(
         RowChange::Delete { old } => (ChangeOp::Delete, old),
     };
 
-    let mut values = HashMap::new();
+    let mut values = FieldMap::new();
     if relation.columns.is_empty() {
         for col_name in column_names {
             let cell = row_data
@@ -61,7 +59,7 @@ pub fn cdc_to_change(
     let data = if op == ChangeOp::Delete {
         None
     } else {
-        let fields: HashMap<String, Value> = values
+        let fields: FieldMap = values
             .into_iter()
             .filter(|(name, _)| !pk_columns.contains(name))
             .collect();
@@ -227,7 +225,7 @@ fn parse_postgres_timestamp(text: &str) -> Result<NaiveDateTime> {
         .map_err(|e| anyhow!("invalid timestamp '{text}': {e}"))
 }
 
-fn extract_primary_key(values: &HashMap<String, Value>, pk_columns: &[String]) -> Result<Value> {
+fn extract_primary_key(values: &FieldMap, pk_columns: &[String]) -> Result<Value> {
     if pk_columns.len() == 1 {
         return values
             .get(&pk_columns[0])
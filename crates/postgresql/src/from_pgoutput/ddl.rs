@@ -1,6 +1,7 @@
 //! DDL handling for pgoutput Relation and Truncate events.
 
 use crate::pgoutput_protocol::RelationMeta;
+use surreal_sync_core::{ColumnDrift, DatabaseSchema};
 
 /// A table rename detected from consecutive Relation events on the same OID.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,6 +51,20 @@ pub(crate) fn detect_relation_rename_from_filter(
     None
 }
 
+/// Column drift between a Relation event and the collected schema of its
+/// table. `None` when the table is not in the schema or nothing drifted;
+/// generated (read-only) columns, which pgoutput does not publish, are not
+/// reported missing.
+pub(crate) fn relation_column_drift(
+    schema: &DatabaseSchema,
+    meta: &RelationMeta,
+) -> Option<ColumnDrift> {
+    let table = schema.get_table(&meta.table)?;
+    let reported: Vec<&str> = meta.columns.iter().map(|c| &*c.name).collect();
+    let drift = table.column_drift(&reported);
+    (!drift.is_empty()).then_some(drift)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +99,32 @@ mod tests {
         let cache = std::collections::HashMap::new();
         assert!(detect_relation_renames(&cache, &meta(1, "widgets")).is_empty());
     }
+
+    #[test]
+    fn generated_columns_missing_from_relation_are_not_drift() {
+        use surreal_sync_core::{ColumnDefinition, TableDefinition, Type};
+
+        let schema = DatabaseSchema::new(vec![TableDefinition::new(
+            "order_lines",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![
+                ColumnDefinition::new("qty", Type::Int32),
+                ColumnDefinition::new("total", Type::Int32).mark_read_only(),
+            ],
+        )]);
+        let column = |name: &str| pg_walstream::RelationColumn {
+            name: name.into(),
+            type_id: 23,
+            type_modifier: -1,
+            is_key: name == "id",
+        };
+        let mut relation = meta(7, "order_lines");
+        relation.columns = vec![column("id"), column("qty")];
+        assert_eq!(relation_column_drift(&schema, &relation), None);
+
+        relation.columns = vec![column("id"), column("note")];
+        let drift = relation_column_drift(&schema, &relation).expect("drift");
+        assert_eq!(drift.missing, vec!["qty".to_string()]);
+        assert_eq!(drift.added, vec!["note".to_string()]);
+    }
 }
@@ -2,7 +2,8 @@
 
 use std::sync::Arc;
 
-use crate::get_user_tables;
+use crate::get_schema_tables;
+use crate::pgoutput_protocol::Lsn;
 use anyhow::Result;
 use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{Checkpoint, CheckpointStore, SyncManager, SyncPhase};
@@ -16,8 +17,8 @@ use crate::from_pgoutput::catch_up::{
 };
 use crate::from_pgoutput::checkpoint::PgoutputCheckpoint;
 use crate::from_pgoutput::client::{
-    connect_wal_client, ensure_publication_for_source, get_current_wal_lsn, new_sql_client,
-    resolve_schema,
+    connect_wal_client, connect_wal_client_with_export, ensure_publication_for_source,
+    get_current_wal_lsn, new_sql_client, resolve_schema,
 };
 use crate::from_pgoutput::schema::collect_postgresql_database_schema;
 use crate::from_pgoutput::{SourceOpts, SyncOpts};
@@ -122,14 +123,288 @@ pub async fn run_full_sync_cancellable_with_transforms<S: SurrealSink, CS: Check
     };
     info!("Found {} tables to migrate", tables.len());
 
+    let Some(total_migrated) = migrate_tables(
+        &sql, surreal, &tables, sync_opts, &db_schema, cancel, pipeline, apply_opts,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+
+    if let Some(manager) = sync_manager {
+        let checkpoint = capture_wal_checkpoint(&sql).await?;
+        manager
+            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncEnd)
+            .await?;
+        info!(
+            "Emitted full sync end checkpoint (t2): {}",
+            checkpoint.to_cli_string()
+        );
+
+        let table_names = tables.clone();
+        let existing = read_catch_up_progress(manager).await?;
+        let mut progress = existing.unwrap_or_else(|| CatchUpProgress::new(checkpoint.clone()));
+        progress.merge_tables(&table_names, CoverageKind::Initial, &checkpoint);
+        emit_catch_up_progress(manager, &progress).await?;
+    }
+
+    info!("PostgreSQL WAL full sync completed: {total_migrated} total records migrated");
+    Ok(())
+}
+
+/// Consistent-snapshot full sync: the replication slot is created with
+/// `EXPORT_SNAPSHOT` and every table is read in one `REPEATABLE READ`
+/// transaction that imports that snapshot. The slot's consistent point is both
+/// FullSyncStart and FullSyncEnd, so streaming from the returned checkpoint
+/// neither misses nor replays a change.
+///
+/// The slot must not exist yet, with one exception: an inactive slot still at
+/// the FullSyncStart of an earlier, interrupted attempt is dropped and
+/// re-created. Returns `None` when cancelled, without emitting `FullSyncEnd`.
+pub async fn run_consistent_snapshot_full_sync_with_transforms<
+    S: SurrealSink,
+    CS: CheckpointStore,
+>(
+    surreal: &S,
+    from_opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+    sync_manager: Option<&SyncManager<CS>>,
+    cancel: &tokio_util::sync::CancellationToken,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<Option<PgoutputCheckpoint>> {
+    info!("Starting PostgreSQL WAL consistent snapshot full sync to SurrealDB");
+
+    let sql = new_sql_client(&from_opts.connection_string).await?;
+    let schema = resolve_schema(from_opts).await;
+    {
+        let client = sql.lock().await;
+        ensure_publication_for_source(&client, from_opts, &schema).await?;
+    }
+
+    let mut wal = connect_wal_client_with_export(from_opts, true).await?;
+    let snapshot_name = match wal.ensure_replication_slot().await? {
+        Some(name) => name,
+        None => {
+            drop(wal);
+            let previous_start = match sync_manager {
+                Some(manager) => manager
+                    .read_checkpoint::<PgoutputCheckpoint>(SyncPhase::FullSyncStart)
+                    .await
+                    .ok(),
+                None => None,
+            };
+            let client = sql.lock().await;
+            let slot = read_slot_state(&client, &from_opts.slot_name).await?;
+            if !slot_is_interrupted_snapshot(slot, previous_start.as_ref()) {
+                anyhow::bail!(
+                    "Replication slot '{}' already exists, so no snapshot can be exported for it. \
+                     Drop it with SELECT pg_drop_replication_slot('{}') or use another \
+                     --slot for a consistent snapshot",
+                    from_opts.slot_name,
+                    from_opts.slot_name
+                );
+            }
+            info!(
+                "Re-creating replication slot '{}' left by an interrupted consistent snapshot",
+                from_opts.slot_name
+            );
+            client
+                .execute(
+                    "SELECT pg_drop_replication_slot($1)",
+                    &[&from_opts.slot_name],
+                )
+                .await?;
+            drop(client);
+            wal = connect_wal_client_with_export(from_opts, true).await?;
+            wal.ensure_replication_slot().await?.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Replication slot '{}' was re-created without an exported snapshot",
+                    from_opts.slot_name
+                )
+            })?
+        }
+    };
+
+    let checkpoint = {
+        let client = sql.lock().await;
+        let (_, lsn) = read_slot_state(&client, &from_opts.slot_name)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("Replication slot '{}' vanished", from_opts.slot_name)
+            })?;
+        client
+            .batch_execute(&format!(
+                "BEGIN ISOLATION LEVEL REPEATABLE READ READ ONLY; \
+                 SET TRANSACTION SNAPSHOT '{}'",
+                snapshot_name.replace('\'', "''")
+            ))
+            .await?;
+        PgoutputCheckpoint {
+            lsn,
+            timestamp: chrono::Utc::now(),
+        }
+    };
+    // The importing transaction now holds the snapshot; the exporting
+    // replication connection is no longer needed.
+    drop(wal);
+    info!(
+        "Consistent snapshot {snapshot_name} opened at {}",
+        checkpoint.to_cli_string()
+    );
+
+    if let Some(manager) = sync_manager {
+        manager
+            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncStart)
+            .await?;
+    }
+
+    let db_schema = {
+        let client = sql.lock().await;
+        collect_postgresql_database_schema(&client).await?
+    };
+    let tables = {
+        let client = sql.lock().await;
+        resolve_user_tables(&client, &schema, from_opts).await?
+    };
+    info!("Found {} tables to migrate", tables.len());
+
+    let Some(total_migrated) = migrate_tables(
+        &sql, surreal, &tables, sync_opts, &db_schema, cancel, pipeline, apply_opts,
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+    sql.lock().await.batch_execute("COMMIT").await?;
+
+    if let Some(manager) = sync_manager {
+        manager
+            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncEnd)
+            .await?;
+        let existing = read_catch_up_progress(manager).await?;
+        let mut progress = existing.unwrap_or_else(|| CatchUpProgress::new(checkpoint.clone()));
+        progress.merge_tables(&tables, CoverageKind::Initial, &checkpoint);
+        emit_catch_up_progress(manager, &progress).await?;
+    }
+
+    info!(
+        "PostgreSQL WAL consistent snapshot completed: {total_migrated} total records migrated; \
+         streaming starts at {}",
+        checkpoint.to_cli_string()
+    );
+    Ok(Some(checkpoint))
+}
+
+/// `snapshot_mode=initial` entry for [`run_consistent_snapshot_full_sync_with_transforms`].
+///
+/// When a previous run already recorded `FullSyncEnd` and covered every
+/// requested table, the snapshot is skipped and the latest saved LSN is
+/// returned, so a restart goes straight back to streaming. Returns the stream
+/// start, or `None` when cancelled.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_initial_consistent_snapshot_with_transforms<
+    S: SurrealSink,
+    CS: CheckpointStore,
+>(
+    surreal: &S,
+    from_opts: &SourceOpts,
+    sync_opts: &SyncOpts,
+    sync_manager: Option<&SyncManager<CS>>,
+    cancel: &tokio_util::sync::CancellationToken,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<Option<PgoutputCheckpoint>> {
+    use crate::from_pgoutput::catch_up::{max_pgoutput_checkpoint, tables_pending_snapshot};
+    use crate::from_pgoutput::watermark_source::PgoutputWatermarkSource;
+
+    if let Some(manager) = sync_manager {
+        if let Ok(end) = manager
+            .read_checkpoint::<PgoutputCheckpoint>(SyncPhase::FullSyncEnd)
+            .await
+        {
+            if let Some(progress) = read_catch_up_progress(manager).await? {
+                let requested =
+                    PgoutputWatermarkSource::resolve_snapshot_table_names(from_opts).await?;
+                if tables_pending_snapshot(&requested, &progress).is_empty() {
+                    let resume = max_pgoutput_checkpoint(&[progress.position, end]);
+                    info!(
+                        "Consistent snapshot already completed; streaming from {}",
+                        resume.to_cli_string()
+                    );
+                    return Ok(Some(resume));
+                }
+            }
+        }
+    }
+
+    run_consistent_snapshot_full_sync_with_transforms(
+        surreal,
+        from_opts,
+        sync_opts,
+        sync_manager,
+        cancel,
+        pipeline,
+        apply_opts,
+    )
+    .await
+}
+
+/// `(active, confirmed_flush_lsn)` of a logical replication slot, or `None`
+/// when it does not exist.
+async fn read_slot_state(client: &Client, slot_name: &str) -> Result<Option<(bool, Lsn)>> {
+    let row = client
+        .query_opt(
+            "SELECT active, confirmed_flush_lsn::text FROM pg_replication_slots \
+             WHERE slot_name = $1",
+            &[&slot_name],
+        )
+        .await?;
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    let active: bool = row.get(0);
+    let lsn: Option<String> = row.get(1);
+    let lsn = lsn.ok_or_else(|| {
+        anyhow::anyhow!("Replication slot '{slot_name}' has no confirmed_flush_lsn")
+    })?;
+    Ok(Some((active, Lsn::parse(&lsn)?)))
+}
+
+/// True when an existing slot was left by an interrupted consistent snapshot:
+/// nobody streams from it and it never advanced past the recorded
+/// FullSyncStart, so dropping it loses no change that was not re-snapshotted.
+fn slot_is_interrupted_snapshot(
+    slot: Option<(bool, Lsn)>,
+    previous_start: Option<&PgoutputCheckpoint>,
+) -> bool {
+    match (slot, previous_start) {
+        (Some((active, lsn)), Some(start)) => !active && lsn == start.lsn,
+        _ => false,
+    }
+}
+
+/// Copy `tables` in order on the shared SQL client. Returns `None` when
+/// cancelled between tables; the caller then leaves `FullSyncEnd` unwritten.
+#[allow(clippy::too_many_arguments)]
+async fn migrate_tables<S: SurrealSink>(
+    sql: &Arc<Mutex<Client>>,
+    surreal: &S,
+    tables: &[String],
+    sync_opts: &SyncOpts,
+    db_schema: &surreal_sync_core::DatabaseSchema,
+    cancel: &tokio_util::sync::CancellationToken,
+    pipeline: &Pipeline,
+    apply_opts: &ApplyOpts,
+) -> Result<Option<usize>> {
     let mut total_migrated = 0;
-    for table_name in &tables {
+    for table_name in tables {
         if cancel.is_cancelled() {
             info!(
                 "Cancellation requested during full sync; stopping before table '{table_name}'. \
                  Resume point remains the FullSyncStart LSN."
             );
-            return Ok(());
+            return Ok(None);
         }
         info!("Migrating table: {table_name}");
         let count = {
@@ -139,7 +414,7 @@ pub async fn run_full_sync_cancellable_with_transforms<S: SurrealSink, CS: Check
                 surreal,
                 table_name,
                 sync_opts,
-                Some(&db_schema),
+                Some(db_schema),
                 cancel,
                 pipeline,
                 apply_opts,
@@ -153,29 +428,10 @@ pub async fn run_full_sync_cancellable_with_transforms<S: SurrealSink, CS: Check
                 "Cancellation requested after table '{table_name}'; stopping full sync. \
                  Resume point remains the FullSyncStart LSN."
             );
-            return Ok(());
+            return Ok(None);
         }
     }
-
-    if let Some(manager) = sync_manager {
-        let checkpoint = capture_wal_checkpoint(&sql).await?;
-        manager
-            .emit_checkpoint(&checkpoint, SyncPhase::FullSyncEnd)
-            .await?;
-        info!(
-            "Emitted full sync end checkpoint (t2): {}",
-            checkpoint.to_cli_string()
-        );
-
-        let table_names = tables.clone();
-        let existing = read_catch_up_progress(manager).await?;
-        let mut progress = existing.unwrap_or_else(|| CatchUpProgress::new(checkpoint.clone()));
-        progress.merge_tables(&table_names, CoverageKind::Initial, &checkpoint);
-        emit_catch_up_progress(manager, &progress).await?;
-    }
-
-    info!("PostgreSQL WAL full sync completed: {total_migrated} total records migrated");
-    Ok(())
+    Ok(Some(total_migrated))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -651,18 +907,7 @@ async fn resolve_user_tables(
     if !from_opts.tables.is_empty() {
         return Ok(from_opts.tables.clone());
     }
-    if schema == "public" {
-        return get_user_tables(client, schema).await;
-    }
-    let rows = client
-        .query(
-            "SELECT tablename FROM pg_tables \
-             WHERE schemaname = $1 AND tablename NOT LIKE 'surreal_sync_%' \
-             ORDER BY tablename",
-            &[&schema],
-        )
-        .await?;
-    Ok(rows.into_iter().map(|row| row.get(0)).collect())
+    get_schema_tables(client, schema).await
 }
 
 /// Resolve a "start at head" checkpoint for incremental sync: the server's
@@ -709,3 +954,38 @@ pub async fn load_schema(sql: &Arc<Mutex<Client>>) -> Result<surreal_sync_core::
     let client = sql.lock().await;
     collect_postgresql_database_schema(&client).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start_at(lsn: u64) -> PgoutputCheckpoint {
+        PgoutputCheckpoint {
+            lsn: Lsn::new(lsn),
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn only_an_idle_slot_at_the_recorded_start_is_recreated() {
+        let start = start_at(0x1600);
+        assert!(slot_is_interrupted_snapshot(
+            Some((false, Lsn::new(0x1600))),
+            Some(&start)
+        ));
+        // Streamed past the snapshot, in use, or never recorded: keep it.
+        assert!(!slot_is_interrupted_snapshot(
+            Some((false, Lsn::new(0x1700))),
+            Some(&start)
+        ));
+        assert!(!slot_is_interrupted_snapshot(
+            Some((true, Lsn::new(0x1600))),
+            Some(&start)
+        ));
+        assert!(!slot_is_interrupted_snapshot(
+            Some((false, Lsn::new(0x1600))),
+            None
+        ));
+        assert!(!slot_is_interrupted_snapshot(None, Some(&start)));
+    }
+}
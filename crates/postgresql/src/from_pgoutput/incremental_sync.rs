@@ -29,7 +29,8 @@ use crate::from_pgoutput::client::{
     start_wal_from_checkpoint,
 };
 use crate::from_pgoutput::ddl::{
-    detect_relation_rename_from_filter, detect_relation_renames, truncate_affects_synced,
+    detect_relation_rename_from_filter, detect_relation_renames, relation_column_drift,
+    truncate_affects_synced,
 };
 use crate::from_pgoutput::schema::{
     collect_postgresql_database_schema, get_table_column_names_ordinal,
@@ -363,6 +364,13 @@ where
                         }
                     }
                     self.prev_relations.insert(meta.relation_oid, meta.clone());
+                    if let Some(drift) = relation_column_drift(&self.db_schema, &meta) {
+                        warn!(
+                            "Columns of '{}' drifted from the collected schema \
+                             (missing: {:?}, added: {:?}); refreshing at commit",
+                            meta.table, drift.missing, drift.added
+                        );
+                    }
                     if !renames.is_empty() {
                         apply_renames_to_filter(&mut self.table_filter, &renames);
                         info!(
@@ -486,6 +494,10 @@ where
 {
     type Position = Lsn;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        Some(format!("{position}"))
+    }
+
     async fn poll_work(&mut self) -> Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() {
             return Ok(Vec::new());
@@ -24,8 +24,9 @@ pub use catch_up::{
 pub use change::cdc_to_change;
 pub use checkpoint::{get_current_checkpoint, PgoutputCheckpoint, PgoutputReconciliationPos};
 pub use full_sync::{
-    capture_head_checkpoint, run_full_sync, run_full_sync_cancellable,
-    run_full_sync_cancellable_with_transforms,
+    capture_head_checkpoint, run_consistent_snapshot_full_sync_with_transforms, run_full_sync,
+    run_full_sync_cancellable, run_full_sync_cancellable_with_transforms,
+    run_initial_consistent_snapshot_with_transforms,
 };
 pub use incremental_sync::{
     run_replication_tail, run_replication_tail_with_checkpoints,
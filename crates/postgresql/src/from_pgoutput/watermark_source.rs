@@ -17,7 +17,7 @@ use surreal_sync_runtime::{
 };
 use tokio::sync::Mutex as AsyncMutex;
 use tokio_postgres::Client;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::from_pgoutput::catch_up::{
@@ -33,8 +33,8 @@ use crate::from_pgoutput::client::{
     start_wal_at_end, start_wal_from_checkpoint,
 };
 use crate::from_pgoutput::ddl::{
-    detect_relation_rename_from_filter, detect_relation_renames, truncate_affects_synced,
-    TableRename,
+    detect_relation_rename_from_filter, detect_relation_renames, relation_column_drift,
+    truncate_affects_synced, TableRename,
 };
 use crate::from_pgoutput::full_sync::read_table_chunk;
 use crate::from_pgoutput::schema::{
@@ -475,6 +475,13 @@ impl WatermarkSource for PgoutputWatermarkSource {
                         }
                     }
                     self.prev_relations.insert(meta.relation_oid, meta.clone());
+                    if let Some(drift) = relation_column_drift(&self.db_schema, &meta) {
+                        warn!(
+                            "Columns of '{}' drifted from the collected schema \
+                             (missing: {:?}, added: {:?}); refreshing at commit",
+                            meta.table, drift.missing, drift.added
+                        );
+                    }
                     if !renames.is_empty() {
                         self.apply_table_renames(&renames);
                         info!(
@@ -706,18 +713,7 @@ async fn get_snapshot_tables(
     if !from_opts.tables.is_empty() {
         return Ok(from_opts.tables.clone());
     }
-    if schema == "public" {
-        return crate::get_user_tables(client, schema).await;
-    }
-    let rows = client
-        .query(
-            "SELECT tablename FROM pg_tables \
-             WHERE schemaname = $1 AND tablename NOT LIKE 'surreal_sync_%' \
-             ORDER BY tablename",
-            &[&schema],
-        )
-        .await?;
-    Ok(rows.into_iter().map(|row| row.get(0)).collect())
+    crate::get_schema_tables(client, schema).await
 }
 
 pub async fn request_snapshot(from_opts: &SourceOpts, tables: &[String]) -> Result<()> {
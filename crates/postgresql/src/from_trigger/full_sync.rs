@@ -60,15 +60,11 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     });
 
     if let Some(manager) = sync_manager {
-        let tables = crate::get_user_tables(
-            &client,
-            from_opts.source_database.as_deref().unwrap_or("public"),
-        )
-        .await?;
+        let tables = from_opts.tracked_tables(&client).await?;
         let incremental_client = crate::new_postgresql_client(&from_opts.source_uri).await?;
         let mut incremental_source =
             super::incremental_sync::PostgresIncrementalSource::new(incremental_client, 0);
-        incremental_source.setup_tracking(tables).await?;
+        incremental_source.setup_tracking_tables(tables).await?;
 
         let current_sequence = incremental_source.get_current_sequence().await?;
         let checkpoint = super::checkpoint::PostgreSQLCheckpoint {
@@ -105,6 +101,16 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
             &sync_opts,
             Some(&db_schema),
             &from_opts.relation_tables,
+            &from_opts.keyset_columns,
+            &from_opts.column_filters,
+            ParallelRead {
+                source_uri: &from_opts.source_uri,
+                degree: from_opts
+                    .parallel_reads
+                    .get(table_name)
+                    .copied()
+                    .unwrap_or(1),
+            },
             pipeline,
             apply_opts,
         )
@@ -139,6 +145,12 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     Ok(())
 }
 
+/// Connection and degree for intra-table parallel reads of one table.
+struct ParallelRead<'a> {
+    source_uri: &'a str,
+    degree: usize,
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
     client: &tokio_postgres::Client,
@@ -147,16 +159,20 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
     sync_opts: &crate::SyncOpts,
     schema: Option<&surreal_sync_core::DatabaseSchema>,
     relation_overrides: &[String],
+    keyset_columns: &surreal_sync_core::KeysetColumns,
+    column_filters: &surreal_sync_core::ColumnFilters,
+    parallel: ParallelRead<'_>,
     pipeline: &surreal_sync_runtime::Pipeline,
     apply_opts: &surreal_sync_runtime::ApplyOpts,
 ) -> anyhow::Result<usize> {
     use crate::{
-        get_primary_key_columns, read_offset_relation_chunk, read_offset_table_chunk,
-        read_relation_chunk, read_table_chunk,
+        get_keyset_columns, get_primary_key_columns, get_read_columns, parallel_range_chunks,
+        read_offset_relation_chunk, read_offset_table_chunk, read_relation_chunk,
+        read_table_chunk_by_key, ReadColumns,
     };
     use async_trait::async_trait;
     use std::sync::Arc;
-    use surreal_sync_core::{classify_table, Relation, Row, TableKind, Value};
+    use surreal_sync_core::{classify_table, KeysetColumn, Relation, Row, TableKind, Value};
     use surreal_sync_runtime::{
         run_source_runtime_with, RelationChunkDriver, RelationChunkSource, RowChunkDriver,
         RowChunkSource, SourceRuntimeOpts,
@@ -343,7 +359,14 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         return Ok(driver.sunk_count() as usize);
     }
 
-    if pk_columns.is_empty() {
+    // A configured sort key takes precedence over the primary key, and lets
+    // tables without a primary key avoid OFFSET scans.
+    let key = match keyset_columns.get(table_name) {
+        Some(columns) => get_keyset_columns(client, table_name, columns).await?,
+        None => pk_columns.iter().map(KeysetColumn::new).collect(),
+    };
+
+    if key.is_empty() {
         tracing::warn!(
             "Table '{table_name}' has no primary key; streaming via OFFSET/LIMIT chunks \
              (ORDER BY ctid). Unsafe under concurrent source writes — prefer a PK \
@@ -430,17 +453,25 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         return Ok(driver.sunk_count() as usize);
     }
 
+    // Column filters narrow the SELECT; key columns are always read.
+    let mut read_key = pk_columns.clone();
+    read_key.extend(key.iter().map(|c| c.name.clone()));
+    let columns = get_read_columns(client, table_name, column_filters, &read_key).await?;
+
     if sync_opts.dry_run {
         let mut total = 0usize;
         let mut after: Option<Vec<surreal_sync_core::Value>> = None;
         loop {
-            let chunk = read_table_chunk(
+            let chunk = read_table_chunk_by_key(
                 client,
                 table_name,
                 &pk_columns,
+                &key,
                 after.as_deref(),
                 batch_size,
+                total as u64,
                 schema,
+                columns.as_ref(),
             )
             .await?;
             if chunk.rows.is_empty() {
@@ -456,13 +487,41 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         return Ok(total);
     }
 
+    if let Some(ranges) = parallel_range_chunks(
+        client,
+        parallel.source_uri,
+        table_name,
+        &pk_columns,
+        &key,
+        parallel.degree,
+        batch_size,
+        schema,
+        columns.as_ref(),
+    )
+    .await?
+    {
+        let mut driver = RowChunkDriver::new(ranges);
+        run_source_runtime_with(
+            &mut driver,
+            surreal,
+            Arc::new(pipeline.clone()),
+            apply_opts,
+            &SourceRuntimeOpts::new(),
+        )
+        .await?;
+        return Ok(driver.sunk_count() as usize);
+    }
+
     struct PgKeysetChunks<'a> {
         client: &'a tokio_postgres::Client,
         table_name: &'a str,
         pk_columns: &'a [String],
+        key: &'a [KeysetColumn],
         after: Option<Vec<surreal_sync_core::Value>>,
         batch_size: usize,
+        base: u64,
         schema: Option<&'a surreal_sync_core::DatabaseSchema>,
+        columns: Option<&'a ReadColumns>,
         exhausted: bool,
     }
 
@@ -472,13 +531,16 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
             if self.exhausted {
                 return Ok(None);
             }
-            let chunk = read_table_chunk(
+            let chunk = read_table_chunk_by_key(
                 self.client,
                 self.table_name,
                 self.pk_columns,
+                self.key,
                 self.after.as_deref(),
                 self.batch_size,
+                self.base,
                 self.schema,
+                self.columns,
             )
             .await?;
             if chunk.rows.is_empty() {
@@ -486,6 +548,7 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
                 return Ok(None);
             }
             let n = chunk.rows.len();
+            self.base += n as u64;
             self.after = chunk.last_pk;
             if n < self.batch_size {
                 self.exhausted = true;
@@ -498,9 +561,12 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         client,
         table_name,
         pk_columns: &pk_columns,
+        key: &key,
         after: None,
         batch_size,
+        base: 0,
         schema,
+        columns: columns.as_ref(),
         exhausted: false,
     };
     let mut driver = RowChunkDriver::new(chunks);
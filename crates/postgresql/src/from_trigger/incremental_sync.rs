@@ -1,4 +1,5 @@
 use super::checkpoint::PostgreSQLCheckpoint;
+use super::schema::{TrackedTable, DEFAULT_SCHEMA};
 use crate::from_trigger::SourceOpts;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
@@ -10,7 +11,8 @@ use std::sync::Arc;
 use surreal_sync_core::Checkpoint;
 use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{
-    classify_table, Change, ChangeOp, DatabaseSchema, RelationChange, TableKind, Value,
+    classify_table, Change, ChangeOp, DatabaseSchema, FieldMap, RelationChange, TableDefinition,
+    TableKind, Value,
 };
 use surreal_sync_json::types::{
     convert_id_with_database_schema, json_to_universal_with_table_schema,
@@ -157,15 +159,11 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
         }
     });
 
-    let tables = crate::get_user_tables(
-        &pg_client,
-        from_opts.source_database.as_deref().unwrap_or("public"),
-    )
-    .await?;
+    let tables = from_opts.tracked_tables(&pg_client).await?;
     info!("Setting up tracking for tables: {tables:?}");
 
     log::debug!("🔨 Setting up tracking for tables: {tables:?}");
-    source.setup_tracking(tables).await?;
+    source.setup_tracking_tables(tables).await?;
     log::debug!("Tracking setup completed");
 
     log::debug!("📡 Getting change stream");
@@ -269,6 +267,10 @@ impl PostgresTriggerSourceDriver<'_> {
 impl SourceDriver for PostgresTriggerSourceDriver<'_> {
     type Position = i64;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        Some(format!("sequence_id {position}"))
+    }
+
     async fn poll_work(&mut self) -> Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() || self.finished {
             return Ok(Vec::new());
@@ -342,6 +344,8 @@ impl SourceDriver for PostgresTriggerSourceDriver<'_> {
 /// Configuration for tracking a table in PostgreSQL
 #[derive(Debug, Clone)]
 pub struct TableTrackingConfig {
+    /// The schema of the table to track
+    pub schema: String,
     /// The table name to track
     pub table_name: String,
     /// The primary key column name for this table
@@ -375,6 +379,11 @@ pub struct PostgresIncrementalSource {
     /// Mapping of table names to their primary key column names
     /// Used for schema-aware ID type conversion in the change stream
     pk_columns: HashMap<String, Vec<String>>,
+    /// SurrealDB table of each tracked `(schema, table)`
+    targets: HashMap<(String, String), String>,
+    /// Definitions of tracked tables outside `public` or synced under another
+    /// name, named after their SurrealDB table
+    target_definitions: Vec<TableDefinition>,
 }
 
 impl PostgresIncrementalSource {
@@ -387,6 +396,8 @@ impl PostgresIncrementalSource {
             last_sequence: initial_sequence_id,
             database_schema: None,
             pk_columns: HashMap::new(),
+            targets: HashMap::new(),
+            target_definitions: Vec::new(),
         }
     }
 
@@ -395,15 +406,17 @@ impl PostgresIncrementalSource {
 
         // Create tracking table
         let create_table = format!(
-            "CREATE TABLE IF NOT EXISTS {} (
+            "CREATE TABLE IF NOT EXISTS {0} (
                 sequence_id BIGSERIAL PRIMARY KEY,
                 table_name TEXT NOT NULL,
                 operation TEXT NOT NULL,
                 row_id JSONB NOT NULL,
                 old_data JSONB,
                 new_data JSONB,
-                changed_at TIMESTAMPTZ DEFAULT NOW()
-            )",
+                changed_at TIMESTAMPTZ DEFAULT NOW(),
+                table_schema TEXT NOT NULL DEFAULT 'public'
+            );
+            ALTER TABLE {0} ADD COLUMN IF NOT EXISTS table_schema TEXT NOT NULL DEFAULT 'public'",
             self.tracking_table
         );
 
@@ -449,8 +462,9 @@ impl PostgresIncrementalSource {
     /// Query and return all primary key columns for a table (supports composite keys)
     async fn query_composite_primary_id_columns(
         client: &Client,
-        table_name: &str,
+        table: &TrackedTable,
     ) -> Result<Vec<String>> {
+        let table_name = table.qualified_name();
         let pk_check = format!(
             "SELECT a.attname as pk_column
             FROM pg_constraint c
@@ -478,7 +492,12 @@ impl PostgresIncrementalSource {
     }
 
     /// Create a PostgreSQL trigger function for tracking changes with explicit PK columns (supports composite keys)
+    ///
+    /// The function lives in the table's schema, so identically named tables
+    /// of different schemas get their own, and records `TG_TABLE_SCHEMA` in
+    /// the audit table next to the table name.
     fn create_trigger_function_for_table_with_pk_columns(
+        schema: &str,
         target_table_name: &str,
         tracking_table_name: &str,
         pk_columns: &[String],
@@ -490,7 +509,8 @@ impl PostgresIncrementalSource {
             .collect();
         let pk_concat = format!("jsonb_build_array({})", pk_parts.join(", "));
 
-        let func_name = format!("surreal_sync_track_changes_{target_table_name}");
+        let func_name = format!("{schema}.surreal_sync_track_changes_{target_table_name}");
+        let tracking_table_name = format!("{DEFAULT_SCHEMA}.{tracking_table_name}");
 
         let sql = format!(
             "CREATE OR REPLACE FUNCTION {func_name}() RETURNS TRIGGER AS $$
@@ -505,8 +525,8 @@ impl PostgresIncrementalSource {
                     IF pk_json @> 'null'::jsonb THEN
                         RAISE EXCEPTION 'Primary key columns have NULL value in table %', TG_TABLE_NAME;
                     END IF;
-                    INSERT INTO {tracking_table_name} (table_name, operation, row_id, new_data)
-                    VALUES (TG_TABLE_NAME, TG_OP, pk_json, row_json);
+                    INSERT INTO {tracking_table_name} (table_schema, table_name, operation, row_id, new_data)
+                    VALUES (TG_TABLE_SCHEMA, TG_TABLE_NAME, TG_OP, pk_json, row_json);
                     RETURN NEW;
                 ELSIF TG_OP = 'UPDATE' THEN
                     row_json := to_jsonb(NEW);
@@ -515,8 +535,8 @@ impl PostgresIncrementalSource {
                     IF pk_json @> 'null'::jsonb THEN
                         RAISE EXCEPTION 'Primary key columns have NULL value in table %', TG_TABLE_NAME;
                     END IF;
-                    INSERT INTO {tracking_table_name} (table_name, operation, row_id, old_data, new_data)
-                    VALUES (TG_TABLE_NAME, TG_OP, pk_json, to_jsonb(OLD), row_json);
+                    INSERT INTO {tracking_table_name} (table_schema, table_name, operation, row_id, old_data, new_data)
+                    VALUES (TG_TABLE_SCHEMA, TG_TABLE_NAME, TG_OP, pk_json, to_jsonb(OLD), row_json);
                     RETURN NEW;
                 ELSIF TG_OP = 'DELETE' THEN
                     row_json := to_jsonb(OLD);
@@ -525,8 +545,8 @@ impl PostgresIncrementalSource {
                     IF pk_json @> 'null'::jsonb THEN
                         RAISE EXCEPTION 'Primary key columns have NULL value in table %', TG_TABLE_NAME;
                     END IF;
-                    INSERT INTO {tracking_table_name} (table_name, operation, row_id, old_data)
-                    VALUES (TG_TABLE_NAME, TG_OP, pk_json, row_json);
+                    INSERT INTO {tracking_table_name} (table_schema, table_name, operation, row_id, old_data)
+                    VALUES (TG_TABLE_SCHEMA, TG_TABLE_NAME, TG_OP, pk_json, row_json);
                     RETURN OLD;
                 END IF;
                 RETURN NULL;
@@ -538,25 +558,47 @@ impl PostgresIncrementalSource {
     }
 
     /// Set up trigger-based tracking for specified tables (backward compatibility)
-    /// Auto-detects primary key columns for each table
+    /// Auto-detects primary key columns for each table. Entries may be
+    /// schema-qualified (`schema.table`); see [`TrackedTable::parse`].
     pub async fn setup_tracking(&mut self, tables: Vec<String>) -> Result<()> {
+        let tables = super::schema::resolve_tracked_tables(&tables, &HashMap::new())?;
+        self.setup_tracking_tables(tables).await
+    }
+
+    /// Set up trigger-based tracking for `tables`, whose changes are reported
+    /// under their SurrealDB target table names.
+    /// Auto-detects primary key columns for each table
+    pub async fn setup_tracking_tables(&mut self, tables: Vec<TrackedTable>) -> Result<()> {
         let client = self.client.lock().await;
         let mut configs: Vec<TableTrackingConfig> = Vec::new();
 
         // Auto-detect primary key for each table
-        for table_name in tables {
-            let id_columns = Self::query_composite_primary_id_columns(&client, &table_name).await?;
+        for table in &tables {
+            let id_columns = Self::query_composite_primary_id_columns(&client, table).await?;
 
             // Store PK columns for schema-aware ID conversion in change stream
             self.pk_columns
-                .insert(table_name.clone(), id_columns.clone());
+                .insert(table.target.clone(), id_columns.clone());
+            self.targets.insert(
+                (table.schema.clone(), table.table.clone()),
+                table.target.clone(),
+            );
 
             configs.push(TableTrackingConfig {
-                table_name,
+                schema: table.schema.clone(),
+                table_name: table.table.clone(),
                 id_columns,
             });
         }
 
+        // Tables `initialize` does not find under their SurrealDB name
+        let renamed: Vec<TrackedTable> = tables
+            .into_iter()
+            .filter(|t| t.schema != DEFAULT_SCHEMA || t.target != t.table)
+            .collect();
+        self.target_definitions =
+            super::schema::collect_tracked_tables_schema(&client, &renamed).await?;
+
         drop(client); // Release the lock before calling setup_tracking_with_config
         self.setup_tracking_with_config(configs).await
     }
@@ -583,10 +625,11 @@ impl PostgresIncrementalSource {
 
         // Create triggers on specified tables (excluding the audit table itself)
         for config in &configs {
+            let schema = &config.schema;
             let table = &config.table_name;
 
             // Skip the audit table to prevent infinite recursion
-            if table == &self.tracking_table {
+            if schema == DEFAULT_SCHEMA && table == &self.tracking_table {
                 info!("Skipping audit table: {table}");
                 continue;
             }
@@ -599,6 +642,7 @@ impl PostgresIncrementalSource {
 
             // Create a robust trigger function that looks up ID column from config
             let (func, trigger_function) = Self::create_trigger_function_for_table_with_pk_columns(
+                schema,
                 table,
                 &self.tracking_table,
                 &config.id_columns,
@@ -613,12 +657,12 @@ impl PostgresIncrementalSource {
             let trigger = format!("surreal_sync_trigger_{table}");
 
             // Drop existing trigger first
-            let drop_trigger = format!("DROP TRIGGER IF EXISTS {trigger} ON {table}");
+            let drop_trigger = format!("DROP TRIGGER IF EXISTS {trigger} ON {schema}.{table}");
             let _ = client.simple_query(&drop_trigger).await;
 
             let trigger = format!(
                 "CREATE TRIGGER {trigger}
-                AFTER INSERT OR UPDATE OR DELETE ON {table}
+                AFTER INSERT OR UPDATE OR DELETE ON {schema}.{table}
                 FOR EACH ROW EXECUTE FUNCTION {func}()"
             );
 
@@ -673,12 +717,18 @@ impl IncrementalSource for PostgresIncrementalSource {
     }
 
     async fn get_changes(&mut self) -> Result<Box<dyn ChangeStream>> {
+        let database_schema = self.database_schema.clone().map(|schema| {
+            let mut tables = schema.tables;
+            tables.extend(self.target_definitions.iter().cloned());
+            DatabaseSchema::new(tables)
+        });
         let stream = PostgresChangeStream::new(
             self.client.clone(),
             self.tracking_table.clone(),
             self.last_sequence,
-            self.database_schema.clone(),
+            database_schema,
             self.pk_columns.clone(),
+            self.targets.clone(),
         )
         .await?;
 
@@ -712,6 +762,8 @@ pub struct PostgresChangeStream {
     database_schema: Option<DatabaseSchema>,
     /// Mapping of table names to their primary key column names
     pk_columns: HashMap<String, Vec<String>>,
+    /// SurrealDB table of each tracked `(schema, table)`
+    targets: HashMap<(String, String), String>,
 }
 
 impl PostgresChangeStream {
@@ -721,6 +773,7 @@ impl PostgresChangeStream {
         start_sequence: i64,
         database_schema: Option<DatabaseSchema>,
         pk_columns: HashMap<String, Vec<String>>,
+        targets: HashMap<(String, String), String>,
     ) -> Result<Self> {
         Ok(Self {
             client,
@@ -731,6 +784,7 @@ impl PostgresChangeStream {
             empty_poll_count: 0,
             database_schema,
             pk_columns,
+            targets,
         })
     }
 
@@ -755,7 +809,8 @@ impl PostgresChangeStream {
         }
 
         let query = format!(
-            "SELECT sequence_id, table_name, operation, row_id, old_data, new_data, changed_at
+            "SELECT sequence_id, table_name, operation, row_id, old_data, new_data, changed_at,
+                table_schema
             FROM {}
             WHERE sequence_id > $1
             ORDER BY sequence_id
@@ -772,12 +827,20 @@ impl PostgresChangeStream {
 
         for row in rows {
             let sequence_id: i64 = row.get(0);
-            let table_name: String = row.get(1);
+            let source_table: String = row.get(1);
             let operation: String = row.get(2);
             let row_id: Option<JsonValue> = row.get(3);
             let old_data: Option<JsonValue> = row.get(4);
             let new_data: Option<JsonValue> = row.get(5);
             let _changed_at: DateTime<Utc> = row.get(6);
+            let table_schema: String = row.get(7);
+
+            // Changes are reported under the table's SurrealDB name
+            let table_name = self
+                .targets
+                .get(&(table_schema, source_table.clone()))
+                .cloned()
+                .unwrap_or(source_table);
 
             let json_data = match operation.as_str() {
                 "INSERT" | "UPDATE" => new_data,
@@ -803,7 +866,7 @@ impl PostgresChangeStream {
                 )
             })?;
 
-            let universal_data: HashMap<String, Value> = if let Some(json_value) = json_data {
+            let universal_data: FieldMap = if let Some(json_value) = json_data {
                 // Use schema-aware conversion if schema is available
                 if let Some(table_schema) = self
                     .database_schema
@@ -820,7 +883,7 @@ impl PostgresChangeStream {
                     // that the filtered values match the row_id JSONB array.
                     match json_value {
                         serde_json::Value::Object(map) => {
-                            let mut m = HashMap::new();
+                            let mut m = FieldMap::new();
                             for (key, val) in map {
                                 // Check if this column is a primary key column
                                 if let Some(pk_index) = pk_cols.iter().position(|col| col == &key) {
@@ -866,7 +929,7 @@ impl PostgresChangeStream {
                     );
                 }
             } else {
-                HashMap::new()
+                FieldMap::new()
             };
 
             // Convert row_id to Value using schema information
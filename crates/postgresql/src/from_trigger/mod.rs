@@ -28,9 +28,42 @@ pub struct SourceOpts {
     pub source_uri: String,
     /// Source database/schema name
     pub source_database: Option<String>,
-    /// Tables to sync (empty means all tables)
+    /// Tables to sync (empty means all tables of the `public` schema). An
+    /// entry is `table` or a schema-qualified `schema.table`.
     pub tables: Vec<String>,
+    /// SurrealDB table per `tables` entry (`table` or `schema.table`).
+    /// Unmapped tables outside `public` sync to `schema_table`; see
+    /// [`schema::TrackedTable::parse`].
+    pub table_targets: std::collections::HashMap<String, String>,
     /// Tables to force-classify as relation (join) tables for SurrealDB RELATE.
     /// When empty (default), auto-detection is used based on FK/PK heuristics.
     pub relation_tables: Vec<String>,
+    /// Per-table keyset sort columns for chunked full-sync reads, e.g. a
+    /// composite `(tenant_id, created_at)` key. Overrides the primary key as
+    /// the pagination order and avoids OFFSET scans on tables without one.
+    pub keyset_columns: surreal_sync_core::KeysetColumns,
+    /// Per-table read parallelism for full sync: the table is split into this
+    /// many key ranges of its leading (integer or uuid) key column, read
+    /// concurrently on separate connections. Tables not listed use one reader.
+    pub parallel_reads: surreal_sync_core::ParallelReads,
+    /// Per-table column include/exclude lists. Keyset reads select only the
+    /// kept columns; key columns are always read for ids and cursors.
+    pub column_filters: surreal_sync_core::ColumnFilters,
+}
+
+impl SourceOpts {
+    /// Tables trigger-based change tracking covers: `tables`, or every user
+    /// table of the `public` schema when it is empty.
+    pub(crate) async fn tracked_tables(
+        &self,
+        client: &tokio_postgres::Client,
+    ) -> anyhow::Result<Vec<schema::TrackedTable>> {
+        let entries = if self.tables.is_empty() {
+            crate::get_user_tables(client, self.source_database.as_deref().unwrap_or("public"))
+                .await?
+        } else {
+            self.tables.clone()
+        };
+        schema::resolve_tracked_tables(&entries, &self.table_targets)
+    }
 }
@@ -7,6 +7,82 @@ use crate::types::postgresql_column_to_universal_type;
 use std::collections::HashMap;
 use surreal_sync_core::{ColumnDefinition, DatabaseSchema, TableDefinition, Type};
 
+/// Schema unqualified `tables` entries refer to.
+pub const DEFAULT_SCHEMA: &str = "public";
+
+/// A table tracked by the trigger source: `schema.table` at the source,
+/// written to the SurrealDB table `target`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrackedTable {
+    /// Source schema
+    pub schema: String,
+    /// Source table name
+    pub table: String,
+    /// SurrealDB table the table's changes are written to
+    pub target: String,
+}
+
+impl TrackedTable {
+    /// Parse a `tables` entry, either `table` (in [`DEFAULT_SCHEMA`]) or
+    /// `schema.table`.
+    ///
+    /// `targets` maps an entry (as written, or as `schema.table`) to its
+    /// SurrealDB table. Without one, a table of the default schema keeps its
+    /// name and any other table becomes `schema_table`, so `tenant1.orders`
+    /// and `tenant2.orders` land in different tables.
+    pub fn parse(entry: &str, targets: &HashMap<String, String>) -> Self {
+        let (schema, table) = entry.split_once('.').unwrap_or((DEFAULT_SCHEMA, entry));
+        let qualified = format!("{schema}.{table}");
+        let target = targets
+            .get(entry)
+            .or_else(|| targets.get(&qualified))
+            .cloned()
+            .unwrap_or_else(|| {
+                if schema == DEFAULT_SCHEMA {
+                    table.to_string()
+                } else {
+                    format!("{schema}_{table}")
+                }
+            });
+        Self {
+            schema: schema.to_string(),
+            table: table.to_string(),
+            target,
+        }
+    }
+
+    /// `schema.table`, for use in SQL.
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", self.schema, self.table)
+    }
+}
+
+/// Parse `tables` entries (see [`TrackedTable::parse`]), rejecting two
+/// source tables written to the same SurrealDB table.
+pub fn resolve_tracked_tables(
+    entries: &[String],
+    targets: &HashMap<String, String>,
+) -> anyhow::Result<Vec<TrackedTable>> {
+    let tables: Vec<TrackedTable> = entries
+        .iter()
+        .map(|entry| TrackedTable::parse(entry, targets))
+        .collect();
+    for (i, table) in tables.iter().enumerate() {
+        if let Some(other) = tables[..i].iter().find(|t| t.target == table.target) {
+            anyhow::bail!(
+                "Tables {}.{} and {}.{} both sync to SurrealDB table '{}'; \
+                 map one of them to another table",
+                other.schema,
+                other.table,
+                table.schema,
+                table.table,
+                table.target
+            );
+        }
+    }
+    Ok(tables)
+}
+
 /// Collect schema information for all tables in a PostgreSQL database.
 ///
 /// Returns a `DatabaseSchema` with proper `Type` mapping.
@@ -14,16 +90,52 @@ use surreal_sync_core::{ColumnDefinition, DatabaseSchema, TableDefinition, Type}
 pub async fn collect_postgresql_database_schema(
     client: &tokio_postgres::Client,
 ) -> anyhow::Result<DatabaseSchema> {
+    Ok(DatabaseSchema::new(
+        collect_schema_tables(client, DEFAULT_SCHEMA).await?,
+    ))
+}
+
+/// Collect the definitions of `tables`, each named after its SurrealDB
+/// target so identically named tables of different schemas stay apart.
+pub async fn collect_tracked_tables_schema(
+    client: &tokio_postgres::Client,
+    tables: &[TrackedTable],
+) -> anyhow::Result<Vec<TableDefinition>> {
+    let mut by_schema: HashMap<&str, Vec<TableDefinition>> = HashMap::new();
+    let mut definitions = Vec::new();
+    for tracked in tables {
+        if !by_schema.contains_key(tracked.schema.as_str()) {
+            let defs = collect_schema_tables(client, &tracked.schema).await?;
+            by_schema.insert(&tracked.schema, defs);
+        }
+        if let Some(def) = by_schema[tracked.schema.as_str()]
+            .iter()
+            .find(|def| def.name == tracked.table)
+        {
+            let mut def = def.clone();
+            def.name = tracked.target.clone();
+            definitions.push(def);
+        }
+    }
+    Ok(definitions)
+}
+
+/// Table definitions of every table in `schema`.
+async fn collect_schema_tables(
+    client: &tokio_postgres::Client,
+    schema: &str,
+) -> anyhow::Result<Vec<TableDefinition>> {
     // First, collect all columns with their types
     // Note: For array types, data_type returns 'ARRAY' but udt_name contains the actual type
     // like '_text' for text[], '_int4' for integer[]. We use udt_name for proper array handling.
     let columns_query = "
-        SELECT table_name, column_name, data_type, udt_name, numeric_precision, numeric_scale
+        SELECT table_name, column_name, data_type, udt_name, numeric_precision, numeric_scale,
+               is_generated
         FROM information_schema.columns
-        WHERE table_schema = 'public'
+        WHERE table_schema = $1
         ORDER BY table_name, ordinal_position";
 
-    let column_rows = client.query(columns_query, &[]).await?;
+    let column_rows = client.query(columns_query, &[&schema]).await?;
 
     // Also collect primary key information
     let pk_query = "
@@ -33,10 +145,10 @@ pub async fn collect_postgresql_database_schema(
             ON tc.constraint_name = kcu.constraint_name
             AND tc.table_schema = kcu.table_schema
         WHERE tc.constraint_type = 'PRIMARY KEY'
-            AND tc.table_schema = 'public'
+            AND tc.table_schema = $1
         ORDER BY tc.table_name, kcu.ordinal_position";
 
-    let pk_rows = client.query(pk_query, &[]).await?;
+    let pk_rows = client.query(pk_query, &[&schema]).await?;
 
     // Build primary key lookup: table_name -> ordered PK column names
     let mut pk_columns: HashMap<String, Vec<String>> = HashMap::new();
@@ -47,7 +159,7 @@ pub async fn collect_postgresql_database_schema(
     }
 
     // Build tables with columns
-    let mut table_columns: HashMap<String, Vec<(String, Type)>> = HashMap::new();
+    let mut table_columns: HashMap<String, Vec<(String, Type, bool)>> = HashMap::new();
 
     for row in column_rows {
         let table_name: String = row.get(0);
@@ -56,6 +168,9 @@ pub async fn collect_postgresql_database_schema(
         let udt_name: String = row.get(3);
         let precision: Option<i32> = row.get(4);
         let scale: Option<i32> = row.get(5);
+        // Generated columns are synced like any other but marked read-only.
+        let is_generated: Option<String> = row.get(6);
+        let read_only = is_generated.as_deref() == Some("ALWAYS");
 
         // Convert i32 to u32 for precision/scale
         let precision = precision.map(|p| p as u32);
@@ -74,7 +189,7 @@ pub async fn collect_postgresql_database_schema(
         table_columns
             .entry(table_name)
             .or_default()
-            .push((column_name, universal_type));
+            .push((column_name, universal_type, read_only));
     }
 
     // Build TableDefinition for each table
@@ -92,11 +207,13 @@ pub async fn collect_postgresql_database_schema(
         let mut primary_key: Option<ColumnDefinition> = None;
         let mut other_columns = Vec::new();
 
-        for (col_name, col_type) in columns {
-            if col_name == pk_col_name {
-                primary_key = Some(ColumnDefinition::new(col_name, col_type));
+        for (col_name, col_type, read_only) in columns {
+            let mut column = ColumnDefinition::new(col_name, col_type);
+            column.read_only = read_only;
+            if column.name == pk_col_name {
+                primary_key = Some(column);
             } else {
-                other_columns.push(ColumnDefinition::new(col_name, col_type));
+                other_columns.push(column);
             }
         }
 
@@ -113,7 +230,7 @@ pub async fn collect_postgresql_database_schema(
         tables.push(table_def);
     }
 
-    Ok(DatabaseSchema::new(tables))
+    Ok(tables)
 }
 
 /// Determine the effective type string to use for type mapping.
@@ -132,6 +249,50 @@ pub fn get_effective_type<'a>(data_type: &'a str, udt_name: &'a str) -> &'a str
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tracked_table_parse() {
+        let targets = HashMap::from([("tenant2.orders".to_string(), "t2_orders".to_string())]);
+
+        let plain = TrackedTable::parse("orders", &targets);
+        assert_eq!(
+            (
+                plain.schema.as_str(),
+                plain.table.as_str(),
+                plain.target.as_str()
+            ),
+            ("public", "orders", "orders")
+        );
+        let tenant1 = TrackedTable::parse("tenant1.orders", &targets);
+        assert_eq!(tenant1.target, "tenant1_orders");
+        assert_eq!(tenant1.qualified_name(), "tenant1.orders");
+        assert_eq!(
+            TrackedTable::parse("tenant2.orders", &targets).target,
+            "t2_orders"
+        );
+        assert_eq!(
+            TrackedTable::parse("public.users", &targets).target,
+            "users"
+        );
+    }
+
+    #[test]
+    fn test_resolve_tracked_tables_rejects_colliding_targets() {
+        let entries = vec!["tenant1.orders".to_string(), "tenant2.orders".to_string()];
+        assert_eq!(
+            resolve_tracked_tables(&entries, &HashMap::new())
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let targets = HashMap::from([
+            ("tenant1.orders".to_string(), "orders".to_string()),
+            ("tenant2.orders".to_string(), "orders".to_string()),
+        ]);
+        let err = resolve_tracked_tables(&entries, &targets).unwrap_err();
+        assert!(err.to_string().contains("'orders'"), "{err}");
+    }
+
     #[test]
     fn test_get_effective_type_for_array() {
         // PostgreSQL returns data_type='ARRAY' and udt_name='_text' for TEXT[]
@@ -1,11 +1,11 @@
 //! PostgreSQL change data structures and conversion from wal2json format
 
+use crate::from_wal2json::wal2json::is_unchanged_toast_datum;
 use anyhow::{bail, Context, Result};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
-use std::collections::HashMap;
 use std::fmt;
 use std::time::Duration;
-use surreal_sync_core::{Type, Value};
+use surreal_sync_core::{FieldMap, Type, Value};
 
 /// Represents a database row with primary key and column data
 #[derive(Debug, Clone)]
@@ -13,14 +13,39 @@ pub struct Row {
     /// Primary key value(s) - can be composite
     pub primary_key: Value,
     /// Map of column names to their values
-    pub columns: HashMap<String, Value>,
+    pub columns: FieldMap,
+    /// Old values of the replica identity columns of an update or delete
+    /// (the primary key by default, every column under
+    /// `REPLICA IDENTITY FULL`), when wal2json sent them
+    pub old_columns: Option<FieldMap>,
     /// Schema name
     pub schema: String,
     /// Table name
     pub table: String,
 }
 
-/// Represents a database action (Insert, Update, or Delete)
+impl Row {
+    /// The whole old row of an update, when the replica identity covers
+    /// every column (`REPLICA IDENTITY FULL`); `None` otherwise.
+    pub fn before_image(&self) -> Option<&FieldMap> {
+        self.old_columns
+            .as_ref()
+            .filter(|old| self.columns.keys().all(|name| old.contains_key(name)))
+    }
+
+    /// Names in `columns` this row carries no value for. wal2json leaves
+    /// unchanged TOAST values (and generated columns) out of an update, so
+    /// these must keep their stored values rather than be cleared; a column
+    /// set to `NULL` is sent with a `null` value and is not missing.
+    pub fn missing_columns<'a>(&self, columns: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+        columns
+            .into_iter()
+            .filter(|name| !self.columns.contains_key(*name))
+            .collect()
+    }
+}
+
+/// Represents a database action (Insert, Update, Delete, or Truncate)
 #[derive(Debug, Clone)]
 pub enum Action {
     /// Insert operation with new row data
@@ -29,6 +54,8 @@ pub enum Action {
     Update(Row),
     /// Delete operation with old row data
     Delete(Row),
+    /// Truncate of one table (wal2json emits one message per truncated table)
+    Truncate { schema: String, table: String },
     /// Begin transaction marker
     Begin {
         xid: String,
@@ -48,6 +75,7 @@ impl fmt::Display for Action {
             Action::Insert(_) => write!(f, "Insert"),
             Action::Update(_) => write!(f, "Update"),
             Action::Delete(_) => write!(f, "Delete"),
+            Action::Truncate { .. } => write!(f, "Truncate"),
             Action::Begin { .. } => write!(f, "Begin"),
             Action::Commit { .. } => write!(f, "Commit"),
         }
@@ -109,6 +137,19 @@ pub fn wal2json_to_psql(wal2json_value: &serde_json::Value) -> Result<Action> {
                 timestamp,
             })
         }
+        "T" => {
+            let schema = obj
+                .get("schema")
+                .and_then(|v| v.as_str())
+                .unwrap_or("public")
+                .to_string();
+            let table = obj
+                .get("table")
+                .and_then(|v| v.as_str())
+                .context("Truncate action missing 'table'")?
+                .to_string();
+            Ok(Action::Truncate { schema, table })
+        }
         "I" | "U" | "D" => {
             // Data modification actions
             let schema = obj
@@ -123,7 +164,7 @@ pub fn wal2json_to_psql(wal2json_value: &serde_json::Value) -> Result<Action> {
                 .context("Missing 'table' field")?
                 .to_string();
 
-            let mut columns = HashMap::new();
+            let mut columns = FieldMap::new();
             let mut primary_key_value = Value::Null;
 
             // DELETE actions might have different structure
@@ -169,6 +210,12 @@ pub fn wal2json_to_psql(wal2json_value: &serde_json::Value) -> Result<Action> {
 
                     let col_value = col_obj.get("value");
 
+                    // An unchanged TOAST value is left out, as when wal2json
+                    // omits it, so the stored value is kept.
+                    if action_str == "U" && is_unchanged_toast_datum(col_value) {
+                        continue;
+                    }
+
                     // Convert the value based on PostgreSQL type
                     let converted_value = convert_postgres_wal2json_value(col_value, col_type)?;
 
@@ -224,9 +271,26 @@ pub fn wal2json_to_psql(wal2json_value: &serde_json::Value) -> Result<Action> {
                 }
             }
 
+            let old_columns = match obj.get("identity").and_then(|v| v.as_array()) {
+                Some(identity) if action_str == "U" || action_str == "D" => {
+                    Some(convert_columns(identity)?)
+                }
+                _ => None,
+            };
+            // Under REPLICA IDENTITY FULL the old row still holds the values
+            // of unchanged TOAST columns the new row leaves out.
+            if let Some(old) = old_columns.as_ref().filter(|_| action_str == "U") {
+                for (name, value) in old {
+                    if !columns.contains_key(name) {
+                        columns.insert(name.clone(), value.clone());
+                    }
+                }
+            }
+
             let row = Row {
                 primary_key: primary_key_value,
                 columns,
+                old_columns,
                 schema,
                 table,
             };
@@ -242,6 +306,167 @@ pub fn wal2json_to_psql(wal2json_value: &serde_json::Value) -> Result<Action> {
     }
 }
 
+/// Converts a wal2json `columns` / `identity` array into a field map.
+fn convert_columns(columns: &[serde_json::Value]) -> Result<FieldMap> {
+    let mut fields = FieldMap::new();
+    for col in columns {
+        if is_unchanged_toast_datum(col.get("value")) {
+            continue;
+        }
+        let col_obj = col.as_object().context("Column entry must be an object")?;
+        let col_name = col_obj
+            .get("name")
+            .and_then(|v| v.as_str())
+            .context("Column missing 'name' field")?;
+        let col_type = col_obj
+            .get("type")
+            .and_then(|v| v.as_str())
+            .context("Column missing 'type' field")?;
+        fields.insert(
+            col_name.to_string(),
+            convert_postgres_wal2json_value(col_obj.get("value"), col_type)?,
+        );
+    }
+    Ok(fields)
+}
+
+/// Converts one wal2json `format-version` 1 message into Actions
+///
+/// A version 1 message holds a whole transaction: the transaction fields
+/// (`xid`, `nextlsn`, `timestamp`) at the top level and every change in a
+/// `change` array. The result is the same sequence version 2 streams as
+/// separate messages: `Begin`, the data changes in order, then `Commit`.
+/// `nextlsn` is only present when the slot is peeked with `include-lsn`.
+///
+/// # Arguments
+/// * `wal2json_value` - One parsed version 1 message
+///
+/// # Returns
+/// * `Result<Vec<Action>>` - The transaction as Actions, or an error
+pub fn wal2json_v1_to_psql(wal2json_value: &serde_json::Value) -> Result<Vec<Action>> {
+    let obj = wal2json_value
+        .as_object()
+        .context("wal2json value must be an object")?;
+
+    // Version 1 reports xid as a number (with `include-xids`)
+    let xid = match obj.get("xid") {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Number(n)) => n.to_string(),
+        _ => "unknown".to_string(),
+    };
+    let timestamp = obj
+        .get("timestamp")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+    let nextlsn = obj
+        .get("nextlsn")
+        .and_then(|v| v.as_str())
+        .context("wal2json v1 transaction missing 'nextlsn' (peek with 'include-lsn')")?
+        .to_string();
+    let changes = obj
+        .get("change")
+        .and_then(|v| v.as_array())
+        .context("wal2json v1 transaction missing 'change' array")?;
+
+    let mut actions = Vec::with_capacity(changes.len() + 2);
+    actions.push(Action::Begin {
+        xid: xid.clone(),
+        timestamp: timestamp.clone(),
+    });
+    for change in changes {
+        actions.push(wal2json_to_psql(&v1_change_to_v2(change)?)?);
+    }
+    actions.push(Action::Commit {
+        xid,
+        nextlsn,
+        timestamp,
+    });
+    Ok(actions)
+}
+
+/// Rewrites one entry of a version 1 `change` array in the version 2 shape
+/// [`wal2json_to_psql`] reads.
+fn v1_change_to_v2(change: &serde_json::Value) -> Result<serde_json::Value> {
+    let obj = change
+        .as_object()
+        .context("wal2json v1 change entry must be an object")?;
+
+    let kind = obj
+        .get("kind")
+        .and_then(|v| v.as_str())
+        .context("wal2json v1 change missing 'kind'")?;
+    let action = match kind {
+        "insert" => "I",
+        "update" => "U",
+        "delete" => "D",
+        "truncate" => "T",
+        _ => bail!("Unknown wal2json v1 change kind: {kind}"),
+    };
+
+    let mut out = serde_json::Map::new();
+    out.insert("action".to_string(), action.into());
+    for field in ["schema", "table"] {
+        if let Some(v) = obj.get(field) {
+            out.insert(field.to_string(), v.clone());
+        }
+    }
+    if obj.contains_key("columnnames") {
+        out.insert(
+            "columns".to_string(),
+            v1_columns(obj, "columnnames", "columntypes", Some("columnvalues"))?,
+        );
+    }
+    if let Some(oldkeys) = obj.get("oldkeys").and_then(|v| v.as_object()) {
+        out.insert(
+            "identity".to_string(),
+            v1_columns(oldkeys, "keynames", "keytypes", Some("keyvalues"))?,
+        );
+    }
+    if let Some(pk) = obj.get("pk").and_then(|v| v.as_object()) {
+        out.insert(
+            "pk".to_string(),
+            v1_columns(pk, "pknames", "pktypes", None)?,
+        );
+    }
+    Ok(serde_json::Value::Object(out))
+}
+
+/// Zips version 1 parallel name/type(/value) arrays into version 2
+/// `{name, type, value}` column objects.
+fn v1_columns(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    names_key: &str,
+    types_key: &str,
+    values_key: Option<&str>,
+) -> Result<serde_json::Value> {
+    let array = |key: &str| {
+        obj.get(key)
+            .and_then(|v| v.as_array())
+            .with_context(|| format!("wal2json v1 change missing '{key}' array"))
+    };
+    let names = array(names_key)?;
+    let types = array(types_key)?;
+    let values = values_key.map(array).transpose()?;
+    if types.len() != names.len() || values.is_some_and(|v| v.len() != names.len()) {
+        bail!("wal2json v1 '{names_key}' and its type/value arrays differ in length");
+    }
+
+    let columns = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let mut column = serde_json::Map::new();
+            column.insert("name".to_string(), name.clone());
+            column.insert("type".to_string(), types[i].clone());
+            if let Some(values) = values {
+                column.insert("value".to_string(), values[i].clone());
+            }
+            serde_json::Value::Object(column)
+        })
+        .collect();
+    Ok(serde_json::Value::Array(columns))
+}
+
 /// Converts a PostgreSQL value from wal2json format to Value
 fn convert_postgres_wal2json_value(
     value: Option<&serde_json::Value>,
@@ -920,6 +1145,360 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_truncate_action() {
+        let wal2json = json!({
+            "action": "T",
+            "lsn": "0/16B3748",
+            "schema": "sales",
+            "table": "orders"
+        });
+
+        match wal2json_to_psql(&wal2json).unwrap() {
+            Action::Truncate { schema, table } => {
+                assert_eq!(schema, "sales");
+                assert_eq!(table, "orders");
+            }
+            other => panic!("Expected Truncate action, got {other}"),
+        }
+        assert!(wal2json_to_psql(&json!({"action": "T"})).is_err());
+    }
+
+    #[test]
+    fn test_convert_v2_update_and_delete_actions() {
+        // Messages as peeked with 'format-version' '2', 'include-pk' 'true'
+        let update = json!({
+            "action": "U",
+            "lsn": "0/16B3850",
+            "schema": "public",
+            "table": "users",
+            "columns": [
+                {"name": "id", "type": "integer", "value": 7},
+                {"name": "name", "type": "text", "value": "Bob"}
+            ],
+            "identity": [
+                {"name": "id", "type": "integer", "value": 7}
+            ],
+            "pk": [
+                {"name": "id", "type": "integer"}
+            ]
+        });
+        match wal2json_to_psql(&update).unwrap() {
+            Action::Update(row) => {
+                assert_eq!(row.primary_key, Value::Int32(7));
+                assert_eq!(
+                    row.columns.get("name"),
+                    Some(&Value::Text("Bob".to_string()))
+                );
+            }
+            other => panic!("Expected Update action, got {other}"),
+        }
+
+        let delete = json!({
+            "action": "D",
+            "lsn": "0/16B3900",
+            "schema": "public",
+            "table": "order_items",
+            "identity": [
+                {"name": "order_id", "type": "integer", "value": 3},
+                {"name": "line", "type": "smallint", "value": 2}
+            ],
+            "pk": [
+                {"name": "order_id", "type": "integer"},
+                {"name": "line", "type": "smallint"}
+            ]
+        });
+        match wal2json_to_psql(&delete).unwrap() {
+            Action::Delete(row) => {
+                assert_eq!(row.table, "order_items");
+                assert_eq!(
+                    row.primary_key,
+                    Value::Array {
+                        elements: vec![Value::Int32(3), Value::Int16(2)],
+                        element_type: Box::new(Type::Text),
+                    }
+                );
+            }
+            other => panic!("Expected Delete action, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_delete_old_values_by_replica_identity() {
+        // REPLICA IDENTITY DEFAULT: only the primary key of the old row
+        let default = json!({
+            "action": "D",
+            "schema": "public",
+            "table": "users",
+            "identity": [
+                {"name": "id", "type": "integer", "value": 7}
+            ],
+            "pk": [
+                {"name": "id", "type": "integer"}
+            ]
+        });
+        match wal2json_to_psql(&default).unwrap() {
+            Action::Delete(row) => {
+                let old = row.old_columns.expect("old key");
+                assert_eq!(old.len(), 1);
+                assert_eq!(old.get("id"), Some(&Value::Int32(7)));
+            }
+            other => panic!("Expected Delete action, got {other}"),
+        }
+
+        // REPLICA IDENTITY FULL: every column of the old row
+        let full = json!({
+            "action": "D",
+            "schema": "public",
+            "table": "users",
+            "identity": [
+                {"name": "id", "type": "integer", "value": 7},
+                {"name": "name", "type": "text", "value": "Bob"},
+                {"name": "email", "type": "text", "value": null}
+            ],
+            "pk": [
+                {"name": "id", "type": "integer"}
+            ]
+        });
+        match wal2json_to_psql(&full).unwrap() {
+            Action::Delete(row) => {
+                assert_eq!(row.primary_key, Value::Int32(7));
+                let old = row.old_columns.expect("old row");
+                assert_eq!(old.get("name"), Some(&Value::Text("Bob".to_string())));
+                assert_eq!(old.get("email"), Some(&Value::Null));
+            }
+            other => panic!("Expected Delete action, got {other}"),
+        }
+
+        // Version 1 sends the same values as `oldkeys`
+        let v1 = json!({
+            "xid": 772,
+            "nextlsn": "0/16B3B20",
+            "change": [{
+                "kind": "delete",
+                "schema": "public",
+                "table": "users",
+                "oldkeys": {
+                    "keynames": ["id", "name"],
+                    "keytypes": ["integer", "text"],
+                    "keyvalues": [7, "Bob"]
+                }
+            }]
+        });
+        let actions = wal2json_v1_to_psql(&v1).unwrap();
+        match &actions[1] {
+            Action::Delete(row) => {
+                let old = row.old_columns.as_ref().expect("old row");
+                assert_eq!(old.get("name"), Some(&Value::Text("Bob".to_string())));
+            }
+            other => panic!("Expected Delete action, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_update_keeps_full_replica_identity() {
+        // A table without a primary key under REPLICA IDENTITY FULL: no `pk`,
+        // and `identity` holds the whole old row.
+        let update = json!({
+            "action": "U",
+            "schema": "public",
+            "table": "events",
+            "columns": [
+                {"name": "kind", "type": "text", "value": "click"},
+                {"name": "count", "type": "integer", "value": 2}
+            ],
+            "identity": [
+                {"name": "kind", "type": "text", "value": "click"},
+                {"name": "count", "type": "integer", "value": 1}
+            ]
+        });
+        match wal2json_to_psql(&update).unwrap() {
+            Action::Update(row) => {
+                assert_eq!(row.primary_key, Value::Null);
+                let old = row.old_columns.expect("old row");
+                assert_eq!(old.get("count"), Some(&Value::Int32(1)));
+                assert_eq!(old.get("kind"), Some(&Value::Text("click".into())));
+                assert_eq!(row.columns.get("count"), Some(&Value::Int32(2)));
+            }
+            other => panic!("Expected Update action, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_update_distinguishes_unchanged_toast_from_null() {
+        // `body` is an unchanged TOAST value and is left out; `note` was set
+        // to NULL and is sent with a null value.
+        let update = json!({
+            "action": "U",
+            "schema": "public",
+            "table": "posts",
+            "columns": [
+                {"name": "id", "type": "integer", "value": 1},
+                {"name": "note", "type": "text", "value": null}
+            ],
+            "identity": [
+                {"name": "id", "type": "integer", "value": 1}
+            ],
+            "pk": [
+                {"name": "id", "type": "integer"}
+            ]
+        });
+        match wal2json_to_psql(&update).unwrap() {
+            Action::Update(row) => {
+                assert_eq!(row.columns.get("note"), Some(&Value::Null));
+                assert_eq!(row.missing_columns(["id", "note", "body"]), vec!["body"]);
+            }
+            other => panic!("Expected Update action, got {other}"),
+        }
+
+        // Under REPLICA IDENTITY FULL the old row supplies the omitted value.
+        let full = json!({
+            "action": "U",
+            "schema": "public",
+            "table": "posts",
+            "columns": [
+                {"name": "id", "type": "integer", "value": 1},
+                {"name": "note", "type": "text", "value": null}
+            ],
+            "identity": [
+                {"name": "id", "type": "integer", "value": 1},
+                {"name": "note", "type": "text", "value": "old"},
+                {"name": "body", "type": "text", "value": "long body"}
+            ],
+            "pk": [
+                {"name": "id", "type": "integer"}
+            ]
+        });
+        match wal2json_to_psql(&full).unwrap() {
+            Action::Update(row) => {
+                assert_eq!(row.columns.get("note"), Some(&Value::Null));
+                assert_eq!(
+                    row.columns.get("body"),
+                    Some(&Value::Text("long body".into()))
+                );
+                assert!(row.missing_columns(["id", "note", "body"]).is_empty());
+            }
+            other => panic!("Expected Update action, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_update_skips_unchanged_toast_placeholder() {
+        // With `include-unchanged-toast`, wal2json sends a placeholder for the
+        // unchanged `body` instead of leaving it out.
+        let update = json!({
+            "action": "U",
+            "schema": "public",
+            "table": "posts",
+            "columns": [
+                {"name": "id", "type": "integer", "value": 1},
+                {"name": "title", "type": "text", "value": "renamed"},
+                {"name": "body", "type": "text", "value": "unchanged-toast-datum"}
+            ],
+            "identity": [
+                {"name": "id", "type": "integer", "value": 1},
+                {"name": "title", "type": "text", "value": "draft"},
+                {"name": "body", "type": "text", "value": "unchanged-toast-datum"}
+            ],
+            "pk": [
+                {"name": "id", "type": "integer"}
+            ]
+        });
+        match wal2json_to_psql(&update).unwrap() {
+            Action::Update(row) => {
+                assert_eq!(
+                    row.columns.get("title"),
+                    Some(&Value::Text("renamed".into()))
+                );
+                assert_eq!(row.missing_columns(["id", "title", "body"]), vec!["body"]);
+                assert!(row
+                    .before_image()
+                    .is_some_and(|old| !old.contains_key("body")));
+            }
+            other => panic!("Expected Update action, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_v1_transaction() {
+        // One message as peeked with 'format-version' '1', 'include-lsn' 'true',
+        // 'include-pk' 'true', 'include-xids' 'true'
+        let wal2json = json!({
+            "xid": 771,
+            "nextlsn": "0/16B3A10",
+            "timestamp": "2024-05-01 10:00:00.123456+00",
+            "change": [
+                {
+                    "kind": "insert",
+                    "schema": "public",
+                    "table": "users",
+                    "columnnames": ["id", "name"],
+                    "columntypes": ["integer", "text"],
+                    "columnvalues": [1, "Alice"],
+                    "pk": {"pknames": ["id"], "pktypes": ["integer"]}
+                },
+                {
+                    "kind": "update",
+                    "schema": "public",
+                    "table": "users",
+                    "columnnames": ["id", "name"],
+                    "columntypes": ["integer", "text"],
+                    "columnvalues": [1, "Alicia"],
+                    "pk": {"pknames": ["id"], "pktypes": ["integer"]},
+                    "oldkeys": {"keynames": ["id"], "keytypes": ["integer"], "keyvalues": [1]}
+                },
+                {
+                    "kind": "delete",
+                    "schema": "public",
+                    "table": "users",
+                    "pk": {"pknames": ["id"], "pktypes": ["integer"]},
+                    "oldkeys": {"keynames": ["id"], "keytypes": ["integer"], "keyvalues": [2]}
+                }
+            ]
+        });
+
+        let actions = wal2json_v1_to_psql(&wal2json).unwrap();
+        let kinds: Vec<String> = actions.iter().map(ToString::to_string).collect();
+        assert_eq!(kinds, ["Begin", "Insert", "Update", "Delete", "Commit"]);
+
+        match &actions[0] {
+            Action::Begin { xid, .. } => assert_eq!(xid, "771"),
+            other => panic!("Expected Begin action, got {other}"),
+        }
+        match &actions[2] {
+            Action::Update(row) => {
+                assert_eq!(row.primary_key, Value::Int32(1));
+                assert_eq!(
+                    row.columns.get("name"),
+                    Some(&Value::Text("Alicia".to_string()))
+                );
+            }
+            other => panic!("Expected Update action, got {other}"),
+        }
+        match &actions[3] {
+            Action::Delete(row) => assert_eq!(row.primary_key, Value::Int32(2)),
+            other => panic!("Expected Delete action, got {other}"),
+        }
+        match &actions[4] {
+            Action::Commit { nextlsn, .. } => assert_eq!(nextlsn, "0/16B3A10"),
+            other => panic!("Expected Commit action, got {other}"),
+        }
+
+        // Without 'include-lsn' there is no position to advance the slot to
+        assert!(wal2json_v1_to_psql(&json!({"xid": 1, "change": []})).is_err());
+        let mismatched = json!({
+            "nextlsn": "0/1",
+            "change": [{
+                "kind": "insert",
+                "table": "users",
+                "columnnames": ["id", "name"],
+                "columntypes": ["integer"],
+                "columnvalues": [1, "x"]
+            }]
+        });
+        assert!(wal2json_v1_to_psql(&mismatched).is_err());
+    }
+
     #[test]
     fn test_convert_array_types() {
         let wal2json = json!({
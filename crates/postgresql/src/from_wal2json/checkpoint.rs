@@ -6,6 +6,9 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use surreal_sync_core::CheckpointValidity;
+
+use super::Lsn;
 
 /// PostgreSQL logical replication checkpoint containing LSN and timestamp
 ///
@@ -65,6 +68,69 @@ pub async fn get_current_checkpoint(
     })
 }
 
+/// Check whether incremental sync can still resume from `checkpoint` on
+/// `slot_name`.
+///
+/// Logical decoding only replays changes after the slot's
+/// `confirmed_flush_lsn`, so a checkpoint behind it has a gap that can never
+/// be re-read. A dropped slot, or one whose WAL was removed
+/// (`wal_status = 'lost'`), cannot resume at all.
+pub async fn validate_checkpoint(
+    client: &tokio_postgres::Client,
+    slot_name: &str,
+    checkpoint: &PostgreSQLLogicalCheckpoint,
+) -> Result<CheckpointValidity> {
+    // `wal_status` only exists on PostgreSQL 13+; reading it through jsonb
+    // yields NULL on older servers instead of failing the query.
+    let rows = client
+        .query(
+            "SELECT confirmed_flush_lsn::text, to_jsonb(s) ->> 'wal_status' \
+             FROM pg_replication_slots s WHERE slot_name = $1",
+            &[&slot_name],
+        )
+        .await?;
+    let Some(row) = rows.first() else {
+        return Ok(CheckpointValidity::too_old(format!(
+            "replication slot '{slot_name}' does not exist, so WAL after the checkpoint was not retained"
+        )));
+    };
+    let confirmed_flush_lsn: Option<String> = row.try_get(0)?;
+    let wal_status: Option<String> = row.try_get(1)?;
+    slot_validity(
+        slot_name,
+        &checkpoint.lsn,
+        confirmed_flush_lsn.as_deref(),
+        wal_status.as_deref(),
+    )
+}
+
+fn slot_validity(
+    slot_name: &str,
+    checkpoint_lsn: &str,
+    confirmed_flush_lsn: Option<&str>,
+    wal_status: Option<&str>,
+) -> Result<CheckpointValidity> {
+    if wal_status == Some("lost") {
+        return Ok(CheckpointValidity::too_old(format!(
+            "replication slot '{slot_name}' has lost required WAL (wal_status = lost)"
+        )));
+    }
+    // "0/0" resumes from wherever the slot is, like incremental sync does.
+    if checkpoint_lsn.is_empty() || checkpoint_lsn == "0/0" {
+        return Ok(CheckpointValidity::Valid);
+    }
+    let Some(confirmed) = confirmed_flush_lsn else {
+        return Ok(CheckpointValidity::Valid);
+    };
+    if Lsn::parse(checkpoint_lsn)? < Lsn::parse(confirmed)? {
+        return Ok(CheckpointValidity::too_old(format!(
+            "checkpoint LSN {checkpoint_lsn} is behind the confirmed flush LSN {confirmed} of \
+             replication slot '{slot_name}'; changes in between are no longer retained"
+        )));
+    }
+    Ok(CheckpointValidity::Valid)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,4 +248,29 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("type mismatch"));
     }
+
+    #[test]
+    fn test_slot_validity_compares_lsns_numerically() {
+        // "0/A0" is lexicographically greater than "0/1000" but numerically smaller.
+        let stale = slot_validity("s", "0/A0", Some("0/1000"), Some("reserved")).unwrap();
+        assert!(!stale.is_valid());
+        assert!(stale
+            .to_string()
+            .contains("behind the confirmed flush LSN 0/1000"));
+
+        let ok = slot_validity("s", "0/1000", Some("0/1000"), Some("reserved")).unwrap();
+        assert_eq!(ok, CheckpointValidity::Valid);
+        let ahead = slot_validity("s", "1/0", Some("0/FFFF"), None).unwrap();
+        assert_eq!(ahead, CheckpointValidity::Valid);
+    }
+
+    #[test]
+    fn test_slot_validity_lost_wal_and_unset_positions() {
+        let lost = slot_validity("s", "0/0", Some("0/10"), Some("lost")).unwrap();
+        assert!(lost.to_string().contains("wal_status = lost"));
+        assert!(slot_validity("s", "0/0", Some("0/10"), None)
+            .unwrap()
+            .is_valid());
+        assert!(slot_validity("s", "0/10", None, None).unwrap().is_valid());
+    }
 }
@@ -14,6 +14,12 @@ pub struct Config {
 
     /// Replication slot name for logical replication (default: "surreal_sync_slot")
     pub slot: String,
+
+    /// Publication scoping which tables stream (default: none, all tables)
+    pub publication: Option<String>,
+
+    /// Create `publication` for `tables` when it does not exist (default: false)
+    pub create_publication: bool,
 }
 
 impl Config {
@@ -33,6 +39,8 @@ impl Config {
             tables: Vec::new(),
             schema: "public".to_string(),
             slot,
+            publication: None,
+            create_publication: false,
         })
     }
 
@@ -53,6 +61,8 @@ impl Default for Config {
             tables: Vec::new(),
             schema: "public".to_string(),
             slot: "surreal_sync_slot".to_string(),
+            publication: None,
+            create_publication: false,
         }
     }
 }
@@ -79,6 +89,8 @@ mod tests {
         assert_eq!(config.slot, "test_slot");
         assert_eq!(config.schema, "public");
         assert!(config.tables.is_empty());
+        assert!(config.publication.is_none());
+        assert!(!config.create_publication);
     }
 
     #[test]
@@ -95,6 +107,8 @@ mod tests {
         assert_eq!(config.schema, "public");
         assert_eq!(config.slot, "surreal_sync_slot");
         assert!(config.tables.is_empty());
+        assert!(config.publication.is_none());
+        assert!(!config.create_publication);
     }
 
     #[test]
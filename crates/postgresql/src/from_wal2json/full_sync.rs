@@ -23,9 +23,48 @@ pub struct SourceOpts {
     pub tables: Vec<String>,
     /// PostgreSQL schema (default: public)
     pub schema: String,
+    /// Publication whose tables are streamed (wal2json `add-tables`). `None`
+    /// streams every table matching `tables`.
+    pub publication_name: Option<String>,
+    /// Create `publication_name` for `tables` when it does not exist.
+    pub create_publication: bool,
     /// Tables to force-classify as relation (join) tables for SurrealDB RELATE.
     /// When empty (default), auto-detection is used based on FK/PK heuristics.
     pub relation_tables: Vec<String>,
+    /// Per-table keyset sort columns for chunked full-sync reads, e.g. a
+    /// composite `(tenant_id, created_at)` key. Overrides the primary key as
+    /// the pagination order and avoids OFFSET scans on tables without one.
+    pub keyset_columns: surreal_sync_core::KeysetColumns,
+    /// Per-table read parallelism for full sync: the table is split into this
+    /// many key ranges of its leading (integer or uuid) key column, read
+    /// concurrently on separate connections. Tables not listed use one reader.
+    pub parallel_reads: surreal_sync_core::ParallelReads,
+    /// Per-table column include/exclude lists. Keyset reads select only the
+    /// kept columns; key columns are always read for ids and cursors.
+    pub column_filters: surreal_sync_core::ColumnFilters,
+    /// Apply source `TRUNCATE`s during incremental sync by deleting every
+    /// record of the corresponding SurrealDB table. Off by default because a
+    /// full-table delete is destructive; when off, truncations are logged
+    /// and skipped.
+    pub apply_truncate: bool,
+    /// wal2json `format-version` the slot is peeked with (default: 2); see
+    /// [`Client::set_format_version`](crate::from_wal2json::Client::set_format_version).
+    pub format_version: crate::from_wal2json::Wal2jsonFormat,
+}
+
+impl SourceOpts {
+    /// The connection and replication [`Config`](crate::from_wal2json::Config)
+    /// these options describe. Sync paths connect and bind the publication
+    /// from it.
+    pub fn config(&self) -> Result<crate::from_wal2json::Config> {
+        let mut config =
+            crate::from_wal2json::Config::new(&self.connection_string, self.slot_name.clone())?;
+        config.tables = self.tables.clone();
+        config.schema = self.schema.clone();
+        config.publication = self.publication_name.clone();
+        config.create_publication = self.create_publication;
+        Ok(config)
+    }
 }
 
 /// Run full sync from PostgreSQL to SurrealDB with checkpoint support (identity transforms).
@@ -70,14 +109,17 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
         );
     }
 
-    let (client, connection) = tokio_postgres::connect(&from_opts.connection_string, NoTls).await?;
+    let config = from_opts.config()?;
+    let (client, connection) = config.postgres_config().connect(NoTls).await?;
     tokio::spawn(async move {
         if let Err(e) = connection.await {
             eprintln!("PostgreSQL connection error: {e}");
         }
     });
 
-    let pg_client = crate::from_wal2json::Client::new(client, from_opts.tables.clone());
+    let mut pg_client = crate::from_wal2json::Client::new(client, config.tables.clone());
+    pg_client.set_format_version(from_opts.format_version);
+    let publication = pg_client.bind_configured_publication(&config).await?;
     pg_client.create_slot(&from_opts.slot_name).await?;
 
     if let Some(manager) = sync_manager {
@@ -91,10 +133,12 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
         );
     }
 
-    let tables = if from_opts.tables.is_empty() {
-        crate::get_user_tables(pg_client.pg_client(), &from_opts.schema).await?
-    } else {
+    let tables = if !from_opts.tables.is_empty() {
         from_opts.tables.clone()
+    } else if let Some(publication) = &publication {
+        publication.tables_in(&from_opts.schema)
+    } else {
+        crate::get_schema_tables(pg_client.pg_client(), &from_opts.schema).await?
     };
 
     info!("Found {} tables to migrate", tables.len());
@@ -113,6 +157,16 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
             &sync_opts,
             Some(&db_schema),
             &from_opts.relation_tables,
+            &from_opts.keyset_columns,
+            &from_opts.column_filters,
+            ParallelRead {
+                source_uri: &from_opts.connection_string,
+                degree: from_opts
+                    .parallel_reads
+                    .get(table_name)
+                    .copied()
+                    .unwrap_or(1),
+            },
             pipeline,
             apply_opts,
         )
@@ -137,6 +191,12 @@ pub async fn run_full_sync_with_transforms<S: SurrealSink, CS: CheckpointStore>(
     Ok(())
 }
 
+/// Connection and degree for intra-table parallel reads of one table.
+struct ParallelRead<'a> {
+    source_uri: &'a str,
+    degree: usize,
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
     client: &tokio_postgres::Client,
@@ -145,16 +205,20 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
     sync_opts: &crate::SyncOpts,
     schema: Option<&surreal_sync_core::DatabaseSchema>,
     relation_overrides: &[String],
+    keyset_columns: &surreal_sync_core::KeysetColumns,
+    column_filters: &surreal_sync_core::ColumnFilters,
+    parallel: ParallelRead<'_>,
     pipeline: &surreal_sync_runtime::Pipeline,
     apply_opts: &surreal_sync_runtime::ApplyOpts,
 ) -> anyhow::Result<usize> {
     use crate::{
-        get_primary_key_columns, read_offset_relation_chunk, read_offset_table_chunk,
-        read_relation_chunk, read_table_chunk,
+        get_keyset_columns, get_primary_key_columns, get_read_columns, parallel_range_chunks,
+        read_offset_relation_chunk, read_offset_table_chunk, read_relation_chunk,
+        read_table_chunk_by_key, ReadColumns,
     };
     use async_trait::async_trait;
     use std::sync::Arc;
-    use surreal_sync_core::{classify_table, Relation, Row, TableKind, Value};
+    use surreal_sync_core::{classify_table, KeysetColumn, Relation, Row, TableKind, Value};
     use surreal_sync_runtime::{
         run_source_runtime_with, RelationChunkDriver, RelationChunkSource, RowChunkDriver,
         RowChunkSource, SourceRuntimeOpts,
@@ -341,7 +405,14 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         return Ok(driver.sunk_count() as usize);
     }
 
-    if pk_columns.is_empty() {
+    // A configured sort key takes precedence over the primary key, and lets
+    // tables without a primary key avoid OFFSET scans.
+    let key = match keyset_columns.get(table_name) {
+        Some(columns) => get_keyset_columns(client, table_name, columns).await?,
+        None => pk_columns.iter().map(KeysetColumn::new).collect(),
+    };
+
+    if key.is_empty() {
         tracing::warn!(
             "Table '{table_name}' has no primary key; streaming via OFFSET/LIMIT chunks \
              (ORDER BY ctid). Unsafe under concurrent source writes — prefer a PK \
@@ -428,17 +499,25 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         return Ok(driver.sunk_count() as usize);
     }
 
+    // Column filters narrow the SELECT; key columns are always read.
+    let mut read_key = pk_columns.clone();
+    read_key.extend(key.iter().map(|c| c.name.clone()));
+    let columns = get_read_columns(client, table_name, column_filters, &read_key).await?;
+
     if sync_opts.dry_run {
         let mut total = 0usize;
         let mut after: Option<Vec<surreal_sync_core::Value>> = None;
         loop {
-            let chunk = read_table_chunk(
+            let chunk = read_table_chunk_by_key(
                 client,
                 table_name,
                 &pk_columns,
+                &key,
                 after.as_deref(),
                 batch_size,
+                total as u64,
                 schema,
+                columns.as_ref(),
             )
             .await?;
             if chunk.rows.is_empty() {
@@ -454,13 +533,41 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         return Ok(total);
     }
 
+    if let Some(ranges) = parallel_range_chunks(
+        client,
+        parallel.source_uri,
+        table_name,
+        &pk_columns,
+        &key,
+        parallel.degree,
+        batch_size,
+        schema,
+        columns.as_ref(),
+    )
+    .await?
+    {
+        let mut driver = RowChunkDriver::new(ranges);
+        run_source_runtime_with(
+            &mut driver,
+            surreal,
+            Arc::new(pipeline.clone()),
+            apply_opts,
+            &SourceRuntimeOpts::new(),
+        )
+        .await?;
+        return Ok(driver.sunk_count() as usize);
+    }
+
     struct PgKeysetChunks<'a> {
         client: &'a tokio_postgres::Client,
         table_name: &'a str,
         pk_columns: &'a [String],
+        key: &'a [KeysetColumn],
         after: Option<Vec<surreal_sync_core::Value>>,
         batch_size: usize,
+        base: u64,
         schema: Option<&'a surreal_sync_core::DatabaseSchema>,
+        columns: Option<&'a ReadColumns>,
         exhausted: bool,
     }
 
@@ -470,13 +577,16 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
             if self.exhausted {
                 return Ok(None);
             }
-            let chunk = read_table_chunk(
+            let chunk = read_table_chunk_by_key(
                 self.client,
                 self.table_name,
                 self.pk_columns,
+                self.key,
                 self.after.as_deref(),
                 self.batch_size,
+                self.base,
                 self.schema,
+                self.columns,
             )
             .await?;
             if chunk.rows.is_empty() {
@@ -484,6 +594,7 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
                 return Ok(None);
             }
             let n = chunk.rows.len();
+            self.base += n as u64;
             self.after = chunk.last_pk;
             if n < self.batch_size {
                 self.exhausted = true;
@@ -496,9 +607,12 @@ async fn migrate_one_table_keyset<S: surreal_sync_core::SurrealSink>(
         client,
         table_name,
         pk_columns: &pk_columns,
+        key: &key,
         after: None,
         batch_size,
+        base: 0,
         schema,
+        columns: columns.as_ref(),
         exhausted: false,
     };
     let mut driver = RowChunkDriver::new(chunks);
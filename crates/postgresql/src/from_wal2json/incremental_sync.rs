@@ -1,13 +1,15 @@
 //! PostgreSQL wal2json incremental sync via SourceDriver.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{
-    classify_table, Change, ChangeOp, DatabaseSchema, RelationChange, TableKind,
+    classify_table, Change, ChangeOp, ColumnDefinition, DatabaseSchema, FieldMap, RelationChange,
+    TableDefinition, TableKind,
 };
 use surreal_sync_core::{CheckpointID, CheckpointStore};
 use surreal_sync_runtime::{
@@ -133,7 +135,8 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
         );
     }
 
-    let (client, connection) = tokio_postgres::connect(&from_opts.connection_string, NoTls).await?;
+    let config = from_opts.config()?;
+    let (client, connection) = config.postgres_config().connect(NoTls).await?;
     tokio::spawn(async move {
         if let Err(e) = connection.await {
             error!("PostgreSQL connection error: {e}");
@@ -142,8 +145,19 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
 
     let db_schema = crate::schema::collect_database_schema_with_fks(&client).await?;
     let relation_table_overrides = from_opts.relation_tables.clone();
+    if from_opts.apply_truncate {
+        warn!("Source TRUNCATEs will delete every record of the matching SurrealDB table");
+    }
 
-    let pg_client = crate::from_wal2json::Client::new(client, from_opts.tables.clone());
+    let mut pg_client = crate::from_wal2json::Client::new(client, config.tables.clone());
+    pg_client.set_format_version(from_opts.format_version);
+    pg_client.bind_configured_publication(&config).await?;
+    let pkless_tables = pg_client
+        .tables_without_primary_key(&from_opts.schema)
+        .await?;
+    if !pkless_tables.is_empty() {
+        info!("Tables without a primary key, matched by every column value: {pkless_tables:?}");
+    }
     pg_client.create_slot(&from_opts.slot_name).await?;
     let slot = pg_client
         .start_replication(Some(&from_opts.slot_name))
@@ -163,6 +177,8 @@ pub async fn run_incremental_sync_with_transforms<S: SurrealSink>(
         slot,
         db_schema,
         relation_table_overrides,
+        pkless_tables,
+        apply_truncate: from_opts.apply_truncate,
         options: &options,
         until_reached: false,
         returned_since_advance: 0,
@@ -216,6 +232,11 @@ struct Wal2JsonSourceDriver<'a> {
     slot: Slot,
     db_schema: DatabaseSchema,
     relation_table_overrides: Vec<String>,
+    /// Tables without a primary key (REPLICA IDENTITY FULL), whose record ids
+    /// are synthesized from every column value.
+    pkless_tables: HashSet<String>,
+    /// Emit source `TRUNCATE`s as table-wide deletes (otherwise skipped).
+    apply_truncate: bool,
     options: &'a ReplicationTailOptions,
     until_reached: bool,
     /// Changes already converted/emitted from the current unadvanced peek prefix.
@@ -231,7 +252,7 @@ struct Wal2JsonSourceDriver<'a> {
 
 impl Wal2JsonSourceDriver<'_> {
     fn convert_actions(
-        &self,
+        &mut self,
         changes: &[ChangeAtLsn],
         nextlsn: Lsn,
     ) -> Result<Vec<PositionedEvent<Lsn>>> {
@@ -259,16 +280,45 @@ impl Wal2JsonSourceDriver<'_> {
                     );
                     (row, ChangeOp::Delete)
                 }
+                crate::from_wal2json::Action::Truncate { schema, table } => {
+                    if self.apply_truncate {
+                        info!("TRUNCATE: deleting all records of table {table}");
+                        out.push(PositionedEvent::truncate(table.clone(), nextlsn));
+                    } else {
+                        warn!(
+                            "Skipping TRUNCATE of {schema}.{table}; SurrealDB keeps its records \
+                             (enable apply_truncate to mirror truncations)"
+                        );
+                    }
+                    continue;
+                }
                 crate::from_wal2json::Action::Begin { .. }
                 | crate::from_wal2json::Action::Commit { .. } => continue,
             };
-            out.push(action_to_positioned_event(
-                row,
-                op,
-                nextlsn,
-                &self.db_schema,
-                &self.relation_table_overrides,
-            )?);
+            if op != ChangeOp::Delete {
+                let added = learn_added_columns(&mut self.db_schema, row);
+                if !added.is_empty() {
+                    info!(
+                        "Columns {added:?} were added to '{}' at the source; \
+                         adding them to the schema",
+                        row.table
+                    );
+                }
+            }
+            let rows = if self.pkless_tables.contains(&row.table) {
+                pkless_changes(row, op)?
+            } else {
+                vec![(row.clone(), op)]
+            };
+            for (row, op) in rows {
+                out.push(action_to_positioned_event(
+                    &row,
+                    op,
+                    nextlsn,
+                    &self.db_schema,
+                    &self.relation_table_overrides,
+                )?);
+            }
         }
         Ok(out)
     }
@@ -321,6 +371,10 @@ impl Wal2JsonSourceDriver<'_> {
 impl SourceDriver for Wal2JsonSourceDriver<'_> {
     type Position = Lsn;
 
+    fn describe_position(&self, position: &Self::Position) -> Option<String> {
+        Some(format!("{position}"))
+    }
+
     async fn poll_work(&mut self) -> Result<Vec<PositionedEvent<Self::Position>>> {
         if self.stop_reason().is_some() {
             return Ok(Vec::new());
@@ -441,14 +495,105 @@ impl SourceDriver for Wal2JsonSourceDriver<'_> {
     }
 }
 
-/// Convert a Row to Change
-fn row_to_change(row: &crate::from_wal2json::Row, op: ChangeOp) -> Change {
+/// Changes for a row of a table without a primary key, addressed by ids
+/// synthesized from every column value.
+///
+/// Inserts use the new row and deletes the old row (wal2json sends the full
+/// replica identity as the delete's columns). An update that changes any
+/// value moves the record: the old row's record is deleted and the new row's
+/// written.
+fn pkless_changes(
+    row: &crate::from_wal2json::Row,
+    op: ChangeOp,
+) -> Result<Vec<(crate::from_wal2json::Row, ChangeOp)>> {
+    let missing_identity = || {
+        anyhow!(
+            "{op:?} on table '{}' without a primary key carries no old row; \
+             set REPLICA IDENTITY FULL on the table",
+            row.table
+        )
+    };
+    let with_id = |columns: &FieldMap| crate::from_wal2json::Row {
+        primary_key: crate::synthesized_row_id(columns),
+        columns: columns.clone(),
+        old_columns: None,
+        schema: row.schema.clone(),
+        table: row.table.clone(),
+    };
+    match op {
+        ChangeOp::Update => {
+            let old = row.old_columns.as_ref().ok_or_else(missing_identity)?;
+            let (old, new) = (with_id(old), with_id(&row.columns));
+            if old.primary_key == new.primary_key {
+                Ok(vec![(new, op)])
+            } else {
+                Ok(vec![(old, ChangeOp::Delete), (new, op)])
+            }
+        }
+        ChangeOp::Delete if row.columns.is_empty() => Err(missing_identity()),
+        _ => Ok(vec![(with_id(&row.columns), op)]),
+    }
+}
+
+/// Add the columns `row` carries that its table's schema lacks, typed from
+/// their values, and return their names.
+///
+/// wal2json streams no DDL, but every insert and update names its columns, so
+/// a column added at the source shows up on the first row written after the
+/// `ALTER TABLE`. A column whose value is `NULL` has no type yet and waits for
+/// a row that sets it. Tables outside the schema are left alone.
+fn learn_added_columns(
+    db_schema: &mut DatabaseSchema,
+    row: &crate::from_wal2json::Row,
+) -> Vec<String> {
+    let Some(table_def) = db_schema.get_table_mut(&row.table) else {
+        return Vec::new();
+    };
+    let mut added = Vec::new();
+    for (name, value) in &row.columns {
+        if value.is_null() || table_def.get_column(name).is_some() {
+            continue;
+        }
+        table_def
+            .columns
+            .push(ColumnDefinition::nullable(name.clone(), value.to_type()));
+        added.push(name.clone());
+    }
+    added
+}
+
+/// Convert a Row to Change. An update missing some of `table_def`'s columns
+/// (unchanged TOAST values) becomes a partial change, so the sink keeps the
+/// stored values of those columns.
+fn row_to_change(
+    row: &crate::from_wal2json::Row,
+    op: ChangeOp,
+    table_def: Option<&TableDefinition>,
+) -> Change {
     let data = if op == ChangeOp::Delete {
         None
     } else {
         Some(row.columns.clone())
     };
-    Change::new(op, row.table.clone(), row.primary_key.clone(), data)
+    let mut change = Change::new(op, row.table.clone(), row.primary_key.clone(), data);
+    if let (ChangeOp::Update, Some(td)) = (op, table_def) {
+        let missing = row.missing_columns(td.column_names());
+        if !missing.is_empty() {
+            debug!(
+                "UPDATE of {} omits unchanged columns {missing:?}; keeping their stored values",
+                row.table
+            );
+            change = change.with_partial(true);
+        }
+    }
+    let change = match row.before_image() {
+        Some(before) if op == ChangeOp::Update => change.with_before_image(before),
+        _ => change,
+    };
+    match &row.old_columns {
+        Some(old) => change.with_before(old.clone()),
+        None => change,
+    }
 }
 
 /// FK enrichment and relation routing before the event enters the apply window.
@@ -468,7 +613,7 @@ fn action_to_positioned_event(
             ref out_fk,
         }) => {
             let data = if op == ChangeOp::Delete {
-                std::collections::HashMap::new()
+                FieldMap::new()
             } else {
                 row.columns.clone()
             };
@@ -483,7 +628,7 @@ fn action_to_positioned_event(
             Ok(PositionedEvent::relation_change(rel_change, position))
         }
         _ => {
-            let mut change = row_to_change(row, op);
+            let mut change = row_to_change(row, op, table_def);
             if let (Some(td), Some(ref mut data)) = (table_def, change.fields.as_mut()) {
                 crate::fk_transform::transform_fk_values(data, td);
             }
@@ -543,4 +688,129 @@ mod tests {
         assert_eq!(compare_lsn("0/200", "0/100"), 1);
         assert_eq!(compare_lsn("1/0", "0/FF"), 1);
     }
+
+    fn events_row(count: i32) -> FieldMap {
+        FieldMap::from([
+            (
+                "kind".to_string(),
+                surreal_sync_core::Value::Text("click".into()),
+            ),
+            ("count".to_string(), surreal_sync_core::Value::Int32(count)),
+        ])
+    }
+
+    #[test]
+    fn test_update_omitting_columns_is_partial() {
+        use surreal_sync_core::{ColumnDefinition, Type, Value};
+
+        let posts = TableDefinition::new(
+            "posts",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![
+                ColumnDefinition::new("note", Type::Text),
+                ColumnDefinition::new("body", Type::Text),
+            ],
+        );
+        let row = |columns: FieldMap| crate::from_wal2json::Row {
+            primary_key: Value::Int32(1),
+            columns,
+            old_columns: None,
+            schema: "public".to_string(),
+            table: "posts".to_string(),
+        };
+        let mut columns = FieldMap::from([
+            ("id".to_string(), Value::Int32(1)),
+            ("note".to_string(), Value::Null),
+        ]);
+        let change = row_to_change(&row(columns.clone()), ChangeOp::Update, Some(&posts));
+        assert!(change.partial);
+
+        columns.insert("body".to_string(), Value::Null);
+        let change = row_to_change(&row(columns), ChangeOp::Update, Some(&posts));
+        assert!(!change.partial);
+    }
+
+    #[test]
+    fn test_insert_with_added_column_extends_schema() {
+        use surreal_sync_core::{Type, Value};
+
+        let mut db_schema = DatabaseSchema::new(vec![TableDefinition::new(
+            "posts",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![ColumnDefinition::new("body", Type::Text)],
+        )]);
+        // `ALTER TABLE posts ADD COLUMN rating int, ADD COLUMN tag text`,
+        // then an insert that sets the new `rating` column.
+        let insert = crate::from_wal2json::Row {
+            primary_key: Value::Int32(1),
+            columns: FieldMap::from([
+                ("id".to_string(), Value::Int32(1)),
+                ("body".to_string(), Value::Text("hi".to_string())),
+                ("rating".to_string(), Value::Int32(5)),
+                ("tag".to_string(), Value::Null),
+            ]),
+            old_columns: None,
+            schema: "public".to_string(),
+            table: "posts".to_string(),
+        };
+        assert_eq!(learn_added_columns(&mut db_schema, &insert), ["rating"]);
+        assert!(learn_added_columns(&mut db_schema, &insert).is_empty());
+
+        let posts = db_schema.get_table("posts").unwrap();
+        assert_eq!(posts.get_column_type("rating"), Some(&Type::Int32));
+        assert!(posts.get_column("tag").is_none());
+
+        let change = row_to_change(&insert, ChangeOp::Create, Some(posts));
+        assert_eq!(change.fields.unwrap().get("rating"), Some(&Value::Int32(5)));
+
+        // A later update leaving the new column out (unchanged TOAST) now
+        // keeps its stored value instead of clearing it.
+        let update = crate::from_wal2json::Row {
+            columns: FieldMap::from([
+                ("id".to_string(), Value::Int32(1)),
+                ("body".to_string(), Value::Text("edited".to_string())),
+            ]),
+            ..insert
+        };
+        assert!(row_to_change(&update, ChangeOp::Update, Some(posts)).partial);
+    }
+
+    #[test]
+    fn test_pkless_update_moves_record_to_new_synthesized_id() {
+        let update = crate::from_wal2json::Row {
+            primary_key: surreal_sync_core::Value::Null,
+            columns: events_row(2),
+            old_columns: Some(events_row(1)),
+            schema: "public".to_string(),
+            table: "events".to_string(),
+        };
+        let changes = pkless_changes(&update, ChangeOp::Update).unwrap();
+        let ops: Vec<(surreal_sync_core::Value, ChangeOp)> = changes
+            .into_iter()
+            .map(|(row, op)| (row.primary_key, op))
+            .collect();
+        assert_eq!(
+            ops,
+            vec![
+                (crate::synthesized_row_id(&events_row(1)), ChangeOp::Delete),
+                (crate::synthesized_row_id(&events_row(2)), ChangeOp::Update),
+            ]
+        );
+
+        let unchanged = crate::from_wal2json::Row {
+            old_columns: Some(events_row(2)),
+            ..update.clone()
+        };
+        assert_eq!(
+            pkless_changes(&unchanged, ChangeOp::Update).unwrap().len(),
+            1
+        );
+
+        let without_identity = crate::from_wal2json::Row {
+            old_columns: None,
+            ..update
+        };
+        let err = pkless_changes(&without_identity, ChangeOp::Update).unwrap_err();
+        assert!(err.to_string().contains("REPLICA IDENTITY FULL"));
+    }
 }
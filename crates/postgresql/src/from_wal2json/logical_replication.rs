@@ -110,12 +110,14 @@
 //! committed transactions regardless of wal2json's output format.
 
 use anyhow::{bail, Context, Result};
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio_postgres::Client as PgClient;
 use tracing::{debug, info};
 
-use crate::from_wal2json::change::{wal2json_to_psql, Action};
-use crate::from_wal2json::wal2json::parse_wal2json;
+use crate::from_wal2json::change::{wal2json_to_psql, wal2json_v1_to_psql, Action, Row};
+use crate::from_wal2json::config::Config;
+use crate::from_wal2json::wal2json::{parse_wal2json, Wal2jsonFormat};
 
 /// A change action paired with the WAL LSN at which it occurred.
 ///
@@ -139,6 +141,33 @@ pub struct Client {
     pg_client: Arc<PgClient>,
     /// List of table names to track for replication
     table_names: Vec<String>,
+    /// `(schema, table)` pairs wal2json should decode server-side (`add-tables`).
+    /// Empty means every table is decoded.
+    decoded_tables: Vec<(String, String)>,
+    /// wal2json `format-version` slots peek with.
+    format_version: Wal2jsonFormat,
+}
+
+/// Result of the publication preflight in [`Client::bind_publication`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicationInfo {
+    /// Publication name.
+    pub name: String,
+    /// Whether the publication was created by the preflight.
+    pub created: bool,
+    /// Published tables as `(schema, table)` pairs.
+    pub tables: Vec<(String, String)>,
+}
+
+impl PublicationInfo {
+    /// Names of the published tables that live in `schema`.
+    pub fn tables_in(&self, schema: &str) -> Vec<String> {
+        self.tables
+            .iter()
+            .filter(|(s, _)| s == schema)
+            .map(|(_, t)| t.clone())
+            .collect()
+    }
 }
 
 impl Client {
@@ -151,9 +180,160 @@ impl Client {
         Self {
             pg_client: Arc::new(pg_client),
             table_names,
+            decoded_tables: Vec::new(),
+            format_version: Wal2jsonFormat::default(),
+        }
+    }
+
+    /// Choose the wal2json `format-version` (default: version 2).
+    ///
+    /// Version 2 emits one message per change and is the better fit for
+    /// large transactions. Version 1 emits one message per transaction and
+    /// is kept for servers or tooling that expect it. Both yield the same
+    /// [`Action`] sequence from [`Slot::peek`].
+    ///
+    /// Call before [`Client::start_replication`].
+    pub fn set_format_version(&mut self, format_version: Wal2jsonFormat) {
+        self.format_version = format_version;
+    }
+
+    /// Scope replication to the tables of a `PUBLICATION`.
+    ///
+    /// wal2json does not read publications itself, so the published tables
+    /// are resolved here and passed to wal2json as `add-tables`: only those
+    /// tables are decoded server-side, which also cuts output volume. Only
+    /// published tables of `schema` are decoded. When tables were configured
+    /// on the client they must all be published in `schema`; an empty table
+    /// list adopts the publication's tables of `schema`.
+    ///
+    /// Preflight: a missing publication is an error unless `create_if_missing`
+    /// is set, in which case it is created for the configured tables (or
+    /// `FOR ALL TABLES` when none are configured) in `schema`.
+    ///
+    /// Call before [`Client::start_replication`].
+    pub async fn bind_publication(
+        &mut self,
+        publication: &str,
+        schema: &str,
+        create_if_missing: bool,
+    ) -> Result<PublicationInfo> {
+        let exists = !self
+            .pg_client
+            .query(
+                "SELECT 1 FROM pg_publication WHERE pubname = $1",
+                &[&publication],
+            )
+            .await
+            .context("Failed to check for existing publication")?
+            .is_empty();
+
+        let created = if exists {
+            false
+        } else if create_if_missing {
+            let sql = create_publication_sql(publication, schema, &self.table_names);
+            info!("Creating publication {publication}: {sql}");
+            self.pg_client
+                .batch_execute(&sql)
+                .await
+                .with_context(|| format!("Failed to create publication '{publication}'"))?;
+            true
+        } else {
+            bail!(
+                "Publication '{publication}' does not exist; create it with CREATE PUBLICATION \
+                 or enable publication creation"
+            );
+        };
+
+        let tables: Vec<(String, String)> = self
+            .pg_client
+            .query(
+                "SELECT schemaname::text, tablename::text FROM pg_publication_tables \
+                 WHERE pubname = $1 ORDER BY schemaname, tablename",
+                &[&publication],
+            )
+            .await
+            .context("Failed to list publication tables")?
+            .iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect();
+
+        if tables.is_empty() {
+            bail!("Publication '{publication}' does not publish any tables");
+        }
+
+        self.decoded_tables =
+            published_tables_in_schema(publication, schema, &tables, &self.table_names)?;
+        if self.table_names.is_empty() {
+            self.table_names = self.decoded_tables.iter().map(|(_, t)| t.clone()).collect();
+        }
+
+        info!(
+            "Bound replication to publication {publication} ({} tables{})",
+            self.decoded_tables.len(),
+            if created { ", created" } else { "" }
+        );
+        Ok(PublicationInfo {
+            name: publication.to_string(),
+            created,
+            tables,
+        })
+    }
+
+    /// [`Self::bind_publication`] with the publication of `config`, if any.
+    pub async fn bind_configured_publication(
+        &mut self,
+        config: &Config,
+    ) -> Result<Option<PublicationInfo>> {
+        match &config.publication {
+            Some(name) => Ok(Some(
+                self.bind_publication(name, &config.schema, config.create_publication)
+                    .await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Stream an extra table alongside a bound publication (e.g. a signal
+    /// table that is not published). Without a publication every table is
+    /// already decoded, so only the client-side filter is extended.
+    pub fn track_table(&mut self, table: &str) {
+        if !self.table_names.is_empty() && !self.table_names.iter().any(|t| t == table) {
+            self.table_names.push(table.to_string());
+        }
+        if !self.decoded_tables.is_empty() {
+            self.decoded_tables
+                .push(("*".to_string(), table.to_string()));
         }
     }
 
+    /// Tracked tables of `schema` that have no primary key.
+    ///
+    /// Their rows are identified by every column value
+    /// ([`crate::synthesized_row_id`]), so updates and deletes must carry the
+    /// whole old row: each such table needs `REPLICA IDENTITY FULL`. Fails
+    /// naming the tables that are not, with the statement that fixes them.
+    pub async fn tables_without_primary_key(&self, schema: &str) -> Result<HashSet<String>> {
+        let tables: Vec<(String, String)> = self
+            .pg_client
+            .query(
+                "SELECT c.relname::text, c.relreplident::text FROM pg_class c \
+                 JOIN pg_namespace n ON n.oid = c.relnamespace \
+                 WHERE n.nspname = $1 AND c.relkind IN ('r', 'p') \
+                 AND NOT EXISTS (SELECT 1 FROM pg_index i \
+                 WHERE i.indrelid = c.oid AND i.indisprimary)",
+                &[&schema],
+            )
+            .await
+            .context("Failed to list tables without a primary key")?
+            .iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .filter(|(table, _): &(String, String)| {
+                self.table_names.is_empty() || self.table_names.contains(table)
+            })
+            .collect();
+        require_full_replica_identity(schema, &tables)
+    }
+
     /// Gets the current WAL LSN position
     ///
     /// This is useful for capturing the LSN position before taking a snapshot,
@@ -323,14 +503,132 @@ impl Client {
         info!("Starting logical replication with slot: {}", slot_name);
 
         // Create and return a Slot for the existing slot
-        Ok(Slot::new(
+        let mut slot = Slot::new(
             Arc::clone(&self.pg_client),
             slot_name.to_string(),
             self.table_names.clone(),
-        ))
+        );
+        if !self.decoded_tables.is_empty() {
+            slot.add_tables = Some(wal2json_add_tables(&self.decoded_tables));
+        }
+        slot.format_version = self.format_version;
+        Ok(slot)
     }
 }
 
+/// Names of the `(table, relreplident)` tables, or an error listing those
+/// whose replica identity is not `FULL` (`f`).
+fn require_full_replica_identity(
+    schema: &str,
+    tables: &[(String, String)],
+) -> Result<HashSet<String>> {
+    let not_full: Vec<&str> = tables
+        .iter()
+        .filter(|(_, identity)| identity != "f")
+        .map(|(table, _)| table.as_str())
+        .collect();
+    if !not_full.is_empty() {
+        let fix: Vec<String> = not_full
+            .iter()
+            .map(|t| {
+                format!(
+                    "ALTER TABLE {}.{} REPLICA IDENTITY FULL;",
+                    quote_ident(schema),
+                    quote_ident(t)
+                )
+            })
+            .collect();
+        bail!(
+            "Tables {not_full:?} have no primary key and their REPLICA IDENTITY is not FULL, \
+             so updates and deletes cannot be matched to SurrealDB records. Run: {}",
+            fix.join(" ")
+        );
+    }
+    Ok(tables.iter().map(|(table, _)| table.clone()).collect())
+}
+
+/// The published `(schema, table)` pairs of `schema` to decode. Every
+/// configured table must be published in `schema`; an empty `configured`
+/// list takes all of them.
+fn published_tables_in_schema(
+    publication: &str,
+    schema: &str,
+    published: &[(String, String)],
+    configured: &[String],
+) -> Result<Vec<(String, String)>> {
+    let in_schema: Vec<(String, String)> = published
+        .iter()
+        .filter(|(s, _)| s == schema)
+        .cloned()
+        .collect();
+    if configured.is_empty() {
+        if in_schema.is_empty() {
+            bail!("Publication '{publication}' does not publish any tables in schema '{schema}'");
+        }
+        return Ok(in_schema);
+    }
+    let missing: Vec<&String> = configured
+        .iter()
+        .filter(|t| !in_schema.iter().any(|(_, pt)| pt == *t))
+        .collect();
+    if !missing.is_empty() {
+        bail!("Tables {missing:?} of schema '{schema}' are not in publication '{publication}'");
+    }
+    Ok(in_schema)
+}
+
+/// `CREATE PUBLICATION` for `tables` in `schema`, or for all tables.
+fn create_publication_sql(publication: &str, schema: &str, tables: &[String]) -> String {
+    if tables.is_empty() {
+        format!(
+            "CREATE PUBLICATION {} FOR ALL TABLES",
+            quote_ident(publication)
+        )
+    } else {
+        let list: Vec<String> = tables
+            .iter()
+            .map(|t| format!("{}.{}", quote_ident(schema), quote_ident(t)))
+            .collect();
+        format!(
+            "CREATE PUBLICATION {} FOR TABLE {}",
+            quote_ident(publication),
+            list.join(", ")
+        )
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Render wal2json's `add-tables` option value. Special characters inside
+/// names (space, quote, comma, period, asterisk, backslash) are
+/// backslash-escaped; a `*` schema is kept as a wildcard.
+fn wal2json_add_tables(tables: &[(String, String)]) -> String {
+    fn escape(name: &str) -> String {
+        let mut out = String::with_capacity(name.len());
+        for c in name.chars() {
+            if matches!(c, '\\' | ',' | '.' | '*' | ' ' | '\'') {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+        out
+    }
+    tables
+        .iter()
+        .map(|(schema, table)| {
+            let schema = if schema == "*" {
+                "*".to_string()
+            } else {
+                escape(schema)
+            };
+            format!("{schema}.{}", escape(table))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 /// Slot for consuming logical replication changes
 ///
 /// Provides an interface for reading changes from a PostgreSQL
@@ -343,6 +641,10 @@ pub struct Slot {
     slot_name: String,
     /// List of table names to filter (if needed)
     table_names: Vec<String>,
+    /// wal2json `add-tables` value restricting decoding server-side.
+    add_tables: Option<String>,
+    /// wal2json `format-version` to peek with.
+    format_version: Wal2jsonFormat,
 }
 
 impl Slot {
@@ -352,6 +654,8 @@ impl Slot {
             pg_client,
             slot_name,
             table_names,
+            add_tables: None,
+            format_version: Wal2jsonFormat::default(),
         }
     }
 
@@ -411,7 +715,7 @@ impl Slot {
     ///
     /// # Returns
     /// * `Result<(Vec<Action>, String)>` - Tuple of (changes, nextlsn)
-    ///   - changes: Vector of Action enums (Insert, Update, Delete - excluding transaction begin/commit)
+    ///   - changes: Vector of Action enums (Insert, Update, Delete, Truncate - excluding transaction begin/commit)
     ///   - nextlsn: The nextlsn from the last commit action, to be used with advance()
     ///
     /// # Usage Pattern (batch processing with at-least-once delivery)
@@ -449,10 +753,24 @@ impl Slot {
     pub async fn peek_with_positions(&self) -> Result<(Vec<ChangeAtLsn>, String)> {
         // wal2json options for formatting
         //
-        // 'format-version', '2' - use format version 2
+        // 'format-version', '2' - use format version 2 (or 1, see Client::set_format_version)
         // 'include-lsn', 'true' - include LSN and nextlsn fields in the output
         // 'include-pk', 'true' - add primary key information as pk. Column name and data type is included
-        let wal2json_options = "'format-version', '2', 'include-lsn', 'true', 'include-pk', 'true'";
+        // 'include-xids', 'true' - version 1 only: include the transaction xid
+        // 'add-tables', '...' - only decode these tables (set by a bound publication)
+        let mut wal2json_options = format!(
+            "'format-version', '{}', 'include-lsn', 'true', 'include-pk', 'true'",
+            self.format_version.option_value()
+        );
+        if self.format_version == Wal2jsonFormat::V1 {
+            wal2json_options.push_str(", 'include-xids', 'true'");
+        }
+        if let Some(add_tables) = &self.add_tables {
+            wal2json_options.push_str(&format!(
+                ", 'add-tables', '{}'",
+                add_tables.replace('\'', "''")
+            ));
+        }
 
         let query = format!(
             "SELECT lsn::text, xid::text, data FROM pg_logical_slot_peek_changes('{}', NULL, NULL, {})",
@@ -474,83 +792,88 @@ impl Slot {
             let xid: String = row.get(1);
             let data: String = row.get(2);
 
-            match parse_wal2json(&data) {
-                Ok(parsed) => {
-                    // Convert to strongly-typed Action
-                    match wal2json_to_psql(&parsed) {
-                        Ok(action) => {
-                            match &action {
-                                Action::Begin {
-                                    xid: action_xid, ..
-                                } => {
-                                    // Begin transaction
-                                    if let Some(previous_xid) = current_xid.as_ref() {
-                                        bail!("Found Begin transaction xid={action_xid} while previous transaction xid={previous_xid} is not committed");
-                                    }
-                                    current_xid = Some(xid.clone());
-                                    debug!("Begin transaction xid={}", xid);
-                                }
-                                Action::Commit { nextlsn, .. } => {
-                                    // Commit transaction - extract nextlsn
-                                    if let Some(ref expected_xid) = current_xid {
-                                        if expected_xid != &xid {
-                                            bail!("Transaction xid mismatch: expected {expected_xid} but got {xid} in Commit");
-                                        }
-                                    } else {
-                                        bail!("Found Commit for xid={xid} without corresponding Begin");
-                                    }
-
-                                    last_nextlsn = nextlsn.clone();
-                                    debug!("Commit transaction xid={xid} with nextlsn: {nextlsn}");
-                                    current_xid = None;
-                                }
-                                Action::Insert(row) | Action::Update(row) | Action::Delete(row) => {
-                                    // Insert, Update, or Delete - actual data changes
-                                    // Validate transaction consistency
-                                    if let Some(ref expected_xid) = current_xid {
-                                        if expected_xid != &xid {
-                                            bail!("Transaction xid mismatch: expected {expected_xid} but got {xid} in {action} action");
-                                        }
-                                    } else {
-                                        bail!("Found {action} action with xid={xid} outside of transaction");
-                                    }
-
-                                    // Table filtering: Client-side filtering after reading from WAL
-                                    //
-                                    // IMPORTANT: All changes are read from the WAL regardless of the
-                                    // table filter. Filtering happens in-memory by checking each change's
-                                    // table name against the configured table_names list.
-                                    //
-                                    // This means:
-                                    // - Multiple processes with different table filters will read the SAME
-                                    //   WAL data (once per replication slot)
-                                    // - For parallel processing, use separate replication slots (different
-                                    //   --slot names), each maintaining its own position in the WAL
-                                    // - Each slot causes the WAL to be read again, so partitioning tables
-                                    //   across multiple processes increases WAL read load proportionally
-                                    let should_include = if !self.table_names.is_empty() {
-                                        self.table_names.iter().any(|t| t == &row.table)
-                                    } else {
-                                        true
-                                    };
-
-                                    if should_include {
-                                        changes.push(ChangeAtLsn {
-                                            lsn: lsn.clone(),
-                                            action,
-                                        });
-                                    }
-                                }
+            // Convert to strongly-typed Actions: one per version 2 message,
+            // a whole Begin..Commit transaction per version 1 message
+            let parsed = match parse_wal2json(&data) {
+                Ok(parsed) => parsed,
+                Err(e) => bail!("Failed to parse wal2json data, due to {e}: {data}"),
+            };
+            let actions = match self.format_version {
+                Wal2jsonFormat::V1 => wal2json_v1_to_psql(&parsed),
+                Wal2jsonFormat::V2 => wal2json_to_psql(&parsed).map(|action| vec![action]),
+            };
+            let actions = match actions {
+                Ok(actions) => actions,
+                Err(e) => bail!("Failed to convert wal2json to Action: {e}"),
+            };
+
+            for action in actions {
+                match &action {
+                    Action::Begin {
+                        xid: action_xid, ..
+                    } => {
+                        // Begin transaction
+                        if let Some(previous_xid) = current_xid.as_ref() {
+                            bail!("Found Begin transaction xid={action_xid} while previous transaction xid={previous_xid} is not committed");
+                        }
+                        current_xid = Some(xid.clone());
+                        debug!("Begin transaction xid={}", xid);
+                    }
+                    Action::Commit { nextlsn, .. } => {
+                        // Commit transaction - extract nextlsn
+                        if let Some(ref expected_xid) = current_xid {
+                            if expected_xid != &xid {
+                                bail!("Transaction xid mismatch: expected {expected_xid} but got {xid} in Commit");
                             }
+                        } else {
+                            bail!("Found Commit for xid={xid} without corresponding Begin");
                         }
-                        Err(e) => {
-                            bail!("Failed to convert wal2json to Action: {e}");
+
+                        last_nextlsn = nextlsn.clone();
+                        debug!("Commit transaction xid={xid} with nextlsn: {nextlsn}");
+                        current_xid = None;
+                    }
+                    Action::Insert(Row { table, .. })
+                    | Action::Update(Row { table, .. })
+                    | Action::Delete(Row { table, .. })
+                    | Action::Truncate { table, .. } => {
+                        // Insert, Update, Delete, or Truncate - actual data changes
+                        // Validate transaction consistency
+                        if let Some(ref expected_xid) = current_xid {
+                            if expected_xid != &xid {
+                                bail!("Transaction xid mismatch: expected {expected_xid} but got {xid} in {action} action");
+                            }
+                        } else {
+                            bail!("Found {action} action with xid={xid} outside of transaction");
+                        }
+
+                        // Table filtering: Client-side filtering after reading from WAL
+                        //
+                        // IMPORTANT: All changes are read from the WAL regardless of the
+                        // table filter. Filtering happens in-memory by checking each change's
+                        // table name against the configured table_names list.
+                        //
+                        // This means:
+                        // - Multiple processes with different table filters will read the SAME
+                        //   WAL data (once per replication slot)
+                        // - For parallel processing, use separate replication slots (different
+                        //   --slot names), each maintaining its own position in the WAL
+                        // - Each slot causes the WAL to be read again, so partitioning tables
+                        //   across multiple processes increases WAL read load proportionally
+                        let should_include = if !self.table_names.is_empty() {
+                            self.table_names.iter().any(|t| t == table)
+                        } else {
+                            true
+                        };
+
+                        if should_include {
+                            changes.push(ChangeAtLsn {
+                                lsn: lsn.clone(),
+                                action,
+                            });
                         }
                     }
                 }
-                Err(e) => {
-                    bail!("Failed to parse wal2json data, due to {e}: {data}");
-                }
             }
         }
 
@@ -577,3 +900,81 @@ impl Slot {
         Ok((changes, last_nextlsn))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_publication_sql_for_tables() {
+        let sql = create_publication_sql(
+            "pub",
+            "public",
+            &["users".to_string(), "order\"s".to_string()],
+        );
+        assert_eq!(
+            sql,
+            r#"CREATE PUBLICATION "pub" FOR TABLE "public"."users", "public"."order""s""#
+        );
+    }
+
+    #[test]
+    fn test_create_publication_sql_for_all_tables() {
+        assert_eq!(
+            create_publication_sql("pub", "public", &[]),
+            r#"CREATE PUBLICATION "pub" FOR ALL TABLES"#
+        );
+    }
+
+    #[test]
+    fn test_published_tables_in_schema_matches_schema_and_table() {
+        let published = vec![
+            ("other".to_string(), "users".to_string()),
+            ("public".to_string(), "orders".to_string()),
+        ];
+
+        let err = published_tables_in_schema("pub", "public", &published, &["users".to_string()])
+            .unwrap_err();
+        assert!(err.to_string().contains("[\"users\"]"), "{err}");
+
+        assert_eq!(
+            published_tables_in_schema("pub", "public", &published, &["orders".to_string()])
+                .unwrap(),
+            vec![("public".to_string(), "orders".to_string())]
+        );
+        assert_eq!(
+            published_tables_in_schema("pub", "public", &published, &[]).unwrap(),
+            vec![("public".to_string(), "orders".to_string())]
+        );
+        assert!(published_tables_in_schema("pub", "audit", &published, &[]).is_err());
+    }
+
+    #[test]
+    fn test_wal2json_add_tables_escapes_names() {
+        let tables = vec![
+            ("public".to_string(), "users".to_string()),
+            ("my.schema".to_string(), "a,b".to_string()),
+            ("*".to_string(), "signal".to_string()),
+        ];
+        assert_eq!(
+            wal2json_add_tables(&tables),
+            r"public.users,my\.schema.a\,b,*.signal"
+        );
+    }
+
+    #[test]
+    fn test_require_full_replica_identity() {
+        let tables = vec![
+            ("events".to_string(), "f".to_string()),
+            ("audit log".to_string(), "d".to_string()),
+        ];
+        let err = require_full_replica_identity("public", &tables)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(r#"ALTER TABLE "public"."audit log" REPLICA IDENTITY FULL;"#));
+        assert!(!err.contains("events"));
+
+        let full = require_full_replica_identity("public", &tables[..1]).unwrap();
+        assert_eq!(full, HashSet::from(["events".to_string()]));
+    }
+}
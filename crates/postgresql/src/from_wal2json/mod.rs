@@ -16,7 +16,14 @@
 //!     slot_name: "surreal_sync_slot".to_string(),
 //!     tables: vec!["users".to_string(), "orders".to_string()],
 //!     schema: "public".to_string(),
+//!     publication_name: None,
+//!     create_publication: false,
 //!     relation_tables: vec![],
+//!     keyset_columns: Default::default(),
+//!     parallel_reads: Default::default(),
+//!     column_filters: Default::default(),
+//!     apply_truncate: false,
+//!     format_version: Default::default(),
 //! };
 //!
 //! run_full_sync(source_opts, "namespace", "database", surreal_opts, sync_config).await?;
@@ -57,13 +64,14 @@ mod watermark_source;
 pub mod testing;
 
 pub use change::{Action, Row};
-pub use checkpoint::PostgreSQLLogicalCheckpoint;
+pub use checkpoint::{validate_checkpoint, PostgreSQLLogicalCheckpoint};
 pub use config::Config;
 pub use full_sync::{run_full_sync, run_full_sync_with_transforms, SourceOpts};
 pub use incremental_sync::{
     run_incremental_sync, run_incremental_sync_with_transforms, ReplicationTailOptions,
 };
-pub use logical_replication::{ChangeAtLsn, Client, Slot};
+pub use logical_replication::{ChangeAtLsn, Client, PublicationInfo, Slot};
+pub use wal2json::Wal2jsonFormat;
 pub use watermark_source::{
     request_snapshot, run_interleaved_snapshot_full_sync,
     run_interleaved_snapshot_full_sync_with_transforms, Lsn, Wal2JsonWatermarkSource, SIGNAL_TABLE,
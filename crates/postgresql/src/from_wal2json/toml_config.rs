@@ -26,6 +26,11 @@ pub struct Wal2jsonFullConfig {
     pub checkpoint_dir: Option<String>,
 
     pub checkpoints_surreal_table: Option<String>,
+
+    pub publication: Option<String>,
+
+    #[serde(default)]
+    pub create_publication: bool,
 }
 
 /// Source config for `from postgresql incremental -c <file>`.
@@ -58,6 +63,14 @@ pub struct Wal2jsonIncrementalConfig {
 
     #[serde(default = "default_timeout")]
     pub timeout: u64,
+
+    pub publication: Option<String>,
+
+    #[serde(default)]
+    pub create_publication: bool,
+
+    #[serde(default)]
+    pub apply_truncate: bool,
 }
 
 fn default_slot() -> String {
@@ -96,6 +109,8 @@ checkpoint_dir = "./checkpoints"
             config.postgresql.checkpoint_dir.as_deref(),
             Some("./checkpoints")
         );
+        assert!(config.postgresql.publication.is_none());
+        assert!(!config.postgresql.create_publication);
     }
 
     #[test]
@@ -108,9 +123,13 @@ schema = "myschema"
 checkpoints_surreal_table = "surreal_sync_checkpoints"
 incremental_from = "0/1949850"
 timeout = 7200
+publication = "my_pub"
+create_publication = true
 "#;
         let config: Wal2jsonIncrementalSource = toml::from_str(toml_str).unwrap();
         assert_eq!(config.postgresql.slot, "my_slot");
+        assert_eq!(config.postgresql.publication.as_deref(), Some("my_pub"));
+        assert!(config.postgresql.create_publication);
         assert_eq!(config.postgresql.schema, "myschema");
         assert_eq!(
             config.postgresql.checkpoints_surreal_table.as_deref(),
@@ -35,3 +35,49 @@ pub fn parse_wal2json(input: &str) -> Result<Value> {
 
     Ok(value)
 }
+
+/// Value wal2json sends in place of an unchanged TOAST column of an update
+/// when `include-unchanged-toast` is on (by default the column is left out).
+pub const UNCHANGED_TOAST_DATUM: &str = "unchanged-toast-datum";
+
+/// Whether a column value of an update is wal2json's unchanged-TOAST
+/// placeholder rather than the column's value.
+pub fn is_unchanged_toast_datum(value: Option<&Value>) -> bool {
+    value.and_then(Value::as_str) == Some(UNCHANGED_TOAST_DATUM)
+}
+
+/// wal2json output format requested from the plugin (`format-version`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Wal2jsonFormat {
+    /// One JSON object per transaction, with every change in a `change`
+    /// array. A large transaction is decoded and buffered as a single message.
+    V1,
+    /// One JSON object per change, plus `B`/`C` transaction markers (the
+    /// default). Lower latency and memory for large transactions.
+    #[default]
+    V2,
+}
+
+impl Wal2jsonFormat {
+    /// Value of the wal2json `format-version` option.
+    pub fn option_value(self) -> &'static str {
+        match self {
+            Wal2jsonFormat::V1 => "1",
+            Wal2jsonFormat::V2 => "2",
+        }
+    }
+}
+
+impl std::str::FromStr for Wal2jsonFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Wal2jsonFormat::V1),
+            "2" => Ok(Wal2jsonFormat::V2),
+            other => Err(format!(
+                "unsupported wal2json format-version '{other}' (expected 1 or 2)"
+            )),
+        }
+    }
+}
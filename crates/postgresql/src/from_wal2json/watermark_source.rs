@@ -34,7 +34,7 @@ use surreal_sync_runtime::{
 };
 use tokio::sync::Mutex;
 use tokio_postgres::NoTls;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::from_wal2json::change::Action;
@@ -46,7 +46,7 @@ use crate::from_wal2json::logical_replication::{Client, Slot};
 /// Watermark rows are inserted here so they are captured by the replication
 /// slot and reappear in the change stream, letting the framework detect when
 /// its low/high watermarks pass by. The `surreal_sync_` prefix means
-/// [`crate::get_user_tables`] already excludes it from the
+/// [`crate::get_schema_tables`] already excludes it from the
 /// set of tables to snapshot.
 pub const SIGNAL_TABLE: &str = "surreal_sync_signal";
 
@@ -215,7 +215,8 @@ impl Wal2JsonWatermarkSource {
         from_opts: &SourceOpts,
         signal_table: &str,
     ) -> Result<Self> {
-        let (pg, connection) = tokio_postgres::connect(&from_opts.connection_string, NoTls).await?;
+        let config = from_opts.config()?;
+        let (pg, connection) = config.postgres_config().connect(NoTls).await?;
         tokio::spawn(async move {
             if let Err(e) = connection.await {
                 eprintln!("PostgreSQL connection error: {e}");
@@ -224,13 +225,18 @@ impl Wal2JsonWatermarkSource {
 
         // The slot must surface both the signal-table watermarks and the user
         // tables we snapshot. An empty filter means "all tables"; otherwise we
-        // include the signal table alongside the requested tables.
-        let mut filter = from_opts.tables.clone();
-        if !filter.is_empty() {
-            filter.push(signal_table.to_string());
+        // include the signal table alongside the requested (or published)
+        // tables. The signal table is tracked after binding the publication
+        // since it is normally not published.
+        let mut client = Client::new(pg, config.tables.clone());
+        client.set_format_version(from_opts.format_version);
+        let mut explicit_tables = config.tables.clone();
+        if let Some(publication) = client.bind_configured_publication(&config).await? {
+            if explicit_tables.is_empty() {
+                explicit_tables = publication.tables_in(&config.schema);
+            }
         }
-
-        let client = Client::new(pg, filter);
+        client.track_table(signal_table);
 
         // Create the signal table before the slot is consumed so watermark
         // inserts are captured. A UUID primary key lets the framework key
@@ -260,7 +266,7 @@ impl Wal2JsonWatermarkSource {
             slot,
             slot_name: from_opts.slot_name.clone(),
             schema: from_opts.schema.clone(),
-            explicit_tables: from_opts.tables.clone(),
+            explicit_tables,
             signal_table: signal_table.to_string(),
             confirmed: start_lsn,
             returned_since_advance: 0,
@@ -361,12 +367,23 @@ fn pk_tuple_from_primary_key(pk: &Value) -> PkTuple {
 }
 
 /// Convert a decoded WAL action into a stream event payload, or `None` for
-/// transaction markers.
+/// transaction markers and truncations.
+///
+/// The watermark buffer deduplicates per primary key and has no table-wide
+/// event, so a `TRUNCATE` during an interleaved snapshot is not applied; it
+/// is logged so the operator can re-run the snapshot of that table.
 fn action_to_event(action: &Action) -> Option<(String, PkTuple, Change)> {
     let (row, op) = match action {
         Action::Insert(row) => (row, ChangeOp::Create),
         Action::Update(row) => (row, ChangeOp::Update),
         Action::Delete(row) => (row, ChangeOp::Delete),
+        Action::Truncate { schema, table } => {
+            warn!(
+                "Ignoring TRUNCATE of {schema}.{table} during interleaved snapshot; \
+                 re-sync the table if its rows must be removed"
+            );
+            return None;
+        }
         Action::Begin { .. } | Action::Commit { .. } => return None,
     };
     let pk = pk_tuple_from_primary_key(&row.primary_key);
@@ -376,6 +393,14 @@ fn action_to_event(action: &Action) -> Option<(String, PkTuple, Change)> {
         Some(row.columns.clone())
     };
     let change = Change::new(op, row.table.clone(), row.primary_key.clone(), data);
+    let change = match row.before_image() {
+        Some(before) if op == ChangeOp::Update => change.with_before_image(before),
+        _ => change,
+    };
+    let change = match &row.old_columns {
+        Some(old) => change.with_before(old.clone()),
+        None => change,
+    };
     Some((row.table.clone(), pk, change))
 }
 
@@ -385,7 +410,7 @@ impl WatermarkSource for Wal2JsonWatermarkSource {
 
     async fn snapshot_tables(&self) -> Result<Vec<TableSpec>> {
         let tables = if self.explicit_tables.is_empty() {
-            crate::get_user_tables(self.client.pg_client(), &self.schema).await?
+            crate::get_schema_tables(self.client.pg_client(), &self.schema).await?
         } else {
             self.explicit_tables.clone()
         };
@@ -480,7 +505,19 @@ impl WatermarkSource for Wal2JsonWatermarkSource {
         // LSNs) is still delivered in its correct commit position.
         let mut out = Vec::new();
         for change in changes.iter().skip(self.returned_since_advance) {
-            if let Some((table, pk, event_change)) = action_to_event(&change.action) {
+            if let Some((table, pk, mut event_change)) = action_to_event(&change.action) {
+                if let Action::Update(row) = &change.action {
+                    if table != self.signal_table {
+                        let columns = self.column_type_oids(&table).await?;
+                        if !row
+                            .missing_columns(columns.keys().map(String::as_str))
+                            .is_empty()
+                        {
+                            // Unchanged TOAST columns are omitted from the update.
+                            event_change = event_change.with_partial(true);
+                        }
+                    }
+                }
                 let emit = if table == self.signal_table {
                     pk.single_uuid()
                         .is_some_and(|id| Some(id) == low || Some(id) == high)
@@ -4,15 +4,20 @@
 //! including row conversion utilities.
 
 use anyhow::Result;
+use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rust_decimal::Decimal;
 use std::collections::HashMap;
+use std::sync::Arc;
 use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{
-    classify_table, DatabaseSchema, GeometryType, Relation, Row, TableKind, Type, Value,
+    classify_table, keyset_after_predicate, keyset_order_by, split_key_range, ColumnFilter,
+    ColumnFilters, DatabaseSchema, FieldMap, GeometryType, KeyRange, KeysetColumn, NullsOrder,
+    Relation, Row, TableKind, Type, Value,
 };
+use surreal_sync_runtime::{ParallelRowChunks, RowChunkSource};
 use tokio_postgres::types::ToSql;
-use tokio_postgres::{Client, Row as PgRow};
+use tokio_postgres::{Client, NoTls, Row as PgRow};
 use tracing::{debug, info, warn};
 
 use crate::fk_transform;
@@ -154,6 +159,67 @@ pub struct TableChunk {
     pub last_pk: Option<Vec<Value>>,
 }
 
+/// Narrowed column list for keyset chunk reads (column include/exclude
+/// push-down), from [`get_read_columns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadColumns {
+    /// Columns to `SELECT`, in table order. Includes the key columns.
+    pub select: Vec<String>,
+    /// Filter applied to converted fields, dropping key columns that were
+    /// read only for record ids and cursors.
+    pub filter: ColumnFilter,
+}
+
+impl ReadColumns {
+    fn select_list(&self) -> String {
+        self.select
+            .iter()
+            .map(|c| format!("\"{}\"", c.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Columns of `table_name` to read when `filters` narrows it.
+///
+/// Returns `None` (read every column) when the table has no filter. `keep`
+/// columns (primary and sort key) are selected even when filtered out,
+/// because record ids and keyset cursors are built from them.
+pub async fn get_read_columns(
+    client: &Client,
+    table_name: &str,
+    filters: &ColumnFilters,
+    keep: &[String],
+) -> Result<Option<ReadColumns>> {
+    let Some(filter) = filters.for_table(table_name) else {
+        return Ok(None);
+    };
+    let query = "
+        SELECT a.attname
+        FROM pg_attribute a
+        WHERE a.attrelid = $1::text::regclass
+        AND a.attnum > 0
+        AND NOT a.attisdropped
+        ORDER BY a.attnum
+    ";
+    let columns: Vec<String> = client
+        .query(query, &[&table_name])
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    let select: Vec<String> = filters
+        .select_columns(table_name, &columns, keep)
+        .into_iter()
+        .cloned()
+        .collect();
+    debug!("Reading columns {select:?} of table {table_name}");
+    Ok(Some(ReadColumns {
+        select,
+        filter: filter.clone(),
+    }))
+}
+
 /// Read a single primary-key-ordered chunk of a table using keyset pagination.
 ///
 /// Rows are ordered by the table's primary key column(s). When `after` is
@@ -182,42 +248,129 @@ pub async fn read_table_chunk(
             "Table '{table_name}' has no primary key columns; keyset chunk reads require a primary key"
         ));
     }
+    let key: Vec<KeysetColumn> = pk_columns.iter().map(KeysetColumn::new).collect();
+    read_table_chunk_by_key(
+        client, table_name, pk_columns, &key, after, limit, 0, schema, None,
+    )
+    .await
+}
 
-    let order_by = pk_columns.join(", ");
+/// Keyset-paginated read of a table ordered by an arbitrary (composite) sort key.
+///
+/// Like [`read_table_chunk`], but pages on `key` instead of the primary key, so
+/// tables without a single monotonic primary key (e.g. `(tenant_id, created_at)`)
+/// avoid OFFSET scans. Nullable key columns are ordered `NULLS LAST` and the
+/// continuation predicate is expanded so rows with NULL keys are neither skipped
+/// nor repeated; see [`surreal_sync_core::keyset`]. The key must be unique over
+/// the table for reads to be gap- and duplicate-free.
+///
+/// Record ids still come from `pk_columns`; when the table has no primary key
+/// (`pk_columns` empty) ids are synthetic `Int64(row_index_base + i)` values,
+/// matching the OFFSET path. The returned `last_pk` is the cursor over `key`.
+///
+/// `columns` narrows the read to a filtered column list (see
+/// [`get_read_columns`]); `None` reads every column.
+#[allow(clippy::too_many_arguments)]
+pub async fn read_table_chunk_by_key(
+    client: &Client,
+    table_name: &str,
+    pk_columns: &[String],
+    key: &[KeysetColumn],
+    after: Option<&[Value]>,
+    limit: usize,
+    row_index_base: u64,
+    schema: Option<&DatabaseSchema>,
+    columns: Option<&ReadColumns>,
+) -> Result<TableChunk> {
+    read_table_range_chunk(
+        client,
+        table_name,
+        pk_columns,
+        key,
+        &KeyRange::full(),
+        after,
+        limit,
+        row_index_base,
+        schema,
+        columns,
+    )
+    .await
+}
+
+/// Keyset-paginated read restricted to a [`KeyRange`] of the leading key column.
+///
+/// Like [`read_table_chunk_by_key`], but only rows with
+/// `range.lower <= key[0] < range.upper` are returned. Reading every range of a
+/// [`split_key_range`](surreal_sync_core::split_key_range) split to exhaustion
+/// reads each row exactly once. The leading key column must be `NOT NULL`:
+/// NULL keys fall in no range.
+#[allow(clippy::too_many_arguments)]
+pub async fn read_table_range_chunk(
+    client: &Client,
+    table_name: &str,
+    pk_columns: &[String],
+    key: &[KeysetColumn],
+    range: &KeyRange,
+    after: Option<&[Value]>,
+    limit: usize,
+    row_index_base: u64,
+    schema: Option<&DatabaseSchema>,
+    columns: Option<&ReadColumns>,
+) -> Result<TableChunk> {
+    if key.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Table '{table_name}' has no keyset columns; keyset chunk reads require a sort key"
+        ));
+    }
+
+    let order_by = keyset_order_by(key, NullsOrder::Last, true);
 
-    // Build the keyset predicate and bind the cursor values (if any).
+    // Build the range and keyset predicates and bind their values (if any).
     // `Send` is required so callers can drive this read from a `Send` async
     // context (e.g. interleaved-snapshot trait methods).
     let mut boxed_params: Vec<Box<dyn ToSql + Sync + Send>> = Vec::new();
-    let where_clause = match after {
-        Some(cursor) => {
-            if cursor.len() != pk_columns.len() {
-                return Err(anyhow::anyhow!(
-                    "Keyset cursor length ({}) does not match primary key column count ({}) for table '{table_name}'",
-                    cursor.len(),
-                    pk_columns.len()
-                ));
-            }
-            let placeholders: Vec<String> =
-                (1..=pk_columns.len()).map(|i| format!("${i}")).collect();
-            for value in cursor {
-                boxed_params.push(pk_value_to_sql(value)?);
-            }
-            if pk_columns.len() == 1 {
-                format!("WHERE {} > {}", pk_columns[0], placeholders[0])
-            } else {
-                format!(
-                    "WHERE ({}) > ({})",
-                    pk_columns.join(", "),
-                    placeholders.join(", ")
-                )
-            }
+    let mut predicates = Vec::new();
+    if let Some(lower) = &range.lower {
+        boxed_params.push(pk_value_to_sql(lower)?);
+        predicates.push(format!("{} >= ${}", key[0].name, boxed_params.len()));
+    }
+    if let Some(upper) = &range.upper {
+        boxed_params.push(pk_value_to_sql(upper)?);
+        predicates.push(format!("{} < ${}", key[0].name, boxed_params.len()));
+    }
+    if let Some(cursor) = after {
+        if cursor.len() != key.len() {
+            return Err(anyhow::anyhow!(
+                "Keyset cursor length ({}) does not match key column count ({}) for table '{table_name}'",
+                cursor.len(),
+                key.len()
+            ));
         }
-        None => String::new(),
+        let offset = boxed_params.len();
+        let Some(predicate) = keyset_after_predicate(key, cursor, NullsOrder::Last, |i| {
+            format!("${}", i + offset)
+        }) else {
+            // The cursor is at the very end of the key order.
+            return Ok(TableChunk {
+                rows: Vec::new(),
+                last_pk: None,
+            });
+        };
+        for &i in &predicate.binds {
+            boxed_params.push(pk_value_to_sql(&cursor[i])?);
+        }
+        predicates.push(predicate.sql);
+    }
+    let where_clause = if predicates.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", predicates.join(" AND "))
     };
 
-    let query =
-        format!("SELECT * FROM {table_name} {where_clause} ORDER BY {order_by} LIMIT {limit}");
+    let select = columns.map_or_else(|| "*".to_string(), ReadColumns::select_list);
+    let query = format!(
+        "SELECT {select} FROM {table_name} {where_clause} ORDER BY {order_by} LIMIT {limit}"
+    );
     debug!("Chunk-reading table {table_name} with: {query}");
 
     let params: Vec<&(dyn ToSql + Sync)> = boxed_params
@@ -228,22 +381,173 @@ pub async fn read_table_chunk(
     let rows = client.query(&query, &params).await?;
 
     let table_def = schema.and_then(|s| s.get_table(table_name));
+    let key_names: Vec<String> = key.iter().map(|c| c.name.clone()).collect();
 
     let mut out = Vec::with_capacity(rows.len());
     let mut last_pk: Option<Vec<Value>> = None;
-    for (row_index, row) in rows.iter().enumerate() {
-        let mut record =
-            convert_row_to_universal_row(table_name, row, pk_columns, row_index as u64)?;
+    for (i, row) in rows.iter().enumerate() {
+        let row_index = row_index_base + i as u64;
+        let mut record = convert_row_to_universal_row(table_name, row, pk_columns, row_index)?;
         if let Some(td) = table_def {
             fk_transform::transform_fk_values(&mut record.fields, td);
         }
-        last_pk = Some(extract_pk_cursor_values(row, pk_columns)?);
+        if let Some(columns) = columns {
+            columns.filter.retain(&mut record.fields);
+        }
+        last_pk = Some(extract_pk_cursor_values(row, &key_names)?);
         out.push(record);
     }
 
     Ok(TableChunk { rows: out, last_pk })
 }
 
+/// Read the smallest and largest value of `column`, or `None` for an empty
+/// table. The column must be `NOT NULL`.
+pub async fn probe_key_bounds(
+    client: &Client,
+    table_name: &str,
+    column: &str,
+) -> Result<Option<(Value, Value)>> {
+    // ORDER BY/LIMIT rather than min()/max(): there are no uuid aggregates,
+    // and both forms are answered from the primary-key index.
+    let query = format!(
+        "SELECT (SELECT {column} FROM {table_name} ORDER BY {column} ASC LIMIT 1) AS lo, \
+                (SELECT {column} FROM {table_name} ORDER BY {column} DESC LIMIT 1) AS hi"
+    );
+    let row = client.query_one(&query, &[]).await?;
+    let mut bounds = extract_pk_cursor_values(&row, &["lo".to_string(), "hi".to_string()])?;
+    let hi = bounds.pop().unwrap_or(Value::Null);
+    let lo = bounds.pop().unwrap_or(Value::Null);
+    if lo.is_null() || hi.is_null() {
+        return Ok(None);
+    }
+    Ok(Some((lo, hi)))
+}
+
+/// Keyset reader over one [`KeyRange`] of a table, on its own connection.
+struct RangeChunks {
+    client: Client,
+    table_name: String,
+    pk_columns: Vec<String>,
+    key: Vec<KeysetColumn>,
+    range: KeyRange,
+    after: Option<Vec<Value>>,
+    batch_size: usize,
+    schema: Option<Arc<DatabaseSchema>>,
+    columns: Option<Arc<ReadColumns>>,
+    exhausted: bool,
+}
+
+#[async_trait]
+impl RowChunkSource for RangeChunks {
+    async fn next_chunk(&mut self) -> Result<Option<Vec<Row>>> {
+        if self.exhausted {
+            return Ok(None);
+        }
+        // Ids come from the primary key, so the row index base is unused.
+        let chunk = read_table_range_chunk(
+            &self.client,
+            &self.table_name,
+            &self.pk_columns,
+            &self.key,
+            &self.range,
+            self.after.as_deref(),
+            self.batch_size,
+            0,
+            self.schema.as_deref(),
+            self.columns.as_deref(),
+        )
+        .await?;
+        if chunk.rows.is_empty() {
+            self.exhausted = true;
+            return Ok(None);
+        }
+        if chunk.rows.len() < self.batch_size {
+            self.exhausted = true;
+        }
+        self.after = chunk.last_pk;
+        Ok(Some(chunk.rows))
+    }
+}
+
+/// Split a table's keyset scan into `degree` key ranges read concurrently,
+/// each on its own connection to `source_uri`.
+///
+/// Returns `None` (read the table with one reader) when `degree <= 1`, the
+/// table has no primary key (synthetic row ids would collide across ranges),
+/// the leading key column is nullable or not an integer/uuid column, or the
+/// table is too small to split.
+#[allow(clippy::too_many_arguments)]
+pub async fn parallel_range_chunks(
+    client: &Client,
+    source_uri: &str,
+    table_name: &str,
+    pk_columns: &[String],
+    key: &[KeysetColumn],
+    degree: usize,
+    batch_size: usize,
+    schema: Option<&DatabaseSchema>,
+    columns: Option<&ReadColumns>,
+) -> Result<Option<ParallelRowChunks>> {
+    if degree <= 1 {
+        return Ok(None);
+    }
+    let Some(leading) = key.first() else {
+        return Ok(None);
+    };
+    if pk_columns.is_empty() || leading.nullable {
+        warn!(
+            "Table '{table_name}': parallel reads need a primary key and a NOT NULL leading \
+             key column; reading with a single reader"
+        );
+        return Ok(None);
+    }
+    let Some((min, max)) = probe_key_bounds(client, table_name, &leading.name).await? else {
+        return Ok(None);
+    };
+    let Some(ranges) = split_key_range(&min, &max, degree) else {
+        warn!(
+            "Table '{table_name}': leading key column '{}' is not an integer or uuid column; \
+             reading with a single reader",
+            leading.name
+        );
+        return Ok(None);
+    };
+    if ranges.len() < 2 {
+        return Ok(None);
+    }
+
+    info!(
+        "Reading table '{table_name}' in {} key ranges of '{}' ({min:?}..={max:?})",
+        ranges.len(),
+        leading.name
+    );
+    let schema = schema.map(|s| Arc::new(s.clone()));
+    let columns = columns.map(|c| Arc::new(c.clone()));
+    let mut sources = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        let (range_client, connection) = tokio_postgres::connect(source_uri, NoTls).await?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                log::error!("PostgreSQL connection error: {e}");
+            }
+        });
+        sources.push(RangeChunks {
+            client: range_client,
+            table_name: table_name.to_string(),
+            pk_columns: pk_columns.to_vec(),
+            key: key.to_vec(),
+            range,
+            after: None,
+            batch_size: batch_size.max(1),
+            schema: schema.clone(),
+            columns: columns.clone(),
+            exhausted: false,
+        });
+    }
+    Ok(Some(ParallelRowChunks::spawn(sources)))
+}
+
 /// A chunk of relation edges from a join table, with optional keyset cursor.
 #[derive(Debug, Clone)]
 pub struct RelationChunk {
@@ -336,8 +640,9 @@ pub async fn read_relation_chunk(
 /// a quiescent table. Concurrent inserts/deletes can still shift offsets and
 /// cause skipped or duplicated rows — prefer a primary key with keyset reads
 /// or interleaved-snapshot when the table may be written during full sync.
-/// When the table has no PK, row ids are synthetic `Int64(row_index)` values
-/// (MySQL-style), not source primary keys.
+/// When the table has no PK, row ids are synthesized from every column value
+/// (see [`crate::synthesized_row_id`]), so re-syncs and wal2json changes
+/// address the same records.
 pub async fn read_offset_table_chunk(
     client: &Client,
     table_name: &str,
@@ -410,21 +715,30 @@ pub async fn read_offset_relation_chunk(
     Ok(out)
 }
 
-/// Extract the raw primary-key column values from a row, in primary-key column
-/// order, for use as a keyset-pagination cursor.
+/// Extract the raw key column values from a row, in key column order, for use
+/// as a keyset-pagination cursor. NULL key values become [`Value::Null`].
 fn extract_pk_cursor_values(row: &PgRow, pk_columns: &[String]) -> Result<Vec<Value>> {
     let mut values = Vec::with_capacity(pk_columns.len());
     for col in pk_columns {
-        let value = if let Ok(v) = row.try_get::<_, i32>(col.as_str()) {
-            Value::Int32(v)
-        } else if let Ok(v) = row.try_get::<_, i64>(col.as_str()) {
-            Value::Int64(v)
-        } else if let Ok(v) = row.try_get::<_, i16>(col.as_str()) {
-            Value::Int16(v)
-        } else if let Ok(v) = row.try_get::<_, String>(col.as_str()) {
-            Value::Text(v)
-        } else if let Ok(v) = row.try_get::<_, uuid::Uuid>(col.as_str()) {
-            Value::Uuid(v)
+        let col = col.as_str();
+        let value = if let Ok(v) = row.try_get::<_, Option<i32>>(col) {
+            v.map_or(Value::Null, Value::Int32)
+        } else if let Ok(v) = row.try_get::<_, Option<i64>>(col) {
+            v.map_or(Value::Null, Value::Int64)
+        } else if let Ok(v) = row.try_get::<_, Option<i16>>(col) {
+            v.map_or(Value::Null, Value::Int16)
+        } else if let Ok(v) = row.try_get::<_, Option<String>>(col) {
+            v.map_or(Value::Null, Value::Text)
+        } else if let Ok(v) = row.try_get::<_, Option<uuid::Uuid>>(col) {
+            v.map_or(Value::Null, Value::Uuid)
+        } else if let Ok(v) = row.try_get::<_, Option<NaiveDateTime>>(col) {
+            v.map_or(Value::Null, |dt| Value::LocalDateTime(dt.and_utc()))
+        } else if let Ok(v) = row.try_get::<_, Option<DateTime<Utc>>>(col) {
+            v.map_or(Value::Null, Value::ZonedDateTime)
+        } else if let Ok(v) = row.try_get::<_, Option<NaiveDate>>(col) {
+            v.map_or(Value::Null, |d| {
+                Value::Date(d.and_time(NaiveTime::MIN).and_utc())
+            })
         } else {
             return Err(anyhow::anyhow!(
                 "Failed to extract primary key value from column '{col}' for keyset pagination - unsupported data type"
@@ -447,16 +761,54 @@ fn pk_value_to_sql(value: &Value) -> Result<Box<dyn ToSql + Sync + Send>> {
         Value::VarChar { value, .. } => Ok(Box::new(value.clone())),
         Value::Char { value, .. } => Ok(Box::new(value.clone())),
         Value::Uuid(v) => Ok(Box::new(*v)),
+        Value::LocalDateTime(v) => Ok(Box::new(v.naive_utc())),
+        Value::ZonedDateTime(v) => Ok(Box::new(*v)),
+        Value::Date(v) => Ok(Box::new(v.date_naive())),
         other => Err(anyhow::anyhow!(
             "Unsupported primary key value type for keyset pagination: {other:?}"
         )),
     }
 }
 
+/// Resolve user-specified keyset sort columns for a table, probing nullability.
+///
+/// Columns are returned in the given order. Unknown columns are an error so a
+/// typo does not silently degrade into a partial sort key.
+pub async fn get_keyset_columns(
+    client: &Client,
+    table_name: &str,
+    columns: &[String],
+) -> Result<Vec<KeysetColumn>> {
+    let query = "
+        SELECT a.attname, a.attnotnull
+        FROM pg_attribute a
+        WHERE a.attrelid = $1::text::regclass
+        AND a.attnum > 0
+        AND NOT a.attisdropped
+    ";
+    // Bound as text and cast: `&str` does not encode as `regclass`
+    let rows = client.query(query, &[&table_name]).await?;
+    let not_null: HashMap<String, bool> = rows
+        .iter()
+        .map(|row| (row.get::<_, String>(0), row.get::<_, bool>(1)))
+        .collect();
+
+    columns
+        .iter()
+        .map(|col| match not_null.get(col) {
+            Some(true) => Ok(KeysetColumn::new(col)),
+            Some(false) => Ok(KeysetColumn::nullable(col)),
+            None => Err(anyhow::anyhow!(
+                "Keyset column '{col}' does not exist in table '{table_name}'"
+            )),
+        })
+        .collect()
+}
+
 /// Get primary key columns for a table.
 ///
 /// Returns an empty vec when the table has no primary key so callers can fall
-/// back to OFFSET streaming (synthetic row ids) instead of failing the probe.
+/// back to OFFSET streaming (synthesized row ids) instead of failing the probe.
 pub async fn get_primary_key_columns(client: &Client, table_name: &str) -> Result<Vec<String>> {
     let query = format!(
         "
@@ -479,25 +831,27 @@ fn convert_row_to_universal_row(
     pk_columns: &[String],
     row_index: u64,
 ) -> anyhow::Result<Row> {
-    let (id, data) = convert_row_to_keys_and_universal_values(row, pk_columns, row_index)?;
+    let (id, data) = convert_row_to_keys_and_universal_values(row, pk_columns)?;
     Ok(Row::new(table.to_string(), row_index, id, data))
 }
 
 /// Convert a PostgreSQL row to a map of universal values.
 ///
-/// When `pk_columns` is empty, the id is a synthetic `Int64(row_index)` (same
-/// convention as MySQL LIMIT/OFFSET full sync).
+/// When `pk_columns` is empty, the id is synthesized from every column value
+/// ([`crate::synthesized_row_id`]), matching the ids wal2json incremental sync
+/// gives the same rows.
 fn convert_row_to_keys_and_universal_values(
     row: &PgRow,
     pk_columns: &[String],
-    row_index: u64,
-) -> Result<(Value, HashMap<String, Value>)> {
-    let mut record = HashMap::new();
+) -> Result<(Value, FieldMap)> {
+    if pk_columns.is_empty() {
+        let record = convert_all_columns_to_universal_values(row)?;
+        return Ok((crate::synthesized_row_id(&record), record));
+    }
+    let mut record = FieldMap::new();
 
-    // Generate ID from primary key columns, or a stable synthetic index.
-    let id = if pk_columns.is_empty() {
-        Value::Int64(row_index as i64)
-    } else if pk_columns.len() == 1 {
+    // Generate ID from primary key columns
+    let id = if pk_columns.len() == 1 {
         // Single primary key column - extract its value
         let pk_col = &pk_columns[0];
         let id = if let Ok(id) = row.try_get::<_, i32>(pk_col.as_str()) {
@@ -556,8 +910,8 @@ fn convert_row_to_keys_and_universal_values(
 
 /// Convert all columns in a PostgreSQL row to Values (including PK columns).
 /// Used for relation tables where FK columns may overlap with PK columns.
-fn convert_all_columns_to_universal_values(row: &PgRow) -> Result<HashMap<String, Value>> {
-    let mut record = HashMap::new();
+fn convert_all_columns_to_universal_values(row: &PgRow) -> Result<FieldMap> {
+    let mut record = FieldMap::new();
     for (i, column) in row.columns().iter().enumerate() {
         let value = convert_postgres_value_to_universal(row, i)?;
         record.insert(column.name().to_string(), value);
@@ -751,7 +1105,7 @@ fn json_to_universal_value(value: serde_json::Value) -> Value {
             }
         }
         serde_json::Value::Object(map) => {
-            let obj: HashMap<String, Value> = map
+            let obj: FieldMap = map
                 .into_iter()
                 .map(|(k, v)| (k, json_to_universal_value(v)))
                 .collect();
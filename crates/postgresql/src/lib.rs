@@ -13,6 +13,7 @@ mod autoconf;
 mod client;
 pub mod fk_transform;
 mod full_sync;
+mod row_id;
 pub mod schema;
 pub mod testing;
 
@@ -31,14 +32,18 @@ pub mod from_wal2json;
 #[cfg(feature = "from_trigger")]
 pub mod from_trigger;
 
-pub use autoconf::get_user_tables;
+pub use autoconf::{
+    current_schema, estimate_tables, get_schema_tables, get_user_tables, list_tables,
+};
 pub use client::new_postgresql_client;
 #[allow(deprecated)]
 pub use full_sync::{
-    convert_table, get_primary_key_columns, migrate_table, read_offset_relation_chunk,
-    read_offset_table_chunk, read_relation_chunk, read_table_chunk, RelationChunk, SyncOpts,
-    TableChunk,
+    convert_table, get_keyset_columns, get_primary_key_columns, get_read_columns, migrate_table,
+    parallel_range_chunks, probe_key_bounds, read_offset_relation_chunk, read_offset_table_chunk,
+    read_relation_chunk, read_table_chunk, read_table_chunk_by_key, read_table_range_chunk,
+    ReadColumns, RelationChunk, SyncOpts, TableChunk,
 };
+pub use row_id::synthesized_row_id;
 pub use schema::{
     collect_database_schema, collect_database_schema_with_fks, collect_foreign_keys,
     enrich_schema_with_fks,
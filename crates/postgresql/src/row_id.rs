@@ -0,0 +1,184 @@
+//! Record ids for rows of tables without a primary key.
+//!
+//! Such rows have no stable identity of their own, so the id is derived from
+//! every column value. Full sync and wal2json read the same column with
+//! slightly different [`Value`] variants (e.g. `Text` vs `VarChar`, a decimal
+//! as `12.50` vs `12.5`, ISO 8601 duration text as `Duration` vs `Text`), so
+//! values are first rendered in a canonical text form that only keeps what
+//! both readers agree on, then hashed with FNV-1a (128-bit), which is stable
+//! across builds and platforms.
+
+use surreal_sync_core::{parse_iso8601_duration, FieldMap, Value};
+
+const FNV_OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Deterministic record id for a row of a table without a primary key.
+///
+/// The id is a 32-character hex string hashed from every column name and
+/// value, independent of column order. Rows with identical values share an
+/// id, so exact duplicates collapse into one record.
+pub fn synthesized_row_id(columns: &FieldMap) -> Value {
+    let mut names: Vec<&String> = columns.keys().collect();
+    names.sort();
+    let mut text = String::new();
+    for name in names {
+        text.push_str(&format!("{}=", serde_json::Value::from(name.as_str())));
+        canonical_text(&columns[name], &mut text);
+        text.push(';');
+    }
+    let hash = text.bytes().fold(FNV_OFFSET, |hash, byte| {
+        (hash ^ u128::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+    Value::Text(format!("{hash:032x}"))
+}
+
+/// Append the canonical text of `value`: its variant family and content,
+/// without type metadata (lengths, precision) the readers disagree on.
+fn canonical_text(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(&format!("b:{b}")),
+        Value::Int8 { value, .. } => out.push_str(&format!("i:{value}")),
+        Value::Int16(v) => out.push_str(&format!("i:{v}")),
+        Value::Int32(v) => out.push_str(&format!("i:{v}")),
+        Value::Int64(v) => out.push_str(&format!("i:{v}")),
+        Value::Float32(v) => out.push_str(&format!("f:{}", f64::from(*v))),
+        Value::Float64(v) => out.push_str(&format!("f:{v}")),
+        Value::Decimal { value, .. } => {
+            let value = match value.split_once('.') {
+                Some((int, frac)) => match frac.trim_end_matches('0') {
+                    "" => int.to_string(),
+                    frac => format!("{int}.{frac}"),
+                },
+                None => value.clone(),
+            };
+            out.push_str(&format!("n:{value}"));
+        }
+        Value::Char { value, .. }
+        | Value::VarChar { value, .. }
+        | Value::Enum { value, .. }
+        | Value::Text(value) => match parse_iso8601_duration(value) {
+            Some(duration) => canonical_text(&Value::Duration(duration), out),
+            None => out.push_str(&format!("s:{}", serde_json::Value::from(value.as_str()))),
+        },
+        Value::TimeTz(value) => out.push_str(&format!("tz:{value}")),
+        Value::Blob(bytes) | Value::Bytes(bytes) => {
+            out.push_str("x:");
+            for byte in bytes {
+                out.push_str(&format!("{byte:02x}"));
+            }
+        }
+        Value::Date(t)
+        | Value::Time(t)
+        | Value::LocalDateTime(t)
+        | Value::LocalDateTimeNano(t)
+        | Value::ZonedDateTime(t) => out.push_str(&format!("t:{}", t.to_rfc3339())),
+        Value::Duration(d) => out.push_str(&format!("d:{}.{:09}", d.as_secs(), d.subsec_nanos())),
+        Value::Uuid(u) => out.push_str(&format!("u:{u}")),
+        Value::Ulid(u) => out.push_str(&format!("u:{u}")),
+        Value::Json(json) | Value::Jsonb(json) => out.push_str(&format!("j:{json}")),
+        Value::Geometry { data, .. } => out.push_str(&format!("g:{}", data.0)),
+        Value::Array { elements, .. } => {
+            out.push('[');
+            for element in elements {
+                canonical_text(element, out);
+                out.push(',');
+            }
+            out.push(']');
+        }
+        Value::Set { elements, .. } => {
+            out.push('[');
+            for element in elements {
+                out.push_str(&format!("s:{},", serde_json::Value::from(element.as_str())));
+            }
+            out.push(']');
+        }
+        Value::Object(fields) => {
+            let mut names: Vec<&String> = fields.keys().collect();
+            names.sort();
+            out.push('{');
+            for name in names {
+                out.push_str(&format!("{}:", serde_json::Value::from(name.as_str())));
+                canonical_text(&fields[name], out);
+                out.push(',');
+            }
+            out.push('}');
+        }
+        Value::Thing { table, id } => {
+            out.push_str(&format!("r:{table}:"));
+            canonical_text(id, out);
+        }
+        Value::ZeroTemporal { source, .. } => {
+            out.push_str(&format!("z:{}", source.as_deref().unwrap_or_default()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn columns(values: Vec<(&str, Value)>) -> FieldMap {
+        values
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+
+    #[test]
+    fn full_sync_and_wal2json_values_synthesize_the_same_id() {
+        // As read by full sync.
+        let full_sync = columns(vec![
+            ("name", Value::Text("widget".into())),
+            ("qty", Value::Int32(3)),
+            ("price", Value::decimal("12.50", 38, 2)),
+            ("timeout", Value::Duration(Duration::from_secs(5))),
+            ("note", Value::Null),
+        ]);
+        // As decoded from wal2json, in another column order.
+        let wal2json = columns(vec![
+            ("qty", Value::Int32(3)),
+            ("note", Value::Null),
+            (
+                "name",
+                Value::VarChar {
+                    value: "widget".into(),
+                    length: 255,
+                },
+            ),
+            ("price", Value::decimal("12.5", 38, 10)),
+            ("timeout", Value::Text("PT5S".into())),
+        ]);
+        let id = synthesized_row_id(&full_sync);
+        assert_eq!(id, synthesized_row_id(&wal2json));
+        assert!(matches!(&id, Value::Text(hex) if hex.len() == 32));
+    }
+
+    #[test]
+    fn different_rows_synthesize_different_ids() {
+        let row = |qty, note: &str| {
+            columns(vec![
+                ("qty", Value::Int32(qty)),
+                ("note", Value::Text(note.into())),
+            ])
+        };
+        assert_ne!(
+            synthesized_row_id(&row(1, "a")),
+            synthesized_row_id(&row(2, "a"))
+        );
+        assert_ne!(
+            synthesized_row_id(&row(1, "a")),
+            synthesized_row_id(&row(1, "b"))
+        );
+        // Values cannot bleed into neighbouring columns.
+        assert_ne!(
+            synthesized_row_id(&columns(vec![("a", Value::Text("x;b=s:\"y\"".into()))])),
+            synthesized_row_id(&columns(vec![
+                ("a", Value::Text("x".into())),
+                ("b", Value::Text("y".into())),
+            ])),
+        );
+    }
+}
@@ -21,7 +21,8 @@ type FkConstraintEntry = (Vec<String>, String, Vec<String>);
 /// lives in the shared crate so both source crates can use it.
 pub async fn collect_database_schema(client: &Client) -> Result<DatabaseSchema> {
     let columns_query = "
-        SELECT table_name, column_name, data_type, udt_name, numeric_precision, numeric_scale
+        SELECT table_name, column_name, data_type, udt_name, numeric_precision, numeric_scale,
+               is_generated
         FROM information_schema.columns
         WHERE table_schema = 'public'
         ORDER BY table_name, ordinal_position";
@@ -47,7 +48,7 @@ pub async fn collect_database_schema(client: &Client) -> Result<DatabaseSchema>
         pk_columns.entry(table_name).or_default().push(column_name);
     }
 
-    let mut table_columns: HashMap<String, Vec<(String, Type)>> = HashMap::new();
+    let mut table_columns: HashMap<String, Vec<(String, Type, bool)>> = HashMap::new();
     let mut enum_labels: HashMap<String, Vec<String>> = HashMap::new();
     let enum_rows = client
         .query(
@@ -73,6 +74,10 @@ pub async fn collect_database_schema(client: &Client) -> Result<DatabaseSchema>
         let udt_name: String = row.get(3);
         let precision: Option<i32> = row.get(4);
         let scale: Option<i32> = row.get(5);
+        // `GENERATED ALWAYS AS (...) STORED` columns are computed by Postgres
+        // and reject explicit values, so they are synced but marked read-only.
+        let is_generated: Option<String> = row.get(6);
+        let read_only = is_generated.as_deref() == Some("ALWAYS");
 
         let precision = precision.map(|p| p as u32);
         let scale = scale.map(|s| s as u32);
@@ -98,7 +103,7 @@ pub async fn collect_database_schema(client: &Client) -> Result<DatabaseSchema>
         table_columns
             .entry(table_name)
             .or_default()
-            .push((column_name, universal_type));
+            .push((column_name, universal_type, read_only));
     }
 
     let mut tables = Vec::new();
@@ -113,11 +118,13 @@ pub async fn collect_database_schema(client: &Client) -> Result<DatabaseSchema>
         let mut primary_key: Option<ColumnDefinition> = None;
         let mut other_columns = Vec::new();
 
-        for (col_name, col_type) in columns {
-            if col_name == pk_col_name {
-                primary_key = Some(ColumnDefinition::new(col_name, col_type));
+        for (col_name, col_type, read_only) in columns {
+            let mut column = ColumnDefinition::new(col_name, col_type);
+            column.read_only = read_only;
+            if column.name == pk_col_name {
+                primary_key = Some(column);
             } else {
-                other_columns.push(ColumnDefinition::new(col_name, col_type));
+                other_columns.push(column);
             }
         }
 
@@ -3,7 +3,7 @@
 //! This module provides DDL (Data Definition Language) generation for PostgreSQL,
 //! converting sync-core's `Type` to PostgreSQL column type definitions.
 
-use surreal_sync_core::{GeometryType, Type};
+use surreal_sync_core::{GeometryType, TableDefinition, Type};
 
 /// Trait for generating DDL type strings.
 pub trait ToDdl {
@@ -181,6 +181,18 @@ impl PostgreSQLDdl {
         )
     }
 
+    /// Generate an INSERT statement template for writing a row of `table`
+    /// back to PostgreSQL. Read-only (generated) columns are left out, since
+    /// PostgreSQL rejects explicit values for them.
+    pub fn to_insert_for_table(&self, table: &TableDefinition) -> String {
+        let columns: Vec<String> = table
+            .writable_column_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        self.to_insert(&table.name, &columns)
+    }
+
     /// Generate a batch INSERT statement using UNNEST.
     ///
     /// PostgreSQL's UNNEST allows efficient bulk inserts:
@@ -258,6 +270,13 @@ mod tests {
             }),
             "NUMERIC(10,2)"
         );
+        assert_eq!(
+            ddl.to_ddl(&Type::Decimal {
+                precision: 38,
+                scale: 10
+            }),
+            "NUMERIC(38,10)"
+        );
     }
 
     #[test]
@@ -423,6 +442,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_insert_for_table_skips_read_only_columns() {
+        use surreal_sync_core::ColumnDefinition;
+
+        let table = TableDefinition::new(
+            "order_lines",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![
+                ColumnDefinition::new("qty", Type::Int32),
+                ColumnDefinition::new("total", Type::Int32).mark_read_only(),
+            ],
+        );
+        assert_eq!(
+            PostgreSQLDdl.to_insert_for_table(&table),
+            "INSERT INTO \"order_lines\" (\"id\", \"qty\") VALUES ($1, $2)"
+        );
+    }
+
     #[test]
     fn test_batch_insert_unnest() {
         let ddl = PostgreSQLDdl;
@@ -6,7 +6,7 @@
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use postgres_types::Type as PgType;
 use rust_decimal::Decimal;
-use surreal_sync_core::{GeometryType, Type, TypedValue, Value};
+use surreal_sync_core::{FieldMap, GeometryType, Type, TypedValue, Value};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -194,9 +194,11 @@ impl PostgreSQLValueWithSchema {
                     let s = d.to_string();
                     let precision = s.replace(['-', '.'], "").len() as u8;
                     let scale = d.scale() as u8;
-                    Ok(TypedValue::decimal(s, precision, scale))
+                    TypedValue::try_decimal(s, precision, scale)
+                        .map_err(|e| ConversionError::DecimalError(e.to_string()))
                 } else if let PostgreSQLRawValue::Text(s) = &self.value {
-                    Ok(TypedValue::decimal(s.clone(), 38, 10))
+                    TypedValue::try_decimal(s.as_str(), 38, 10)
+                        .map_err(|e| ConversionError::DecimalError(e.to_string()))
                 } else {
                     Err(ConversionError::TypeMismatch {
                         expected: "decimal".to_string(),
@@ -396,6 +398,43 @@ impl PostgreSQLValueWithSchema {
             }
         }
     }
+
+    /// Convert to a TypedValue of `sync_type` where it differs from the
+    /// column's own type.
+    ///
+    /// A JSON/JSONB object read as `Type::Object` becomes a nested
+    /// `Value::Object` (the inverse of the forward conversion, which stores
+    /// objects as JSONB). Other combinations convert as
+    /// [`to_typed_value`](Self::to_typed_value).
+    pub fn to_typed_value_as(&self, sync_type: &Type) -> Result<TypedValue, ConversionError> {
+        match (sync_type, &self.value) {
+            (Type::Object, PostgreSQLRawValue::Null) => Ok(TypedValue::null(Type::Object)),
+            (Type::Object, PostgreSQLRawValue::Json(serde_json::Value::Object(obj))) => {
+                Ok(TypedValue::object(json_object_to_field_map(obj)))
+            }
+            (Type::Object, other) => Err(ConversionError::TypeMismatch {
+                expected: "json object".to_string(),
+                actual: format!("{other:?}"),
+            }),
+            _ => self.to_typed_value(),
+        }
+    }
+}
+
+/// Convert a JSON object to a FieldMap, keeping nested objects as nested
+/// `Value::Object`s.
+fn json_object_to_field_map(obj: &serde_json::Map<String, serde_json::Value>) -> FieldMap {
+    obj.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::Object(nested) => {
+                    Value::Object(json_object_to_field_map(nested))
+                }
+                other => json_to_generated_value(other.clone()),
+            };
+            (key.clone(), value)
+        })
+        .collect()
 }
 
 /// Convert PostgreSQL type to Type.
@@ -445,7 +484,6 @@ fn pg_type_to_sync_type(pg_type: &PgType) -> Type {
 }
 
 /// Convert serde_json::Value to Value.
-#[allow(dead_code)]
 fn json_to_generated_value(json: serde_json::Value) -> Value {
     match json {
         serde_json::Value::Null => Value::Null,
@@ -475,6 +513,7 @@ fn json_to_generated_value(json: serde_json::Value) -> Value {
 mod tests {
     use super::*;
     use chrono::Datelike;
+    use surreal_sync_core::test_support::nested_object;
 
     #[test]
     fn test_bool_conversion() {
@@ -553,6 +592,34 @@ mod tests {
         assert!(matches!(tv.value, Value::Null));
     }
 
+    #[test]
+    fn test_nested_object_round_trip() {
+        let original = nested_object();
+
+        let crate::types::PostgreSQLValue::Json(json) =
+            crate::types::PostgreSQLValue::from(original.clone())
+        else {
+            panic!("Expected objects to be stored as JSONB");
+        };
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Alice",
+                "address": { "city": "Paris", "geo": { "lat": 48.85, "lon": 2.35 } }
+            })
+        );
+        let pv = PostgreSQLValueWithSchema::new(PgType::JSONB, PostgreSQLRawValue::Json(json));
+        let tv = pv.to_typed_value_as(&Type::Object).unwrap();
+        assert_eq!(tv.sync_type, Type::Object);
+        assert_eq!(tv.value, original.value);
+
+        // Without the Object hint, jsonb columns keep their own type
+        assert!(matches!(
+            pv.to_typed_value().unwrap().sync_type,
+            Type::Jsonb
+        ));
+    }
+
     #[test]
     fn test_json_conversion() {
         let json = serde_json::json!({"name": "Alice", "age": 30});
@@ -589,6 +656,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_numeric_must_fit_decimal() {
+        let numeric = |s: &str| {
+            PostgreSQLValueWithSchema::new(PgType::NUMERIC, PostgreSQLRawValue::Text(s.to_string()))
+                .to_typed_value()
+        };
+        assert!(numeric("7922816251426433759354395033.5").is_ok());
+        let err = numeric("79228162514264337593543950336").unwrap_err();
+        assert!(matches!(err, ConversionError::DecimalError(_)), "{err}");
+    }
+
     #[test]
     fn test_text_array_conversion() {
         let arr = vec!["a".to_string(), "b".to_string(), "c".to_string()];
@@ -0,0 +1,69 @@
+//! `--include-columns` / `--exclude-columns` push-down: keyset reads select
+//! only kept columns, and key columns read for ids never leak into fields.
+
+use anyhow::Result;
+use surreal_sync_core::{ColumnFilters, KeysetColumn};
+use surreal_sync_postgresql::{get_read_columns, read_table_chunk_by_key};
+
+#[tokio::test]
+async fn excluded_columns_are_absent_from_synced_rows() -> Result<()> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, "column_filters_trig").await?;
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute(
+            "CREATE TABLE accounts ( \
+                 id INT PRIMARY KEY, \
+                 created_at INT NOT NULL, \
+                 name TEXT NOT NULL, \
+                 password_hash TEXT NOT NULL, \
+                 avatar BYTEA); \
+             INSERT INTO accounts VALUES \
+                 (1, 20, 'a', 'x', '\\x00'), (2, 10, 'b', 'y', NULL)",
+        )
+        .await?;
+
+    let pk = vec!["id".to_string()];
+    let mut filters = ColumnFilters::new();
+    filters
+        .include("accounts", ["id", "name", "password_hash"])
+        .exclude("accounts", ["password_hash"]);
+
+    // Sort by a filtered-out column: it is read for the cursor only.
+    let key = [KeysetColumn::new("created_at")];
+    let read_key = vec!["id".to_string(), "created_at".to_string()];
+    let columns = get_read_columns(&client, "accounts", &filters, &read_key)
+        .await?
+        .expect("accounts is filtered");
+    assert_eq!(columns.select, vec!["id", "created_at", "name"]);
+
+    let chunk = read_table_chunk_by_key(
+        &client,
+        "accounts",
+        &pk,
+        &key,
+        None,
+        10,
+        0,
+        None,
+        Some(&columns),
+    )
+    .await?;
+    assert_eq!(chunk.rows.len(), 2);
+    for row in &chunk.rows {
+        let mut fields: Vec<&String> = row.fields.keys().collect();
+        fields.sort();
+        assert_eq!(fields, vec!["name"], "only kept columns are synced");
+    }
+    assert!(chunk.last_pk.is_some(), "cursor built from the sort key");
+
+    assert!(
+        get_read_columns(&client, "accounts", &ColumnFilters::new(), &read_key)
+            .await?
+            .is_none()
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! `--count-only` estimates: table listing and counts follow the schema the
+//! connection resolves unqualified table names to.
+
+use anyhow::Result;
+use surreal_sync_postgresql::{current_schema, estimate_tables, get_schema_tables};
+
+#[tokio::test]
+async fn estimate_counts_tables_of_the_search_path_schema() -> Result<()> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, "count_only_schema").await?;
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute(
+            "CREATE SCHEMA IF NOT EXISTS shop; \
+             CREATE TABLE public.users (id INT PRIMARY KEY); \
+             CREATE TABLE shop.orders (id INT PRIMARY KEY); \
+             CREATE TABLE shop.surreal_sync_changes (id INT PRIMARY KEY); \
+             INSERT INTO shop.orders VALUES (1), (2), (3); \
+             SET search_path TO shop, public",
+        )
+        .await?;
+
+    let schema = current_schema(&client).await?;
+    assert_eq!(schema, "shop");
+    assert_eq!(get_schema_tables(&client, &schema).await?, vec!["orders"]);
+
+    let estimate = estimate_tables(&client, &schema, &[], false).await?;
+    assert_eq!(estimate.tables.len(), 1);
+    assert_eq!(estimate.tables[0].name, "orders");
+    assert_eq!(estimate.tables[0].rows, 3);
+    Ok(())
+}
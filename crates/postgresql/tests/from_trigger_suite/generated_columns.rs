@@ -0,0 +1,85 @@
+//! `GENERATED ALWAYS AS` columns — marked read-only in the collected schema,
+//! but their computed values are still copied by full sync reads.
+
+use anyhow::Result;
+use surreal_sync_core::{KeysetColumn, Value};
+use surreal_sync_postgresql::from_trigger::schema::collect_postgresql_database_schema;
+use surreal_sync_postgresql::types::PostgreSQLDdl;
+use surreal_sync_postgresql::{collect_database_schema, read_table_chunk_by_key};
+
+#[tokio::test]
+async fn generated_columns_are_read_only_and_still_synced() -> Result<()> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, "generated_cols_trig").await?;
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute(
+            "CREATE TABLE order_lines ( \
+                 id INT PRIMARY KEY, \
+                 qty INT NOT NULL, \
+                 unit_price INT NOT NULL, \
+                 total INT GENERATED ALWAYS AS (qty * unit_price) STORED); \
+             INSERT INTO order_lines (id, qty, unit_price) VALUES (1, 2, 5), (2, 3, 7)",
+        )
+        .await?;
+
+    for schema in [
+        collect_database_schema(&client).await?,
+        collect_postgresql_database_schema(&client).await?,
+    ] {
+        let table = schema.get_table("order_lines").expect("order_lines table");
+        assert!(table.get_column("total").unwrap().read_only);
+        assert!(!table.get_column("qty").unwrap().read_only);
+        assert!(!table.primary_key.read_only);
+        assert_eq!(
+            table.writable_column_names(),
+            vec!["id", "qty", "unit_price"]
+        );
+    }
+
+    let schema = collect_database_schema(&client).await?;
+    let chunk = read_table_chunk_by_key(
+        &client,
+        "order_lines",
+        &["id".to_string()],
+        &[KeysetColumn::new("id")],
+        None,
+        10,
+        0,
+        Some(&schema),
+        None,
+    )
+    .await?;
+    let totals: Vec<Option<&Value>> = chunk
+        .rows
+        .iter()
+        .map(|row| row.fields.get("total"))
+        .collect();
+    assert_eq!(
+        totals,
+        vec![Some(&Value::Int32(10)), Some(&Value::Int32(21))],
+        "generated values must still be copied by full sync"
+    );
+
+    // Writing the synced row back only sets writable columns, so PostgreSQL
+    // recomputes `total` instead of rejecting the insert.
+    let table = schema.get_table("order_lines").expect("order_lines table");
+    client
+        .execute("DELETE FROM order_lines WHERE id = 1", &[])
+        .await?;
+    client
+        .execute(
+            &PostgreSQLDdl.to_insert_for_table(table),
+            &[&1i32, &2i32, &5i32],
+        )
+        .await?;
+    let total: i32 = client
+        .query_one("SELECT total FROM order_lines WHERE id = 1", &[])
+        .await?
+        .get(0);
+    assert_eq!(total, 10);
+    Ok(())
+}
@@ -11,7 +11,7 @@ use std::sync::Mutex;
 use anyhow::Result;
 use async_trait::async_trait;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, ChangeOp, Relation, RelationChange, Row, Value};
+use surreal_sync_core::{Change, ChangeOp, FieldMap, Relation, RelationChange, Row, Value};
 use tokio_postgres::{Client, NoTls};
 
 use surreal_sync_postgresql::from_trigger::{PostgresTriggerWatermarkSource, SourceOpts};
@@ -25,7 +25,7 @@ use surreal_sync_runtime::{
 
 #[derive(Default)]
 struct MockSink {
-    rows: Mutex<HashMap<String, HashMap<String, Value>>>,
+    rows: Mutex<HashMap<String, FieldMap>>,
 }
 
 fn id_key(id: &Value) -> String {
@@ -33,7 +33,7 @@ fn id_key(id: &Value) -> String {
 }
 
 impl MockSink {
-    fn state(&self) -> HashMap<String, HashMap<String, Value>> {
+    fn state(&self) -> HashMap<String, FieldMap> {
         self.rows.lock().unwrap().clone()
     }
 
@@ -121,7 +121,11 @@ fn opts(uri: &str) -> SourceOpts {
         source_uri: uri.to_string(),
         source_database: Some("public".to_string()),
         tables: Vec::new(),
+        table_targets: Default::default(),
         relation_tables: Vec::new(),
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     }
 }
 
@@ -1,9 +1,15 @@
 mod shared;
 
 mod array_trigger_test;
+mod column_filters;
+mod composite_keyset;
+mod count_only;
 mod fk_introspection_test;
+mod generated_columns;
 mod incremental_array_e2e_test;
 mod interleaved_snapshot_test;
 mod no_pk_offset;
+mod parallel_reads;
 mod post_categories_schema_test;
+mod schema_qualified;
 mod transforms;
@@ -154,7 +154,11 @@ async fn full_sync_streams_no_pk_table_via_offset_chunks() -> Result<()> {
             source_uri: conn_str,
             source_database: Some("public".to_string()),
             tables: vec!["notes".to_string()],
+            table_targets: Default::default(),
             relation_tables: vec![],
+            keyset_columns: Default::default(),
+            parallel_reads: Default::default(),
+            column_filters: Default::default(),
         },
         sync_opts,
         None::<&surreal_sync_core::SyncManager<surreal_sync_core::NullStore>>,
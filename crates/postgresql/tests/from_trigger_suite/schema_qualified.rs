@@ -0,0 +1,146 @@
+//! Schema-qualified trigger tracking: same-named tables in two schemas sync
+//! to separate SurrealDB tables.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use surreal_sync_core::{Change, SurrealSink, Value};
+use surreal_sync_postgresql::from_trigger::{
+    run_incremental_sync_with_transforms, PostgreSQLCheckpoint, PostgresIncrementalSource,
+    ReplicationTailOptions, SourceOpts,
+};
+use surreal_sync_runtime::{ApplyOpts, Pipeline};
+use tokio::sync::Mutex as TokioMutex;
+
+#[derive(Default)]
+struct CaptureSink {
+    changes: Mutex<Vec<Change>>,
+}
+
+#[async_trait::async_trait]
+impl SurrealSink for CaptureSink {
+    async fn write_rows(&self, rows: &[surreal_sync_core::Row]) -> anyhow::Result<()> {
+        let mut changes = self.changes.lock().expect("lock");
+        for row in rows {
+            changes.push(Change::update(
+                row.table.clone(),
+                row.id.clone(),
+                row.fields.clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn write_relations(
+        &self,
+        _relations: &[surreal_sync_core::Relation],
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn apply_change(&self, change: &Change) -> anyhow::Result<()> {
+        self.changes.lock().expect("lock").push(change.clone());
+        Ok(())
+    }
+
+    async fn apply_relation_change(
+        &self,
+        _change: &surreal_sync_core::RelationChange,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+fn items_by_table(changes: &[Change]) -> HashMap<String, Vec<String>> {
+    let mut by_table: HashMap<String, Vec<String>> = HashMap::new();
+    for change in changes {
+        let item = match change.fields.as_ref().and_then(|f| f.get("item")) {
+            Some(Value::VarChar { value, .. } | Value::Text(value)) => value.clone(),
+            other => panic!("unexpected item in {change:?}: {other:?}"),
+        };
+        by_table.entry(change.table.clone()).or_default().push(item);
+    }
+    for items in by_table.values_mut() {
+        items.sort();
+        items.dedup();
+    }
+    by_table
+}
+
+#[tokio::test]
+async fn same_table_name_in_two_schemas_syncs_to_separate_targets() -> Result<()> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, "schema_qualified_trig").await?;
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    for schema in ["tenant1", "tenant2"] {
+        client
+            .batch_execute(&format!(
+                "DROP SCHEMA IF EXISTS {schema} CASCADE; \
+                 CREATE SCHEMA {schema}; \
+                 CREATE TABLE {schema}.orders (id INT PRIMARY KEY, item VARCHAR(64))"
+            ))
+            .await?;
+    }
+    client
+        .execute("DROP TABLE IF EXISTS surreal_sync_changes CASCADE", &[])
+        .await?;
+
+    let tables = vec!["tenant1.orders".to_string(), "tenant2.orders".to_string()];
+    let mut source = PostgresIncrementalSource::new(Arc::new(TokioMutex::new(client)), 0);
+    source.setup_tracking(tables.clone()).await?;
+
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute(
+            "INSERT INTO tenant1.orders (id, item) VALUES (1, 'apple'), (2, 'pear'); \
+             INSERT INTO tenant2.orders (id, item) VALUES (1, 'bolt')",
+        )
+        .await?;
+
+    let sink = CaptureSink::default();
+    let source_opts = SourceOpts {
+        source_uri: conn_str,
+        source_database: Some("public".to_string()),
+        tables,
+        table_targets: HashMap::from([(
+            "tenant2.orders".to_string(),
+            "supplier_orders".to_string(),
+        )]),
+        relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+    };
+    run_incremental_sync_with_transforms(
+        &sink,
+        source_opts,
+        PostgreSQLCheckpoint {
+            sequence_id: 0,
+            timestamp: chrono::Utc::now(),
+        },
+        ReplicationTailOptions::stream(chrono::Utc::now() + chrono::Duration::seconds(10), None),
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+    )
+    .await?;
+
+    let by_table = items_by_table(&sink.changes.lock().expect("lock"));
+    assert_eq!(
+        by_table,
+        HashMap::from([
+            (
+                "tenant1_orders".to_string(),
+                vec!["apple".to_string(), "pear".to_string()]
+            ),
+            ("supplier_orders".to_string(), vec!["bolt".to_string()]),
+        ])
+    );
+    Ok(())
+}
@@ -180,7 +180,11 @@ async fn identity_pipeline_incremental_sync() -> Result<()> {
         source_uri: conn_str,
         source_database: Some("public".to_string()),
         tables: vec![table],
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
     let pipeline = Pipeline::new();
     let apply_opts = ApplyOpts::identity();
@@ -247,7 +251,11 @@ async fn external_mutate_worker_transforms_incremental_changes() -> Result<()> {
             source_uri: conn_str,
             source_database: Some("public".to_string()),
             tables: vec![table],
+            table_targets: Default::default(),
             relation_tables: vec![],
+            keyset_columns: Default::default(),
+            parallel_reads: Default::default(),
+            column_filters: Default::default(),
         },
         PostgreSQLCheckpoint {
             sequence_id: 0,
@@ -336,7 +344,11 @@ async fn relation_change_path_records_junction_applies() -> Result<()> {
             source_uri: conn_str,
             source_database: Some("public".to_string()),
             tables,
+            table_targets: Default::default(),
             relation_tables: vec![],
+            keyset_columns: Default::default(),
+            parallel_reads: Default::default(),
+            column_filters: Default::default(),
         },
         PostgreSQLCheckpoint {
             sequence_id: 0,
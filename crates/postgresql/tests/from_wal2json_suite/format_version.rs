@@ -0,0 +1,73 @@
+//! Incremental sync streamed with wal2json `format-version` 1.
+
+use anyhow::Result;
+use surreal_sync_postgresql::from_wal2json::{
+    run_incremental_sync_with_transforms, Client, ReplicationTailOptions, SourceOpts,
+    Wal2jsonFormat,
+};
+use surreal_sync_runtime::{ApplyOpts, Pipeline};
+
+#[tokio::test]
+async fn incremental_sync_streams_with_format_version_1() -> Result<()> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, "format_version_1").await?;
+    let slot = "format_version_1_slot";
+
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute("CREATE TABLE orders (id BIGINT PRIMARY KEY, item TEXT)")
+        .await?;
+
+    let (pg_client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    let repl = Client::new(pg_client, vec!["orders".to_string()]);
+    repl.create_slot(slot).await?;
+    let checkpoint = repl.get_current_wal_lsn_checkpoint().await?;
+
+    client
+        .batch_execute(
+            "INSERT INTO orders VALUES (1, 'a'), (2, 'b'), (3, 'c');
+             UPDATE orders SET item = 'B' WHERE id = 2;
+             DELETE FROM orders WHERE id = 3;",
+        )
+        .await?;
+
+    let db = surrealdb::engine::any::connect("memory").await?;
+    db.use_ns("test").use_db("test").await?;
+    let sink = surreal_sync_surreal::v2::Surreal2Sink::new(db.clone());
+    let source_opts = SourceOpts {
+        connection_string: conn_str.clone(),
+        slot_name: slot.to_string(),
+        tables: vec!["orders".to_string()],
+        schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
+        relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Wal2jsonFormat::V1,
+    };
+    run_incremental_sync_with_transforms(
+        &sink,
+        source_opts,
+        checkpoint,
+        ReplicationTailOptions::stream(chrono::Utc::now() + chrono::Duration::seconds(10), None),
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+    )
+    .await?;
+
+    let mut resp = db
+        .query("SELECT VALUE [meta::id(id), item] FROM orders ORDER BY id")
+        .await?;
+    let rows: Vec<(i64, String)> = resp.take(0)?;
+    assert_eq!(rows, vec![(1, "a".to_string()), (2, "B".to_string())]);
+    Ok(())
+}
@@ -113,7 +113,14 @@ fn source_opts(conn: &str, slot: &str) -> SourceOpts {
         slot_name: slot.to_string(),
         tables: vec![],
         schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Default::default(),
     }
 }
 
@@ -2,12 +2,16 @@ mod shared;
 
 mod conversion;
 mod date;
+mod format_version;
 mod integration;
 mod interleaved_snapshot;
 mod interval;
 mod no_pk_offset;
+mod publication;
 mod time;
 mod timestamp;
 mod timestamptz;
 mod timetz;
+mod toast;
 mod transforms;
+mod truncate;
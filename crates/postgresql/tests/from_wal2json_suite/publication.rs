@@ -0,0 +1,103 @@
+//! Publication preflight in `Client::bind_publication`.
+
+use anyhow::Result;
+use surreal_sync_postgresql::from_wal2json::Client;
+
+/// Create `db_name` with two tables and return a replication client tracking
+/// `orders`, plus a plain client for assertions.
+async fn setup(db_name: &str) -> Result<(Client, tokio_postgres::Client)> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, db_name).await?;
+
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute(
+            "CREATE TABLE orders (id BIGINT PRIMARY KEY, item TEXT);
+             CREATE TABLE audit (id BIGINT PRIMARY KEY, note TEXT);",
+        )
+        .await?;
+
+    let (pg_client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    Ok((Client::new(pg_client, vec!["orders".to_string()]), client))
+}
+
+#[tokio::test]
+async fn missing_publication_is_created_for_configured_tables() -> Result<()> {
+    let (mut repl, client) = setup("publication_create").await?;
+
+    let info = repl.bind_publication("orders_pub", "public", true).await?;
+    assert!(info.created);
+    assert_eq!(
+        info.tables,
+        vec![("public".to_string(), "orders".to_string())]
+    );
+
+    let published: Vec<String> = client
+        .query(
+            "SELECT tablename::text FROM pg_publication_tables WHERE pubname = 'orders_pub'",
+            &[],
+        )
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(published, vec!["orders".to_string()]);
+
+    // A second preflight finds the publication and does not recreate it.
+    let info = repl.bind_publication("orders_pub", "public", true).await?;
+    assert!(!info.created);
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_publication_without_create_is_an_error() -> Result<()> {
+    let (mut repl, client) = setup("publication_missing").await?;
+
+    let err = repl
+        .bind_publication("orders_pub", "public", false)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("does not exist"),
+        "unexpected error: {err}"
+    );
+
+    let count: i64 = client
+        .query_one(
+            "SELECT count(*) FROM pg_publication WHERE pubname = 'orders_pub'",
+            &[],
+        )
+        .await?
+        .get(0);
+    assert_eq!(count, 0, "preflight must not create without opt-in");
+    Ok(())
+}
+
+#[tokio::test]
+async fn table_published_in_another_schema_is_an_error() -> Result<()> {
+    let (mut repl, client) = setup("publication_other_schema").await?;
+    client
+        .batch_execute(
+            "CREATE SCHEMA other;
+             CREATE TABLE other.orders (id BIGINT PRIMARY KEY);
+             CREATE PUBLICATION orders_pub FOR TABLE other.orders;",
+        )
+        .await?;
+
+    // `other.orders` is published, `public.orders` is not.
+    let err = repl
+        .bind_publication("orders_pub", "public", false)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("not in publication"),
+        "unexpected error: {err}"
+    );
+    Ok(())
+}
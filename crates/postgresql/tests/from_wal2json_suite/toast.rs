@@ -0,0 +1,82 @@
+//! Unchanged TOAST columns in wal2json incremental sync.
+
+use anyhow::Result;
+use surreal_sync_postgresql::from_wal2json::{
+    run_incremental_sync_with_transforms, Client, ReplicationTailOptions, SourceOpts,
+};
+use surreal_sync_runtime::{ApplyOpts, Pipeline};
+
+/// An update of a small column leaves a large, out-of-line `body` unchanged;
+/// the streamed update must not clear it in SurrealDB.
+#[tokio::test]
+async fn update_keeps_unchanged_toast_column() -> Result<()> {
+    let db_name = "toast_unchanged";
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, db_name).await?;
+    let slot = format!("{db_name}_slot");
+
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    // EXTERNAL storage keeps the body out of line and uncompressed, so it is
+    // always TOASTed.
+    client
+        .batch_execute(
+            "CREATE TABLE posts (id BIGINT PRIMARY KEY, title TEXT, body TEXT);
+             ALTER TABLE posts ALTER COLUMN body SET STORAGE EXTERNAL;",
+        )
+        .await?;
+
+    let (pg_client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    let repl = Client::new(pg_client, vec!["posts".to_string()]);
+    repl.create_slot(&slot).await?;
+    let checkpoint = repl.get_current_wal_lsn_checkpoint().await?;
+
+    let body = "x".repeat(64 * 1024);
+    client
+        .execute(
+            "INSERT INTO posts VALUES (1, 'draft', $1)",
+            &[&body.as_str()],
+        )
+        .await?;
+    client
+        .batch_execute("UPDATE posts SET title = 'published' WHERE id = 1")
+        .await?;
+
+    let db = surrealdb::engine::any::connect("memory").await?;
+    db.use_ns("test").use_db("test").await?;
+    let sink = surreal_sync_surreal::v2::Surreal2Sink::new(db.clone());
+    run_incremental_sync_with_transforms(
+        &sink,
+        SourceOpts {
+            connection_string: conn_str.clone(),
+            slot_name: slot,
+            tables: vec!["posts".to_string()],
+            schema: "public".to_string(),
+            publication_name: None,
+            create_publication: false,
+            relation_tables: vec![],
+            keyset_columns: Default::default(),
+            parallel_reads: Default::default(),
+            column_filters: Default::default(),
+            apply_truncate: false,
+            format_version: Default::default(),
+        },
+        checkpoint,
+        ReplicationTailOptions::stream(chrono::Utc::now() + chrono::Duration::seconds(10), None),
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+    )
+    .await?;
+
+    let mut resp = db
+        .query("SELECT VALUE [title, string::len(body)] FROM posts:1")
+        .await?;
+    let rows: Vec<(String, i64)> = resp.take(0)?;
+    assert_eq!(rows, vec![("published".to_string(), body.len() as i64)]);
+    Ok(())
+}
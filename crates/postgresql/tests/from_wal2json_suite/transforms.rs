@@ -139,7 +139,14 @@ fn source_opts(conn_str: &str, slot: &str, tables: Vec<String>) -> SourceOpts {
         slot_name: slot.to_string(),
         tables,
         schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Default::default(),
     }
 }
 
@@ -0,0 +1,102 @@
+//! Source `TRUNCATE` handling in wal2json incremental sync.
+
+use anyhow::Result;
+use surreal_sync_postgresql::from_wal2json::{
+    run_incremental_sync_with_transforms, Action, Client, ReplicationTailOptions, SourceOpts,
+};
+use surreal_sync_runtime::{ApplyOpts, Pipeline};
+
+fn source_opts(conn_str: &str, slot: &str, apply_truncate: bool) -> SourceOpts {
+    SourceOpts {
+        connection_string: conn_str.to_string(),
+        slot_name: slot.to_string(),
+        tables: vec!["orders".to_string()],
+        schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
+        relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate,
+        format_version: Default::default(),
+    }
+}
+
+/// Insert two rows, TRUNCATE, insert one more, then stream the slot into an
+/// in-memory SurrealDB. Returns the ids left in SurrealDB.
+async fn sync_truncated_table(db_name: &str, apply_truncate: bool) -> Result<Vec<i64>> {
+    let container = crate::shared::postgres().await;
+    let conn_str = crate::shared::create_test_db(container, db_name).await?;
+    let slot = format!("{db_name}_slot");
+
+    let (client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    client
+        .batch_execute("CREATE TABLE orders (id BIGINT PRIMARY KEY, item TEXT)")
+        .await?;
+
+    let (pg_client, connection) = tokio_postgres::connect(&conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    let repl = Client::new(pg_client, vec!["orders".to_string()]);
+    repl.create_slot(&slot).await?;
+    let checkpoint = repl.get_current_wal_lsn_checkpoint().await?;
+
+    client
+        .batch_execute(
+            "INSERT INTO orders VALUES (1, 'a'), (2, 'b');
+             TRUNCATE orders;
+             INSERT INTO orders VALUES (3, 'c');",
+        )
+        .await?;
+
+    // The slot decodes the TRUNCATE as its own action.
+    let slot_handle = repl.start_replication(Some(&slot)).await?;
+    let (actions, _) = slot_handle.peek().await?;
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::Truncate { table, .. } if table == "orders")),
+        "expected a TRUNCATE action, got {actions:?}"
+    );
+
+    let db = surrealdb::engine::any::connect("memory").await?;
+    db.use_ns("test").use_db("test").await?;
+    let sink = surreal_sync_surreal::v2::Surreal2Sink::new(db.clone());
+    run_incremental_sync_with_transforms(
+        &sink,
+        source_opts(&conn_str, &slot, apply_truncate),
+        checkpoint,
+        ReplicationTailOptions::stream(chrono::Utc::now() + chrono::Duration::seconds(10), None),
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+    )
+    .await?;
+
+    let mut resp = db.query("SELECT VALUE meta::id(id) FROM orders").await?;
+    let mut ids: Vec<i64> = resp.take(0)?;
+    ids.sort();
+    Ok(ids)
+}
+
+#[tokio::test]
+async fn truncate_empties_surreal_table_when_enabled() -> Result<()> {
+    let ids = sync_truncated_table("truncate_apply", true).await?;
+    assert_eq!(ids, vec![3], "rows before the TRUNCATE must be removed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn truncate_is_skipped_by_default() -> Result<()> {
+    let ids = sync_truncated_table("truncate_skip", false).await?;
+    assert_eq!(
+        ids,
+        vec![1, 2, 3],
+        "TRUNCATE must not delete without opt-in"
+    );
+    Ok(())
+}
@@ -3,6 +3,7 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 use surreal_sync_core::{CheckpointID, CheckpointStore, StoredCheckpoint};
 
@@ -23,6 +24,32 @@ impl FilesystemStore {
     pub fn dir(&self) -> &PathBuf {
         &self.dir
     }
+
+    /// Every checkpoint file in the directory with its parsed contents.
+    ///
+    /// Ids come from the file contents, not the filename, so files written
+    /// under older naming schemes are still found. Files that don't parse
+    /// as a checkpoint are skipped.
+    async fn stored_files(&self) -> Result<Vec<(PathBuf, StoredCheckpoint)>> {
+        let mut files = Vec::new();
+        if !tokio::fs::try_exists(&self.dir).await.unwrap_or(false) {
+            return Ok(files);
+        }
+
+        let mut entries = tokio::fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let content = tokio::fs::read_to_string(&path).await?;
+            match serde_json::from_str::<StoredCheckpoint>(&content) {
+                Ok(stored) => files.push((path, stored)),
+                Err(e) => tracing::debug!("Skipping {}: not a checkpoint ({e})", path.display()),
+            }
+        }
+        Ok(files)
+    }
 }
 
 #[async_trait]
@@ -77,4 +104,27 @@ impl CheckpointStore for FilesystemStore {
             None => Ok(None),
         }
     }
+
+    async fn list_checkpoints(&self) -> Result<Vec<CheckpointID>> {
+        let ids: BTreeSet<CheckpointID> = self
+            .stored_files()
+            .await?
+            .into_iter()
+            .map(|(_, stored)| CheckpointID {
+                database_type: stored.database_type,
+                phase: stored.phase,
+            })
+            .collect();
+        Ok(ids.into_iter().collect())
+    }
+
+    async fn delete_checkpoint(&self, id: &CheckpointID) -> Result<()> {
+        for (path, stored) in self.stored_files().await? {
+            if stored.database_type == id.database_type && stored.phase == id.phase {
+                tokio::fs::remove_file(&path).await?;
+                tracing::info!("Deleted checkpoint {}", path.display());
+            }
+        }
+        Ok(())
+    }
 }
@@ -234,6 +234,51 @@ async fn test_sync_manager_emit_and_read() {
     assert_eq!(read.value, 123);
 }
 
+#[tokio::test]
+async fn test_filesystem_store_list_and_delete() {
+    use surreal_sync_core::{CheckpointID, CheckpointStore};
+
+    let tmp = TempDir::new().unwrap();
+    let store = FilesystemStore::new(tmp.path());
+    assert!(store.list_checkpoints().await.unwrap().is_empty());
+
+    let start = CheckpointID {
+        database_type: "mysql".to_string(),
+        phase: "full_sync_start".to_string(),
+    };
+    let end = CheckpointID {
+        database_type: "mysql".to_string(),
+        phase: "full_sync_end".to_string(),
+    };
+    store
+        .store_checkpoint(&start, "1".to_string())
+        .await
+        .unwrap();
+    store.store_checkpoint(&end, "2".to_string()).await.unwrap();
+    store.store_checkpoint(&end, "3".to_string()).await.unwrap();
+
+    // Ids come from the contents: a file under an old name is still listed,
+    // and non-checkpoint JSON is ignored.
+    let legacy = r#"{"checkpoint_data":"0","database_type":"mongodb","phase":"full_sync_start","created_at":"2024-01-01T00:00:00Z"}"#;
+    std::fs::write(tmp.path().join("mongodb-t1.json"), legacy).unwrap();
+    std::fs::write(tmp.path().join("notes.json"), "{}").unwrap();
+
+    let mongodb = CheckpointID {
+        database_type: "mongodb".to_string(),
+        phase: "full_sync_start".to_string(),
+    };
+    assert_eq!(
+        store.list_checkpoints().await.unwrap(),
+        vec![mongodb.clone(), end.clone(), start.clone()]
+    );
+
+    store.delete_checkpoint(&end).await.unwrap();
+    store.delete_checkpoint(&mongodb).await.unwrap();
+    assert_eq!(store.list_checkpoints().await.unwrap(), vec![start.clone()]);
+    assert!(store.read_checkpoint(&end).await.unwrap().is_none());
+    assert!(tmp.path().join("notes.json").exists());
+}
+
 #[tokio::test]
 async fn test_sync_manager_emit_disabled() {
     let tmp = TempDir::new().unwrap();
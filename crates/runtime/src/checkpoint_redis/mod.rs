@@ -0,0 +1,134 @@
+//! Redis checkpoint storage for surreal-sync.
+//!
+//! Provides [`RedisStore`], a [`CheckpointStore`] shared by every process
+//! that points at the same Redis server — useful when syncs run in
+//! ephemeral containers without a persistent filesystem.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::StreamExt;
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use surreal_sync_core::{CheckpointID, CheckpointStore, StoredCheckpoint};
+
+/// Prefix of every checkpoint key.
+const KEY_PREFIX: &str = "surreal-sync:checkpoint:";
+
+/// Redis implementation of CheckpointStore trait.
+///
+/// Stores each checkpoint as a JSON string under
+/// `surreal-sync:checkpoint:{database_type}:{phase}`; a new checkpoint for
+/// the same id replaces the previous one.
+#[derive(Clone)]
+pub struct RedisStore {
+    connection: MultiplexedConnection,
+}
+
+impl RedisStore {
+    /// Connect to the Redis server at `url` (`redis://[user:pass@]host[:port][/db]`).
+    pub async fn new(url: &str) -> Result<Self> {
+        let client =
+            redis::Client::open(url).with_context(|| format!("Invalid Redis URL: {url}"))?;
+        let connection = client
+            .get_multiplexed_async_connection()
+            .await
+            .with_context(|| format!("Failed to connect to Redis at {url}"))?;
+        Ok(Self { connection })
+    }
+}
+
+/// Key a checkpoint is stored under.
+fn key(id: &CheckpointID) -> String {
+    format!("{KEY_PREFIX}{}:{}", id.database_type, id.phase)
+}
+
+/// The checkpoint id a key was built from. Phases never contain `:`.
+fn parse_key(key: &str) -> Option<CheckpointID> {
+    let (database_type, phase) = key.strip_prefix(KEY_PREFIX)?.rsplit_once(':')?;
+    Some(CheckpointID {
+        database_type: database_type.to_string(),
+        phase: phase.to_string(),
+    })
+}
+
+#[async_trait]
+impl CheckpointStore for RedisStore {
+    async fn store_checkpoint(&self, id: &CheckpointID, checkpoint_data: String) -> Result<()> {
+        let stored = StoredCheckpoint {
+            checkpoint_data,
+            database_type: id.database_type.clone(),
+            phase: id.phase.clone(),
+            created_at: Utc::now(),
+        };
+
+        let key = key(id);
+        let mut connection = self.connection.clone();
+        let _: () = connection
+            .set(&key, serde_json::to_string(&stored)?)
+            .await
+            .with_context(|| format!("Failed to store checkpoint in Redis key {key}"))?;
+        tracing::info!("Stored checkpoint to Redis key {key}");
+        Ok(())
+    }
+
+    async fn read_checkpoint(&self, id: &CheckpointID) -> Result<Option<StoredCheckpoint>> {
+        let key = key(id);
+        let mut connection = self.connection.clone();
+        let value: Option<String> = connection
+            .get(&key)
+            .await
+            .with_context(|| format!("Failed to read checkpoint from Redis key {key}"))?;
+        value
+            .map(|json| {
+                serde_json::from_str(&json)
+                    .with_context(|| format!("Invalid checkpoint JSON in Redis key {key}"))
+            })
+            .transpose()
+    }
+
+    /// Ids of every checkpoint key, found with `SCAN`.
+    async fn list_checkpoints(&self) -> Result<Vec<CheckpointID>> {
+        let mut connection = self.connection.clone();
+        let mut keys: Vec<String> = connection
+            .scan_match::<_, String>(format!("{KEY_PREFIX}*"))
+            .await?
+            .collect()
+            .await;
+        keys.sort();
+        Ok(keys.iter().filter_map(|key| parse_key(key)).collect())
+    }
+
+    async fn delete_checkpoint(&self, id: &CheckpointID) -> Result<()> {
+        let key = key(id);
+        let mut connection = self.connection.clone();
+        let _: () = connection
+            .del(&key)
+            .await
+            .with_context(|| format!("Failed to delete Redis key {key}"))?;
+        tracing::info!("Deleted checkpoint Redis key {key}");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_roundtrip() {
+        let id = CheckpointID {
+            database_type: "postgresql-pgoutput".to_string(),
+            phase: "full_sync_start".to_string(),
+        };
+        let key = key(&id);
+        assert_eq!(
+            key,
+            "surreal-sync:checkpoint:postgresql-pgoutput:full_sync_start"
+        );
+        let parsed = parse_key(&key).unwrap();
+        assert_eq!(parsed.database_type, id.database_type);
+        assert_eq!(parsed.phase, id.phase);
+        assert!(parse_key("other:checkpoint:mysql:full_sync_end").is_none());
+    }
+}
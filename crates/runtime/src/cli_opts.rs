@@ -2,8 +2,16 @@
 //!
 //! Prefer [`crate::SurrealConfig`] when not parsing CLI flags.
 
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
 use clap::Args;
-use surreal_sync_core::ZeroTemporalPolicy;
+use surreal_sync_core::{
+    ArrayMergeStrategy, CircuitBreakerConfig, ColumnFilterError, ColumnFilters, ConflictPolicy,
+    LargeFieldConfig, MergeConfig, SoftDeleteConfig, WriteRetryConfig, WriteTemplate,
+    ZeroTemporalPolicy,
+};
 
 use crate::SurrealConfig;
 
@@ -34,6 +42,142 @@ pub struct SurrealCliOpts {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Concurrent write tasks per table within each batch. Independent records
+    /// may be written out of order; relations still follow their records.
+    #[arg(long, default_value = "1", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub write_concurrency: usize,
+
+    /// Write concurrency for one table, overriding `--write-concurrency`
+    /// (repeatable), e.g. `--table-write-concurrency events=16`.
+    #[arg(
+        long = "table-write-concurrency",
+        value_name = "TABLE=N",
+        value_parser = parse_table_write_concurrency
+    )]
+    pub table_write_concurrency: Vec<(String, usize)>,
+
+    /// Tables whose records are written strictly in source order through a
+    /// single write stream (comma-separated), e.g. tables with events that
+    /// depend on insertion order. `--write-concurrency` does not apply to
+    /// them; other tables keep writing concurrently.
+    #[arg(long, value_name = "TABLES", value_delimiter = ',')]
+    pub ordered_tables: Vec<String>,
+
+    /// SurrealDB connections the concurrent write tasks are spread over. One
+    /// connection multiplexes every request over a single WebSocket; extra
+    /// connections are opened on the first concurrent write.
+    #[arg(long, default_value = "1", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub connection_pool_size: usize,
+
+    /// Tries per batch write, the first one included, while the circuit
+    /// breaker is disabled. Only transient failures (connection loss,
+    /// timeouts, unavailable server) are retried; other errors fail at once.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    pub write_max_attempts: u32,
+
+    /// Milliseconds before the first retry of a transiently failed batch
+    /// write; the delay doubles after each further failure.
+    #[arg(long, default_value_t = 100)]
+    pub write_retry_backoff_ms: u64,
+
+    /// Consecutive failed batch writes that open the write circuit breaker.
+    /// Transiently failed batches (connection loss, timeouts, unavailable
+    /// server) are retried until then and other errors fail at once; `0`
+    /// (default) disables the breaker so a failed batch is only retried up
+    /// to `--write-max-attempts`.
+    #[arg(long, default_value_t = 0)]
+    pub write_failure_threshold: u32,
+
+    /// Seconds writes pause after the circuit opens (or a probe fails) before
+    /// one probe batch is retried.
+    #[arg(long, default_value_t = 5)]
+    pub write_circuit_cooldown_secs: u64,
+
+    /// Seconds the circuit may stay open before the sync fails.
+    #[arg(long, default_value_t = 300)]
+    pub write_circuit_deadline_secs: u64,
+
+    /// Last-write-wins field for incremental sync: a create/update only
+    /// applies when its value for this field is greater than the stored
+    /// record's, so out-of-order events cannot regress a record. Changes
+    /// without the field, deletes, and full-sync writes are unconditional.
+    #[arg(long, value_name = "FIELD")]
+    pub version_field: Option<String>,
+
+    /// Soft-delete field for incremental sync: a delete sets this datetime
+    /// field to the apply time instead of removing the record. Relation
+    /// deletes and truncations still remove data.
+    #[arg(long, value_name = "FIELD")]
+    pub soft_delete_field: Option<String>,
+
+    /// Boolean field also set to `true` on soft-deleted records. Requires
+    /// `--soft-delete-field`.
+    #[arg(long, value_name = "FIELD", requires = "soft_delete_field")]
+    pub soft_delete_flag_field: Option<String>,
+
+    /// Deep-merge incremental creates/updates into the stored record instead
+    /// of replacing it: nested objects merge key by key, so fields a partial
+    /// change omits are kept, and arrays follow `--merge-arrays`.
+    #[arg(long)]
+    pub merge_updates: bool,
+
+    /// Array strategy for `--merge-updates`: `replace` (default), `append`
+    /// or `union-by-key:FIELD` (stored elements with a matching FIELD are
+    /// replaced, new ones appended).
+    #[arg(long, value_name = "STRATEGY", requires = "merge_updates")]
+    pub merge_arrays: Option<ArrayMergeStrategy>,
+
+    /// Array strategy for one dotted field path, overriding `--merge-arrays`
+    /// (repeatable), e.g. `--merge-array profile.tags=append`.
+    #[arg(
+        long = "merge-array",
+        value_name = "PATH=STRATEGY",
+        value_parser = parse_merge_array,
+        requires = "merge_updates"
+    )]
+    pub merge_array_fields: Vec<(String, ArrayMergeStrategy)>,
+
+    /// Apply incremental updates as patches of only the fields they changed,
+    /// for sources that know them (wal2json and binlog before images, MongoDB
+    /// update descriptions). Other updates write the whole record.
+    #[arg(long)]
+    pub skip_unchanged_fields: bool,
+
+    /// Dead-letter table: a record whose write fails after retries is stored
+    /// in this SurrealDB table (source data as JSON, error, source table and
+    /// row position) and the sync continues. By default the sync fails.
+    #[arg(long, value_name = "TABLE")]
+    pub error_table: Option<String>,
+
+    /// Detect incremental creates/updates to records modified outside
+    /// surreal-sync since it last wrote them (via a `_sync_meta` checksum
+    /// stamped on written records) and `overwrite` them, `skip` them, or
+    /// store the change in `--error-table` (`error-table`). Off by default.
+    #[arg(
+        long,
+        value_name = "POLICY",
+        conflicts_with_all = ["version_field", "merge_updates", "skip_unchanged_fields"]
+    )]
+    pub conflict_policy: Option<ConflictPolicy>,
+
+    /// Offload string / byte field values longer than this many bytes to
+    /// files under `--large-field-dir`; the record stores a `{ path, size,
+    /// kind }` reference instead of the value. Requires `--large-field-dir`.
+    #[arg(long, value_name = "BYTES", requires = "large_field_dir")]
+    pub large_field_threshold: Option<usize>,
+
+    /// Directory offloaded large field values are written to. Requires
+    /// `--large-field-threshold`.
+    #[arg(long, value_name = "DIR", requires = "large_field_threshold")]
+    pub large_field_dir: Option<PathBuf>,
+
+    /// Write a table's records with a SurrealQL statement instead of the
+    /// built-in upsert (repeatable), e.g. `--write-template 'users=UPSERT $id
+    /// SET name = $name, name_len = string::len($name)'`. `$id` is the record
+    /// id, `$content` the record and other `$names` its fields.
+    #[arg(long = "write-template", value_name = "TABLE=STATEMENT")]
+    pub write_templates: Vec<WriteTemplate>,
+
     /// SurrealDB SDK version to use. Auto-detects from server if not specified.
     ///
     /// **CLI-only:** embed `run::<OneSink>` ignores this and monomorphizes one sink.
@@ -61,6 +205,124 @@ impl SurrealCliOpts {
             zero_temporal: self.zero_temporal,
             batch_size: self.batch_size,
             dry_run: self.dry_run,
+            write_concurrency: self.write_concurrency,
+            table_write_concurrency: self.table_write_concurrency(),
+            ordered_tables: self.ordered_tables.clone(),
+            connection_pool_size: self.connection_pool_size,
+            circuit_breaker: self.circuit_breaker(),
+            write_retry: self.write_retry(),
+            version_field: self.version_field.clone(),
+            soft_delete: self.soft_delete(),
+            merge: self.merge(),
+            skip_unchanged_fields: self.skip_unchanged_fields,
+            error_table: self.error_table.clone(),
+            conflict_policy: self.conflict_policy,
+            large_fields: self.large_fields(),
+            write_templates: self.write_templates.clone(),
+        }
+    }
+
+    /// Per-table write concurrency from `--table-write-concurrency`.
+    pub fn table_write_concurrency(&self) -> BTreeMap<String, usize> {
+        self.table_write_concurrency.iter().cloned().collect()
+    }
+
+    /// Soft-delete mode from `--soft-delete-field` / `--soft-delete-flag-field`.
+    pub fn soft_delete(&self) -> Option<SoftDeleteConfig> {
+        self.soft_delete_field
+            .as_ref()
+            .map(|field| SoftDeleteConfig {
+                deleted_at_field: field.clone(),
+                deleted_flag_field: self.soft_delete_flag_field.clone(),
+            })
+    }
+
+    /// Deep-merge mode from `--merge-updates` / `--merge-arrays` /
+    /// `--merge-array`.
+    pub fn merge(&self) -> Option<MergeConfig> {
+        self.merge_updates.then(|| MergeConfig {
+            arrays: self.merge_arrays.clone().unwrap_or_default(),
+            array_fields: self.merge_array_fields.iter().cloned().collect(),
+        })
+    }
+
+    /// Large-field offload from `--large-field-threshold` /
+    /// `--large-field-dir`.
+    pub fn large_fields(&self) -> Option<LargeFieldConfig> {
+        match (self.large_field_threshold, &self.large_field_dir) {
+            (Some(threshold), Some(dir)) => Some(LargeFieldConfig::new(threshold, dir)),
+            _ => None,
+        }
+    }
+
+    /// Write circuit-breaker thresholds from the `--write-*` flags.
+    pub fn circuit_breaker(&self) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: self.write_failure_threshold,
+            cooldown: Duration::from_secs(self.write_circuit_cooldown_secs),
+            open_deadline: Duration::from_secs(self.write_circuit_deadline_secs),
+        }
+    }
+
+    /// Write retry attempts and backoff from `--write-max-attempts` and
+    /// `--write-retry-backoff-ms`.
+    pub fn write_retry(&self) -> WriteRetryConfig {
+        WriteRetryConfig {
+            max_attempts: self.write_max_attempts,
+            backoff: Duration::from_millis(self.write_retry_backoff_ms),
         }
     }
 }
+
+fn parse_table_write_concurrency(s: &str) -> anyhow::Result<(String, usize)> {
+    let (table, concurrency) = s
+        .split_once('=')
+        .filter(|(table, _)| !table.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("expected TABLE=N, got '{s}'"))?;
+    match concurrency.parse::<usize>() {
+        Ok(n) if n >= 1 => Ok((table.to_string(), n)),
+        _ => anyhow::bail!("expected a concurrency of at least 1, got '{concurrency}'"),
+    }
+}
+
+fn parse_merge_array(s: &str) -> anyhow::Result<(String, ArrayMergeStrategy)> {
+    let (path, strategy) = s
+        .split_once('=')
+        .filter(|(path, _)| !path.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("expected PATH=STRATEGY, got '{s}'"))?;
+    Ok((path.to_string(), strategy.parse()?))
+}
+
+/// Clap per-table column include/exclude flags (shared by every `from *`
+/// sync command).
+#[derive(Args, Clone, Debug, Default)]
+pub struct ColumnFilterCliOpts {
+    /// Sync only these columns of a table: `table=col1,col2` (repeatable).
+    /// Key columns are always read.
+    #[arg(long = "include-columns", value_name = "TABLE=COLUMNS")]
+    pub include_columns: Vec<String>,
+
+    /// Drop these columns of a table: `table=col1,col2` (repeatable).
+    /// Wins over `--include-columns`.
+    #[arg(long = "exclude-columns", value_name = "TABLE=COLUMNS")]
+    pub exclude_columns: Vec<String>,
+
+    /// Drop fields matching these glob patterns from every table, e.g.
+    /// `__*,*_internal` (comma-separated, repeatable). `id`, `_id` and
+    /// record IDs are never dropped.
+    #[arg(
+        long = "exclude-fields",
+        value_name = "PATTERNS",
+        value_delimiter = ','
+    )]
+    pub exclude_fields: Vec<String>,
+}
+
+impl ColumnFilterCliOpts {
+    /// Parse the flags into [`ColumnFilters`].
+    pub fn to_filters(&self) -> Result<ColumnFilters, ColumnFilterError> {
+        let mut filters = ColumnFilters::parse(&self.include_columns, &self.exclude_columns)?;
+        filters.exclude_fields(self.exclude_fields.iter().map(|p| p.trim()));
+        Ok(filters)
+    }
+}
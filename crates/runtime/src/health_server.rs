@@ -0,0 +1,214 @@
+//! Minimal HTTP endpoint over [`SyncStatus`] for Kubernetes probes and
+//! Prometheus scrapes (same hand-rolled request handling as the loadtest
+//! aggregator server; no web framework).
+//!
+//! - `GET /health` — `200` once a source runtime has started (connected and
+//!   past its initial checkpoint load), `503` before.
+//! - `GET /ready` — `200` while a source runtime is running, `503` otherwise.
+//! - `GET /metrics` — Prometheus text format.
+
+use crate::status::{StatusSnapshot, SyncStatus};
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Bind `listen` (e.g. `0.0.0.0:9090`) and serve [`SyncStatus::global`] in a
+/// background task for the rest of the process. Returns the bound address.
+pub async fn spawn(listen: &str) -> Result<SocketAddr> {
+    spawn_with(listen, SyncStatus::global()).await
+}
+
+/// Like [`spawn`], serving `status` instead of the global instance.
+pub async fn spawn_with(listen: &str, status: &'static SyncStatus) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(listen)
+        .await
+        .with_context(|| format!("Failed to bind health server to {listen}"))?;
+    let addr = listener.local_addr()?;
+    tracing::info!("Health server listening on {addr}");
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer)) => {
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, status).await {
+                            tracing::debug!("Health server connection from {peer} failed: {e}");
+                        }
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!("Health server accept failed: {e}");
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+            }
+        }
+    });
+    Ok(addr)
+}
+
+async fn handle_connection(mut stream: TcpStream, status: &SyncStatus) -> Result<()> {
+    let mut reader = BufReader::new(&mut stream);
+    let mut request_line = String::new();
+    tokio::time::timeout(Duration::from_secs(10), reader.read_line(&mut request_line))
+        .await
+        .context("timed out reading request")??;
+    // Drain headers; GET requests carry no body.
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 || line.trim().is_empty() {
+            break;
+        }
+    }
+
+    let snapshot = status.snapshot();
+    let path = request_line.split_whitespace().nth(1).unwrap_or("");
+    let (status_line, content_type, body) = if !request_line.starts_with("GET ") {
+        (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        )
+    } else {
+        match path {
+            "/health" if snapshot.started => ("200 OK", "text/plain", "ok\n".to_string()),
+            "/health" => (
+                "503 Service Unavailable",
+                "text/plain",
+                "starting\n".to_string(),
+            ),
+            "/ready" if snapshot.running > 0 => ("200 OK", "text/plain", "ready\n".to_string()),
+            "/ready" => (
+                "503 Service Unavailable",
+                "text/plain",
+                "not ready\n".to_string(),
+            ),
+            "/metrics" => (
+                "200 OK",
+                "text/plain; version=0.0.4",
+                render_metrics(&snapshot, SystemTime::now()),
+            ),
+            _ => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        }
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status_line}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+/// Render `snapshot` in the Prometheus text exposition format.
+pub fn render_metrics(snapshot: &StatusSnapshot, now: SystemTime) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, kind: &str, help: &str, sample: String| {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} {kind}");
+        let _ = writeln!(out, "{name}{sample}");
+    };
+    gauge(
+        "surreal_sync_up",
+        "gauge",
+        "Whether a source runtime has started (1) or not (0).",
+        format!(" {}", u8::from(snapshot.started)),
+    );
+    gauge(
+        "surreal_sync_running",
+        "gauge",
+        "Source runtimes currently running.",
+        format!(" {}", snapshot.running),
+    );
+    gauge(
+        "surreal_sync_records_applied_total",
+        "counter",
+        "Source events applied to SurrealDB.",
+        format!(" {}", snapshot.records_applied),
+    );
+    if let Some(ms) = snapshot.last_applied_unix_ms {
+        gauge(
+            "surreal_sync_last_applied_timestamp_seconds",
+            "gauge",
+            "Unix time of the last successful apply.",
+            format!(" {:.3}", ms as f64 / 1000.0),
+        );
+    }
+    if let Some(lag) = snapshot.seconds_since_last_apply(now) {
+        gauge(
+            "surreal_sync_apply_lag_seconds",
+            "gauge",
+            "Seconds since the last successful apply.",
+            format!(" {lag:.3}"),
+        );
+    }
+    if let Some(checkpoint) = &snapshot.checkpoint {
+        gauge(
+            "surreal_sync_checkpoint_info",
+            "gauge",
+            "Last sink-safe source position.",
+            format!("{{position=\"{}\"}} 1", escape_label(checkpoint)),
+        );
+    }
+    out
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    async fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\nHost: test\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn probes_follow_runtime_lifecycle() {
+        let status: &'static SyncStatus = Box::leak(Box::default());
+        let addr = spawn_with("127.0.0.1:0", status).await.unwrap();
+
+        assert!(get(addr, "/health").await.starts_with("HTTP/1.1 503"));
+        assert!(get(addr, "/ready").await.starts_with("HTTP/1.1 503"));
+
+        let guard = status.enter_runtime();
+        status.record_applied(7, Some("mysql-bin.000003:\"4\"".to_string()));
+        assert!(get(addr, "/health").await.starts_with("HTTP/1.1 200"));
+        assert!(get(addr, "/ready").await.starts_with("HTTP/1.1 200"));
+        let metrics = get(addr, "/metrics").await;
+        assert!(
+            metrics.contains("\nsurreal_sync_records_applied_total 7\n"),
+            "{metrics}"
+        );
+        assert!(
+            metrics.contains(
+                "surreal_sync_checkpoint_info{position=\"mysql-bin.000003:\\\"4\\\"\"} 1"
+            ),
+            "{metrics}"
+        );
+        assert!(
+            metrics.contains("\nsurreal_sync_apply_lag_seconds "),
+            "{metrics}"
+        );
+
+        drop(guard);
+        assert!(get(addr, "/health").await.starts_with("HTTP/1.1 200"));
+        assert!(get(addr, "/ready").await.starts_with("HTTP/1.1 503"));
+        assert!(get(addr, "/nope").await.starts_with("HTTP/1.1 404"));
+    }
+}
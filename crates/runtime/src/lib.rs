@@ -8,7 +8,12 @@
 //! Sink crates use the default features (no CLI argument parsing). Enable `cli`
 //! only if you need [`SurrealCliOpts`].
 //!
-//! Enable `checkpoint_fs` (default) for [`checkpoint_fs::FilesystemStore`].
+//! Enable `checkpoint_fs` (default) for [`checkpoint_fs::FilesystemStore`], or
+//! `checkpoint_redis` for [`checkpoint_redis::RedisStore`] (checkpoints shared
+//! across containers through a Redis server).
+//!
+//! Enable `health-server` for [`health_server`], which serves
+//! [`status::SyncStatus`] to liveness / readiness probes and scrapers.
 //!
 //! # Sink + checkpoint typing
 //!
@@ -30,15 +35,29 @@ pub mod pipeline;
 #[cfg(feature = "checkpoint_fs")]
 pub mod checkpoint_fs;
 
+/// Store checkpoints in Redis.
+#[cfg(feature = "checkpoint_redis")]
+pub mod checkpoint_redis;
+
 #[cfg(feature = "cli")]
 mod cli_opts;
 
+/// Process-wide sync status (health / metrics counters).
+pub mod status;
+
+/// Lightweight HTTP server exposing `/health`, `/ready` and `/metrics`.
+#[cfg(feature = "health-server")]
+pub mod health_server;
+
 #[cfg(feature = "cli")]
-pub use cli_opts::SurrealCliOpts;
+pub use cli_opts::{ColumnFilterCliOpts, SurrealCliOpts};
 pub use config::SurrealConfig;
 pub use init::init;
 pub use sink_connect::{SinkConnect, SinkWithCheckpoints};
-pub use transforms::{load_transforms_from_args, merge_inplace_boxed, merge_inplace_transforms};
+pub use transforms::{
+    load_transforms_from_args, load_transforms_with_columns, merge_inplace_boxed,
+    merge_inplace_transforms,
+};
 
 // Re-exports commonly used pipeline types at the crate root.
 pub use pipeline::{
@@ -51,16 +70,18 @@ pub use pipeline::{
     run_source_runtime, run_source_runtime_with, write_relations, write_relations_with, write_rows,
     write_rows_with, AdhocApply, ApplyContext, ApplyEvent, ApplyOpts, BatchTransformer, ChangeFeed,
     ChangeFeedDriver, ChangeFeedRef, CheckpointPolicy, ChildStdioMode, CommandStageConfig,
-    ConfiguredStage, ControlSignal, CowBatch, ExternalTransform, ExternalTransport, FailurePolicy,
-    FlattenId, FlattenIdStageConfig, Framer, FramerKind, InPlaceTransform,
-    InterleavedSnapshotCheckpoint, InterleavedSnapshotConfig, InterleavedSnapshotResult,
-    ManagerCheckpointer, NdjsonFramer, NoopCheckpointer, Passthrough, PersistentChildStdio,
-    Pipeline, PipelineSection, PkTuple, PositionedChange, PositionedEvent, ReconciliationEvent,
-    ReconciliationPos, RelationChunkDriver, RelationChunkSource, RequestHeader, ResponseHeader,
-    RetryPolicy, RowChunkDriver, RowChunkSource, RuntimeExit, SnapshotCheckpointer, SnapshotSignal,
+    ConfiguredStage, ControlSignal, ConvertingRowChunks, CowBatch, ExternalTransform,
+    ExternalTransport, FailurePolicy, FlattenId, FlattenIdStageConfig, Framer, FramerKind,
+    InPlaceTransform, InterleavedSnapshotCheckpoint, InterleavedSnapshotConfig,
+    InterleavedSnapshotResult, ManagerCheckpointer, NdjsonFramer, NoopCheckpointer, NormalizeId,
+    NormalizeIdStageConfig, ParallelRowChunks, Passthrough, PersistentChildStdio, Pipeline,
+    PipelineSection, PkTuple, PositionedChange, PositionedEvent, RawChunkSource,
+    ReconciliationEvent, ReconciliationPos, Redact, RedactPattern, RedactStageConfig, Redaction,
+    RelationChunkDriver, RelationChunkSource, RequestHeader, ResponseHeader, RetryPolicy,
+    RowChunkDriver, RowChunkSource, RuntimeExit, SnapshotCheckpointer, SnapshotSignal,
     SnapshotTableProgress, SnapshotTransforms, SourceDriver, SourceRuntimeOpts, Stage, StdioConfig,
-    StopReason, TableSpec, TransformsConfig, TransientChildStdio, WatermarkKind, WatermarkSource,
-    WireItemKind, WireResponse, DEFAULT_CHUNK_SIZE, DEFAULT_FLATTEN_ID_SEPARATOR,
+    StopReason, TableSpec, TransformsConfig, TransientChildStdio, UnicodeForm, WatermarkKind,
+    WatermarkSource, WireItemKind, WireResponse, DEFAULT_CHUNK_SIZE, DEFAULT_FLATTEN_ID_SEPARATOR,
     RELATION_WIRE_BATCH_ID_BIT,
 };
 
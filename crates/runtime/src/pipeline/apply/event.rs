@@ -1,4 +1,5 @@
-//! Unified apply events: row changes and relation (graph edge) changes.
+//! Unified apply events: row changes, relation (graph edge) changes and
+//! table truncations.
 
 use surreal_sync_core::{Change, RelationChange};
 
@@ -15,6 +16,12 @@ pub enum ApplyEvent {
     Change(Change),
     /// Graph-edge create / update / delete ([`RelationChange`]).
     RelationChange(Box<RelationChange>),
+    /// Delete every record of a table (source-side `TRUNCATE`).
+    ///
+    /// Transform stages pass truncations through unchanged; the sink applies
+    /// them with [`SurrealSink::truncate_table`](surreal_sync_core::SurrealSink::truncate_table)
+    /// in source order relative to surrounding changes.
+    Truncate(String),
 }
 
 impl ApplyEvent {
@@ -28,6 +35,11 @@ impl ApplyEvent {
         Self::RelationChange(Box::new(change))
     }
 
+    /// Wrap a table truncation.
+    pub fn truncate(table: impl Into<String>) -> Self {
+        Self::Truncate(table.into())
+    }
+
     /// Whether this is a row change.
     pub fn is_change(&self) -> bool {
         matches!(self, Self::Change(_))
@@ -37,6 +49,11 @@ impl ApplyEvent {
     pub fn is_relation_change(&self) -> bool {
         matches!(self, Self::RelationChange(_))
     }
+
+    /// Whether this is a table truncation.
+    pub fn is_truncate(&self) -> bool {
+        matches!(self, Self::Truncate(_))
+    }
 }
 
 /// A source event plus the position to advance after sink success.
@@ -63,4 +80,9 @@ impl<P> PositionedEvent<P> {
     pub fn relation_change(change: RelationChange, position: P) -> Self {
         Self::new(ApplyEvent::relation_change(change), position)
     }
+
+    /// Positioned table truncation.
+    pub fn truncate(table: impl Into<String>, position: P) -> Self {
+        Self::new(ApplyEvent::truncate(table), position)
+    }
 }
@@ -17,7 +17,10 @@ mod transform;
 pub use event::{ApplyEvent, PositionedEvent};
 pub use feed::{ChangeFeed, PositionedChange};
 pub use opts::{ApplyOpts, FailurePolicy};
-pub use row_chunk::{RelationChunkDriver, RelationChunkSource, RowChunkDriver, RowChunkSource};
+pub use row_chunk::{
+    ConvertingRowChunks, ParallelRowChunks, RawChunkSource, RelationChunkDriver,
+    RelationChunkSource, RowChunkDriver, RowChunkSource,
+};
 pub use runtime::{
     apply_changes, apply_changes_with, apply_relation_changes, apply_relation_changes_with,
     run_change_feed, run_change_feed_with, write_relations, write_relations_with, write_rows,
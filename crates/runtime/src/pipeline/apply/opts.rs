@@ -37,6 +37,20 @@ pub struct ApplyOpts {
     /// Flush a partial batch after this idle wait.
     /// Default: 500ms.
     pub batch_max_wait: Duration,
+    /// Start a batch once its oldest change has waited this long, even while
+    /// the source keeps returning changes, so quiet sources apply and
+    /// checkpoint without waiting for `batch_size` changes.
+    /// Default: `None` (batches start on `batch_size` or `batch_max_wait`).
+    pub commit_interval: Option<Duration>,
+    /// Persist the checkpoint once this many batches have been sunk since the
+    /// last persist, instead of after every batch.
+    /// Default: `None` (every batch unless [`Self::checkpoint_every`] is set).
+    pub checkpoint_every_batches: Option<usize>,
+    /// Persist the checkpoint once this long has passed since the last persist,
+    /// instead of after every batch. Combined with
+    /// [`Self::checkpoint_every_batches`], whichever comes first wins.
+    /// Default: `None`.
+    pub checkpoint_every: Option<Duration>,
     /// Per-batch transform timeout.
     /// Default: 60s.
     pub timeout: Duration,
@@ -51,6 +65,9 @@ impl Default for ApplyOpts {
             max_in_flight: 1,
             batch_size: 1000,
             batch_max_wait: Duration::from_millis(500),
+            commit_interval: None,
+            checkpoint_every_batches: None,
+            checkpoint_every: None,
             timeout: Duration::from_secs(60),
             failure_policy: FailurePolicy::Fail,
         }
@@ -86,6 +103,30 @@ impl ApplyOpts {
         self
     }
 
+    /// Builder: set the time-based commit interval (`None` disables it).
+    pub fn with_commit_interval(mut self, d: Option<Duration>) -> Self {
+        self.commit_interval = d;
+        self
+    }
+
+    /// Builder: persist the checkpoint every `n` sunk batches (`None` disables
+    /// the batch count; `Some(n)` is clamped to at least 1).
+    pub fn with_checkpoint_every_batches(mut self, n: Option<usize>) -> Self {
+        self.checkpoint_every_batches = n.map(|n| n.max(1));
+        self
+    }
+
+    /// Builder: persist the checkpoint at most this often (`None` disables it).
+    pub fn with_checkpoint_every(mut self, d: Option<Duration>) -> Self {
+        self.checkpoint_every = d;
+        self
+    }
+
+    /// Whether checkpoint writes are throttled rather than made after every batch.
+    pub fn has_checkpoint_cadence(&self) -> bool {
+        self.checkpoint_every_batches.is_some() || self.checkpoint_every.is_some()
+    }
+
     /// Builder: set per-batch transform timeout.
     pub fn with_timeout(mut self, d: Duration) -> Self {
         self.timeout = d;
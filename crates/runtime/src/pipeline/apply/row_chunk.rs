@@ -21,6 +21,173 @@ pub trait RowChunkSource: Send {
     async fn next_chunk(&mut self) -> Result<Option<Vec<Row>>>;
 }
 
+/// Reads several [`RowChunkSource`]s concurrently and yields their chunks as
+/// one stream (intra-table parallel reads over disjoint key ranges).
+///
+/// Each source runs on its own task; chunks are yielded in arrival order, so
+/// rows must carry source-derived ids rather than rely on read order. The
+/// first read error is returned and the remaining readers are aborted.
+pub struct ParallelRowChunks {
+    receiver: tokio::sync::mpsc::Receiver<Result<Vec<Row>>>,
+    readers: Vec<tokio::task::JoinHandle<()>>,
+}
+
+impl ParallelRowChunks {
+    /// Spawn one reader task per source.
+    pub fn spawn<C>(sources: Vec<C>) -> Self
+    where
+        C: RowChunkSource + 'static,
+    {
+        // One buffered chunk per reader keeps every reader busy without
+        // letting a fast range run far ahead of the sink.
+        let (sender, receiver) = tokio::sync::mpsc::channel(sources.len().max(1));
+        let readers = sources
+            .into_iter()
+            .map(|mut source| {
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    loop {
+                        match source.next_chunk().await {
+                            Ok(Some(rows)) if !rows.is_empty() => {
+                                if sender.send(Ok(rows)).await.is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(_) => return,
+                            Err(e) => {
+                                let _ = sender.send(Err(e)).await;
+                                return;
+                            }
+                        }
+                    }
+                })
+            })
+            .collect();
+        Self { receiver, readers }
+    }
+}
+
+#[async_trait]
+impl RowChunkSource for ParallelRowChunks {
+    async fn next_chunk(&mut self) -> Result<Option<Vec<Row>>> {
+        match self.receiver.recv().await {
+            Some(Ok(rows)) => Ok(Some(rows)),
+            Some(Err(e)) => {
+                for reader in &self.readers {
+                    reader.abort();
+                }
+                Err(e)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl Drop for ParallelRowChunks {
+    fn drop(&mut self) {
+        for reader in &self.readers {
+            reader.abort();
+        }
+    }
+}
+
+/// Produces successive chunks of raw (not yet converted) source records for
+/// [`ConvertingRowChunks`].
+///
+/// Return `Ok(None)` (or an empty `Vec`) when there are no more records.
+#[async_trait]
+pub trait RawChunkSource: Send {
+    type Raw: Send + 'static;
+
+    async fn next_raw_chunk(&mut self) -> Result<Option<Vec<Self::Raw>>>;
+}
+
+type ConversionWorker = tokio::task::JoinHandle<Result<Vec<Row>>>;
+
+/// Converts raw chunks to rows on a pool of blocking workers, decoupled from
+/// the reads (CPU-bound source conversions such as BSON).
+///
+/// A reader task pulls raw chunks from the source and hands each to a
+/// `spawn_blocking` worker; up to `workers` chunks convert at once while the
+/// reader fetches the next. Chunks are yielded in read order, so rows keep
+/// their per-table order. The first read or conversion error is returned and
+/// the reader is aborted.
+pub struct ConvertingRowChunks {
+    receiver: tokio::sync::mpsc::Receiver<Result<ConversionWorker>>,
+    reader: tokio::task::JoinHandle<()>,
+}
+
+impl ConvertingRowChunks {
+    /// Spawn the reader task. `convert` receives each raw record and its
+    /// zero-based index in read order; `workers` is clamped to at least 1.
+    pub fn spawn<C, F>(mut source: C, workers: usize, convert: F) -> Self
+    where
+        C: RawChunkSource + 'static,
+        F: Fn(C::Raw, u64) -> Result<Row> + Send + Sync + 'static,
+    {
+        let workers = workers.max(1);
+        let convert = std::sync::Arc::new(convert);
+        let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(workers));
+        let (sender, receiver) = tokio::sync::mpsc::channel(workers);
+        let reader = tokio::spawn(async move {
+            let mut next_index = 0u64;
+            loop {
+                let raw = match source.next_raw_chunk().await {
+                    Ok(Some(raw)) if !raw.is_empty() => raw,
+                    Ok(_) => return,
+                    Err(e) => {
+                        let _ = sender.send(Err(e)).await;
+                        return;
+                    }
+                };
+                let Ok(permit) = permits.clone().acquire_owned().await else {
+                    return;
+                };
+                let start = next_index;
+                next_index = next_index.saturating_add(raw.len() as u64);
+                let convert = convert.clone();
+                let worker = tokio::task::spawn_blocking(move || {
+                    let _permit = permit;
+                    raw.into_iter()
+                        .zip(start..)
+                        .map(|(raw, index)| convert(raw, index))
+                        .collect()
+                });
+                if sender.send(Ok(worker)).await.is_err() {
+                    return;
+                }
+            }
+        });
+        Self { receiver, reader }
+    }
+}
+
+#[async_trait]
+impl RowChunkSource for ConvertingRowChunks {
+    async fn next_chunk(&mut self) -> Result<Option<Vec<Row>>> {
+        let Some(worker) = self.receiver.recv().await else {
+            return Ok(None);
+        };
+        let rows = match worker {
+            Ok(worker) => worker
+                .await
+                .map_err(|e| anyhow::anyhow!("Conversion worker failed: {e}"))
+                .and_then(|rows| rows),
+            Err(e) => Err(e),
+        };
+        if rows.is_err() {
+            self.reader.abort();
+        }
+        rows.map(Some)
+    }
+}
+
+impl Drop for ConvertingRowChunks {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
 /// Long-lived [`SourceDriver`] over a [`RowChunkSource`] (CSV-like full-sync pattern).
 ///
 /// Each poll loads one chunk, converts rows to upsert changes, and returns them
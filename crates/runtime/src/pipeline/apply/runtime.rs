@@ -14,6 +14,7 @@ use crate::pipeline::apply::{
     RuntimeExit, SourceDriver, SourceRuntimeOpts,
 };
 use crate::pipeline::pipeline::Pipeline;
+use crate::status::SyncStatus;
 use anyhow::{anyhow, bail, Context, Result};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -170,7 +171,8 @@ where
 /// [`SurrealSink::write_rows`] /
 /// [`SurrealSink::write_relations`] so large `write_rows` /
 /// `write_relations` vecs keep a bulk trait call **inside** the window. CDC
-/// `Create` / `Delete` (and mixed batches) stay on per-event apply.
+/// `Create` / `Delete`, truncations (and mixed batches) stay on per-event
+/// apply.
 pub(crate) async fn apply_transformed_sink_events<S: SurrealSink>(
     sink: &S,
     events: &[ApplyEvent],
@@ -199,6 +201,11 @@ pub(crate) async fn apply_transformed_sink_events<S: SurrealSink>(
                     .await
                     .context("sink apply_relation_change")?;
             }
+            ApplyEvent::Truncate(table) => {
+                sink.truncate_table(table)
+                    .await
+                    .context("sink truncate_table")?;
+            }
         }
     }
     Ok(())
@@ -338,10 +345,13 @@ pub struct ApplyContext<'a, S, T, P = ()> {
     poisoned: bool,
     /// Events successfully sunk since the last [`Self::take_sunk_change_count`].
     sunk_since_take: u64,
-    /// Deferred sink-safe position for [`CheckpointPolicy::IntervalWhenDrained`].
+    /// Deferred sink-safe position for [`CheckpointPolicy::IntervalWhenDrained`]
+    /// or a throttled [`ApplyOpts::checkpoint_every_batches`] / [`ApplyOpts::checkpoint_every`].
     pending_checkpoint: Option<P>,
     /// Last wall-clock time a deferred checkpoint was persisted.
     last_checkpoint_persist: Instant,
+    /// Watermark advances since the last persist (checkpoint cadence).
+    advances_since_persist: usize,
 }
 
 impl<'a, S, T, P> ApplyContext<'a, S, T, P>
@@ -370,6 +380,7 @@ where
             sunk_since_take: 0,
             pending_checkpoint: None,
             last_checkpoint_persist: Instant::now(),
+            advances_since_persist: 0,
         }
     }
 
@@ -450,11 +461,21 @@ where
 
     fn should_flush_partial(&self) -> bool {
         match self.buffer_started {
-            Some(started) => started.elapsed() >= self.opts.batch_max_wait,
+            Some(started) => {
+                started.elapsed() >= self.opts.batch_max_wait || self.commit_interval_due()
+            }
             None => false,
         }
     }
 
+    /// Whether the oldest buffered change has waited [`ApplyOpts::commit_interval`].
+    pub(crate) fn commit_interval_due(&self) -> bool {
+        match (self.buffer_started, self.opts.commit_interval) {
+            (Some(started), Some(interval)) => started.elapsed() >= interval,
+            _ => false,
+        }
+    }
+
     /// Push one row change; may start transforms and drain ordered sink.
     ///
     /// Returns the last position successfully sunk (caller should `advance_watermark`).
@@ -733,6 +754,7 @@ where
             .advance_watermark(last_position.clone())
             .await
             .context("advance_watermark")?;
+        SyncStatus::global().record_applied(sunk, driver.describe_position(&last_position));
         // Free the sink slot before persist checks so IntervalWhenDrained sees a
         // drained window when nothing else is outstanding.
         self.next_to_apply += 1;
@@ -829,18 +851,20 @@ where
     ) -> Result<()> {
         match driver.checkpoint_policy() {
             CheckpointPolicy::PersistAfterAdvance => {
-                driver
-                    .persist_checkpoint(position)
-                    .await
-                    .context("persist_checkpoint")?;
+                self.pending_checkpoint = Some(position);
+                self.advances_since_persist += 1;
+                if self.checkpoint_cadence_due() {
+                    self.flush_pending_checkpoint(driver).await?;
+                }
             }
             CheckpointPolicy::AdvanceOnly => {}
             CheckpointPolicy::IntervalWhenDrained { .. } => {
                 self.pending_checkpoint = Some(position);
+                self.advances_since_persist += 1;
                 // Persist sunk watermarks promptly once the window is empty
                 // (matches pre-SourceDriver last-sunk-on-sink cadence). Interval
                 // still gates filtered-only read_progress persists.
-                if self.is_fully_drained() {
+                if self.is_fully_drained() && self.checkpoint_cadence_due() {
                     self.flush_pending_checkpoint(driver).await?;
                 }
             }
@@ -848,16 +872,48 @@ where
         Ok(())
     }
 
+    /// Whether a pending position should be persisted now under
+    /// [`ApplyOpts::checkpoint_every_batches`] / [`ApplyOpts::checkpoint_every`].
+    /// Always true when neither is set (persist every advance).
+    fn checkpoint_cadence_due(&self) -> bool {
+        if !self.opts.has_checkpoint_cadence() {
+            return true;
+        }
+        let batches_due = self
+            .opts
+            .checkpoint_every_batches
+            .is_some_and(|n| self.advances_since_persist >= n);
+        let time_due = self
+            .opts
+            .checkpoint_every
+            .is_some_and(|d| self.last_checkpoint_persist.elapsed() >= d);
+        batches_due || time_due
+    }
+
     async fn try_interval_persist(
         &mut self,
         driver: &mut impl SourceDriver<Position = P>,
     ) -> Result<()> {
-        let CheckpointPolicy::IntervalWhenDrained { interval } = driver.checkpoint_policy() else {
-            return Ok(());
+        let interval = match driver.checkpoint_policy() {
+            CheckpointPolicy::PersistAfterAdvance => {
+                // Only a throttled cadence leaves a position pending here.
+                if self.pending_checkpoint.is_some() && self.checkpoint_cadence_due() {
+                    self.flush_pending_checkpoint(driver).await?;
+                }
+                return Ok(());
+            }
+            CheckpointPolicy::AdvanceOnly => return Ok(()),
+            CheckpointPolicy::IntervalWhenDrained { interval } => interval,
         };
         if !self.is_fully_drained() {
             return Ok(());
         }
+        if self.pending_checkpoint.is_some()
+            && self.opts.has_checkpoint_cadence()
+            && self.checkpoint_cadence_due()
+        {
+            return self.flush_pending_checkpoint(driver).await;
+        }
         if self.last_checkpoint_persist.elapsed() < interval {
             return Ok(());
         }
@@ -890,6 +946,7 @@ where
                 .await
                 .context("persist_checkpoint")?;
             self.last_checkpoint_persist = Instant::now();
+            self.advances_since_persist = 0;
         }
         Ok(())
     }
@@ -910,7 +967,8 @@ where
                 && self.completed.is_empty()
                 && !self.sink_in_flight
             {
-                // Force-persist any deferred IntervalWhenDrained position.
+                // Force-persist any deferred IntervalWhenDrained or throttled
+                // position, so a clean stop always records the last sunk one.
                 self.flush_pending_checkpoint(driver).await?;
                 return Ok(());
             }
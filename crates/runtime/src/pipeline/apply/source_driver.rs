@@ -13,6 +13,7 @@ use crate::pipeline::apply::runtime::{
 };
 use crate::pipeline::apply::transform::BatchTransformer;
 use crate::pipeline::pipeline::Pipeline;
+use crate::status::SyncStatus;
 use anyhow::{Context, Result};
 use std::future::Future;
 use std::pin::Pin;
@@ -59,7 +60,9 @@ pub enum StopReason {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CheckpointPolicy {
     /// After each successful sink + [`advance_watermark`](SourceDriver::advance_watermark),
-    /// also call [`SourceDriver::persist_checkpoint`] with that position.
+    /// also call [`SourceDriver::persist_checkpoint`] with that position, or
+    /// only on the cadence set by [`ApplyOpts::checkpoint_every_batches`] /
+    /// [`ApplyOpts::checkpoint_every`] (the last position is flushed on stop).
     #[default]
     PersistAfterAdvance,
     /// Do not call `persist_checkpoint` (driver folds durability into
@@ -271,6 +274,13 @@ pub trait SourceDriver: Send {
     /// advanced past without writing (so drivers that gate slot/cursor advance
     /// on sunk counts do not stall). Default: no-op.
     fn note_sunk_events(&mut self, _count: u64) {}
+
+    /// Human-readable form of a sink-safe `position`, reported as the current
+    /// checkpoint by [`SyncStatus`](crate::status::SyncStatus) (health /
+    /// metrics endpoint). Default: `None` (not reported).
+    fn describe_position(&self, _position: &Self::Position) -> Option<String> {
+        None
+    }
 }
 
 /// Adapter: any [`ChangeFeed`] is a [`SourceDriver`] with no-op control hooks.
@@ -434,6 +444,7 @@ where
     S: SurrealSink,
     T: BatchTransformer + 'static,
 {
+    let _running = SyncStatus::global().enter_runtime();
     let mut ctx = ApplyContext::new(sink, Arc::clone(&transformer), apply_opts);
     // In-flight ordered sink work (overlaps with poll/transform). The drive
     // future is created once per batch so select! cancelling an await never
@@ -476,7 +487,12 @@ where
             // batch_size events — that overshoot is intentional: we keep every
             // event in the buffer (never drop) and form transform batches from
             // it. Memory may exceed batch_size until the excess is drained.
-            while ctx.buffer_len() < apply_opts.batch_size && !driver.is_finished() {
+            // A due commit interval stops filling so a steady trickle of
+            // events cannot hold a partial batch back.
+            while ctx.buffer_len() < apply_opts.batch_size
+                && !driver.is_finished()
+                && !ctx.commit_interval_due()
+            {
                 let polled = driver.poll_work().await.context("poll_work")?;
                 if polled.is_empty() {
                     break;
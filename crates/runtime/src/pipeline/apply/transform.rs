@@ -80,7 +80,8 @@ pub trait BatchTransformer: Send + Sync {
     /// [`transform_relation_changes`](Self::transform_relation_changes), and
     /// recombines. Length of each kind must be preserved (filter/fan-out of a
     /// single kind in a mixed batch is not supported here — use homogeneous
-    /// batches or External on row-only feeds).
+    /// batches or External on row-only feeds). [`ApplyEvent::Truncate`] events
+    /// keep their slot untouched.
     async fn transform_events(
         &self,
         batch_id: u64,
@@ -102,6 +103,7 @@ pub trait BatchTransformer: Send + Sync {
                     rel_idxs.push(i);
                     rels.push(*r);
                 }
+                truncate @ ApplyEvent::Truncate(_) => out[i] = Some(truncate),
             }
         }
 
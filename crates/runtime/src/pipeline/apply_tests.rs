@@ -8,14 +8,13 @@ use crate::pipeline::{
     ApplyContext, ApplyOpts, FailurePolicy, InPlaceTransform, Pipeline, PositionedChange,
 };
 use anyhow::Result;
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
-use surreal_sync_core::{Change, Relation, RelationChange, Row, ThingRef, Value};
+use surreal_sync_core::{Change, FieldMap, Relation, RelationChange, Row, ThingRef, Value};
 use tokio::time::timeout;
 
 fn change(id: i64) -> Change {
-    let mut data = HashMap::new();
+    let mut data = FieldMap::new();
     data.insert(
         "name".to_string(),
         Value::VarChar {
@@ -49,7 +48,7 @@ impl InPlaceTransform for TagName {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         if let Some(fields) = fields {
             fields.insert(
@@ -220,7 +219,7 @@ async fn delete_and_mixed_batches_use_per_event_apply() {
     );
 
     let sink = RecordingSink::new();
-    let mut update_data = HashMap::new();
+    let mut update_data = FieldMap::new();
     update_data.insert(
         "name".to_string(),
         Value::VarChar {
@@ -744,7 +743,7 @@ fn relation(id: i64) -> Relation {
         Value::Int64(id),
         ThingRef::new("users", Value::Int64(id)),
         ThingRef::new("users", Value::Int64(id + 1)),
-        HashMap::new(),
+        FieldMap::new(),
     )
 }
 
@@ -17,7 +17,10 @@ use crate::pipeline::apply::{ApplyOpts, FailurePolicy};
 use crate::pipeline::external::{ChildStdioMode, ExternalTransform, RetryPolicy};
 use crate::pipeline::flatten_id::{FlattenId, DEFAULT_FLATTEN_ID_SEPARATOR};
 use crate::pipeline::framer::FramerKind;
+use crate::pipeline::normalize_id::{NormalizeId, UnicodeForm};
 use crate::pipeline::pipeline::Pipeline;
+use crate::pipeline::redact::{Redact, RedactPattern, Redaction};
+use crate::pipeline::source_id::SourceId;
 use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 use std::path::Path;
@@ -44,6 +47,12 @@ pub struct PipelineSection {
     pub batch_size: Option<usize>,
     /// Override for [`ApplyOpts::batch_max_wait`] when set.
     pub batch_max_wait: Option<Duration>,
+    /// [`ApplyOpts::commit_interval`] when set.
+    pub commit_interval: Option<Duration>,
+    /// [`ApplyOpts::checkpoint_every_batches`] when set.
+    pub checkpoint_every_batches: Option<usize>,
+    /// [`ApplyOpts::checkpoint_every`] when set.
+    pub checkpoint_every: Option<Duration>,
     /// Outer whole-pipeline transform timeout (covers all stages + retries).
     pub timeout: Option<Duration>,
     /// Override for [`ApplyOpts::max_in_flight`] when set.
@@ -57,6 +66,12 @@ pub enum ConfiguredStage {
     Command(CommandStageConfig),
     /// Flatten Array record IDs to Text (`type = "flatten_id"`).
     FlattenId(FlattenIdStageConfig),
+    /// Normalize text record IDs (`type = "normalize_id"`).
+    NormalizeId(NormalizeIdStageConfig),
+    /// Redact regex matches in text field values (`type = "redact"`).
+    Redact(RedactStageConfig),
+    /// Keep the source record ID in a field (`type = "source_id"`).
+    SourceId(SourceIdStageConfig),
 }
 
 /// Flatten-id stage settings from TOML (`type = "flatten_id"`).
@@ -66,6 +81,42 @@ pub struct FlattenIdStageConfig {
     pub separator: String,
 }
 
+/// Normalize-id stage settings from TOML (`type = "normalize_id"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizeIdStageConfig {
+    /// Tables to normalize; empty means every table.
+    pub tables: Vec<String>,
+    /// Lowercase IDs.
+    pub lowercase: bool,
+    /// Unicode normalization form (`"nfc"` / `"nfkc"`).
+    pub unicode: Option<UnicodeForm>,
+    /// Remove combining marks (accents).
+    pub strip_accents: bool,
+    /// Field that receives the original ID.
+    pub original_field: Option<String>,
+}
+
+/// Source-id stage settings from TOML (`type = "source_id"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceIdStageConfig {
+    /// Field that receives the source ID.
+    pub field: String,
+    /// Tables to annotate; empty means every table.
+    pub tables: Vec<String>,
+}
+
+/// Redact stage settings from TOML (`type = "redact"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactStageConfig {
+    /// Tables to redact; empty means every table.
+    pub tables: Vec<String>,
+    /// Top-level fields to scan; empty means every field.
+    pub fields: Vec<String>,
+    /// `(pattern, replacement)` pairs, applied in order. Patterns are
+    /// checked to compile at load time.
+    pub patterns: Vec<(String, Redaction)>,
+}
+
 /// Command-stage settings from TOML (`type = "command"`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandStageConfig {
@@ -148,6 +199,15 @@ impl ApplyOpts {
         if let Some(d) = p.batch_max_wait {
             opts.batch_max_wait = d;
         }
+        if let Some(d) = p.commit_interval {
+            opts.commit_interval = Some(d);
+        }
+        if let Some(n) = p.checkpoint_every_batches {
+            opts.checkpoint_every_batches = Some(n.max(1));
+        }
+        if let Some(d) = p.checkpoint_every {
+            opts.checkpoint_every = Some(d);
+        }
         if let Some(d) = p.timeout {
             opts.timeout = d;
         }
@@ -185,6 +245,33 @@ impl Pipeline {
                 ConfiguredStage::FlattenId(flat) => {
                     pipeline.push_inplace(FlattenId::new(flat.separator.clone()));
                 }
+                ConfiguredStage::NormalizeId(norm) => {
+                    pipeline.push_inplace(NormalizeId {
+                        tables: norm.tables.clone(),
+                        lowercase: norm.lowercase,
+                        unicode: norm.unicode,
+                        strip_accents: norm.strip_accents,
+                        original_field: norm.original_field.clone(),
+                    });
+                }
+                ConfiguredStage::Redact(redact) => {
+                    let patterns = redact
+                        .patterns
+                        .iter()
+                        .map(|(pattern, redaction)| RedactPattern::new(pattern, redaction.clone()))
+                        .collect::<Result<_>>()?;
+                    pipeline.push_inplace(Redact {
+                        tables: redact.tables.clone(),
+                        fields: redact.fields.clone(),
+                        patterns,
+                    });
+                }
+                ConfiguredStage::SourceId(source_id) => {
+                    pipeline.push_inplace(SourceId {
+                        field: source_id.field.clone(),
+                        tables: source_id.tables.clone(),
+                    });
+                }
             }
         }
         Ok(pipeline)
@@ -300,6 +387,12 @@ struct RawPipeline {
     #[serde(default)]
     batch_max_wait: Option<String>,
     #[serde(default)]
+    commit_interval: Option<String>,
+    #[serde(default)]
+    checkpoint_every_batches: Option<usize>,
+    #[serde(default)]
+    checkpoint_every: Option<String>,
+    #[serde(default)]
     timeout: Option<String>,
     #[serde(default)]
     max_in_flight: Option<usize>,
@@ -311,6 +404,9 @@ enum RawStage {
     Passthrough {},
     Command(RawCommandStage),
     FlattenId(RawFlattenIdStage),
+    NormalizeId(RawNormalizeIdStage),
+    Redact(RawRedactStage),
+    SourceId(RawSourceIdStage),
 }
 
 #[derive(Debug, Deserialize)]
@@ -320,6 +416,50 @@ struct RawFlattenIdStage {
     separator: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawNormalizeIdStage {
+    #[serde(default)]
+    tables: Vec<String>,
+    #[serde(default)]
+    lowercase: bool,
+    #[serde(default)]
+    unicode: Option<String>,
+    #[serde(default)]
+    strip_accents: bool,
+    #[serde(default)]
+    original_field: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawSourceIdStage {
+    field: String,
+    #[serde(default)]
+    tables: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRedactStage {
+    #[serde(default)]
+    tables: Vec<String>,
+    #[serde(default)]
+    fields: Vec<String>,
+    #[serde(default)]
+    patterns: Vec<RawRedactPattern>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRedactPattern {
+    pattern: String,
+    #[serde(default)]
+    replacement: Option<String>,
+    #[serde(default)]
+    hash: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct RawCommandStage {
@@ -399,6 +539,27 @@ impl TryFrom<RawTransformsFile> for TransformsConfig {
                         separator,
                     }));
                 }
+                RawStage::NormalizeId(raw) => {
+                    stages.push(ConfiguredStage::NormalizeId(validate_normalize_id(i, raw)?));
+                }
+                RawStage::Redact(raw) => {
+                    stages.push(ConfiguredStage::Redact(validate_redact(i, raw)?));
+                }
+                RawStage::SourceId(raw) => {
+                    let ctx = format!("transforms[{i}] (type = \"source_id\")");
+                    if raw.field.is_empty() {
+                        bail!("{ctx}: field must not be empty");
+                    }
+                    if !stages.is_empty() {
+                        bail!(
+                            "{ctx}: must be the first stage, so it sees IDs before other stages rewrite them"
+                        );
+                    }
+                    stages.push(ConfiguredStage::SourceId(SourceIdStageConfig {
+                        field: raw.field,
+                        tables: raw.tables,
+                    }));
+                }
             }
         }
         Ok(TransformsConfig { pipeline, stages })
@@ -413,12 +574,33 @@ fn validate_pipeline(raw: RawPipeline) -> Result<PipelineSection> {
     if let Some(0) = raw.max_in_flight {
         bail!("{}: max_in_flight must be >= 1", ctx());
     }
+    if let Some(0) = raw.checkpoint_every_batches {
+        bail!("{}: checkpoint_every_batches must be >= 1", ctx());
+    }
     let batch_max_wait = raw
         .batch_max_wait
         .as_deref()
         .map(parse_humantime)
         .transpose()
         .with_context(|| format!("{}: invalid batch_max_wait", ctx()))?;
+    let commit_interval = raw
+        .commit_interval
+        .as_deref()
+        .map(parse_humantime)
+        .transpose()
+        .with_context(|| format!("{}: invalid commit_interval", ctx()))?;
+    if commit_interval == Some(Duration::ZERO) {
+        bail!("{}: commit_interval must be greater than zero", ctx());
+    }
+    let checkpoint_every = raw
+        .checkpoint_every
+        .as_deref()
+        .map(parse_humantime)
+        .transpose()
+        .with_context(|| format!("{}: invalid checkpoint_every", ctx()))?;
+    if checkpoint_every == Some(Duration::ZERO) {
+        bail!("{}: checkpoint_every must be greater than zero", ctx());
+    }
     let timeout = raw
         .timeout
         .as_deref()
@@ -433,11 +615,73 @@ fn validate_pipeline(raw: RawPipeline) -> Result<PipelineSection> {
         failure_policy,
         batch_size: raw.batch_size,
         batch_max_wait,
+        commit_interval,
+        checkpoint_every_batches: raw.checkpoint_every_batches,
+        checkpoint_every,
         timeout,
         max_in_flight: raw.max_in_flight,
     })
 }
 
+fn validate_normalize_id(index: usize, raw: RawNormalizeIdStage) -> Result<NormalizeIdStageConfig> {
+    let ctx = || format!("transforms[{index}] (type = \"normalize_id\")");
+    let unicode = match raw
+        .unicode
+        .as_deref()
+        .map(|u| u.trim().to_ascii_lowercase())
+    {
+        None => None,
+        Some(u) if u == "nfc" => Some(UnicodeForm::Nfc),
+        Some(u) if u == "nfkc" => Some(UnicodeForm::Nfkc),
+        Some(other) => bail!(
+            "{}: unsupported unicode {other:?} (expected \"nfc\" or \"nfkc\")",
+            ctx()
+        ),
+    };
+    if !raw.lowercase && unicode.is_none() && !raw.strip_accents {
+        bail!(
+            "{}: set at least one of lowercase, unicode or strip_accents",
+            ctx()
+        );
+    }
+    if raw.original_field.as_deref() == Some("") {
+        bail!("{}: original_field must not be empty", ctx());
+    }
+    Ok(NormalizeIdStageConfig {
+        tables: raw.tables,
+        lowercase: raw.lowercase,
+        unicode,
+        strip_accents: raw.strip_accents,
+        original_field: raw.original_field,
+    })
+}
+
+fn validate_redact(index: usize, raw: RawRedactStage) -> Result<RedactStageConfig> {
+    let ctx = |p: Option<usize>| match p {
+        Some(p) => format!("transforms[{index}] (type = \"redact\") patterns[{p}]"),
+        None => format!("transforms[{index}] (type = \"redact\")"),
+    };
+    if raw.patterns.is_empty() {
+        bail!("{}: patterns must not be empty", ctx(None));
+    }
+    let mut patterns = Vec::with_capacity(raw.patterns.len());
+    for (p, pattern) in raw.patterns.into_iter().enumerate() {
+        let redaction = match (pattern.replacement, pattern.hash) {
+            (Some(_), true) => bail!("{}: set replacement or hash, not both", ctx(Some(p))),
+            (Some(mask), false) => Redaction::Mask(mask),
+            (None, true) => Redaction::Hash,
+            (None, false) => bail!("{}: set replacement or hash = true", ctx(Some(p))),
+        };
+        RedactPattern::new(&pattern.pattern, redaction.clone()).with_context(|| ctx(Some(p)))?;
+        patterns.push((pattern.pattern, redaction));
+    }
+    Ok(RedactStageConfig {
+        tables: raw.tables,
+        fields: raw.fields,
+        patterns,
+    })
+}
+
 struct RawCommandFields {
     command: Option<Vec<String>>,
     mode: Option<String>,
@@ -541,6 +785,115 @@ fn validate_retry(ctx: &impl Fn() -> String, raw: RawRetry) -> Result<RetryPolic
 mod tests {
     use super::*;
 
+    #[test]
+    fn normalize_id_parses_per_table_settings() {
+        let cfg = parse_transforms_toml(
+            r#"
+[[transforms]]
+type = "normalize_id"
+tables = ["users"]
+lowercase = true
+unicode = "NFKC"
+strip_accents = true
+original_field = "original_id"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cfg.stages,
+            vec![ConfiguredStage::NormalizeId(NormalizeIdStageConfig {
+                tables: vec!["users".to_string()],
+                lowercase: true,
+                unicode: Some(UnicodeForm::Nfkc),
+                strip_accents: true,
+                original_field: Some("original_id".to_string()),
+            })]
+        );
+        assert!(!Pipeline::from_config(&cfg).unwrap().is_identity());
+
+        let err = parse_transforms_toml("[[transforms]]\ntype = \"normalize_id\"\n").unwrap_err();
+        assert!(err.to_string().contains("at least one"), "{err}");
+        let err =
+            parse_transforms_toml("[[transforms]]\ntype = \"normalize_id\"\nunicode = \"nfd\"\n")
+                .unwrap_err();
+        assert!(err.to_string().contains("unsupported unicode"), "{err}");
+    }
+
+    #[test]
+    fn source_id_must_be_the_first_stage() {
+        let cfg = parse_transforms_toml(
+            "[[transforms]]\ntype = \"source_id\"\nfield = \"source_id\"\n\n[[transforms]]\ntype = \"flatten_id\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            cfg.stages[0],
+            ConfiguredStage::SourceId(SourceIdStageConfig {
+                field: "source_id".to_string(),
+                tables: Vec::new(),
+            })
+        );
+
+        let err = parse_transforms_toml(
+            "[[transforms]]\ntype = \"flatten_id\"\n\n[[transforms]]\ntype = \"source_id\"\nfield = \"source_id\"\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("must be the first stage"), "{err}");
+        let err = parse_transforms_toml("[[transforms]]\ntype = \"source_id\"\nfield = \"\"\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("must not be empty"), "{err}");
+    }
+
+    #[test]
+    fn redact_parses_patterns_and_rejects_bad_ones() {
+        let cfg = parse_transforms_toml(
+            r#"
+[[transforms]]
+type = "redact"
+fields = ["email", "notes"]
+
+[[transforms.patterns]]
+pattern = '[\w.+-]+@[\w-]+\.[\w.]+'
+replacement = "<email>"
+
+[[transforms.patterns]]
+pattern = '\d{3}-\d{4}'
+hash = true
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cfg.stages,
+            vec![ConfiguredStage::Redact(RedactStageConfig {
+                tables: Vec::new(),
+                fields: vec!["email".to_string(), "notes".to_string()],
+                patterns: vec![
+                    (
+                        r"[\w.+-]+@[\w-]+\.[\w.]+".to_string(),
+                        Redaction::Mask("<email>".to_string())
+                    ),
+                    (r"\d{3}-\d{4}".to_string(), Redaction::Hash),
+                ],
+            })]
+        );
+        assert!(!Pipeline::from_config(&cfg).unwrap().is_identity());
+
+        let err = parse_transforms_toml("[[transforms]]\ntype = \"redact\"\n").unwrap_err();
+        assert!(
+            err.to_string().contains("patterns must not be empty"),
+            "{err}"
+        );
+        let err = parse_transforms_toml(
+            "[[transforms]]\ntype = \"redact\"\n[[transforms.patterns]]\npattern = \"(\"\nhash = true\n",
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("invalid pattern"), "{err:#}");
+        let err = parse_transforms_toml(
+            "[[transforms]]\ntype = \"redact\"\n[[transforms.patterns]]\npattern = \"x\"\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("replacement or hash"), "{err}");
+    }
+
     #[test]
     fn flatten_id_parses_with_default_separator() {
         let cfg = parse_transforms_toml(
@@ -658,6 +1011,9 @@ command = ["kreuzberg-worker"]
 failure_policy = "fail"
 batch_size = 1000
 batch_max_wait = "500ms"
+commit_interval = "5s"
+checkpoint_every_batches = 50
+checkpoint_every = "30s"
 timeout = "120s"
 max_in_flight = 2
 
@@ -697,6 +1053,9 @@ retry.jitter = false
         assert_eq!(opts.failure_policy, FailurePolicy::Fail);
         assert_eq!(opts.batch_size, 1000);
         assert_eq!(opts.batch_max_wait, Duration::from_millis(500));
+        assert_eq!(opts.commit_interval, Some(Duration::from_secs(5)));
+        assert_eq!(opts.checkpoint_every_batches, Some(50));
+        assert_eq!(opts.checkpoint_every, Some(Duration::from_secs(30)));
         assert_eq!(opts.timeout, Duration::from_secs(120));
         assert_eq!(opts.max_in_flight, 2);
     }
@@ -4,14 +4,13 @@ use crate::pipeline::test_support::{
     ExternalBatchScript, RecordingSink, ScriptedChangeFeed, ScriptedExternalTransport,
 };
 use crate::pipeline::{run_change_feed, ApplyOpts, ExternalTransform, Pipeline, PositionedChange};
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
-use surreal_sync_core::{Change, Value};
+use surreal_sync_core::{Change, FieldMap, Value};
 use tokio::time::timeout;
 
 fn change(id: i64) -> Change {
-    let mut data = HashMap::new();
+    let mut data = FieldMap::new();
     data.insert(
         "name".to_string(),
         Value::VarChar {
@@ -244,7 +243,7 @@ async fn external_exchanges_relation_changes_over_wire() {
         Value::Int64(7),
         ThingRef::new("users", Value::Int64(1)),
         ThingRef::new("users", Value::Int64(2)),
-        HashMap::new(),
+        FieldMap::new(),
     );
     let out = ext
         .exchange_relation_changes(1, vec![RelationChange::create(rel)])
@@ -273,7 +272,7 @@ async fn external_pipeline_transforms_mixed_relation_and_change() {
         Value::Int64(5),
         ThingRef::new("users", Value::Int64(5)),
         ThingRef::new("users", Value::Int64(6)),
-        HashMap::new(),
+        FieldMap::new(),
     );
     let mut driver = crate::pipeline::test_support::ScriptedSourceDriver::new(vec![
         PositionedEvent::change(change(1), 10u64),
@@ -320,7 +319,7 @@ async fn mixed_external_batch_uses_distinct_wire_batch_ids_and_kinds() {
         Value::Int64(9),
         ThingRef::new("users", Value::Int64(1)),
         ThingRef::new("users", Value::Int64(2)),
-        HashMap::new(),
+        FieldMap::new(),
     );
     let events = vec![
         ApplyEvent::Change(change(1)),
@@ -1,9 +1,8 @@
 //! Built-in in-place transform: flatten Array record IDs to Text.
 
 use anyhow::Result;
-use std::collections::HashMap;
 use surreal_sync_core::InPlaceTransform;
-use surreal_sync_core::{flatten_composite_id, Relation, RelationChange, Value};
+use surreal_sync_core::{flatten_composite_id, FieldMap, Relation, RelationChange, Value};
 
 /// Default separator matching relation FK flattening and historical Snowflake IDs.
 pub const DEFAULT_FLATTEN_ID_SEPARATOR: &str = ":";
@@ -41,7 +40,7 @@ impl InPlaceTransform for FlattenId {
         &self,
         _table: &str,
         id: &mut Value,
-        _fields: Option<&mut HashMap<String, Value>>,
+        _fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         *id = flatten_composite_id(std::mem::replace(id, Value::Null), &self.separator);
         Ok(())
@@ -4,13 +4,12 @@
 //! scripts change-stream events directly, so the conflict (dedup) cases and
 //! the bounded-memory guarantee run in microseconds.
 
-use std::collections::HashMap;
 use std::sync::Mutex;
 
 use anyhow::Result;
 use async_trait::async_trait;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, ChangeOp, Relation, RelationChange, Row, Value};
+use surreal_sync_core::{Change, ChangeOp, FieldMap, Relation, RelationChange, Row, Value};
 use uuid::Uuid;
 
 use crate::pipeline::{
@@ -141,13 +140,13 @@ impl MockSource {
             position: state.position,
             table: "surreal_sync_signal".to_string(),
             pk: PkTuple::new(vec![Value::Uuid(id)]),
-            change: Change::create("surreal_sync_signal", Value::Uuid(id), HashMap::new()),
+            change: Change::create("surreal_sync_signal", Value::Uuid(id), FieldMap::new()),
         }
     }
 }
 
 fn user_row(i: i64) -> Row {
-    let mut fields = HashMap::new();
+    let mut fields = FieldMap::new();
     fields.insert("id".to_string(), Value::Int64(i));
     fields.insert("val".to_string(), Value::Text(format!("v{i}")));
     Row::new("users", i as u64, Value::Int64(i), fields)
@@ -257,7 +256,7 @@ impl WatermarkSource for MockSource {
 // ---------------------------------------------------------------------------
 
 fn update_event(i: i64) -> DataEvent {
-    let mut fields = HashMap::new();
+    let mut fields = FieldMap::new();
     fields.insert("id".to_string(), Value::Int64(i));
     fields.insert("val".to_string(), Value::Text(format!("v{i}-updated")));
     DataEvent {
@@ -276,7 +275,7 @@ fn delete_event(i: i64) -> DataEvent {
 }
 
 fn create_event(i: i64) -> DataEvent {
-    let mut fields = HashMap::new();
+    let mut fields = FieldMap::new();
     fields.insert("id".to_string(), Value::Int64(i));
     fields.insert("val".to_string(), Value::Text(format!("v{i}")));
     DataEvent {
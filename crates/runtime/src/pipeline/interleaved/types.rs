@@ -151,7 +151,7 @@ mod pk_key_tests {
 
     #[test]
     fn from_row_canonicalizes_field_pk() {
-        let mut fields = std::collections::HashMap::new();
+        let mut fields = surreal_sync_core::FieldMap::new();
         fields.insert("id".to_string(), Value::Int32(7));
         let row = Row::new("users", 0, Value::Int32(7), fields);
         let pk = PkTuple::from_row(&row, &["id".to_string()]).unwrap();
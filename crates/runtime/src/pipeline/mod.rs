@@ -45,23 +45,27 @@ mod flatten_id;
 mod framer;
 mod inplace;
 pub mod interleaved;
+mod normalize_id;
 // Nested under `runtime::pipeline` after absorbing the former pipeline crate.
 #[allow(clippy::module_inception)]
 mod pipeline;
+mod redact;
+mod source_id;
 
 pub use apply::{
     apply_changes, apply_changes_with, apply_relation_changes, apply_relation_changes_with,
     run_change_feed, run_change_feed_with, run_source_runtime, run_source_runtime_with,
     write_relations, write_relations_with, write_rows, write_rows_with, AdhocApply, ApplyContext,
     ApplyEvent, ApplyOpts, BatchTransformer, ChangeFeed, ChangeFeedDriver, ChangeFeedRef,
-    CheckpointPolicy, ControlSignal, FailurePolicy, PositionedChange, PositionedEvent,
-    RelationChunkDriver, RelationChunkSource, RowChunkDriver, RowChunkSource, RuntimeExit,
-    SourceDriver, SourceRuntimeOpts, StopReason,
+    CheckpointPolicy, ControlSignal, ConvertingRowChunks, FailurePolicy, ParallelRowChunks,
+    PositionedChange, PositionedEvent, RawChunkSource, RelationChunkDriver, RelationChunkSource,
+    RowChunkDriver, RowChunkSource, RuntimeExit, SourceDriver, SourceRuntimeOpts, StopReason,
 };
 pub use config::{
     ensure_command_resolvable, load_pipeline_and_opts, load_transforms_config, parse_humantime,
     parse_transforms_toml, CommandStageConfig, ConfiguredStage, FlattenIdStageConfig,
-    PipelineSection, StdioConfig, TransformsConfig,
+    NormalizeIdStageConfig, PipelineSection, RedactStageConfig, SourceIdStageConfig, StdioConfig,
+    TransformsConfig,
 };
 pub use cow::CowBatch;
 pub use external::{
@@ -81,7 +85,10 @@ pub use interleaved::{
     SnapshotTableProgress, SnapshotTransforms, TableSpec, WatermarkKind, WatermarkSource,
     DEFAULT_CHUNK_SIZE,
 };
+pub use normalize_id::{NormalizeId, UnicodeForm};
 pub use pipeline::{Pipeline, Stage};
+pub use redact::{Redact, RedactPattern, Redaction};
+pub use source_id::SourceId;
 
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
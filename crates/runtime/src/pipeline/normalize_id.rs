@@ -0,0 +1,241 @@
+//! Built-in in-place transform: normalize text record IDs (case, Unicode form,
+//! accents) so keys that a source compares case- or accent-insensitively map
+//! to one SurrealDB record.
+
+use anyhow::Result;
+use surreal_sync_core::InPlaceTransform;
+use surreal_sync_core::{FieldMap, Relation, RelationChange, Value};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Unicode normalization form applied to text IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeForm {
+    /// Canonical composition (`é` as one code point).
+    Nfc,
+    /// Compatibility composition (also folds e.g. `ﬁ` → `fi`, full-width → ASCII).
+    Nfkc,
+}
+
+/// Rewrite text record IDs of the configured tables to a normalized spelling.
+///
+/// Text, `Char` and `VarChar` IDs (and text parts of composite Array IDs) are
+/// normalized in this order: accents stripped, Unicode form applied,
+/// lowercased. Other ID types are left unchanged. Relation edge IDs and
+/// endpoints are normalized by their own table, so links keep pointing at
+/// normalized records.
+///
+/// With [`Self::original_field`] set, the un-normalized ID is kept in that
+/// field of every record (not on deletes). When several source keys
+/// normalize to one ID, the last write wins and its spelling is kept.
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeId {
+    /// Tables to normalize; empty means every table.
+    pub tables: Vec<String>,
+    /// Lowercase IDs (full Unicode case mapping).
+    pub lowercase: bool,
+    /// Unicode normalization form, if any.
+    pub unicode: Option<UnicodeForm>,
+    /// Remove combining marks (`é` → `e`).
+    pub strip_accents: bool,
+    /// Field that receives the original ID.
+    pub original_field: Option<String>,
+}
+
+impl NormalizeId {
+    fn applies_to(&self, table: &str) -> bool {
+        self.tables.is_empty() || self.tables.iter().any(|t| t == table)
+    }
+
+    /// Normalize one string according to this stage's settings.
+    pub fn normalize_str(&self, s: &str) -> String {
+        let mut out = if self.strip_accents {
+            s.nfd().filter(|c| !is_combining_mark(*c)).nfc().collect()
+        } else {
+            s.to_string()
+        };
+        out = match self.unicode {
+            Some(UnicodeForm::Nfc) => out.nfc().collect(),
+            Some(UnicodeForm::Nfkc) => out.nfkc().collect(),
+            None => out,
+        };
+        if self.lowercase {
+            out = out.to_lowercase();
+        }
+        out
+    }
+
+    /// Normalize an ID value (text variants and text parts of Array IDs).
+    pub fn normalize_value(&self, id: &mut Value) {
+        match id {
+            Value::Text(s) | Value::Char { value: s, .. } | Value::VarChar { value: s, .. } => {
+                *s = self.normalize_str(s)
+            }
+            Value::Array { elements, .. } => {
+                for element in elements {
+                    self.normalize_value(element);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn normalize_keeping_original(&self, id: &mut Value, fields: Option<&mut FieldMap>) {
+        if let (Some(field), Some(fields)) = (&self.original_field, fields) {
+            fields.insert(field.clone(), id.clone());
+        }
+        self.normalize_value(id);
+    }
+}
+
+impl InPlaceTransform for NormalizeId {
+    fn transform(&self, table: &str, id: &mut Value, fields: Option<&mut FieldMap>) -> Result<()> {
+        if self.applies_to(table) {
+            self.normalize_keeping_original(id, fields);
+        }
+        Ok(())
+    }
+
+    fn transform_relation(&self, relation: &mut Relation) -> Result<()> {
+        if self.applies_to(&relation.relation_type) {
+            self.normalize_keeping_original(&mut relation.id, Some(&mut relation.data));
+        }
+        if self.applies_to(&relation.input.table) {
+            self.normalize_value(&mut relation.input.id);
+        }
+        if self.applies_to(&relation.output.table) {
+            self.normalize_value(&mut relation.output.id);
+        }
+        Ok(())
+    }
+
+    fn transform_relation_change(&self, change: &mut RelationChange) -> Result<()> {
+        self.transform_relation(&mut change.relation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_core::{Row, ThingRef, Type};
+
+    fn email_ids() -> NormalizeId {
+        NormalizeId {
+            tables: vec!["users".to_string()],
+            lowercase: true,
+            unicode: Some(UnicodeForm::Nfkc),
+            strip_accents: true,
+            original_field: None,
+        }
+    }
+
+    fn normalized_row_id(t: &NormalizeId, table: &str, id: &str) -> Value {
+        let mut row = Row::new(table, 0, Value::Text(id.to_string()), FieldMap::new());
+        t.transform_row(&mut row).unwrap();
+        row.id
+    }
+
+    #[test]
+    fn case_folding_maps_spellings_to_one_id() {
+        let t = email_ids();
+        let expected = Value::Text("alice@example.com".into());
+        for id in [
+            "Alice@Example.com",
+            "ALICE@EXAMPLE.COM",
+            "alice@example.com",
+        ] {
+            assert_eq!(normalized_row_id(&t, "users", id), expected, "{id}");
+        }
+    }
+
+    #[test]
+    fn accent_stripping_maps_composed_and_decomposed_forms_to_one_id() {
+        let t = email_ids();
+        let expected = Value::Text("jose".into());
+        // Precomposed é, decomposed e + U+0301, and the plain spelling.
+        for id in ["José", "Jose\u{0301}", "JOSE"] {
+            assert_eq!(normalized_row_id(&t, "users", id), expected, "{id:?}");
+        }
+    }
+
+    #[test]
+    fn nfc_keeps_accents_but_unifies_encodings() {
+        let t = NormalizeId {
+            unicode: Some(UnicodeForm::Nfc),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalized_row_id(&t, "t", "Jose\u{0301}"),
+            Value::Text("Jos\u{e9}".into())
+        );
+        let t = NormalizeId {
+            unicode: Some(UnicodeForm::Nfkc),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalized_row_id(&t, "t", "\u{FB01}le"),
+            Value::Text("file".into())
+        );
+    }
+
+    #[test]
+    fn other_tables_and_non_text_ids_are_untouched() {
+        let t = email_ids();
+        assert_eq!(
+            normalized_row_id(&t, "orders", "Alice"),
+            Value::Text("Alice".into())
+        );
+        let mut row = Row::new("users", 0, Value::Int64(7), FieldMap::new());
+        t.transform_row(&mut row).unwrap();
+        assert_eq!(row.id, Value::Int64(7));
+    }
+
+    #[test]
+    fn keeps_original_id_in_field() {
+        let t = NormalizeId {
+            original_field: Some("original_id".to_string()),
+            ..email_ids()
+        };
+        let mut row = Row::new(
+            "users",
+            0,
+            Value::Array {
+                elements: vec![Value::Text("Ünïcode".into()), Value::Int32(1)],
+                element_type: Box::new(Type::Text),
+            },
+            FieldMap::new(),
+        );
+        let original = row.id.clone();
+        t.transform_row(&mut row).unwrap();
+        assert_eq!(
+            row.id,
+            Value::Array {
+                elements: vec![Value::Text("unicode".into()), Value::Int32(1)],
+                element_type: Box::new(Type::Text),
+            }
+        );
+        assert_eq!(row.fields.get("original_id"), Some(&original));
+    }
+
+    #[test]
+    fn relation_endpoints_follow_their_tables() {
+        let t = email_ids();
+        let mut rel = Relation {
+            relation_type: "follows".to_string(),
+            id: Value::Text("Edge".into()),
+            input: ThingRef {
+                table: "users".to_string(),
+                id: Value::Text("Bob@Example.com".into()),
+            },
+            output: ThingRef {
+                table: "teams".to_string(),
+                id: Value::Text("Core".into()),
+            },
+            data: FieldMap::new(),
+        };
+        t.transform_relation(&mut rel).unwrap();
+        assert_eq!(rel.id, Value::Text("Edge".into()));
+        assert_eq!(rel.input.id, Value::Text("bob@example.com".into()));
+        assert_eq!(rel.output.id, Value::Text("Core".into()));
+    }
+}
@@ -154,6 +154,7 @@ impl Pipeline {
         if self.is_identity() {
             return Ok(());
         }
+        forget_changed_fields(changes.iter_mut());
         for stage in &self.stages {
             match stage {
                 Stage::InPlace(t) => t.transform_changes_inplace(changes)?,
@@ -193,6 +194,7 @@ impl Pipeline {
         if self.is_identity() {
             return Ok(changes);
         }
+        forget_changed_fields(changes.iter_mut());
         for stage in &self.stages {
             match stage {
                 Stage::InPlace(t) => t.transform_changes_inplace(&mut changes)?,
@@ -319,7 +321,8 @@ impl Pipeline {
     /// row changes and relation changes over NDJSON (no silent pass-through).
     ///
     /// Homogeneous batches may filter/fan-out (length may change). Mixed
-    /// change+relation batches must preserve length of each kind.
+    /// change+relation batches must preserve length of each kind. Truncations
+    /// are not exchanged and keep their position in the batch.
     ///
     /// Mixed External exchanges use distinct wire `batch_id`s (see
     /// [`crate::pipeline::relation_wire_batch_id`]) so change vs relation requests never
@@ -332,6 +335,10 @@ impl Pipeline {
         if self.is_identity() {
             return Ok(events);
         }
+        forget_changed_fields(events.iter_mut().filter_map(|event| match event {
+            ApplyEvent::Change(change) => Some(change),
+            ApplyEvent::RelationChange(_) | ApplyEvent::Truncate(_) => None,
+        }));
         for stage in &self.stages {
             match stage {
                 Stage::InPlace(t) => {
@@ -339,6 +346,7 @@ impl Pipeline {
                         match event {
                             ApplyEvent::Change(c) => t.transform_change(c)?,
                             ApplyEvent::RelationChange(r) => t.transform_relation_change(r)?,
+                            ApplyEvent::Truncate(_) => {}
                         }
                     }
                 }
@@ -350,7 +358,9 @@ impl Pipeline {
                             .into_iter()
                             .map(|e| match e {
                                 ApplyEvent::Change(c) => c,
-                                ApplyEvent::RelationChange(_) => unreachable!(),
+                                ApplyEvent::RelationChange(_) | ApplyEvent::Truncate(_) => {
+                                    unreachable!()
+                                }
                             })
                             .collect();
                         let transformed = ext.exchange_changes(batch_id, changes).await?;
@@ -360,7 +370,9 @@ impl Pipeline {
                             .into_iter()
                             .map(|e| match e {
                                 ApplyEvent::RelationChange(r) => *r,
-                                ApplyEvent::Change(_) => unreachable!(),
+                                ApplyEvent::Change(_) | ApplyEvent::Truncate(_) => {
+                                    unreachable!()
+                                }
                             })
                             .collect();
                         let transformed = ext.exchange_relation_changes(batch_id, rels).await?;
@@ -383,6 +395,7 @@ impl Pipeline {
                                     rel_idxs.push(i);
                                     rels.push((**r).clone());
                                 }
+                                ApplyEvent::Truncate(_) => {}
                             }
                         }
                         // Distinct wire ids: reuse of `batch_id` for both kinds
@@ -426,3 +439,12 @@ impl Pipeline {
         Ok(events)
     }
 }
+
+/// Transforms may rename or rewrite fields, so a source's list of changed
+/// fields no longer describes a transformed change: drop it so the sink
+/// writes the whole record.
+fn forget_changed_fields<'a>(changes: impl Iterator<Item = &'a mut Change>) {
+    for change in changes {
+        change.changed_fields = None;
+    }
+}
@@ -0,0 +1,275 @@
+//! Built-in in-place transform: redact text matching regex patterns (emails,
+//! phone numbers, ...) in field values, whichever field they appear in.
+
+use std::borrow::Cow;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use surreal_sync_core::InPlaceTransform;
+use surreal_sync_core::{FieldMap, Relation, RelationChange, Value};
+
+/// What a match of a [`RedactPattern`] is replaced with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redaction {
+    /// Fixed mask; `$1` / `${name}` expand capture groups as in
+    /// [`Regex::replace_all`].
+    Mask(String),
+    /// Lowercase hex SHA-256 of the matched text, so equal values still
+    /// compare equal after redaction.
+    Hash,
+}
+
+/// One compiled pattern and its replacement.
+#[derive(Debug, Clone)]
+pub struct RedactPattern {
+    regex: Regex,
+    redaction: Redaction,
+}
+
+impl RedactPattern {
+    /// Compile `pattern` once; it is reused for every value.
+    pub fn new(pattern: &str, redaction: Redaction) -> Result<Self> {
+        let regex = Regex::new(pattern).with_context(|| format!("invalid pattern {pattern:?}"))?;
+        Ok(Self { regex, redaction })
+    }
+
+    /// Source text of the pattern.
+    pub fn pattern(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// Replacement for matches of this pattern.
+    pub fn redaction(&self) -> &Redaction {
+        &self.redaction
+    }
+
+    fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match &self.redaction {
+            Redaction::Mask(mask) => self.regex.replace_all(text, mask.as_str()),
+            Redaction::Hash => self.regex.replace_all(text, |caps: &regex::Captures| {
+                format!("{:x}", Sha256::digest(caps[0].as_bytes()))
+            }),
+        }
+    }
+}
+
+/// Replace every match of the configured patterns in text field values.
+///
+/// `Text`, `Char` and `VarChar` values are scanned, including those nested in
+/// arrays, objects, `Set` / `Enum` values and string leaves of JSON values.
+/// Patterns run in order, each over the previous one's output. Record IDs and
+/// relation endpoints are never rewritten; relation fields are.
+#[derive(Debug, Clone, Default)]
+pub struct Redact {
+    /// Tables to redact; empty means every table.
+    pub tables: Vec<String>,
+    /// Top-level fields to scan; empty means every field.
+    pub fields: Vec<String>,
+    /// Patterns applied in order.
+    pub patterns: Vec<RedactPattern>,
+}
+
+impl Redact {
+    fn applies_to(&self, table: &str) -> bool {
+        self.tables.is_empty() || self.tables.iter().any(|t| t == table)
+    }
+
+    /// Redact one string; `None` when no pattern matched.
+    pub fn redact_str(&self, text: &str) -> Option<String> {
+        let mut out = Cow::Borrowed(text);
+        for pattern in &self.patterns {
+            if let Cow::Owned(redacted) = pattern.apply(&out) {
+                out = Cow::Owned(redacted);
+            }
+        }
+        match out {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(redacted) => Some(redacted),
+        }
+    }
+
+    fn redact_in_place(&self, text: &mut String) {
+        if let Some(redacted) = self.redact_str(text) {
+            *text = redacted;
+        }
+    }
+
+    /// Redact every text value inside `value`.
+    pub fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::Text(s)
+            | Value::Char { value: s, .. }
+            | Value::VarChar { value: s, .. }
+            | Value::Enum { value: s, .. } => self.redact_in_place(s),
+            Value::Set { elements, .. } => {
+                elements.iter_mut().for_each(|s| self.redact_in_place(s))
+            }
+            Value::Array { elements, .. } => {
+                elements.iter_mut().for_each(|v| self.redact_value(v));
+            }
+            Value::Object(fields) => fields.values_mut().for_each(|v| self.redact_value(v)),
+            Value::Json(json) | Value::Jsonb(json) => self.redact_json(json),
+            _ => {}
+        }
+    }
+
+    fn redact_json(&self, json: &mut serde_json::Value) {
+        match json {
+            serde_json::Value::String(s) => self.redact_in_place(s),
+            serde_json::Value::Array(items) => items.iter_mut().for_each(|v| self.redact_json(v)),
+            serde_json::Value::Object(map) => map.values_mut().for_each(|v| self.redact_json(v)),
+            _ => {}
+        }
+    }
+
+    fn redact_fields(&self, fields: &mut FieldMap) {
+        for (name, value) in fields.iter_mut() {
+            if self.fields.is_empty() || self.fields.iter().any(|f| f == name) {
+                self.redact_value(value);
+            }
+        }
+    }
+}
+
+impl InPlaceTransform for Redact {
+    fn transform(&self, table: &str, _id: &mut Value, fields: Option<&mut FieldMap>) -> Result<()> {
+        if let (true, Some(fields)) = (self.applies_to(table), fields) {
+            self.redact_fields(fields);
+        }
+        Ok(())
+    }
+
+    fn transform_relation(&self, relation: &mut Relation) -> Result<()> {
+        if self.applies_to(&relation.relation_type) {
+            self.redact_fields(&mut relation.data);
+        }
+        Ok(())
+    }
+
+    fn transform_relation_change(&self, change: &mut RelationChange) -> Result<()> {
+        self.transform_relation(&mut change.relation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_core::{Row, Type};
+
+    const EMAIL: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}";
+
+    fn emails(redaction: Redaction) -> Redact {
+        Redact {
+            patterns: vec![RedactPattern::new(EMAIL, redaction).unwrap()],
+            ..Default::default()
+        }
+    }
+
+    fn row(fields: Vec<(&str, Value)>) -> Row {
+        let fields = fields
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        Row::new("users", 0, Value::Text("alice@example.com".into()), fields)
+    }
+
+    fn text(s: &str) -> Value {
+        Value::Text(s.to_string())
+    }
+
+    #[test]
+    fn masks_emails_in_any_field_and_leaves_other_text_intact() {
+        let t = emails(Redaction::Mask("<email>".into()));
+        let mut row = row(vec![
+            ("contact", text("alice@example.com")),
+            (
+                "notes",
+                text("mail bob.smith@corp.co.uk or carol@x.io today"),
+            ),
+            ("city", text("Paris")),
+            ("age", Value::Int64(30)),
+        ]);
+        t.transform_row(&mut row).unwrap();
+
+        assert_eq!(row.fields["contact"], text("<email>"));
+        assert_eq!(row.fields["notes"], text("mail <email> or <email> today"));
+        assert_eq!(row.fields["city"], text("Paris"));
+        assert_eq!(row.fields["age"], Value::Int64(30));
+        // Record IDs are not field values.
+        assert_eq!(row.id, text("alice@example.com"));
+    }
+
+    #[test]
+    fn field_allowlist_limits_scanned_fields() {
+        let t = Redact {
+            fields: vec!["notes".into()],
+            ..emails(Redaction::Mask("***".into()))
+        };
+        let mut row = row(vec![
+            ("contact", text("alice@example.com")),
+            ("notes", text("cc alice@example.com")),
+        ]);
+        t.transform_row(&mut row).unwrap();
+        assert_eq!(row.fields["contact"], text("alice@example.com"));
+        assert_eq!(row.fields["notes"], text("cc ***"));
+    }
+
+    #[test]
+    fn hash_redaction_is_stable_and_reaches_nested_values() {
+        let t = emails(Redaction::Hash);
+        let hashed = t.redact_str("alice@example.com").unwrap();
+        assert_eq!(hashed.len(), 64);
+        assert_eq!(t.redact_str("alice@example.com").unwrap(), hashed);
+        assert_ne!(t.redact_str("bob@example.com").unwrap(), hashed);
+
+        let mut row = row(vec![
+            (
+                "emails",
+                Value::Array {
+                    elements: vec![text("alice@example.com")],
+                    element_type: Box::new(Type::Text),
+                },
+            ),
+            (
+                "profile",
+                Value::Json(Box::new(serde_json::json!({
+                    "contacts": ["alice@example.com", 42],
+                }))),
+            ),
+        ]);
+        t.transform_row(&mut row).unwrap();
+        assert_eq!(
+            row.fields["emails"],
+            Value::Array {
+                elements: vec![text(&hashed)],
+                element_type: Box::new(Type::Text),
+            }
+        );
+        assert_eq!(
+            row.fields["profile"],
+            Value::Json(Box::new(serde_json::json!({ "contacts": [hashed, 42] })))
+        );
+    }
+
+    #[test]
+    fn patterns_apply_in_order_with_capture_groups() {
+        let t = Redact {
+            patterns: vec![
+                RedactPattern::new(EMAIL, Redaction::Mask("<email>".into())).unwrap(),
+                RedactPattern::new(
+                    r"\+?\d[\d -]{7,}(\d{2})",
+                    Redaction::Mask("<phone ..$1>".into()),
+                )
+                .unwrap(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            t.redact_str("x@y.com, +33 6 12 34 56 78").as_deref(),
+            Some("<email>, <phone ..78>")
+        );
+        assert_eq!(t.redact_str("no contact details"), None);
+        assert!(RedactPattern::new("(", Redaction::Hash).is_err());
+    }
+}
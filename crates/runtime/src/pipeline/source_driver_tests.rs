@@ -6,12 +6,11 @@ use crate::pipeline::{
     CheckpointPolicy, ControlSignal, Pipeline, PositionedEvent, RuntimeExit, SourceDriver,
     SourceRuntimeOpts, StopReason,
 };
-use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use surreal_sync_core::{Change, Relation, RelationChange, ThingRef, Value};
+use surreal_sync_core::{Change, FieldMap, Relation, RelationChange, ThingRef, Value};
 
 fn change(id: i64) -> Change {
-    let mut data = HashMap::new();
+    let mut data = FieldMap::new();
     data.insert(
         "name".to_string(),
         Value::VarChar {
@@ -28,7 +27,7 @@ fn relation(id: i64) -> Relation {
         Value::Int64(id),
         ThingRef::new("users", Value::Int64(id)),
         ThingRef::new("users", Value::Int64(id + 1)),
-        HashMap::new(),
+        FieldMap::new(),
     )
 }
 
@@ -221,6 +220,79 @@ async fn mixed_relation_and_change_events_ordered() {
     assert_eq!(driver.persisted, vec![10, 20, 30]);
 }
 
+#[tokio::test]
+async fn truncate_events_apply_in_source_order() {
+    let mut driver = ScriptedSourceDriver::new(vec![
+        PositionedEvent::change(change(1), 10u64),
+        PositionedEvent::truncate("users", 20u64),
+        PositionedEvent::change(change(2), 30u64),
+    ]);
+    let sink = RecordingSink::new();
+    // One mixed batch through an in-place stage: the truncation keeps its slot.
+    let mut pipeline = Pipeline::new();
+    pipeline.push_inplace(surreal_sync_core::Passthrough);
+    let apply_opts = opts().with_batch_size(3);
+
+    run_source_runtime(
+        &mut driver,
+        &sink,
+        &pipeline,
+        &apply_opts,
+        &SourceRuntimeOpts::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        sink.apply_order_tags(),
+        vec![
+            "change:1".to_string(),
+            "truncate:users".to_string(),
+            "change:2".to_string(),
+        ]
+    );
+    assert_eq!(driver.advances.last(), Some(&30));
+}
+
+#[tokio::test]
+async fn column_filter_stage_drops_excluded_fields_before_sink() {
+    let mut filters = surreal_sync_core::ColumnFilters::new();
+    filters.exclude("users", ["password"]);
+    let (pipeline, _) = crate::load_transforms_with_columns(None, &filters).unwrap();
+    assert!(!pipeline.is_identity());
+
+    let mut with_secret = change(1);
+    with_secret
+        .fields
+        .as_mut()
+        .unwrap()
+        .insert("password".to_string(), Value::Text("hunter2".to_string()));
+    let mut driver = ScriptedSourceDriver::new(vec![
+        PositionedEvent::change(with_secret, 10u64),
+        PositionedEvent::change(change(2), 20u64),
+    ]);
+    let sink = RecordingSink::new();
+    let apply_opts = opts().with_batch_size(2);
+
+    run_source_runtime(
+        &mut driver,
+        &sink,
+        &pipeline,
+        &apply_opts,
+        &SourceRuntimeOpts::default(),
+    )
+    .await
+    .unwrap();
+
+    let applied = sink.applied();
+    assert_eq!(applied.len(), 2);
+    for change in applied {
+        let fields = change.fields.unwrap();
+        assert!(fields.contains_key("name"));
+        assert!(!fields.contains_key("password"), "excluded column synced");
+    }
+}
+
 #[tokio::test]
 async fn interval_when_drained_persists_once_after_advances() {
     // batch_size=1 + max_in_flight=1 ⇒ each item drains fully before the next.
@@ -330,6 +402,69 @@ async fn interval_when_drained_persists_sunk_promptly_when_already_drained() {
     );
 }
 
+#[tokio::test]
+async fn checkpoint_every_batches_throttles_persists_and_flushes_on_stop() {
+    let events = || {
+        (1..=7)
+            .map(|i| PositionedEvent::change(change(i), i as u64 * 10))
+            .collect::<Vec<_>>()
+    };
+    let apply_opts = opts().with_checkpoint_every_batches(Some(3));
+
+    for mut driver in [
+        ScriptedSourceDriver::new(events()),
+        ScriptedSourceDriver::new(events()).interval_when_drained(Duration::from_secs(3600)),
+    ] {
+        let sink = RecordingSink::new();
+        run_source_runtime(
+            &mut driver,
+            &sink,
+            &Pipeline::new(),
+            &apply_opts,
+            &SourceRuntimeOpts::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(driver.advances, vec![10, 20, 30, 40, 50, 60, 70]);
+        assert_eq!(
+            driver.persisted,
+            vec![30, 60, 70],
+            "persist every third batch, then the last sunk position on stop ({:?})",
+            driver.policy
+        );
+    }
+}
+
+#[tokio::test]
+async fn checkpoint_every_defers_persist_until_cancel_flush() {
+    let mut driver = ScriptedSourceDriver::new(vec![
+        PositionedEvent::change(change(1), 10u64),
+        PositionedEvent::change(change(2), 20u64),
+    ])
+    .cancel_after_polls(10);
+    driver.finished_when_empty = false;
+    let sink = RecordingSink::new();
+    let apply_opts = opts().with_checkpoint_every(Some(Duration::from_secs(3600)));
+
+    run_source_runtime(
+        &mut driver,
+        &sink,
+        &Pipeline::new(),
+        &apply_opts,
+        &SourceRuntimeOpts::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(driver.advances, vec![10, 20]);
+    assert_eq!(
+        driver.persisted,
+        vec![20],
+        "advances inside one interval are not persisted until the cancel flush"
+    );
+}
+
 #[tokio::test]
 async fn adhoc_snapshot_receives_apply_helpers_and_can_write() {
     use surreal_sync_core::Row;
@@ -375,7 +510,7 @@ async fn adhoc_snapshot_receives_apply_helpers_and_can_write() {
             apply: &dyn crate::pipeline::AdhocApply,
         ) -> anyhow::Result<()> {
             assert_eq!(tables, &["users".to_string()]);
-            let mut data = HashMap::new();
+            let mut data = FieldMap::new();
             data.insert(
                 "name".to_string(),
                 Value::VarChar {
@@ -1210,7 +1345,7 @@ async fn filter_transform_notes_input_count_for_kafka_and_wal2json() {
                     .into_iter()
                     .map(|e| match e {
                         ApplyEvent::Change(c) => c,
-                        ApplyEvent::RelationChange(_) => unreachable!(),
+                        _ => unreachable!(),
                     })
                     .collect();
                 let out = self.transform_changes(batch_id, changes).await?;
@@ -1433,7 +1568,7 @@ async fn fan_out_transform_notes_input_count_not_output_length() {
                     .into_iter()
                     .map(|e| match e {
                         ApplyEvent::Change(c) => c,
-                        ApplyEvent::RelationChange(_) => unreachable!(),
+                        _ => unreachable!(),
                     })
                     .collect();
                 let out = self.transform_changes(batch_id, changes).await?;
@@ -1655,7 +1790,7 @@ async fn row_chunk_driver_streams_chunks_through_runtime() {
                 return Ok(None);
             }
             let id = self.n as i64;
-            let mut fields = HashMap::new();
+            let mut fields = FieldMap::new();
             fields.insert("v".to_string(), Value::Int64(id));
             Ok(Some(vec![Row::new(
                 "t",
@@ -1682,3 +1817,267 @@ async fn row_chunk_driver_streams_chunks_through_runtime() {
     assert_eq!(sink.rows_written().len(), 2);
     assert!(sink.applied().is_empty());
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn parallel_row_chunks_sink_every_range_row_once() {
+    use crate::pipeline::{ParallelRowChunks, RowChunkDriver, RowChunkSource};
+    use async_trait::async_trait;
+    use std::collections::HashSet;
+    use surreal_sync_core::Row;
+
+    /// Ids `next..end` in chunks, like one key-range reader.
+    struct RangeChunks {
+        next: i64,
+        end: i64,
+        chunk: i64,
+    }
+
+    #[async_trait]
+    impl RowChunkSource for RangeChunks {
+        async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Row>>> {
+            if self.next >= self.end {
+                return Ok(None);
+            }
+            let stop = (self.next + self.chunk).min(self.end);
+            let rows = (self.next..stop)
+                .map(|id| Row::new("t", 0, Value::Int64(id), FieldMap::new()))
+                .collect();
+            self.next = stop;
+            tokio::task::yield_now().await;
+            Ok(Some(rows))
+        }
+    }
+
+    let ranges = (0..4)
+        .map(|i| RangeChunks {
+            next: i * 100,
+            end: i * 100 + 100,
+            chunk: 7,
+        })
+        .collect();
+    let mut driver = RowChunkDriver::new(ParallelRowChunks::spawn(ranges));
+    let sink = RecordingSink::new();
+    let apply_opts = ApplyOpts::identity().with_max_in_flight(2);
+    run_source_runtime(
+        &mut driver,
+        &sink,
+        &Pipeline::new(),
+        &apply_opts,
+        &SourceRuntimeOpts::default(),
+    )
+    .await
+    .unwrap();
+
+    let ids: Vec<i64> = sink
+        .rows_written()
+        .into_iter()
+        .flatten()
+        .map(|row| match row.id {
+            Value::Int64(id) => id,
+            other => panic!("unexpected id {other:?}"),
+        })
+        .collect();
+    assert_eq!(ids.len(), 400, "every range row sunk exactly once");
+    assert_eq!(
+        ids.into_iter().collect::<HashSet<_>>(),
+        (0..400).collect::<HashSet<_>>()
+    );
+    assert_eq!(driver.sunk_count(), 400);
+}
+
+#[tokio::test]
+async fn parallel_row_chunks_surface_reader_errors() {
+    use crate::pipeline::{ParallelRowChunks, RowChunkSource};
+    use async_trait::async_trait;
+    use surreal_sync_core::Row;
+
+    struct FailingChunks;
+
+    #[async_trait]
+    impl RowChunkSource for FailingChunks {
+        async fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Row>>> {
+            anyhow::bail!("range read failed")
+        }
+    }
+
+    let mut merged = ParallelRowChunks::spawn(vec![FailingChunks]);
+    let err = merged.next_chunk().await.unwrap_err();
+    assert!(err.to_string().contains("range read failed"), "{err}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn converting_row_chunks_keep_read_order_with_many_workers() {
+    use crate::pipeline::{ConvertingRowChunks, RawChunkSource, RowChunkDriver};
+    use async_trait::async_trait;
+    use surreal_sync_core::Row;
+
+    /// Raw ids `next..end` in chunks, like a cursor handing out documents.
+    struct RawIds {
+        next: i64,
+        end: i64,
+        chunk: i64,
+    }
+
+    #[async_trait]
+    impl RawChunkSource for RawIds {
+        type Raw = i64;
+
+        async fn next_raw_chunk(&mut self) -> anyhow::Result<Option<Vec<i64>>> {
+            if self.next >= self.end {
+                return Ok(None);
+            }
+            let stop = (self.next + self.chunk).min(self.end);
+            let raw = (self.next..stop).collect();
+            self.next = stop;
+            Ok(Some(raw))
+        }
+    }
+
+    let source = RawIds {
+        next: 0,
+        end: 500,
+        chunk: 9,
+    };
+    let chunks = ConvertingRowChunks::spawn(source, 4, |raw: i64, index| {
+        assert_eq!(raw as u64, index, "index follows read order");
+        // Uneven per-record cost so later chunks can finish first.
+        if raw % 9 == 0 {
+            std::thread::sleep(std::time::Duration::from_millis((raw % 4) as u64));
+        }
+        let mut fields = FieldMap::new();
+        fields.insert("double".to_string(), Value::Int64(raw * 2));
+        Ok(Row::new("t", index, Value::Int64(raw), fields))
+    });
+    let mut driver = RowChunkDriver::new(chunks);
+    let sink = RecordingSink::new();
+    run_source_runtime(
+        &mut driver,
+        &sink,
+        &Pipeline::new(),
+        &ApplyOpts::identity(),
+        &SourceRuntimeOpts::default(),
+    )
+    .await
+    .unwrap();
+
+    let rows: Vec<Row> = sink.rows_written().into_iter().flatten().collect();
+    let ids: Vec<i64> = rows
+        .iter()
+        .map(|row| match row.id {
+            Value::Int64(id) => id,
+            ref other => panic!("unexpected id {other:?}"),
+        })
+        .collect();
+    assert_eq!(ids, (0..500).collect::<Vec<_>>(), "rows sunk in read order");
+    assert!(rows.iter().all(|row| {
+        let Value::Int64(id) = row.id else {
+            return false;
+        };
+        row.fields.get("double") == Some(&Value::Int64(id * 2))
+    }));
+    assert_eq!(driver.sunk_count(), 500);
+}
+
+#[tokio::test]
+async fn converting_row_chunks_surface_conversion_errors() {
+    use crate::pipeline::{ConvertingRowChunks, RawChunkSource, RowChunkSource};
+    use async_trait::async_trait;
+
+    struct OneChunk(Option<Vec<u8>>);
+
+    #[async_trait]
+    impl RawChunkSource for OneChunk {
+        type Raw = u8;
+
+        async fn next_raw_chunk(&mut self) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(self.0.take())
+        }
+    }
+
+    let mut chunks = ConvertingRowChunks::spawn(OneChunk(Some(vec![1, 2])), 2, |raw, _| {
+        anyhow::bail!("cannot convert {raw}")
+    });
+    let err = chunks.next_chunk().await.unwrap_err();
+    assert!(err.to_string().contains("cannot convert 1"), "{err}");
+}
+
+/// A slow trickle never fills a batch and never polls empty, so only the
+/// commit interval starts batches before the source finishes.
+#[tokio::test]
+async fn commit_interval_flushes_slow_trickle_before_batch_fills() {
+    struct TrickleDriver {
+        next: u64,
+        total: u64,
+        advances: Vec<(u64, u64)>,
+    }
+
+    #[async_trait::async_trait]
+    impl SourceDriver for TrickleDriver {
+        type Position = u64;
+
+        async fn poll_work(&mut self) -> anyhow::Result<Vec<PositionedEvent<Self::Position>>> {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            self.next += 1;
+            Ok(vec![PositionedEvent::change(
+                change(self.next as i64),
+                self.next,
+            )])
+        }
+
+        async fn advance_watermark(&mut self, position: Self::Position) -> anyhow::Result<()> {
+            // (sunk position, events polled so far)
+            self.advances.push((position, self.next));
+            Ok(())
+        }
+
+        fn is_finished(&self) -> bool {
+            self.next >= self.total
+        }
+    }
+
+    async fn run(commit_interval: Option<Duration>) -> (TrickleDriver, usize) {
+        let mut driver = TrickleDriver {
+            next: 0,
+            total: 40,
+            advances: Vec::new(),
+        };
+        let sink = RecordingSink::new();
+        let apply_opts = ApplyOpts::default()
+            .with_batch_size(1000)
+            .with_batch_max_wait(Duration::from_secs(10))
+            .with_commit_interval(commit_interval)
+            .with_timeout(Duration::from_secs(5));
+        run_source_runtime(
+            &mut driver,
+            &sink,
+            &Pipeline::new(),
+            &apply_opts,
+            &SourceRuntimeOpts::default(),
+        )
+        .await
+        .unwrap();
+        let applied = sink.applied().len();
+        (driver, applied)
+    }
+
+    // Batch-size trigger only: one batch once the source finishes.
+    let (driver, applied) = run(None).await;
+    assert_eq!(applied, 40);
+    assert_eq!(driver.advances, vec![(40, 40)]);
+
+    let (driver, applied) = run(Some(Duration::from_millis(30))).await;
+    assert_eq!(applied, 40, "every change is sunk exactly once");
+    assert!(
+        driver.advances.len() >= 3,
+        "expected time-based flushes, got {:?}",
+        driver.advances
+    );
+    let (first_sunk, polled) = driver.advances[0];
+    assert!(
+        first_sunk < 40 && polled < 40,
+        "first flush should come before the source finished: {:?}",
+        driver.advances
+    );
+    assert_eq!(driver.advances.last().unwrap().0, 40);
+    assert!(driver.advances.windows(2).all(|w| w[0].0 < w[1].0));
+}
@@ -0,0 +1,126 @@
+//! Built-in in-place transform: keep the source record ID in a field, so it
+//! survives later stages that rewrite IDs.
+
+use anyhow::Result;
+use surreal_sync_core::InPlaceTransform;
+use surreal_sync_core::{FieldMap, Relation, RelationChange, Value};
+
+/// Copy each record's ID, as the source emitted it, into [`Self::field`].
+///
+/// Config validation puts this stage first, so `flatten_id`, `normalize_id`
+/// and command stages rewrite only the record ID, not the copy. Composite IDs
+/// are stored as arrays. Deletes carry no fields and are left unchanged.
+/// Relation edges keep their own ID the same way; endpoints are not copied.
+#[derive(Debug, Clone)]
+pub struct SourceId {
+    /// Field that receives the source ID.
+    pub field: String,
+    /// Tables to annotate; empty means every table.
+    pub tables: Vec<String>,
+}
+
+impl SourceId {
+    /// Keep the source ID of every table in `field`.
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            tables: Vec::new(),
+        }
+    }
+
+    fn applies_to(&self, table: &str) -> bool {
+        self.tables.is_empty() || self.tables.iter().any(|t| t == table)
+    }
+
+    fn keep(&self, table: &str, id: &Value, fields: Option<&mut FieldMap>) {
+        if let Some(fields) = fields {
+            if self.applies_to(table) {
+                fields.insert(self.field.clone(), id.clone());
+            }
+        }
+    }
+}
+
+impl InPlaceTransform for SourceId {
+    fn transform(&self, table: &str, id: &mut Value, fields: Option<&mut FieldMap>) -> Result<()> {
+        self.keep(table, id, fields);
+        Ok(())
+    }
+
+    fn transform_relation(&self, relation: &mut Relation) -> Result<()> {
+        self.keep(
+            &relation.relation_type,
+            &relation.id,
+            Some(&mut relation.data),
+        );
+        Ok(())
+    }
+
+    fn transform_relation_change(&self, change: &mut RelationChange) -> Result<()> {
+        self.transform_relation(&mut change.relation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{parse_transforms_toml, Pipeline};
+    use surreal_sync_core::{Change, Row, Type};
+
+    fn composite_id() -> Value {
+        Value::Array {
+            elements: vec![Value::Text("Acme".into()), Value::Int64(7)],
+            element_type: Box::new(Type::Text),
+        }
+    }
+
+    #[test]
+    fn keeps_source_id_when_later_stages_rewrite_it() {
+        let cfg = parse_transforms_toml(
+            r#"
+[[transforms]]
+type = "source_id"
+field = "source_id"
+
+[[transforms]]
+type = "normalize_id"
+lowercase = true
+
+[[transforms]]
+type = "flatten_id"
+"#,
+        )
+        .unwrap();
+        let pipeline = Pipeline::from_config(&cfg).unwrap();
+
+        let rows = pipeline
+            .apply_rows(vec![Row::new("orders", 0, composite_id(), FieldMap::new())])
+            .unwrap();
+        assert_eq!(rows[0].id, Value::Text("acme:7".into()));
+        assert_eq!(rows[0].get_field("source_id"), Some(&composite_id()));
+
+        let changes = pipeline
+            .apply_changes(vec![
+                Change::update("orders", composite_id(), FieldMap::new()),
+                Change::delete("orders", composite_id()),
+            ])
+            .unwrap();
+        assert_eq!(changes[0].id, Value::Text("acme:7".into()));
+        assert_eq!(
+            changes[0].fields.as_ref().unwrap().get("source_id"),
+            Some(&composite_id())
+        );
+        assert_eq!(changes[1].id, Value::Text("acme:7".into()));
+    }
+
+    #[test]
+    fn other_tables_are_untouched() {
+        let t = SourceId {
+            field: "source_id".to_string(),
+            tables: vec!["orders".to_string()],
+        };
+        let mut row = Row::new("users", 0, Value::Int64(1), FieldMap::new());
+        t.transform_row(&mut row).unwrap();
+        assert!(row.fields.is_empty());
+    }
+}
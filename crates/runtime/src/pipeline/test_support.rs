@@ -501,7 +501,7 @@ impl crate::pipeline::InPlaceTransform for ScriptedInPlace {
         &self,
         _table: &str,
         _id: &mut surreal_sync_core::Value,
-        _fields: Option<&mut std::collections::HashMap<String, surreal_sync_core::Value>>,
+        _fields: Option<&mut surreal_sync_core::FieldMap>,
     ) -> Result<()> {
         let mut c = self.calls.lock().expect("scripted inplace lock");
         let idx = *c;
@@ -696,7 +696,8 @@ impl RecordingSink {
             .clone()
     }
 
-    /// Combined apply order as `"change:{id}"` / `"relation:{id}"` strings.
+    /// Combined apply order as `"change:{id}"` / `"relation:{id}"` /
+    /// `"truncate:{table}"` strings.
     pub fn apply_order_tags(&self) -> Vec<String> {
         self.state
             .lock()
@@ -802,6 +803,12 @@ impl SurrealSink for RecordingSink {
         st.relations_applied.push(change.clone());
         Ok(())
     }
+
+    async fn truncate_table(&self, table: &str) -> Result<()> {
+        let mut st = self.state.lock().expect("recording sink lock");
+        st.event_order.push(format!("truncate:{table}"));
+        Ok(())
+    }
 }
 
 fn relation_id_display(id: &surreal_sync_core::Value) -> String {
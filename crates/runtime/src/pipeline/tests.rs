@@ -2,10 +2,9 @@
 
 use crate::pipeline::{CowBatch, ExternalTransform, InPlaceTransform, Passthrough, Pipeline};
 use anyhow::{bail, Result};
-use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use surreal_sync_core::{Change, ChangeOp, Row, Value};
+use surreal_sync_core::{Change, ChangeOp, FieldMap, Row, Value};
 
 fn sample_row(name: &str) -> Row {
     Row::builder("users", 0, Value::Int64(1))
@@ -20,7 +19,7 @@ fn sample_row(name: &str) -> Row {
 }
 
 fn sample_change(name: &str) -> Change {
-    let mut data = HashMap::new();
+    let mut data = FieldMap::new();
     data.insert(
         "name".to_string(),
         Value::VarChar {
@@ -41,7 +40,7 @@ impl InPlaceTransform for Rename {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         if let Some(fields) = fields {
             fields.insert(
@@ -64,12 +63,7 @@ struct Counting<T> {
 }
 
 impl<T: InPlaceTransform> InPlaceTransform for Counting<T> {
-    fn transform(
-        &self,
-        table: &str,
-        id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
-    ) -> Result<()> {
+    fn transform(&self, table: &str, id: &mut Value, fields: Option<&mut FieldMap>) -> Result<()> {
         // Count via the concrete path that will run (row vs change) in slice helpers.
         self.inner.transform(table, id, fields)
     }
@@ -93,7 +87,7 @@ impl InPlaceTransform for AlwaysFail {
         &self,
         _table: &str,
         _id: &mut Value,
-        _fields: Option<&mut HashMap<String, Value>>,
+        _fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         bail!("stage failed")
     }
@@ -183,6 +177,19 @@ fn lone_passthrough_is_not_identity() {
     );
 }
 
+#[test]
+fn transform_stages_drop_changed_fields() {
+    let change = || sample_change("alice").with_changed_fields(vec!["name".to_string()]);
+    let out = Pipeline::new().apply_changes(vec![change()]).unwrap();
+    assert_eq!(out[0].changed_fields, Some(vec!["name".to_string()]));
+
+    // A stage may rename or rewrite fields, so the list no longer applies.
+    let mut pipeline = Pipeline::new();
+    pipeline.push_inplace(Passthrough);
+    let out = pipeline.apply_changes(vec![change()]).unwrap();
+    assert_eq!(out[0].changed_fields, None);
+}
+
 #[test]
 fn passthrough_on_owned_vec_mutates_in_place_without_realloc() {
     let mut pipeline = Pipeline::new();
@@ -481,7 +488,7 @@ fn pipeline_external_sync_relation_inplace_errors() {
         Value::Int64(1),
         ThingRef::new("users", Value::Int64(1)),
         ThingRef::new("users", Value::Int64(2)),
-        HashMap::new(),
+        FieldMap::new(),
     );
 
     let err_changes = pipeline
@@ -538,7 +545,7 @@ async fn batch_transformer_default_relation_methods_fail_closed() {
         Value::Int64(1),
         ThingRef::new("users", Value::Int64(1)),
         ThingRef::new("users", Value::Int64(2)),
-        HashMap::new(),
+        FieldMap::new(),
     );
 
     let err = t
@@ -570,7 +577,7 @@ async fn batch_transformer_default_relation_methods_fail_closed() {
                         Value::Int64(2),
                         ThingRef::new("users", Value::Int64(3)),
                         ThingRef::new("users", Value::Int64(4)),
-                        HashMap::new(),
+                        FieldMap::new(),
                     ),
                 )),
             ],
@@ -0,0 +1,129 @@
+//! Process-wide sync status for health probes and metrics.
+//!
+//! [`run_source_runtime`](crate::pipeline::run_source_runtime) updates the
+//! [`SyncStatus::global`] instance as it runs: it marks the process started when
+//! the loop begins (after the source connected and loaded its checkpoint),
+//! counts sunk events, and records the last sink-safe position a driver
+//! describes via [`SourceDriver::describe_position`](crate::SourceDriver::describe_position).
+//! Updates are a few atomics per batch, so they are always on; the optional
+//! `health-server` feature only adds the HTTP endpoint that reads them.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Live counters shared by every source runtime in the process.
+#[derive(Debug, Default)]
+pub struct SyncStatus {
+    started: AtomicBool,
+    running: AtomicUsize,
+    records_applied: AtomicU64,
+    last_applied_unix_ms: AtomicU64,
+    checkpoint: Mutex<Option<String>>,
+}
+
+/// Point-in-time copy of [`SyncStatus`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusSnapshot {
+    /// A source runtime has started (connected, checkpoint loaded).
+    pub started: bool,
+    /// Source runtimes currently running.
+    pub running: usize,
+    /// Source events applied to SurrealDB so far.
+    pub records_applied: u64,
+    /// Unix time (ms) of the last successful apply, if any.
+    pub last_applied_unix_ms: Option<u64>,
+    /// Last sink-safe position, as described by the driver.
+    pub checkpoint: Option<String>,
+}
+
+impl SyncStatus {
+    /// The process-wide instance updated by the source runtime.
+    pub fn global() -> &'static SyncStatus {
+        static STATUS: OnceLock<SyncStatus> = OnceLock::new();
+        STATUS.get_or_init(SyncStatus::default)
+    }
+
+    /// Mark a source runtime as running until the returned guard is dropped.
+    pub fn enter_runtime(&self) -> RuntimeGuard<'_> {
+        self.started.store(true, Ordering::SeqCst);
+        self.running.fetch_add(1, Ordering::SeqCst);
+        RuntimeGuard { status: self }
+    }
+
+    /// Record `count` applied events and the sink-safe position they reached.
+    pub fn record_applied(&self, count: u64, checkpoint: Option<String>) {
+        self.records_applied.fetch_add(count, Ordering::SeqCst);
+        self.last_applied_unix_ms
+            .store(unix_millis(SystemTime::now()), Ordering::SeqCst);
+        if let Some(checkpoint) = checkpoint {
+            *self.checkpoint.lock().unwrap() = Some(checkpoint);
+        }
+    }
+
+    /// Copy the current values.
+    pub fn snapshot(&self) -> StatusSnapshot {
+        let last_applied = self.last_applied_unix_ms.load(Ordering::SeqCst);
+        StatusSnapshot {
+            started: self.started.load(Ordering::SeqCst),
+            running: self.running.load(Ordering::SeqCst),
+            records_applied: self.records_applied.load(Ordering::SeqCst),
+            last_applied_unix_ms: (last_applied > 0).then_some(last_applied),
+            checkpoint: self.checkpoint.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Keeps [`StatusSnapshot::running`] incremented while a runtime loop runs.
+pub struct RuntimeGuard<'a> {
+    status: &'a SyncStatus,
+}
+
+impl Drop for RuntimeGuard<'_> {
+    fn drop(&mut self) {
+        self.status.running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl StatusSnapshot {
+    /// Seconds between the last successful apply and `now`.
+    pub fn seconds_since_last_apply(&self, now: SystemTime) -> Option<f64> {
+        self.last_applied_unix_ms
+            .map(|ms| unix_millis(now).saturating_sub(ms) as f64 / 1000.0)
+    }
+}
+
+fn unix_millis(t: SystemTime) -> u64 {
+    t.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn tracks_running_runtimes_and_applied_records() {
+        let status = SyncStatus::default();
+        assert!(!status.snapshot().started);
+        {
+            let _a = status.enter_runtime();
+            let _b = status.enter_runtime();
+            assert_eq!(status.snapshot().running, 2);
+            status.record_applied(3, Some("lsn 0/10".to_string()));
+            status.record_applied(2, None);
+        }
+        let snap = status.snapshot();
+        assert!(snap.started);
+        assert_eq!(snap.running, 0);
+        assert_eq!(snap.records_applied, 5);
+        assert_eq!(snap.checkpoint.as_deref(), Some("lsn 0/10"));
+        let applied = UNIX_EPOCH + Duration::from_millis(snap.last_applied_unix_ms.unwrap());
+        let lag = snap
+            .seconds_since_last_apply(applied + Duration::from_millis(1500))
+            .unwrap();
+        assert!((lag - 1.5).abs() < f64::EPSILON, "{lag}");
+    }
+}
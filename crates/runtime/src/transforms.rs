@@ -7,7 +7,7 @@
 
 use anyhow::Context;
 use std::path::Path;
-use surreal_sync_core::InPlaceTransform;
+use surreal_sync_core::{ColumnFilters, InPlaceTransform};
 
 use crate::pipeline::{load_pipeline_and_opts, ApplyOpts, Pipeline};
 
@@ -84,6 +84,20 @@ where
     )
 }
 
+/// Load optional `--transforms-config`, then append a column-filter stage
+/// when `columns` filters any table (`--include-columns` /
+/// `--exclude-columns`).
+///
+/// The stage runs after the TOML stages, so configured transforms still see
+/// every column. Sources that push the filter into their reads hand over
+/// already-narrowed rows; the stage then has nothing left to drop.
+pub fn load_transforms_with_columns(
+    transforms_config: Option<&Path>,
+    columns: &ColumnFilters,
+) -> anyhow::Result<(Pipeline, ApplyOpts)> {
+    merge_inplace_transforms(transforms_config, columns.clone().into_stage())
+}
+
 /// Like [`merge_inplace_transforms`], but accepts mixed concrete stage types via
 /// [`Box<dyn InPlaceTransform>`] (needed for heterogeneous lists).
 pub fn merge_inplace_boxed(
@@ -204,7 +218,7 @@ command = ["/nonexistent/surreal-sync-transform-worker-cli-xyz"]
             &self,
             _table: &str,
             _id: &mut surreal_sync_core::Value,
-            fields: Option<&mut std::collections::HashMap<String, surreal_sync_core::Value>>,
+            fields: Option<&mut surreal_sync_core::FieldMap>,
         ) -> anyhow::Result<()> {
             if let Some(fields) = fields {
                 fields.insert("tagged".into(), surreal_sync_core::Value::Bool(true));
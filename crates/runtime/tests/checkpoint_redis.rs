@@ -0,0 +1,68 @@
+//! `RedisStore` against a live Redis server.
+//!
+//! Skipped unless `REDIS_URL` is set, e.g.
+//! `REDIS_URL=redis://127.0.0.1:6379 cargo test -p surreal-sync-runtime --features checkpoint_redis`.
+
+use surreal_sync_core::{CheckpointID, CheckpointStore};
+use surreal_sync_runtime::checkpoint_redis::RedisStore;
+
+#[tokio::test]
+async fn store_read_list_and_delete_checkpoints() {
+    let Ok(url) = std::env::var("REDIS_URL") else {
+        eprintln!("REDIS_URL not set; skipping Redis checkpoint test");
+        return;
+    };
+    let store = RedisStore::new(&url).await.expect("connect to Redis");
+
+    // A unique database type keeps parallel runs from seeing each other.
+    let database_type = format!("test-{}", uuid::Uuid::new_v4());
+    let start = CheckpointID {
+        database_type: database_type.clone(),
+        phase: "full_sync_start".to_string(),
+    };
+    let end = CheckpointID {
+        database_type: database_type.clone(),
+        phase: "full_sync_end".to_string(),
+    };
+
+    assert!(store.read_checkpoint(&start).await.unwrap().is_none());
+
+    store
+        .store_checkpoint(&start, "lsn:0/1".to_string())
+        .await
+        .unwrap();
+    store
+        .store_checkpoint(&start, "lsn:0/2".to_string())
+        .await
+        .unwrap();
+    store
+        .store_checkpoint(&end, "lsn:0/3".to_string())
+        .await
+        .unwrap();
+
+    let stored = store.read_checkpoint(&start).await.unwrap().unwrap();
+    assert_eq!(stored.checkpoint_data, "lsn:0/2");
+    assert_eq!(stored.database_type, database_type);
+    assert_eq!(stored.phase, "full_sync_start");
+
+    let mut phases: Vec<String> = store
+        .list_checkpoints()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|id| id.database_type == database_type)
+        .map(|id| id.phase)
+        .collect();
+    phases.sort();
+    assert_eq!(phases, ["full_sync_end", "full_sync_start"]);
+
+    store.delete_checkpoint(&start).await.unwrap();
+    store.delete_checkpoint(&end).await.unwrap();
+    assert!(store.read_checkpoint(&start).await.unwrap().is_none());
+    assert!(!store
+        .list_checkpoints()
+        .await
+        .unwrap()
+        .iter()
+        .any(|id| id.database_type == database_type));
+}
@@ -1,9 +1,8 @@
 //! Child-stdio fixture-worker integration tests (persistent + transient).
 
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
-use surreal_sync_core::{Change, Value};
+use surreal_sync_core::{Change, FieldMap, Value};
 use surreal_sync_runtime::test_support::{RecordingSink, ScriptedChangeFeed};
 use surreal_sync_runtime::{
     run_change_feed, write_rows, ApplyOpts, ChildStdioMode, ConfiguredStage, ExternalTransform,
@@ -16,7 +15,7 @@ fn fixture_cmd(mode: &str) -> Vec<String> {
 }
 
 fn change(id: i64, name: &str) -> Change {
-    let mut data = HashMap::new();
+    let mut data = FieldMap::new();
     data.insert(
         "name".to_string(),
         Value::VarChar {
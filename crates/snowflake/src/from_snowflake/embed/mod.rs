@@ -3,7 +3,7 @@
 //! # Documented embed path
 //!
 //! ```ignore
-//! use surreal_sync_snowflake::{run, FlattenId, InPlaceTransform, Value};
+//! use surreal_sync_snowflake::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 //! use surreal_sync_surreal::Surreal3Sink;
 //!
 //! #[tokio::main]
@@ -34,7 +34,7 @@ use super::{SourceOpts, SyncOpts};
 
 // Re-exports that form the public embed surface (also re-exported from
 // `from_snowflake` and the crate root).
-pub use surreal_sync_core::Value;
+pub use surreal_sync_core::{FieldMap, Value};
 pub use surreal_sync_runtime::SurrealConfig;
 pub use surreal_sync_runtime::{FlattenId, InPlaceTransform, Pipeline};
 
@@ -14,7 +14,7 @@
 //! (`CheckpointPolicy::AdvanceOnly`), but there is nothing to resume after a
 //! process restart.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::types::{convert_cell, ColumnType};
@@ -22,7 +22,7 @@ use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use serde_json::Value as JsonValue;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Row, Value};
+use surreal_sync_core::{FieldMap, Row, Value};
 use surreal_sync_runtime::{
     run_source_runtime_with, ApplyOpts, Pipeline, RowChunkDriver, RowChunkSource, SourceRuntimeOpts,
 };
@@ -317,7 +317,7 @@ fn convert_row(
         ));
     }
 
-    let mut fields: HashMap<String, Value> = HashMap::new();
+    let mut fields: FieldMap = FieldMap::new();
     for (i, col) in columns.iter().enumerate() {
         // When ID columns are explicit, keep them out of the field map so the
         // record ID is not duplicated as a field (matches the PostgreSQL PK behavior).
@@ -10,11 +10,11 @@
 //!
 //! # Embed surface
 //!
-//! Public embed API is only [`run`], [`FlattenId`], [`InPlaceTransform`], and
-//! [`Value`]:
+//! Public embed API is only [`run`], [`FlattenId`], [`InPlaceTransform`],
+//! [`FieldMap`] and [`Value`]:
 //!
 //! ```ignore
-//! use surreal_sync_snowflake::from_snowflake::{run, FlattenId, InPlaceTransform, Value};
+//! use surreal_sync_snowflake::from_snowflake::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 //! use surreal_sync_surreal::Surreal3Sink;
 //!
 //! run::<Surreal3Sink>([Box::new(FlattenId::default()) as Box<dyn InPlaceTransform>]).await?;
@@ -63,8 +63,8 @@ pub struct SyncOpts {
     pub dry_run: bool,
 }
 
-/// Public embed surface: `run`, `FlattenId`, `InPlaceTransform`, `Value` only.
-pub use embed::{run, FlattenId, InPlaceTransform, Value};
+/// Public embed surface: `run`, `FlattenId`, `InPlaceTransform`, `FieldMap`, `Value` only.
+pub use embed::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 
 /// Stock CLI argv helpers (`Args`, `run_args_with_sink`). Not part of the embed API.
 #[doc(hidden)]
@@ -5,8 +5,8 @@
 //! With the `from_snowflake` feature, embedders use only:
 //!
 //! ```ignore
-//! use surreal_sync_snowflake::{run, FlattenId, InPlaceTransform, Value};
-//! // or: use surreal_sync_snowflake::from_snowflake::{run, FlattenId, InPlaceTransform, Value};
+//! use surreal_sync_snowflake::{run, FieldMap, FlattenId, InPlaceTransform, Value};
+//! // or: use surreal_sync_snowflake::from_snowflake::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 //! ```
 
 #[cfg(feature = "types")]
@@ -15,7 +15,7 @@ pub mod types;
 #[cfg(feature = "from_snowflake")]
 pub mod from_snowflake;
 
-/// Crate-root sugar for the public embed surface (same four items as
+/// Crate-root sugar for the public embed surface (same five items as
 /// [`from_snowflake`]).
 #[cfg(feature = "from_snowflake")]
-pub use from_snowflake::{run, FlattenId, InPlaceTransform, Value};
+pub use from_snowflake::{run, FieldMap, FlattenId, InPlaceTransform, Value};
@@ -31,8 +31,7 @@
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
-use surreal_sync_core::{Type, Value};
+use surreal_sync_core::{FieldMap, Type, Value};
 
 /// Metadata for a single result column, deserialized directly from the Snowflake
 /// SQL API `resultSetMetaData.rowType[]` entries.
@@ -311,7 +310,7 @@ fn json_to_universal_value(value: JsonValue) -> Value {
             }
         }
         JsonValue::Object(map) => {
-            let obj: HashMap<String, Value> = map
+            let obj: FieldMap = map
                 .into_iter()
                 .map(|(k, v)| (k, json_to_universal_value(v)))
                 .collect();
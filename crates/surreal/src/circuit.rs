@@ -0,0 +1,444 @@
+//! Circuit breaker around SurrealDB batch writes, shared by the v2 and v3
+//! sinks. See [`CircuitBreakerConfig`] for the state machine and
+//! [`WriteRetryConfig`] for the retries made while it is disabled.
+//!
+//! Only transient failures (see [`is_transient`]) are retried and counted
+//! towards opening the circuit; any other error, such as a rejected record or
+//! an invalid query, fails the write at once because retrying cannot fix it.
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use surreal_sync_core::{CircuitBreakerConfig, WriteRetryConfig};
+use tokio::time::{sleep, Instant};
+
+/// Lowercase fragments of SDK error messages that mark a failure as
+/// transient. The v2 and v3 SDKs share no error type, so both are matched on
+/// their rendered messages.
+const TRANSIENT_MARKERS: &[&str] = &[
+    "connection",
+    "connect error",
+    "not connected",
+    "broken pipe",
+    "timed out",
+    "timeout",
+    "unavailable",
+    "service is busy",
+    "can be retried",
+];
+
+/// Whether a failed write may succeed when retried unchanged: connection
+/// loss, timeouts, an unavailable or overloaded server, or a transaction
+/// conflict SurrealDB reports as retryable.
+pub fn is_transient(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        if cause.is::<std::io::Error>() || cause.is::<tokio::time::error::Elapsed>() {
+            return true;
+        }
+        let message = cause.to_string().to_lowercase();
+        TRANSIENT_MARKERS
+            .iter()
+            .any(|marker| message.contains(marker))
+    })
+}
+
+#[derive(Debug, Default)]
+struct State {
+    consecutive_failures: u32,
+    /// When the circuit opened; `None` while closed.
+    open_since: Option<Instant>,
+    /// Earliest time the next probe may run while open.
+    probe_at: Option<Instant>,
+    /// A probe batch is in flight; other writers wait for its outcome.
+    probing: bool,
+    last_error: String,
+}
+
+enum Gate {
+    Run { probe: bool },
+    Wait(Duration),
+}
+
+/// Retries failed batch writes and stops hammering an unavailable server.
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    retry: WriteRetryConfig,
+    state: Mutex<State>,
+}
+
+impl CircuitBreaker {
+    /// Breaker with the given thresholds.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            retry: WriteRetryConfig::default(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Use `retry` for the attempts and backoff of transiently failed writes.
+    pub fn with_retry(mut self, retry: WriteRetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Thresholds in use.
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Write retry settings in use.
+    pub fn retry(&self) -> &WriteRetryConfig {
+        &self.retry
+    }
+
+    /// Whether the circuit is currently open.
+    pub fn is_open(&self) -> bool {
+        self.state.lock().unwrap().open_since.is_some()
+    }
+
+    /// Run `write` (one whole batch), retrying transient failures through the
+    /// breaker until it succeeds or the circuit has been open past the
+    /// deadline. Other errors are returned at once. With the breaker
+    /// disabled, transient failures are retried up to the configured
+    /// attempts instead.
+    pub async fn call<T, F, Fut>(&self, mut write: F) -> anyhow::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        if !self.config.is_enabled() {
+            return self.call_with_retries(write).await;
+        }
+        loop {
+            let probe = loop {
+                match self.gate()? {
+                    Gate::Run { probe } => break probe,
+                    Gate::Wait(wait) => sleep(wait).await,
+                }
+            };
+            match write().await {
+                Ok(value) => {
+                    self.on_success();
+                    return Ok(value);
+                }
+                Err(e) if !is_transient(&e) => {
+                    self.on_permanent_failure(probe);
+                    return Err(e);
+                }
+                Err(e) => {
+                    if let Some(backoff) = self.on_failure(probe, &e) {
+                        sleep(backoff).await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Run `write`, retrying transient failures with exponential backoff
+    /// until it succeeds or `max_attempts` tries have failed.
+    async fn call_with_retries<T, F, Fut>(&self, mut write: F) -> anyhow::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let max_attempts = self.retry.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            match write().await {
+                Err(e) if attempt < max_attempts && is_transient(&e) => {
+                    let backoff = self.retry.backoff_after(attempt);
+                    tracing::warn!(
+                        "SurrealDB batch write failed ({e:#}); retrying in {backoff:?} \
+                         (attempt {attempt}/{max_attempts})"
+                    );
+                    sleep(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn gate(&self) -> anyhow::Result<Gate> {
+        let mut state = self.state.lock().unwrap();
+        let Some(open_since) = state.open_since else {
+            return Ok(Gate::Run { probe: false });
+        };
+        let now = Instant::now();
+        let open_for = now.duration_since(open_since);
+        if open_for >= self.config.open_deadline {
+            anyhow::bail!(
+                "SurrealDB writes failing: circuit open for {open_for:?} after {} consecutive \
+                 batch failures (deadline {:?}); last error: {}",
+                state.consecutive_failures,
+                self.config.open_deadline,
+                state.last_error
+            );
+        }
+        let deadline_at = open_since + self.config.open_deadline;
+        if let Some(probe_at) = state.probe_at.filter(|at| *at > now) {
+            return Ok(Gate::Wait(probe_at.min(deadline_at) - now));
+        }
+        if state.probing {
+            let wait = self.retry.backoff.min(self.config.cooldown);
+            return Ok(Gate::Wait(wait.min(deadline_at - now)));
+        }
+        state.probing = true;
+        tracing::info!("Probing SurrealDB with one batch before resuming writes");
+        Ok(Gate::Run { probe: true })
+    }
+
+    fn on_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.open_since.is_some() {
+            tracing::info!("SurrealDB write probe succeeded; closing circuit");
+        }
+        *state = State::default();
+    }
+
+    /// A non-transient failure says nothing about server health: it is not
+    /// counted, and a failed probe only frees the probe slot for the next
+    /// writer.
+    fn on_permanent_failure(&self, probe: bool) {
+        if probe {
+            self.state.lock().unwrap().probing = false;
+        }
+    }
+
+    /// Record a failed (transient) write. Returns the backoff before retrying while the
+    /// circuit is still closed; open-circuit waits go through [`Self::gate`].
+    fn on_failure(&self, probe: bool, error: &anyhow::Error) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        state.consecutive_failures += 1;
+        state.last_error = format!("{error:#}");
+        if probe {
+            state.probing = false;
+            state.probe_at = Some(now + self.config.cooldown);
+            tracing::warn!(
+                "SurrealDB write probe failed ({}); circuit stays open for another {:?}",
+                state.last_error,
+                self.config.cooldown
+            );
+            return None;
+        }
+        if state.open_since.is_some() {
+            // Another writer already opened the circuit.
+            return None;
+        }
+        if state.consecutive_failures >= self.config.failure_threshold {
+            state.open_since = Some(now);
+            state.probe_at = Some(now + self.config.cooldown);
+            tracing::warn!(
+                "SurrealDB writes failed {} times in a row ({}); opening circuit for {:?}",
+                state.consecutive_failures,
+                state.last_error,
+                self.config.cooldown
+            );
+            return None;
+        }
+        let backoff = self
+            .retry
+            .backoff_after(state.consecutive_failures)
+            .min(self.config.cooldown);
+        tracing::warn!(
+            "SurrealDB batch write failed ({}); retrying in {backoff:?} ({}/{} failures)",
+            state.last_error,
+            state.consecutive_failures,
+            self.config.failure_threshold
+        );
+        Some(backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn config(failure_threshold: u32) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold,
+            cooldown: Duration::from_secs(5),
+            open_deadline: Duration::from_secs(30),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sustained_failures_open_the_circuit_and_fail_past_the_deadline() {
+        let breaker = CircuitBreaker::new(config(3));
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+        let started = Instant::now();
+
+        let result: anyhow::Result<()> = breaker
+            .call(move || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                anyhow::bail!("connection refused")
+            })
+            .await;
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("circuit open"), "{err}");
+        assert!(err.contains("connection refused"), "{err}");
+        // 3 closed-state attempts, then one probe per 5s cooldown until the
+        // 30s deadline: a bounded number of attempts, not a retry storm.
+        let attempts = counter.load(Ordering::SeqCst);
+        assert!((3..=10).contains(&attempts), "attempts = {attempts}");
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_secs(30), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(40), "{elapsed:?}");
+        assert!(breaker.is_open());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn successful_probe_closes_the_circuit() {
+        let breaker = CircuitBreaker::new(config(2));
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+
+        let value = breaker
+            .call(move || async move {
+                // Fail twice (opens), fail the first probe, then recover.
+                if attempts.fetch_add(1, Ordering::SeqCst) < 3 {
+                    anyhow::bail!("server unavailable")
+                }
+                Ok(7)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(value, 7);
+        assert_eq!(counter.load(Ordering::SeqCst), 4);
+        assert!(!breaker.is_open());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn non_transient_errors_fail_without_retrying() {
+        let breaker = CircuitBreaker::new(config(3));
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+        let started = Instant::now();
+
+        let result: anyhow::Result<()> = breaker
+            .call(move || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                anyhow::bail!("Found 'abc' for field `age`, but expected a int")
+            })
+            .await;
+
+        assert!(result.unwrap_err().to_string().contains("expected a int"));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(started.elapsed(), Duration::ZERO);
+        assert!(!breaker.is_open());
+
+        // Interleaved with transient failures, a permanent one is not counted.
+        let result: anyhow::Result<()> = breaker
+            .call(move || async move {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    1 | 2 => anyhow::bail!("connection reset"),
+                    _ => anyhow::bail!("invalid query"),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap_err().to_string(), "invalid query");
+        assert_eq!(counter.load(Ordering::SeqCst), 4);
+        assert!(!breaker.is_open());
+    }
+
+    #[test]
+    fn transient_errors_are_classified_by_cause() {
+        let io = anyhow::Error::new(std::io::Error::from(std::io::ErrorKind::ConnectionReset))
+            .context("batch write");
+        assert!(is_transient(&io));
+        assert!(is_transient(&anyhow::anyhow!("request timed out")));
+        assert!(is_transient(&anyhow::anyhow!(
+            "Failed to commit transaction due to a read or write conflict. This transaction can be retried"
+        )));
+        assert!(!is_transient(&anyhow::anyhow!(
+            "Parse error: unexpected token"
+        )));
+        assert!(!is_transient(&anyhow::anyhow!(
+            "Database record `users:1` already exists"
+        )));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn disabled_breaker_fails_immediately() {
+        let breaker = CircuitBreaker::new(config(0));
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+
+        let result: anyhow::Result<()> = breaker
+            .call(move || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                anyhow::bail!("invalid field")
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), "invalid field");
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn disabled_breaker_retries_transient_errors_up_to_max_attempts() {
+        let retry = WriteRetryConfig {
+            max_attempts: 3,
+            backoff: Duration::from_millis(50),
+        };
+        let breaker = CircuitBreaker::new(config(0)).with_retry(retry);
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+        let started = Instant::now();
+
+        let result: anyhow::Result<()> = breaker
+            .call(move || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                anyhow::bail!("connection reset by peer")
+            })
+            .await;
+
+        assert!(result.unwrap_err().to_string().contains("connection reset"));
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+        // 50ms, then 100ms between the three attempts.
+        assert_eq!(started.elapsed(), Duration::from_millis(150));
+        assert!(!breaker.is_open());
+
+        // A write that recovers within the attempts succeeds.
+        let value = breaker
+            .call(move || async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 4 {
+                    anyhow::bail!("request timed out")
+                }
+                Ok(7)
+            })
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+        assert_eq!(counter.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn single_attempt_does_not_retry() {
+        let retry = WriteRetryConfig {
+            max_attempts: 0,
+            ..WriteRetryConfig::default()
+        };
+        let breaker = CircuitBreaker::new(config(0)).with_retry(retry);
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+
+        let result: anyhow::Result<()> = breaker
+            .call(move || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                anyhow::bail!("connection refused")
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,46 @@
+//! SurrealQL for conflict-checked incremental writes (see
+//! [`ConflictPolicy`](surreal_sync_core::ConflictPolicy)).
+//!
+//! The checksum is computed server-side from the stored record, both after
+//! a write and when checking the next one, so it does not depend on how
+//! either SDK serializes the bound content.
+
+use surreal_sync_core::SYNC_META_FIELD;
+
+use crate::ddl::escape_ident;
+
+/// Statements upserting `$content` as `$record_id` unless the stored record
+/// no longer matches the checksum surreal-sync stamped on it (or `$overwrite`
+/// is true), then re-stamping it. Result 3 is whether there was a conflict.
+///
+/// With `partial_fields`, only those fields of `$content` are set and the
+/// stored record's other fields are kept (see
+/// [`Change::partial`](surreal_sync_core::Change::partial)).
+pub(crate) fn checked_upsert_statements(partial_fields: Option<Vec<&str>>) -> String {
+    let meta = SYNC_META_FIELD;
+    let write = match partial_fields {
+        Some(fields) if fields.is_empty() => "UPSERT $record_id".to_string(),
+        Some(fields) => format!(
+            "UPSERT $record_id SET {}",
+            fields
+                .iter()
+                .map(|field| {
+                    let field = escape_ident(field);
+                    format!("{field} = $content.{field}")
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => "UPSERT $record_id CONTENT $content".to_string(),
+    };
+    let checksum = format!("crypto::sha256(<string> (SELECT * OMIT {meta} FROM ONLY $record_id))");
+    format!(
+        "LET $stored = $record_id.{meta}.checksum; \
+         LET $conflict = $stored != NONE AND $stored != {checksum}; \
+         IF !$conflict OR $overwrite {{ \
+             {write}; \
+             UPSERT $record_id SET {meta} = {{ synced_at: time::now(), checksum: {checksum} }}; \
+         }}; \
+         RETURN $conflict;"
+    )
+}
@@ -0,0 +1,763 @@
+//! SurrealQL DDL generation from Type.
+//!
+//! Converts sync-core's `Type` to SurrealQL field types and renders
+//! `DEFINE TABLE` / `DEFINE FIELD` / `DEFINE INDEX` statements for a table
+//! definition. Field types follow what the v2/v3 sinks actually write for each
+//! `Type` (e.g. dates and times are written as strings, ULIDs as strings), so
+//! every value a sync writes passes the generated type assertions. Declared
+//! [`FieldConstraints`] render as `ASSERT` clauses, so out-of-range or
+//! non-matching values are rejected by SurrealDB.
+
+use surreal_sync_core::{
+    FieldConstraints, GeneratorFieldDefinition, GeneratorTableDefinition, ToDdl, Type,
+};
+
+/// SurrealDB major version a table is defined for.
+///
+/// SCHEMAFULL tables drop (v2) or reject (v3) nested values that no field
+/// definition covers, and the versions differ in which field types may be
+/// `FLEXIBLE`: v2 needs it on `any` fields to keep their nested contents,
+/// while v3 keeps them and only allows `FLEXIBLE` on object types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DdlTarget {
+    V2,
+    V3,
+}
+
+/// How text fields are typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextType {
+    /// `string`
+    String,
+    /// `string | duration`: the sinks write duration-like text (`PT181S`) as
+    /// durations, which inferred text fields may hold
+    StringOrDuration,
+}
+
+/// SurrealQL DDL generator.
+pub struct SurrealDdl;
+
+impl ToDdl for SurrealDdl {
+    fn to_ddl(&self, sync_type: &Type) -> String {
+        match sync_type {
+            Type::Bool => "bool".to_string(),
+
+            Type::Int8 { .. } | Type::Int16 | Type::Int32 | Type::Int64 => "int".to_string(),
+            Type::Float32 | Type::Float64 => "float".to_string(),
+            // Decimals that exceed SurrealDB's decimal fall back to floats.
+            Type::Decimal { .. } => "number".to_string(),
+
+            Type::Char { .. } | Type::VarChar { .. } | Type::Text => "string".to_string(),
+            Type::Blob | Type::Bytes => "bytes".to_string(),
+
+            // Written as formatted strings, not datetimes.
+            Type::Date | Type::Time | Type::TimeTz => "string".to_string(),
+            Type::LocalDateTime | Type::LocalDateTimeNano | Type::ZonedDateTime => {
+                "datetime".to_string()
+            }
+
+            Type::Uuid => "uuid".to_string(),
+            Type::Ulid => "string".to_string(),
+
+            // JSON documents may hold any value; geometries are written as
+            // GeoJSON objects.
+            Type::Json | Type::Jsonb | Type::Geometry { .. } => "any".to_string(),
+            Type::Object => "object".to_string(),
+
+            Type::Array { element_type } => format!("array<{}>", self.to_ddl(element_type)),
+            Type::Set { .. } => "array<string>".to_string(),
+            Type::Enum { .. } => "string".to_string(),
+
+            Type::Duration => "duration".to_string(),
+            Type::Thing => "record".to_string(),
+        }
+    }
+}
+
+impl SurrealDdl {
+    /// SurrealQL field type for a column, wrapped in `option<..>` when nullable.
+    pub fn to_field_type(&self, sync_type: &Type, nullable: bool) -> String {
+        let ddl = self.to_ddl(sync_type);
+        if nullable && ddl != "any" {
+            format!("option<{ddl}>")
+        } else {
+            ddl
+        }
+    }
+
+    /// `DEFINE TABLE` plus one `DEFINE FIELD` per field of `table` and one
+    /// `DEFINE INDEX` per indexed field.
+    ///
+    /// The table is SCHEMAFULL: fields outside the definition are dropped
+    /// (v2) or rejected (v3). Fields that hold nested objects are `FLEXIBLE`
+    /// as `target` requires, so their contents are kept. Array fields with
+    /// nullable elements get `option<..>` elements. Statements use
+    /// `IF NOT EXISTS`, so an existing table, field or index definition is
+    /// left untouched. An `id` field is skipped: the record id is not a
+    /// regular field and is always indexed. Fields with constraints get an
+    /// `ASSERT` clause (see [`SurrealDdl::assert_clause`]).
+    pub fn define_table(&self, table: &GeneratorTableDefinition, target: DdlTarget) -> Vec<String> {
+        self.define(table, target, TextType::String)
+    }
+
+    /// [`SurrealDdl::define_table`] for a table inferred from sampled values
+    /// (see `TableSchemaInferrer`): text fields are typed `string | duration`,
+    /// since sampled text may include values the sinks write as durations.
+    pub fn define_inferred_table(
+        &self,
+        table: &GeneratorTableDefinition,
+        target: DdlTarget,
+    ) -> Vec<String> {
+        self.define(table, target, TextType::StringOrDuration)
+    }
+
+    fn define(
+        &self,
+        table: &GeneratorTableDefinition,
+        target: DdlTarget,
+        text: TextType,
+    ) -> Vec<String> {
+        let table_name = escape_ident(&table.name);
+        let fields: Vec<&GeneratorFieldDefinition> =
+            table.fields.iter().filter(|f| f.name != "id").collect();
+        let mut statements = vec![format!(
+            "DEFINE TABLE IF NOT EXISTS {table_name} SCHEMAFULL;"
+        )];
+        for field in &fields {
+            let assert = self
+                .assert_clause(field)
+                .map(|expr| format!(" ASSERT {expr}"))
+                .unwrap_or_default();
+            statements.push(format!(
+                "DEFINE FIELD IF NOT EXISTS {} ON TABLE {table_name} TYPE {}{assert};",
+                escape_ident(&field.name),
+                self.field_type_clause(field, target, text)
+            ));
+        }
+        for field in fields.iter().filter(|f| f.indexed) {
+            statements.push(format!(
+                "DEFINE INDEX IF NOT EXISTS {} ON TABLE {table_name} FIELDS {};",
+                escape_ident(&format!("{}_{}_idx", table.name, field.name)),
+                escape_ident(&field.name)
+            ));
+        }
+        statements
+    }
+
+    /// `ASSERT` expression enforcing `field`'s constraints, or `None` when it
+    /// declares none.
+    ///
+    /// Bounds compare the value of numeric fields, and the length of string
+    /// and array fields. A missing value of a nullable field is not checked.
+    pub fn assert_clause(&self, field: &GeneratorFieldDefinition) -> Option<String> {
+        let FieldConstraints { min, max, pattern } = &field.constraints;
+        let measured = match &field.field_type {
+            t if t.is_string() => "string::len($value)",
+            Type::Array { .. } | Type::Set { .. } => "array::len($value)",
+            _ => "$value",
+        };
+        let mut checks = Vec::new();
+        if let Some(min) = min {
+            checks.push(format!("{measured} >= {min}"));
+        }
+        if let Some(max) = max {
+            checks.push(format!("{measured} <= {max}"));
+        }
+        if let Some(pattern) = pattern {
+            checks.push(format!(
+                "string::matches($value, {})",
+                quote_string(pattern)
+            ));
+        }
+        if checks.is_empty() {
+            return None;
+        }
+        let expr = checks.join(" AND ");
+        if field.nullable {
+            Some(format!("$value = NONE OR ({expr})"))
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Field type [`SurrealDdl::define_table`] declares for `field` on
+    /// `target`, without the `FLEXIBLE` keyword.
+    pub fn field_type(&self, field: &GeneratorFieldDefinition, target: DdlTarget) -> String {
+        self.field_type_as(field, target, TextType::String)
+    }
+
+    fn field_type_as(
+        &self,
+        field: &GeneratorFieldDefinition,
+        target: DdlTarget,
+        text: TextType,
+    ) -> String {
+        let ddl = match &field.field_type {
+            // v3 rejects nested objects inside `array<any>`; plain `any` keeps them.
+            Type::Array { element_type }
+                if target == DdlTarget::V3 && renders_any(element_type) =>
+            {
+                "any".to_string()
+            }
+            Type::Array { element_type } if field.nullable_elements => {
+                let element = self.text_aware_ddl(element_type, text);
+                if element == "any" {
+                    "array<any>".to_string()
+                } else {
+                    format!("array<option<{element}>>")
+                }
+            }
+            other => self.text_aware_ddl(other, text),
+        };
+        if field.nullable && ddl != "any" {
+            format!("option<{ddl}>")
+        } else {
+            ddl
+        }
+    }
+
+    /// [`ToDdl::to_ddl`], typing text (also inside arrays) as `text`.
+    fn text_aware_ddl(&self, sync_type: &Type, text: TextType) -> String {
+        match (sync_type, text) {
+            (t, TextType::StringOrDuration) if t.is_string() => "string | duration".to_string(),
+            (Type::Array { element_type }, _) => {
+                format!("array<{}>", self.text_aware_ddl(element_type, text))
+            }
+            (other, _) => self.to_ddl(other),
+        }
+    }
+
+    /// Field type of `field` for `target`, with `FLEXIBLE` where nested
+    /// object contents must be kept.
+    fn field_type_clause(
+        &self,
+        field: &GeneratorFieldDefinition,
+        target: DdlTarget,
+        text: TextType,
+    ) -> String {
+        let ddl = self.field_type_as(field, target, text);
+        let flexible = match target {
+            DdlTarget::V2 => contains_object(&field.field_type) || renders_any(&field.field_type),
+            DdlTarget::V3 => ddl != "any" && contains_object(&field.field_type),
+        };
+        if flexible {
+            format!("{ddl} FLEXIBLE")
+        } else {
+            ddl
+        }
+    }
+}
+
+/// Whether `sync_type` renders as, or as an array of, `any`.
+fn renders_any(sync_type: &Type) -> bool {
+    match sync_type {
+        Type::Json | Type::Jsonb | Type::Geometry { .. } => true,
+        Type::Array { element_type } => renders_any(element_type),
+        _ => false,
+    }
+}
+
+/// Whether `sync_type` is, or is an array of, `object`.
+fn contains_object(sync_type: &Type) -> bool {
+    match sync_type {
+        Type::Object => true,
+        Type::Array { element_type } => contains_object(element_type),
+        _ => false,
+    }
+}
+
+/// Quote an identifier with backticks unless it is a plain identifier.
+pub(crate) fn escape_ident(ident: &str) -> String {
+    let plain = ident
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && ident.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        ident.to_string()
+    } else {
+        format!("`{}`", ident.replace('\\', "\\\\").replace('`', "\\`"))
+    }
+}
+
+/// Render `value` as a double-quoted SurrealQL string literal.
+fn quote_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_core::{
+        GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition, GeometryType,
+    };
+
+    #[test]
+    fn test_scalar_ddl() {
+        assert_eq!(SurrealDdl.to_ddl(&Type::Int32), "int");
+        assert_eq!(SurrealDdl.to_ddl(&Type::Float64), "float");
+        assert_eq!(SurrealDdl.to_ddl(&Type::Text), "string");
+        assert_eq!(SurrealDdl.to_ddl(&Type::ZonedDateTime), "datetime");
+        assert_eq!(SurrealDdl.to_ddl(&Type::Date), "string");
+        assert_eq!(SurrealDdl.to_ddl(&Type::Json), "any");
+    }
+
+    #[test]
+    fn test_field_type_ddl() {
+        let ints = Type::Array {
+            element_type: Box::new(Type::Int64),
+        };
+        assert_eq!(SurrealDdl.to_field_type(&ints, false), "array<int>");
+        assert_eq!(SurrealDdl.to_field_type(&ints, true), "option<array<int>>");
+        assert_eq!(SurrealDdl.to_field_type(&Type::Json, true), "any");
+    }
+
+    fn field(name: &str, field_type: Type, nullable: bool) -> GeneratorFieldDefinition {
+        GeneratorFieldDefinition {
+            name: name.to_string(),
+            field_type,
+            generator: GeneratorConfig::Null,
+            nullable,
+            nullable_elements: false,
+            indexed: false,
+            constraints: FieldConstraints::default(),
+        }
+    }
+
+    fn constrained(
+        field: GeneratorFieldDefinition,
+        min: Option<f64>,
+        max: Option<f64>,
+        pattern: Option<&str>,
+    ) -> GeneratorFieldDefinition {
+        GeneratorFieldDefinition {
+            constraints: FieldConstraints {
+                min,
+                max,
+                pattern: pattern.map(str::to_string),
+            },
+            ..field
+        }
+    }
+
+    fn table(name: &str, fields: Vec<GeneratorFieldDefinition>) -> GeneratorTableDefinition {
+        GeneratorTableDefinition {
+            name: name.to_string(),
+            id: GeneratorIDDefinition {
+                id_type: Type::Int64,
+                generator: GeneratorConfig::Null,
+            },
+            fields,
+        }
+    }
+
+    /// Fields of each kind the sinks write, some with range and pattern
+    /// constraints.
+    fn constrained_table() -> GeneratorTableDefinition {
+        table(
+            "accounts",
+            vec![
+                constrained(
+                    field(
+                        "balance",
+                        Type::Decimal {
+                            precision: 10,
+                            scale: 2,
+                        },
+                        false,
+                    ),
+                    Some(0.0),
+                    Some(1000000.0),
+                    None,
+                ),
+                constrained(field("rating", Type::Float64, true), Some(-1.5), None, None),
+                constrained(
+                    field("code", Type::VarChar { length: 8 }, false),
+                    Some(2.0),
+                    Some(8.0),
+                    Some(r"^[A-Z]+\d*$"),
+                ),
+                constrained(
+                    field(
+                        "tags",
+                        Type::Array {
+                            element_type: Box::new(Type::Text),
+                        },
+                        true,
+                    ),
+                    None,
+                    Some(3.0),
+                    None,
+                ),
+                field("opened_at", Type::ZonedDateTime, false),
+                field("location", Type::geometry(GeometryType::Point), true),
+                field("owner", Type::Uuid, false),
+                field("avatar", Type::Bytes, true),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_define_field_assertions() {
+        let fields = |target| {
+            SurrealDdl
+                .define_table(&constrained_table(), target)
+                .into_iter()
+                .skip(1)
+                .collect::<Vec<_>>()
+        };
+        let expected = || {
+            vec![
+                "DEFINE FIELD IF NOT EXISTS balance ON TABLE accounts TYPE number \
+                 ASSERT $value >= 0 AND $value <= 1000000;"
+                    .to_string(),
+                "DEFINE FIELD IF NOT EXISTS rating ON TABLE accounts TYPE option<float> \
+                 ASSERT $value = NONE OR ($value >= -1.5);"
+                    .to_string(),
+                "DEFINE FIELD IF NOT EXISTS code ON TABLE accounts TYPE string \
+                 ASSERT string::len($value) >= 2 AND string::len($value) <= 8 \
+                 AND string::matches($value, \"^[A-Z]+\\\\d*$\");"
+                    .to_string(),
+                "DEFINE FIELD IF NOT EXISTS tags ON TABLE accounts \
+                 TYPE option<array<string>> \
+                 ASSERT $value = NONE OR (array::len($value) <= 3);"
+                    .to_string(),
+                "DEFINE FIELD IF NOT EXISTS opened_at ON TABLE accounts TYPE datetime;".to_string(),
+                "DEFINE FIELD IF NOT EXISTS owner ON TABLE accounts TYPE uuid;".to_string(),
+                "DEFINE FIELD IF NOT EXISTS avatar ON TABLE accounts TYPE option<bytes>;"
+                    .to_string(),
+            ]
+        };
+
+        let mut v2 = expected();
+        v2.insert(
+            5,
+            "DEFINE FIELD IF NOT EXISTS location ON TABLE accounts TYPE any FLEXIBLE;".to_string(),
+        );
+        assert_eq!(fields(DdlTarget::V2), v2);
+
+        let mut v3 = expected();
+        v3.insert(
+            5,
+            "DEFINE FIELD IF NOT EXISTS location ON TABLE accounts TYPE any;".to_string(),
+        );
+        assert_eq!(fields(DdlTarget::V3), v3);
+    }
+
+    #[test]
+    fn test_define_table_statements() {
+        let tags = GeneratorFieldDefinition {
+            nullable_elements: true,
+            ..field(
+                "tags",
+                Type::Array {
+                    element_type: Box::new(Type::Int64),
+                },
+                false,
+            )
+        };
+        let sku = GeneratorFieldDefinition {
+            indexed: true,
+            ..field("sku", Type::Text, false)
+        };
+        let table = GeneratorTableDefinition {
+            name: "order-lines".to_string(),
+            id: GeneratorIDDefinition {
+                id_type: Type::Int64,
+                generator: GeneratorConfig::Null,
+            },
+            fields: vec![
+                field("id", Type::Int64, false),
+                field("qty", Type::Int64, false),
+                field("note", Type::Text, true),
+                sku,
+                tags,
+                field("attrs", Type::Object, true),
+                field("doc", Type::Json, true),
+                field(
+                    "docs",
+                    Type::Array {
+                        element_type: Box::new(Type::Json),
+                    },
+                    false,
+                ),
+            ],
+        };
+        let common = [
+            "DEFINE TABLE IF NOT EXISTS `order-lines` SCHEMAFULL;",
+            "DEFINE FIELD IF NOT EXISTS qty ON TABLE `order-lines` TYPE int;",
+            "DEFINE FIELD IF NOT EXISTS note ON TABLE `order-lines` TYPE option<string>;",
+            "DEFINE FIELD IF NOT EXISTS sku ON TABLE `order-lines` TYPE string;",
+            "DEFINE FIELD IF NOT EXISTS tags ON TABLE `order-lines` TYPE array<option<int>>;",
+            "DEFINE FIELD IF NOT EXISTS attrs ON TABLE `order-lines` TYPE option<object> FLEXIBLE;",
+        ];
+        let index =
+            "DEFINE INDEX IF NOT EXISTS `order-lines_sku_idx` ON TABLE `order-lines` FIELDS sku;";
+
+        let mut v2 = common.to_vec();
+        v2.extend([
+            "DEFINE FIELD IF NOT EXISTS doc ON TABLE `order-lines` TYPE any FLEXIBLE;",
+            "DEFINE FIELD IF NOT EXISTS docs ON TABLE `order-lines` TYPE array<any> FLEXIBLE;",
+            index,
+        ]);
+        assert_eq!(SurrealDdl.define_table(&table, DdlTarget::V2), v2);
+
+        let mut v3 = common.to_vec();
+        v3.extend([
+            "DEFINE FIELD IF NOT EXISTS doc ON TABLE `order-lines` TYPE any;",
+            "DEFINE FIELD IF NOT EXISTS docs ON TABLE `order-lines` TYPE any;",
+            index,
+        ]);
+        assert_eq!(SurrealDdl.define_table(&table, DdlTarget::V3), v3);
+
+        // Inferred tables also accept durations in text fields.
+        let mut inferred = v3.clone();
+        inferred[2] = "DEFINE FIELD IF NOT EXISTS note ON TABLE `order-lines` \
+                       TYPE option<string | duration>;";
+        inferred[3] =
+            "DEFINE FIELD IF NOT EXISTS sku ON TABLE `order-lines` TYPE string | duration;";
+        assert_eq!(
+            SurrealDdl.define_inferred_table(&table, DdlTarget::V3),
+            inferred
+        );
+    }
+
+    /// An `accounts` row for [`constrained_table`] with the given balance and
+    /// code, and valid values elsewhere.
+    fn account_row(index: u64, balance: &str, code: &str) -> surreal_sync_core::Row {
+        use surreal_sync_core::{FieldMap, GeometryData, Row, Value};
+
+        let mut fields = FieldMap::new();
+        fields.insert("balance".into(), Value::decimal(balance, 10, 2));
+        fields.insert("rating".into(), Value::Null);
+        fields.insert(
+            "code".into(),
+            Value::VarChar {
+                value: code.to_string(),
+                length: 8,
+            },
+        );
+        fields.insert(
+            "tags".into(),
+            Value::Array {
+                elements: vec![Value::Text("a".into())],
+                element_type: Box::new(Type::Text),
+            },
+        );
+        fields.insert(
+            "opened_at".into(),
+            Value::ZonedDateTime(chrono::DateTime::UNIX_EPOCH),
+        );
+        fields.insert(
+            "location".into(),
+            Value::Geometry {
+                data: GeometryData(serde_json::json!({"type": "Point", "coordinates": [1.0, 2.0]})),
+                geometry_type: GeometryType::Point,
+            },
+        );
+        fields.insert("owner".into(), Value::Uuid(uuid::Uuid::nil()));
+        fields.insert("avatar".into(), Value::Bytes(vec![1, 2]));
+        Row::new("accounts", index, Value::Int64(index as i64), fields)
+    }
+
+    /// `(balance, code, accepted)` cases checked against the generated
+    /// assertions: in range, out of range, not matching the pattern, and
+    /// duration-like text (written as a duration, which `string` rejects).
+    const ACCOUNT_CASES: [(&str, &str, bool); 4] = [
+        ("12.50", "AB12", true),
+        ("-1.00", "AB12", false),
+        ("12.50", "ab12", false),
+        ("12.50", "PT5S", false),
+    ];
+
+    /// Rows with duration-like text, nested JSON and null array elements,
+    /// inferred the way file sources infer a schema.
+    fn sampled_table() -> (GeneratorTableDefinition, Vec<surreal_sync_core::Row>) {
+        use surreal_sync_core::{FieldMap, Row, TableSchemaInferrer, Value};
+
+        let rows: Vec<Row> = [("PT181S", Value::Null), ("PT5S", Value::Int64(3))]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (timeout, element))| {
+                let mut fields = FieldMap::new();
+                fields.insert("timeout".to_string(), Value::Text(timeout.to_string()));
+                fields.insert(
+                    "doc".to_string(),
+                    Value::json(serde_json::json!({"a": {"b": i}})),
+                );
+                fields.insert(
+                    "scores".to_string(),
+                    Value::Array {
+                        elements: vec![Value::Int64(1), element],
+                        element_type: Box::new(Type::Int64),
+                    },
+                );
+                Row::new("jobs", i as u64, Value::Int64(i as i64), fields)
+            })
+            .collect();
+        let mut inferrer = TableSchemaInferrer::new("jobs");
+        for row in &rows {
+            inferrer.observe(&row.id, &row.fields);
+        }
+        (inferrer.finish(), rows)
+    }
+
+    #[test]
+    fn test_quote_string() {
+        assert_eq!(quote_string(r"^[A-Z]+\d*$"), r#""^[A-Z]+\\d*$""#);
+        assert_eq!(
+            quote_string("say \"hi\"\n\t\u{1}"),
+            r#""say \"hi\"\n\t\u0001""#
+        );
+    }
+
+    #[cfg(feature = "v3")]
+    #[tokio::test]
+    async fn test_v3_quoted_strings_round_trip() {
+        let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+        let text = "it's a \"quoted\" \\d+ pattern\n\twith\u{1} control";
+        let returned: Option<String> = surreal
+            .query(format!("RETURN {}", quote_string(text)))
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(returned.as_deref(), Some(text));
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_v2_quoted_strings_round_trip() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        let text = "it's a \"quoted\" \\d+ pattern\n\twith\u{1} control";
+        let returned: Option<String> = surreal
+            .query(format!("RETURN {}", quote_string(text)))
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(returned.as_deref(), Some(text));
+    }
+
+    #[cfg(feature = "v3")]
+    #[tokio::test]
+    async fn test_v3_import_passes_generated_ddl() {
+        let (table, rows) = sampled_table();
+        let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query(
+                SurrealDdl
+                    .define_inferred_table(&table, DdlTarget::V3)
+                    .join("\n"),
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        crate::v3::sink::write_rows(&surreal, &rows, Default::default())
+            .await
+            .unwrap();
+
+        let checks: Vec<bool> = surreal
+            .query(
+                "SELECT VALUE type::is_duration(timeout) AND doc.a.b = id.id() \
+                 AND array::len(scores) = 2 FROM [jobs:0, jobs:1]",
+            )
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(checks, vec![true, true]);
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_v2_import_passes_generated_ddl() {
+        let (table, rows) = sampled_table();
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query(
+                SurrealDdl
+                    .define_inferred_table(&table, DdlTarget::V2)
+                    .join("\n"),
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        crate::v2::sink::write_rows(&surreal, &rows, Default::default())
+            .await
+            .unwrap();
+
+        let checks: Vec<bool> = surreal
+            .query(
+                "SELECT VALUE type::is::duration(timeout) AND doc.a.b = meta::id(id) \
+                 AND array::len(scores) = 2 FROM [jobs:0, jobs:1]",
+            )
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(checks, vec![true, true]);
+    }
+
+    #[cfg(feature = "v3")]
+    #[tokio::test]
+    async fn test_v3_generated_assertions_reject_invalid_values() {
+        let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query(
+                SurrealDdl
+                    .define_table(&constrained_table(), DdlTarget::V3)
+                    .join("\n"),
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        for (index, (balance, code, accepted)) in ACCOUNT_CASES.into_iter().enumerate() {
+            let row = account_row(index as u64, balance, code);
+            let result = crate::v3::sink::write_rows(&surreal, &[row], Default::default()).await;
+            assert_eq!(result.is_ok(), accepted, "{balance} {code}: {result:?}");
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_v2_generated_assertions_reject_invalid_values() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query(
+                SurrealDdl
+                    .define_table(&constrained_table(), DdlTarget::V2)
+                    .join("\n"),
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        for (index, (balance, code, accepted)) in ACCOUNT_CASES.into_iter().enumerate() {
+            let row = account_row(index as u64, balance, code);
+            let result = crate::v2::sink::write_rows(&surreal, &[row], Default::default()).await;
+            assert_eq!(result.is_ok(), accepted, "{balance} {code}: {result:?}");
+        }
+    }
+}
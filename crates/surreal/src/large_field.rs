@@ -0,0 +1,318 @@
+//! Offloading large field values to files ([`LargeFieldConfig`]).
+//!
+//! Writers call these before converting records to SurrealDB values, so an
+//! offloaded value is never copied into an SDK value or a request payload:
+//! its bytes go from the source row straight to the file, and only the small
+//! reference object is sent to SurrealDB. Records without large values are
+//! passed through borrowed.
+
+use std::borrow::Cow;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use surreal_sync_core::{Change, FieldMap, LargeFieldConfig, Relation, RelationChange, Row, Value};
+
+const FNV_OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Offload the large values of a row batch.
+///
+/// With `write_files` false (dry run) the references are built but no file
+/// is written.
+pub(crate) fn offload_rows<'a>(
+    rows: &'a [Row],
+    config: Option<&LargeFieldConfig>,
+    write_files: bool,
+) -> Result<Cow<'a, [Row]>> {
+    let Some(config) = config else {
+        return Ok(Cow::Borrowed(rows));
+    };
+    if !rows.iter().any(|row| has_large_value(&row.fields, config)) {
+        return Ok(Cow::Borrowed(rows));
+    }
+    rows.iter()
+        .map(|row| {
+            Ok(Row {
+                table: row.table.clone(),
+                index: row.index,
+                id: row.id.clone(),
+                fields: offload_fields(&row.table, &row.id, &row.fields, config, write_files)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Cow::Owned)
+}
+
+/// Offload the large values of a relation batch.
+pub(crate) fn offload_relations<'a>(
+    relations: &'a [Relation],
+    config: Option<&LargeFieldConfig>,
+    write_files: bool,
+) -> Result<Cow<'a, [Relation]>> {
+    let Some(config) = config else {
+        return Ok(Cow::Borrowed(relations));
+    };
+    if !relations
+        .iter()
+        .any(|rel| has_large_value(&rel.data, config))
+    {
+        return Ok(Cow::Borrowed(relations));
+    }
+    relations
+        .iter()
+        .map(|rel| offload_relation(rel, config, write_files))
+        .collect::<Result<Vec<_>>>()
+        .map(Cow::Owned)
+}
+
+/// Offload the large values of a change (deletes carry none).
+pub(crate) fn offload_change<'a>(
+    change: &'a Change,
+    config: Option<&LargeFieldConfig>,
+    write_files: bool,
+) -> Result<Cow<'a, Change>> {
+    let (Some(config), Some(fields)) = (config, &change.fields) else {
+        return Ok(Cow::Borrowed(change));
+    };
+    if !has_large_value(fields, config) {
+        return Ok(Cow::Borrowed(change));
+    }
+    Ok(Cow::Owned(Change {
+        operation: change.operation,
+        table: change.table.clone(),
+        id: change.id.clone(),
+        fields: Some(offload_fields(
+            &change.table,
+            &change.id,
+            fields,
+            config,
+            write_files,
+        )?),
+        changed_fields: change.changed_fields.clone(),
+        partial: change.partial,
+        before: change.before.clone(),
+    }))
+}
+
+/// Offload the large values of a relation change.
+pub(crate) fn offload_relation_change<'a>(
+    change: &'a RelationChange,
+    config: Option<&LargeFieldConfig>,
+    write_files: bool,
+) -> Result<Cow<'a, RelationChange>> {
+    let Some(config) = config else {
+        return Ok(Cow::Borrowed(change));
+    };
+    if !has_large_value(&change.relation.data, config) {
+        return Ok(Cow::Borrowed(change));
+    }
+    Ok(Cow::Owned(RelationChange {
+        operation: change.operation,
+        relation: offload_relation(&change.relation, config, write_files)?,
+    }))
+}
+
+fn offload_relation(
+    rel: &Relation,
+    config: &LargeFieldConfig,
+    write_files: bool,
+) -> Result<Relation> {
+    Ok(Relation {
+        relation_type: rel.relation_type.clone(),
+        id: rel.id.clone(),
+        input: rel.input.clone(),
+        output: rel.output.clone(),
+        data: offload_fields(&rel.relation_type, &rel.id, &rel.data, config, write_files)?,
+    })
+}
+
+fn has_large_value(fields: &FieldMap, config: &LargeFieldConfig) -> bool {
+    fields
+        .values()
+        .any(|value| large_content(value, config).is_some())
+}
+
+/// The content of a string or byte value longer than the threshold, with
+/// its reference `kind`.
+fn large_content<'a>(
+    value: &'a Value,
+    config: &LargeFieldConfig,
+) -> Option<(&'a [u8], &'static str)> {
+    let (content, kind) = match value {
+        Value::Char { value, .. }
+        | Value::VarChar { value, .. }
+        | Value::Enum { value, .. }
+        | Value::Text(value) => (value.as_bytes(), "text"),
+        Value::Blob(bytes) | Value::Bytes(bytes) => (bytes.as_slice(), "bytes"),
+        _ => return None,
+    };
+    (content.len() > config.threshold_bytes).then_some((content, kind))
+}
+
+/// Copy `fields`, writing large values to their files and replacing them
+/// with reference objects.
+fn offload_fields(
+    table: &str,
+    id: &Value,
+    fields: &FieldMap,
+    config: &LargeFieldConfig,
+    write_files: bool,
+) -> Result<FieldMap> {
+    fields
+        .iter()
+        .map(|(name, value)| {
+            let value = match large_content(value, config) {
+                Some((content, kind)) => {
+                    let path = offload_path(config, table, id, name)?;
+                    if write_files {
+                        write_file(&path, content)?;
+                    }
+                    reference(&path, content.len(), kind)
+                }
+                None => value.clone(),
+            };
+            Ok((name.clone(), value))
+        })
+        .collect()
+}
+
+/// `<dir>/<table>/<hash of record id and field>.bin`.
+fn offload_path(
+    config: &LargeFieldConfig,
+    table: &str,
+    id: &Value,
+    field: &str,
+) -> Result<PathBuf> {
+    let key = format!("{}\n{field}", serde_json::to_string(id)?);
+    let hash = key.bytes().fold(FNV_OFFSET, |hash, byte| {
+        (hash ^ u128::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+    Ok(config.dir.join(table).join(format!("{hash:032x}.bin")))
+}
+
+/// Write `content` next to `path` and rename it into place, so a reader
+/// never sees a partially written value.
+fn write_file(path: &Path, content: &[u8]) -> Result<()> {
+    let dir = path.parent().expect("offload path has a table directory");
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create large-field directory {}", dir.display()))?;
+    let tmp = path.with_extension("bin.tmp");
+    let mut file = fs::File::create(&tmp)
+        .with_context(|| format!("failed to create large-field file {}", tmp.display()))?;
+    file.write_all(content)
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("failed to write large-field file {}", tmp.display()))?;
+    fs::rename(&tmp, path)
+        .with_context(|| format!("failed to move large-field file to {}", path.display()))
+}
+
+fn reference(path: &Path, size: usize, kind: &str) -> Value {
+    let mut fields = FieldMap::new();
+    fields.insert(
+        "path".to_string(),
+        Value::Text(path.to_string_lossy().into_owned()),
+    );
+    fields.insert("size".to_string(), Value::Int64(size as i64));
+    fields.insert("kind".to_string(), Value::Text(kind.to_string()));
+    Value::Object(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "surreal-sync-large-field-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn large_values_are_written_to_files_and_replaced_by_references() {
+        let dir = temp_dir("rows");
+        let config = LargeFieldConfig::new(8, &dir);
+        let small = Row::new(
+            "docs",
+            0,
+            Value::Int64(1),
+            FieldMap::from([("body".to_string(), Value::Text("short".into()))]),
+        );
+        let large = Row::new(
+            "docs",
+            1,
+            Value::Int64(2),
+            FieldMap::from([
+                ("body".to_string(), Value::Text("a long document".into())),
+                ("blob".to_string(), Value::Bytes(vec![7; 9])),
+                ("n".to_string(), Value::Int64(3)),
+            ]),
+        );
+
+        let only_small = [small.clone()];
+        let out = offload_rows(&only_small, Some(&config), true).unwrap();
+        assert!(matches!(out, Cow::Borrowed(_)));
+
+        let rows = [small.clone(), large];
+        let out = offload_rows(&rows, Some(&config), true).unwrap();
+        assert_eq!(out[0], small);
+        let fields = &out[1].fields;
+        assert_eq!(fields["n"], Value::Int64(3));
+        let Value::Object(body) = &fields["body"] else {
+            panic!("body was not offloaded: {:?}", fields["body"]);
+        };
+        assert_eq!(body["size"], Value::Int64(15));
+        assert_eq!(body["kind"], Value::Text("text".into()));
+        let Value::Text(path) = &body["path"] else {
+            panic!("reference has no path");
+        };
+        assert!(path.starts_with(&*dir.join("docs").to_string_lossy()));
+        assert_eq!(fs::read(path).unwrap(), b"a long document");
+        let Value::Object(blob) = &fields["blob"] else {
+            panic!("blob was not offloaded");
+        };
+        assert_eq!(blob["kind"], Value::Text("bytes".into()));
+        assert_ne!(blob["path"], body["path"]);
+
+        // Re-syncing the record overwrites the same file.
+        let mut updated = rows[1].clone();
+        updated
+            .fields
+            .insert("body".to_string(), Value::Text("another long one".into()));
+        let again = offload_rows(std::slice::from_ref(&updated), Some(&config), true).unwrap();
+        let Value::Object(body_again) = &again[0].fields["body"] else {
+            panic!("body was not offloaded");
+        };
+        assert_eq!(body_again["path"], body["path"]);
+        assert_eq!(fs::read(path).unwrap(), b"another long one");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn dry_run_builds_references_without_writing_files() {
+        let dir = temp_dir("dry-run");
+        let config = LargeFieldConfig::new(4, &dir);
+        let change = Change::update(
+            "docs",
+            Value::Int64(1),
+            FieldMap::from([("body".to_string(), Value::Text("long text".into()))]),
+        );
+        let out = offload_change(&change, Some(&config), false).unwrap();
+        assert!(matches!(
+            &out.fields.as_ref().unwrap()["body"],
+            Value::Object(_)
+        ));
+        assert!(!dir.exists());
+
+        let delete = Change::delete("docs", Value::Int64(1));
+        assert!(matches!(
+            offload_change(&delete, Some(&config), true).unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -16,9 +16,33 @@
 //! use surreal_sync_surreal::Surreal3Sink; // feature = "v3" (default)
 //! ```
 
+#[cfg(any(feature = "v2", feature = "v3"))]
+pub mod circuit;
+
 #[cfg(feature = "reqwest")]
 pub mod client;
 
+#[cfg(any(feature = "v2", feature = "v3"))]
+mod conflict;
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+pub mod ddl;
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+mod large_field;
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+mod merge;
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+mod relate;
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+pub mod schema_check;
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+mod validation;
+
 #[cfg(feature = "reqwest")]
 pub mod version;
 
@@ -28,6 +52,15 @@ pub mod v2;
 #[cfg(feature = "v3")]
 pub mod v3;
 
+#[cfg(any(feature = "v2", feature = "v3"))]
+pub use circuit::{is_transient, CircuitBreaker};
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+pub use ddl::{DdlTarget, SurrealDdl};
+
+#[cfg(any(feature = "v2", feature = "v3"))]
+pub use schema_check::{compare_schema, SchemaDifference};
+
 #[cfg(feature = "v2")]
 pub use v2::{Surreal2Sink, Surreal2Store};
 
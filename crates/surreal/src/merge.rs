@@ -0,0 +1,127 @@
+//! SurrealQL `MERGE` clause for deep-merge writes ([`MergeConfig`]).
+//!
+//! `UPSERT ... MERGE { ... }` already merges nested objects key by key, so
+//! objects are rendered as nested object literals. Array fields render as an
+//! expression over the stored value at the same path, chosen by the field's
+//! [`ArrayMergeStrategy`]. All other values (and replaced arrays) are bound as
+//! `$merge_N` parameters, which the v2/v3 writers convert with their own
+//! value types.
+
+use surreal_sync_core::{ArrayMergeStrategy, FieldMap, MergeConfig, Value};
+
+use crate::ddl::escape_ident;
+
+/// Rendered `MERGE` object plus the values its parameters bind to.
+#[derive(Debug)]
+pub(crate) struct MergeClause<'a> {
+    /// Object expression to put after `MERGE`.
+    pub expr: String,
+    /// `(parameter name, value)` pairs, without the `$`.
+    pub binds: Vec<(String, &'a Value)>,
+}
+
+/// Build the `MERGE` object for a change's `fields`.
+pub(crate) fn merge_clause<'a>(fields: &'a FieldMap, config: &MergeConfig) -> MergeClause<'a> {
+    let mut binds = Vec::new();
+    let expr = object_expr(fields, &mut Vec::new(), config, &mut binds);
+    MergeClause { expr, binds }
+}
+
+fn object_expr<'a>(
+    fields: &'a FieldMap,
+    path: &mut Vec<&'a str>,
+    config: &MergeConfig,
+    binds: &mut Vec<(String, &'a Value)>,
+) -> String {
+    let entries: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| {
+            path.push(key);
+            let expr = value_expr(value, path, config, binds);
+            path.pop();
+            // JSON string escaping is valid for SurrealQL object keys.
+            let key = serde_json::to_string(key).expect("string keys serialize");
+            format!("{key}: {expr}")
+        })
+        .collect();
+    format!("{{ {} }}", entries.join(", "))
+}
+
+fn value_expr<'a>(
+    value: &'a Value,
+    path: &mut Vec<&'a str>,
+    config: &MergeConfig,
+    binds: &mut Vec<(String, &'a Value)>,
+) -> String {
+    if let Value::Object(fields) = value {
+        return object_expr(fields, path, config, binds);
+    }
+    let param = format!("merge_{}", binds.len());
+    binds.push((param.clone(), value));
+    if !matches!(value, Value::Array { .. }) {
+        return format!("${param}");
+    }
+    let stored = path
+        .iter()
+        .map(|segment| escape_ident(segment))
+        .collect::<Vec<_>>()
+        .join(".");
+    match config.array_strategy(&path.join(".")) {
+        ArrayMergeStrategy::Replace => format!("${param}"),
+        ArrayMergeStrategy::Append => format!("array::concat({stored} ?? [], ${param})"),
+        ArrayMergeStrategy::UnionByKey(key) => {
+            let key = escape_ident(key);
+            format!(
+                "array::concat(({stored} ?? [])[WHERE {key} NOTINSIDE ${param}.{key}], ${param})"
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_core::Type;
+
+    #[test]
+    fn nested_objects_and_array_strategies_render() {
+        let tags = Value::Array {
+            elements: vec![Value::Text("new".into())],
+            element_type: Box::new(Type::Text),
+        };
+        let items = Value::Array {
+            elements: vec![],
+            element_type: Box::new(Type::Json),
+        };
+        let fields = FieldMap::from([
+            ("name".to_string(), Value::Text("a".into())),
+            (
+                "profile".to_string(),
+                Value::Object(FieldMap::from([
+                    ("tags".to_string(), tags.clone()),
+                    ("line items".to_string(), items.clone()),
+                ])),
+            ),
+            ("plain".to_string(), tags.clone()),
+        ]);
+        let config = MergeConfig::new(ArrayMergeStrategy::Append)
+            .with_array_field(
+                "profile.line items",
+                ArrayMergeStrategy::UnionByKey("sku".into()),
+            )
+            .with_array_field("plain", ArrayMergeStrategy::Replace);
+
+        let clause = merge_clause(&fields, &config);
+        assert_eq!(
+            clause.expr,
+            "{ \"name\": $merge_0, \"profile\": { \
+             \"tags\": array::concat(profile.tags ?? [], $merge_1), \
+             \"line items\": array::concat((profile.`line items` ?? [])\
+             [WHERE sku NOTINSIDE $merge_2.sku], $merge_2) }, \
+             \"plain\": $merge_3 }"
+        );
+        let names: Vec<&str> = clause.binds.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["merge_0", "merge_1", "merge_2", "merge_3"]);
+        assert_eq!(clause.binds[2].1, &items);
+    }
+}
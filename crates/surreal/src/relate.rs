@@ -0,0 +1,70 @@
+//! SurrealQL for idempotent relation writes.
+//!
+//! `RELATE` with an explicit relation id fails on SurrealDB v3 once that id
+//! exists, so replaying a partly applied batch (after a retry, a circuit
+//! breaker resume or a restart from the last checkpoint) would abort on the
+//! first relation already written. The statement built here branches on the
+//! relation id instead: a new relation is created with `INSERT RELATION`,
+//! which, unlike `UPSERT`, also creates the graph edge between `in` and `out`,
+//! and an existing one has its content replaced with `UPDATE ... CONTENT`, as
+//! `RELATE ... CONTENT` used to.
+
+use crate::ddl::escape_ident;
+
+/// Statement writing relation `id` from `input` to `output` into `table`,
+/// with `fields` taken from `$content`. `id`, `input` and `output` are
+/// SurrealQL expressions (bound parameters or record-id literals).
+///
+/// An existing relation is replaced: fields it has that are not in `fields`
+/// are removed. Its `in` and `out` are kept, since SurrealDB does not move
+/// the edge of an existing relation.
+pub(crate) fn write_relation_statement(
+    table: &str,
+    id: &str,
+    input: &str,
+    output: &str,
+    fields: &[&str],
+) -> String {
+    let content = fields
+        .iter()
+        .filter(|field| !matches!(**field, "id" | "in" | "out"))
+        .map(|field| {
+            let field = escape_ident(field);
+            format!(", {field}: $content.{field}")
+        })
+        .collect::<String>();
+    format!(
+        "IF record::exists({id}) \
+         THEN (UPDATE {id} CONTENT {{ in: {input}, out: {output}{content} }}) \
+         ELSE (INSERT RELATION INTO {} {{ id: {id}, in: {input}, out: {output}{content} }}) \
+         END",
+        escape_ident(table)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_relation_statement() {
+        assert_eq!(
+            write_relation_statement("likes", "$id", "$in", "$out", &["weight", "my field"]),
+            "IF record::exists($id) \
+             THEN (UPDATE $id CONTENT { in: $in, out: $out, \
+             weight: $content.weight, `my field`: $content.`my field` }) \
+             ELSE (INSERT RELATION INTO likes { id: $id, in: $in, out: $out, \
+             weight: $content.weight, `my field`: $content.`my field` }) END"
+        );
+    }
+
+    #[test]
+    fn test_write_relation_statement_without_fields() {
+        assert_eq!(
+            write_relation_statement("knows", "knows:1", "person:1", "person:2", &["id"]),
+            "IF record::exists(knows:1) \
+             THEN (UPDATE knows:1 CONTENT { in: person:1, out: person:2 }) \
+             ELSE (INSERT RELATION INTO knows { id: knows:1, in: person:1, out: person:2 }) END"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Structural comparison of a sync schema against SurrealDB table definitions.
+//!
+//! The expected field types are the ones [`SurrealDdl::define_table`] declares
+//! for the schema, so a field defined by `--define-schema` and left untouched
+//! always matches. [`compare_schema`] takes the field definitions read back
+//! with `INFO FOR TABLE` and reports missing tables, missing and extra fields,
+//! and fields whose declared type differs. It checks definitions only; the
+//! stored values are covered by the data-level verifier.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use surreal_sync_core::Schema;
+
+use crate::ddl::{escape_ident, DdlTarget, SurrealDdl};
+
+/// Clauses that may follow `TYPE ...` in a `DEFINE FIELD` statement as
+/// SurrealDB renders it.
+const CLAUSES_AFTER_TYPE: [&str; 7] = [
+    " DEFAULT ",
+    " READONLY",
+    " VALUE ",
+    " ASSERT ",
+    " REFERENCE",
+    " COMMENT ",
+    " PERMISSIONS ",
+];
+
+/// Field definitions of one table: field name (as `INFO FOR TABLE` keys it)
+/// to its `DEFINE FIELD` statement.
+pub type TableFields = HashMap<String, String>;
+
+/// One way the SurrealDB schema differs from the sync schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDifference {
+    /// The table is not defined in SurrealDB.
+    MissingTable { table: String },
+    /// The field is in the sync schema but not defined on the table.
+    MissingField {
+        table: String,
+        field: String,
+        expected: String,
+    },
+    /// The field is defined on the table but not in the sync schema.
+    ExtraField {
+        table: String,
+        field: String,
+        actual: Option<String>,
+    },
+    /// The field is defined with a different type, or without one (`None`).
+    TypeMismatch {
+        table: String,
+        field: String,
+        expected: String,
+        actual: Option<String>,
+    },
+}
+
+impl fmt::Display for SchemaDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTable { table } => write!(f, "table '{table}' is not defined"),
+            Self::MissingField {
+                table,
+                field,
+                expected,
+            } => write!(f, "{table}.{field}: missing (expected {expected})"),
+            Self::ExtraField {
+                table,
+                field,
+                actual,
+            } => write!(
+                f,
+                "{table}.{field}: not in the source schema (defined as {})",
+                actual.as_deref().unwrap_or("untyped")
+            ),
+            Self::TypeMismatch {
+                table,
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{table}.{field}: expected {expected}, found {}",
+                actual.as_deref().unwrap_or("no type")
+            ),
+        }
+    }
+}
+
+/// Compare `schema` with the field definitions of each table in `defined`
+/// (tables absent from the map are reported missing).
+///
+/// Nested field definitions (`tags[*]`, `address.city`) are not compared,
+/// and neither is the record `id`. Tables defined in SurrealDB but not in
+/// `schema` are ignored.
+pub fn compare_schema(
+    schema: &Schema,
+    defined: &HashMap<String, TableFields>,
+    target: DdlTarget,
+) -> Vec<SchemaDifference> {
+    let ddl = SurrealDdl;
+    let mut differences = Vec::new();
+    for table in &schema.tables {
+        let Some(fields) = defined.get(&table.name) else {
+            differences.push(SchemaDifference::MissingTable {
+                table: table.name.clone(),
+            });
+            continue;
+        };
+        for field in table.fields.iter().filter(|f| f.name != "id") {
+            let expected = ddl.field_type(field, target);
+            match fields.get(&escape_ident(&field.name)) {
+                None => differences.push(SchemaDifference::MissingField {
+                    table: table.name.clone(),
+                    field: field.name.clone(),
+                    expected,
+                }),
+                Some(definition) => {
+                    let actual = defined_field_type(definition);
+                    if actual.as_deref().map(normalize_type) != Some(normalize_type(&expected)) {
+                        differences.push(SchemaDifference::TypeMismatch {
+                            table: table.name.clone(),
+                            field: field.name.clone(),
+                            expected,
+                            actual,
+                        });
+                    }
+                }
+            }
+        }
+        let mut extra: Vec<(String, Option<String>)> = fields
+            .iter()
+            .map(|(name, definition)| (unescape_ident(name), definition))
+            .filter(|(name, _)| {
+                name != "id" && !name.contains(['.', '[']) && table.get_field(name).is_none()
+            })
+            .map(|(name, definition)| (name, defined_field_type(definition)))
+            .collect();
+        extra.sort();
+        differences.extend(
+            extra
+                .into_iter()
+                .map(|(field, actual)| SchemaDifference::ExtraField {
+                    table: table.name.clone(),
+                    field,
+                    actual,
+                }),
+        );
+    }
+    differences
+}
+
+/// The type of a `DEFINE FIELD` statement as SurrealDB renders it, or `None`
+/// when the field declares no type.
+pub fn defined_field_type(definition: &str) -> Option<String> {
+    let (_, rest) = definition.split_once(" TYPE ")?;
+    let end = CLAUSES_AFTER_TYPE
+        .iter()
+        .filter_map(|clause| rest.find(clause))
+        .min()
+        .unwrap_or(rest.len());
+    Some(rest[..end].trim().to_string())
+}
+
+/// Type text with whitespace removed, so `string|duration` and
+/// `string | duration` compare equal.
+fn normalize_type(ty: &str) -> String {
+    ty.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// A field name as keyed by `INFO FOR TABLE`, with backtick escaping removed.
+fn unescape_ident(name: &str) -> String {
+    match name.strip_prefix('`').and_then(|n| n.strip_suffix('`')) {
+        Some(inner) => inner.replace("\\`", "`").replace("\\\\", "\\"),
+        None => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defined_field_type_stops_at_next_clause() {
+        assert_eq!(
+            defined_field_type(
+                "DEFINE FIELD tags ON users TYPE array<option<string>> ASSERT $value != NONE PERMISSIONS FULL"
+            )
+            .as_deref(),
+            Some("array<option<string>>")
+        );
+        assert_eq!(
+            defined_field_type("DEFINE FIELD meta ON users FLEXIBLE TYPE any PERMISSIONS FULL")
+                .as_deref(),
+            Some("any")
+        );
+        assert_eq!(
+            defined_field_type("DEFINE FIELD n ON users PERMISSIONS FULL"),
+            None
+        );
+    }
+}
@@ -56,4 +56,30 @@ impl CheckpointStore for Surreal2Store {
         let checkpoints: Vec<StoredCheckpoint> = response.take(0)?;
         Ok(checkpoints.into_iter().next())
     }
+
+    async fn list_checkpoints(&self) -> Result<Vec<CheckpointID>> {
+        let mut response = self
+            .client
+            .query("SELECT * FROM type::table($table) ORDER BY database_type, phase")
+            .bind(("table", self.table_name.clone()))
+            .await?;
+        let checkpoints: Vec<StoredCheckpoint> = response.take(0)?;
+        Ok(checkpoints
+            .into_iter()
+            .map(|stored| CheckpointID {
+                database_type: stored.database_type,
+                phase: stored.phase,
+            })
+            .collect())
+    }
+
+    async fn delete_checkpoint(&self, id: &CheckpointID) -> Result<()> {
+        let thing = self.to_thing(id);
+        self.client
+            .query("DELETE $record_id")
+            .bind(("record_id", thing))
+            .await?
+            .check()?;
+        Ok(())
+    }
 }
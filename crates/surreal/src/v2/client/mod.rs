@@ -4,8 +4,14 @@
 
 #[cfg(feature = "reqwest")]
 use crate::client::SurrealTestClient;
+use crate::ddl::{escape_ident, DdlTarget};
+use crate::schema_check::{compare_schema, SchemaDifference, TableFields};
+use crate::v2::types::reverse::object_to_row;
 use anyhow::Result;
+use std::collections::HashMap;
+use surreal_sync_core::{GeneratorTableDefinition, Row, Schema};
 use surrealdb2::engine::any::Any;
+use surrealdb2::sql::Value as SqlValue;
 use surrealdb2::Surreal;
 
 /// SurrealDB v2 test client wrapper.
@@ -54,6 +60,82 @@ impl Surreal2Client {
     pub fn inner_mut(&mut self) -> &mut Surreal<Any> {
         &mut self.client
     }
+
+    /// Names of the tables defined in the database (`INFO FOR DB`).
+    pub async fn table_names(&self) -> Result<Vec<String>> {
+        let mut result = self.client.query("INFO FOR DB;").await?;
+        let info: Option<serde_json::Value> = result.take(0)?;
+        Ok(info
+            .as_ref()
+            .and_then(|info| info.get("tables"))
+            .and_then(|tables| tables.as_object())
+            .map(|tables| tables.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    /// Field definitions of `table` (`INFO FOR TABLE`), keyed by field name.
+    pub async fn table_fields(&self, table: &str) -> Result<TableFields> {
+        let mut result = self
+            .client
+            .query(format!("INFO FOR TABLE {};", escape_ident(table)))
+            .await?;
+        let info: Option<serde_json::Value> = result.take(0)?;
+        Ok(info
+            .as_ref()
+            .and_then(|info| info.get("fields"))
+            .and_then(|fields| fields.as_object())
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(|(name, def)| Some((name.clone(), def.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Records `start..start + limit` of `table` in record ID order, as rows
+    /// (see [`object_to_row`]).
+    pub async fn read_rows(
+        &self,
+        table: &str,
+        start: usize,
+        limit: usize,
+        table_schema: Option<&GeneratorTableDefinition>,
+    ) -> Result<Vec<Row>> {
+        let mut result = self
+            .client
+            .query("SELECT * FROM type::table($table) ORDER BY id LIMIT $limit START $start")
+            .bind(("table", table.to_string()))
+            .bind(("limit", limit as i64))
+            .bind(("start", start as i64))
+            .await?
+            .check()?;
+        let records: surrealdb2::Value = result.take(0)?;
+        let SqlValue::Array(records) = records.into_inner() else {
+            anyhow::bail!("SELECT on table '{table}' did not return an array");
+        };
+        records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| match record {
+                SqlValue::Object(obj) => {
+                    object_to_row(table, (start + i) as u64, obj, table_schema)
+                }
+                other => anyhow::bail!("Unexpected record in table '{table}': {other}"),
+            })
+            .collect()
+    }
+
+    /// Compare the tables and field types defined in the database with the
+    /// ones `schema` implies. See [`compare_schema`].
+    pub async fn compare_schema(&self, schema: &Schema) -> Result<Vec<SchemaDifference>> {
+        let tables = self.table_names().await?;
+        let mut defined = HashMap::new();
+        for table in schema.tables.iter().filter(|t| tables.contains(&t.name)) {
+            defined.insert(table.name.clone(), self.table_fields(&table.name).await?);
+        }
+        Ok(compare_schema(schema, &defined, DdlTarget::V2))
+    }
 }
 
 #[cfg(feature = "reqwest")]
@@ -118,4 +200,156 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<Surreal2Client>();
     }
+
+    #[tokio::test]
+    async fn test_compare_schema_reports_mistyped_field() {
+        use crate::ddl::SurrealDdl;
+        use surreal_sync_core::{
+            FieldConstraints, GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition,
+            GeneratorTableDefinition, Type,
+        };
+
+        let field = |name: &str, field_type: Type, nullable: bool| GeneratorFieldDefinition {
+            name: name.to_string(),
+            field_type,
+            generator: GeneratorConfig::Null,
+            nullable,
+            nullable_elements: false,
+            indexed: false,
+            constraints: FieldConstraints::default(),
+        };
+        let table = |name: &str, fields| GeneratorTableDefinition {
+            name: name.to_string(),
+            id: GeneratorIDDefinition {
+                id_type: Type::Int64,
+                generator: GeneratorConfig::Null,
+            },
+            fields,
+        };
+        let schema = Schema::new(vec![
+            table(
+                "users",
+                vec![
+                    field("name", Type::Text, false),
+                    field("age", Type::Int32, true),
+                    field("signed_up", Type::ZonedDateTime, false),
+                    field("tags", Type::Set { values: vec![] }, false),
+                ],
+            ),
+            table("orders", vec![field("total", Type::Float64, false)]),
+        ]);
+
+        let client = Surreal2Client::new(surrealdb2::engine::any::connect("mem://").await.unwrap());
+        client.inner().use_ns("test").use_db("test").await.unwrap();
+        let users = SurrealDdl.define_table(&schema.tables[0], DdlTarget::V2);
+        client
+            .inner()
+            .query(users.join("\n"))
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        // Only `orders` differs: it was never defined.
+        assert_eq!(
+            client.compare_schema(&schema).await.unwrap(),
+            vec![SchemaDifference::MissingTable {
+                table: "orders".to_string(),
+            }]
+        );
+
+        // `age` lands as a string, `signed_up` is dropped and `note` added.
+        client
+            .inner()
+            .query(
+                "DEFINE FIELD OVERWRITE age ON TABLE users TYPE option<string>; \
+                 REMOVE FIELD signed_up ON TABLE users; \
+                 DEFINE FIELD note ON TABLE users TYPE string;",
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let differences = client.compare_schema(&schema).await.unwrap();
+        assert_eq!(
+            differences,
+            vec![
+                SchemaDifference::TypeMismatch {
+                    table: "users".to_string(),
+                    field: "age".to_string(),
+                    expected: "option<int>".to_string(),
+                    actual: Some("option<string>".to_string()),
+                },
+                SchemaDifference::MissingField {
+                    table: "users".to_string(),
+                    field: "signed_up".to_string(),
+                    expected: "datetime".to_string(),
+                },
+                SchemaDifference::ExtraField {
+                    table: "users".to_string(),
+                    field: "note".to_string(),
+                    actual: Some("string".to_string()),
+                },
+                SchemaDifference::MissingTable {
+                    table: "orders".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_rows_pages_in_record_id_order() {
+        use surreal_sync_core::{
+            FieldConstraints, GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition,
+            Type, Value,
+        };
+
+        let client = Surreal2Client::new(surrealdb2::engine::any::connect("mem://").await.unwrap());
+        client.inner().use_ns("test").use_db("test").await.unwrap();
+        client
+            .inner()
+            .query(
+                "CREATE users:2 CONTENT { name: 'b', age: 41 }; \
+                 CREATE users:1 CONTENT { name: 'a', age: 30 }; \
+                 CREATE users:3 CONTENT { name: 'c', age: 52 };",
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+
+        let page = client.read_rows("users", 0, 2, None).await.unwrap();
+        let ids: Vec<_> = page.iter().map(|row| (row.index, row.id.clone())).collect();
+        assert_eq!(ids, vec![(0, Value::Int64(1)), (1, Value::Int64(2))]);
+        assert_eq!(
+            page[0].get_field("name"),
+            Some(&Value::Text("a".to_string()))
+        );
+        assert_eq!(page[0].get_field("age"), Some(&Value::Int64(30)));
+        assert!(page[0].get_field("id").is_none());
+
+        let users = GeneratorTableDefinition {
+            name: "users".to_string(),
+            id: GeneratorIDDefinition {
+                id_type: Type::Int64,
+                generator: GeneratorConfig::Null,
+            },
+            fields: vec![GeneratorFieldDefinition {
+                name: "age".to_string(),
+                field_type: Type::Int32,
+                generator: GeneratorConfig::Null,
+                nullable: false,
+                nullable_elements: false,
+                indexed: false,
+                constraints: FieldConstraints::default(),
+            }],
+        };
+        let rest = client.read_rows("users", 2, 2, Some(&users)).await.unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!((rest[0].index, &rest[0].id), (2, &Value::Int64(3)));
+        assert_eq!(rest[0].get_field("age"), Some(&Value::Int32(52)));
+        assert_eq!(
+            rest[0].get_field("name"),
+            Some(&Value::Text("c".to_string()))
+        );
+    }
 }
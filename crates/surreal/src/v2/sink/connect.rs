@@ -1,11 +1,15 @@
 use std::time::Duration;
 
+use surreal_sync_core::WriteRetryConfig;
+
 /// SurrealDB connection options
 #[derive(Clone, Debug)]
 pub struct SurrealOpts {
     pub surreal_endpoint: String,
     pub surreal_username: String,
     pub surreal_password: String,
+    /// Attempts and backoff for sink writes that fail transiently.
+    pub write_retry: WriteRetryConfig,
 }
 
 /// Default number of connection retry attempts
@@ -4,6 +4,7 @@
 
 mod change;
 mod connect;
+mod pool;
 mod rows;
 mod sink_impl;
 mod write;
@@ -12,13 +13,16 @@ pub use change::{Mutation, MutationOp};
 pub use connect::{surreal_connect, surreal_connect_with_retries, SurrealOpts};
 pub use rows::{
     relation_to_surreal_relation, row_to_surreal_record, value_to_surreal_id, write_relations,
-    write_rows,
+    write_relations_concurrent, write_relations_ordered, write_relations_pooled, write_rows,
+    write_rows_concurrent, write_rows_pooled, write_rows_templated,
 };
 pub use sink_impl::Surreal2Sink;
 pub use surreal_sync_core::ZeroTemporalPolicy;
 pub use write::{
-    apply_change, apply_mutation, write_native_relations, write_record, write_records,
-    write_relation,
+    apply_change, apply_change_with_version, apply_mutation, apply_templated_change, merge_change,
+    run_validation, scan_field, soft_delete_change, truncate_table, write_change_checked,
+    write_dead_letter, write_native_relations, write_record, write_record_if_newer, write_records,
+    write_relation, write_templated,
 };
 
 // Re-export SurrealDB types for use by source crates
@@ -0,0 +1,81 @@
+//! Extra SurrealDB connections for concurrent writes.
+//!
+//! One `Surreal<Any>` client multiplexes every request over a single
+//! WebSocket, so write lanes sharing it are serialized on that socket. The
+//! pool opens separate clients, lazily on the first concurrent write, on the
+//! namespace and database the sink's own client uses.
+
+use anyhow::Context;
+use surrealdb2::engine::any::Any;
+use surrealdb2::Surreal;
+use tokio::sync::OnceCell;
+
+use super::connect::{surreal_connect, SurrealOpts};
+
+/// Up to `size` connections, the sink's own client included.
+#[derive(Debug)]
+pub(crate) struct ConnectionPool {
+    opts: SurrealOpts,
+    size: usize,
+    clients: OnceCell<Vec<Surreal<Any>>>,
+}
+
+impl ConnectionPool {
+    pub(crate) fn new(opts: SurrealOpts, size: usize) -> Self {
+        Self {
+            opts,
+            size: size.max(1),
+            clients: OnceCell::new(),
+        }
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    /// `primary` followed by the pool's own connections, opened on first use.
+    pub(crate) async fn clients(&self, primary: &Surreal<Any>) -> anyhow::Result<&[Surreal<Any>]> {
+        let clients = self
+            .clients
+            .get_or_try_init(|| async {
+                let (ns, db) = session(primary).await?;
+                let mut clients = vec![primary.clone()];
+                for _ in 1..self.size {
+                    clients.push(surreal_connect(&self.opts, &ns, &db).await?);
+                }
+                tracing::info!("Opened SurrealDB connection pool of {}", clients.len());
+                Ok::<_, anyhow::Error>(clients)
+            })
+            .await?;
+        Ok(clients)
+    }
+}
+
+/// Namespace and database selected on `surreal`.
+async fn session(surreal: &Surreal<Any>) -> anyhow::Result<(String, String)> {
+    let session: Vec<Option<String>> = surreal
+        .query("RETURN [session::ns(), session::db()]")
+        .await?
+        .check()?
+        .take(0)?;
+    match session.as_slice() {
+        [Some(ns), Some(db)] => Ok((ns.clone(), db.clone())),
+        _ => None.context("The connection pool needs a namespace and database selected"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_session_reads_selected_namespace_and_database() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        assert!(session(&surreal).await.is_err());
+        surreal.use_ns("app").use_db("prod").await.unwrap();
+        assert_eq!(
+            session(&surreal).await.unwrap(),
+            ("app".to_string(), "prod".to_string())
+        );
+    }
+}
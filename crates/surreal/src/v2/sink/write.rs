@@ -1,13 +1,22 @@
 use super::Mutation;
 use crate::v2::types::{RecordWithSurrealValues as Record, Relation, SurrealValue};
+use anyhow::Context;
 use std::collections::HashMap;
 use std::time::Duration;
-use surreal_sync_core::{Change, ChangeOp, RelationChange, ZeroTemporalPolicy};
+use surreal_sync_core::{
+    Change, ChangeOp, MergeConfig, RelationChange, SoftDeleteConfig, ValidationFailure,
+    ValidationQuery, WriteTemplate, ZeroTemporalPolicy,
+};
 use surrealdb2::sql;
 use surrealdb2::Surreal;
 use tokio::time::sleep;
 
 use super::rows::{relation_to_surreal_relation, value_to_surreal_id};
+use crate::conflict::checked_upsert_statements;
+use crate::ddl::escape_ident;
+use crate::merge::merge_clause;
+use crate::relate::write_relation_statement;
+use crate::validation::validation_statements;
 
 /// Convert a `surrealdb2::sql::Id` to a `surrealdb2::sql::Value` suitable for parameter binding.
 ///
@@ -88,6 +97,19 @@ pub async fn apply_change(
     surreal: &Surreal<surrealdb2::engine::any::Any>,
     change: &Change,
     zero_temporal: ZeroTemporalPolicy,
+) -> anyhow::Result<()> {
+    apply_change_with_version(surreal, change, zero_temporal, None).await
+}
+
+/// Like [`apply_change`], but with `version_field` set a create/update only
+/// lands when its value for that field is newer than the stored record's
+/// (see [`write_record_if_newer`]). Changes without the field, and deletes,
+/// apply unconditionally.
+pub async fn apply_change_with_version(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    change: &Change,
+    zero_temporal: ZeroTemporalPolicy,
+    version_field: Option<&str>,
 ) -> anyhow::Result<()> {
     // Convert ID from Value to SurrealDB ID
     let surreal_id = value_to_surreal_id(&change.id)?;
@@ -95,24 +117,18 @@ pub async fn apply_change(
 
     match change.operation {
         ChangeOp::Create | ChangeOp::Update => {
-            // Convert data from HashMap<String, Value> to HashMap<String, surrealdb2::sql::Value>
-            let data = change.fields.as_ref().ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Create/Update change must have data, but found None for table '{}'",
-                    change.table
-                )
-            })?;
-
-            let surreal_data: HashMap<String, surrealdb2::sql::Value> = data
-                .iter()
-                .map(|(k, v)| {
-                    let sv = SurrealValue::from_universal_with_policy(v.clone(), zero_temporal);
-                    (k.clone(), sv.into_inner())
-                })
-                .collect();
-
-            let record = Record::new(thing.clone(), surreal_data);
-            write_record(surreal, &record).await?;
+            let record = change_to_record(thing.clone(), change, zero_temporal)?;
+            match version_field.filter(|field| record.data.contains_key(*field)) {
+                Some(field) => {
+                    if !write_record_if_newer(surreal, &record, field).await? {
+                        tracing::debug!(
+                            "Skipped stale change for {:?}: stored '{field}' is not older",
+                            record.id
+                        );
+                    }
+                }
+                None => write_record(surreal, &record).await?,
+            }
 
             tracing::trace!("Successfully upserted record: {thing:?}");
         }
@@ -134,16 +150,335 @@ pub async fn apply_change(
     Ok(())
 }
 
+/// Build the record a create/update `change` upserts as `id`.
+pub(crate) fn change_to_record(
+    id: surrealdb2::sql::Thing,
+    change: &Change,
+    zero_temporal: ZeroTemporalPolicy,
+) -> anyhow::Result<Record> {
+    // Convert data from FieldMap to HashMap<String, surrealdb2::sql::Value>
+    let data = change.fields.as_ref().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Create/Update change must have data, but found None for table '{}'",
+            change.table
+        )
+    })?;
+
+    let surreal_data: HashMap<String, surrealdb2::sql::Value> = data
+        .iter()
+        .map(|(k, v)| {
+            let sv = SurrealValue::from_universal_with_policy(v.clone(), zero_temporal);
+            (k.clone(), sv.into_inner())
+        })
+        .collect();
+
+    Ok(Record::new(id, surreal_data))
+}
+
+/// Soft-delete the record a delete `change` targets: set the configured
+/// `deleted_at` field (and flag field, if any) instead of removing it.
+/// `UPDATE` never creates records, so deletes for missing records are no-ops.
+pub async fn soft_delete_change(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    change: &Change,
+    soft_delete: &SoftDeleteConfig,
+) -> anyhow::Result<()> {
+    let surreal_id = value_to_surreal_id(&change.id)?;
+    let mut assignments = vec![format!(
+        "{} = time::now()",
+        escape_ident(&soft_delete.deleted_at_field)
+    )];
+    if let Some(flag) = &soft_delete.deleted_flag_field {
+        assignments.push(format!("{} = true", escape_ident(flag)));
+    }
+    let query = format!(
+        "UPDATE type::thing($record_tb, $record_id) SET {}",
+        assignments.join(", ")
+    );
+    tracing::trace!("Executing SurrealDB query: {}", query);
+
+    let mut q = surreal.query(query);
+    q = q.bind(("record_tb", change.table.clone()));
+    q = q.bind(("record_id", id_to_sql_value(&surreal_id)));
+    q.await?.check()?;
+
+    tracing::trace!(
+        "Successfully soft-deleted record: {}:{surreal_id:?}",
+        change.table
+    );
+    Ok(())
+}
+
+/// Apply an update by setting only its `changed_fields` on the stored record
+/// (fields missing from the change are set to `NONE`), so unchanged fields
+/// and their indexes are not rewritten. An update that changed nothing is
+/// skipped. With `version_field`, the patch only applies when the change's
+/// version is newer than the stored one.
+///
+/// Returns `false` when the record did not exist before the patch (it now
+/// holds only the patched fields), so the caller can write it whole.
+pub async fn patch_change(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    change: &Change,
+    changed_fields: &[String],
+    zero_temporal: ZeroTemporalPolicy,
+    version_field: Option<&str>,
+) -> anyhow::Result<bool> {
+    let surreal_id = value_to_surreal_id(&change.id)?;
+    let fields = change.fields.as_ref().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Create/Update change must have data, but found None for table '{}'",
+            change.table
+        )
+    })?;
+    if changed_fields.is_empty() {
+        return Ok(true);
+    }
+    let mut binds = Vec::new();
+    let version = version_field.and_then(|field| Some((field, fields.get(field)?)));
+    let mut assignments = Vec::new();
+    for name in changed_fields {
+        let field = escape_ident(name);
+        match fields.get(name) {
+            Some(value) => {
+                let param = format!("patch_{}", binds.len());
+                assignments.push(format!("{field} = ${param}"));
+                binds.push((param, value));
+            }
+            None => assignments.push(format!("{field} = NONE")),
+        }
+    }
+    let mut query = format!(
+        "LET $before = (UPSERT type::thing($record_tb, $record_id) SET {}",
+        assignments.join(", ")
+    );
+    if let Some((field, _)) = version {
+        let field = escape_ident(field);
+        query.push_str(&format!(" WHERE {field} IS NONE OR {field} < $version"));
+    }
+    // `RETURN BEFORE` yields `NONE` for a record the patch created, and
+    // nothing when the version check skipped an existing one.
+    query.push_str(" RETURN BEFORE); RETURN array::len($before) = 0 OR $before[0] != NONE;");
+    tracing::trace!("Executing SurrealDB query: {}", query);
+
+    let to_surreal = |value: &surreal_sync_core::Value| {
+        SurrealValue::from_universal_with_policy(value.clone(), zero_temporal).into_inner()
+    };
+    let mut q = surreal.query(query);
+    q = q.bind(("record_tb", change.table.clone()));
+    q = q.bind(("record_id", id_to_sql_value(&surreal_id)));
+    for (name, value) in binds {
+        q = q.bind((name, to_surreal(value)));
+    }
+    if let Some((_, value)) = version {
+        q = q.bind(("version", to_surreal(value)));
+    }
+    let exists: Option<bool> = q.await?.check()?.take(1)?;
+    let exists = exists.unwrap_or(false);
+    if exists {
+        tracing::trace!(
+            "Patched {} changed fields of {}:{surreal_id:?}",
+            changed_fields.len(),
+            change.table
+        );
+    }
+    Ok(exists)
+}
+
+/// Deep-merge a create/update `change` into the stored record (see
+/// [`MergeConfig`]), creating it when absent. With `version_field` set and
+/// present in the change, the merge only lands when the change is newer, as
+/// in [`write_record_if_newer`].
+pub async fn merge_change(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    change: &Change,
+    zero_temporal: ZeroTemporalPolicy,
+    merge: &MergeConfig,
+    version_field: Option<&str>,
+) -> anyhow::Result<()> {
+    let surreal_id = value_to_surreal_id(&change.id)?;
+    let fields = change.fields.as_ref().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Create/Update change must have data, but found None for table '{}'",
+            change.table
+        )
+    })?;
+    let clause = merge_clause(fields, merge);
+    let mut query = format!(
+        "UPSERT type::thing($record_tb, $record_id) MERGE {}",
+        clause.expr
+    );
+    let version = version_field.and_then(|field| Some((field, fields.get(field)?)));
+    if let Some((field, _)) = version {
+        let field = escape_ident(field);
+        query.push_str(&format!(" WHERE {field} IS NONE OR {field} < $version"));
+    }
+    tracing::trace!("Executing SurrealDB query: {}", query);
+
+    let to_surreal = |value: &surreal_sync_core::Value| {
+        SurrealValue::from_universal_with_policy(value.clone(), zero_temporal).into_inner()
+    };
+    let mut q = surreal.query(query);
+    q = q.bind(("record_tb", change.table.clone()));
+    q = q.bind(("record_id", id_to_sql_value(&surreal_id)));
+    for (name, value) in clause.binds {
+        q = q.bind((name, to_surreal(value)));
+    }
+    if let Some((_, value)) = version {
+        q = q.bind(("version", to_surreal(value)));
+    }
+    q.await?.check()?;
+
+    tracing::trace!(
+        "Successfully merged record: {}:{surreal_id:?}",
+        change.table
+    );
+    Ok(())
+}
+
+/// Upsert a create/update `change` with conflict detection: when the stored
+/// record was modified since surreal-sync last wrote it (see
+/// [`ConflictPolicy`](surreal_sync_core::ConflictPolicy)) the change is only
+/// applied with `overwrite`. Written records are stamped with
+/// [`SYNC_META_FIELD`](surreal_sync_core::SYNC_META_FIELD).
+///
+/// Returns whether the stored record was in conflict.
+pub async fn write_change_checked(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    change: &Change,
+    zero_temporal: ZeroTemporalPolicy,
+    overwrite: bool,
+) -> anyhow::Result<bool> {
+    let thing =
+        surrealdb2::sql::Thing::from((change.table.as_str(), value_to_surreal_id(&change.id)?));
+    let record = change_to_record(thing, change, zero_temporal)?;
+    let partial_fields = change
+        .partial
+        .then(|| record.data.keys().map(String::as_str).collect());
+    let query = checked_upsert_statements(partial_fields);
+    tracing::trace!("Executing SurrealDB query: {}", query);
+
+    let conflict: Option<bool> = surreal
+        .query(query)
+        .bind(("record_id", record.id.clone()))
+        .bind(("content", record.get_upsert_content()))
+        .bind(("overwrite", overwrite))
+        .await?
+        .check()?
+        .take(3)?;
+    Ok(conflict.unwrap_or(false))
+}
+
+/// Store a record whose write failed in the dead-letter `error_table`: the
+/// source `table`, the record's `position` in the source (full-sync row
+/// index, when known), its source `data` serialized as JSON and the `error`.
+pub async fn write_dead_letter(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    error_table: &str,
+    table: &str,
+    position: Option<u64>,
+    data: String,
+    error: &anyhow::Error,
+) -> anyhow::Result<()> {
+    let query = "CREATE type::table($error_tb) CONTENT { \
+                 source_table: $source_table, position: $position, data: $data, \
+                 error: $error, failed_at: time::now() }";
+    tracing::trace!("Executing SurrealDB query: {}", query);
+    surreal
+        .query(query)
+        .bind(("error_tb", error_table.to_string()))
+        .bind(("source_table", table.to_string()))
+        .bind(("position", position))
+        .bind(("data", data))
+        .bind(("error", format!("{error:#}")))
+        .await?
+        .check()?;
+    Ok(())
+}
+
 // Write a single record to SurrealDB using UPSERT with retry for transaction conflicts
 pub async fn write_record(
     surreal: &Surreal<surrealdb2::engine::any::Any>,
     document: &Record,
 ) -> anyhow::Result<()> {
+    if !upsert_record(surreal, document, "UPSERT $record_id CONTENT $content").await? {
+        tracing::warn!("Failed to create record: {:?}", document.id);
+    }
+    Ok(())
+}
+
+/// Write `document` with a user [`WriteTemplate`]: `$id` is bound to the
+/// record id, `$content` to the record and every other placeholder to the
+/// record's field of that name (`NONE` when it has none).
+pub async fn write_templated(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    template: &WriteTemplate,
+    document: &Record,
+) -> anyhow::Result<()> {
+    tracing::trace!("Executing SurrealDB query: {}", template.statement);
+    let mut q = surreal.query(template.statement.as_str());
+    q = q.bind(("id", document.id.clone()));
+    q = q.bind(("content", document.get_upsert_content()));
+    for name in template.field_placeholders() {
+        let value = document.data.get(name).cloned().unwrap_or(sql::Value::None);
+        q = q.bind((name.to_string(), value));
+    }
+    q.await?
+        .check()
+        .with_context(|| format!("write template for '{}' failed", template.table))?;
+    Ok(())
+}
+
+/// Apply a create/update `change` through `template` (see
+/// [`write_templated`]).
+pub async fn apply_templated_change(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    change: &Change,
+    template: &WriteTemplate,
+    zero_temporal: ZeroTemporalPolicy,
+) -> anyhow::Result<()> {
+    let id =
+        surrealdb2::sql::Thing::from((change.table.as_str(), value_to_surreal_id(&change.id)?));
+    let record = change_to_record(id, change, zero_temporal)?;
+    write_templated(surreal, template, &record).await
+}
+
+/// Serialized size of the `CONTENT` [`write_record`] binds for `document`
+/// (used for dry-run write estimates).
+pub(crate) fn upsert_payload_len(document: &Record) -> usize {
+    document.get_upsert_content().to_string().len()
+}
+
+/// Serialized size of the `CONTENT` [`write_relation`] binds for `r`.
+pub(crate) fn relate_payload_len(r: &Relation) -> usize {
+    r.get_relate_content().to_string().len()
+}
+
+/// Upsert `document` only when the stored record has no `version_field` or
+/// an older value than the document's (`UPSERT ... WHERE`), so out-of-order
+/// changes cannot regress a record. Returns whether the record was written.
+pub async fn write_record_if_newer(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    document: &Record,
+    version_field: &str,
+) -> anyhow::Result<bool> {
+    let field = escape_ident(version_field);
+    let query = format!(
+        "UPSERT $record_id CONTENT $content WHERE {field} IS NONE OR {field} < $content.{field}"
+    );
+    upsert_record(surreal, document, &query).await
+}
+
+/// Run an UPSERT `query` binding `$record_id` / `$content`, retrying
+/// transaction conflicts. Returns whether a record was written.
+async fn upsert_record(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    document: &Record,
+    query: &str,
+) -> anyhow::Result<bool> {
     let record_id = &document.id;
     let upsert_content = document.get_upsert_content();
 
-    let query = "UPSERT $record_id CONTENT $content".to_string();
-
     tracing::trace!("Executing SurrealDB query with flattened fields: {}", query);
 
     log::info!("🔧 migrate_batch executing: {query} for record: {record_id:?}");
@@ -167,7 +502,7 @@ pub async fn write_record(
             sleep(Duration::from_millis(delay_ms)).await;
         }
 
-        let mut q = surreal.query(query.clone());
+        let mut q = surreal.query(query);
         q = q.bind(("record_id", record_id.clone()));
         q = q.bind(("content", upsert_content.clone()));
 
@@ -187,12 +522,10 @@ pub async fn write_record(
 
                 match result {
                     Ok(res) => {
-                        if res.is_empty() {
-                            tracing::warn!("Failed to create record: {:?}", record_id);
-                        } else {
+                        if !res.is_empty() {
                             tracing::trace!("Successfully created record: {:?}", record_id);
                         }
-                        return Ok(());
+                        return Ok(!res.is_empty());
                     }
                     Err(e) => {
                         if is_retriable_transaction_error(&e) {
@@ -271,7 +604,9 @@ pub async fn write_relation(
     surreal: &Surreal<surrealdb2::engine::any::Any>,
     r: &Relation,
 ) -> anyhow::Result<()> {
-    let query = format!("RELATE $in->{}->$out CONTENT $content", r.id.tb);
+    let mut fields: Vec<&str> = r.data.keys().map(String::as_str).collect();
+    fields.sort_unstable();
+    let query = write_relation_statement(&r.id.tb, "$id", "$in", "$out", &fields);
 
     let record_id = &r.id;
 
@@ -302,6 +637,7 @@ pub async fn write_relation(
         }
 
         let mut q = surreal.query(query.clone());
+        q = q.bind(("id", sql::Value::Thing(r.id.clone())));
         q = q.bind(("in", r.get_in()));
         q = q.bind(("out", r.get_out()));
         q = q.bind(("content", r.get_relate_content()));
@@ -437,3 +773,786 @@ pub async fn apply_relation_change(
     }
     Ok(())
 }
+
+/// Delete every record of `table` (a source-side `TRUNCATE`).
+pub async fn truncate_table(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    table: &str,
+) -> anyhow::Result<()> {
+    let query = "DELETE type::table($tb)";
+    tracing::trace!("Executing SurrealDB query: {}", query);
+    surreal
+        .query(query)
+        .bind(("tb", table.to_string()))
+        .await?
+        .check()?;
+    tracing::debug!("Deleted all records of table {table}");
+    Ok(())
+}
+
+/// Up to `limit` records of `table` whose id sorts after `after`, in id
+/// order, with the string stored in `field` (see
+/// [`SurrealSink::scan_field`](surreal_sync_core::SurrealSink::scan_field)).
+pub async fn scan_field(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    table: &str,
+    field: &str,
+    after: Option<&surreal_sync_core::Value>,
+    limit: usize,
+) -> anyhow::Result<Vec<(surreal_sync_core::Value, Option<String>)>> {
+    let field = escape_ident(field);
+    let filter = if after.is_some() {
+        " WHERE id > type::thing($tb, $after)"
+    } else {
+        ""
+    };
+    let query = format!(
+        "SELECT id, IF type::is::string({field}) THEN {field} END AS value \
+         FROM type::table($tb){filter} ORDER BY id LIMIT {limit}"
+    );
+    tracing::trace!("Executing SurrealDB query: {}", query);
+    let mut q = surreal.query(query).bind(("tb", table.to_string()));
+    if let Some(after) = after {
+        q = q.bind(("after", id_to_sql_value(&value_to_surreal_id(after)?)));
+    }
+    let mut response = q.await?.check()?;
+    let ids: Vec<sql::Thing> = response.take((0, "id"))?;
+    let values: Vec<Option<String>> = response.take((0, "value"))?;
+    ids.into_iter()
+        .zip(values)
+        .map(|(thing, value)| {
+            let id = match thing.id {
+                sql::Id::Number(n) => surreal_sync_core::Value::Int64(n),
+                sql::Id::String(s) => surreal_sync_core::Value::Text(s),
+                sql::Id::Uuid(u) => surreal_sync_core::Value::Uuid(u.0),
+                other => anyhow::bail!("cannot scan record id {other} of table '{table}'"),
+            };
+            Ok((id, value))
+        })
+        .collect()
+}
+
+/// Run a post-sync validation query; `None` when it returns no rows.
+pub async fn run_validation(
+    surreal: &Surreal<surrealdb2::engine::any::Any>,
+    validation: &ValidationQuery,
+) -> anyhow::Result<Option<ValidationFailure>> {
+    let query = validation_statements(validation);
+    tracing::trace!("Executing SurrealDB query: {}", query);
+    let context = || format!("validation '{}' failed to run", validation.name);
+    let mut response = surreal
+        .query(query)
+        .await
+        .with_context(context)?
+        .check()
+        .with_context(context)?;
+    let violations: Option<i64> = response.take(1)?;
+    let violations = violations.unwrap_or_default() as u64;
+    if violations == 0 {
+        return Ok(None);
+    }
+    let samples: Vec<String> = response.take(2)?;
+    Ok(Some(ValidationFailure {
+        name: validation.name.clone(),
+        violations,
+        samples,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::sink::rows::{write_relations, write_rows};
+    use surreal_sync_core::{FieldMap, Relation as SyncRelation, Row, ThingRef, Value};
+
+    #[tokio::test]
+    async fn test_truncate_table_empties_only_that_table() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let rows: Vec<Row> = (0..6i64)
+            .map(|i| {
+                let table = if i % 2 == 0 { "orders" } else { "users" };
+                Row::new(table, i as u64, Value::Int64(i), FieldMap::new())
+            })
+            .collect();
+        write_rows(&surreal, &rows, ZeroTemporalPolicy::default())
+            .await
+            .unwrap();
+
+        truncate_table(&surreal, "orders").await.unwrap();
+
+        for (table, expected) in [("orders", 0), ("users", 3)] {
+            let count: Option<i64> = surreal
+                .query(format!("RETURN count(SELECT * FROM {table})"))
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert_eq!(count, Some(expected), "unexpected record count in {table}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replayed_relation_batch_is_idempotent() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query("CREATE person:0, person:1, person:2, person:3; CREATE item:0, item:1;")
+            .await
+            .unwrap();
+        let batch: Vec<SyncRelation> = (0..4i64)
+            .map(|i| {
+                let mut data = FieldMap::new();
+                data.insert("weight".to_string(), Value::Int64(i));
+                data.insert("first seen".to_string(), Value::Text(format!("day-{i}")));
+                SyncRelation::new(
+                    "likes",
+                    Value::Int64(i),
+                    ThingRef::new("person", Value::Int64(i)),
+                    ThingRef::new("item", Value::Int64(i % 2)),
+                    data,
+                )
+            })
+            .collect();
+
+        // The batch failed after its first two relations and is replayed whole.
+        write_relations(&surreal, &batch[..2], ZeroTemporalPolicy::default())
+            .await
+            .unwrap();
+        write_relations(&surreal, &batch, ZeroTemporalPolicy::default())
+            .await
+            .unwrap();
+
+        let mut response = surreal
+            .query(
+                "RETURN count(SELECT * FROM likes); \
+                 SELECT VALUE weight FROM likes; \
+                 SELECT VALUE `first seen` FROM likes; \
+                 RETURN array::len(item:1<-likes<-person);",
+            )
+            .await
+            .unwrap();
+        let count: Option<i64> = response.take(0).unwrap();
+        let mut weights: Vec<i64> = response.take(1).unwrap();
+        let mut first_seen: Vec<String> = response.take(2).unwrap();
+        let liked_item_1: Option<i64> = response.take(3).unwrap();
+        weights.sort_unstable();
+        first_seen.sort_unstable();
+        assert_eq!(count, Some(4));
+        assert_eq!(weights, vec![0, 1, 2, 3]);
+        assert_eq!(first_seen, vec!["day-0", "day-1", "day-2", "day-3"]);
+        assert_eq!(
+            liked_item_1,
+            Some(2),
+            "graph edges were not created once each"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rewritten_relation_replaces_its_content() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query("CREATE person:1; CREATE item:1;")
+            .await
+            .unwrap();
+        let relation = |fields: &[(&str, i64)]| {
+            let data: FieldMap = fields
+                .iter()
+                .map(|(name, value)| (name.to_string(), Value::Int64(*value)))
+                .collect();
+            SyncRelation::new(
+                "likes",
+                Value::Int64(1),
+                ThingRef::new("person", Value::Int64(1)),
+                ThingRef::new("item", Value::Int64(1)),
+                data,
+            )
+        };
+
+        write_relations(
+            &surreal,
+            &[relation(&[("weight", 1), ("rank", 9)])],
+            ZeroTemporalPolicy::default(),
+        )
+        .await
+        .unwrap();
+        write_relations(
+            &surreal,
+            &[relation(&[("weight", 2)])],
+            ZeroTemporalPolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut response = surreal
+            .query(
+                "SELECT VALUE weight FROM likes; \
+                 SELECT VALUE rank FROM likes; \
+                 RETURN array::len(item:1<-likes<-person);",
+            )
+            .await
+            .unwrap();
+        let weights: Vec<i64> = response.take(0).unwrap();
+        let ranks: Vec<Option<i64>> = response.take(1).unwrap();
+        let liked: Option<i64> = response.take(2).unwrap();
+        assert_eq!(weights, vec![2]);
+        assert_eq!(ranks, vec![None], "stale field was kept");
+        assert_eq!(liked, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_version_field_skips_out_of_order_updates() {
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let update = |version: i64, name: &str| {
+            Change::update(
+                "users",
+                Value::Int64(1),
+                FieldMap::from([
+                    ("version".to_string(), Value::Int64(version)),
+                    ("name".to_string(), Value::Text(name.to_string())),
+                ]),
+            )
+        };
+        let policy = ZeroTemporalPolicy::default();
+
+        for change in [update(1, "first"), update(3, "newest"), update(2, "stale")] {
+            apply_change_with_version(&surreal, &change, policy, Some("version"))
+                .await
+                .unwrap();
+        }
+
+        let name: Option<String> = surreal
+            .query("RETURN users:1.name")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(name.as_deref(), Some("newest"));
+
+        // Without a version field the late update wins.
+        apply_change(&surreal, &update(2, "stale"), policy)
+            .await
+            .unwrap();
+        let name: Option<String> = surreal
+            .query("RETURN users:1.name")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(name.as_deref(), Some("stale"));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_estimate_matches_real_write_queries() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::SurrealSink;
+
+        let rows: Vec<Row> = (0..5i64)
+            .map(|i| {
+                let fields = FieldMap::from([("n".to_string(), Value::Int64(i))]);
+                Row::new("users", i as u64, Value::Int64(i), fields)
+            })
+            .collect();
+        let change = Change::update(
+            "users",
+            Value::Int64(9),
+            FieldMap::from([("n".to_string(), Value::Int64(9))]),
+        );
+        let run = |sink: Surreal2Sink| {
+            let (rows, change) = (rows.clone(), change.clone());
+            async move {
+                for batch in rows.chunks(2) {
+                    sink.write_rows(batch).await.unwrap();
+                }
+                sink.apply_change(&change).await.unwrap();
+                sink
+            }
+        };
+        let connect = || async {
+            let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+            surreal.use_ns("test").use_db("test").await.unwrap();
+            surreal
+        };
+        let count = |surreal: Surreal<surrealdb2::engine::any::Any>| async move {
+            // A table that was never written may not exist at all.
+            let count: Option<i64> = surreal
+                .query("RETURN count(SELECT * FROM users)")
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap_or_default();
+            count.unwrap_or_default() as u64
+        };
+
+        let dry = run(Surreal2Sink::new(connect().await).with_dry_run(true)).await;
+        let real = run(Surreal2Sink::new(connect().await)).await;
+
+        let estimate = dry.write_estimate().tables["users"];
+        // One UPSERT per record: the real run stored exactly that many.
+        assert_eq!(estimate.queries, count(real.into_inner()).await);
+        assert_eq!(estimate.records, 6);
+        assert_eq!(estimate.batches, 3);
+        assert!(estimate.bytes > 0);
+        assert_eq!(count(dry.into_inner()).await, 0, "dry run must not write");
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_marks_record_instead_of_removing_it() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::{SoftDeleteConfig, SurrealSink};
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let sink = Surreal2Sink::new(surreal.clone()).with_soft_delete(Some(
+            SoftDeleteConfig::new("deleted_at").with_deleted_flag("deleted"),
+        ));
+
+        sink.apply_change(&Change::create(
+            "users",
+            Value::Int64(1),
+            FieldMap::from([("name".to_string(), Value::Text("alice".to_string()))]),
+        ))
+        .await
+        .unwrap();
+        sink.apply_change(&Change::delete("users", Value::Int64(1)))
+            .await
+            .unwrap();
+        // Deleting a record that was never synced must not create it.
+        sink.apply_change(&Change::delete("users", Value::Int64(2)))
+            .await
+            .unwrap();
+
+        let soft_deleted: Option<bool> = surreal
+            .query(
+                "RETURN users:1.name = 'alice' AND users:1.deleted = true \
+                 AND type::is::datetime(users:1.deleted_at)",
+            )
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(soft_deleted, Some(true), "record must be kept and marked");
+        let count: Option<i64> = surreal
+            .query("RETURN count(SELECT * FROM users)")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(count, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_error_table_captures_failed_records() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::SurrealSink;
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        surreal
+            .query("DEFINE TABLE users SCHEMAFULL; DEFINE FIELD age ON users TYPE int;")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let sink = Surreal2Sink::new(surreal.clone()).with_error_table(Some("sync_errors".into()));
+        let user = |id: i64, age: Value| {
+            Row::new(
+                "users",
+                id as u64,
+                Value::Int64(id),
+                FieldMap::from([("age".to_string(), age)]),
+            )
+        };
+        let bad_row = user(1, Value::Text("old".to_string()));
+        let bad_change = Change::update(
+            "users",
+            Value::Int64(3),
+            FieldMap::from([("age".to_string(), Value::Text("older".to_string()))]),
+        );
+
+        // The bad row fails its batch; the good row is still written.
+        sink.write_rows(&[user(0, Value::Int64(30)), bad_row.clone()])
+            .await
+            .unwrap();
+        sink.apply_change(&bad_change).await.unwrap();
+
+        let query_one = |query: &'static str| {
+            let surreal = surreal.clone();
+            async move {
+                let value: Option<String> = surreal.query(query).await.unwrap().take(0).unwrap();
+                value.unwrap_or_else(|| panic!("no result for {query}"))
+            }
+        };
+        let users: Option<i64> = surreal
+            .query("RETURN count(SELECT * FROM users)")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(users, Some(1));
+        let errors: Option<i64> = surreal
+            .query("RETURN count(SELECT * FROM sync_errors WHERE source_table = 'users')")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(errors, Some(2));
+
+        let error =
+            query_one("RETURN (SELECT VALUE error FROM sync_errors WHERE position = 1)[0]").await;
+        assert!(error.contains("age"), "unexpected error detail: {error}");
+        let data =
+            query_one("RETURN (SELECT VALUE data FROM sync_errors WHERE position = 1)[0]").await;
+        assert_eq!(serde_json::from_str::<Row>(&data).unwrap(), bad_row);
+        let data =
+            query_one("RETURN (SELECT VALUE data FROM sync_errors WHERE position != 1)[0]").await;
+        assert_eq!(serde_json::from_str::<Change>(&data).unwrap(), bad_change);
+    }
+
+    #[tokio::test]
+    async fn test_merge_mode_merges_objects_and_array_strategies() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::{ArrayMergeStrategy, MergeConfig, SurrealSink, Type};
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let sink = Surreal2Sink::new(surreal.clone()).with_merge(Some(
+            MergeConfig::new(ArrayMergeStrategy::Append)
+                .with_array_field("items", ArrayMergeStrategy::UnionByKey("id".to_string())),
+        ));
+        let text = |s: &str| Value::Text(s.to_string());
+        let object = |pairs: Vec<(&str, Value)>| {
+            Value::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+        };
+        let array = |elements: Vec<Value>| Value::Array {
+            elements,
+            element_type: Box::new(Type::Json),
+        };
+        let item =
+            |id: i64, qty: i64| object(vec![("id", Value::Int64(id)), ("qty", Value::Int64(qty))]);
+
+        sink.apply_change(&Change::create(
+            "users",
+            Value::Int64(1),
+            FieldMap::from([
+                ("name".to_string(), text("alice")),
+                (
+                    "profile".to_string(),
+                    object(vec![("city", text("Paris")), ("zip", text("75001"))]),
+                ),
+                ("tags".to_string(), array(vec![text("a")])),
+                ("items".to_string(), array(vec![item(1, 1), item(2, 1)])),
+            ]),
+        ))
+        .await
+        .unwrap();
+        // A partial update: one nested key, an array delta and a keyed item.
+        sink.apply_change(&Change::update(
+            "users",
+            Value::Int64(1),
+            FieldMap::from([
+                ("profile".to_string(), object(vec![("zip", text("75002"))])),
+                ("tags".to_string(), array(vec![text("b")])),
+                ("items".to_string(), array(vec![item(2, 5), item(3, 1)])),
+            ]),
+        ))
+        .await
+        .unwrap();
+
+        for check in [
+            "users:1.name = 'alice'",
+            "users:1.profile = { city: 'Paris', zip: '75002' }",
+            "users:1.tags = ['a', 'b']",
+            "users:1.items = [{ id: 1, qty: 1 }, { id: 2, qty: 5 }, { id: 3, qty: 1 }]",
+        ] {
+            let holds: Option<bool> = surreal
+                .query(format!("RETURN {check}"))
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert_eq!(holds, Some(true), "{check}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_skip_unchanged_fields_patches_only_changed_fields() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::SurrealSink;
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let sink = Surreal2Sink::new(surreal.clone()).with_skip_unchanged_fields(true);
+        let user = |age: i64| {
+            FieldMap::from([
+                ("name".to_string(), Value::Text("alice".to_string())),
+                ("age".to_string(), Value::Int64(age)),
+            ])
+        };
+        let holds = |check: &'static str| {
+            let surreal = surreal.clone();
+            async move {
+                let holds: Option<bool> = surreal
+                    .query(format!("RETURN {check}"))
+                    .await
+                    .unwrap()
+                    .take(0)
+                    .unwrap();
+                assert_eq!(holds, Some(true), "{check}");
+            }
+        };
+
+        // No stored record yet: the patch falls back to a full write.
+        let change = Change::update("users", Value::Int64(1), user(30))
+            .with_changed_fields(vec!["age".to_string()]);
+        sink.apply_change(&change).await.unwrap();
+        holds("users:1.* = { id: users:1, name: 'alice', age: 30 }").await;
+
+        // `name` is edited behind the sync's back. An update that only changed
+        // `age` sets `age` alone, so the edit survives.
+        surreal
+            .query("UPDATE users:1 SET name = 'edited', nickname = 'al'")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let change = Change::update("users", Value::Int64(1), user(31))
+            .with_changed_fields(vec!["age".to_string(), "nickname".to_string()]);
+        sink.apply_change(&change).await.unwrap();
+        holds("users:1.* = { id: users:1, name: 'edited', age: 31 }").await;
+
+        // Without the mode the whole record is written.
+        Surreal2Sink::new(surreal.clone())
+            .apply_change(&change)
+            .await
+            .unwrap();
+        holds("users:1.name = 'alice'").await;
+    }
+
+    #[tokio::test]
+    async fn test_partial_update_keeps_omitted_fields() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::SurrealSink;
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let sink = Surreal2Sink::new(surreal.clone());
+        let post = FieldMap::from([
+            ("title".to_string(), Value::Text("hello".to_string())),
+            ("body".to_string(), Value::Text("long body".to_string())),
+            ("note".to_string(), Value::Text("draft".to_string())),
+        ]);
+        sink.apply_change(&Change::create("posts", Value::Int64(1), post))
+            .await
+            .unwrap();
+
+        // An UPDATE that left the unchanged TOAST `body` out and set `note`
+        // to NULL: `body` keeps its stored value, `note` is cleared.
+        let update = FieldMap::from([
+            ("title".to_string(), Value::Text("edited".to_string())),
+            ("note".to_string(), Value::Null),
+        ]);
+        sink.apply_change(&Change::update("posts", Value::Int64(1), update).with_partial(true))
+            .await
+            .unwrap();
+        let holds: Option<bool> = surreal
+            .query(
+                "RETURN posts:1.title = 'edited' AND posts:1.body = 'long body' \
+                 AND posts:1.note IS NONE",
+            )
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(holds, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_conflict_policy_detects_external_modifications() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::{ConflictPolicy, SurrealSink};
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let update = |name: &str| {
+            Change::update(
+                "users",
+                Value::Int64(1),
+                FieldMap::from([("name".to_string(), Value::Text(name.to_string()))]),
+            )
+        };
+        let name = || {
+            let surreal = surreal.clone();
+            async move {
+                let name: Option<String> = surreal
+                    .query("RETURN users:1.name")
+                    .await
+                    .unwrap()
+                    .take(0)
+                    .unwrap();
+                name.unwrap()
+            }
+        };
+        let modify_externally = || {
+            let surreal = surreal.clone();
+            async move {
+                surreal
+                    .query("UPDATE users:1 SET name = 'external'")
+                    .await
+                    .unwrap()
+                    .check()
+                    .unwrap();
+            }
+        };
+        let policy = ZeroTemporalPolicy::default();
+
+        // Records surreal-sync wrote itself are never conflicts.
+        for n in ["first", "second"] {
+            let conflict = write_change_checked(&surreal, &update(n), policy, false)
+                .await
+                .unwrap();
+            assert!(!conflict);
+        }
+        assert_eq!(name().await, "second");
+
+        // Another writer changes the record between syncs.
+        modify_externally().await;
+        let conflict = write_change_checked(&surreal, &update("third"), policy, false)
+            .await
+            .unwrap();
+        assert!(conflict);
+        assert_eq!(name().await, "external");
+
+        // Overwriting re-stamps the record, so the next update is clean.
+        let conflict = write_change_checked(&surreal, &update("third"), policy, true)
+            .await
+            .unwrap();
+        assert!(conflict);
+        assert_eq!(name().await, "third");
+        let conflict = write_change_checked(&surreal, &update("fourth"), policy, false)
+            .await
+            .unwrap();
+        assert!(!conflict);
+
+        // The error-table policy keeps the record and stores the change.
+        let sink = Surreal2Sink::new(surreal.clone())
+            .with_conflict_policy(Some(ConflictPolicy::ErrorTable))
+            .with_error_table(Some("sync_errors".into()));
+        modify_externally().await;
+        sink.apply_change(&update("fifth")).await.unwrap();
+        assert_eq!(name().await, "external");
+        let error: Option<String> = surreal
+            .query("RETURN (SELECT VALUE error FROM sync_errors WHERE source_table = 'users')[0]")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert!(error.unwrap().contains("modified outside surreal-sync"));
+    }
+
+    #[tokio::test]
+    async fn test_validation_queries_report_violating_records() {
+        use crate::v2::Surreal2Sink;
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let rows: Vec<Row> = [(1i64, 30i64), (2, -4), (3, 41), (4, -1)]
+            .into_iter()
+            .map(|(id, age)| {
+                Row::new(
+                    "users",
+                    id as u64,
+                    Value::Int64(id),
+                    FieldMap::from([("age".to_string(), Value::Int64(age))]),
+                )
+            })
+            .collect();
+        write_rows(&surreal, &rows, ZeroTemporalPolicy::default())
+            .await
+            .unwrap();
+        let sink = Surreal2Sink::new(surreal);
+
+        let failures = sink
+            .run_validations(&[
+                ValidationQuery::new("ages below 150", "SELECT * FROM users WHERE age >= 150;"),
+                ValidationQuery::new(
+                    "ages not negative",
+                    "SELECT id, age FROM users WHERE age < 0 ORDER BY id",
+                ),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(failures.len(), 1, "unexpected failures: {failures:?}");
+        let failure = &failures[0];
+        assert_eq!(failure.name, "ages not negative");
+        assert_eq!(failure.violations, 2);
+        assert_eq!(failure.samples.len(), 2);
+        assert!(failure.samples[0].contains("users:2") && failure.samples[0].contains("-4"));
+        assert!(failure.samples[1].contains("users:4"));
+
+        let err = sink
+            .run_validations(&[ValidationQuery::new("broken", "SELEC * FROM users")])
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("broken"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_template_computes_derived_field() {
+        use crate::v2::Surreal2Sink;
+        use surreal_sync_core::SurrealSink;
+
+        let surreal = surrealdb2::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+        let template = WriteTemplate::new(
+            "users",
+            "UPSERT $id CONTENT $content; \
+             UPDATE $id SET display = string::concat($first, ' ', $last), \
+             has_email = $email != NONE",
+        )
+        .unwrap();
+        let sink = Surreal2Sink::new(surreal.clone())
+            .with_write_concurrency(2)
+            .with_write_templates(vec![template]);
+        let user = |first: &str, last: &str| {
+            FieldMap::from([
+                ("first".to_string(), Value::Text(first.to_string())),
+                ("last".to_string(), Value::Text(last.to_string())),
+            ])
+        };
+        let holds = |check: &'static str| {
+            let surreal = surreal.clone();
+            async move {
+                let holds: Option<bool> = surreal
+                    .query(format!("RETURN {check}"))
+                    .await
+                    .unwrap()
+                    .take(0)
+                    .unwrap();
+                assert_eq!(holds, Some(true), "{check}");
+            }
+        };
+
+        sink.write_rows(&[
+            Row::new("users", 0, Value::Int64(1), user("Ada", "Lovelace")),
+            Row::new("users", 1, Value::Int64(2), user("Alan", "Turing")),
+            Row::new("teams", 2, Value::Int64(1), user("not", "templated")),
+        ])
+        .await
+        .unwrap();
+        holds("users:1.display = 'Ada Lovelace' AND users:1.has_email = false").await;
+        holds("users:2.display = 'Alan Turing' AND users:2.first = 'Alan'").await;
+        holds("teams:1.display = NONE AND teams:1.first = 'not'").await;
+
+        // Incremental updates go through the template too.
+        let change = Change::update("users", Value::Int64(1), user("Ada", "King"));
+        sink.apply_change(&change).await.unwrap();
+        holds("users:1.display = 'Ada King'").await;
+    }
+}
@@ -4,7 +4,9 @@
 
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
-use surreal_sync_core::{Type, TypedValue, Value};
+use surreal_sync_core::{
+    FieldMap, GeneratorTableDefinition, Row, Type, TypedValue, TypedValueError, Value,
+};
 use surrealdb2::sql::{Number, Object, Value as SqlValue};
 
 /// SurrealDB value paired with schema information for type-aware conversion.
@@ -26,6 +28,20 @@ impl SurrealValueWithSchema {
     pub fn to_typed_value(&self) -> TypedValue {
         TypedValue::from(self.clone())
     }
+
+    /// [`to_typed_value`](Self::to_typed_value), failing when a decimal does
+    /// not fit its type's precision and scale (see [`TypedValue::try_decimal`]).
+    pub fn try_to_typed_value(&self) -> Result<TypedValue, TypedValueError> {
+        let typed = self.to_typed_value();
+        match &typed.value {
+            Value::Decimal {
+                value,
+                precision,
+                scale,
+            } => TypedValue::try_decimal(value.as_str(), *precision, *scale),
+            _ => Ok(typed),
+        }
+    }
 }
 
 impl From<SurrealValueWithSchema> for TypedValue {
@@ -144,6 +160,9 @@ impl From<SurrealValueWithSchema> for TypedValue {
                 }
             }
 
+            // Object - nested document with typed fields
+            (Type::Object, SqlValue::Object(obj)) => TypedValue::object(object_to_hashmap(obj)),
+
             // Array types
             (Type::Array { element_type }, SqlValue::Array(arr)) => {
                 let values: Vec<Value> = arr
@@ -227,10 +246,9 @@ impl From<SurrealValueWithSchema> for TypedValue {
     }
 }
 
-/// Convert a SurrealDB Object to a HashMap of Value.
-#[allow(dead_code)]
-fn object_to_hashmap(obj: &Object) -> HashMap<String, Value> {
-    let mut map = HashMap::new();
+/// Convert a SurrealDB Object to a FieldMap of Value.
+pub fn object_to_hashmap(obj: &Object) -> FieldMap {
+    let mut map = FieldMap::new();
     for (key, value) in obj.iter() {
         map.insert(key.clone(), surreal_value_to_generated(value));
     }
@@ -238,8 +256,7 @@ fn object_to_hashmap(obj: &Object) -> HashMap<String, Value> {
 }
 
 /// Convert a SurrealDB SqlValue to Value (without type context).
-#[allow(dead_code)]
-fn surreal_value_to_generated(value: &SqlValue) -> Value {
+pub fn surreal_value_to_generated(value: &SqlValue) -> Value {
     match value {
         SqlValue::None => Value::Null,
         SqlValue::Bool(b) => Value::Bool(*b),
@@ -263,7 +280,7 @@ fn surreal_value_to_generated(value: &SqlValue) -> Value {
         }
         SqlValue::Object(obj) => {
             // Convert SurrealDB Object to Value::Object
-            let mut map = std::collections::HashMap::new();
+            let mut map = FieldMap::new();
             for (k, v) in obj.iter() {
                 map.insert(k.clone(), surreal_value_to_generated(v));
             }
@@ -394,11 +411,47 @@ pub fn object_to_typed_values(
     result
 }
 
+/// Convert a record read with `SELECT *` into a [`Row`] of `table`.
+///
+/// The row ID is the key of the record's `id`. Fields of `table_schema` are
+/// converted with their declared type, like [`extract_field`]; other fields,
+/// and every field without a schema, are converted without type context.
+/// Fails when a decimal field does not fit its declared precision and scale.
+pub fn object_to_row(
+    table: &str,
+    index: u64,
+    obj: &Object,
+    table_schema: Option<&GeneratorTableDefinition>,
+) -> anyhow::Result<Row> {
+    let id = match obj.get("id").map(surreal_value_to_generated) {
+        Some(Value::Thing { id, .. }) => *id,
+        other => anyhow::bail!("Record of table '{table}' has no record id: {other:?}"),
+    };
+    let mut fields = FieldMap::new();
+    for (key, value) in obj.iter().filter(|(key, _)| key.as_str() != "id") {
+        let field_type = table_schema
+            .and_then(|t| t.fields.iter().find(|f| &f.name == key))
+            .map(|f| &f.field_type);
+        let value = match field_type {
+            Some(field_type) => {
+                SurrealValueWithSchema::new(value.clone(), field_type.clone())
+                    .try_to_typed_value()
+                    .map_err(|e| anyhow::anyhow!("Field '{key}' of table '{table}': {e}"))?
+                    .value
+            }
+            None => surreal_value_to_generated(value),
+        };
+        fields.insert(key.clone(), value);
+    }
+    Ok(Row::new(table, index, id, fields))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{Datelike, TimeZone, Utc};
     use std::collections::BTreeMap;
+    use surreal_sync_core::test_support::nested_object;
     use surrealdb2::sql::{Array, Datetime, Strand};
 
     #[test]
@@ -467,6 +520,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_to_typed_value_checks_decimal_precision() {
+        let decimal = |s: &str| {
+            SurrealValueWithSchema::new(
+                SqlValue::Number(Number::Decimal(
+                    rust_decimal::Decimal::from_str_exact(s).unwrap(),
+                )),
+                Type::Decimal {
+                    precision: 5,
+                    scale: 2,
+                },
+            )
+            .try_to_typed_value()
+        };
+        assert!(decimal("999.99").is_ok());
+        assert!(decimal("1000.00").is_err());
+    }
+
     #[test]
     fn test_string_conversion() {
         let sv =
@@ -589,6 +660,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_nested_object_round_trip() {
+        let original = nested_object();
+
+        let surreal = crate::v2::types::SurrealValue::from(original.clone()).into_inner();
+        // Stored as native nested objects, not a serialized JSON value
+        let object = |fields: Vec<(&str, SqlValue)>| {
+            SqlValue::Object(Object::from(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect::<BTreeMap<_, _>>(),
+            ))
+        };
+        let geo = object(vec![
+            ("lat", SqlValue::Number(Number::Float(48.85))),
+            ("lon", SqlValue::Number(Number::Float(2.35))),
+        ]);
+        let address = object(vec![
+            ("city", SqlValue::Strand(Strand::from("Paris"))),
+            ("geo", geo),
+        ]);
+        assert_eq!(
+            surreal,
+            object(vec![
+                ("name", SqlValue::Strand(Strand::from("Alice"))),
+                ("address", address)
+            ])
+        );
+        let back = TypedValue::from(SurrealValueWithSchema::new(surreal, Type::Object));
+        assert_eq!(back.sync_type, Type::Object);
+        assert_eq!(back.value, original.value);
+    }
+
     #[test]
     fn test_array_int_conversion() {
         let arr = Array::from(vec![
@@ -2,15 +2,16 @@
 //!
 //! This crate provides `Surreal3Store`, which implements the `CheckpointStore`
 //! trait for SurrealDB v3 SDK. It enables checkpoint storage in SurrealDB v3
-//! servers using the v3 SDK types (`RecordId`, `RecordIdKey`).
+//! servers, keeping a configurable history of checkpoints per id.
 
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use surreal_sync_core::{CheckpointID, CheckpointStore, StoredCheckpoint};
 use surrealdb3::engine::any::Any;
-use surrealdb3::types::{RecordId, RecordIdKey, SurrealValue};
+use surrealdb3::types::SurrealValue;
 
 /// Internal struct for storing checkpoint data in SurrealDB v3.
 ///
@@ -52,30 +53,96 @@ impl TryFrom<CheckpointRecord> for StoredCheckpoint {
 
 /// SurrealDB v3 SDK implementation of CheckpointStore trait.
 ///
-/// Stores checkpoints in a SurrealDB table using the v3 SDK.
+/// Stores checkpoints in a SurrealDB table using the v3 SDK. Every
+/// `store_checkpoint` creates a new record; the newest `history_limit`
+/// records per checkpoint id are kept and older ones are deleted.
 pub struct Surreal3Store {
     client: surrealdb3::Surreal<Any>,
     table_name: String,
+    history_limit: usize,
 }
 
 impl Surreal3Store {
     /// Create a new Surreal3Store with the given client and table name.
+    ///
+    /// Keeps only the latest checkpoint per id; see
+    /// [`Self::with_history_limit`].
     pub fn new(client: surrealdb3::Surreal<Any>, table_name: String) -> Self {
-        Self { client, table_name }
+        Self {
+            client,
+            table_name,
+            history_limit: 1,
+        }
     }
 
-    /// Convert a CheckpointID to a SurrealDB v3 RecordId.
-    fn to_record_id(&self, id: &CheckpointID) -> RecordId {
-        let id_str = format!("{}_{}", id.database_type.replace('-', "_"), id.phase);
-        RecordId::new(self.table_name.as_str(), RecordIdKey::String(id_str))
+    /// Keep the newest `history_limit` checkpoints per id (at least one).
+    pub fn with_history_limit(mut self, history_limit: usize) -> Self {
+        self.history_limit = history_limit.max(1);
+        self
+    }
+
+    /// Stored checkpoints for `id`, newest first, at most `limit` of them.
+    pub async fn read_checkpoint_history(
+        &self,
+        id: &CheckpointID,
+        limit: usize,
+    ) -> Result<Vec<StoredCheckpoint>> {
+        // `created_at` is an RFC3339 string and ORDER BY only takes fields,
+        // so the cast to datetime is projected as `created` to order on
+        let mut response = self
+            .client
+            .query(
+                "SELECT *, <datetime> created_at AS created FROM type::table($table) \
+                 WHERE database_type = $database_type AND phase = $phase \
+                 ORDER BY created DESC LIMIT $limit",
+            )
+            .bind(("table", self.table_name.clone()))
+            .bind(("database_type", id.database_type.clone()))
+            .bind(("phase", id.phase.clone()))
+            .bind(("limit", limit as i64))
+            .await?;
+
+        // SurrealDB v3 returns an error for non-existent tables; treat as "not found"
+        let records: Vec<CheckpointRecord> = match response.take(0) {
+            Ok(r) => r,
+            Err(e) if e.to_string().contains("does not exist") => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        records
+            .into_iter()
+            .map(StoredCheckpoint::try_from)
+            .collect()
+    }
+
+    /// Delete all but the newest `history_limit` records for `id`.
+    ///
+    /// The records to keep are picked by id, so records sharing a
+    /// `created_at` never push the history past the limit.
+    async fn trim_history(&self, id: &CheckpointID) -> Result<()> {
+        self.client
+            .query(
+                "LET $kept = (SELECT id, <datetime> created_at AS created \
+                 FROM type::table($table) \
+                 WHERE database_type = $database_type AND phase = $phase \
+                 ORDER BY created DESC LIMIT $limit).id; \
+                 DELETE type::table($table) \
+                 WHERE database_type = $database_type AND phase = $phase \
+                 AND id NOTINSIDE $kept",
+            )
+            .bind(("table", self.table_name.clone()))
+            .bind(("database_type", id.database_type.clone()))
+            .bind(("phase", id.phase.clone()))
+            .bind(("limit", self.history_limit as i64))
+            .await?
+            .check()?;
+        Ok(())
     }
 }
 
 #[async_trait]
 impl CheckpointStore for Surreal3Store {
     async fn store_checkpoint(&self, id: &CheckpointID, checkpoint_data: String) -> Result<()> {
-        let record_id = self.to_record_id(id);
-
         let stored = StoredCheckpoint {
             checkpoint_data,
             database_type: id.database_type.clone(),
@@ -87,12 +154,14 @@ impl CheckpointStore for Surreal3Store {
         let record = CheckpointRecord::from(&stored);
         let json_value = serde_json::to_value(&record)?;
 
-        // Use UPSERT with raw JSON content
+        // A new record per checkpoint; the table keeps the history
         self.client
-            .query("UPSERT $record_id CONTENT $content")
-            .bind(("record_id", record_id))
+            .query("CREATE type::table($table) CONTENT $content")
+            .bind(("table", self.table_name.clone()))
             .bind(("content", json_value))
-            .await?;
+            .await?
+            .check()?;
+        self.trim_history(id).await?;
 
         tracing::info!(
             "Stored checkpoint in SurrealDB v3 table '{}': {} / {}",
@@ -105,25 +174,125 @@ impl CheckpointStore for Surreal3Store {
     }
 
     async fn read_checkpoint(&self, id: &CheckpointID) -> Result<Option<StoredCheckpoint>> {
-        let record_id = self.to_record_id(id);
+        Ok(self
+            .read_checkpoint_history(id, 1)
+            .await?
+            .into_iter()
+            .next())
+    }
 
+    async fn list_checkpoints(&self) -> Result<Vec<CheckpointID>> {
         let mut response = self
             .client
-            .query("SELECT * FROM $record_id")
-            .bind(("record_id", record_id))
+            .query("SELECT * FROM type::table($table)")
+            .bind(("table", self.table_name.clone()))
             .await?;
 
-        // SurrealDB v3 returns an error for non-existent tables; treat as "not found"
+        // As in `read_checkpoint_history`, a missing table holds no checkpoints
         let records: Vec<CheckpointRecord> = match response.take(0) {
             Ok(r) => r,
-            Err(e) if e.to_string().contains("does not exist") => return Ok(None),
+            Err(e) if e.to_string().contains("does not exist") => return Ok(Vec::new()),
             Err(e) => return Err(e.into()),
         };
 
-        if let Some(record) = records.into_iter().next() {
-            Ok(Some(record.try_into()?))
-        } else {
-            Ok(None)
+        // One id per checkpoint, however much history it has
+        let ids: BTreeSet<CheckpointID> = records
+            .into_iter()
+            .map(|record| CheckpointID {
+                database_type: record.database_type,
+                phase: record.phase,
+            })
+            .collect();
+        Ok(ids.into_iter().collect())
+    }
+
+    async fn delete_checkpoint(&self, id: &CheckpointID) -> Result<()> {
+        self.client
+            .query(
+                "DELETE type::table($table) \
+                 WHERE database_type = $database_type AND phase = $phase",
+            )
+            .bind(("table", self.table_name.clone()))
+            .bind(("database_type", id.database_type.clone()))
+            .bind(("phase", id.phase.clone()))
+            .await?
+            .check()?;
+
+        tracing::info!(
+            "Deleted checkpoint from SurrealDB v3 table '{}': {} / {}",
+            self.table_name,
+            id.database_type,
+            id.phase
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn store(history_limit: usize) -> Surreal3Store {
+        let client = surrealdb3::engine::any::connect("mem://").await.unwrap();
+        client.use_ns("test").use_db("test").await.unwrap();
+        Surreal3Store::new(client, "checkpoints".to_string()).with_history_limit(history_limit)
+    }
+
+    fn checkpoint_id() -> CheckpointID {
+        CheckpointID {
+            database_type: "mysql".to_string(),
+            phase: "full_sync_start".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_is_newest_first_and_limited() {
+        let store = store(3).await;
+        let id = checkpoint_id();
+        for n in 1..=5 {
+            store.store_checkpoint(&id, format!("{n}")).await.unwrap();
+        }
+
+        let history = store.read_checkpoint_history(&id, 10).await.unwrap();
+        let data: Vec<&str> = history
+            .iter()
+            .map(|stored| stored.checkpoint_data.as_str())
+            .collect();
+        assert_eq!(data, ["5", "4", "3"]);
+        assert_eq!(
+            store.read_checkpoint_history(&id, 2).await.unwrap().len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trim_keeps_limit_when_timestamps_tie() {
+        let store = store(2).await;
+        let id = checkpoint_id();
+        let created_at = Utc::now();
+        for n in 1..=4 {
+            let record = CheckpointRecord::from(&StoredCheckpoint {
+                checkpoint_data: format!("{n}"),
+                database_type: id.database_type.clone(),
+                phase: id.phase.clone(),
+                created_at,
+            });
+            store
+                .client
+                .query("CREATE checkpoints CONTENT $content")
+                .bind(("content", serde_json::to_value(&record).unwrap()))
+                .await
+                .unwrap()
+                .check()
+                .unwrap();
         }
+
+        store.trim_history(&id).await.unwrap();
+
+        assert_eq!(
+            store.read_checkpoint_history(&id, 10).await.unwrap().len(),
+            2
+        );
     }
 }
@@ -4,8 +4,11 @@
 
 #[cfg(feature = "reqwest")]
 use crate::client::SurrealTestClient;
+use crate::v3::types::reverse::object_to_row;
 use anyhow::Result;
+use surreal_sync_core::{GeneratorTableDefinition, Row};
 use surrealdb3::engine::any::Any;
+use surrealdb3::types::Value as DbValue;
 use surrealdb3::Surreal;
 
 /// SurrealDB v3 test client wrapper.
@@ -58,6 +61,49 @@ impl Surreal3Client {
     pub fn inner_mut(&mut self) -> &mut Surreal<Any> {
         &mut self.client
     }
+
+    /// Names of the tables defined in the database (`INFO FOR DB`).
+    pub async fn table_names(&self) -> Result<Vec<String>> {
+        let mut result = self.client.query("INFO FOR DB;").await?;
+        let info: Option<serde_json::Value> = result.take(0)?;
+        Ok(info
+            .as_ref()
+            .and_then(|info| info.get("tables"))
+            .and_then(|tables| tables.as_object())
+            .map(|tables| tables.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    /// Records `start..start + limit` of `table` in record ID order, as rows
+    /// (see [`object_to_row`]).
+    pub async fn read_rows(
+        &self,
+        table: &str,
+        start: usize,
+        limit: usize,
+        table_schema: Option<&GeneratorTableDefinition>,
+    ) -> Result<Vec<Row>> {
+        let mut result = self
+            .client
+            .query("SELECT * FROM type::table($table) ORDER BY id LIMIT $limit START $start")
+            .bind(("table", table.to_string()))
+            .bind(("limit", limit as i64))
+            .bind(("start", start as i64))
+            .await?
+            .check()?;
+        let records: DbValue = result.take(0)?;
+        let DbValue::Array(records) = records else {
+            anyhow::bail!("SELECT on table '{table}' did not return an array");
+        };
+        records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| match record {
+                DbValue::Object(obj) => object_to_row(table, (start + i) as u64, obj, table_schema),
+                other => anyhow::bail!("Unexpected record in table '{table}': {other:?}"),
+            })
+            .collect()
+    }
 }
 
 #[cfg(feature = "reqwest")]
@@ -122,4 +168,61 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<Surreal3Client>();
     }
+
+    #[tokio::test]
+    async fn test_read_rows_pages_in_record_id_order() {
+        use surreal_sync_core::{
+            FieldConstraints, GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition,
+            Type, Value,
+        };
+
+        let client = Surreal3Client::new(surrealdb3::engine::any::connect("mem://").await.unwrap());
+        client.inner().use_ns("test").use_db("test").await.unwrap();
+        client
+            .inner()
+            .query(
+                "CREATE users:2 CONTENT { name: 'b', age: 41 }; \
+                 CREATE users:1 CONTENT { name: 'a', age: 30 }; \
+                 CREATE users:3 CONTENT { name: 'c', age: 52 };",
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+
+        let page = client.read_rows("users", 0, 2, None).await.unwrap();
+        let ids: Vec<_> = page.iter().map(|row| (row.index, row.id.clone())).collect();
+        assert_eq!(ids, vec![(0, Value::Int64(1)), (1, Value::Int64(2))]);
+        assert_eq!(
+            page[0].get_field("name"),
+            Some(&Value::Text("a".to_string()))
+        );
+        assert_eq!(page[0].get_field("age"), Some(&Value::Int64(30)));
+        assert!(page[0].get_field("id").is_none());
+
+        let users = GeneratorTableDefinition {
+            name: "users".to_string(),
+            id: GeneratorIDDefinition {
+                id_type: Type::Int64,
+                generator: GeneratorConfig::Null,
+            },
+            fields: vec![GeneratorFieldDefinition {
+                name: "age".to_string(),
+                field_type: Type::Int32,
+                generator: GeneratorConfig::Null,
+                nullable: false,
+                nullable_elements: false,
+                indexed: false,
+                constraints: FieldConstraints::default(),
+            }],
+        };
+        let rest = client.read_rows("users", 2, 2, Some(&users)).await.unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!((rest[0].index, &rest[0].id), (2, &Value::Int64(3)));
+        assert_eq!(rest[0].get_field("age"), Some(&Value::Int32(52)));
+        assert_eq!(
+            rest[0].get_field("name"),
+            Some(&Value::Text("c".to_string()))
+        );
+    }
 }
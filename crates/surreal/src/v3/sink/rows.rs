@@ -1,10 +1,13 @@
 //! Row-level operations for converting and writing Row to SurrealDB v3.
 
-use super::write::{write_record, write_relation};
+use super::write::{write_record, write_relation, write_templated};
 use crate::v3::types::{RecordWithSurrealValues, Relation as SurrealRelation, SurrealValue};
 use anyhow::{bail, Result};
-use std::collections::HashMap;
-use surreal_sync_core::{Relation, Row, Value, ZeroTemporalPolicy};
+use futures::future::try_join_all;
+use std::collections::{BTreeMap, HashMap};
+use surreal_sync_core::{
+    partition_write_lanes_per_table, Relation, Row, Value, WriteTemplate, ZeroTemporalPolicy,
+};
 use surrealdb3::types::{Array, Number, RecordId, RecordIdKey, Value as DbValue};
 use surrealdb3::Surreal;
 
@@ -108,6 +111,122 @@ pub async fn write_rows(
     Ok(())
 }
 
+/// Write a batch of Rows with up to `concurrency` concurrent writers per table.
+///
+/// Each table's rows are split into lanes by record id (see
+/// [`partition_write_lanes`]) and the lanes are written concurrently over the
+/// shared connection. Returns only after every lane has finished, so callers
+/// that advance checkpoints after this call never get ahead of the data.
+pub async fn write_rows_concurrent(
+    surreal: &Surreal<surrealdb3::engine::any::Any>,
+    rows: &[Row],
+    zero_temporal: ZeroTemporalPolicy,
+    concurrency: usize,
+) -> Result<()> {
+    write_rows_pooled(
+        std::slice::from_ref(surreal),
+        rows,
+        zero_temporal,
+        concurrency,
+    )
+    .await
+}
+
+/// [`write_rows_concurrent`] over several connections: lane `i` of a table
+/// writes through `pool[i % pool.len()]`.
+pub async fn write_rows_pooled(
+    pool: &[Surreal<surrealdb3::engine::any::Any>],
+    rows: &[Row],
+    zero_temporal: ZeroTemporalPolicy,
+    concurrency: usize,
+) -> Result<()> {
+    write_rows_templated(
+        pool,
+        rows,
+        &[],
+        &[],
+        &BTreeMap::new(),
+        zero_temporal,
+        concurrency,
+    )
+    .await
+}
+
+/// [`write_rows_pooled`], writing the rows of tables with a [`WriteTemplate`]
+/// through their template instead of the built-in upsert. Rows of
+/// `ordered_tables` are written one at a time in batch order, whatever the
+/// `concurrency`; tables in `table_concurrency` use their own concurrency
+/// instead of `concurrency`.
+pub async fn write_rows_templated(
+    pool: &[Surreal<surrealdb3::engine::any::Any>],
+    rows: &[Row],
+    templates: &[WriteTemplate],
+    ordered_tables: &[String],
+    table_concurrency: &BTreeMap<String, usize>,
+    zero_temporal: ZeroTemporalPolicy,
+    concurrency: usize,
+) -> Result<()> {
+    let Some(first) = pool.first() else {
+        bail!("Cannot write rows without a SurrealDB connection");
+    };
+    if concurrency <= 1 && table_concurrency.values().all(|&n| n <= 1) {
+        for row in rows {
+            write_row(first, row, templates, zero_temporal).await?;
+        }
+        return Ok(());
+    }
+    for table_lanes in partition_write_lanes_per_table(
+        rows,
+        |table| lanes_for_table(table, ordered_tables, table_concurrency, concurrency),
+        |r| r.table.as_str(),
+        |r| &r.id,
+    ) {
+        try_join_all(
+            table_lanes
+                .into_iter()
+                .enumerate()
+                .map(|(i, lane)| async move {
+                    let surreal = &pool[i % pool.len()];
+                    for row in lane {
+                        write_row(surreal, row, templates, zero_temporal).await?;
+                    }
+                    Ok::<_, anyhow::Error>(())
+                }),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Write lanes for `table`: one for ordered tables, else its entry in
+/// `table_concurrency`, else `concurrency`.
+fn lanes_for_table(
+    table: &str,
+    ordered_tables: &[String],
+    table_concurrency: &BTreeMap<String, usize>,
+    concurrency: usize,
+) -> usize {
+    if ordered_tables.iter().any(|t| t == table) {
+        1
+    } else {
+        table_concurrency.get(table).copied().unwrap_or(concurrency)
+    }
+}
+
+/// Write one row, through its table's template if it has one.
+async fn write_row(
+    surreal: &Surreal<surrealdb3::engine::any::Any>,
+    row: &Row,
+    templates: &[WriteTemplate],
+    zero_temporal: ZeroTemporalPolicy,
+) -> Result<()> {
+    let record = row_to_surreal_record(row, zero_temporal)?;
+    match templates.iter().find(|t| t.table == row.table) {
+        Some(template) => write_templated(surreal, template, &record).await,
+        None => write_record(surreal, &record).await,
+    }
+}
+
 /// Convert Relation to SurrealDB Relation.
 ///
 /// Returns an error if any ID type is not supported.
@@ -161,10 +280,87 @@ pub async fn write_relations(
     Ok(())
 }
 
+/// Write a batch of Relations with up to `concurrency` concurrent writers per
+/// relation table. See [`write_rows_concurrent`].
+pub async fn write_relations_concurrent(
+    surreal: &Surreal<surrealdb3::engine::any::Any>,
+    relations: &[Relation],
+    zero_temporal: ZeroTemporalPolicy,
+    concurrency: usize,
+) -> Result<()> {
+    write_relations_pooled(
+        std::slice::from_ref(surreal),
+        relations,
+        zero_temporal,
+        concurrency,
+    )
+    .await
+}
+
+/// [`write_relations_concurrent`] over several connections. See
+/// [`write_rows_pooled`].
+pub async fn write_relations_pooled(
+    pool: &[Surreal<surrealdb3::engine::any::Any>],
+    relations: &[Relation],
+    zero_temporal: ZeroTemporalPolicy,
+    concurrency: usize,
+) -> Result<()> {
+    write_relations_ordered(
+        pool,
+        relations,
+        &[],
+        &BTreeMap::new(),
+        zero_temporal,
+        concurrency,
+    )
+    .await
+}
+
+/// [`write_relations_pooled`], writing the relations of `ordered_tables` one
+/// at a time in batch order, whatever the `concurrency`; tables in
+/// `table_concurrency` use their own concurrency instead of `concurrency`.
+pub async fn write_relations_ordered(
+    pool: &[Surreal<surrealdb3::engine::any::Any>],
+    relations: &[Relation],
+    ordered_tables: &[String],
+    table_concurrency: &BTreeMap<String, usize>,
+    zero_temporal: ZeroTemporalPolicy,
+    concurrency: usize,
+) -> Result<()> {
+    let Some(first) = pool.first() else {
+        bail!("Cannot write relations without a SurrealDB connection");
+    };
+    if concurrency <= 1 && table_concurrency.values().all(|&n| n <= 1) {
+        return write_relations(first, relations, zero_temporal).await;
+    }
+    for table_lanes in partition_write_lanes_per_table(
+        relations,
+        |table| lanes_for_table(table, ordered_tables, table_concurrency, concurrency),
+        |r| r.relation_type.as_str(),
+        |r| &r.id,
+    ) {
+        try_join_all(
+            table_lanes
+                .into_iter()
+                .enumerate()
+                .map(|(i, lane)| async move {
+                    let surreal = &pool[i % pool.len()];
+                    for rel in lane {
+                        let surreal_rel = relation_to_surreal_relation(rel, zero_temporal)?;
+                        write_relation(surreal, &surreal_rel).await?;
+                    }
+                    Ok::<_, anyhow::Error>(())
+                }),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use surreal_sync_core::Value;
+    use surreal_sync_core::{FieldMap, Value};
 
     #[test]
     fn test_value_to_surreal_id_text() {
@@ -270,4 +466,290 @@ mod tests {
         let err = value_to_surreal_id(&value).unwrap_err();
         assert!(err.to_string().contains("Nested arrays"));
     }
+
+    #[tokio::test]
+    async fn test_write_rows_concurrent_lands_every_record() {
+        let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+        surreal.use_ns("test").use_db("test").await.unwrap();
+
+        // 100 records per table, each written twice; the later write must win.
+        let rows: Vec<Row> = (0..400u64)
+            .map(|i| {
+                let table = if i % 2 == 0 { "items" } else { "others" };
+                let mut fields = FieldMap::new();
+                fields.insert("seq".to_string(), Value::Int64(i as i64));
+                Row::new(
+                    table.to_string(),
+                    i,
+                    Value::Int64(((i / 2) % 100) as i64),
+                    fields,
+                )
+            })
+            .collect();
+        write_rows_concurrent(&surreal, &rows, ZeroTemporalPolicy::default(), 8)
+            .await
+            .unwrap();
+
+        for table in ["items", "others"] {
+            let count: Option<i64> = surreal
+                .query(format!("RETURN count(SELECT * FROM {table})"))
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert_eq!(count, Some(100), "missing records in {table}");
+            let min_seq: Option<i64> = surreal
+                .query(format!("RETURN math::min(SELECT VALUE seq FROM {table})"))
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert!(
+                min_seq.unwrap() >= 200,
+                "an earlier write overwrote a later one in {table}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_rows_concurrent_matches_sequential() {
+        // 150 records in each of 3 tables, each written twice, interleaved.
+        let tables = ["items", "others", "extras"];
+        let rows: Vec<Row> = (0..900u64)
+            .map(|i| {
+                let mut fields = FieldMap::new();
+                fields.insert("seq".to_string(), Value::Int64(i as i64));
+                Row::new(
+                    tables[(i % 3) as usize].to_string(),
+                    i,
+                    Value::Int64(((i / 3) % 150) as i64),
+                    fields,
+                )
+            })
+            .collect();
+
+        let mut results = Vec::new();
+        for concurrency in [1, 8] {
+            let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+            surreal.use_ns("test").use_db("test").await.unwrap();
+            write_rows_concurrent(&surreal, &rows, ZeroTemporalPolicy::default(), concurrency)
+                .await
+                .unwrap();
+
+            let mut per_table = Vec::new();
+            for table in tables {
+                let mut seqs: Vec<i64> = surreal
+                    .query(format!("SELECT VALUE seq FROM {table}"))
+                    .await
+                    .unwrap()
+                    .take(0)
+                    .unwrap();
+                seqs.sort_unstable();
+                let count: Option<usize> = surreal
+                    .query(format!("RETURN count(SELECT * FROM {table})"))
+                    .await
+                    .unwrap()
+                    .take(0)
+                    .unwrap();
+                assert_eq!(
+                    count,
+                    Some(150),
+                    "{table} at write_concurrency={concurrency}"
+                );
+                per_table.push(seqs);
+            }
+            results.push(per_table);
+        }
+
+        assert!(results[0].iter().all(|seqs| seqs.len() == 150));
+        assert_eq!(results[0], results[1], "concurrent writes diverged");
+    }
+
+    /// Throughput of sequential vs. concurrent writes against an in-memory
+    /// SurrealDB. Run with
+    /// `cargo test -p surreal-sync-surreal --all-features --release --lib -- --ignored --nocapture write_concurrency_throughput`;
+    /// recorded results are in `docs/sync-pipeline.md`.
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "throughput measurement, not a correctness test"]
+    async fn write_concurrency_throughput() {
+        let rows: Vec<Row> = (0..5_000u64)
+            .map(|i| {
+                let mut fields = FieldMap::new();
+                fields.insert("seq".to_string(), Value::Int64(i as i64));
+                fields.insert("name".to_string(), Value::Text(format!("item-{i}")));
+                Row::new("items".to_string(), i, Value::Int64(i as i64), fields)
+            })
+            .collect();
+
+        for concurrency in [1, 2, 4, 8, 16] {
+            let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+            surreal.use_ns("bench").use_db("bench").await.unwrap();
+            let started = std::time::Instant::now();
+            write_rows_concurrent(&surreal, &rows, ZeroTemporalPolicy::default(), concurrency)
+                .await
+                .unwrap();
+            let elapsed = started.elapsed();
+            eprintln!(
+                "write_concurrency={concurrency:>2}: {} rows in {elapsed:?} ({:.0} rows/s)",
+                rows.len(),
+                rows.len() as f64 / elapsed.as_secs_f64()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_rows_pooled_spreads_lanes_over_connections() {
+        // Separate in-memory datastores stand in for separate connections, so
+        // each one holds exactly the records written through it.
+        let mut pool = Vec::new();
+        for _ in 0..3 {
+            let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+            surreal.use_ns("test").use_db("test").await.unwrap();
+            pool.push(surreal);
+        }
+        let rows: Vec<Row> = (0..300u64)
+            .map(|i| Row::new("items", i, Value::Int64(i as i64), FieldMap::new()))
+            .collect();
+        write_rows_pooled(&pool, &rows, ZeroTemporalPolicy::default(), 6)
+            .await
+            .unwrap();
+
+        let mut total = 0;
+        for surreal in &pool {
+            let count: Option<i64> = surreal
+                .query("RETURN count(SELECT * FROM items)")
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            let count = count.unwrap();
+            assert!(count > 0, "a pooled connection wrote nothing");
+            total += count;
+        }
+        assert_eq!(total, 300);
+    }
+
+    #[tokio::test]
+    async fn test_write_rows_ordered_table_keeps_source_order() {
+        // Separate in-memory datastores stand in for separate connections.
+        // The first one logs every `orders` write in the order it happened.
+        let mut pool = Vec::new();
+        for _ in 0..3 {
+            let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+            surreal.use_ns("test").use_db("test").await.unwrap();
+            surreal.query("DEFINE TABLE orders").await.unwrap();
+            pool.push(surreal);
+        }
+        pool[0]
+            .query(
+                "DEFINE EVENT log_write ON TABLE orders THEN {
+                     UPSERT counter:orders SET n += 1;
+                     CREATE order_log CONTENT {
+                         n: (SELECT VALUE n FROM ONLY counter:orders),
+                         order: record::id($after.id),
+                     };
+                 };",
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+
+        // Order ids in scrambled (source) order, interleaved with items.
+        let order_ids: Vec<i64> = (0..50).map(|i| (i * 7) % 50).collect();
+        let rows: Vec<Row> = order_ids
+            .iter()
+            .zip(0..)
+            .flat_map(|(&id, i)| {
+                [
+                    Row::new("orders", 2 * i, Value::Int64(id), FieldMap::new()),
+                    Row::new("items", 2 * i + 1, Value::Int64(i as i64), FieldMap::new()),
+                ]
+            })
+            .collect();
+        write_rows_templated(
+            &pool,
+            &rows,
+            &[],
+            &["orders".to_string()],
+            &BTreeMap::new(),
+            ZeroTemporalPolicy::default(),
+            6,
+        )
+        .await
+        .unwrap();
+
+        let logged: Vec<i64> = pool[0]
+            .query("SELECT VALUE order FROM (SELECT n, order FROM order_log ORDER BY n)")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        assert_eq!(logged, order_ids, "orders were not written in source order");
+
+        // `items` still fans out over every connection; `orders` uses one.
+        for (i, surreal) in pool.iter().enumerate() {
+            let items: Option<i64> = surreal
+                .query("RETURN count(SELECT * FROM items)")
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert!(items.unwrap() > 0, "connection {i} wrote no items");
+            let orders: Option<i64> = surreal
+                .query("RETURN count(SELECT * FROM orders)")
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert_eq!(orders.unwrap(), if i == 0 { 50 } else { 0 });
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_rows_table_concurrency_overrides_global() {
+        // Global concurrency 1, but `items` is overridden to 6 lanes: only
+        // its rows fan out over the pool; `orders` stays on one connection.
+        let mut pool = Vec::new();
+        for _ in 0..3 {
+            let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+            surreal.use_ns("test").use_db("test").await.unwrap();
+            surreal.query("DEFINE TABLE orders").await.unwrap();
+            pool.push(surreal);
+        }
+        let rows: Vec<Row> = (0..300u64)
+            .map(|i| {
+                let table = if i % 2 == 0 { "items" } else { "orders" };
+                Row::new(table, i, Value::Int64(i as i64), FieldMap::new())
+            })
+            .collect();
+        write_rows_templated(
+            &pool,
+            &rows,
+            &[],
+            &[],
+            &BTreeMap::from([("items".to_string(), 6)]),
+            ZeroTemporalPolicy::default(),
+            1,
+        )
+        .await
+        .unwrap();
+
+        for (i, surreal) in pool.iter().enumerate() {
+            let items: Option<i64> = surreal
+                .query("RETURN count(SELECT * FROM items)")
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert!(items.unwrap() > 0, "connection {i} wrote no items");
+            let orders: Option<i64> = surreal
+                .query("RETURN count(SELECT * FROM orders)")
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            assert_eq!(orders.unwrap(), if i == 0 { 150 } else { 0 });
+        }
+    }
 }
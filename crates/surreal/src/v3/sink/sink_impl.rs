@@ -1,13 +1,35 @@
 //! Implementation of SurrealSink trait for SurrealDB v3.
 
-use anyhow::Result;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
 use surreal_sync_core::SurrealSink;
-use surreal_sync_core::{Change, Relation, RelationChange, Row, ZeroTemporalPolicy};
+use surreal_sync_core::{
+    Change, ChangeOp, CircuitBreakerConfig, ConflictPolicy, LargeFieldConfig, MergeConfig,
+    Relation, RelationChange, Row, SoftDeleteConfig, ValidationFailure, ValidationQuery, Value,
+    WriteEstimate, WriteRetryConfig, WriteTemplate, ZeroTemporalPolicy,
+};
 use surrealdb3::engine::any::Any;
 use surrealdb3::Surreal;
 
-use super::rows::{write_relations, write_rows};
-use super::write::{apply_change, apply_relation_change};
+use crate::circuit::CircuitBreaker;
+use crate::large_field::{
+    offload_change, offload_relation_change, offload_relations, offload_rows,
+};
+
+use super::connect::SurrealOpts;
+use super::pool::ConnectionPool;
+use super::rows::{
+    relation_to_surreal_relation, row_to_surreal_record, value_to_surreal_id, write_relations,
+    write_relations_ordered, write_rows_templated,
+};
+use super::write::{
+    apply_change_with_version, apply_relation_change, apply_templated_change, change_to_record,
+    merge_change, patch_change, relate_payload_len, run_validation, scan_field, soft_delete_change,
+    truncate_table, upsert_payload_len, write_change_checked, write_dead_letter,
+};
 
 /// Wrapper around Surreal<Any> that implements SurrealSink.
 ///
@@ -17,6 +39,21 @@ use super::write::{apply_change, apply_relation_change};
 pub struct Surreal3Sink {
     client: Surreal<Any>,
     zero_temporal: ZeroTemporalPolicy,
+    write_concurrency: usize,
+    table_write_concurrency: BTreeMap<String, usize>,
+    ordered_tables: Vec<String>,
+    pool: Option<ConnectionPool>,
+    breaker: CircuitBreaker,
+    version_field: Option<String>,
+    soft_delete: Option<SoftDeleteConfig>,
+    merge: Option<MergeConfig>,
+    skip_unchanged_fields: bool,
+    error_table: Option<String>,
+    conflict_policy: Option<ConflictPolicy>,
+    large_fields: Option<LargeFieldConfig>,
+    write_templates: Vec<WriteTemplate>,
+    dry_run: bool,
+    estimate: Mutex<WriteEstimate>,
 }
 
 impl Surreal3Sink {
@@ -33,9 +70,343 @@ impl Surreal3Sink {
         Self {
             client,
             zero_temporal,
+            write_concurrency: 1,
+            table_write_concurrency: BTreeMap::new(),
+            ordered_tables: Vec::new(),
+            pool: None,
+            breaker: CircuitBreaker::default(),
+            version_field: None,
+            soft_delete: None,
+            merge: None,
+            skip_unchanged_fields: false,
+            error_table: None,
+            conflict_policy: None,
+            large_fields: None,
+            write_templates: Vec::new(),
+            dry_run: false,
+            estimate: Mutex::new(WriteEstimate::default()),
         }
     }
 
+    /// Set the number of concurrent write tasks per table used by
+    /// `write_rows` / `write_relations` (values below 1 are treated as 1).
+    ///
+    /// Batches still complete as a whole before the call returns, so
+    /// checkpoints advanced afterwards only cover durably written records.
+    pub fn with_write_concurrency(mut self, write_concurrency: usize) -> Self {
+        self.write_concurrency = write_concurrency.max(1);
+        self
+    }
+
+    /// Override the write concurrency for individual tables (values below 1
+    /// are treated as 1). Tables not in `concurrency` use
+    /// [`with_write_concurrency`](Self::with_write_concurrency); ordered
+    /// tables stay sequential whatever their entry.
+    pub fn with_table_write_concurrency(mut self, concurrency: BTreeMap<String, usize>) -> Self {
+        self.table_write_concurrency = concurrency
+            .into_iter()
+            .map(|(table, n)| (table, n.max(1)))
+            .collect();
+        self
+    }
+
+    /// Write the records and relations of `tables` strictly in batch order
+    /// through a single write stream, for tables whose events or consumers
+    /// depend on insertion order. Write concurrency does not apply to them;
+    /// other tables keep their concurrent lanes.
+    pub fn with_ordered_tables(mut self, tables: Vec<String>) -> Self {
+        self.ordered_tables = tables;
+        self
+    }
+
+    /// Spread concurrent write lanes over up to `size` connections, this
+    /// sink's client included (1, the default, shares the one client). The
+    /// extra connections are opened with `opts` on the first concurrent
+    /// write, on the client's namespace and database.
+    pub fn with_connection_pool(mut self, opts: SurrealOpts, size: usize) -> Self {
+        self.pool = (size > 1).then(|| ConnectionPool::new(opts, size));
+        self
+    }
+
+    /// Retry failed writes behind a circuit breaker (see
+    /// [`CircuitBreakerConfig`]); the default config disables it.
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.breaker = CircuitBreaker::new(config).with_retry(*self.breaker.retry());
+        self
+    }
+
+    /// Attempts and backoff for writes that fail transiently (see
+    /// [`WriteRetryConfig`]), usually [`SurrealOpts::write_retry`].
+    pub fn with_write_retry(mut self, retry: WriteRetryConfig) -> Self {
+        self.breaker = CircuitBreaker::new(*self.breaker.config()).with_retry(retry);
+        self
+    }
+
+    /// Apply incremental creates/updates only when their `field` value is
+    /// newer than the stored record's (last-write-wins; stale changes are
+    /// skipped). `None` (default) applies every change.
+    pub fn with_version_field(mut self, field: Option<String>) -> Self {
+        self.version_field = field;
+        self
+    }
+
+    /// Turn incremental deletes into soft deletes (see [`SoftDeleteConfig`]).
+    /// `None` (default) removes deleted records.
+    pub fn with_soft_delete(mut self, soft_delete: Option<SoftDeleteConfig>) -> Self {
+        self.soft_delete = soft_delete;
+        self
+    }
+
+    /// Deep-merge incremental creates/updates into stored records (see
+    /// [`MergeConfig`]). `None` (default) overwrites them.
+    pub fn with_merge(mut self, merge: Option<MergeConfig>) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Apply incremental updates that know which fields they changed (see
+    /// [`Change::changed_fields`]) as patches setting only those fields.
+    /// Other updates, and every update in merge mode, write the whole record.
+    pub fn with_skip_unchanged_fields(mut self, skip_unchanged_fields: bool) -> Self {
+        self.skip_unchanged_fields = skip_unchanged_fields;
+        self
+    }
+
+    /// Store records whose write still fails (after circuit-breaker retries)
+    /// in the dead-letter `error_table` and carry on. A failed batch is
+    /// retried record by record so only the failing records are stored.
+    /// `None` (default) fails the write instead.
+    pub fn with_error_table(mut self, error_table: Option<String>) -> Self {
+        self.error_table = error_table;
+        self
+    }
+
+    /// Check incremental creates/updates for records modified outside
+    /// surreal-sync since it last wrote them and handle conflicts per
+    /// `policy` (see [`ConflictPolicy`]). Checked writes stamp
+    /// [`SYNC_META_FIELD`](surreal_sync_core::SYNC_META_FIELD) and take
+    /// precedence over version-field, merge and patch writes. `None`
+    /// (default) applies every change unchecked.
+    pub fn with_conflict_policy(mut self, policy: Option<ConflictPolicy>) -> Self {
+        self.conflict_policy = policy;
+        self
+    }
+
+    /// Write string / byte field values above a size threshold to files and
+    /// store a reference in the record instead (see [`LargeFieldConfig`]).
+    /// `None` (default) writes every value to SurrealDB.
+    pub fn with_large_fields(mut self, large_fields: Option<LargeFieldConfig>) -> Self {
+        self.large_fields = large_fields;
+        self
+    }
+
+    /// Write the full-sync rows and incremental creates/updates of each
+    /// template's table with its SurrealQL statement (see [`WriteTemplate`])
+    /// instead of the built-in upsert. Templated writes take precedence over
+    /// conflict-checked, version-field, merge and patch writes.
+    pub fn with_write_templates(mut self, write_templates: Vec<WriteTemplate>) -> Self {
+        self.write_templates = write_templates;
+        self
+    }
+
+    /// In dry-run mode every write is converted and serialized exactly as a
+    /// real one, then counted into [`Self::write_estimate`] instead of being
+    /// sent to SurrealDB.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Batches, queries, records and payload bytes this sink would have
+    /// written so far (dry-run mode only; empty otherwise).
+    pub fn write_estimate(&self) -> WriteEstimate {
+        self.estimate.lock().unwrap().clone()
+    }
+
+    /// Run post-sync validation queries in order, returning the ones that
+    /// found violating rows.
+    pub async fn run_validations(
+        &self,
+        validations: &[ValidationQuery],
+    ) -> Result<Vec<ValidationFailure>> {
+        let mut failures = Vec::new();
+        for validation in validations {
+            if let Some(failure) = run_validation(&self.client, validation).await? {
+                failures.push(failure);
+            }
+        }
+        Ok(failures)
+    }
+
+    /// Patch the changed fields of `change`, writing the whole record when
+    /// it does not exist yet.
+    async fn patch_or_write(&self, change: &Change, changed_fields: &[String]) -> Result<()> {
+        let version_field = self.version_field.as_deref();
+        if !patch_change(
+            &self.client,
+            change,
+            changed_fields,
+            self.zero_temporal,
+            version_field,
+        )
+        .await?
+        {
+            apply_change_with_version(&self.client, change, self.zero_temporal, version_field)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Write a create/update `change` with conflict detection, handling a
+    /// conflict per `policy`.
+    async fn write_checked(&self, change: &Change, policy: ConflictPolicy) -> Result<()> {
+        let overwrite = policy == ConflictPolicy::Overwrite;
+        if !write_change_checked(&self.client, change, self.zero_temporal, overwrite).await? {
+            return Ok(());
+        }
+        let target = format!("{}:{:?}", change.table, change.id);
+        match policy {
+            ConflictPolicy::Overwrite => {
+                tracing::warn!("Overwrote {target}, which was modified outside surreal-sync");
+                Ok(())
+            }
+            ConflictPolicy::Skip => {
+                tracing::warn!(
+                    "Skipped change to {target}, which was modified outside surreal-sync"
+                );
+                Ok(())
+            }
+            ConflictPolicy::ErrorTable => {
+                let error_table = self.error_table.as_deref().ok_or_else(|| {
+                    anyhow!("conflict on {target}: the error-table conflict policy needs an error table")
+                })?;
+                let error = anyhow!(
+                    "conflict: {target} was modified outside surreal-sync since it was last synced"
+                );
+                self.dead_letter(error_table, &change.table, None, change, &error)
+                    .await
+            }
+        }
+    }
+
+    /// Retry the records of a failed batch one by one, storing those that
+    /// still fail in `error_table`.
+    async fn dead_letter_rows(&self, error_table: &str, rows: &[Row]) -> Result<()> {
+        for row in rows {
+            let result = write_rows_templated(
+                std::slice::from_ref(&self.client),
+                std::slice::from_ref(row),
+                &self.write_templates,
+                &self.ordered_tables,
+                &BTreeMap::new(),
+                self.zero_temporal,
+                1,
+            )
+            .await;
+            if let Err(e) = result {
+                self.dead_letter(error_table, &row.table, Some(row.index), row, &e)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn dead_letter_relations(&self, error_table: &str, relations: &[Relation]) -> Result<()> {
+        for rel in relations {
+            let result =
+                write_relations(&self.client, std::slice::from_ref(rel), self.zero_temporal).await;
+            if let Err(e) = result {
+                self.dead_letter(error_table, &rel.relation_type, None, rel, &e)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Store `item` in the dead-letter table when `result` is a failure and
+    /// an error table is configured; otherwise pass `result` through.
+    async fn or_dead_letter<T: Serialize>(
+        &self,
+        table: &str,
+        item: &T,
+        result: Result<()>,
+    ) -> Result<()> {
+        match (&self.error_table, result) {
+            (Some(error_table), Err(e)) => {
+                self.dead_letter(error_table, table, None, item, &e).await
+            }
+            (_, result) => result,
+        }
+    }
+
+    async fn dead_letter<T: Serialize>(
+        &self,
+        error_table: &str,
+        table: &str,
+        position: Option<u64>,
+        item: &T,
+        error: &anyhow::Error,
+    ) -> Result<()> {
+        tracing::warn!("Storing failed write to {table} in {error_table}: {error:#}");
+        let data = serde_json::to_string(item)?;
+        write_dead_letter(&self.client, error_table, table, position, data, error)
+            .await
+            .with_context(|| format!("failed to store failed write in {error_table}: {error:#}"))
+    }
+
+    fn estimate_rows(&self, rows: &[Row]) -> Result<()> {
+        let mut estimate = self.estimate.lock().unwrap();
+        estimate.add_batch(rows.iter().map(|r| r.table.as_str()));
+        for row in rows {
+            let record = row_to_surreal_record(row, self.zero_temporal)?;
+            estimate.add_record(&row.table, upsert_payload_len(&record));
+        }
+        Ok(())
+    }
+
+    fn estimate_relations(&self, relations: &[Relation]) -> Result<()> {
+        let mut estimate = self.estimate.lock().unwrap();
+        estimate.add_batch(relations.iter().map(|r| r.relation_type.as_str()));
+        for rel in relations {
+            let surreal_rel = relation_to_surreal_relation(rel, self.zero_temporal)?;
+            estimate.add_record(&rel.relation_type, relate_payload_len(&surreal_rel));
+        }
+        Ok(())
+    }
+
+    fn estimate_change(&self, change: &Change) -> Result<()> {
+        let bytes = match change.operation {
+            ChangeOp::Create | ChangeOp::Update => {
+                let id = surrealdb3::types::RecordId::new(
+                    change.table.as_str(),
+                    value_to_surreal_id(&change.id)?,
+                );
+                upsert_payload_len(&change_to_record(id, change, self.zero_temporal)?)
+            }
+            ChangeOp::Delete => 0,
+        };
+        self.estimate
+            .lock()
+            .unwrap()
+            .add_record(&change.table, bytes);
+        Ok(())
+    }
+
+    fn estimate_relation_change(&self, change: &RelationChange) -> Result<()> {
+        let rel = &change.relation;
+        let bytes = match change.operation {
+            ChangeOp::Create | ChangeOp::Update => {
+                relate_payload_len(&relation_to_surreal_relation(rel, self.zero_temporal)?)
+            }
+            ChangeOp::Delete => 0,
+        };
+        self.estimate
+            .lock()
+            .unwrap()
+            .add_record(&rel.relation_type, bytes);
+        Ok(())
+    }
+
     /// Get a reference to the underlying Surreal client.
     pub fn inner(&self) -> &Surreal<Any> {
         &self.client
@@ -55,24 +426,265 @@ impl Surreal3Sink {
     pub fn zero_temporal_policy(&self) -> ZeroTemporalPolicy {
         self.zero_temporal
     }
+
+    /// Concurrent write tasks per table.
+    pub fn write_concurrency(&self) -> usize {
+        self.write_concurrency
+    }
+
+    /// Per-table overrides of [`write_concurrency`](Self::write_concurrency).
+    pub fn table_write_concurrency(&self) -> &BTreeMap<String, usize> {
+        &self.table_write_concurrency
+    }
+
+    /// Tables written strictly in batch order.
+    pub fn ordered_tables(&self) -> &[String] {
+        &self.ordered_tables
+    }
+
+    /// Connections concurrent writes are spread over.
+    pub fn connection_pool_size(&self) -> usize {
+        self.pool.as_ref().map_or(1, ConnectionPool::size)
+    }
+
+    /// Whether any table is written with more than one lane.
+    fn writes_concurrently(&self) -> bool {
+        self.write_concurrency > 1 || self.table_write_concurrency.values().any(|&n| n > 1)
+    }
+
+    /// Connections for the write lanes of one batch.
+    async fn write_pool(&self) -> Result<&[Surreal<Any>]> {
+        match &self.pool {
+            Some(pool) if self.writes_concurrently() => pool.clients(&self.client).await,
+            _ => Ok(std::slice::from_ref(&self.client)),
+        }
+    }
+
+    /// Field compared by conditional incremental writes, if enabled.
+    pub fn version_field(&self) -> Option<&str> {
+        self.version_field.as_deref()
+    }
+
+    /// Soft-delete mode applied to incremental deletes, if enabled.
+    pub fn soft_delete(&self) -> Option<&SoftDeleteConfig> {
+        self.soft_delete.as_ref()
+    }
+
+    /// Deep-merge mode applied to incremental creates/updates, if enabled.
+    pub fn merge(&self) -> Option<&MergeConfig> {
+        self.merge.as_ref()
+    }
+
+    /// Whether updates with known changed fields are applied as patches.
+    pub fn skip_unchanged_fields(&self) -> bool {
+        self.skip_unchanged_fields
+    }
+
+    /// Dead-letter table for failed writes, if enabled.
+    pub fn error_table(&self) -> Option<&str> {
+        self.error_table.as_deref()
+    }
+
+    /// Conflict policy for incremental creates/updates, if detection is
+    /// enabled.
+    pub fn conflict_policy(&self) -> Option<ConflictPolicy> {
+        self.conflict_policy
+    }
+
+    /// Large-field offload settings, if enabled.
+    pub fn large_fields(&self) -> Option<&LargeFieldConfig> {
+        self.large_fields.as_ref()
+    }
+
+    /// Templates writing their tables' records.
+    pub fn write_templates(&self) -> &[WriteTemplate] {
+        &self.write_templates
+    }
+
+    fn write_template(&self, table: &str) -> Option<&WriteTemplate> {
+        self.write_templates.iter().find(|t| t.table == table)
+    }
 }
 
 #[async_trait::async_trait]
 impl SurrealSink for Surreal3Sink {
     async fn write_rows(&self, rows: &[Row]) -> Result<()> {
-        write_rows(&self.client, rows, self.zero_temporal).await
+        let rows = &*offload_rows(rows, self.large_fields.as_ref(), !self.dry_run)?;
+        if self.dry_run {
+            return self.estimate_rows(rows);
+        }
+        let pool = self.write_pool().await?;
+        let result = self
+            .breaker
+            .call(|| {
+                write_rows_templated(
+                    pool,
+                    rows,
+                    &self.write_templates,
+                    &self.ordered_tables,
+                    &self.table_write_concurrency,
+                    self.zero_temporal,
+                    self.write_concurrency,
+                )
+            })
+            .await;
+        match (&self.error_table, result) {
+            (Some(error_table), Err(e)) => {
+                tracing::warn!(
+                    "Batch of {} rows failed, retrying one by one: {e:#}",
+                    rows.len()
+                );
+                self.dead_letter_rows(error_table, rows).await
+            }
+            (_, result) => result,
+        }
     }
 
     async fn write_relations(&self, relations: &[Relation]) -> Result<()> {
-        write_relations(&self.client, relations, self.zero_temporal).await
+        let relations = &*offload_relations(relations, self.large_fields.as_ref(), !self.dry_run)?;
+        if self.dry_run {
+            return self.estimate_relations(relations);
+        }
+        let pool = self.write_pool().await?;
+        let result = self
+            .breaker
+            .call(|| {
+                write_relations_ordered(
+                    pool,
+                    relations,
+                    &self.ordered_tables,
+                    &self.table_write_concurrency,
+                    self.zero_temporal,
+                    self.write_concurrency,
+                )
+            })
+            .await;
+        match (&self.error_table, result) {
+            (Some(error_table), Err(e)) => {
+                tracing::warn!(
+                    "Batch of {} relations failed, retrying one by one: {e:#}",
+                    relations.len()
+                );
+                self.dead_letter_relations(error_table, relations).await
+            }
+            (_, result) => result,
+        }
     }
 
     async fn apply_change(&self, change: &Change) -> Result<()> {
-        apply_change(&self.client, change, self.zero_temporal).await
+        let change = &*offload_change(change, self.large_fields.as_ref(), !self.dry_run)?;
+        if self.dry_run {
+            return self.estimate_change(change);
+        }
+        if let (Some(template), ChangeOp::Create | ChangeOp::Update) =
+            (self.write_template(&change.table), change.operation)
+        {
+            let result = self
+                .breaker
+                .call(|| apply_templated_change(&self.client, change, template, self.zero_temporal))
+                .await;
+            return self.or_dead_letter(&change.table, change, result).await;
+        }
+        if let (Some(policy), ChangeOp::Create | ChangeOp::Update) =
+            (self.conflict_policy, change.operation)
+        {
+            let result = self
+                .breaker
+                .call(|| self.write_checked(change, policy))
+                .await;
+            return self.or_dead_letter(&change.table, change, result).await;
+        }
+        let changed_fields = change
+            .changed_fields
+            .as_deref()
+            .filter(|_| self.skip_unchanged_fields);
+        let result = match (
+            &self.soft_delete,
+            &self.merge,
+            change.operation,
+            changed_fields,
+        ) {
+            (Some(soft_delete), _, ChangeOp::Delete, _) => {
+                self.breaker
+                    .call(|| soft_delete_change(&self.client, change, soft_delete))
+                    .await
+            }
+            (_, Some(merge), ChangeOp::Create | ChangeOp::Update, _) => {
+                self.breaker
+                    .call(|| {
+                        merge_change(
+                            &self.client,
+                            change,
+                            self.zero_temporal,
+                            merge,
+                            self.version_field.as_deref(),
+                        )
+                    })
+                    .await
+            }
+            (_, None, ChangeOp::Update, Some(changed_fields)) => {
+                self.breaker
+                    .call(|| self.patch_or_write(change, changed_fields))
+                    .await
+            }
+            (_, None, ChangeOp::Update, None) if change.partial => {
+                let fields: Vec<String> = change
+                    .fields
+                    .iter()
+                    .flatten()
+                    .map(|(k, _)| k.clone())
+                    .collect();
+                self.breaker
+                    .call(|| self.patch_or_write(change, &fields))
+                    .await
+            }
+            _ => {
+                self.breaker
+                    .call(|| {
+                        apply_change_with_version(
+                            &self.client,
+                            change,
+                            self.zero_temporal,
+                            self.version_field.as_deref(),
+                        )
+                    })
+                    .await
+            }
+        };
+        self.or_dead_letter(&change.table, change, result).await
     }
 
     async fn apply_relation_change(&self, change: &RelationChange) -> Result<()> {
-        apply_relation_change(&self.client, change, self.zero_temporal).await
+        let change = &*offload_relation_change(change, self.large_fields.as_ref(), !self.dry_run)?;
+        if self.dry_run {
+            return self.estimate_relation_change(change);
+        }
+        let result = self
+            .breaker
+            .call(|| apply_relation_change(&self.client, change, self.zero_temporal))
+            .await;
+        self.or_dead_letter(&change.relation.relation_type, change, result)
+            .await
+    }
+
+    async fn truncate_table(&self, table: &str) -> Result<()> {
+        if self.dry_run {
+            self.estimate.lock().unwrap().add_query(table);
+            return Ok(());
+        }
+        self.breaker
+            .call(|| truncate_table(&self.client, table))
+            .await
+    }
+
+    async fn scan_field(
+        &self,
+        table: &str,
+        field: &str,
+        after: Option<&Value>,
+        limit: usize,
+    ) -> Result<Vec<(Value, Option<String>)>> {
+        scan_field(&self.client, table, field, after, limit).await
     }
 }
 
@@ -83,12 +695,27 @@ impl surreal_sync_core::SinkConnect for Surreal3Sink {
             surreal_endpoint: config.endpoint.clone(),
             surreal_username: config.username.clone(),
             surreal_password: config.password.clone(),
+            write_retry: config.write_retry,
         };
         let client = super::surreal_connect(&opts, &config.namespace, &config.database).await?;
-        Ok(Self::with_zero_temporal_policy(
-            client,
-            config.zero_temporal,
-        ))
+        Ok(
+            Self::with_zero_temporal_policy(client, config.zero_temporal)
+                .with_write_concurrency(config.write_concurrency)
+                .with_table_write_concurrency(config.table_write_concurrency.clone())
+                .with_ordered_tables(config.ordered_tables.clone())
+                .with_write_retry(opts.write_retry)
+                .with_connection_pool(opts, config.connection_pool_size)
+                .with_circuit_breaker(config.circuit_breaker)
+                .with_version_field(config.version_field.clone())
+                .with_soft_delete(config.soft_delete.clone())
+                .with_merge(config.merge.clone())
+                .with_skip_unchanged_fields(config.skip_unchanged_fields)
+                .with_error_table(config.error_table.clone())
+                .with_conflict_policy(config.conflict_policy)
+                .with_large_fields(config.large_fields.clone())
+                .with_write_templates(config.write_templates.clone())
+                .with_dry_run(config.dry_run),
+        )
     }
 }
 
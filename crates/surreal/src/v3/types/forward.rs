@@ -5,7 +5,7 @@
 use rust_decimal::Decimal;
 use std::collections::BTreeMap;
 use std::str::FromStr;
-use surreal_sync_core::{Type, TypedValue, Value};
+use surreal_sync_core::{parse_iso8601_duration, Type, TypedValue, Value};
 use surrealdb3::types::{Array, Datetime, Number, Object, RecordId, RecordIdKey, Value as DbValue};
 
 /// Wrapper for SurrealDB values.
@@ -157,7 +157,7 @@ impl From<Value> for SurrealValue {
 
             // String types - auto-detect ISO 8601 duration strings
             Value::Char { value, .. } => {
-                if let Some(duration) = try_parse_iso8601_duration(&value) {
+                if let Some(duration) = parse_iso8601_duration(&value) {
                     SurrealValue(DbValue::Duration(surrealdb3::types::Duration::from(
                         duration,
                     )))
@@ -166,7 +166,7 @@ impl From<Value> for SurrealValue {
                 }
             }
             Value::VarChar { value, .. } => {
-                if let Some(duration) = try_parse_iso8601_duration(&value) {
+                if let Some(duration) = parse_iso8601_duration(&value) {
                     SurrealValue(DbValue::Duration(surrealdb3::types::Duration::from(
                         duration,
                     )))
@@ -176,7 +176,7 @@ impl From<Value> for SurrealValue {
             }
             Value::Text(s) => {
                 // Auto-detect ISO 8601 duration strings (PTxxxS format) and convert to Duration
-                if let Some(duration) = try_parse_iso8601_duration(&s) {
+                if let Some(duration) = parse_iso8601_duration(&s) {
                     SurrealValue(DbValue::Duration(surrealdb3::types::Duration::from(
                         duration,
                     )))
@@ -317,27 +317,6 @@ fn json_to_surreal(value: &serde_json::Value) -> DbValue {
     }
 }
 
-/// Try to parse an ISO 8601 duration string (e.g., "PT181S" or "PT181.000000000S").
-/// Returns Some(std::time::Duration) if the string matches the expected format.
-fn try_parse_iso8601_duration(s: &str) -> Option<std::time::Duration> {
-    let trimmed = s.trim();
-    // Only accept "PTxS" or "PTx.xxxxxxxxxS" format
-    if let Some(secs_str) = trimmed.strip_prefix("PT").and_then(|s| s.strip_suffix('S')) {
-        if let Some(dot_pos) = secs_str.find('.') {
-            // Has fractional seconds
-            let secs: u64 = secs_str[..dot_pos].parse().ok()?;
-            let nanos_str = &secs_str[dot_pos + 1..];
-            let nanos: u32 = nanos_str.parse().ok()?;
-            Some(std::time::Duration::new(secs, nanos))
-        } else {
-            let secs: u64 = secs_str.parse().ok()?;
-            Some(std::time::Duration::from_secs(secs))
-        }
-    } else {
-        None
-    }
-}
-
 /// Create a SurrealDB RecordId (record ID).
 ///
 /// Returns an error for unsupported ID types.
@@ -0,0 +1,21 @@
+//! SurrealQL for post-sync [`ValidationQuery`] runs.
+//!
+//! The validation query is wrapped so one round trip returns both the number
+//! of violating rows and a few of them cast to strings, which the v2 and v3
+//! SDKs deserialize alike.
+
+use surreal_sync_core::ValidationQuery;
+
+/// Violating rows kept per failed validation.
+pub(crate) const VALIDATION_SAMPLES: usize = 5;
+
+/// Statements whose results 1 and 2 are the violation count and the sample
+/// rows of `validation`.
+pub(crate) fn validation_statements(validation: &ValidationQuery) -> String {
+    let query = validation.query.trim().trim_end_matches(';');
+    format!(
+        "LET $violations = ({query}); \
+         RETURN array::len($violations); \
+         RETURN array::map(array::slice($violations, 0, {VALIDATION_SAMPLES}), |$row| <string> $row);"
+    )
+}
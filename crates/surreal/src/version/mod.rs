@@ -3,11 +3,21 @@
 //! Utilities to detect the SurrealDB server version by querying the
 //! `/version` HTTP endpoint. This is used to select the appropriate SDK
 //! version (v2 or v3) since they use incompatible WebSocket subprotocols.
+//! When `/version` is not served (e.g. hidden by a proxy), the version is
+//! inferred from the subprotocol the server accepts on `/rpc` instead.
+//!
+//! Detected versions are cached per endpoint for the life of the process.
 
 pub mod testing;
 
-use reqwest::Client;
+use reqwest::header::{
+    CONNECTION, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_PROTOCOL, SEC_WEBSOCKET_VERSION, UPGRADE,
+};
+use reqwest::{Client, StatusCode};
 use semver::Version;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
 
 /// Detected SurrealDB major version.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,7 +39,11 @@ impl std::fmt::Display for SurrealMajorVersion {
 
 /// Auto-detect SurrealDB server version via HTTP GET to /version endpoint.
 ///
-/// Returns the major version (V2 or V3) for SDK selection.
+/// Returns the major version (V2 or V3) for SDK selection. Makes a single
+/// attempt; see [`detect_server_version_with_retries`] for a server that may
+/// still be starting. If `/version` cannot be read, falls back to a
+/// WebSocket handshake offering both SDKs' subprotocols; the one the server
+/// picks gives the version.
 ///
 /// # Arguments
 ///
@@ -45,41 +59,187 @@ impl std::fmt::Display for SurrealMajorVersion {
 /// - "surrealdb-2.4.1" -> V2
 /// - "surrealdb-3.0.1" -> V3
 pub async fn detect_server_version(endpoint: &str) -> anyhow::Result<SurrealMajorVersion> {
-    // Convert ws:// to http:// for version check
+    detect_server_version_with_retries(endpoint, 1, Duration::ZERO).await
+}
+
+/// [`detect_server_version`], retrying while the server is unreachable.
+///
+/// Connection failures, timeouts and 5xx responses are retried up to
+/// `max_attempts` attempts in total, sleeping `backoff` before the first
+/// retry and doubling it before each one after. A 4xx or unparseable
+/// response falls back to the handshake probe at once, as do retryable
+/// failures once the attempts run out. An unsupported version is returned
+/// as an error without retrying or probing.
+pub async fn detect_server_version_with_retries(
+    endpoint: &str,
+    max_attempts: u32,
+    backoff: Duration,
+) -> anyhow::Result<SurrealMajorVersion> {
+    let version_url = version_url(endpoint);
+    if let Some(version) = version_cache().lock().unwrap().get(&version_url) {
+        tracing::debug!("Using cached SurrealDB version {version} for {version_url}");
+        return Ok(*version);
+    }
+
+    tracing::debug!("Detecting SurrealDB version at {version_url}");
+
+    let client = Client::builder().timeout(Duration::from_secs(10)).build()?;
+    let max_attempts = max_attempts.max(1);
+    let mut delay = backoff;
+    let mut attempt = 1;
+    let version_error = loop {
+        match fetch_version(&client, &version_url).await {
+            Ok(version) => {
+                version_cache().lock().unwrap().insert(version_url, version);
+                return Ok(version);
+            }
+            Err(FetchError::Transient(e)) if attempt < max_attempts => {
+                tracing::warn!(
+                    "SurrealDB version check failed (attempt {attempt}/{max_attempts}), retrying in {delay:?}: {e}"
+                );
+                tokio::time::sleep(delay).await;
+                delay = delay.saturating_mul(2);
+                attempt += 1;
+            }
+            Err(FetchError::Transient(e) | FetchError::Unavailable(e)) => break e,
+            Err(FetchError::Unsupported(e)) => return Err(e),
+        }
+    };
+
+    tracing::warn!("{version_error}; probing the WebSocket subprotocol instead");
+    match probe_subprotocol(&client, endpoint).await {
+        Ok(version) => {
+            version_cache().lock().unwrap().insert(version_url, version);
+            Ok(version)
+        }
+        Err(probe_error) => Err(anyhow::anyhow!(
+            "Could not detect the SurrealDB version of '{endpoint}': {version_error}; \
+             WebSocket probe: {probe_error}"
+        )),
+    }
+}
+
+/// `/version` URL of `endpoint` (ws:// and wss:// become http:// and https://).
+fn version_url(endpoint: &str) -> String {
     let http_endpoint = endpoint
         .replace("ws://", "http://")
         .replace("wss://", "https://");
+    format!("{}/version", http_endpoint.trim_end_matches('/'))
+}
 
-    let version_url = format!("{}/version", http_endpoint.trim_end_matches('/'));
-
-    tracing::debug!("Detecting SurrealDB version at {version_url}");
+/// Versions detected so far, by `/version` URL.
+fn version_cache() -> &'static Mutex<HashMap<String, SurrealMajorVersion>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, SurrealMajorVersion>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
 
-    let client = Client::builder()
-        .timeout(std::time::Duration::from_secs(10))
-        .build()?;
+/// A failed `/version` request.
+enum FetchError {
+    /// The server could not be reached or is not serving yet
+    Transient(anyhow::Error),
+    /// The server answered, but not with a version
+    Unavailable(anyhow::Error),
+    /// The server runs a major version this crate does not support
+    Unsupported(anyhow::Error),
+}
 
-    let response = client.get(&version_url).send().await.map_err(|e| {
-        anyhow::anyhow!("Failed to fetch SurrealDB version from '{version_url}': {e}")
+async fn fetch_version(
+    client: &Client,
+    version_url: &str,
+) -> Result<SurrealMajorVersion, FetchError> {
+    let response = client.get(version_url).send().await.map_err(|e| {
+        let error = anyhow::anyhow!("Failed to fetch SurrealDB version from '{version_url}': {e}");
+        if e.is_builder() {
+            FetchError::Unavailable(error)
+        } else {
+            FetchError::Transient(error)
+        }
     })?;
 
-    if !response.status().is_success() {
-        let status = response.status();
-        return Err(anyhow::anyhow!(
-            "SurrealDB version endpoint returned status {status}: {version_url}"
-        ));
+    let status = response.status();
+    if !status.is_success() {
+        let error =
+            anyhow::anyhow!("SurrealDB version endpoint returned status {status}: {version_url}");
+        return Err(if status.is_server_error() {
+            FetchError::Transient(error)
+        } else {
+            FetchError::Unavailable(error)
+        });
     }
 
     let version_string = response.text().await.map_err(|e| {
-        anyhow::anyhow!("Failed to read SurrealDB version response from '{version_url}': {e}")
+        FetchError::Transient(anyhow::anyhow!(
+            "Failed to read SurrealDB version response from '{version_url}': {e}"
+        ))
     })?;
 
-    parse_version_string(&version_string)
+    let version = parse_semver(&version_string).map_err(FetchError::Unavailable)?;
+    major_version(&version).map_err(FetchError::Unsupported)
+}
+
+/// WebSocket subprotocol of the v2 SDK.
+const V2_SUBPROTOCOL: &str = "revision";
+/// WebSocket subprotocol of the v3 SDK.
+const V3_SUBPROTOCOL: &str = "flatbuffers";
+
+/// Infer the major version from the subprotocol `/rpc` negotiates when
+/// offered both the v2 and v3 one.
+///
+/// Only the handshake response is read; the connection is dropped before
+/// any frame is sent.
+async fn probe_subprotocol(client: &Client, endpoint: &str) -> anyhow::Result<SurrealMajorVersion> {
+    let http_endpoint = endpoint
+        .replace("ws://", "http://")
+        .replace("wss://", "https://");
+    let rpc_url = format!("{}/rpc", http_endpoint.trim_end_matches('/'));
+
+    tracing::debug!("Probing SurrealDB WebSocket subprotocol at {rpc_url}");
+
+    let response = client
+        .get(&rpc_url)
+        .header(CONNECTION, "Upgrade")
+        .header(UPGRADE, "websocket")
+        .header(SEC_WEBSOCKET_VERSION, "13")
+        // Nothing is exchanged after the handshake, so the RFC 6455 sample
+        // key does as well as a random one
+        .header(SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")
+        .header(
+            SEC_WEBSOCKET_PROTOCOL,
+            format!("{V2_SUBPROTOCOL}, {V3_SUBPROTOCOL}"),
+        )
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to open a WebSocket to '{rpc_url}': {e}"))?;
+
+    let status = response.status();
+    if status != StatusCode::SWITCHING_PROTOCOLS {
+        anyhow::bail!("WebSocket handshake to '{rpc_url}' returned status {status}");
+    }
+
+    let protocol = response
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim);
+    let version = match protocol {
+        Some(V2_SUBPROTOCOL) => SurrealMajorVersion::V2,
+        Some(V3_SUBPROTOCOL) => SurrealMajorVersion::V3,
+        Some(other) => anyhow::bail!("'{rpc_url}' negotiated unknown subprotocol '{other}'"),
+        None => anyhow::bail!("'{rpc_url}' accepted the WebSocket without a subprotocol"),
+    };
+
+    tracing::info!("Detected SurrealDB server version {version} from subprotocol at {rpc_url}");
+    Ok(version)
 }
 
 /// Parse a SurrealDB version string like "surrealdb-2.4.1" or "surrealdb-3.0.1".
 ///
 /// Returns the major version (V2 or V3).
 pub fn parse_version_string(version_string: &str) -> anyhow::Result<SurrealMajorVersion> {
+    major_version(&parse_semver(version_string)?)
+}
+
+fn parse_semver(version_string: &str) -> anyhow::Result<Version> {
     let version_string = version_string.trim();
 
     tracing::debug!("Parsing SurrealDB version string: '{version_string}'");
@@ -101,6 +261,10 @@ pub fn parse_version_string(version_string: &str) -> anyhow::Result<SurrealMajor
         version.major
     );
 
+    Ok(version)
+}
+
+fn major_version(version: &Version) -> anyhow::Result<SurrealMajorVersion> {
     match version.major {
         2 => Ok(SurrealMajorVersion::V2),
         3 => Ok(SurrealMajorVersion::V3),
@@ -113,6 +277,173 @@ pub fn parse_version_string(version_string: &str) -> anyhow::Result<SurrealMajor
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Answer one connection per entry of `responses`, in order, and count
+    /// the requests. Returns a ws:// endpoint for the server.
+    fn serve(responses: Vec<String>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        std::thread::spawn(move || {
+            for (response, stream) in responses.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (endpoint, hits)
+    }
+
+    fn response(status: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    /// A server without `/version` whose `/rpc` only speaks `subprotocol`.
+    /// Answers `connections` connections.
+    fn serve_websocket_only(subprotocol: &'static str, connections: usize) -> String {
+        serve_websocket(subprotocol, connections, false)
+    }
+
+    /// Like [`serve_websocket_only`], but when `ignore_offer` is set `/rpc`
+    /// upgrades with `subprotocol` whatever the client offered.
+    fn serve_websocket(
+        subprotocol: &'static str,
+        connections: usize,
+        ignore_offer: bool,
+    ) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(connections) {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 1024];
+                let n = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..n]).to_lowercase();
+                let offered = ignore_offer
+                    || request
+                        .lines()
+                        .find_map(|line| line.strip_prefix("sec-websocket-protocol:"))
+                        .is_some_and(|protocols| {
+                            protocols.split(',').any(|p| p.trim() == subprotocol)
+                        });
+                let reply = if !request.starts_with("get /rpc ") {
+                    response("404 Not Found", "")
+                } else if offered {
+                    format!(
+                        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\
+                         Connection: Upgrade\r\n\
+                         Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n\
+                         Sec-WebSocket-Protocol: {subprotocol}\r\n\r\n"
+                    )
+                } else {
+                    response("400 Bad Request", "")
+                };
+                stream.write_all(reply.as_bytes()).unwrap();
+            }
+        });
+        endpoint
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_subprotocol_probe() {
+        let v2 = serve_websocket_only(V2_SUBPROTOCOL, 2);
+        let v3 = serve_websocket_only(V3_SUBPROTOCOL, 2);
+
+        assert_eq!(
+            detect_server_version(&v2).await.unwrap(),
+            SurrealMajorVersion::V2
+        );
+        assert_eq!(
+            detect_server_version(&v3).await.unwrap(),
+            SurrealMajorVersion::V3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_probe_rejects_unknown_subprotocol() {
+        let endpoint = serve_websocket("json", 2, true);
+
+        let err = detect_server_version(&endpoint).await.unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("status 404"), "{message}");
+        assert!(
+            message.contains("negotiated unknown subprotocol 'json'"),
+            "{message}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detected_version_is_cached() {
+        let (endpoint, hits) = serve(vec![response("200 OK", "surrealdb-2.4.1")]);
+
+        let first = detect_server_version(&endpoint).await.unwrap();
+        let second = detect_server_version(&endpoint).await.unwrap();
+
+        assert_eq!(first, SurrealMajorVersion::V2);
+        assert_eq!(second, SurrealMajorVersion::V2);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_server_is_ready() {
+        let (endpoint, hits) = serve(vec![
+            response("503 Service Unavailable", ""),
+            response("503 Service Unavailable", ""),
+            response("200 OK", "surrealdb-3.0.1"),
+        ]);
+
+        let version = detect_server_version_with_retries(&endpoint, 5, Duration::from_millis(5))
+            .await
+            .unwrap();
+
+        assert_eq!(version, SurrealMajorVersion::V3);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let (endpoint, hits) = serve(vec![
+            response("503 Service Unavailable", ""),
+            response("503 Service Unavailable", ""),
+        ]);
+
+        let result =
+            detect_server_version_with_retries(&endpoint, 2, Duration::from_millis(5)).await;
+
+        // The listener is gone by the time of the probe
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("status 503"), "{message}");
+        assert!(message.contains("WebSocket probe"), "{message}");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_version_is_not_retried() {
+        let (endpoint, hits) = serve(vec![
+            response("200 OK", "surrealdb-4.0.0"),
+            response("200 OK", "surrealdb-4.0.0"),
+        ]);
+
+        let result =
+            detect_server_version_with_retries(&endpoint, 5, Duration::from_millis(5)).await;
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unsupported SurrealDB version"));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
 
     #[test]
     fn test_parse_version_v2() {
@@ -0,0 +1,109 @@
+//! Memory bound of large-field offload (`Surreal3Sink::with_large_fields`).
+//!
+//! Runs in its own test binary because it installs a counting global
+//! allocator: the peak number of bytes allocated while a row with a large
+//! field is written must stay far below the field's size when the field is
+//! offloaded, and exceed it when it is not.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use surreal_sync_core::{FieldMap, LargeFieldConfig, Row, SurrealSink, Value};
+use surreal_sync_surreal::Surreal3Sink;
+
+struct CountingAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let now = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(now, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const FIELD_BYTES: usize = 32 * 1024 * 1024;
+
+fn document(id: i64, body: String) -> Row {
+    Row::new(
+        "documents",
+        id as u64,
+        Value::Int64(id),
+        FieldMap::from([
+            ("title".to_string(), Value::Text(format!("doc {id}"))),
+            ("body".to_string(), Value::Text(body)),
+        ]),
+    )
+}
+
+/// Bytes allocated on top of what was live before `write_rows` started.
+async fn peak_write_allocation(sink: &Surreal3Sink, row: Row) -> usize {
+    let rows = [row];
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    sink.write_rows(&rows).await.unwrap();
+    PEAK.load(Ordering::SeqCst) - before
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn large_field_sync_allocates_far_less_than_the_field() {
+    let dir = std::env::temp_dir().join(format!(
+        "surreal-sync-large-field-memory-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let surreal = surrealdb3::engine::any::connect("mem://").await.unwrap();
+    surreal.use_ns("test").use_db("test").await.unwrap();
+    let offloading = Surreal3Sink::new(surreal.clone())
+        .with_large_fields(Some(LargeFieldConfig::new(1024 * 1024, &dir)));
+    let plain = Surreal3Sink::new(surreal.clone());
+
+    // Warm up the engine so table creation is not measured.
+    offloading
+        .write_rows(&[document(0, "small".to_string())])
+        .await
+        .unwrap();
+
+    let body = "x".repeat(FIELD_BYTES);
+    let offloaded_peak = peak_write_allocation(&offloading, document(1, body)).await;
+    assert!(
+        offloaded_peak < FIELD_BYTES / 8,
+        "offloaded write allocated {offloaded_peak} bytes for a {FIELD_BYTES}-byte field"
+    );
+
+    // The same write without offload copies the field at least once.
+    let body = "x".repeat(FIELD_BYTES);
+    let plain_peak = peak_write_allocation(&plain, document(2, body)).await;
+    assert!(
+        plain_peak > FIELD_BYTES,
+        "plain write allocated only {plain_peak} bytes for a {FIELD_BYTES}-byte field"
+    );
+
+    let mut response = surreal
+        .query("RETURN documents:1.body.size; RETURN documents:1.body.kind; RETURN documents:1.body.path")
+        .await
+        .unwrap();
+    let size: Option<i64> = response.take(0).unwrap();
+    let kind: Option<String> = response.take(1).unwrap();
+    let path: Option<String> = response.take(2).unwrap();
+    assert_eq!(size, Some(FIELD_BYTES as i64));
+    assert_eq!(kind.as_deref(), Some("text"));
+    let len = std::fs::metadata(path.unwrap()).unwrap().len();
+    assert_eq!(len, FIELD_BYTES as u64);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
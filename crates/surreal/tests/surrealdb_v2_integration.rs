@@ -195,3 +195,49 @@ async fn test_checkpoint_store_update_existing() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_checkpoint_store_list_and_delete() -> anyhow::Result<()> {
+    let mut db = SurrealDbContainer::new("test-checkpoint-list-delete");
+    db.start()?;
+    db.wait_until_ready(30)?;
+    if skip_unless_v2(&db) {
+        eprintln!("skip: Surreal2Store tests require a v2 server");
+        return Ok(());
+    }
+
+    let ctx = connect(&db, "test_checkpoints_list").await?;
+    let store = ctx.store();
+    assert!(store.list_checkpoints().await?.is_empty());
+
+    let start = CheckpointID {
+        database_type: "postgresql-wal2json".to_string(),
+        phase: "full_sync_start".to_string(),
+    };
+    let end = CheckpointID {
+        database_type: "postgresql-wal2json".to_string(),
+        phase: "full_sync_end".to_string(),
+    };
+    store
+        .store_checkpoint(&start, r#"{"lsn":"0/1000000"}"#.to_string())
+        .await?;
+    store
+        .store_checkpoint(&end, r#"{"lsn":"0/2000000"}"#.to_string())
+        .await?;
+
+    assert_eq!(
+        store.list_checkpoints().await?,
+        vec![end.clone(), start.clone()]
+    );
+
+    store.delete_checkpoint(&start).await?;
+    assert!(store.read_checkpoint(&start).await?.is_none());
+    assert_eq!(store.list_checkpoints().await?, vec![end.clone()]);
+
+    // Deleting again is a no-op
+    store.delete_checkpoint(&start).await?;
+    store.delete_checkpoint(&end).await?;
+    assert!(store.list_checkpoints().await?.is_empty());
+
+    Ok(())
+}
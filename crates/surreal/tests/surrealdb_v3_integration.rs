@@ -35,11 +35,17 @@ async fn connect(db: &SurrealDbContainer, table: &str) -> anyhow::Result<TestCtx
 }
 
 async fn cleanup_record(ctx: &TestCtx, table: &str, id: &CheckpointID) -> anyhow::Result<()> {
-    let id_str = format!("{}_{}", id.database_type.replace('-', "_"), id.phase);
     ctx.client
-        .query("DELETE type::record($record_tb, $record_key)")
+        .query(
+            "DELETE type::table($record_tb) \
+             WHERE database_type = $database_type AND phase = $phase",
+        )
         .bind(("record_tb", table.to_string()))
-        .bind(("record_key", SurrealValue::String(id_str)))
+        .bind((
+            "database_type",
+            SurrealValue::String(id.database_type.clone()),
+        ))
+        .bind(("phase", SurrealValue::String(id.phase.clone())))
         .await?;
     Ok(())
 }
@@ -111,3 +117,90 @@ async fn test_checkpoint_store_not_found() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_checkpoint_store_list_and_delete() -> anyhow::Result<()> {
+    let mut db = SurrealDbContainer::new("test-checkpoint-list-delete-v3");
+    db.start()?;
+    db.wait_until_ready(30)?;
+    if skip_unless_v3(&db) {
+        eprintln!("skip: Surreal3Store tests require a v3 server");
+        return Ok(());
+    }
+
+    let ctx = connect(&db, "test_checkpoints_list").await?;
+    let store = ctx.store();
+    assert!(store.list_checkpoints().await?.is_empty());
+
+    let start = CheckpointID {
+        database_type: "postgresql-wal2json".to_string(),
+        phase: "full_sync_start".to_string(),
+    };
+    let end = CheckpointID {
+        database_type: "postgresql-wal2json".to_string(),
+        phase: "full_sync_end".to_string(),
+    };
+    store
+        .store_checkpoint(&start, r#"{"lsn":"0/1000000"}"#.to_string())
+        .await?;
+    store
+        .store_checkpoint(&end, r#"{"lsn":"0/2000000"}"#.to_string())
+        .await?;
+
+    assert_eq!(
+        store.list_checkpoints().await?,
+        vec![end.clone(), start.clone()]
+    );
+
+    store.delete_checkpoint(&start).await?;
+    assert!(store.read_checkpoint(&start).await?.is_none());
+    assert_eq!(store.list_checkpoints().await?, vec![end.clone()]);
+
+    // Deleting again is a no-op
+    store.delete_checkpoint(&start).await?;
+    store.delete_checkpoint(&end).await?;
+    assert!(store.list_checkpoints().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_checkpoint_store_history() -> anyhow::Result<()> {
+    let mut db = SurrealDbContainer::new("test-checkpoint-history-v3");
+    db.start()?;
+    db.wait_until_ready(30)?;
+    if skip_unless_v3(&db) {
+        eprintln!("skip: Surreal3Store tests require a v3 server");
+        return Ok(());
+    }
+
+    let mut ctx = connect(&db, "test_checkpoints_history").await?;
+    ctx.store = Surreal3Store::new(ctx.client.clone(), "test_checkpoints_history".to_string())
+        .with_history_limit(3);
+    let store = &ctx.store;
+
+    let id = CheckpointID {
+        database_type: "mysql".to_string(),
+        phase: "full_sync_start".to_string(),
+    };
+    for n in 1..=5 {
+        store.store_checkpoint(&id, format!("{n}")).await?;
+    }
+
+    let history = store.read_checkpoint_history(&id, 10).await?;
+    let data: Vec<&str> = history
+        .iter()
+        .map(|stored| stored.checkpoint_data.as_str())
+        .collect();
+    assert_eq!(data, ["5", "4", "3"], "newest first, trimmed to the limit");
+
+    let latest = store.read_checkpoint(&id).await?.unwrap();
+    assert_eq!(latest.checkpoint_data, "5");
+    assert_eq!(store.read_checkpoint_history(&id, 2).await?.len(), 2);
+    assert_eq!(store.list_checkpoints().await?, vec![id.clone()]);
+
+    store.delete_checkpoint(&id).await?;
+    assert!(store.read_checkpoint_history(&id, 10).await?.is_empty());
+
+    Ok(())
+}
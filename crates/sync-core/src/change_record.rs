@@ -0,0 +1,400 @@
+//! Versioned JSON serialization of changes for external consumers.
+//!
+//! Incremental sync normally ends in a SurrealDB write. Applications that
+//! want to react to the same stream (update a search index, invalidate a
+//! cache, forward to a message bus) receive [`ChangeRecord`]s instead: a
+//! plain JSON shape that does not depend on the SurrealDB SDK or on
+//! [`Value`]'s internal enum layout.
+//!
+//! One record serializes as:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "op": "update",
+//!   "table": "users",
+//!   "id": 42,
+//!   "before": null,
+//!   "after": {"name": "alice", "balance": "12.50"},
+//!   "position": "0/16B3748"
+//! }
+//! ```
+//!
+//! Relation changes additionally carry `in` and `out` record references
+//! (`{"table": ..., "id": ...}`). A table truncation is one `truncate` record
+//! with a `null` id. `before` holds the values the source sent for the row
+//! before an update or delete ([`Change::before`]), `null` when it sent none
+//! (only some sources capture them, and possibly only the key columns). `position` is the
+//! source position of the change when the emitter knows it, `null`
+//! otherwise. Field values follow [`value_to_json`]. New fields may be added
+//! within a version; a breaking change to the shape bumps
+//! [`CHANGE_RECORD_VERSION`].
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+
+use crate::relation_change::RelationChange;
+use crate::values::{Change, ChangeOp, FieldMap, Relation, Row, ThingRef, Value};
+
+/// Current version of the [`ChangeRecord`] format.
+pub const CHANGE_RECORD_VERSION: u32 = 1;
+
+/// Operation of a [`ChangeRecord`]: a [`ChangeOp`] on one record, or the
+/// truncation of a whole table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordOp {
+    /// A record was created
+    Create,
+    /// A record was updated (or written as a whole)
+    Update,
+    /// A record was deleted
+    Delete,
+    /// Every record of the table was deleted
+    Truncate,
+}
+
+impl From<ChangeOp> for RecordOp {
+    fn from(op: ChangeOp) -> Self {
+        match op {
+            ChangeOp::Create => Self::Create,
+            ChangeOp::Update => Self::Update,
+            ChangeOp::Delete => Self::Delete,
+        }
+    }
+}
+
+/// A record reference (`{"table": ..., "id": ...}`) in a [`ChangeRecord`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordRef {
+    /// Table of the referenced record
+    pub table: String,
+    /// Id of the referenced record, as JSON
+    pub id: JsonValue,
+}
+
+impl From<&ThingRef> for RecordRef {
+    fn from(thing: &ThingRef) -> Self {
+        Self {
+            table: thing.table.clone(),
+            id: value_to_json(&thing.id),
+        }
+    }
+}
+
+/// One change in the stable, versioned external format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeRecord {
+    /// Format version ([`CHANGE_RECORD_VERSION`] when produced by this crate)
+    pub version: u32,
+    /// `create`, `update`, `delete` or `truncate`
+    pub op: RecordOp,
+    /// Target table (relation table for relation changes)
+    pub table: String,
+    /// Record id, as JSON (`null` for truncations)
+    pub id: JsonValue,
+    /// Source record of a relation change
+    #[serde(rename = "in", default, skip_serializing_if = "Option::is_none")]
+    pub relation_in: Option<RecordRef>,
+    /// Target record of a relation change
+    #[serde(rename = "out", default, skip_serializing_if = "Option::is_none")]
+    pub relation_out: Option<RecordRef>,
+    /// Fields before the change, when the source captured them
+    pub before: Option<Map<String, JsonValue>>,
+    /// Fields after the change; `None` for deletes
+    pub after: Option<Map<String, JsonValue>>,
+    /// Source position of the change, when known
+    pub position: Option<String>,
+}
+
+impl ChangeRecord {
+    /// Record for a row change.
+    pub fn from_change(change: &Change) -> Self {
+        Self {
+            version: CHANGE_RECORD_VERSION,
+            op: change.operation.into(),
+            table: change.table.clone(),
+            id: value_to_json(&change.id),
+            relation_in: None,
+            relation_out: None,
+            before: change.before.as_deref().map(fields_to_json),
+            after: match change.operation {
+                ChangeOp::Delete => None,
+                _ => change.fields.as_ref().map(fields_to_json),
+            },
+            position: None,
+        }
+    }
+
+    /// Record for a relation (graph edge) change.
+    pub fn from_relation_change(change: &RelationChange) -> Self {
+        let relation = &change.relation;
+        Self {
+            version: CHANGE_RECORD_VERSION,
+            op: change.operation.into(),
+            table: relation.relation_type.clone(),
+            id: value_to_json(&relation.id),
+            relation_in: Some(RecordRef::from(&relation.input)),
+            relation_out: Some(RecordRef::from(&relation.output)),
+            before: None,
+            after: match change.operation {
+                ChangeOp::Delete => None,
+                _ => Some(fields_to_json(&relation.data)),
+            },
+            position: None,
+        }
+    }
+
+    /// `update` record for a row written as a whole (batch writes upsert).
+    pub fn from_row(row: &Row) -> Self {
+        Self::from_change(&Change::update(
+            row.table.clone(),
+            row.id.clone(),
+            row.fields.clone(),
+        ))
+    }
+
+    /// `update` record for a relation written as a whole.
+    pub fn from_relation(relation: &Relation) -> Self {
+        Self::from_relation_change(&RelationChange::update(relation.clone()))
+    }
+
+    /// `truncate` record: every record of `table` was deleted.
+    pub fn truncate(table: impl Into<String>) -> Self {
+        Self {
+            version: CHANGE_RECORD_VERSION,
+            op: RecordOp::Truncate,
+            table: table.into(),
+            id: JsonValue::Null,
+            relation_in: None,
+            relation_out: None,
+            before: None,
+            after: None,
+            position: None,
+        }
+    }
+
+    /// Attach the source position of the change.
+    pub fn with_position(mut self, position: impl Into<String>) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Serialize to a single-line JSON string.
+    pub fn to_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Parse a record produced by [`ChangeRecord::to_json_string`].
+    pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+}
+
+fn fields_to_json(fields: &FieldMap) -> Map<String, JsonValue> {
+    fields
+        .iter()
+        .map(|(name, value)| (name.clone(), value_to_json(value)))
+        .collect()
+}
+
+/// Stable JSON form of a [`Value`] in change records.
+///
+/// - integers and finite floats are numbers; NaN and infinities are `null`
+/// - decimals are strings, so no precision is lost
+/// - text, char, enum, UUID and ULID values are strings; sets are arrays of
+///   strings
+/// - binary values are standard base64 strings
+/// - dates are `YYYY-MM-DD`, times `HH:MM:SS[.f]`, local datetimes
+///   `YYYY-MM-DDTHH:MM:SS[.f]` and zoned datetimes RFC 3339 in UTC (`Z`);
+///   zero dates (e.g. MySQL `0000-00-00`) keep their source literal
+/// - durations are ISO 8601 seconds (`PT90S`, `PT1.500000000S`)
+/// - JSON values pass through; geometries are their GeoJSON
+/// - record links are `{"table": ..., "id": ...}`
+pub fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Null => JsonValue::Null,
+        Value::Bool(b) => JsonValue::Bool(*b),
+        Value::Int8 { value, .. } => JsonValue::from(*value),
+        Value::Int16(n) => JsonValue::from(*n),
+        Value::Int32(n) => JsonValue::from(*n),
+        Value::Int64(n) => JsonValue::from(*n),
+        Value::Float32(f) => float_to_json(f64::from(*f)),
+        Value::Float64(f) => float_to_json(*f),
+        Value::Decimal { value, .. } => JsonValue::String(value.clone()),
+        Value::Char { value, .. } | Value::VarChar { value, .. } | Value::Text(value) => {
+            JsonValue::String(value.clone())
+        }
+        Value::Blob(bytes) | Value::Bytes(bytes) => {
+            JsonValue::String(base64::engine::general_purpose::STANDARD.encode(bytes))
+        }
+        Value::Date(dt) => JsonValue::String(dt.format("%Y-%m-%d").to_string()),
+        Value::Time(dt) => JsonValue::String(dt.format("%H:%M:%S%.f").to_string()),
+        Value::LocalDateTime(dt) | Value::LocalDateTimeNano(dt) => {
+            JsonValue::String(dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+        }
+        Value::ZonedDateTime(dt) => {
+            JsonValue::String(dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+        }
+        Value::TimeTz(s) => JsonValue::String(s.clone()),
+        Value::ZeroTemporal {
+            intended_type,
+            source,
+        } => JsonValue::String(
+            source
+                .clone()
+                .unwrap_or_else(|| Value::canonical_zero_literal(intended_type).to_string()),
+        ),
+        Value::Uuid(u) => JsonValue::String(u.to_string()),
+        Value::Ulid(u) => JsonValue::String(u.to_string()),
+        Value::Json(j) | Value::Jsonb(j) => (**j).clone(),
+        Value::Array { elements, .. } => {
+            JsonValue::Array(elements.iter().map(value_to_json).collect())
+        }
+        Value::Set { elements, .. } => JsonValue::Array(
+            elements
+                .iter()
+                .map(|e| JsonValue::String(e.clone()))
+                .collect(),
+        ),
+        Value::Enum { value, .. } => JsonValue::String(value.clone()),
+        Value::Geometry { data, .. } => data.0.clone(),
+        Value::Duration(d) => JsonValue::String(if d.subsec_nanos() == 0 {
+            format!("PT{}S", d.as_secs())
+        } else {
+            format!("PT{}.{:09}S", d.as_secs(), d.subsec_nanos())
+        }),
+        Value::Thing { table, id } => serde_json::json!({
+            "table": table,
+            "id": value_to_json(id),
+        }),
+        Value::Object(fields) => JsonValue::Object(fields_to_json(fields)),
+    }
+}
+
+fn float_to_json(f: f64) -> JsonValue {
+    serde_json::Number::from_f64(f).map_or(JsonValue::Null, JsonValue::Number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_change_record_shape() {
+        let mut fields = FieldMap::new();
+        fields.insert("name".to_string(), Value::Text("alice".to_string()));
+        fields.insert("balance".to_string(), Value::decimal("12.50", 10, 2));
+        fields.insert("avatar".to_string(), Value::Bytes(vec![1, 2, 3]));
+        fields.insert(
+            "joined".to_string(),
+            Value::ZonedDateTime("2024-03-01T10:00:00Z".parse().unwrap()),
+        );
+        let record = ChangeRecord::from_change(&Change::update("users", Value::Int64(42), fields))
+            .with_position("0/16B3748");
+
+        assert_eq!(
+            serde_json::to_value(&record).unwrap(),
+            json!({
+                "version": 1,
+                "op": "update",
+                "table": "users",
+                "id": 42,
+                "before": null,
+                "after": {
+                    "name": "alice",
+                    "balance": "12.50",
+                    "avatar": "AQID",
+                    "joined": "2024-03-01T10:00:00Z",
+                },
+                "position": "0/16B3748",
+            })
+        );
+        let line = record.to_json_string().unwrap();
+        assert_eq!(ChangeRecord::from_json_str(&line).unwrap(), record);
+
+        let delete =
+            ChangeRecord::from_change(&Change::delete("users", Value::Int64(42)).with_before(
+                FieldMap::from([("name".to_string(), Value::Text("alice".to_string()))]),
+            ));
+        assert_eq!(
+            serde_json::to_value(&delete).unwrap(),
+            json!({
+                "version": 1,
+                "op": "delete",
+                "table": "users",
+                "id": 42,
+                "before": {"name": "alice"},
+                "after": null,
+                "position": null,
+            })
+        );
+
+        let truncate = ChangeRecord::truncate("users");
+        assert_eq!(
+            serde_json::to_value(&truncate).unwrap(),
+            json!({
+                "version": 1,
+                "op": "truncate",
+                "table": "users",
+                "id": null,
+                "before": null,
+                "after": null,
+                "position": null,
+            })
+        );
+        let line = truncate.to_json_string().unwrap();
+        assert_eq!(ChangeRecord::from_json_str(&line).unwrap(), truncate);
+    }
+
+    #[test]
+    fn test_relation_change_record_shape() {
+        let relation = Relation::new(
+            "book_tags",
+            Value::Int64(1),
+            ThingRef::new("books", Value::Int64(10)),
+            ThingRef::new("tags", Value::Text("rust".to_string())),
+            FieldMap::from([("weight".to_string(), Value::Float64(0.5))]),
+        );
+        let record = ChangeRecord::from_relation_change(&RelationChange::create(relation));
+        assert_eq!(
+            serde_json::to_value(&record).unwrap(),
+            json!({
+                "version": 1,
+                "op": "create",
+                "table": "book_tags",
+                "id": 1,
+                "in": {"table": "books", "id": 10},
+                "out": {"table": "tags", "id": "rust"},
+                "before": null,
+                "after": {"weight": 0.5},
+                "position": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_value_to_json_temporal_and_special() {
+        let dt: chrono::DateTime<chrono::Utc> = "2024-03-01T10:20:30.5Z".parse().unwrap();
+        assert_eq!(value_to_json(&Value::Date(dt)), json!("2024-03-01"));
+        assert_eq!(value_to_json(&Value::Time(dt)), json!("10:20:30.500"));
+        assert_eq!(
+            value_to_json(&Value::LocalDateTime(dt)),
+            json!("2024-03-01T10:20:30.500")
+        );
+        assert_eq!(
+            value_to_json(&Value::Duration(std::time::Duration::from_secs(90))),
+            json!("PT90S")
+        );
+        assert_eq!(value_to_json(&Value::Float64(f64::NAN)), JsonValue::Null);
+        assert_eq!(
+            value_to_json(&Value::Thing {
+                table: "users".to_string(),
+                id: Box::new(Value::Int64(1)),
+            }),
+            json!({"table": "users", "id": 1})
+        );
+    }
+}
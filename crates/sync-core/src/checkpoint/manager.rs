@@ -119,6 +119,14 @@ impl CheckpointStore for NullStore {
     ) -> anyhow::Result<Option<StoredCheckpoint>> {
         Ok(None)
     }
+
+    async fn list_checkpoints(&self) -> anyhow::Result<Vec<CheckpointID>> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_checkpoint(&self, _id: &CheckpointID) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 /// Type alias for SyncManager with disabled checkpointing.
@@ -11,6 +11,7 @@ mod phase;
 mod snapshot;
 mod snapshot_checkpointer;
 pub mod store;
+mod validity;
 
 pub use config::{CheckpointStorage, SyncConfig};
 pub use file::CheckpointFile;
@@ -19,6 +20,7 @@ pub use phase::SyncPhase;
 pub use snapshot::{InterleavedSnapshotCheckpoint, SnapshotTableProgress};
 pub use snapshot_checkpointer::SnapshotCheckpointer;
 pub use store::{CheckpointID, CheckpointStore, StoredCheckpoint};
+pub use validity::CheckpointValidity;
 
 /// Trait that database-specific checkpoints must implement.
 ///
@@ -9,7 +9,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Checkpoint identifier for storage
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct CheckpointID {
     /// Database type (e.g., "postgresql-pgoutput", "postgresql-wal2json", "mysql", "mongodb")
     pub database_type: String,
@@ -46,4 +46,12 @@ pub trait CheckpointStore: Send + Sync {
     ///
     /// Returns None if the checkpoint doesn't exist.
     async fn read_checkpoint(&self, id: &CheckpointID) -> Result<Option<StoredCheckpoint>>;
+
+    /// List the ids of all checkpoints in the storage backend.
+    async fn list_checkpoints(&self) -> Result<Vec<CheckpointID>>;
+
+    /// Delete every stored checkpoint for `id`.
+    ///
+    /// Deleting a checkpoint that doesn't exist is not an error.
+    async fn delete_checkpoint(&self, id: &CheckpointID) -> Result<()>;
 }
@@ -0,0 +1,57 @@
+//! Result of checking a stored checkpoint against the source's retention.
+
+/// Whether a stored checkpoint can still be resumed from.
+///
+/// Sources only retain change history for a bounded window (WAL held by a
+/// replication slot, Kafka topic retention, the MongoDB oplog). A checkpoint
+/// that has fallen out of that window cannot be resumed without losing
+/// changes, so a full sync is required instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointValidity {
+    /// The source still retains every change after the checkpoint.
+    Valid,
+    /// The source no longer retains changes after the checkpoint.
+    TooOld {
+        /// Source-specific explanation, e.g. which position was lost.
+        reason: String,
+    },
+}
+
+impl CheckpointValidity {
+    /// Shorthand for [`CheckpointValidity::TooOld`].
+    pub fn too_old(reason: impl Into<String>) -> Self {
+        Self::TooOld {
+            reason: reason.into(),
+        }
+    }
+
+    /// Whether resuming from the checkpoint is possible.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
+
+impl std::fmt::Display for CheckpointValidity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Valid => f.write_str("valid"),
+            Self::TooOld { reason } => write!(f, "too old, full sync required: {reason}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_validity_display() {
+        assert_eq!(CheckpointValidity::Valid.to_string(), "valid");
+        let stale = CheckpointValidity::too_old("offset 3 < low watermark 10");
+        assert!(!stale.is_valid());
+        assert_eq!(
+            stale.to_string(),
+            "too old, full sync required: offset 3 < low watermark 10"
+        );
+    }
+}
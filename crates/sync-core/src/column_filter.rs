@@ -0,0 +1,437 @@
+//! Per-table column include/exclude filtering.
+//!
+//! Table selection decides *which* tables sync; [`ColumnFilters`] decides which
+//! columns of a table make it into the synced record. A table may list the
+//! columns to keep (`include`), the columns to drop (`exclude`), or both
+//! (exclude wins). Tables without an entry keep every column.
+//!
+//! Sources that build their own reads push the filter down (a narrower
+//! `SELECT` list, a MongoDB projection) via [`ColumnFilters::select_columns`] /
+//! [`ColumnFilter::keeps`] so dropped columns are never transferred. Every
+//! other path applies it at conversion time: [`ColumnFilters`] is an
+//! [`InPlaceTransform`] that removes dropped fields from rows, changes and
+//! relation properties. Record IDs are never filtered.
+//!
+//! On top of the per-table filters, [`ExcludedFields`] drops internal/system
+//! fields (MongoDB `__v`, audit bookkeeping columns, ...) from every table by
+//! simple glob patterns (`__*`, `*_internal`). `id` and `_id` fields are never
+//! dropped by a pattern, and neither are the key columns passed to
+//! [`ColumnFilters::select_columns`].
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+
+use crate::relation_change::RelationChange;
+use crate::transform::InPlaceTransform;
+use crate::values::{FieldMap, Relation, Value};
+
+/// Columns kept for one table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnFilter {
+    /// Keep only these columns; `None` keeps every column not excluded.
+    pub include: Option<HashSet<String>>,
+    /// Drop these columns.
+    pub exclude: HashSet<String>,
+}
+
+impl ColumnFilter {
+    /// Whether `column` is synced.
+    pub fn keeps(&self, column: &str) -> bool {
+        !self.exclude.contains(column)
+            && self
+                .include
+                .as_ref()
+                .is_none_or(|include| include.contains(column))
+    }
+
+    /// Drop every field this filter does not keep.
+    pub fn retain(&self, fields: &mut FieldMap) {
+        fields.retain(|name, _| self.keeps(name));
+    }
+}
+
+/// Field names never dropped by an [`ExcludedFields`] pattern.
+const PROTECTED_FIELDS: [&str; 2] = ["id", "_id"];
+
+/// Global field-name patterns dropped from every table.
+///
+/// A pattern is a field name where `*` matches any run of characters (including
+/// none) and `?` matches exactly one character.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExcludedFields {
+    patterns: Vec<String>,
+}
+
+impl ExcludedFields {
+    /// Exclude fields matching any of `patterns`. Empty patterns are ignored.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(Into::into)
+                .filter(|p: &String| !p.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Whether no pattern is configured.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Whether `field` is dropped. `id` and `_id` never are.
+    pub fn excludes(&self, field: &str) -> bool {
+        !PROTECTED_FIELDS.contains(&field)
+            && self
+                .patterns
+                .iter()
+                .any(|pattern| glob_match(pattern.as_bytes(), field.as_bytes()))
+    }
+
+    /// Drop every field matching a pattern.
+    pub fn retain(&self, fields: &mut FieldMap) {
+        if !self.is_empty() {
+            fields.retain(|name, _| !self.excludes(name));
+        }
+    }
+}
+
+/// Match `name` against a glob of `*` and `?` wildcards.
+fn glob_match(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` and the name index it is currently matched up to.
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Error parsing `--include-columns` / `--exclude-columns` entries.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{0}")]
+pub struct ColumnFilterError(String);
+
+/// Per-table column filters, keyed by table name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnFilters {
+    tables: HashMap<String, ColumnFilter>,
+    excluded_fields: ExcludedFields,
+}
+
+impl ColumnFilters {
+    /// No filtering.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse CLI-style `table=col1,col2` entries (repeatable) for the include
+    /// and exclude lists. Entries for the same table accumulate. Empty entries
+    /// are ignored.
+    pub fn parse(include: &[String], exclude: &[String]) -> Result<Self, ColumnFilterError> {
+        let mut filters = Self::new();
+        for (table, columns) in parse_entries(include, "include-columns")? {
+            filters.include(table, columns);
+        }
+        for (table, columns) in parse_entries(exclude, "exclude-columns")? {
+            filters.exclude(table, columns);
+        }
+        Ok(filters)
+    }
+
+    /// Keep only `columns` of `table` (added to any columns already included).
+    pub fn include<I, S>(&mut self, table: impl Into<String>, columns: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let filter = self.tables.entry(table.into()).or_default();
+        filter
+            .include
+            .get_or_insert_with(HashSet::new)
+            .extend(columns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop `columns` of `table`.
+    pub fn exclude<I, S>(&mut self, table: impl Into<String>, columns: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tables
+            .entry(table.into())
+            .or_default()
+            .exclude
+            .extend(columns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop fields matching any of `patterns` from every table (see
+    /// [`ExcludedFields`]).
+    pub fn exclude_fields<I, S>(&mut self, patterns: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.excluded_fields
+            .patterns
+            .extend(ExcludedFields::new(patterns).patterns);
+        self
+    }
+
+    /// Global field patterns dropped from every table.
+    pub fn excluded_fields(&self) -> &ExcludedFields {
+        &self.excluded_fields
+    }
+
+    /// Whether no table is filtered.
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty() && self.excluded_fields.is_empty()
+    }
+
+    /// Filter for `table`, if it has one.
+    pub fn for_table(&self, table: &str) -> Option<&ColumnFilter> {
+        self.tables.get(table)
+    }
+
+    /// Whether `column` of `table` is synced.
+    pub fn keeps(&self, table: &str, column: &str) -> bool {
+        !self.excluded_fields.excludes(column)
+            && self.for_table(table).is_none_or(|f| f.keeps(column))
+    }
+
+    /// The columns of `table` a source should read, in `columns` order.
+    ///
+    /// `key_columns` are always read, filtered or not: sources need them for
+    /// record IDs and keyset pagination.
+    pub fn select_columns<'a>(
+        &self,
+        table: &str,
+        columns: &'a [String],
+        key_columns: &[String],
+    ) -> Vec<&'a String> {
+        columns
+            .iter()
+            .filter(|c| key_columns.contains(c) || self.keeps(table, c))
+            .collect()
+    }
+
+    /// `Some(self)` when any table is filtered, for appending as an optional
+    /// pipeline stage.
+    pub fn into_stage(self) -> Option<Self> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+fn parse_entries(
+    entries: &[String],
+    flag: &str,
+) -> Result<Vec<(String, Vec<String>)>, ColumnFilterError> {
+    let mut out = Vec::new();
+    for entry in entries {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let Some((table, columns)) = entry.split_once('=') else {
+            return Err(ColumnFilterError(format!(
+                "{flag} entry '{entry}' must be table=col1,col2"
+            )));
+        };
+        let table = table.trim();
+        if table.is_empty() {
+            return Err(ColumnFilterError(format!(
+                "{flag} entry '{entry}' has an empty table name"
+            )));
+        }
+        let columns: Vec<String> = columns
+            .split(',')
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+        if columns.is_empty() {
+            return Err(ColumnFilterError(format!(
+                "{flag} entry '{entry}' has no column names"
+            )));
+        }
+        out.push((table.to_string(), columns));
+    }
+    Ok(out)
+}
+
+impl InPlaceTransform for ColumnFilters {
+    fn transform(&self, table: &str, _id: &mut Value, fields: Option<&mut FieldMap>) -> Result<()> {
+        let Some(fields) = fields else {
+            return Ok(());
+        };
+        if let Some(filter) = self.for_table(table) {
+            filter.retain(fields);
+        }
+        self.excluded_fields.retain(fields);
+        Ok(())
+    }
+
+    fn transform_relation(&self, relation: &mut Relation) -> Result<()> {
+        if let Some(filter) = self.for_table(&relation.relation_type) {
+            filter.retain(&mut relation.data);
+        }
+        self.excluded_fields.retain(&mut relation.data);
+        Ok(())
+    }
+
+    fn transform_relation_change(&self, change: &mut RelationChange) -> Result<()> {
+        self.transform_relation(&mut change.relation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::{Change, Row};
+
+    fn strings(s: &[&str]) -> Vec<String> {
+        s.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_keep() {
+        let filters = ColumnFilters::parse(
+            &strings(&["users=id,name,email", "users=avatar"]),
+            &strings(&["users=email", "posts=body_html"]),
+        )
+        .unwrap();
+
+        assert!(filters.keeps("users", "name"));
+        assert!(filters.keeps("users", "avatar"));
+        assert!(
+            !filters.keeps("users", "email"),
+            "exclude wins over include"
+        );
+        assert!(!filters.keeps("users", "password"));
+        assert!(!filters.keeps("posts", "body_html"));
+        assert!(filters.keeps("posts", "title"));
+        assert!(filters.keeps("comments", "anything"));
+
+        assert!(ColumnFilters::parse(&strings(&["users"]), &[]).is_err());
+        assert!(ColumnFilters::parse(&[], &strings(&["users="])).is_err());
+        assert!(ColumnFilters::parse(&strings(&["=id"]), &[]).is_err());
+        assert!(ColumnFilters::parse(&strings(&[""]), &[])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_select_columns_keeps_key_columns() {
+        let mut filters = ColumnFilters::new();
+        filters.include("users", ["name"]);
+        let columns = strings(&["id", "name", "blob"]);
+        assert_eq!(
+            filters.select_columns("users", &columns, &strings(&["id"])),
+            vec!["id", "name"]
+        );
+        assert_eq!(
+            filters.select_columns("other", &columns, &[]),
+            vec!["id", "name", "blob"]
+        );
+    }
+
+    #[test]
+    fn test_transform_drops_excluded_fields() {
+        let mut filters = ColumnFilters::new();
+        filters.exclude("users", ["blob"]);
+
+        let mut row = Row::new(
+            "users",
+            0,
+            Value::Int64(1),
+            FieldMap::from([
+                ("name".to_string(), Value::Text("a".to_string())),
+                ("blob".to_string(), Value::Bytes(vec![0; 4])),
+            ]),
+        );
+        filters.transform_row(&mut row).unwrap();
+        assert!(row.fields.contains_key("name"));
+        assert!(!row.fields.contains_key("blob"));
+
+        let mut delete = Change::delete("users", Value::Int64(1));
+        filters.transform_change(&mut delete).unwrap();
+        assert_eq!(delete.id, Value::Int64(1));
+    }
+
+    #[test]
+    fn test_excluded_field_patterns() {
+        let excluded = ExcludedFields::new(["__*", "*_internal", "_sync_meta", "tmp?"]);
+        assert!(excluded.excludes("__v"));
+        assert!(excluded.excludes("__"));
+        assert!(excluded.excludes("audit_internal"));
+        assert!(excluded.excludes("_internal"));
+        assert!(excluded.excludes("_sync_meta"));
+        assert!(excluded.excludes("tmp1"));
+        assert!(!excluded.excludes("tmp"));
+        assert!(!excluded.excludes("tmp12"));
+        assert!(!excluded.excludes("_v"));
+        assert!(!excluded.excludes("internal_notes"));
+        assert!(!excluded.excludes("name"));
+
+        assert!(glob_match(b"a*b*c", b"axxbyyc"));
+        assert!(glob_match(b"a*b*c", b"abcbc"));
+        assert!(!glob_match(b"a*b*c", b"axxbyy"));
+        assert!(glob_match(b"*", b""));
+        assert!(ExcludedFields::new([""]).is_empty());
+    }
+
+    #[test]
+    fn test_excluded_fields_never_drop_ids() {
+        let mut filters = ColumnFilters::new();
+        filters.exclude_fields(["_*", "*id"]);
+        assert!(!filters.is_empty());
+
+        let mut row = Row::new(
+            "users",
+            0,
+            Value::Int64(1),
+            FieldMap::from([
+                ("_id".to_string(), Value::Text("abc".to_string())),
+                ("id".to_string(), Value::Int64(1)),
+                ("__v".to_string(), Value::Int64(0)),
+                ("owner_id".to_string(), Value::Int64(2)),
+                ("name".to_string(), Value::Text("a".to_string())),
+            ]),
+        );
+        filters.transform_row(&mut row).unwrap();
+        let mut kept: Vec<_> = row.fields.keys().map(String::as_str).collect();
+        kept.sort_unstable();
+        assert_eq!(kept, ["_id", "id", "name"]);
+        assert_eq!(row.id, Value::Int64(1));
+
+        // Key columns are still read even when they match a pattern.
+        let columns = strings(&["user_id", "__v", "name"]);
+        assert_eq!(
+            filters.select_columns("users", &columns, &strings(&["user_id"])),
+            vec!["user_id", "name"]
+        );
+    }
+}
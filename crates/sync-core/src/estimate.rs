@@ -0,0 +1,111 @@
+//! Migration size estimates.
+//!
+//! Full sync run with `--count-only` counts what a migration would move
+//! (rows, documents or nodes per table, and roughly how many bytes) without
+//! converting or writing anything, so operators can size a cutover window.
+//! Each source builds one [`TableEstimate`] per table it would sync and wraps
+//! them in a [`MigrationEstimate`].
+
+use serde::{Deserialize, Serialize};
+
+/// Size estimate for one table, collection or node label.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableEstimate {
+    /// Table name as the source's sync options expect it
+    pub name: String,
+    /// Number of rows / documents / nodes
+    pub rows: u64,
+    /// Whether `rows` is an exact count rather than a statistics estimate
+    pub exact: bool,
+    /// Approximate size in bytes (rows times the source's average row size),
+    /// when the source reports one
+    pub approx_bytes: Option<u64>,
+}
+
+impl TableEstimate {
+    /// Estimate for `name` with `rows` rows and no size information.
+    pub fn new(name: impl Into<String>, rows: u64, exact: bool) -> Self {
+        Self {
+            name: name.into(),
+            rows,
+            exact,
+            approx_bytes: None,
+        }
+    }
+
+    /// Set the size from an average row size in bytes.
+    pub fn with_avg_row_bytes(mut self, avg_row_bytes: Option<f64>) -> Self {
+        self.approx_bytes = avg_row_bytes
+            .filter(|avg| avg.is_finite() && *avg >= 0.0)
+            .map(|avg| (avg * self.rows as f64).round() as u64);
+        self
+    }
+}
+
+/// Size report of a full sync that was only counted, not run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrationEstimate {
+    /// Source kind (e.g. `postgresql`, `mongodb`)
+    pub source: String,
+    /// Per-table estimates, in sync order
+    pub tables: Vec<TableEstimate>,
+    /// Sum of `rows` over all tables
+    pub total_rows: u64,
+    /// Sum of `approx_bytes` over the tables that report a size; `None` when
+    /// no table does
+    pub total_approx_bytes: Option<u64>,
+    /// Whether every table count is exact
+    pub exact: bool,
+}
+
+impl MigrationEstimate {
+    /// Build the report for `source` from its per-table estimates.
+    pub fn new(source: impl Into<String>, tables: Vec<TableEstimate>) -> Self {
+        let total_rows = tables.iter().map(|t| t.rows).sum();
+        let total_approx_bytes = tables
+            .iter()
+            .filter_map(|t| t.approx_bytes)
+            .reduce(|a, b| a.saturating_add(b));
+        let exact = tables.iter().all(|t| t.exact);
+        Self {
+            source: source.into(),
+            tables,
+            total_rows,
+            total_approx_bytes,
+            exact,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_totals() {
+        let estimate = MigrationEstimate::new(
+            "postgresql",
+            vec![
+                TableEstimate::new("users", 1000, true).with_avg_row_bytes(Some(120.4)),
+                TableEstimate::new("events", 250, false).with_avg_row_bytes(Some(64.0)),
+                TableEstimate::new("tags", 7, true),
+            ],
+        );
+        assert_eq!(estimate.total_rows, 1257);
+        assert_eq!(estimate.tables[0].approx_bytes, Some(120_400));
+        assert_eq!(estimate.total_approx_bytes, Some(120_400 + 16_000));
+        assert!(!estimate.exact);
+    }
+
+    #[test]
+    fn test_estimate_without_sizes() {
+        let estimate = MigrationEstimate::new(
+            "neo4j",
+            vec![TableEstimate::new("Person", 3, true).with_avg_row_bytes(Some(f64::NAN))],
+        );
+        assert_eq!(estimate.tables[0].approx_bytes, None);
+        assert_eq!(estimate.total_approx_bytes, None);
+        assert!(estimate.exact);
+        assert!(MigrationEstimate::new("mysql", vec![]).exact);
+    }
+}
@@ -75,8 +75,9 @@ pub fn parse_id_column_overrides(
 /// Apply per-table ID column overrides onto a discovered [`DatabaseSchema`].
 ///
 /// For each overridden table:
-/// - Sets `primary_key` to the first column (type looked up from existing columns,
-///   or [`Type::Text`] if missing).
+/// - Checks every named column exists and is not nullable, since a record ID
+///   cannot be built from a missing or `NULL` value.
+/// - Sets `primary_key` to the first column.
 /// - Moves former PK / other columns so all non-first PK columns remain in `columns`.
 /// - Sets `composite_primary_key` when `cols.len() > 1`, otherwise clears it.
 pub fn apply_id_column_overrides(
@@ -114,9 +115,19 @@ fn apply_override_to_table(
 
     let mut pk_defs = Vec::with_capacity(cols.len());
     for name in cols {
-        let def = by_name
-            .remove(name)
-            .unwrap_or_else(|| ColumnDefinition::new(name.clone(), Type::Text));
+        let def = by_name.remove(name).ok_or_else(|| {
+            IdColumnsError::msg(format!(
+                "id-columns override for table '{}' names unknown column '{name}'",
+                table.name
+            ))
+        })?;
+        if def.nullable {
+            return Err(IdColumnsError::msg(format!(
+                "id-columns override for table '{}' names nullable column '{name}'; \
+                 record ID columns must be NOT NULL",
+                table.name
+            )));
+        }
         pk_defs.push(def);
     }
 
@@ -222,6 +233,31 @@ mod tests {
         assert_eq!(t.primary_key_column_names(), vec!["a", "b"]);
     }
 
+    #[test]
+    fn apply_moves_id_to_non_pk_column() {
+        let mut schema = DatabaseSchema::new(vec![TableDefinition::new(
+            "users",
+            ColumnDefinition::new("id", Type::Int64),
+            vec![
+                ColumnDefinition::new("email", Type::Text),
+                ColumnDefinition::nullable("nickname", Type::Text),
+            ],
+        )]);
+        let overrides = parse_id_column_overrides(&["users=email".into()], None).unwrap();
+        apply_id_column_overrides(&mut schema, &overrides).unwrap();
+        let t = schema.get_table("users").unwrap();
+        assert_eq!(t.primary_key_column_names(), vec!["email"]);
+        assert_eq!(t.get_column_type("id"), Some(&Type::Int64));
+
+        let unknown = parse_id_column_overrides(&["users=handle".into()], None).unwrap();
+        let err = apply_id_column_overrides(&mut schema, &unknown).unwrap_err();
+        assert!(err.to_string().contains("unknown column 'handle'"));
+
+        let nullable = parse_id_column_overrides(&["users=nickname".into()], None).unwrap();
+        let err = apply_id_column_overrides(&mut schema, &nullable).unwrap_err();
+        assert!(err.to_string().contains("nullable column 'nickname'"));
+    }
+
     #[test]
     fn flatten_joins_with_separator() {
         let id = Value::Array {
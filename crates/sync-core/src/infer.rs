@@ -0,0 +1,386 @@
+//! Schema inference from sampled records.
+//!
+//! File imports (CSV, JSONL) carry no schema of their own. [`TableSchemaInferrer`]
+//! observes a sample of converted rows and derives a [`GeneratorTableDefinition`]
+//! from the values it saw, so the target schema can be created up front and
+//! reviewed (or saved and edited as a `--schema-file`).
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use crate::schema::{
+    GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition, GeneratorTableDefinition,
+};
+use crate::{parse_iso8601_duration, FieldMap, Type, Value};
+
+/// Accumulates field types for one table across sampled records.
+#[derive(Debug, Clone)]
+pub struct TableSchemaInferrer {
+    name: String,
+    id_type: Option<Type>,
+    fields: BTreeMap<String, FieldStats>,
+    indexed_fields: Vec<String>,
+    records: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct FieldStats {
+    field_type: Option<Type>,
+    present: u64,
+    saw_null: bool,
+    saw_null_element: bool,
+    /// Hashes of the non-null values seen, cleared once one repeats.
+    seen: HashSet<u64>,
+    saw_duplicate: bool,
+}
+
+impl TableSchemaInferrer {
+    /// Start inferring the schema of `table`.
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            name: table.into(),
+            id_type: None,
+            fields: BTreeMap::new(),
+            indexed_fields: Vec::new(),
+            records: 0,
+        }
+    }
+
+    /// Always index these fields, e.g. source columns that also make up the
+    /// record id.
+    pub fn with_indexed_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.indexed_fields
+            .extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Name of the table being inferred.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Number of records observed so far.
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    /// Fold one record (its id and non-id fields) into the inferred schema.
+    pub fn observe(&mut self, id: &Value, fields: &FieldMap) {
+        self.records += 1;
+        self.id_type = merge_optional(self.id_type.take(), infer_value_type(id));
+        for (name, value) in fields {
+            let stats = self.fields.entry(name.clone()).or_default();
+            stats.present += 1;
+            match value {
+                Value::Null => stats.saw_null = true,
+                Value::Array { elements, .. } if elements.contains(&Value::Null) => {
+                    stats.saw_null_element = true;
+                }
+                _ => {}
+            }
+            if !matches!(value, Value::Null) && !stats.saw_duplicate {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                if !stats.seen.insert(hasher.finish()) {
+                    stats.saw_duplicate = true;
+                    stats.seen = HashSet::new();
+                }
+            }
+            stats.field_type = merge_optional(stats.field_type.take(), infer_value_type(value));
+        }
+    }
+
+    /// Finish inference.
+    ///
+    /// Fields are ordered by name. A field is nullable when it was null or
+    /// missing in any sampled record; fields that were only ever null (or
+    /// empty arrays) are typed [`Type::Json`]. An array field has nullable
+    /// elements when any sampled array held a null.
+    ///
+    /// A field is indexed when it was requested with
+    /// [`Self::with_indexed_fields`], or when it is an integer, string, UUID
+    /// or ULID field whose values were present and distinct in every one of
+    /// at least two sampled records. Generators are [`GeneratorConfig::Null`]
+    /// placeholders: the result describes types only.
+    pub fn finish(self) -> GeneratorTableDefinition {
+        let records = self.records;
+        let indexed_fields = self.indexed_fields;
+        GeneratorTableDefinition {
+            name: self.name,
+            id: GeneratorIDDefinition {
+                id_type: self.id_type.unwrap_or(Type::Ulid),
+                generator: GeneratorConfig::Null,
+            },
+            fields: self
+                .fields
+                .into_iter()
+                .map(|(name, stats)| {
+                    let field_type = stats.field_type.unwrap_or(Type::Json);
+                    let nullable = stats.saw_null || stats.present < records;
+                    let sampled_unique = records >= 2
+                        && !nullable
+                        && !stats.saw_duplicate
+                        && is_index_candidate(&field_type);
+                    GeneratorFieldDefinition {
+                        indexed: sampled_unique || indexed_fields.contains(&name),
+                        nullable_elements: stats.saw_null_element
+                            && matches!(field_type, Type::Array { .. }),
+                        name,
+                        field_type,
+                        generator: GeneratorConfig::Null,
+                        nullable,
+                        constraints: Default::default(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Type of a single value, or `None` when the value carries no type
+/// information (null, or an array without non-null elements).
+///
+/// Array element types are inferred from the non-null elements themselves
+/// rather than the array's declared element type, which untyped sources leave
+/// as a placeholder. Text in the ISO 8601 form the SurrealDB sinks write as a
+/// duration (see [`parse_iso8601_duration`]) is typed [`Type::Duration`].
+pub fn infer_value_type(value: &Value) -> Option<Type> {
+    match value {
+        Value::Null => None,
+        Value::Char { value, .. } | Value::VarChar { value, .. } | Value::Text(value)
+            if parse_iso8601_duration(value).is_some() =>
+        {
+            Some(Type::Duration)
+        }
+        Value::Array { elements, .. } => elements
+            .iter()
+            .map(infer_value_type)
+            .fold(None, merge_optional)
+            .map(|element_type| Type::Array {
+                element_type: Box::new(element_type),
+            }),
+        Value::Set { .. } => Some(Type::Array {
+            element_type: Box::new(Type::Text),
+        }),
+        Value::Enum { .. } | Value::Char { .. } | Value::VarChar { .. } => Some(Type::Text),
+        other => Some(other.to_type()),
+    }
+}
+
+/// Widen two observed types to one that holds values of both.
+///
+/// Integers widen to [`Type::Int64`], integers mixed with floats to
+/// [`Type::Float64`], durations mixed with text to [`Type::Text`] (whose
+/// SurrealDB field type admits both), and arrays merge their element types.
+/// Any other conflict widens to [`Type::Json`].
+pub fn merge_types(a: Type, b: Type) -> Type {
+    if a == b {
+        return a;
+    }
+    match (a, b) {
+        (a, b) if is_integer(&a) && is_integer(&b) => Type::Int64,
+        (a, b) if is_numeric(&a) && is_numeric(&b) => Type::Float64,
+        (Type::Text, Type::Duration) | (Type::Duration, Type::Text) => Type::Text,
+        (Type::Array { element_type: a }, Type::Array { element_type: b }) => Type::Array {
+            element_type: Box::new(merge_types(*a, *b)),
+        },
+        _ => Type::Json,
+    }
+}
+
+fn merge_optional(a: Option<Type>, b: Option<Type>) -> Option<Type> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(merge_types(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn is_integer(t: &Type) -> bool {
+    matches!(
+        t,
+        Type::Int8 { .. } | Type::Int16 | Type::Int32 | Type::Int64
+    )
+}
+
+fn is_numeric(t: &Type) -> bool {
+    is_integer(t) || matches!(t, Type::Float32 | Type::Float64)
+}
+
+/// Types whose sampled-unique fields look like keys worth indexing.
+fn is_index_candidate(t: &Type) -> bool {
+    is_integer(t) || matches!(t, Type::Text | Type::Uuid | Type::Ulid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(fields: &[(&str, Value)]) -> FieldMap {
+        fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_infer_widens_types_and_tracks_nullability() {
+        let mut inferrer = TableSchemaInferrer::new("people");
+        inferrer.observe(
+            &Value::Int64(1),
+            &record(&[
+                ("age", Value::Int64(30)),
+                ("score", Value::Int64(7)),
+                ("name", Value::Text("Alice".into())),
+                ("note", Value::Null),
+            ]),
+        );
+        inferrer.observe(
+            &Value::Int64(2),
+            &record(&[
+                ("age", Value::Int64(25)),
+                ("score", Value::Float64(7.5)),
+                ("name", Value::Bool(true)),
+                ("note", Value::Null),
+            ]),
+        );
+        assert_eq!(inferrer.records(), 2);
+
+        let table = inferrer.finish();
+        assert_eq!(table.name, "people");
+        assert_eq!(table.id.id_type, Type::Int64);
+        assert_eq!(table.field_names(), vec!["age", "name", "note", "score"]);
+        let age = table.get_field("age").unwrap();
+        assert_eq!(age.field_type, Type::Int64);
+        assert!(!age.nullable);
+        assert_eq!(table.get_field_type("score"), Some(&Type::Float64));
+        assert_eq!(table.get_field_type("name"), Some(&Type::Json));
+        let note = table.get_field("note").unwrap();
+        assert_eq!(note.field_type, Type::Json);
+        assert!(note.nullable);
+    }
+
+    #[test]
+    fn test_infer_missing_field_is_nullable() {
+        let mut inferrer = TableSchemaInferrer::new("t");
+        let id = Value::Text("a".into());
+        inferrer.observe(&id, &record(&[("x", Value::Bool(true))]));
+        inferrer.observe(&id, &record(&[]));
+        let table = inferrer.finish();
+        assert_eq!(table.id.id_type, Type::Text);
+        let x = table.get_field("x").unwrap();
+        assert_eq!(x.field_type, Type::Bool);
+        assert!(x.nullable);
+    }
+
+    #[test]
+    fn test_infer_array_element_types() {
+        let ints = Value::Array {
+            elements: vec![Value::Int64(1), Value::Null, Value::Int32(2)],
+            element_type: Box::new(Type::Text),
+        };
+        assert_eq!(
+            infer_value_type(&ints),
+            Some(Type::Array {
+                element_type: Box::new(Type::Int64)
+            })
+        );
+        let empty = Value::Array {
+            elements: vec![],
+            element_type: Box::new(Type::Text),
+        };
+        assert_eq!(infer_value_type(&empty), None);
+        assert_eq!(
+            merge_types(
+                Type::Array {
+                    element_type: Box::new(Type::Int64)
+                },
+                Type::Array {
+                    element_type: Box::new(Type::Float64)
+                },
+            ),
+            Type::Array {
+                element_type: Box::new(Type::Float64)
+            }
+        );
+    }
+
+    #[test]
+    fn test_infer_nullable_array_elements_and_durations() {
+        let mut inferrer = TableSchemaInferrer::new("t");
+        let id = Value::Int64(1);
+        inferrer.observe(
+            &id,
+            &record(&[
+                (
+                    "scores",
+                    Value::Array {
+                        elements: vec![Value::Int64(1), Value::Null],
+                        element_type: Box::new(Type::Text),
+                    },
+                ),
+                (
+                    "ranks",
+                    Value::Array {
+                        elements: vec![Value::Int64(1)],
+                        element_type: Box::new(Type::Text),
+                    },
+                ),
+                ("timeout", Value::Text("PT181S".into())),
+                ("label", Value::Text("PT5S".into())),
+            ]),
+        );
+        inferrer.observe(
+            &id,
+            &record(&[
+                ("timeout", Value::Text("PT0.5S".into())),
+                ("label", Value::Text("five".into())),
+            ]),
+        );
+        let table = inferrer.finish();
+
+        let scores = table.get_field("scores").unwrap();
+        assert_eq!(
+            scores.field_type,
+            Type::Array {
+                element_type: Box::new(Type::Int64)
+            }
+        );
+        assert!(scores.nullable_elements);
+        assert!(!table.get_field("ranks").unwrap().nullable_elements);
+        assert_eq!(table.get_field_type("timeout"), Some(&Type::Duration));
+        assert_eq!(table.get_field_type("label"), Some(&Type::Text));
+    }
+
+    #[test]
+    fn test_infer_indexes_sampled_unique_and_requested_fields() {
+        let mut inferrer = TableSchemaInferrer::new("t").with_indexed_fields(["sku"]);
+        for (i, sku) in [(1, "a"), (2, "a"), (3, "b")] {
+            inferrer.observe(
+                &Value::Int64(i),
+                &record(&[
+                    ("code", Value::Text(format!("c{i}"))),
+                    ("sku", Value::Text(sku.into())),
+                    ("qty", Value::Int64(i % 2)),
+                    ("price", Value::Float64(i as f64)),
+                ]),
+            );
+        }
+        let table = inferrer.finish();
+        assert!(table.get_field("code").unwrap().indexed);
+        assert!(table.get_field("sku").unwrap().indexed);
+        assert!(!table.get_field("qty").unwrap().indexed);
+        assert!(!table.get_field("price").unwrap().indexed);
+
+        let mut single = TableSchemaInferrer::new("t");
+        single.observe(
+            &Value::Int64(1),
+            &record(&[("code", Value::Text("c".into()))]),
+        );
+        assert!(!single.finish().get_field("code").unwrap().indexed);
+    }
+}
@@ -0,0 +1,29 @@
+//! Source introspection.
+//!
+//! Each source exposes a `list_tables` / `list_collections` / `list_labels` /
+//! `list_topics` function that connects and reports what can be synced, so
+//! tools built on surreal-sync can offer a "select what to sync" step before
+//! a sync is configured. All of them return [`SourceEntity`] values.
+
+use serde::{Deserialize, Serialize};
+
+/// A syncable entity (table, collection, node label or topic) of a source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceEntity {
+    /// Entity name as the source's sync options expect it
+    pub name: String,
+    /// Approximate number of rows / documents / nodes / messages, when the
+    /// source can report it without scanning (statistics, count stores,
+    /// offsets). `None` when unknown.
+    pub approx_count: Option<u64>,
+}
+
+impl SourceEntity {
+    /// Entity `name` with its approximate count, if known.
+    pub fn new(name: impl Into<String>, approx_count: Option<u64>) -> Self {
+        Self {
+            name: name.into(),
+            approx_count,
+        }
+    }
+}
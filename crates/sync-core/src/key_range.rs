@@ -0,0 +1,248 @@
+//! Key-range splitting for intra-table parallel reads.
+//!
+//! A full sync of one very large table is bound to a single reader no matter
+//! how many tables are synced side by side. Splitting the table on its leading
+//! key column into contiguous [`KeyRange`]s lets several readers (each on its
+//! own connection) scan disjoint parts of the table concurrently.
+//!
+//! [`split_key_range`] probes nothing itself: the caller reads the current
+//! minimum and maximum key and passes them in. The first range has no lower
+//! bound and the last no upper bound, so rows outside the probed `[min, max]`
+//! (e.g. inserted after the probe) are still read exactly once, and adjacent
+//! ranges share their boundary value (`lower` inclusive, `upper` exclusive) so
+//! no key falls between two ranges.
+//!
+//! This is unrelated to the load-test partitioner
+//! (`loadtest-distributed::partitioner`), which deals whole tables out to
+//! containers round-robin and never divides a single table's keys.
+
+use std::collections::HashMap;
+
+use crate::values::Value;
+
+/// Per-table read parallelism (`table -> number of key ranges`).
+pub type ParallelReads = HashMap<String, usize>;
+
+/// Half-open range over a key column: `lower <= key < upper`.
+///
+/// A missing bound is unbounded on that side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyRange {
+    /// Inclusive lower bound.
+    pub lower: Option<Value>,
+    /// Exclusive upper bound.
+    pub upper: Option<Value>,
+}
+
+impl KeyRange {
+    /// The range covering every key.
+    pub fn full() -> Self {
+        Self {
+            lower: None,
+            upper: None,
+        }
+    }
+}
+
+/// Error parsing `--parallel-reads` entries.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{0}")]
+pub struct ParallelReadsError(String);
+
+/// Parse CLI-style `table=N` entries (repeatable). Empty entries are ignored.
+pub fn parse_parallel_reads(entries: &[String]) -> Result<ParallelReads, ParallelReadsError> {
+    let mut out = ParallelReads::new();
+    for entry in entries {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let Some((table, degree)) = entry.split_once('=') else {
+            return Err(ParallelReadsError(format!(
+                "parallel-reads entry '{entry}' must be table=N"
+            )));
+        };
+        let table = table.trim();
+        if table.is_empty() {
+            return Err(ParallelReadsError(format!(
+                "parallel-reads entry '{entry}' has an empty table name"
+            )));
+        }
+        let degree: usize = degree
+            .trim()
+            .parse()
+            .ok()
+            .filter(|n| *n > 0)
+            .ok_or_else(|| {
+                ParallelReadsError(format!(
+                    "parallel-reads entry '{entry}' must use a positive integer degree"
+                ))
+            })?;
+        out.insert(table.to_string(), degree);
+    }
+    Ok(out)
+}
+
+/// Split the key space between the probed `min` and `max` keys into at most
+/// `parts` contiguous ranges of roughly equal width.
+///
+/// Supported key types are 16/32/64-bit integers and UUIDs (ordered as
+/// unsigned 128-bit big-endian integers, which is how PostgreSQL compares
+/// them). Returns `None` for other key types or when `min` and `max` differ in
+/// type; callers then read the table with a single reader. Fewer than `parts`
+/// ranges are returned when the key space holds fewer distinct keys.
+pub fn split_key_range(min: &Value, max: &Value, parts: usize) -> Option<Vec<KeyRange>> {
+    let (lo, hi) = match (min, max) {
+        (Value::Int16(a), Value::Int16(b)) => (i128::from(*a), i128::from(*b)),
+        (Value::Int32(a), Value::Int32(b)) => (i128::from(*a), i128::from(*b)),
+        (Value::Int64(a), Value::Int64(b)) => (i128::from(*a), i128::from(*b)),
+        (Value::Uuid(a), Value::Uuid(b)) => {
+            let offsets = boundary_offsets(b.as_u128().saturating_sub(a.as_u128()), parts);
+            let base = a.as_u128();
+            return Some(ranges_from_boundaries(
+                offsets
+                    .into_iter()
+                    .map(|offset| Value::Uuid(uuid::Uuid::from_u128(base + offset))),
+            ));
+        }
+        _ => return None,
+    };
+    let width = if hi > lo { (hi - lo) as u128 } else { 0 };
+    let offsets = boundary_offsets(width, parts);
+    Some(ranges_from_boundaries(offsets.into_iter().map(|offset| {
+        // Every boundary lies within [min, max], so it fits the key type.
+        let key = lo + offset as i128;
+        match min {
+            Value::Int16(_) => Value::Int16(key as i16),
+            Value::Int32(_) => Value::Int32(key as i32),
+            _ => Value::Int64(key as i64),
+        }
+    })))
+}
+
+/// Offsets (from the minimum key) of the `parts - 1` interior boundaries over
+/// a key space `width + 1` keys wide. Offsets are strictly increasing and lie
+/// in `1..=width`.
+fn boundary_offsets(width: u128, parts: usize) -> Vec<u128> {
+    let span = width.saturating_add(1);
+    let parts = (parts.max(1) as u128).min(span);
+    let (step, rem) = (span / parts, span % parts);
+    (1..parts).map(|i| step * i + rem * i / parts).collect()
+}
+
+fn ranges_from_boundaries(boundaries: impl Iterator<Item = Value>) -> Vec<KeyRange> {
+    let mut ranges = Vec::new();
+    let mut lower = None;
+    for boundary in boundaries {
+        ranges.push(KeyRange {
+            lower: lower.take(),
+            upper: Some(boundary.clone()),
+        });
+        lower = Some(boundary);
+    }
+    ranges.push(KeyRange { lower, upper: None });
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contains(range: &KeyRange, key: i64) -> bool {
+        let as_i64 = |v: &Value| match v {
+            Value::Int64(n) => *n,
+            Value::Int32(n) => i64::from(*n),
+            Value::Int16(n) => i64::from(*n),
+            other => panic!("unexpected bound {other:?}"),
+        };
+        range.lower.as_ref().is_none_or(|l| key >= as_i64(l))
+            && range.upper.as_ref().is_none_or(|u| key < as_i64(u))
+    }
+
+    #[test]
+    fn test_split_covers_every_key_exactly_once() {
+        for (min, max) in [
+            (1i64, 100i64),
+            (-7, 7),
+            (0, 0),
+            (5, 8),
+            (i64::MIN, i64::MAX),
+        ] {
+            for parts in 1..=9 {
+                let ranges =
+                    split_key_range(&Value::Int64(min), &Value::Int64(max), parts).unwrap();
+                assert!(ranges.len() <= parts);
+                assert_eq!(ranges.first().unwrap().lower, None);
+                assert_eq!(ranges.last().unwrap().upper, None);
+                for pair in ranges.windows(2) {
+                    assert_eq!(pair[0].upper, pair[1].lower, "gap in {ranges:?}");
+                }
+                // Probe keys around every boundary and the probed extremes.
+                let mut keys = vec![min, max, min.saturating_sub(1), max.saturating_add(1)];
+                for range in &ranges {
+                    if let Some(Value::Int64(b)) = range.upper {
+                        keys.extend([b - 1, b, b.saturating_add(1)]);
+                    }
+                }
+                for key in keys {
+                    let hits = ranges.iter().filter(|r| contains(r, key)).count();
+                    assert_eq!(hits, 1, "key {key} in {hits} ranges of {ranges:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_keeps_key_type_and_caps_parts() {
+        let ranges = split_key_range(&Value::Int32(10), &Value::Int32(12), 8).unwrap();
+        assert_eq!(
+            ranges,
+            vec![
+                KeyRange {
+                    lower: None,
+                    upper: Some(Value::Int32(11)),
+                },
+                KeyRange {
+                    lower: Some(Value::Int32(11)),
+                    upper: Some(Value::Int32(12)),
+                },
+                KeyRange {
+                    lower: Some(Value::Int32(12)),
+                    upper: None,
+                },
+            ]
+        );
+        assert_eq!(
+            split_key_range(&Value::Int64(1), &Value::Int64(1000), 1).unwrap(),
+            vec![KeyRange::full()]
+        );
+        assert!(split_key_range(&Value::Text("a".into()), &Value::Text("z".into()), 4).is_none());
+        assert!(split_key_range(&Value::Int32(1), &Value::Int64(9), 4).is_none());
+    }
+
+    #[test]
+    fn test_split_uuid_range() {
+        let min = uuid::Uuid::from_u128(0);
+        let max = uuid::Uuid::from_u128(u128::MAX);
+        let ranges = split_key_range(&Value::Uuid(min), &Value::Uuid(max), 4).unwrap();
+        assert_eq!(ranges.len(), 4);
+        assert_eq!(
+            ranges[0].upper,
+            Some(Value::Uuid(uuid::Uuid::from_u128(u128::MAX / 4)))
+        );
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[0].upper, pair[1].lower);
+        }
+    }
+
+    #[test]
+    fn test_parse_parallel_reads() {
+        let parsed =
+            parse_parallel_reads(&["events=4".to_string(), " users = 2 ".to_string()]).unwrap();
+        assert_eq!(parsed.get("events"), Some(&4));
+        assert_eq!(parsed.get("users"), Some(&2));
+        assert!(parse_parallel_reads(&["events".to_string()]).is_err());
+        assert!(parse_parallel_reads(&["events=0".to_string()]).is_err());
+        assert!(parse_parallel_reads(&["=3".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,540 @@
+//! Keyset-pagination predicate builder shared by SQL sources.
+//!
+//! Full-sync readers page through a table by ordering on one or more key
+//! columns and carrying the key of the last row read as a cursor. For the next
+//! chunk they select rows strictly greater than the cursor:
+//!
+//! - When every key column is `NOT NULL`, the compact row-value comparison
+//!   `(a, b) > ($1, $2)` is used (a single column degenerates to `a > $1`).
+//! - When any key column is nullable, the comparison is expanded
+//!   lexicographically (`a > $1 OR (a = $1 AND b > $2)`) with explicit `IS NULL`
+//!   terms, because SQL row-value comparisons evaluate to `NULL` (and silently
+//!   drop rows) as soon as a NULL is involved.
+//!
+//! The caller picks [`NullsOrder`] to match the `ORDER BY` it emits (see
+//! [`keyset_order_by`]); the predicate and the ordering must agree or chunks
+//! will skip or repeat rows.
+
+use std::collections::HashMap;
+
+use crate::values::Value;
+
+/// Per-table keyset sort columns (`table -> ordered column names`).
+pub type KeysetColumns = HashMap<String, Vec<String>>;
+
+/// Error parsing `--keyset-columns` entries.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{0}")]
+pub struct KeysetColumnsError(String);
+
+/// Parse CLI-style `table=col1,col2` entries (repeatable). Empty entries are
+/// ignored. A column may appear only once per table, since repeating it adds
+/// nothing to the sort key.
+pub fn parse_keyset_columns(entries: &[String]) -> Result<KeysetColumns, KeysetColumnsError> {
+    let mut out = KeysetColumns::new();
+    for entry in entries {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let Some((table, cols)) = entry.split_once('=') else {
+            return Err(KeysetColumnsError(format!(
+                "keyset-columns entry '{entry}' must be table=col1,col2"
+            )));
+        };
+        let table = table.trim();
+        if table.is_empty() {
+            return Err(KeysetColumnsError(format!(
+                "keyset-columns entry '{entry}' has an empty table name"
+            )));
+        }
+        let mut columns: Vec<String> = Vec::new();
+        for col in cols.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+            if columns.iter().any(|c| c == col) {
+                return Err(KeysetColumnsError(format!(
+                    "keyset-columns entry '{entry}' repeats column '{col}'"
+                )));
+            }
+            columns.push(col.to_string());
+        }
+        if columns.is_empty() {
+            return Err(KeysetColumnsError(format!(
+                "keyset-columns entry '{entry}' has no column names"
+            )));
+        }
+        out.insert(table.to_string(), columns);
+    }
+    Ok(out)
+}
+
+/// One column of a keyset-pagination sort key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetColumn {
+    /// Column name, already quoted/qualified as the caller's SQL requires.
+    pub name: String,
+    /// Whether the column may hold NULL.
+    pub nullable: bool,
+}
+
+impl KeysetColumn {
+    /// A `NOT NULL` key column (e.g. a primary-key column).
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            nullable: false,
+        }
+    }
+
+    /// A key column that may hold NULL.
+    pub fn nullable(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            nullable: true,
+        }
+    }
+}
+
+/// Where NULLs sort relative to non-NULL values in ascending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullsOrder {
+    /// NULLs sort before all values (MySQL's ascending default).
+    First,
+    /// NULLs sort after all values (PostgreSQL's ascending default).
+    Last,
+}
+
+/// A keyset predicate ready to be appended to a `WHERE` clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetPredicate {
+    /// SQL boolean expression (without the `WHERE` keyword).
+    pub sql: String,
+    /// Cursor value indexes to bind, in placeholder order.
+    ///
+    /// The same cursor value may appear more than once in the expanded form;
+    /// NULL cursor values are never bound (they render as `IS NULL`).
+    pub binds: Vec<usize>,
+}
+
+/// Build the `ORDER BY` column list for `columns`.
+///
+/// When `explicit_nulls` is true and a column is nullable, `NULLS FIRST` /
+/// `NULLS LAST` is appended so the ordering matches `nulls` regardless of the
+/// server default (PostgreSQL). Dialects without that syntax (MySQL) pass
+/// `false` and select the [`NullsOrder`] matching their default instead.
+pub fn keyset_order_by(
+    columns: &[KeysetColumn],
+    nulls: NullsOrder,
+    explicit_nulls: bool,
+) -> String {
+    columns
+        .iter()
+        .map(|c| {
+            if explicit_nulls && c.nullable {
+                match nulls {
+                    NullsOrder::First => format!("{} ASC NULLS FIRST", c.name),
+                    NullsOrder::Last => format!("{} ASC NULLS LAST", c.name),
+                }
+            } else {
+                c.name.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Build the "strictly after `cursor`" predicate over `columns`.
+///
+/// `placeholder(n)` renders the `n`-th bind placeholder (1-based), e.g.
+/// `|n| format!("${n}")` for PostgreSQL or `|_| "?".to_string()` for MySQL.
+///
+/// Returns `None` when no row can follow the cursor (only possible with
+/// [`NullsOrder::Last`] and a cursor that is NULL in every nullable column
+/// after the last non-NULL prefix, e.g. a single nullable column at NULL).
+///
+/// # Panics
+///
+/// Panics if `cursor.len() != columns.len()`; callers validate cursor length
+/// against the key before building the predicate.
+pub fn keyset_after_predicate(
+    columns: &[KeysetColumn],
+    cursor: &[Value],
+    nulls: NullsOrder,
+    mut placeholder: impl FnMut(usize) -> String,
+) -> Option<KeysetPredicate> {
+    assert_eq!(
+        columns.len(),
+        cursor.len(),
+        "keyset cursor length must match key column count"
+    );
+
+    let any_null = columns.iter().any(|c| c.nullable) || cursor.iter().any(Value::is_null);
+    let mut binds = Vec::new();
+    let mut next = |idx: usize, binds: &mut Vec<usize>| {
+        binds.push(idx);
+        placeholder(binds.len())
+    };
+
+    if !any_null {
+        let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+        let holders: Vec<String> = (0..columns.len()).map(|i| next(i, &mut binds)).collect();
+        let sql = if columns.len() == 1 {
+            format!("{} > {}", names[0], holders[0])
+        } else {
+            format!("({}) > ({})", names.join(", "), holders.join(", "))
+        };
+        return Some(KeysetPredicate { sql, binds });
+    }
+
+    // Lexicographic expansion: for each position i, the first i columns equal
+    // the cursor and column i is strictly greater.
+    let mut disjuncts = Vec::new();
+    for i in 0..columns.len() {
+        // NULL is the largest value under NULLS LAST: nothing follows it here.
+        if cursor[i].is_null() && nulls == NullsOrder::Last {
+            continue;
+        }
+        // Render terms left to right so positional (`?`) binds stay in order.
+        let mut terms = Vec::with_capacity(i + 1);
+        for j in 0..i {
+            terms.push(equal_term(
+                &columns[j],
+                &cursor[j],
+                j,
+                &mut binds,
+                &mut next,
+            ));
+        }
+        terms.push(greater_term(
+            &columns[i],
+            &cursor[i],
+            i,
+            nulls,
+            &mut binds,
+            &mut next,
+        ));
+        disjuncts.push(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            format!("({})", terms.join(" AND "))
+        });
+    }
+
+    if disjuncts.is_empty() {
+        return None;
+    }
+    let sql = if disjuncts.len() == 1 {
+        disjuncts.remove(0)
+    } else {
+        format!("({})", disjuncts.join(" OR "))
+    };
+    Some(KeysetPredicate { sql, binds })
+}
+
+/// `column = cursor` with NULL-safe semantics.
+fn equal_term(
+    column: &KeysetColumn,
+    value: &Value,
+    idx: usize,
+    binds: &mut Vec<usize>,
+    next: &mut impl FnMut(usize, &mut Vec<usize>) -> String,
+) -> String {
+    if value.is_null() {
+        format!("{} IS NULL", column.name)
+    } else {
+        format!("{} = {}", column.name, next(idx, binds))
+    }
+}
+
+/// `column > cursor` under the given NULL ordering.
+///
+/// The caller skips the `(NULL, NullsOrder::Last)` case, where nothing sorts
+/// after the cursor value in this column.
+fn greater_term(
+    column: &KeysetColumn,
+    value: &Value,
+    idx: usize,
+    nulls: NullsOrder,
+    binds: &mut Vec<usize>,
+    next: &mut impl FnMut(usize, &mut Vec<usize>) -> String,
+) -> String {
+    if value.is_null() {
+        // NULL is the smallest value: every non-NULL sorts after it.
+        return format!("{} IS NOT NULL", column.name);
+    }
+    if column.nullable && nulls == NullsOrder::Last {
+        return format!(
+            "({} > {} OR {} IS NULL)",
+            column.name,
+            next(idx, binds),
+            column.name
+        );
+    }
+    format!("{} > {}", column.name, next(idx, binds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    fn pg(n: usize) -> String {
+        format!("${n}")
+    }
+
+    #[test]
+    fn parse_keyset_columns_keeps_column_order() {
+        let parsed =
+            parse_keyset_columns(&["events=tenant_id, created_at".to_string(), " ".to_string()])
+                .unwrap();
+        assert_eq!(
+            parsed.get("events"),
+            Some(&vec!["tenant_id".to_string(), "created_at".to_string()])
+        );
+        assert_eq!(parsed.len(), 1);
+        assert!(parse_keyset_columns(&["tenant_id,created_at".to_string()]).is_err());
+        assert!(parse_keyset_columns(&["=a".to_string()]).is_err());
+        assert!(parse_keyset_columns(&["events=".to_string()]).is_err());
+        assert!(parse_keyset_columns(&["events=a,a".to_string()]).is_err());
+    }
+
+    #[test]
+    fn not_null_single_column_is_simple_comparison() {
+        let p = keyset_after_predicate(
+            &[KeysetColumn::new("id")],
+            &[Value::Int64(5)],
+            NullsOrder::Last,
+            pg,
+        )
+        .unwrap();
+        assert_eq!(p.sql, "id > $1");
+        assert_eq!(p.binds, vec![0]);
+    }
+
+    #[test]
+    fn not_null_composite_uses_row_value_comparison() {
+        let p = keyset_after_predicate(
+            &[
+                KeysetColumn::new("tenant_id"),
+                KeysetColumn::new("created_at"),
+            ],
+            &[Value::Int32(1), Value::Int32(2)],
+            NullsOrder::Last,
+            pg,
+        )
+        .unwrap();
+        assert_eq!(p.sql, "(tenant_id, created_at) > ($1, $2)");
+        assert_eq!(p.binds, vec![0, 1]);
+    }
+
+    #[test]
+    fn nullable_composite_expands_with_nulls_last() {
+        let cols = [KeysetColumn::new("a"), KeysetColumn::nullable("b")];
+        let p = keyset_after_predicate(
+            &cols,
+            &[Value::Int32(1), Value::Int32(2)],
+            NullsOrder::Last,
+            pg,
+        )
+        .unwrap();
+        assert_eq!(p.sql, "(a > $1 OR (a = $2 AND (b > $3 OR b IS NULL)))");
+        assert_eq!(p.binds, vec![0, 0, 1]);
+
+        // Cursor NULL in the last nullable column: only a larger `a` follows.
+        let p =
+            keyset_after_predicate(&cols, &[Value::Int32(1), Value::Null], NullsOrder::Last, pg)
+                .unwrap();
+        assert_eq!(p.sql, "a > $1");
+        assert_eq!(p.binds, vec![0]);
+    }
+
+    #[test]
+    fn nullable_composite_expands_with_nulls_first() {
+        let cols = [KeysetColumn::new("a"), KeysetColumn::nullable("b")];
+        let p = keyset_after_predicate(
+            &cols,
+            &[Value::Int32(1), Value::Null],
+            NullsOrder::First,
+            |_| "?".to_string(),
+        )
+        .unwrap();
+        assert_eq!(p.sql, "(a > ? OR (a = ? AND b IS NOT NULL))");
+        assert_eq!(p.binds, vec![0, 0]);
+    }
+
+    #[test]
+    fn single_nullable_column_at_null_with_nulls_last_has_no_successor() {
+        let p = keyset_after_predicate(
+            &[KeysetColumn::nullable("a")],
+            &[Value::Null],
+            NullsOrder::Last,
+            pg,
+        );
+        assert!(p.is_none());
+    }
+
+    #[test]
+    fn order_by_appends_explicit_null_ordering() {
+        let cols = [KeysetColumn::new("a"), KeysetColumn::nullable("b")];
+        assert_eq!(
+            keyset_order_by(&cols, NullsOrder::Last, true),
+            "a, b ASC NULLS LAST"
+        );
+        assert_eq!(keyset_order_by(&cols, NullsOrder::First, false), "a, b");
+    }
+
+    // ---- Paging simulation over an in-memory table -------------------------
+
+    fn cmp_opt(a: Option<i32>, b: Option<i32>, nulls: NullsOrder) -> Ordering {
+        match (a, b) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => match nulls {
+                NullsOrder::First => Ordering::Less,
+                NullsOrder::Last => Ordering::Greater,
+            },
+            (Some(_), None) => match nulls {
+                NullsOrder::First => Ordering::Greater,
+                NullsOrder::Last => Ordering::Less,
+            },
+            (Some(x), Some(y)) => x.cmp(&y),
+        }
+    }
+
+    /// Evaluate the rendered predicate against one row by re-parsing its
+    /// structure: each disjunct is a conjunction of `col = v`, `col IS NULL`,
+    /// `col IS NOT NULL`, `col > v` and `(col > v OR col IS NULL)` terms.
+    fn eval(sql: &str, binds: &[Option<i32>], row: &[Option<i32>; 2]) -> bool {
+        let col = |name: &str| match name {
+            "a" => row[0],
+            "b" => row[1],
+            other => panic!("unknown column {other}"),
+        };
+        let strip = |s: &str| {
+            s.strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .map(str::to_string)
+                .unwrap_or_else(|| s.to_string())
+        };
+        let mut bind_iter = binds.iter();
+        let body = strip(sql);
+        let disjuncts: Vec<String> = split_top(&body, " OR ");
+        let mut any = false;
+        for d in disjuncts {
+            let d = strip(&d);
+            let mut all = true;
+            for term in split_top(&d, " AND ") {
+                let term = strip(&term);
+                let ok = if let Some(name) = term.strip_suffix(" IS NULL") {
+                    if let Some((lhs, _)) = term.split_once(" > ") {
+                        // `x > $n OR x IS NULL`
+                        let v = bind_iter.next().unwrap().unwrap();
+                        let lhs = lhs.trim();
+                        col(lhs).is_none_or(|c| c > v)
+                    } else {
+                        col(name).is_none()
+                    }
+                } else if let Some(name) = term.strip_suffix(" IS NOT NULL") {
+                    col(name).is_some()
+                } else if let Some((name, _)) = term.split_once(" = ") {
+                    let v = bind_iter.next().unwrap().unwrap();
+                    col(name) == Some(v)
+                } else if let Some((name, _)) = term.split_once(" > ") {
+                    let v = bind_iter.next().unwrap().unwrap();
+                    col(name).is_some_and(|c| c > v)
+                } else {
+                    panic!("unexpected term {term}");
+                };
+                all &= ok;
+            }
+            any |= all;
+        }
+        any
+    }
+
+    /// Split on `sep` at parenthesis depth zero.
+    fn split_top(s: &str, sep: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut depth = 0i32;
+        let mut start = 0;
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            match bytes[i] {
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 && s[i..].starts_with(sep) {
+                out.push(s[start..i].to_string());
+                i += sep.len();
+                start = i;
+                continue;
+            }
+            i += 1;
+        }
+        out.push(s[start..].to_string());
+        out
+    }
+
+    fn page_through(nulls: NullsOrder, limit: usize) {
+        let cols = [KeysetColumn::new("a"), KeysetColumn::nullable("b")];
+        let mut table: Vec<[Option<i32>; 2]> = Vec::new();
+        for a in 0..4 {
+            for b in [None, Some(0), Some(1), Some(2)] {
+                table.push([Some(a), b]);
+            }
+        }
+        let sort = |rows: &mut Vec<[Option<i32>; 2]>| {
+            rows.sort_by(|x, y| cmp_opt(x[0], y[0], nulls).then(cmp_opt(x[1], y[1], nulls)))
+        };
+        let mut expected = table.clone();
+        sort(&mut expected);
+
+        let mut read = Vec::new();
+        let mut cursor: Option<[Option<i32>; 2]> = None;
+        loop {
+            let mut candidates: Vec<[Option<i32>; 2]> = match cursor {
+                None => table.clone(),
+                Some(c) => {
+                    let values: Vec<Value> = c
+                        .iter()
+                        .map(|v| v.map(Value::Int32).unwrap_or(Value::Null))
+                        .collect();
+                    let Some(p) = keyset_after_predicate(&cols, &values, nulls, pg) else {
+                        break;
+                    };
+                    let binds: Vec<Option<i32>> = p.binds.iter().map(|&i| c[i]).collect();
+                    table
+                        .iter()
+                        .filter(|row| eval(&p.sql, &binds, row))
+                        .copied()
+                        .collect()
+                }
+            };
+            sort(&mut candidates);
+            candidates.truncate(limit);
+            if candidates.is_empty() {
+                break;
+            }
+            cursor = candidates.last().copied();
+            read.extend(candidates);
+        }
+        assert_eq!(
+            read, expected,
+            "paging must be complete, gap-free and duplicate-free"
+        );
+    }
+
+    #[test]
+    fn composite_nullable_key_pages_completely_nulls_last() {
+        for limit in 1..=5 {
+            page_through(NullsOrder::Last, limit);
+        }
+    }
+
+    #[test]
+    fn composite_nullable_key_pages_completely_nulls_first() {
+        for limit in 1..=5 {
+            page_through(NullsOrder::First, limit);
+        }
+    }
+}
@@ -44,24 +44,44 @@
 //! // let mysql_value: MySQLValue = value.into();
 //! ```
 
+pub mod change_record;
 pub mod checkpoint;
+pub mod column_filter;
+pub mod estimate;
 pub mod foreign_keys;
 pub mod id_columns;
+pub mod infer;
+pub mod introspect;
+pub mod key_range;
+pub mod keyset;
 pub mod relation_change;
 pub mod schema;
 pub mod sink;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod transform;
 pub mod types;
+pub mod validation;
 pub mod values;
 
 // Re-exports for convenience
 // Checkpoint API (storage backends live in separate crates)
-pub use sink::{SinkConnect, SinkWithCheckpoints, SurrealConfig, SurrealSdkVersion, SurrealSink};
+pub use sink::{
+    partition_write_lanes, partition_write_lanes_ordered, partition_write_lanes_per_table,
+    ArrayMergeStrategy, ChangeConsumer, CircuitBreakerConfig, ConflictPolicy, ConsumerSink,
+    LargeFieldConfig, MergeConfig, SinkConnect, SinkWithCheckpoints, SoftDeleteConfig,
+    SurrealConfig, SurrealSdkVersion, SurrealSink, TableLanes, TableWriteEstimate, TeeSink,
+    WriteEstimate, WriteRetryConfig, WriteTemplate, SYNC_META_FIELD,
+};
+
+// Versioned JSON change records for external consumers
+pub use change_record::{value_to_json, ChangeRecord, RecordOp, RecordRef, CHANGE_RECORD_VERSION};
 
 pub use checkpoint::{
     Checkpoint, CheckpointFile, CheckpointID, CheckpointStorage, CheckpointStore,
-    InterleavedSnapshotCheckpoint, NullStore, NullSyncManager, SnapshotCheckpointer,
-    SnapshotTableProgress, StoredCheckpoint, SyncConfig, SyncManager, SyncPhase,
+    CheckpointValidity, InterleavedSnapshotCheckpoint, NullStore, NullSyncManager,
+    SnapshotCheckpointer, SnapshotTableProgress, StoredCheckpoint, SyncConfig, SyncManager,
+    SyncPhase,
 };
 
 pub use transform::{InPlaceTransform, Passthrough};
@@ -69,20 +89,43 @@ pub use transform::{InPlaceTransform, Passthrough};
 // Foreign key types
 pub use foreign_keys::{classify_table, ForeignKeyDefinition, TableKind};
 
+// Per-table column include/exclude filtering
+pub use column_filter::{ColumnFilter, ColumnFilterError, ColumnFilters, ExcludedFields};
+
 // ID / primary-key column helpers
 pub use id_columns::{
     apply_id_column_overrides, build_composite_record_id, flatten_composite_id,
     parse_id_column_overrides, stringify_id_part, IdColumnOverrides, IdColumnsError,
 };
 
+// Schema inference from sampled records
+pub use infer::{infer_value_type, merge_types, TableSchemaInferrer};
+
+// Source introspection
+pub use introspect::SourceEntity;
+
+// Migration size estimates (`--count-only`)
+pub use estimate::{MigrationEstimate, TableEstimate};
+
+// Key-range splitting for intra-table parallel reads
+pub use key_range::{
+    parse_parallel_reads, split_key_range, KeyRange, ParallelReads, ParallelReadsError,
+};
+
+// Keyset pagination (composite / nullable sort keys)
+pub use keyset::{
+    keyset_after_predicate, keyset_order_by, parse_keyset_columns, KeysetColumn, KeysetColumns,
+    KeysetColumnsError, KeysetPredicate, NullsOrder,
+};
+
 // Base types (context-neutral, no generators)
-pub use schema::{ColumnDefinition, DatabaseSchema, TableDefinition};
+pub use schema::{ColumnDefinition, ColumnDrift, DatabaseSchema, TableDefinition};
 
 // Generator types (with generators)
 pub use schema::{
-    FieldDefinition, GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition,
-    GeneratorSchema, GeneratorTableDefinition, IDDefinition, Schema, SchemaError,
-    TableDefinitionWithGenerators,
+    FieldConstraints, FieldDefinition, GeneratorConfig, GeneratorFieldDefinition,
+    GeneratorIDDefinition, GeneratorSchema, GeneratorTableDefinition, IDDefinition, Schema,
+    SchemaError, TableDefinitionWithGenerators,
 };
 
 // Legacy alias for backwards compatibility
@@ -90,7 +133,9 @@ pub use schema::{
 pub type LoadTestSchema = Schema;
 pub use relation_change::RelationChange;
 pub use types::{GeometryType, ToDdl, Type};
+pub use validation::{ValidationFailure, ValidationQuery};
 pub use values::{
-    Change, ChangeOp, GeometryData, Relation, Row, RowBuilder, RowConverter, ThingRef, TypedValue,
-    TypedValueError, Value, ZeroTemporalPolicy,
+    check_decimal_fits, parse_iso8601_duration, Change, ChangeOp, DuplicateFieldError, FieldMap,
+    GeometryData, Relation, Row, RowBuilder, RowConverter, ThingRef, TypedValue, TypedValueError,
+    Value, ZeroTemporalPolicy,
 };
@@ -46,8 +46,7 @@ impl RelationChange {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::values::{ThingRef, Value};
-    use std::collections::HashMap;
+    use crate::values::{FieldMap, ThingRef, Value};
 
     #[test]
     fn test_create_relation_change() {
@@ -56,7 +55,7 @@ mod tests {
             Value::Int64(1),
             ThingRef::new("books", Value::Int64(10)),
             ThingRef::new("tags", Value::Int64(20)),
-            HashMap::new(),
+            FieldMap::new(),
         );
         let change = RelationChange::create(rel);
         assert_eq!(change.operation, ChangeOp::Create);
@@ -70,7 +69,7 @@ mod tests {
             Value::Int64(1),
             ThingRef::new("books", Value::Int64(10)),
             ThingRef::new("tags", Value::Int64(20)),
-            HashMap::new(),
+            FieldMap::new(),
         );
         let change = RelationChange::delete(rel);
         assert_eq!(change.operation, ChangeOp::Delete);
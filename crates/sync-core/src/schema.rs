@@ -22,6 +22,7 @@
 
 use crate::foreign_keys::ForeignKeyDefinition;
 use crate::types::Type;
+use crate::validation::ValidationQuery;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
@@ -53,6 +54,22 @@ pub enum SchemaError {
     /// Column not found in table schema
     #[error("Column '{column}' not found in table '{table}'")]
     ColumnNotFound { table: String, column: String },
+
+    /// Field constraints that do not apply to the field's type
+    #[error("Invalid constraints on field '{field}' of table '{table}': {reason}")]
+    InvalidConstraints {
+        table: String,
+        field: String,
+        reason: String,
+    },
+
+    /// Generator configuration that cannot generate values
+    #[error("Invalid generator for field '{field}' of table '{table}': {reason}")]
+    InvalidGenerator {
+        table: String,
+        field: String,
+        reason: String,
+    },
 }
 
 // ============================================================================
@@ -62,7 +79,7 @@ pub enum SchemaError {
 /// Column definition - shared by both introspection and generation.
 ///
 /// This type represents a single column in a database table,
-/// including its name, type, and nullability.
+/// including its name, type, nullability, and whether it is writable.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ColumnDefinition {
     /// Column name
@@ -75,6 +92,12 @@ pub struct ColumnDefinition {
     /// Whether this column is nullable
     #[serde(default)]
     pub nullable: bool,
+
+    /// Whether the source computes this column (e.g. a PostgreSQL
+    /// `GENERATED ALWAYS AS` column). Its values are still read and synced,
+    /// but it must never be written back to the source.
+    #[serde(default)]
+    pub read_only: bool,
 }
 
 impl ColumnDefinition {
@@ -84,6 +107,7 @@ impl ColumnDefinition {
             name: name.into(),
             column_type,
             nullable: false,
+            read_only: false,
         }
     }
 
@@ -93,8 +117,15 @@ impl ColumnDefinition {
             name: name.into(),
             column_type,
             nullable: true,
+            read_only: false,
         }
     }
+
+    /// Mark this column as computed by the source (see [`Self::read_only`]).
+    pub fn mark_read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
 }
 
 /// Table schema definition (no generators).
@@ -158,6 +189,38 @@ impl TableDefinition {
         names
     }
 
+    /// Get the names of columns that may be written back to the source
+    /// (including primary key), i.e. all columns except read-only ones.
+    pub fn writable_column_names(&self) -> Vec<&str> {
+        std::iter::once(&self.primary_key)
+            .chain(&self.columns)
+            .filter(|c| !c.read_only)
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    /// Compare this schema with the column names a source reports for the
+    /// table at change time (e.g. a pgoutput `Relation` message).
+    ///
+    /// Read-only columns are never reported missing: sources such as pgoutput
+    /// do not replicate generated columns, so their absence is not drift.
+    pub fn column_drift<S: AsRef<str>>(&self, reported: &[S]) -> ColumnDrift {
+        let missing = self
+            .writable_column_names()
+            .into_iter()
+            .filter(|name| !reported.iter().any(|r| r.as_ref() == *name))
+            .map(str::to_string)
+            .collect();
+        let known = self.column_names();
+        let added = reported
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|name| !known.contains(name))
+            .map(str::to_string)
+            .collect();
+        ColumnDrift { missing, added }
+    }
+
     /// Get the primary key column names (supports composite keys).
     pub fn primary_key_column_names(&self) -> Vec<&str> {
         if let Some(ref cpk) = self.composite_primary_key {
@@ -168,6 +231,22 @@ impl TableDefinition {
     }
 }
 
+/// Column-name differences found by [`TableDefinition::column_drift`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnDrift {
+    /// Writable schema columns the source no longer reports.
+    pub missing: Vec<String>,
+    /// Reported columns the schema does not know.
+    pub added: Vec<String>,
+}
+
+impl ColumnDrift {
+    /// Whether the reported columns match the schema.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.added.is_empty()
+    }
+}
+
 /// Database schema (collection of tables, no generators).
 ///
 /// Used for schema introspection during incremental sync operations.
@@ -321,6 +400,10 @@ pub enum GeneratorConfig {
     OneOf {
         /// Pool of values to select from
         values: Vec<serde_yaml::Value>,
+        /// Relative weight of each value, parallel to `values`. Uniform
+        /// selection when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weights: Option<Vec<f64>>,
     },
 
     /// Generate arrays by sampling from a pool
@@ -368,6 +451,96 @@ pub struct GeneratorFieldDefinition {
     /// Whether this field is nullable
     #[serde(default)]
     pub nullable: bool,
+
+    /// Whether elements of this array field may be null
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub nullable_elements: bool,
+
+    /// Whether the target should index this field
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub indexed: bool,
+
+    /// Value constraints the target should enforce for this field
+    #[serde(default, skip_serializing_if = "FieldConstraints::is_empty")]
+    pub constraints: FieldConstraints,
+}
+
+/// Declared value constraints of a field.
+///
+/// `min` and `max` bound the value of numeric fields and the length of
+/// string and array fields; `pattern` is a regular expression string values
+/// must match. Bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldConstraints {
+    /// Smallest allowed value or length
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+
+    /// Largest allowed value or length
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+
+    /// Regular expression string values must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+impl FieldConstraints {
+    /// Whether no constraint is declared.
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none() && self.max.is_none() && self.pattern.is_none()
+    }
+
+    /// Check that the constraints apply to a field of `field_type`: bounds
+    /// need a numeric, string or array type, a pattern needs a string type,
+    /// and `min` may not exceed `max`.
+    pub fn check(&self, field_type: &Type) -> Result<(), String> {
+        let bounded = field_type.is_numeric()
+            || field_type.is_string()
+            || matches!(field_type, Type::Array { .. } | Type::Set { .. });
+        if (self.min.is_some() || self.max.is_some()) && !bounded {
+            return Err(format!("min/max do not apply to {field_type:?}"));
+        }
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return Err(format!("min {min} is greater than max {max}"));
+            }
+        }
+        if self.pattern.is_some() && !field_type.is_string() {
+            return Err(format!("pattern does not apply to {field_type:?}"));
+        }
+        Ok(())
+    }
+}
+
+impl GeneratorConfig {
+    /// Check that the configuration can generate values: `one_of` weights
+    /// must pair up with the values, be finite and non-negative, and not all
+    /// be zero.
+    pub fn check(&self) -> Result<(), String> {
+        if let GeneratorConfig::OneOf {
+            values,
+            weights: Some(weights),
+        } = self
+        {
+            if weights.len() != values.len() {
+                return Err(format!(
+                    "one_of has {} weights for {} values",
+                    weights.len(),
+                    values.len()
+                ));
+            }
+            if let Some(weight) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+                return Err(format!(
+                    "one_of weight {weight} is not a finite non-negative number"
+                ));
+            }
+            if !values.is_empty() && weights.iter().all(|w| *w == 0.0) {
+                return Err("one_of weights are all zero".to_string());
+            }
+        }
+        Ok(())
+    }
 }
 
 impl GeneratorFieldDefinition {
@@ -377,6 +550,7 @@ impl GeneratorFieldDefinition {
             name: self.name.clone(),
             column_type: self.field_type.clone(),
             nullable: self.nullable,
+            read_only: false,
         }
     }
 }
@@ -400,6 +574,7 @@ impl GeneratorIDDefinition {
             name: "id".to_string(),
             column_type: self.id_type.clone(),
             nullable: false, // Primary keys are never nullable
+            read_only: false,
         }
     }
 }
@@ -467,12 +642,29 @@ pub struct GeneratorSchema {
     /// Table definitions with generators
     pub tables: Vec<GeneratorTableDefinition>,
 
+    /// Queries run against SurrealDB after a full sync, each expected to
+    /// return no rows (see [`ValidationQuery`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validations: Vec<ValidationQuery>,
+
     /// Cached table lookup (not serialized)
     #[serde(skip)]
     table_map: HashMap<String, usize>,
 }
 
 impl GeneratorSchema {
+    /// Create a new schema (version 1) from a list of table definitions.
+    pub fn new(tables: Vec<GeneratorTableDefinition>) -> Self {
+        let mut schema = Self {
+            version: default_version(),
+            tables,
+            validations: Vec::new(),
+            table_map: HashMap::new(),
+        };
+        schema.build_table_map();
+        schema
+    }
+
     /// Load schema from a YAML file.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, SchemaError> {
         let content = fs::read_to_string(path)?;
@@ -483,9 +675,56 @@ impl GeneratorSchema {
     pub fn from_yaml(yaml: &str) -> Result<Self, SchemaError> {
         let mut schema: GeneratorSchema = serde_yaml::from_str(yaml)?;
         schema.build_table_map();
+        schema.validate_constraints()?;
+        schema.validate_generators()?;
         Ok(schema)
     }
 
+    /// Check that every ID and field generator can generate values (see
+    /// [`GeneratorConfig::check`]).
+    pub fn validate_generators(&self) -> Result<(), SchemaError> {
+        for table in &self.tables {
+            let generators = std::iter::once(("id", &table.id.generator)).chain(
+                table
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.as_str(), &field.generator)),
+            );
+            for (field, generator) in generators {
+                generator
+                    .check()
+                    .map_err(|reason| SchemaError::InvalidGenerator {
+                        table: table.name.clone(),
+                        field: field.to_string(),
+                        reason,
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every field's [`FieldConstraints`] apply to its type.
+    pub fn validate_constraints(&self) -> Result<(), SchemaError> {
+        for table in &self.tables {
+            for field in &table.fields {
+                field
+                    .constraints
+                    .check(&field.field_type)
+                    .map_err(|reason| SchemaError::InvalidConstraints {
+                        table: table.name.clone(),
+                        field: field.name.clone(),
+                        reason,
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Render the schema as YAML that [`GeneratorSchema::from_yaml`] accepts.
+    pub fn to_yaml(&self) -> Result<String, SchemaError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
     /// Build the internal table lookup map.
     fn build_table_map(&mut self) {
         self.table_map = self
@@ -578,6 +817,49 @@ mod tests {
         assert_eq!(col.nullable, parsed.nullable);
     }
 
+    #[test]
+    fn test_column_definition_read_only_defaults_and_roundtrips() {
+        let parsed: ColumnDefinition = serde_yaml::from_str("name: total\ntype: int\n").unwrap();
+        assert!(!parsed.read_only);
+
+        let col = ColumnDefinition::new("total", Type::Int32).mark_read_only();
+        let yaml = serde_yaml::to_string(&col).unwrap();
+        let parsed: ColumnDefinition = serde_yaml::from_str(&yaml).unwrap();
+        assert!(parsed.read_only);
+    }
+
+    #[test]
+    fn test_table_definition_writable_column_names_skip_read_only() {
+        let table = TableDefinition::new(
+            "orders",
+            ColumnDefinition::new("id", Type::Int64),
+            vec![
+                ColumnDefinition::new("qty", Type::Int32),
+                ColumnDefinition::new("total", Type::Int32).mark_read_only(),
+            ],
+        );
+        assert_eq!(table.writable_column_names(), vec!["id", "qty"]);
+        assert_eq!(table.column_names(), vec!["id", "qty", "total"]);
+    }
+
+    #[test]
+    fn test_column_drift_ignores_unreported_read_only_columns() {
+        let table = TableDefinition::new(
+            "order_lines",
+            ColumnDefinition::new("id", Type::Int32),
+            vec![
+                ColumnDefinition::new("qty", Type::Int32),
+                ColumnDefinition::new("total", Type::Int32).mark_read_only(),
+            ],
+        );
+        assert!(table.column_drift(&["id", "qty"]).is_empty());
+        assert!(table.column_drift(&["id", "qty", "total"]).is_empty());
+
+        let drift = table.column_drift(&["id", "note"]);
+        assert_eq!(drift.missing, vec!["qty".to_string()]);
+        assert_eq!(drift.added, vec!["note".to_string()]);
+    }
+
     #[test]
     fn test_table_definition_get_column() {
         let table = TableDefinition::new(
@@ -689,6 +971,42 @@ tables:
         assert_eq!(users.fields.len(), 3);
     }
 
+    #[test]
+    fn test_generator_schema_yaml_roundtrip() {
+        let schema = GeneratorSchema::from_yaml(SAMPLE_SCHEMA).unwrap();
+        let rebuilt = GeneratorSchema::new(schema.tables.clone());
+        let parsed = GeneratorSchema::from_yaml(&rebuilt.to_yaml().unwrap()).unwrap();
+
+        assert_eq!(parsed.version, 1);
+        assert_eq!(parsed.table_names(), vec!["users"]);
+        assert_eq!(
+            parsed.get_field_type("users", "email").unwrap(),
+            &Type::VarChar { length: 255 }
+        );
+    }
+
+    #[test]
+    fn test_schema_validations() {
+        let yaml = r#"
+tables: []
+validations:
+  - name: no negative totals
+    query: SELECT id, total FROM orders WHERE total < 0
+"#;
+        let schema = GeneratorSchema::from_yaml(yaml).unwrap();
+        assert_eq!(
+            schema.validations,
+            vec![ValidationQuery::new(
+                "no negative totals",
+                "SELECT id, total FROM orders WHERE total < 0"
+            )]
+        );
+        assert!(GeneratorSchema::from_yaml(SAMPLE_SCHEMA)
+            .unwrap()
+            .validations
+            .is_empty());
+    }
+
     #[test]
     fn test_get_field_type() {
         let schema = GeneratorSchema::from_yaml(SAMPLE_SCHEMA).unwrap();
@@ -766,6 +1084,54 @@ tables:
         ));
     }
 
+    #[test]
+    fn test_one_of_weights() {
+        let yaml = r#"
+version: 1
+tables:
+  - name: users
+    id:
+      type: int
+      generator:
+        type: sequential
+        start: 1
+    fields:
+      - name: status
+        type: text
+        generator:
+          type: one_of
+          values: ["active", "pending", "banned"]
+          weights: [80, 15, 5]
+"#;
+        let schema = GeneratorSchema::from_yaml(yaml).unwrap();
+        let status = &schema
+            .get_table("users")
+            .unwrap()
+            .get_field("status")
+            .unwrap();
+        assert!(matches!(
+            &status.generator,
+            GeneratorConfig::OneOf { weights: Some(weights), .. } if *weights == [80.0, 15.0, 5.0]
+        ));
+
+        let short = yaml.replace("[80, 15, 5]", "[80, 20]");
+        assert!(matches!(
+            GeneratorSchema::from_yaml(&short),
+            Err(SchemaError::InvalidGenerator { ref field, ref reason, .. })
+                if field == "status" && reason == "one_of has 2 weights for 3 values"
+        ));
+        let negative = yaml.replace("[80, 15, 5]", "[80, -15, 5]");
+        assert!(matches!(
+            GeneratorSchema::from_yaml(&negative),
+            Err(SchemaError::InvalidGenerator { .. })
+        ));
+        let zero = yaml.replace("[80, 15, 5]", "[0, 0, 0]");
+        assert!(matches!(
+            GeneratorSchema::from_yaml(&zero),
+            Err(SchemaError::InvalidGenerator { .. })
+        ));
+    }
+
     #[test]
     fn test_generator_field_definition_flatten_serde() {
         // Test that GeneratorFieldDefinition serializes correctly
@@ -776,6 +1142,9 @@ tables:
                 pattern: "user_{index}@test.com".to_string(),
             },
             nullable: false,
+            nullable_elements: false,
+            indexed: false,
+            constraints: FieldConstraints::default(),
         };
 
         let yaml = serde_yaml::to_string(&field).unwrap();
@@ -810,6 +1179,60 @@ tables:
         assert_eq!(email_type, &Type::VarChar { length: 255 });
     }
 
+    #[test]
+    fn test_field_constraints_parse_and_validate() {
+        let yaml = r#"
+tables:
+  - name: users
+    id:
+      type: int
+      generator:
+        type: sequential
+        start: 1
+    fields:
+      - name: age
+        type: int
+        generator:
+          type: int_range
+          min: 18
+          max: 80
+        constraints:
+          min: 0
+          max: 150
+      - name: email
+        type: text
+        generator:
+          type: pattern
+          pattern: "user_{index}@example.com"
+        constraints:
+          pattern: "^[^@]+@[^@]+$"
+"#;
+        let schema = GeneratorSchema::from_yaml(yaml).unwrap();
+        let table = schema.get_table("users").unwrap();
+        let age = &table.get_field("age").unwrap().constraints;
+        assert_eq!((age.min, age.max), (Some(0.0), Some(150.0)));
+        assert_eq!(
+            table
+                .get_field("email")
+                .unwrap()
+                .constraints
+                .pattern
+                .as_deref(),
+            Some("^[^@]+@[^@]+$")
+        );
+
+        let pattern_on_int = yaml.replace("min: 0\n          max: 150", "pattern: \"^1\"");
+        assert!(matches!(
+            GeneratorSchema::from_yaml(&pattern_on_int),
+            Err(SchemaError::InvalidConstraints { ref field, .. }) if field == "age"
+        ));
+        let inverted = yaml.replace("min: 0", "min: 200");
+        assert!(matches!(
+            GeneratorSchema::from_yaml(&inverted),
+            Err(SchemaError::InvalidConstraints { .. })
+        ));
+    }
+
     #[test]
     fn test_type_alias_compatibility() {
         // Ensure type aliases work for backwards compatibility
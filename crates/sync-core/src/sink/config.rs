@@ -1,5 +1,9 @@
 //! SurrealDB connection and write settings (plain fields, no CLI parsing).
 
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
 use crate::ZeroTemporalPolicy;
 
 /// Plain-field SurrealDB connection and write options (no clap).
@@ -25,6 +29,44 @@ pub struct SurrealConfig {
     pub batch_size: usize,
     /// When true, sources should not write to the sink.
     pub dry_run: bool,
+    /// Concurrent write tasks per table within one sink batch (1 = sequential).
+    pub write_concurrency: usize,
+    /// Per-table overrides of `write_concurrency`; tables not listed use it.
+    pub table_write_concurrency: BTreeMap<String, usize>,
+    /// Tables written strictly in source order through a single write
+    /// stream; `write_concurrency` does not apply to them.
+    pub ordered_tables: Vec<String>,
+    /// SurrealDB connections concurrent write tasks are spread over (1 =
+    /// all share one connection).
+    pub connection_pool_size: usize,
+    /// Retry failed batch writes behind a circuit breaker (disabled by default).
+    pub circuit_breaker: CircuitBreakerConfig,
+    /// Attempts and backoff for batch writes that fail transiently.
+    pub write_retry: WriteRetryConfig,
+    /// Last-write-wins field: incremental creates/updates only apply when
+    /// their value for it is newer than the stored one (`None` = always apply).
+    pub version_field: Option<String>,
+    /// Turn incremental deletes into soft deletes (`None` = hard delete).
+    pub soft_delete: Option<SoftDeleteConfig>,
+    /// Deep-merge incremental creates/updates into the stored record
+    /// (`None` = overwrite it).
+    pub merge: Option<MergeConfig>,
+    /// Apply incremental updates that list their changed fields as patches
+    /// setting only those fields (`false` = write the whole record).
+    pub skip_unchanged_fields: bool,
+    /// Dead-letter table: records whose write still fails are stored here
+    /// instead of failing the sync (`None` = fail the sync).
+    pub error_table: Option<String>,
+    /// Detect incremental updates to records modified outside surreal-sync
+    /// and handle them per the policy (`None` = no detection, no
+    /// [`SYNC_META_FIELD`]).
+    pub conflict_policy: Option<ConflictPolicy>,
+    /// Offload string / byte field values above a size threshold to files
+    /// (`None` = write every value to SurrealDB).
+    pub large_fields: Option<LargeFieldConfig>,
+    /// Tables whose records are written by a user-supplied SurrealQL
+    /// statement instead of the built-in upsert.
+    pub write_templates: Vec<WriteTemplate>,
 }
 
 impl Default for SurrealConfig {
@@ -38,6 +80,20 @@ impl Default for SurrealConfig {
             zero_temporal: ZeroTemporalPolicy::default(),
             batch_size: 1000,
             dry_run: false,
+            write_concurrency: 1,
+            table_write_concurrency: BTreeMap::new(),
+            ordered_tables: Vec::new(),
+            connection_pool_size: 1,
+            circuit_breaker: CircuitBreakerConfig::default(),
+            write_retry: WriteRetryConfig::default(),
+            version_field: None,
+            soft_delete: None,
+            merge: None,
+            skip_unchanged_fields: false,
+            error_table: None,
+            conflict_policy: None,
+            large_fields: None,
+            write_templates: Vec::new(),
         }
     }
 }
@@ -61,3 +117,514 @@ impl SurrealConfig {
         }
     }
 }
+
+/// Soft-delete mode for incremental deletes.
+///
+/// Instead of removing the record, a delete change sets `deleted_at_field` to
+/// the time it was applied and, when configured, `deleted_flag_field` to
+/// `true`. Records that do not exist are left absent. Relation deletes and
+/// table truncations still remove data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoftDeleteConfig {
+    /// Datetime field set when a record is deleted at the source.
+    pub deleted_at_field: String,
+    /// Optional boolean field set to `true` alongside `deleted_at_field`.
+    pub deleted_flag_field: Option<String>,
+}
+
+impl SoftDeleteConfig {
+    /// Soft-delete into `deleted_at_field` only.
+    pub fn new(deleted_at_field: impl Into<String>) -> Self {
+        Self {
+            deleted_at_field: deleted_at_field.into(),
+            deleted_flag_field: None,
+        }
+    }
+
+    /// Also set `field` to `true` on soft-deleted records.
+    pub fn with_deleted_flag(mut self, field: impl Into<String>) -> Self {
+        self.deleted_flag_field = Some(field.into());
+        self
+    }
+}
+
+/// Deep-merge mode for incremental creates/updates.
+///
+/// Instead of replacing the stored record, a change merges into it: object
+/// fields merge key by key at every depth, so fields the change does not
+/// carry are kept. Array fields follow [`ArrayMergeStrategy`]: the one set
+/// for their dotted path in `array_fields` (e.g. `profile.tags`), otherwise
+/// `arrays`. Full-sync writes and deletes are unaffected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeConfig {
+    /// Strategy for array fields without an entry in `array_fields`.
+    pub arrays: ArrayMergeStrategy,
+    /// Per-field strategies keyed by dotted field path.
+    pub array_fields: BTreeMap<String, ArrayMergeStrategy>,
+}
+
+impl MergeConfig {
+    /// Merge objects and apply `arrays` to every array field.
+    pub fn new(arrays: ArrayMergeStrategy) -> Self {
+        Self {
+            arrays,
+            array_fields: BTreeMap::new(),
+        }
+    }
+
+    /// Use `strategy` for the array at dotted `path` instead of the default.
+    pub fn with_array_field(
+        mut self,
+        path: impl Into<String>,
+        strategy: ArrayMergeStrategy,
+    ) -> Self {
+        self.array_fields.insert(path.into(), strategy);
+        self
+    }
+
+    /// Strategy for the array at dotted `path`.
+    pub fn array_strategy(&self, path: &str) -> &ArrayMergeStrategy {
+        self.array_fields.get(path).unwrap_or(&self.arrays)
+    }
+}
+
+/// How a merged change combines an array field with the stored array.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ArrayMergeStrategy {
+    /// The incoming array replaces the stored one.
+    #[default]
+    Replace,
+    /// Incoming elements are appended to the stored array.
+    Append,
+    /// Stored elements whose `key` field matches an incoming element are
+    /// dropped; the rest keep their order, followed by the incoming elements.
+    UnionByKey(String),
+}
+
+impl std::fmt::Display for ArrayMergeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Replace => write!(f, "replace"),
+            Self::Append => write!(f, "append"),
+            Self::UnionByKey(key) => write!(f, "union-by-key:{key}"),
+        }
+    }
+}
+
+impl std::str::FromStr for ArrayMergeStrategy {
+    type Err = anyhow::Error;
+
+    /// Parse `replace`, `append` or `union-by-key:KEY`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s.eq_ignore_ascii_case("replace") => Ok(Self::Replace),
+            None if s.eq_ignore_ascii_case("append") => Ok(Self::Append),
+            Some((strategy, key)) if strategy.eq_ignore_ascii_case("union-by-key") => {
+                if key.is_empty() {
+                    anyhow::bail!("union-by-key needs a key field: 'union-by-key:FIELD'");
+                }
+                Ok(Self::UnionByKey(key.to_string()))
+            }
+            _ => Err(anyhow::anyhow!(
+                "Invalid array merge strategy: '{s}'. Expected 'replace', 'append' or \
+                 'union-by-key:FIELD'"
+            )),
+        }
+    }
+}
+
+/// Provenance field conflict detection stamps on every record an
+/// incremental create/update writes: `{ synced_at, checksum }`, where
+/// `checksum` is the SHA-256 of the record (without this field) as written.
+pub const SYNC_META_FIELD: &str = "_sync_meta";
+
+/// What an incremental create/update does when its target record was
+/// modified outside surreal-sync since surreal-sync last wrote it (the
+/// stored record no longer matches its [`SYNC_META_FIELD`] checksum).
+///
+/// Records without the field (never written with conflict detection on)
+/// are not conflicts. Deletes, relations and full-sync writes are not
+/// checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Log the conflict and apply the change anyway.
+    #[default]
+    Overwrite,
+    /// Log the conflict and keep the stored record.
+    Skip,
+    /// Keep the stored record and store the change in the dead-letter
+    /// error table.
+    ErrorTable,
+}
+
+impl std::fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overwrite => write!(f, "overwrite"),
+            Self::Skip => write!(f, "skip"),
+            Self::ErrorTable => write!(f, "error-table"),
+        }
+    }
+}
+
+impl std::str::FromStr for ConflictPolicy {
+    type Err = anyhow::Error;
+
+    /// Parse `overwrite`, `skip` or `error-table`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "overwrite" => Ok(Self::Overwrite),
+            "skip" => Ok(Self::Skip),
+            "error-table" => Ok(Self::ErrorTable),
+            _ => Err(anyhow::anyhow!(
+                "Invalid conflict policy: '{s}'. Expected 'overwrite', 'skip' or 'error-table'"
+            )),
+        }
+    }
+}
+
+/// Large-field offload for record writes.
+///
+/// A top-level string or byte field value longer than `threshold_bytes` is
+/// written to a file under `dir` instead of being sent to SurrealDB, and the
+/// record stores a reference object in its place:
+/// `{ path: "<dir>/<table>/<key>.bin", size: <bytes>, kind: "text" | "bytes" }`.
+/// The file name is derived from the record id and field name, so re-syncing
+/// a record overwrites its file. Values nested in arrays or objects are
+/// written as usual.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LargeFieldConfig {
+    /// Values longer than this many bytes are offloaded.
+    pub threshold_bytes: usize,
+    /// Directory the offloaded values are written to.
+    pub dir: PathBuf,
+}
+
+impl LargeFieldConfig {
+    /// Offload values longer than `threshold_bytes` into `dir`.
+    pub fn new(threshold_bytes: usize, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            threshold_bytes,
+            dir: dir.into(),
+        }
+    }
+}
+
+/// A SurrealQL statement that writes one record of `table`, run in place of
+/// the built-in upsert for full-sync rows and incremental creates/updates.
+///
+/// `$name` placeholders are bound per record: `$id` to the record id
+/// (`table:key`), `$content` to the whole record as an object and any other
+/// name to the record's field of that name (`NONE` when the record lacks
+/// it). SurrealQL's own parameters (`$this`, `$parent`, `$value`, ...) are
+/// left to SurrealDB. Deletes and relations are written as usual.
+///
+/// ```text
+/// users=UPSERT $id CONTENT $content; UPDATE $id SET display = string::concat($first, ' ', $last)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteTemplate {
+    /// Table the template writes.
+    pub table: String,
+    /// The SurrealQL statement.
+    pub statement: String,
+}
+
+impl WriteTemplate {
+    /// Placeholders bound per record (not SurrealQL's own parameters).
+    pub const RESERVED: [&'static str; 2] = ["id", "content"];
+    const SURREALQL_PARAMS: [&'static str; 10] = [
+        "this", "parent", "value", "before", "after", "event", "input", "auth", "session", "token",
+    ];
+
+    /// Write `table` with `statement`.
+    pub fn new(table: impl Into<String>, statement: impl Into<String>) -> anyhow::Result<Self> {
+        let template = Self {
+            table: table.into(),
+            statement: statement.into(),
+        };
+        if template.table.is_empty() {
+            anyhow::bail!("A write template needs a table");
+        }
+        if !template.placeholders().contains(&"id") {
+            anyhow::bail!(
+                "The write template for '{}' never uses $id, so it cannot target the record",
+                template.table
+            );
+        }
+        Ok(template)
+    }
+
+    /// Names of the `$placeholders` in the statement, in first-use order,
+    /// skipping string literals, comments and SurrealQL's own parameters.
+    pub fn placeholders(&self) -> Vec<&str> {
+        let bytes = self.statement.as_bytes();
+        let mut names = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                quote @ (b'\'' | b'"' | b'`') => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != quote {
+                        i += if bytes[i] == b'\\' { 2 } else { 1 };
+                    }
+                    i += 1;
+                }
+                b'#' => skip_to(bytes, &mut i, "\n"),
+                b'-' if bytes.get(i + 1) == Some(&b'-') => skip_to(bytes, &mut i, "\n"),
+                b'/' if bytes.get(i + 1) == Some(&b'/') => skip_to(bytes, &mut i, "\n"),
+                b'/' if bytes.get(i + 1) == Some(&b'*') => skip_to(bytes, &mut i, "*/"),
+                b'$' => {
+                    let start = i + 1;
+                    i = start;
+                    while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_')
+                    {
+                        i += 1;
+                    }
+                    let name = &self.statement[start..i];
+                    if !name.is_empty()
+                        && !Self::SURREALQL_PARAMS.contains(&name)
+                        && !names.contains(&name)
+                    {
+                        names.push(name);
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+        names
+    }
+
+    /// Placeholders bound from the record's fields.
+    pub fn field_placeholders(&self) -> Vec<&str> {
+        self.placeholders()
+            .into_iter()
+            .filter(|name| !Self::RESERVED.contains(name))
+            .collect()
+    }
+
+    /// Check that `schema` has the template's table and a column for every
+    /// field placeholder.
+    pub fn validate(&self, schema: &crate::DatabaseSchema) -> anyhow::Result<()> {
+        let Some(table) = schema.get_table(&self.table) else {
+            anyhow::bail!(
+                "The write template targets table '{}', which is not in the schema",
+                self.table
+            );
+        };
+        let unknown: Vec<_> = self
+            .field_placeholders()
+            .into_iter()
+            .filter(|name| table.get_column(name).is_none())
+            .map(|name| format!("${name}"))
+            .collect();
+        if !unknown.is_empty() {
+            anyhow::bail!(
+                "The write template for '{}' uses {}, which {} not a column of the table \
+                 (columns: {})",
+                self.table,
+                unknown.join(", "),
+                if unknown.len() == 1 { "is" } else { "are" },
+                table.column_names().join(", ")
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Advance `i` past the next `end` (or to the end of `bytes`).
+fn skip_to(bytes: &[u8], i: &mut usize, end: &str) {
+    *i = bytes[*i..]
+        .windows(end.len())
+        .position(|w| w == end.as_bytes())
+        .map_or(bytes.len(), |at| *i + at + end.len());
+}
+
+impl std::str::FromStr for WriteTemplate {
+    type Err = anyhow::Error;
+
+    /// Parse `TABLE=STATEMENT`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (table, statement) = s.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!("Invalid write template '{s}'. Expected TABLE=STATEMENT")
+        })?;
+        Self::new(table.trim(), statement.trim())
+    }
+}
+
+/// Circuit breaker around SurrealDB batch writes.
+///
+/// While closed, a failed batch is retried with a short backoff. After
+/// `failure_threshold` consecutive failures the circuit opens: writes pause for
+/// `cooldown`, then a single probe batch is let through. A successful probe
+/// closes the circuit; a failed one re-opens it for another cooldown. Once the
+/// circuit has been open for `open_deadline`, writes fail instead of waiting,
+/// failing the sync.
+///
+/// Retried batches are re-applied whole, which is safe because sink writes are
+/// idempotent upserts / deletes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive batch failures that open the circuit. `0` disables the
+    /// breaker: a failed batch fails the sync once its
+    /// [`WriteRetryConfig`] attempts are used up.
+    pub failure_threshold: u32,
+    /// Pause between opening (or a failed probe) and the next probe.
+    pub cooldown: Duration,
+    /// Longest time the circuit may stay open before writes fail.
+    pub open_deadline: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 0,
+            cooldown: Duration::from_secs(5),
+            open_deadline: Duration::from_secs(300),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Whether the circuit breaker is in use.
+    pub fn is_enabled(&self) -> bool {
+        self.failure_threshold > 0
+    }
+}
+
+/// Retries of a SurrealDB batch write that failed transiently (connection
+/// loss, timeouts, an unavailable server). Other errors are never retried.
+///
+/// With the circuit breaker disabled, a batch is tried up to `max_attempts`
+/// times, waiting `backoff` after the first failure and doubling the wait
+/// after each further one. With the breaker enabled, its thresholds bound the
+/// retries and `backoff` is the first delay while the circuit is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteRetryConfig {
+    /// Tries per batch, the first one included (`0` is treated as `1`, no
+    /// retry).
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub backoff: Duration,
+}
+
+impl Default for WriteRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+impl WriteRetryConfig {
+    /// Delay after the `failures`-th consecutive failure: `backoff`, doubled
+    /// for each earlier failure.
+    pub fn backoff_after(&self, failures: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2u32.saturating_pow(failures.saturating_sub(1).min(16)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_retry_backoff_doubles_per_failure() {
+        let retry = WriteRetryConfig::default();
+        assert_eq!(retry.backoff_after(1), Duration::from_millis(100));
+        assert_eq!(retry.backoff_after(2), Duration::from_millis(200));
+        assert_eq!(retry.backoff_after(4), Duration::from_millis(800));
+        assert_eq!(retry.backoff_after(100), retry.backoff_after(17));
+    }
+
+    #[test]
+    fn conflict_policy_round_trips() {
+        for policy in [
+            ConflictPolicy::Overwrite,
+            ConflictPolicy::Skip,
+            ConflictPolicy::ErrorTable,
+        ] {
+            assert_eq!(
+                policy.to_string().parse::<ConflictPolicy>().unwrap(),
+                policy
+            );
+        }
+        assert_eq!(
+            "Error-Table".parse::<ConflictPolicy>().unwrap(),
+            ConflictPolicy::ErrorTable
+        );
+        assert!("ignore".parse::<ConflictPolicy>().is_err());
+    }
+
+    #[test]
+    fn write_template_placeholders_validate_against_schema() {
+        use crate::types::Type;
+        use crate::{ColumnDefinition, DatabaseSchema, TableDefinition};
+
+        let template: WriteTemplate = "users = UPSERT $id CONTENT $content, \
+             display: string::concat($first, ' $not_a_field ', $last) \
+             RETURN NONE -- $nor_this\n; /* $nor $these */ SELECT * FROM $parent, $first"
+            .parse()
+            .unwrap();
+        assert_eq!(template.table, "users");
+        assert_eq!(
+            template.placeholders(),
+            vec!["id", "content", "first", "last"]
+        );
+        assert_eq!(template.field_placeholders(), vec!["first", "last"]);
+
+        let users = |columns: &[&str]| {
+            DatabaseSchema::new(vec![TableDefinition::new(
+                "users",
+                ColumnDefinition::new("id", Type::Int64),
+                columns
+                    .iter()
+                    .map(|c| ColumnDefinition::new(*c, Type::Text))
+                    .collect(),
+            )])
+        };
+        template.validate(&users(&["first", "last"])).unwrap();
+        let err = template.validate(&users(&["first"])).unwrap_err();
+        assert!(
+            err.to_string().contains("uses $last, which is not"),
+            "{err}"
+        );
+        let other = WriteTemplate::new("orders", "UPSERT $id CONTENT $content").unwrap();
+        assert!(other.validate(&users(&[])).is_err());
+
+        assert!("users".parse::<WriteTemplate>().is_err());
+        assert!("users=CREATE users CONTENT $content"
+            .parse::<WriteTemplate>()
+            .is_err());
+    }
+
+    #[test]
+    fn array_merge_strategy_round_trips() {
+        for strategy in [
+            ArrayMergeStrategy::Replace,
+            ArrayMergeStrategy::Append,
+            ArrayMergeStrategy::UnionByKey("sku".to_string()),
+        ] {
+            assert_eq!(
+                strategy.to_string().parse::<ArrayMergeStrategy>().unwrap(),
+                strategy
+            );
+        }
+        assert_eq!(
+            "APPEND".parse::<ArrayMergeStrategy>().unwrap(),
+            ArrayMergeStrategy::Append
+        );
+        assert!("union-by-key:".parse::<ArrayMergeStrategy>().is_err());
+        assert!("prepend".parse::<ArrayMergeStrategy>().is_err());
+
+        let merge = MergeConfig::new(ArrayMergeStrategy::Append)
+            .with_array_field("profile.items", ArrayMergeStrategy::UnionByKey("id".into()));
+        assert_eq!(merge.array_strategy("tags"), &ArrayMergeStrategy::Append);
+        assert_eq!(
+            merge.array_strategy("profile.items"),
+            &ArrayMergeStrategy::UnionByKey("id".into())
+        );
+    }
+}
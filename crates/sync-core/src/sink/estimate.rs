@@ -0,0 +1,125 @@
+//! Dry-run write volume estimates.
+
+use std::collections::BTreeMap;
+
+/// Estimated SurrealDB load for one table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableWriteEstimate {
+    /// Sink batches (`write_rows` / `write_relations` calls) touching the table.
+    pub batches: u64,
+    /// Write queries that would be issued (one per upsert, relate or delete).
+    pub queries: u64,
+    /// Records and relations written or deleted.
+    pub records: u64,
+    /// Serialized payload bytes bound to those queries.
+    pub bytes: u64,
+}
+
+impl TableWriteEstimate {
+    fn add(&mut self, other: &TableWriteEstimate) {
+        self.batches += other.batches;
+        self.queries += other.queries;
+        self.records += other.records;
+        self.bytes += other.bytes;
+    }
+}
+
+/// What a dry run would have written, per target table.
+///
+/// Filled in by sinks in dry-run mode, which convert and serialize every
+/// record exactly as a real write would and then skip the query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteEstimate {
+    /// Per-table estimates, keyed by table (or relation table) name.
+    pub tables: BTreeMap<String, TableWriteEstimate>,
+}
+
+impl WriteEstimate {
+    /// Count one sink batch for each distinct table in `tables`.
+    pub fn add_batch<'a>(&mut self, tables: impl IntoIterator<Item = &'a str>) {
+        let mut seen = std::collections::BTreeSet::new();
+        for table in tables {
+            if seen.insert(table) {
+                self.table_mut(table).batches += 1;
+            }
+        }
+    }
+
+    /// Count one query writing (or deleting) one record of `table`, with a
+    /// serialized payload of `bytes`.
+    pub fn add_record(&mut self, table: &str, bytes: usize) {
+        let entry = self.table_mut(table);
+        entry.queries += 1;
+        entry.records += 1;
+        entry.bytes += bytes as u64;
+    }
+
+    /// Count one query that writes no single record (e.g. a table truncate).
+    pub fn add_query(&mut self, table: &str) {
+        self.table_mut(table).queries += 1;
+    }
+
+    /// Sum over all tables.
+    pub fn total(&self) -> TableWriteEstimate {
+        let mut total = TableWriteEstimate::default();
+        for table in self.tables.values() {
+            total.add(table);
+        }
+        total
+    }
+
+    /// Fold `other` into this estimate.
+    pub fn merge(&mut self, other: &WriteEstimate) {
+        for (table, estimate) in &other.tables {
+            self.table_mut(table).add(estimate);
+        }
+    }
+
+    fn table_mut(&mut self, table: &str) -> &mut TableWriteEstimate {
+        if !self.tables.contains_key(table) {
+            self.tables
+                .insert(table.to_string(), TableWriteEstimate::default());
+        }
+        self.tables.get_mut(table).expect("inserted above")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_counts_batches_once_per_table() {
+        let mut estimate = WriteEstimate::default();
+        estimate.add_batch(["users", "users", "orders"]);
+        estimate.add_batch(["users"]);
+        for bytes in [10, 20, 30] {
+            estimate.add_record("users", bytes);
+        }
+        estimate.add_record("orders", 5);
+        estimate.add_query("orders");
+
+        assert_eq!(
+            estimate.tables["users"],
+            TableWriteEstimate {
+                batches: 2,
+                queries: 3,
+                records: 3,
+                bytes: 60,
+            }
+        );
+        assert_eq!(
+            estimate.total(),
+            TableWriteEstimate {
+                batches: 3,
+                queries: 5,
+                records: 4,
+                bytes: 65,
+            }
+        );
+
+        let mut merged = estimate.clone();
+        merged.merge(&estimate);
+        assert_eq!(merged.total().queries, 10);
+    }
+}
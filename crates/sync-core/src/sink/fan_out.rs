@@ -0,0 +1,251 @@
+//! Fan-out of synced changes to external consumers.
+//!
+//! [`ChangeConsumer`] receives [`ChangeRecord`] batches. Two
+//! [`SurrealSink`] adapters plug it into any sync path that is generic over
+//! the sink:
+//!
+//! - [`ConsumerSink`] sends changes to the consumer *instead of* SurrealDB.
+//! - [`TeeSink`] writes to SurrealDB first and then hands the same changes
+//!   to the consumer, so a consumer never sees a change SurrealDB rejected.
+//!
+//! Batch writes (`write_rows` / `write_relations`) are upserts and reach the
+//! consumer as `update` records; table truncations reach it as one
+//! `truncate` record.
+
+use anyhow::Result;
+
+use super::traits::SurrealSink;
+use crate::change_record::ChangeRecord;
+use crate::{Change, Relation, RelationChange, Row, Value};
+
+/// Receiver of changes in the [`ChangeRecord`] format.
+#[async_trait::async_trait]
+pub trait ChangeConsumer: Send + Sync {
+    /// Handle one batch of changes, in source order. An error fails the
+    /// sync the same way a SurrealDB write error does.
+    async fn consume(&self, records: &[ChangeRecord]) -> Result<()>;
+}
+
+/// Sink that forwards every change to a [`ChangeConsumer`] and writes
+/// nothing to SurrealDB.
+#[derive(Debug, Clone)]
+pub struct ConsumerSink<C> {
+    consumer: C,
+}
+
+impl<C: ChangeConsumer> ConsumerSink<C> {
+    /// Wrap `consumer`.
+    pub fn new(consumer: C) -> Self {
+        Self { consumer }
+    }
+
+    /// The wrapped consumer.
+    pub fn consumer(&self) -> &C {
+        &self.consumer
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: ChangeConsumer> SurrealSink for ConsumerSink<C> {
+    async fn write_rows(&self, rows: &[Row]) -> Result<()> {
+        let records: Vec<_> = rows.iter().map(ChangeRecord::from_row).collect();
+        self.consumer.consume(&records).await
+    }
+
+    async fn write_relations(&self, relations: &[Relation]) -> Result<()> {
+        let records: Vec<_> = relations.iter().map(ChangeRecord::from_relation).collect();
+        self.consumer.consume(&records).await
+    }
+
+    async fn apply_change(&self, change: &Change) -> Result<()> {
+        self.consumer
+            .consume(&[ChangeRecord::from_change(change)])
+            .await
+    }
+
+    async fn apply_relation_change(&self, change: &RelationChange) -> Result<()> {
+        self.consumer
+            .consume(&[ChangeRecord::from_relation_change(change)])
+            .await
+    }
+
+    async fn truncate_table(&self, table: &str) -> Result<()> {
+        self.consumer
+            .consume(&[ChangeRecord::truncate(table)])
+            .await
+    }
+}
+
+/// Sink that writes to an inner [`SurrealSink`] and then forwards the same
+/// changes to a [`ChangeConsumer`].
+#[derive(Debug, Clone)]
+pub struct TeeSink<S, C> {
+    sink: S,
+    consumer: ConsumerSink<C>,
+}
+
+impl<S: SurrealSink, C: ChangeConsumer> TeeSink<S, C> {
+    /// Write to `sink`, then to `consumer`.
+    pub fn new(sink: S, consumer: C) -> Self {
+        Self {
+            sink,
+            consumer: ConsumerSink::new(consumer),
+        }
+    }
+
+    /// The inner SurrealDB sink.
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// The wrapped consumer.
+    pub fn consumer(&self) -> &C {
+        self.consumer.consumer()
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SurrealSink, C: ChangeConsumer> SurrealSink for TeeSink<S, C> {
+    async fn write_rows(&self, rows: &[Row]) -> Result<()> {
+        self.sink.write_rows(rows).await?;
+        self.consumer.write_rows(rows).await
+    }
+
+    async fn write_relations(&self, relations: &[Relation]) -> Result<()> {
+        self.sink.write_relations(relations).await?;
+        self.consumer.write_relations(relations).await
+    }
+
+    async fn apply_change(&self, change: &Change) -> Result<()> {
+        self.sink.apply_change(change).await?;
+        self.consumer.apply_change(change).await
+    }
+
+    async fn apply_relation_change(&self, change: &RelationChange) -> Result<()> {
+        self.sink.apply_relation_change(change).await?;
+        self.consumer.apply_relation_change(change).await
+    }
+
+    async fn truncate_table(&self, table: &str) -> Result<()> {
+        self.sink.truncate_table(table).await?;
+        self.consumer.truncate_table(table).await
+    }
+
+    async fn scan_field(
+        &self,
+        table: &str,
+        field: &str,
+        after: Option<&Value>,
+        limit: usize,
+    ) -> Result<Vec<(Value, Option<String>)>> {
+        self.sink.scan_field(table, field, after, limit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::change_record::RecordOp;
+    use crate::{FieldMap, Value};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<ChangeRecord>>);
+
+    #[async_trait::async_trait]
+    impl ChangeConsumer for Recorder {
+        async fn consume(&self, records: &[ChangeRecord]) -> Result<()> {
+            self.0.lock().unwrap().extend_from_slice(records);
+            Ok(())
+        }
+    }
+
+    /// SurrealDB stand-in that rejects every write.
+    struct Failing;
+
+    #[async_trait::async_trait]
+    impl SurrealSink for Failing {
+        async fn write_rows(&self, _: &[Row]) -> Result<()> {
+            anyhow::bail!("rejected")
+        }
+        async fn write_relations(&self, _: &[Relation]) -> Result<()> {
+            anyhow::bail!("rejected")
+        }
+        async fn apply_change(&self, _: &Change) -> Result<()> {
+            anyhow::bail!("rejected")
+        }
+        async fn apply_relation_change(&self, _: &RelationChange) -> Result<()> {
+            anyhow::bail!("rejected")
+        }
+    }
+
+    #[test]
+    fn test_consumer_sink_maps_writes_to_records() {
+        let sink = ConsumerSink::new(Recorder::default());
+        let row = Row::new("users", 0, Value::Int64(1), FieldMap::new());
+        tokio_test::block_on(async {
+            sink.write_rows(&[row]).await.unwrap();
+            sink.apply_change(&Change::delete("users", Value::Int64(2)))
+                .await
+                .unwrap();
+        });
+        let records = sink.consumer().0.lock().unwrap();
+        let ops: Vec<_> = records.iter().map(|r| (r.op, r.id.clone())).collect();
+        assert_eq!(
+            ops,
+            vec![
+                (RecordOp::Update, serde_json::json!(1)),
+                (RecordOp::Delete, serde_json::json!(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tee_sink_skips_consumer_when_surreal_write_fails() {
+        let tee = TeeSink::new(Failing, Recorder::default());
+        let err = tokio_test::block_on(tee.apply_change(&Change::delete("users", Value::Int64(1))))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "rejected");
+        assert!(tee.consumer().0.lock().unwrap().is_empty());
+    }
+
+    /// SurrealDB stand-in that accepts truncations and records them.
+    #[derive(Default)]
+    struct Truncations(Mutex<Vec<String>>);
+
+    #[async_trait::async_trait]
+    impl SurrealSink for Truncations {
+        async fn write_rows(&self, _: &[Row]) -> Result<()> {
+            Ok(())
+        }
+        async fn write_relations(&self, _: &[Relation]) -> Result<()> {
+            Ok(())
+        }
+        async fn apply_change(&self, _: &Change) -> Result<()> {
+            Ok(())
+        }
+        async fn apply_relation_change(&self, _: &RelationChange) -> Result<()> {
+            Ok(())
+        }
+        async fn truncate_table(&self, table: &str) -> Result<()> {
+            self.0.lock().unwrap().push(table.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_tee_sink_forwards_truncations() {
+        let tee = TeeSink::new(Truncations::default(), Recorder::default());
+        tokio_test::block_on(tee.truncate_table("users")).unwrap();
+        assert_eq!(*tee.sink().0.lock().unwrap(), vec!["users".to_string()]);
+        assert_eq!(
+            *tee.consumer().0.lock().unwrap(),
+            vec![ChangeRecord::truncate("users")]
+        );
+
+        // SurrealDB rejecting the truncation keeps it from the consumer.
+        let tee = TeeSink::new(Failing, Recorder::default());
+        assert!(tokio_test::block_on(tee.truncate_table("users")).is_err());
+        assert!(tee.consumer().0.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,202 @@
+//! Fan-out of a write batch across concurrent per-table write lanes.
+//!
+//! Sinks with a write concurrency above one group a batch by table and split
+//! each table's items into lanes keyed by record id. Lanes of one table are
+//! written concurrently; tables are written one after another in the order
+//! they first appear. Items for the same record always share a lane, so their
+//! relative order is preserved while independent records are written out of
+//! order. Ordered tables get a single lane, so all of their items are written
+//! in input order.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::Value;
+
+/// Lanes for one table: at most `lanes` non-empty groups of items.
+pub type TableLanes<'a, T> = Vec<Vec<&'a T>>;
+
+/// Partition `items` into per-table write lanes.
+///
+/// Returns one [`TableLanes`] per distinct table, in first-appearance order.
+/// Within a lane, items keep their input order. `lanes` of zero is treated as
+/// one.
+pub fn partition_write_lanes<'a, T>(
+    items: &'a [T],
+    lanes: usize,
+    table_of: impl Fn(&T) -> &str,
+    id_of: impl Fn(&T) -> &Value,
+) -> Vec<TableLanes<'a, T>> {
+    partition_write_lanes_ordered(items, lanes, |_| false, table_of, id_of)
+}
+
+/// [`partition_write_lanes`], with one lane for every table `is_ordered`
+/// accepts, so that table's items are written strictly in input order.
+pub fn partition_write_lanes_ordered<'a, T>(
+    items: &'a [T],
+    lanes: usize,
+    is_ordered: impl Fn(&str) -> bool,
+    table_of: impl Fn(&T) -> &str,
+    id_of: impl Fn(&T) -> &Value,
+) -> Vec<TableLanes<'a, T>> {
+    partition_write_lanes_per_table(
+        items,
+        |table| if is_ordered(table) { 1 } else { lanes },
+        table_of,
+        id_of,
+    )
+}
+
+/// [`partition_write_lanes`], with `lanes_for(table)` lanes for each table
+/// instead of one count for every table. A count of zero is treated as one.
+pub fn partition_write_lanes_per_table<'a, T>(
+    items: &'a [T],
+    lanes_for: impl Fn(&str) -> usize,
+    table_of: impl Fn(&T) -> &str,
+    id_of: impl Fn(&T) -> &Value,
+) -> Vec<TableLanes<'a, T>> {
+    let mut table_index: HashMap<&str, usize> = HashMap::new();
+    let mut out: Vec<Vec<Vec<&'a T>>> = Vec::new();
+
+    for item in items {
+        let table = table_of(item);
+        let slot = *table_index.entry(table).or_insert_with(|| {
+            out.push(vec![Vec::new(); lanes_for(table).max(1)]);
+            out.len() - 1
+        });
+        let lane = lane_of(id_of(item), out[slot].len());
+        out[slot][lane].push(item);
+    }
+
+    for table_lanes in &mut out {
+        table_lanes.retain(|lane| !lane.is_empty());
+    }
+    out
+}
+
+/// Stable lane for a record id.
+fn lane_of(id: &Value, lanes: usize) -> usize {
+    if lanes == 1 {
+        return 0;
+    }
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    (hasher.finish() % lanes as u64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldMap, Row};
+
+    fn row(table: &str, id: i64, index: u64) -> Row {
+        Row::new(table.to_string(), index, Value::Int64(id), FieldMap::new())
+    }
+
+    #[test]
+    fn test_partition_write_lanes_groups_by_table_and_keeps_every_row() {
+        let rows: Vec<Row> = (0..40)
+            .map(|i| row(if i % 3 == 0 { "a" } else { "b" }, i as i64, i))
+            .collect();
+        let grouped = partition_write_lanes(&rows, 4, |r| r.table.as_str(), |r| &r.id);
+
+        assert_eq!(grouped.len(), 2);
+        assert!(grouped[0].iter().flatten().all(|r| r.table == "a"));
+        assert!(grouped[1].iter().flatten().all(|r| r.table == "b"));
+        for table_lanes in &grouped {
+            assert!(table_lanes.len() <= 4);
+            assert!(table_lanes.iter().all(|lane| !lane.is_empty()));
+        }
+        let mut seen: Vec<u64> = grouped
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|r| r.index)
+            .collect();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..40).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_partition_write_lanes_keeps_same_record_in_one_lane_in_order() {
+        let rows: Vec<Row> = (0..30).map(|i| row("t", (i % 5) as i64, i)).collect();
+        let grouped = partition_write_lanes(&rows, 3, |r| r.table.as_str(), |r| &r.id);
+
+        for lane in &grouped[0] {
+            let indexes: Vec<u64> = lane.iter().map(|r| r.index).collect();
+            assert!(indexes.windows(2).all(|w| w[0] < w[1]));
+        }
+        for id in 0..5 {
+            let lanes_with_id = grouped[0]
+                .iter()
+                .filter(|lane| lane.iter().any(|r| r.id == Value::Int64(id)))
+                .count();
+            assert_eq!(lanes_with_id, 1, "record {id} split across lanes");
+        }
+    }
+
+    #[test]
+    fn test_partition_write_lanes_zero_lanes_is_one() {
+        let rows = vec![row("t", 1, 0), row("t", 2, 1)];
+        let grouped = partition_write_lanes(&rows, 0, |r| r.table.as_str(), |r| &r.id);
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(grouped[0].len(), 1);
+        assert_eq!(grouped[0][0].len(), 2);
+    }
+
+    #[test]
+    fn test_partition_write_lanes_ordered_table_keeps_one_lane_in_order() {
+        let rows: Vec<Row> = (0..40)
+            .map(|i| row(if i % 2 == 0 { "log" } else { "t" }, (40 - i) as i64, i))
+            .collect();
+        let grouped = partition_write_lanes_ordered(
+            &rows,
+            4,
+            |table| table == "log",
+            |r| r.table.as_str(),
+            |r| &r.id,
+        );
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].len(), 1, "ordered table has a single lane");
+        let indexes: Vec<u64> = grouped[0][0].iter().map(|r| r.index).collect();
+        assert_eq!(indexes, (0..40).step_by(2).collect::<Vec<_>>());
+        assert!(grouped[1].len() > 1, "unordered table still fans out");
+        assert!(grouped[1].iter().flatten().all(|r| r.table == "t"));
+    }
+
+    #[test]
+    fn test_partition_write_lanes_per_table_counts() {
+        let rows: Vec<Row> = (0..90)
+            .map(|i| row(["a", "b", "c"][(i % 3) as usize], i as i64, i))
+            .collect();
+        let grouped = partition_write_lanes_per_table(
+            &rows,
+            |table| match table {
+                "a" => 8,
+                "b" => 0,
+                _ => 2,
+            },
+            |r| r.table.as_str(),
+            |r| &r.id,
+        );
+
+        assert_eq!(grouped.len(), 3);
+        assert!(grouped[0].len() > 2 && grouped[0].len() <= 8);
+        assert_eq!(grouped[1].len(), 1, "zero lanes is one");
+        assert_eq!(grouped[2].len(), 2);
+    }
+
+    #[test]
+    fn test_lane_of_is_stable_for_equal_ids() {
+        let id = Value::Thing {
+            table: "t".to_string(),
+            id: Box::new(Value::Text("abc".to_string())),
+        };
+        let lane = lane_of(&id, 8);
+        assert_eq!(lane, lane_of(&id.clone(), 8));
+        assert!(lane < 8);
+        assert_eq!(lane_of(&Value::Float64(1.5), 1), 0);
+    }
+}
@@ -5,10 +5,22 @@
 
 mod config;
 mod connect;
+mod estimate;
+mod fan_out;
+mod lanes;
 mod traits;
 mod version;
 
-pub use config::SurrealConfig;
+pub use config::{
+    ArrayMergeStrategy, CircuitBreakerConfig, ConflictPolicy, LargeFieldConfig, MergeConfig,
+    SoftDeleteConfig, SurrealConfig, WriteRetryConfig, WriteTemplate, SYNC_META_FIELD,
+};
 pub use connect::{SinkConnect, SinkWithCheckpoints};
+pub use estimate::{TableWriteEstimate, WriteEstimate};
+pub use fan_out::{ChangeConsumer, ConsumerSink, TeeSink};
+pub use lanes::{
+    partition_write_lanes, partition_write_lanes_ordered, partition_write_lanes_per_table,
+    TableLanes,
+};
 pub use traits::SurrealSink;
 pub use version::SurrealSdkVersion;
@@ -4,7 +4,7 @@
 //! source crates to be compiled against a single interface that works
 //! with both v2 and v3 servers.
 
-use crate::{Change, Relation, RelationChange, Row};
+use crate::{Change, Relation, RelationChange, Row, Value};
 use anyhow::Result;
 
 /// Trait for writing data to SurrealDB.
@@ -56,4 +56,29 @@ pub trait SurrealSink: Send + Sync {
     /// - Create/Update: RELATE the edge
     /// - Delete: DELETE the relation
     async fn apply_relation_change(&self, change: &RelationChange) -> Result<()>;
+
+    /// Delete every record of `table` (a source-side `TRUNCATE`).
+    ///
+    /// Sinks opt in explicitly: the default fails, so a source that emits
+    /// truncations never silently leaves stale records behind.
+    async fn truncate_table(&self, table: &str) -> Result<()> {
+        anyhow::bail!("this sink does not support truncating table '{table}'")
+    }
+
+    /// Up to `limit` records of `table` whose id sorts after `after` (from
+    /// the start when `None`), in SurrealDB id order, each with the string
+    /// stored in its `field` (`None` when unset or not a string).
+    ///
+    /// Lets a source diff its current state against SurrealDB one page at a
+    /// time. Sinks opt in explicitly: the default fails.
+    async fn scan_field(
+        &self,
+        table: &str,
+        field: &str,
+        after: Option<&Value>,
+        limit: usize,
+    ) -> Result<Vec<(Value, Option<String>)>> {
+        let _ = (field, after, limit);
+        anyhow::bail!("this sink cannot read back records of table '{table}'")
+    }
 }
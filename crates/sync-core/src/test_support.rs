@@ -0,0 +1,28 @@
+//! Fixtures shared by the type crates' conversion tests.
+
+use crate::{FieldMap, TypedValue, Value};
+
+/// A two-level nested object:
+/// `{ name: "Alice", address: { city: "Paris", geo: { lat: 48.85, lon: 2.35 } } }`.
+pub fn nested_object() -> TypedValue {
+    let geo: FieldMap = [
+        ("lat".to_string(), Value::Float64(48.85)),
+        ("lon".to_string(), Value::Float64(2.35)),
+    ]
+    .into_iter()
+    .collect();
+    let address: FieldMap = [
+        ("city".to_string(), Value::Text("Paris".to_string())),
+        ("geo".to_string(), Value::Object(geo)),
+    ]
+    .into_iter()
+    .collect();
+    TypedValue::object(
+        [
+            ("name".to_string(), Value::Text("Alice".to_string())),
+            ("address".to_string(), Value::Object(address)),
+        ]
+        .into_iter()
+        .collect(),
+    )
+}
@@ -11,9 +11,8 @@
 //! Full join-table → relation **source** logic may remain in PostgreSQL (or
 //! other) source crates; relation **edges are first-class in the apply engine**.
 
-use crate::{Change, Relation, RelationChange, Row, Value};
+use crate::{Change, FieldMap, Relation, RelationChange, Row, Value};
 use anyhow::Result;
-use std::collections::HashMap;
 
 /// Mutate-only, same-length transform over sync docs.
 ///
@@ -36,12 +35,7 @@ pub trait InPlaceTransform: Send + Sync {
     /// Mutate the shared document surface (id + optional field map).
     ///
     /// `fields` is `None` for delete changes.
-    fn transform(
-        &self,
-        table: &str,
-        id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
-    ) -> Result<()>;
+    fn transform(&self, table: &str, id: &mut Value, fields: Option<&mut FieldMap>) -> Result<()>;
 
     /// Transform a single row in place.
     fn transform_row(&self, row: &mut Row) -> Result<()> {
@@ -112,7 +106,7 @@ impl InPlaceTransform for Passthrough {
         &self,
         _table: &str,
         _id: &mut Value,
-        _fields: Option<&mut HashMap<String, Value>>,
+        _fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         Ok(())
     }
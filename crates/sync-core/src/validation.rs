@@ -0,0 +1,40 @@
+//! Post-sync validation queries.
+//!
+//! A [`ValidationQuery`] is a SurrealQL query run against the target after a
+//! full sync. It selects the records that violate an invariant, so a passing
+//! validation returns no rows; each row it does return is a violation. Unlike
+//! the loadtest verifier, which compares records one by one with what was
+//! generated, validations check properties of the synced data as a whole
+//! (orphaned references, duplicate keys, out-of-range totals).
+
+use serde::{Deserialize, Serialize};
+
+/// A named SurrealQL query expected to return zero rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationQuery {
+    /// Name reported when the validation fails.
+    pub name: String,
+    /// A single SurrealQL statement returning the violating records, e.g.
+    /// `SELECT * FROM orders WHERE customer.id IS NONE`.
+    pub query: String,
+}
+
+impl ValidationQuery {
+    pub fn new(name: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            query: query.into(),
+        }
+    }
+}
+
+/// A validation that returned rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationFailure {
+    /// [`ValidationQuery::name`] of the failed validation.
+    pub name: String,
+    /// Number of rows the query returned.
+    pub violations: u64,
+    /// The first few violating rows, rendered as SurrealQL.
+    pub samples: Vec<String>,
+}
@@ -6,11 +6,19 @@
 use crate::schema::Schema;
 use crate::types::Type;
 use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use thiserror::Error;
 use uuid::Uuid;
 
+/// Field map of objects, rows, changes and relations.
+///
+/// Keeps fields in insertion order (source column / document order), so a
+/// record converts and serializes the same way on every run. Equality ignores
+/// order. Use `shift_remove` to drop a field without reordering the rest.
+pub type FieldMap = IndexMap<String, Value>;
+
 /// Error when creating a TypedValue with mismatched type and value.
 #[derive(Debug, Error, Clone)]
 #[error(
@@ -25,6 +33,46 @@ pub struct TypedValueError {
     pub actual_value: String,
 }
 
+/// Largest magnitude `rust_decimal` can hold: its mantissa is 96 bits, so not
+/// every 29-digit number fits.
+const RUST_DECIMAL_MAX: &str = "79228162514264337593543950335";
+
+/// Check that the decimal string `value` fits `DECIMAL(precision, scale)`.
+///
+/// At most `precision - scale` integer digits are allowed. Extra fraction
+/// digits are fine, since databases round them to `scale`. The digits kept
+/// after rounding must also fit `rust_decimal`'s 96-bit mantissa, which the
+/// PostgreSQL and MySQL sinks convert decimals through.
+pub fn check_decimal_fits(value: &str, precision: u8, scale: u8) -> Result<(), String> {
+    let unsigned = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err("not a decimal number".to_string());
+    }
+    let integer = integer.trim_start_matches('0');
+    let max_integer_digits = precision.saturating_sub(scale) as usize;
+    if integer.len() > max_integer_digits {
+        return Err(format!(
+            "{} integer digits, at most {max_integer_digits} allowed",
+            integer.len()
+        ));
+    }
+    let kept_fraction = &fraction[..fraction.len().min(scale as usize)];
+    let mantissa = format!("{integer}{kept_fraction}");
+    let mantissa = mantissa.trim_start_matches('0');
+    if mantissa.len() > RUST_DECIMAL_MAX.len()
+        || mantissa.len() == RUST_DECIMAL_MAX.len() && mantissa > RUST_DECIMAL_MAX
+    {
+        return Err("exceeds the 96-bit decimal mantissa".to_string());
+    }
+    Ok(())
+}
+
 /// Universal value representation with 1:1 correspondence to `Type`.
 ///
 /// Each variant of `Value` corresponds exactly to one variant of `Type`,
@@ -195,7 +243,7 @@ pub enum Value {
     ///
     /// This differs from Json/Jsonb which are serialized JSON storage types.
     /// Object represents a structured nested document with typed fields.
-    Object(HashMap<String, Value>),
+    Object(FieldMap),
 
     /// Null value (can be any nullable type)
     Null,
@@ -587,6 +635,84 @@ impl Value {
     }
 }
 
+/// Structural hash, consistent with `PartialEq`: floats hash by their bit
+/// pattern (with `-0.0` folded into `0.0`) and object fields hash
+/// independently of their order. `Value` is not `Eq`, so this is for lane
+/// assignment and sampling, not for `HashMap` keys.
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Value::Bool(v) => v.hash(state),
+            Value::Int8 { value, .. } => value.hash(state),
+            Value::Int16(v) => v.hash(state),
+            Value::Int32(v) => v.hash(state),
+            Value::Int64(v) => v.hash(state),
+            Value::Float32(v) => (v + 0.0).to_bits().hash(state),
+            Value::Float64(v) => (v + 0.0).to_bits().hash(state),
+            Value::Decimal { value, .. }
+            | Value::Char { value, .. }
+            | Value::VarChar { value, .. }
+            | Value::Enum { value, .. }
+            | Value::Text(value)
+            | Value::TimeTz(value) => value.hash(state),
+            Value::Blob(v) | Value::Bytes(v) => v.hash(state),
+            Value::Date(v)
+            | Value::Time(v)
+            | Value::LocalDateTime(v)
+            | Value::LocalDateTimeNano(v)
+            | Value::ZonedDateTime(v) => v.hash(state),
+            Value::Uuid(v) => v.hash(state),
+            Value::Ulid(v) => v.hash(state),
+            Value::Json(v) | Value::Jsonb(v) => v.hash(state),
+            Value::Array { elements, .. } => {
+                elements.len().hash(state);
+                for element in elements {
+                    element.hash(state);
+                }
+            }
+            Value::Set { elements, .. } => elements.hash(state),
+            Value::Geometry { data, .. } => data.0.hash(state),
+            Value::Duration(v) => v.hash(state),
+            Value::Thing { table, id } => {
+                table.hash(state);
+                id.hash(state);
+            }
+            Value::Object(fields) => {
+                // Object equality ignores field order, so combine per-field
+                // hashes order-independently.
+                let combined = fields.iter().fold(0u64, |acc, (key, field)| {
+                    let mut field_hasher = std::collections::hash_map::DefaultHasher::new();
+                    key.hash(&mut field_hasher);
+                    field.hash(&mut field_hasher);
+                    acc.wrapping_add(field_hasher.finish())
+                });
+                fields.len().hash(state);
+                combined.hash(state);
+            }
+            Value::Null => {}
+            Value::ZeroTemporal { source, .. } => source.hash(state),
+        }
+    }
+}
+
+/// Parse the ISO 8601 durations the SurrealDB sinks recognize in text
+/// (`PT181S` or `PT181.000000000S`).
+///
+/// Text values in this form are written to SurrealDB as durations, so schema
+/// inference types them as [`Type::Duration`].
+pub fn parse_iso8601_duration(s: &str) -> Option<std::time::Duration> {
+    let secs_str = s.trim().strip_prefix("PT")?.strip_suffix('S')?;
+    if let Some((secs, nanos)) = secs_str.split_once('.') {
+        Some(std::time::Duration::new(
+            secs.parse().ok()?,
+            nanos.parse().ok()?,
+        ))
+    } else {
+        Some(std::time::Duration::from_secs(secs_str.parse().ok()?))
+    }
+}
+
 /// Typed value with its Type for conversion.
 ///
 /// `TypedValue` combines a `Value` with its corresponding `Type`,
@@ -626,7 +752,8 @@ impl TypedValue {
     /// - `BigInt` type requires `BigInt` value
     /// - `Float` type requires `Float` value
     /// - `Double` type requires `Double` value
-    /// - `Decimal` type requires `Decimal` value
+    /// - `Decimal` type requires `Decimal` value whose digits fit the type's
+    ///   precision and scale (see [`check_decimal_fits`])
     /// - `Char` type requires `Char` value
     /// - `VarChar` type requires `VarChar` value
     /// - `Text` type requires `Text` value
@@ -662,6 +789,12 @@ impl TypedValue {
             });
         }
 
+        if let (Type::Decimal { precision, scale }, Value::Decimal { value: digits, .. }) =
+            (&sync_type, &value)
+        {
+            Self::try_decimal(digits.as_str(), *precision, *scale)?;
+        }
+
         // Strict 1:1 validation - each type requires its exact corresponding value variant
         let is_valid = match (&sync_type, &value) {
             // Boolean
@@ -874,6 +1007,27 @@ impl TypedValue {
         )
     }
 
+    /// Create a decimal typed value, checking that `value` fits
+    /// `DECIMAL(precision, scale)` (see [`check_decimal_fits`]).
+    ///
+    /// Reverse conversions use this, so a source value that would be
+    /// truncated or overflow the sinks' 96-bit decimals fails the conversion.
+    pub fn try_decimal(
+        value: impl Into<String>,
+        precision: u8,
+        scale: u8,
+    ) -> Result<Self, TypedValueError> {
+        let value = value.into();
+        match check_decimal_fits(&value, precision, scale) {
+            Ok(()) => Ok(Self::decimal(value, precision, scale)),
+            Err(reason) => Err(TypedValueError {
+                sync_type: Type::Decimal { precision, scale },
+                expected_value: format!("Decimal fitting ({precision},{scale})"),
+                actual_value: format!("{value} ({reason})"),
+            }),
+        }
+    }
+
     /// Create an array typed value.
     pub fn array(elements: Vec<Value>, element_type: Type) -> Self {
         Self::new(
@@ -897,6 +1051,12 @@ impl TypedValue {
         Self::new(Type::Jsonb, Value::Jsonb(Box::new(value)))
     }
 
+    /// Create a nested object typed value; nested objects are
+    /// `Value::Object` fields of `fields`.
+    pub fn object(fields: FieldMap) -> Self {
+        Self::new(Type::Object, Value::Object(fields))
+    }
+
     /// Create a TINYINT typed value with optional width.
     pub fn int8(value: i8, width: u8) -> Self {
         Self::new(Type::Int8 { width }, Value::Int8 { value, width })
@@ -1019,17 +1179,12 @@ pub struct Row {
     pub id: Value,
 
     /// Field values (column name -> value)
-    pub fields: HashMap<String, Value>,
+    pub fields: FieldMap,
 }
 
 impl Row {
     /// Create a new internal row.
-    pub fn new(
-        table: impl Into<String>,
-        index: u64,
-        id: Value,
-        fields: HashMap<String, Value>,
-    ) -> Self {
+    pub fn new(table: impl Into<String>, index: u64, id: Value, fields: FieldMap) -> Self {
         Self {
             table: table.into(),
             index,
@@ -1044,7 +1199,7 @@ impl Row {
             table: table.into(),
             index,
             id,
-            fields: HashMap::new(),
+            fields: FieldMap::new(),
         }
     }
 
@@ -1059,21 +1214,55 @@ impl Row {
     }
 }
 
+/// Error when a field is added to a [`RowBuilder`] more than once.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Duplicate field '{field}' added to row for table '{table}'")]
+pub struct DuplicateFieldError {
+    /// Table of the row being built
+    pub table: String,
+    /// The field name that was added twice
+    pub field: String,
+}
+
 /// Builder for `Row`.
+///
+/// [`field`](Self::field) is last-wins: adding a name twice replaces the
+/// earlier value. Use [`try_add_field`](Self::try_add_field) where a duplicate
+/// indicates a bug (e.g. merging transform output into an existing row).
 pub struct RowBuilder {
     table: String,
     index: u64,
     id: Value,
-    fields: HashMap<String, Value>,
+    fields: FieldMap,
 }
 
 impl RowBuilder {
-    /// Add a field to the row.
+    /// Add a field to the row, replacing any earlier value with the same name.
     pub fn field(mut self, name: impl Into<String>, value: Value) -> Self {
         self.fields.insert(name.into(), value);
         self
     }
 
+    /// Add a field to the row, failing if the name was already added.
+    pub fn try_add_field(
+        mut self,
+        name: impl Into<String>,
+        value: Value,
+    ) -> Result<Self, DuplicateFieldError> {
+        use indexmap::map::Entry;
+
+        match self.fields.entry(name.into()) {
+            Entry::Occupied(entry) => Err(DuplicateFieldError {
+                table: self.table,
+                field: entry.key().clone(),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                Ok(self)
+            }
+        }
+    }
+
     /// Build the internal row.
     pub fn build(self) -> Row {
         Row {
@@ -1133,7 +1322,24 @@ pub struct Change {
     /// The record ID
     pub id: Value,
     /// Field values (None for Delete operations)
-    pub fields: Option<HashMap<String, Value>>,
+    pub fields: Option<FieldMap>,
+    /// Top-level fields an update actually changed, when the source knows
+    /// them (from a before image or an update description). A listed field
+    /// missing from `fields` was removed. `None` means unknown: the whole
+    /// record is written.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changed_fields: Option<Vec<String>>,
+    /// `fields` lacks some of the record's columns whose values the source
+    /// did not send (e.g. PostgreSQL TOAST values an update left unchanged).
+    /// An update then sets only the fields it carries and keeps the stored
+    /// values of the others, instead of replacing the record.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub partial: bool,
+    /// Values of the row before an update or delete, as far as the source
+    /// sent them (e.g. only the key columns under PostgreSQL's default
+    /// replica identity, every column under `REPLICA IDENTITY FULL`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Box<FieldMap>>,
 }
 
 impl Change {
@@ -1142,23 +1348,60 @@ impl Change {
         operation: ChangeOp,
         table: impl Into<String>,
         id: Value,
-        fields: Option<HashMap<String, Value>>,
+        fields: Option<FieldMap>,
     ) -> Self {
         Self {
             operation,
             table: table.into(),
             id,
             fields,
+            changed_fields: None,
+            partial: false,
+            before: None,
         }
     }
 
+    /// Record which top-level fields an update changed (see
+    /// [`Self::changed_fields`]).
+    pub fn with_changed_fields(mut self, changed_fields: Vec<String>) -> Self {
+        self.changed_fields = Some(changed_fields);
+        self
+    }
+
+    /// Mark the change as carrying only some of the record's fields (see
+    /// [`Self::partial`]).
+    pub fn with_partial(mut self, partial: bool) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    /// Attach the row's values before the change (see [`Self::before`]).
+    pub fn with_before(mut self, before: FieldMap) -> Self {
+        self.before = Some(Box::new(before));
+        self
+    }
+
+    /// Record the fields whose value differs from the source's before image
+    /// of the row. Fields missing from `before` count as changed; fields
+    /// missing from this change's `fields` are not considered removed.
+    pub fn with_before_image(self, before: &FieldMap) -> Self {
+        let changed = self
+            .fields
+            .iter()
+            .flatten()
+            .filter(|(name, value)| before.get(*name) != Some(*value))
+            .map(|(name, _)| name.clone())
+            .collect();
+        self.with_changed_fields(changed)
+    }
+
     /// Create a CREATE change.
-    pub fn create(table: impl Into<String>, id: Value, fields: HashMap<String, Value>) -> Self {
+    pub fn create(table: impl Into<String>, id: Value, fields: FieldMap) -> Self {
         Self::new(ChangeOp::Create, table, id, Some(fields))
     }
 
     /// Create an UPDATE change.
-    pub fn update(table: impl Into<String>, id: Value, fields: HashMap<String, Value>) -> Self {
+    pub fn update(table: impl Into<String>, id: Value, fields: FieldMap) -> Self {
         Self::new(ChangeOp::Update, table, id, Some(fields))
     }
 
@@ -1188,7 +1431,7 @@ pub struct Relation {
     /// The target node reference (table name + id)
     pub output: ThingRef,
     /// Properties on the relation itself
-    pub data: HashMap<String, Value>,
+    pub data: FieldMap,
 }
 
 /// A reference to a record/node in a specific table.
@@ -1219,7 +1462,7 @@ impl Relation {
         id: Value,
         input: ThingRef,
         output: ThingRef,
-        data: HashMap<String, Value>,
+        data: FieldMap,
     ) -> Self {
         Self {
             relation_type: relation_type.into(),
@@ -1277,6 +1520,33 @@ mod tests {
         assert_eq!(row.get_field("age"), Some(&Value::Int32(30)));
     }
 
+    #[test]
+    fn test_row_builder_duplicate_field() {
+        // `field` is last-wins.
+        let row = Row::builder("users", 0, Value::Int64(1))
+            .field("name", Value::Text("Alice".to_string()))
+            .field("name", Value::Text("Bob".to_string()))
+            .build();
+        assert_eq!(row.field_count(), 1);
+        assert_eq!(row.get_field("name"), Some(&Value::Text("Bob".to_string())));
+
+        // `try_add_field` rejects the duplicate.
+        let err = Row::builder("users", 0, Value::Int64(1))
+            .try_add_field("name", Value::Text("Alice".to_string()))
+            .unwrap()
+            .try_add_field("name", Value::Text("Bob".to_string()))
+            .err()
+            .expect("duplicate field must be rejected");
+        assert_eq!(
+            err,
+            DuplicateFieldError {
+                table: "users".to_string(),
+                field: "name".to_string(),
+            }
+        );
+        assert!(err.to_string().contains("Duplicate field 'name'"));
+    }
+
     #[test]
     fn test_try_with_type_valid_combinations() {
         // Bool type with Bool value
@@ -1334,6 +1604,75 @@ mod tests {
             Value::Json(Box::new(serde_json::Value::Bool(true)))
         )
         .is_ok());
+
+        // Object type with a (nested) Object value
+        let inner: FieldMap = [("city".to_string(), Value::Text("Paris".to_string()))]
+            .into_iter()
+            .collect();
+        let outer: FieldMap = [("address".to_string(), Value::Object(inner))]
+            .into_iter()
+            .collect();
+        assert!(TypedValue::try_with_type(Type::Object, Value::Object(outer)).is_ok());
+    }
+
+    #[test]
+    fn test_check_decimal_fits() {
+        assert!(check_decimal_fits("12345678.99", 10, 2).is_ok());
+        assert!(check_decimal_fits("-0012345678.99", 10, 2).is_ok());
+        // Extra fraction digits are rounded by the database
+        assert!(check_decimal_fits("1.23456", 10, 2).is_ok());
+        assert!(check_decimal_fits(".5", 10, 2).is_ok());
+
+        let err = check_decimal_fits("123456789.00", 10, 2).unwrap_err();
+        assert_eq!(err, "9 integer digits, at most 8 allowed");
+        assert!(check_decimal_fits("1e5", 10, 2).is_err());
+        assert!(check_decimal_fits("-", 10, 2).is_err());
+
+        // DECIMAL(38,10): the column fits 28 integer digits, but rust_decimal's
+        // 96-bit mantissa tops out at 79228162514264337593543950335.
+        assert!(check_decimal_fits("7922816251426433759354395033.5", 38, 10).is_ok());
+        let err = check_decimal_fits("7922816251426433759354395033.6", 38, 10).unwrap_err();
+        assert_eq!(err, "exceeds the 96-bit decimal mantissa");
+        assert!(check_decimal_fits("1234567890123456789012345678.1234567890", 38, 10).is_err());
+    }
+
+    #[test]
+    fn test_try_decimal() {
+        let tv = TypedValue::try_decimal("12345678.99", 10, 2).unwrap();
+        assert_eq!(tv.value, TypedValue::decimal("12345678.99", 10, 2).value);
+
+        let err = TypedValue::try_decimal("123456789.00", 10, 2).unwrap_err();
+        assert_eq!(
+            err.sync_type,
+            Type::Decimal {
+                precision: 10,
+                scale: 2
+            }
+        );
+        assert!(err
+            .to_string()
+            .contains("9 integer digits, at most 8 allowed"));
+    }
+
+    #[test]
+    fn test_try_with_type_checks_decimal_precision() {
+        let decimal = |value: &str| Value::Decimal {
+            value: value.to_string(),
+            precision: 5,
+            scale: 2,
+        };
+        let sync_type = Type::Decimal {
+            precision: 5,
+            scale: 2,
+        };
+        assert!(TypedValue::try_with_type(sync_type.clone(), decimal("999.99")).is_ok());
+
+        let err = TypedValue::try_with_type(sync_type, decimal("1000.00")).unwrap_err();
+        assert_eq!(err.expected_value, "Decimal fitting (5,2)");
+        assert_eq!(
+            err.actual_value,
+            "1000.00 (4 integer digits, at most 3 allowed)"
+        );
     }
 
     #[test]
@@ -1369,6 +1708,15 @@ mod tests {
             .unwrap_err();
         assert_eq!(err.expected_value, "Uuid");
         assert_eq!(err.actual_value, "Text");
+
+        // Object type with a serialized JSON document (strict 1:1)
+        let err = TypedValue::try_with_type(
+            Type::Object,
+            Value::Json(Box::new(serde_json::json!({"city": "Paris"}))),
+        )
+        .unwrap_err();
+        assert_eq!(err.expected_value, "Object");
+        assert_eq!(err.actual_value, "Json");
     }
 
     #[test]
@@ -1436,9 +1784,36 @@ mod tests {
         assert_eq!(back, row);
     }
 
+    #[test]
+    fn row_field_order_is_stable_across_roundtrip() {
+        let nested: FieldMap = [("zip", 2), ("city", 1)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), Value::Int64(v)))
+            .collect();
+        let row = Row::builder("users", 0, Value::Int64(1))
+            .field("zeta", Value::Int64(1))
+            .field("alpha", Value::Int64(2))
+            .field("address", Value::Object(nested))
+            .field("mid", Value::Null)
+            .build();
+
+        let json = serde_json::to_string(&row).expect("serialize row");
+        let back: Row = serde_json::from_str(&json).expect("deserialize row");
+        assert_eq!(
+            back.fields.keys().collect::<Vec<_>>(),
+            ["zeta", "alpha", "address", "mid"]
+        );
+        let Some(Value::Object(address)) = back.get_field("address") else {
+            panic!("address should stay an object");
+        };
+        assert_eq!(address.keys().collect::<Vec<_>>(), ["zip", "city"]);
+        // Same input, same bytes.
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+    }
+
     #[test]
     fn universal_change_serde_roundtrip_and_golden() {
-        let mut data = HashMap::new();
+        let mut data = FieldMap::new();
         data.insert("name".to_string(), Value::Text("Bob".to_string()));
         let change = Change::create("users", Value::Int64(9), data);
 
@@ -1446,7 +1821,7 @@ mod tests {
         let back: Change = serde_json::from_str(&json).expect("deserialize change");
         assert_eq!(back, change);
 
-        // Golden: fixed JSON (single field so HashMap order is irrelevant).
+        // Golden: fixed JSON.
         let golden = r#"{"operation":"Create","table":"users","id":{"type":"Int64","value":9},"fields":{"name":{"type":"Text","value":"Bob"}}}"#;
         let from_golden: Change = serde_json::from_str(golden).expect("deserialize golden");
         assert_eq!(from_golden, change);
@@ -1457,4 +1832,29 @@ mod tests {
         assert_eq!(delete_back, delete);
         assert!(delete_back.fields.is_none());
     }
+
+    #[test]
+    fn test_change_with_before_image_lists_changed_fields() {
+        let mut before = FieldMap::new();
+        before.insert("name".to_string(), Value::Text("Bob".to_string()));
+        before.insert("age".to_string(), Value::Int32(30));
+        let mut after = before.clone();
+        after.insert("age".to_string(), Value::Int32(31));
+        after.insert("email".to_string(), Value::Text("b@x".to_string()));
+
+        let change = Change::update("users", Value::Int64(9), after).with_before_image(&before);
+        assert_eq!(
+            change.changed_fields,
+            Some(vec!["age".to_string(), "email".to_string()])
+        );
+
+        let json = serde_json::to_string(&change).unwrap();
+        assert!(json.contains(r#""changed_fields":["age","email"]"#));
+        assert!(!json.contains(r#""before""#));
+        assert_eq!(serde_json::from_str::<Change>(&json).unwrap(), change);
+
+        let delete = Change::delete("users", Value::Int64(9)).with_before(before);
+        let json = serde_json::to_string(&delete).unwrap();
+        assert_eq!(serde_json::from_str::<Change>(&json).unwrap(), delete);
+    }
 }
@@ -14,8 +14,7 @@
 //! ```
 
 use anyhow::Result;
-use std::collections::HashMap;
-use surreal_sync_mysql::from_binlog::{run, FlattenId, InPlaceTransform, Value};
+use surreal_sync_mysql::from_binlog::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 use surreal_sync_surreal::Surreal3Sink;
 
 /// Drop columns that must not leave the source VPC (CDC often mirrors more than the target needs).
@@ -30,11 +29,11 @@ impl InPlaceTransform for RedactPii {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         if let Some(fields) = fields {
             for key in Self::DROP {
-                fields.remove(*key);
+                fields.shift_remove(*key);
             }
         }
         Ok(())
@@ -49,10 +48,10 @@ impl InPlaceTransform for RenameFields {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         if let Some(fields) = fields {
-            if let Some(name) = fields.remove("name") {
+            if let Some(name) = fields.shift_remove("name") {
                 fields.insert("full_name".into(), name);
             }
         }
@@ -71,12 +70,12 @@ impl InPlaceTransform for FkToRecordLink {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         let Some(fields) = fields else {
             return Ok(());
         };
-        let Some(id) = fields.remove(self.field) else {
+        let Some(id) = fields.shift_remove(self.field) else {
             return Ok(());
         };
         if matches!(id, Value::Null) {
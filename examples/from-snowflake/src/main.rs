@@ -15,8 +15,7 @@
 //! ```
 
 use anyhow::Result;
-use std::collections::HashMap;
-use surreal_sync_snowflake::{run, FlattenId, InPlaceTransform, Value};
+use surreal_sync_snowflake::{run, FieldMap, FlattenId, InPlaceTransform, Value};
 use surreal_sync_surreal::Surreal3Sink;
 
 /// Drop columns that must not leave the source VPC.
@@ -31,11 +30,11 @@ impl InPlaceTransform for RedactPii {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         if let Some(fields) = fields {
             for key in Self::DROP {
-                fields.remove(*key);
+                fields.shift_remove(*key);
             }
         }
         Ok(())
@@ -49,10 +48,10 @@ impl InPlaceTransform for RenameFields {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         if let Some(fields) = fields {
-            if let Some(name) = fields.remove("name") {
+            if let Some(name) = fields.shift_remove("name") {
                 fields.insert("full_name".into(), name);
             }
         }
@@ -70,12 +69,12 @@ impl InPlaceTransform for FkToRecordLink {
         &self,
         _table: &str,
         _id: &mut Value,
-        fields: Option<&mut HashMap<String, Value>>,
+        fields: Option<&mut FieldMap>,
     ) -> Result<()> {
         let Some(fields) = fields else {
             return Ok(());
         };
-        let Some(id) = fields.remove(self.field) else {
+        let Some(id) = fields.shift_remove(self.field) else {
             return Ok(());
         };
         if matches!(id, Value::Null) {
@@ -0,0 +1,432 @@
+//! Append-only audit log of sync runs.
+//!
+//! [`AuditLog`] appends one JSON line per run to a local file: when the run
+//! started and finished, what it read from and wrote to, a hash of its
+//! configuration, per-table counts, final checkpoints and the outcome. Each
+//! line carries a monotonically increasing `run_id`, the `hash` of the line
+//! before it (`prev_hash`) and its own `hash`, so editing, reordering or
+//! deleting an earlier line breaks the chain that [`AuditLog::verify`]
+//! checks. Appending takes an exclusive lock on the file, so concurrent runs
+//! writing to the same log still get distinct run ids.
+//!
+//! Credentials never reach the log: source URIs lose their password and
+//! secret-looking query parameters, and the SurrealDB password is left out
+//! of the configuration hash.
+//!
+//! The log is a local file. `s3://` and `https://` locations (as read by
+//! `FileSource`) are read-only and cannot hold it.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use surreal_sync_core::SurrealConfig;
+
+use crate::migrate::{FullSyncReport, MigrateConfig, SourceKind};
+
+/// How a run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+/// SurrealDB target of a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditTarget {
+    pub endpoint: String,
+    pub namespace: String,
+    pub database: String,
+}
+
+/// What one run did, before it is numbered and chained by [`AuditLog::append`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    /// Source URI with credentials redacted (see [`redact_uri`]).
+    pub source: String,
+    /// Source kind, when the URI selected one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_kind: Option<String>,
+    pub target: AuditTarget,
+    /// Hex SHA-256 of the run's configuration, credentials excluded.
+    pub config_hash: String,
+    pub dry_run: bool,
+    /// Records written (or changed) per table.
+    #[serde(default)]
+    pub records: BTreeMap<String, u64>,
+    /// Relations written per relation table.
+    #[serde(default)]
+    pub relations: BTreeMap<String, u64>,
+    /// Final checkpoint per stream, as the source prints it. Empty for full
+    /// syncs, which keep no cursor.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub checkpoints: BTreeMap<String, String>,
+    pub outcome: AuditOutcome,
+    /// Error chain of a failed run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditEntry {
+    /// Entry for a `migrate` run of `source_uri` that started at
+    /// `started_at` and ended just now with `result`.
+    pub fn for_migrate(
+        source_uri: &str,
+        surreal: &SurrealConfig,
+        config: &MigrateConfig,
+        started_at: DateTime<Utc>,
+        result: &Result<FullSyncReport>,
+    ) -> Self {
+        let source = redact_uri(source_uri);
+        let config_hash = sha256_hex(
+            serde_json::json!({
+                "command": "migrate",
+                "source": source,
+                "endpoint": surreal.endpoint,
+                "username": surreal.username,
+                "namespace": surreal.namespace,
+                "database": surreal.database,
+                "batch_size": surreal.batch_size,
+                "dry_run": surreal.dry_run,
+                "tables": config.tables,
+                "file_table": config.file_table,
+                "transforms_config": config.transforms_config,
+                "kafka_timeout_secs": config.kafka_timeout.as_secs(),
+                "sdk_version": config.sdk_version.map(|v| v.to_string()),
+                "validations": config.validations.iter().map(|v| &v.query).collect::<Vec<_>>(),
+            })
+            .to_string()
+            .as_bytes(),
+        );
+        let (records, relations, outcome, error) = match result {
+            Ok(report) => (
+                report.records.clone(),
+                report.relations.clone(),
+                if report.validations_passed() {
+                    AuditOutcome::Success
+                } else {
+                    AuditOutcome::Failure
+                },
+                (!report.validations_passed()).then(|| {
+                    format!(
+                        "{} of {} validations failed",
+                        report.validation_failures.len(),
+                        config.validations.len()
+                    )
+                }),
+            ),
+            Err(e) => (
+                BTreeMap::new(),
+                BTreeMap::new(),
+                AuditOutcome::Failure,
+                Some(format!("{e:#}")),
+            ),
+        };
+        Self {
+            started_at,
+            finished_at: Utc::now(),
+            source,
+            source_kind: SourceKind::from_uri(source_uri).ok().map(|k| k.to_string()),
+            target: AuditTarget {
+                endpoint: surreal.endpoint.clone(),
+                namespace: surreal.namespace.clone(),
+                database: surreal.database.clone(),
+            },
+            config_hash,
+            dry_run: surreal.dry_run,
+            records,
+            relations,
+            checkpoints: BTreeMap::new(),
+            outcome,
+            error,
+        }
+    }
+}
+
+/// One line of the audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// 1 for the first run in the file, then one more than the previous line.
+    pub run_id: u64,
+    #[serde(flatten)]
+    pub entry: AuditEntry,
+    /// `hash` of the previous line (`None` for the first).
+    pub prev_hash: Option<String>,
+    /// Hex SHA-256 over this line without its `hash` field.
+    pub hash: String,
+}
+
+impl AuditRecord {
+    fn seal(run_id: u64, entry: AuditEntry, prev_hash: Option<String>) -> Result<Self> {
+        let mut record = Self {
+            run_id,
+            entry,
+            prev_hash,
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash()?;
+        Ok(record)
+    }
+
+    fn compute_hash(&self) -> Result<String> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("hash");
+        }
+        Ok(sha256_hex(serde_json::to_string(&value)?.as_bytes()))
+    }
+}
+
+/// Append-only JSON-lines audit log at a local path.
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number `entry` after the last line, chain it to that line's hash and
+    /// append it. The file is created if missing.
+    pub fn append(&self, entry: AuditEntry) -> Result<AuditRecord> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+        file.lock()
+            .with_context(|| format!("Failed to lock audit log {}", self.path.display()))?;
+
+        file.seek(SeekFrom::Start(0))?;
+        let last = read_records(&file, &self.path)?.pop();
+        let record = AuditRecord::seal(
+            last.as_ref().map_or(1, |r| r.run_id + 1),
+            entry,
+            last.map(|r| r.hash),
+        )?;
+        let mut line = serde_json::to_string(&record)?;
+        line.push('\n');
+        file.write_all(line.as_bytes())?;
+        file.sync_data()?;
+        Ok(record)
+    }
+
+    /// Every record in the log, in order (empty if the file does not exist).
+    pub fn records(&self) -> Result<Vec<AuditRecord>> {
+        match File::open(&self.path) {
+            Ok(file) => read_records(&file, &self.path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => {
+                Err(e).with_context(|| format!("Failed to open audit log {}", self.path.display()))
+            }
+        }
+    }
+
+    /// Check that run ids increase by one, every hash matches its line and
+    /// every `prev_hash` matches the line before. Returns the number of
+    /// records checked.
+    pub fn verify(&self) -> Result<usize> {
+        let records = self.records()?;
+        let mut prev: Option<&AuditRecord> = None;
+        for record in &records {
+            let expected_id = prev.map_or(1, |p| p.run_id + 1);
+            if record.run_id != expected_id {
+                bail!(
+                    "audit log {}: run {} follows run {}",
+                    self.path.display(),
+                    record.run_id,
+                    expected_id - 1
+                );
+            }
+            if record.prev_hash.as_deref() != prev.map(|p| p.hash.as_str()) {
+                bail!(
+                    "audit log {}: run {} does not chain to the previous run",
+                    self.path.display(),
+                    record.run_id
+                );
+            }
+            if record.hash != record.compute_hash()? {
+                bail!(
+                    "audit log {}: run {} was modified after it was written",
+                    self.path.display(),
+                    record.run_id
+                );
+            }
+            prev = Some(record);
+        }
+        Ok(records.len())
+    }
+}
+
+fn read_records(file: &File, path: &Path) -> Result<Vec<AuditRecord>> {
+    let mut records = Vec::new();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(&line).with_context(|| {
+            format!(
+                "audit log {}: line {} is not an audit record",
+                path.display(),
+                i + 1
+            )
+        })?);
+    }
+    Ok(records)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Query parameters whose values are dropped by [`redact_uri`].
+const SECRET_PARAMS: &[&str] = &["password", "secret", "token", "key"];
+
+/// `uri` with the user-info password and secret-looking query parameter
+/// values replaced by `***`.
+pub fn redact_uri(uri: &str) -> String {
+    let Some((scheme, rest)) = uri.split_once("://") else {
+        return uri.to_string();
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let authority = match authority.rsplit_once('@') {
+        Some((userinfo, host)) => match userinfo.split_once(':') {
+            Some((user, _)) => format!("{user}:***@{host}"),
+            None => authority.to_string(),
+        },
+        None => authority.to_string(),
+    };
+    let mut out = format!("{scheme}://{authority}{path}");
+    if let Some(query) = query {
+        let params: Vec<String> = query
+            .split('&')
+            .map(|param| match param.split_once('=') {
+                Some((name, _))
+                    if SECRET_PARAMS
+                        .iter()
+                        .any(|s| name.to_ascii_lowercase().contains(s)) =>
+                {
+                    format!("{name}=***")
+                }
+                _ => param.to_string(),
+            })
+            .collect();
+        out.push('?');
+        out.push_str(&params.join("&"));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrate::migrate_with_sink;
+
+    #[test]
+    fn test_redact_uri() {
+        assert_eq!(
+            redact_uri("postgres://app:s3cret@db:5432/shop?sslmode=require&sslpassword=x"),
+            "postgres://app:***@db:5432/shop?sslmode=require&sslpassword=***"
+        );
+        assert_eq!(redact_uri("mongodb://app@db/shop"), "mongodb://app@db/shop");
+        assert_eq!(redact_uri("file:///data/a.csv"), "file:///data/a.csv");
+    }
+
+    #[tokio::test]
+    async fn test_completed_migrate_run_is_audited() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("people.jsonl");
+        std::fs::write(&path, "{\"id\": 1}\n{\"id\": 2}\n").unwrap();
+        let uri = format!("file://{}", path.display());
+        let surreal = SurrealConfig {
+            password: "hunter2".to_string(),
+            ..SurrealConfig::default()
+        };
+        let config = MigrateConfig::default();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+
+        for _ in 0..2 {
+            let started_at = Utc::now();
+            let sink = surreal_sync_core::ConsumerSink::new(NoopConsumer);
+            let result = migrate_with_sink(&uri, sink, &surreal, &config).await;
+            log.append(AuditEntry::for_migrate(
+                &uri, &surreal, &config, started_at, &result,
+            ))
+            .unwrap();
+        }
+
+        let records = log.records().unwrap();
+        assert_eq!(records.len(), 2);
+        let first = &records[0];
+        assert_eq!(first.run_id, 1);
+        assert_eq!(first.prev_hash, None);
+        assert_eq!(first.entry.outcome, AuditOutcome::Success);
+        assert_eq!(first.entry.source_kind.as_deref(), Some("jsonl"));
+        assert_eq!(
+            first.entry.records,
+            BTreeMap::from([("people".to_string(), 2)])
+        );
+        assert!(first.entry.finished_at >= first.entry.started_at);
+        assert_eq!(records[1].run_id, 2);
+        assert_eq!(records[1].prev_hash.as_deref(), Some(first.hash.as_str()));
+        assert_eq!(records[1].entry.config_hash, first.entry.config_hash);
+        assert!(!std::fs::read_to_string(log.path())
+            .unwrap()
+            .contains("hunter2"));
+        assert_eq!(log.verify().unwrap(), 2);
+
+        // Rewriting a count in the first line breaks the chain.
+        let tampered = std::fs::read_to_string(log.path()).unwrap().replacen(
+            "\"people\":2",
+            "\"people\":3",
+            1,
+        );
+        std::fs::write(log.path(), tampered).unwrap();
+        assert!(log.verify().is_err());
+    }
+
+    #[test]
+    fn test_failed_run_records_error() {
+        let surreal = SurrealConfig::default();
+        let entry = AuditEntry::for_migrate(
+            "ftp://x",
+            &surreal,
+            &MigrateConfig::default(),
+            Utc::now(),
+            &Err(anyhow::anyhow!("boom")),
+        );
+        assert_eq!(entry.outcome, AuditOutcome::Failure);
+        assert_eq!(entry.error.as_deref(), Some("boom"));
+        assert_eq!(entry.source_kind, None);
+    }
+
+    struct NoopConsumer;
+
+    #[async_trait::async_trait]
+    impl surreal_sync_core::ChangeConsumer for NoopConsumer {
+        async fn consume(&self, _records: &[surreal_sync_core::ChangeRecord]) -> Result<()> {
+            Ok(())
+        }
+    }
+}
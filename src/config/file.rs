@@ -45,6 +45,10 @@ pub struct SurrealDBSinkConfig {
     /// How zero temporal values (e.g. MySQL `0000-00-00`) are written to SurrealDB.
     #[serde(default)]
     pub zero_temporal: ZeroTemporalPolicy,
+
+    /// Concurrent write tasks per table within each batch.
+    #[serde(default = "default_write_concurrency")]
+    pub write_concurrency: usize,
 }
 
 fn default_surreal_endpoint() -> String {
@@ -63,6 +67,10 @@ fn default_batch_size() -> usize {
     1000
 }
 
+fn default_write_concurrency() -> usize {
+    1
+}
+
 /// Load and parse a TOML config file into `ConfigFile<S>`.
 pub fn load_config<S: DeserializeOwned>(path: &Path) -> Result<ConfigFile<S>> {
     let contents = std::fs::read_to_string(path)
@@ -0,0 +1,540 @@
+//! Export: SurrealDB as the source, a file or Kafka as the destination.
+//!
+//! [`run_export`] pages through each table of an [`ExportSource`] in record
+//! ID order and writes the rows to the [`ExportTarget`]:
+//!
+//! | Target | Output |
+//! |--------|--------|
+//! | [`ExportTarget::Jsonl`] | `<dir>/<table>.jsonl`, one JSON object per record with its ID under `id` |
+//! | [`ExportTarget::Kafka`] | one protobuf message per record on topic `<prefix><table>`, keyed by the record ID |
+//!
+//! Records are read back with the SurrealDB reverse conversions: fields of
+//! [`ExportOpts::schema`] get their declared type, other fields are
+//! converted without type context. Kafka messages are encoded like the
+//! `from kafka` source expects them, so a Kafka export needs a schema.
+//! JSONL exports re-import with `from jsonl` and Kafka exports with
+//! `from kafka`, using the `.proto` files written to the target's
+//! `proto_dir`.
+//!
+//! The reads page with `LIMIT`/`START` and are not one snapshot: records
+//! written during an export may be missed or exported twice.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::ClientConfig;
+use surreal_sync_core::{GeneratorTableDefinition, Row, Schema, SurrealConfig, SurrealSdkVersion};
+use surreal_sync_json::types::forward::JsonValue;
+use surreal_sync_kafka::producer::KafkaConnectionOptions;
+use surreal_sync_kafka::types::forward::{encode_row, get_message_key};
+use surreal_sync_surreal::{Surreal2Client, Surreal3Client};
+
+/// Protobuf package of the `.proto` files a Kafka export writes.
+pub const EXPORT_PROTO_PACKAGE: &str = "surreal_sync_export";
+
+/// Where [`run_export`] writes records.
+#[derive(Debug, Clone)]
+pub enum ExportTarget {
+    /// One `<table>.jsonl` file per table in `dir` (created if missing;
+    /// existing files are overwritten).
+    Jsonl { dir: PathBuf },
+    /// One topic per table, `<topic_prefix><table>`.
+    Kafka {
+        connection: KafkaConnectionOptions,
+        topic_prefix: String,
+        /// Write `<table>.proto` here, for `from kafka --proto-path`.
+        proto_dir: Option<PathBuf>,
+    },
+}
+
+impl std::fmt::Display for ExportTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Jsonl { dir } => write!(f, "jsonl ({})", dir.display()),
+            Self::Kafka { connection, .. } => write!(f, "kafka ({})", connection.brokers),
+        }
+    }
+}
+
+/// Options for [`run_export`].
+#[derive(Debug, Clone)]
+pub struct ExportOpts {
+    /// Destination of the exported records.
+    pub target: ExportTarget,
+    /// Tables to export; empty means every table in the database.
+    pub tables: Vec<String>,
+    /// Records read from SurrealDB per query.
+    pub batch_size: usize,
+    /// Field types to export with. Required for Kafka, whose messages are
+    /// encoded from the table schema.
+    pub schema: Option<Schema>,
+    /// SurrealDB SDK to use; `None` detects it from the server.
+    pub sdk_version: Option<SurrealSdkVersion>,
+}
+
+impl ExportOpts {
+    /// Export every table to `target`.
+    pub fn new(target: ExportTarget) -> Self {
+        Self {
+            target,
+            tables: Vec::new(),
+            batch_size: 1000,
+            schema: None,
+            sdk_version: None,
+        }
+    }
+}
+
+/// What a [`run_export`] run wrote, per table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportReport {
+    /// Records written per table.
+    pub records: BTreeMap<String, u64>,
+    /// Wall-clock time of the export.
+    pub elapsed: Duration,
+}
+
+impl ExportReport {
+    /// Records written across all tables.
+    pub fn total_records(&self) -> u64 {
+        self.records.values().sum()
+    }
+}
+
+/// SurrealDB database an export reads from.
+#[async_trait::async_trait]
+pub trait ExportSource: Send + Sync {
+    /// Tables defined in the database.
+    async fn table_names(&self) -> Result<Vec<String>>;
+
+    /// Records `start..start + limit` of `table` in record ID order.
+    async fn read_rows(
+        &self,
+        table: &str,
+        start: usize,
+        limit: usize,
+        table_schema: Option<&GeneratorTableDefinition>,
+    ) -> Result<Vec<Row>>;
+}
+
+#[async_trait::async_trait]
+impl ExportSource for Surreal2Client {
+    async fn table_names(&self) -> Result<Vec<String>> {
+        Surreal2Client::table_names(self).await
+    }
+
+    async fn read_rows(
+        &self,
+        table: &str,
+        start: usize,
+        limit: usize,
+        table_schema: Option<&GeneratorTableDefinition>,
+    ) -> Result<Vec<Row>> {
+        Surreal2Client::read_rows(self, table, start, limit, table_schema).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ExportSource for Surreal3Client {
+    async fn table_names(&self) -> Result<Vec<String>> {
+        Surreal3Client::table_names(self).await
+    }
+
+    async fn read_rows(
+        &self,
+        table: &str,
+        start: usize,
+        limit: usize,
+        table_schema: Option<&GeneratorTableDefinition>,
+    ) -> Result<Vec<Row>> {
+        Surreal3Client::read_rows(self, table, start, limit, table_schema).await
+    }
+}
+
+/// Export the SurrealDB database described by `surreal` (its namespace and
+/// database are the ones exported).
+///
+/// Connects with the v2 or v3 SDK (per [`ExportOpts::sdk_version`], detected
+/// from the server when unset) and runs [`run_export`].
+pub async fn export(surreal: &SurrealConfig, opts: &ExportOpts) -> Result<ExportReport> {
+    let version = match opts.sdk_version {
+        Some(version) => version,
+        None => {
+            match surreal_sync_surreal::version::detect_server_version(&surreal.endpoint).await? {
+                surreal_sync_surreal::version::SurrealMajorVersion::V2 => SurrealSdkVersion::V2,
+                surreal_sync_surreal::version::SurrealMajorVersion::V3 => SurrealSdkVersion::V3,
+            }
+        }
+    };
+    tracing::info!(
+        "Exporting {}/{} to {} (SDK {version})",
+        surreal.namespace,
+        surreal.database,
+        opts.target
+    );
+    match version {
+        SurrealSdkVersion::V2 => {
+            let client = Surreal2Client::connect(
+                &surreal.endpoint,
+                &surreal.username,
+                &surreal.password,
+                &surreal.namespace,
+                &surreal.database,
+            )
+            .await?;
+            run_export(&client, opts).await
+        }
+        SurrealSdkVersion::V3 => {
+            let client = Surreal3Client::connect(
+                &surreal.endpoint,
+                &surreal.username,
+                &surreal.password,
+                &surreal.namespace,
+                &surreal.database,
+            )
+            .await?;
+            run_export(&client, opts).await
+        }
+    }
+}
+
+/// Export the tables of `source` to [`ExportOpts::target`].
+pub async fn run_export(source: &dyn ExportSource, opts: &ExportOpts) -> Result<ExportReport> {
+    if opts.batch_size == 0 {
+        bail!("Export batch size must be at least 1");
+    }
+    let started = Instant::now();
+    let mut tables = if opts.tables.is_empty() {
+        source.table_names().await?
+    } else {
+        opts.tables.clone()
+    };
+    tables.sort();
+
+    let table_schema = |table: &str| {
+        opts.schema
+            .as_ref()
+            .and_then(|schema| schema.get_table(table))
+    };
+    let mut writer = match &opts.target {
+        ExportTarget::Jsonl { dir } => {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create export directory {}", dir.display()))?;
+            ExportWriter::Jsonl { dir: dir.clone() }
+        }
+        ExportTarget::Kafka {
+            connection,
+            topic_prefix,
+            proto_dir,
+        } => {
+            for table in &tables {
+                let Some(table_schema) = table_schema(table) else {
+                    bail!("Kafka export of table '{table}' needs its schema (--schema-file)");
+                };
+                if let Some(dir) = proto_dir {
+                    write_proto(dir, table_schema)?;
+                }
+            }
+            let mut config = ClientConfig::new();
+            connection
+                .apply_to_client_config(&mut config)
+                .context("Invalid Kafka connection options")?;
+            ExportWriter::Kafka {
+                producer: config.create().context("Failed to create Kafka producer")?,
+                topic_prefix: topic_prefix.clone(),
+            }
+        }
+    };
+
+    let mut records = BTreeMap::new();
+    for table in &tables {
+        let table_schema = table_schema(table);
+        let mut table_writer = writer.open(table)?;
+        let mut exported = 0;
+        loop {
+            let rows = source
+                .read_rows(table, exported, opts.batch_size, table_schema)
+                .await
+                .with_context(|| format!("Failed to read table '{table}'"))?;
+            let fetched = rows.len();
+            table_writer.write(&rows, table_schema).await?;
+            exported += fetched;
+            if fetched < opts.batch_size {
+                break;
+            }
+        }
+        table_writer.finish()?;
+        tracing::info!("Exported {exported} records of table '{table}'");
+        records.insert(table.clone(), exported as u64);
+    }
+
+    Ok(ExportReport {
+        records,
+        elapsed: started.elapsed(),
+    })
+}
+
+/// Writes `<dir>/<table>.proto`, describing the messages of a Kafka export.
+fn write_proto(dir: &Path, table_schema: &GeneratorTableDefinition) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create proto directory {}", dir.display()))?;
+    let path = dir.join(format!("{}.proto", table_schema.name));
+    std::fs::write(
+        &path,
+        loadtest_populate_kafka::generate_proto_for_table(table_schema, EXPORT_PROTO_PACKAGE),
+    )
+    .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+enum ExportWriter {
+    Jsonl {
+        dir: PathBuf,
+    },
+    Kafka {
+        producer: FutureProducer,
+        topic_prefix: String,
+    },
+}
+
+impl ExportWriter {
+    fn open(&mut self, table: &str) -> Result<TableWriter<'_>> {
+        Ok(match self {
+            Self::Jsonl { dir } => {
+                let path = dir.join(format!("{table}.jsonl"));
+                let file = File::create(&path)
+                    .with_context(|| format!("Failed to create {}", path.display()))?;
+                TableWriter::Jsonl {
+                    path,
+                    out: BufWriter::new(file),
+                }
+            }
+            Self::Kafka {
+                producer,
+                topic_prefix,
+            } => TableWriter::Kafka {
+                producer,
+                topic: format!("{topic_prefix}{table}"),
+            },
+        })
+    }
+}
+
+/// Destination of one table's records.
+enum TableWriter<'a> {
+    Jsonl {
+        path: PathBuf,
+        out: BufWriter<File>,
+    },
+    Kafka {
+        producer: &'a FutureProducer,
+        topic: String,
+    },
+}
+
+impl TableWriter<'_> {
+    async fn write(
+        &mut self,
+        rows: &[Row],
+        table_schema: Option<&GeneratorTableDefinition>,
+    ) -> Result<()> {
+        match self {
+            Self::Jsonl { path, out } => {
+                for row in rows {
+                    serde_json::to_writer(&mut *out, &row_to_json(row))?;
+                    out.write_all(b"\n")
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                }
+            }
+            Self::Kafka { producer, topic } => {
+                let table_schema = table_schema.context("Kafka export needs the table schema")?;
+                let messages = rows
+                    .iter()
+                    .map(|row| Ok((get_message_key(row), encode_row(row, table_schema)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                let mut deliveries = Vec::with_capacity(messages.len());
+                for (key, payload) in &messages {
+                    let record = FutureRecord::to(topic.as_str()).key(key).payload(payload);
+                    deliveries.push(producer.send(record, Duration::from_secs(30)));
+                }
+                for delivery in deliveries {
+                    delivery.await.map_err(|(err, _)| {
+                        anyhow::anyhow!("Failed to publish to topic '{topic}': {err}")
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        if let Self::Jsonl { path, mut out } = self {
+            out.flush()
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// A JSONL line: the record ID under `id`, followed by the fields.
+fn row_to_json(row: &Row) -> serde_json::Value {
+    let mut object = serde_json::Map::new();
+    object.insert(
+        "id".to_string(),
+        JsonValue::from(row.id.clone()).into_inner(),
+    );
+    for (name, value) in &row.fields {
+        object.insert(name.clone(), JsonValue::from(value.clone()).into_inner());
+    }
+    serde_json::Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surreal_sync_core::{
+        FieldConstraints, GeneratorConfig, GeneratorFieldDefinition, GeneratorIDDefinition, Type,
+    };
+
+    async fn client_with_data() -> Surreal2Client {
+        let client = Surreal2Client::new(surrealdb2::engine::any::connect("mem://").await.unwrap());
+        client.inner().use_ns("test").use_db("test").await.unwrap();
+        client
+            .inner()
+            .query(
+                "DEFINE TABLE users SCHEMALESS; DEFINE TABLE posts SCHEMALESS;
+                 CREATE users:1 CONTENT { name: 'Alice', age: 30, tags: ['a', 'b'] };
+                 CREATE users:2 CONTENT { name: 'Bob', age: 41, address: { city: 'Oslo' } };
+                 CREATE users:3 CONTENT { name: 'Carol', age: 52 };
+                 CREATE posts:hello CONTENT { author: users:1, title: 'Hello' };",
+            )
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        client
+    }
+
+    fn read_jsonl(path: &Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn exports_every_table_to_jsonl_in_record_id_order() {
+        let client = client_with_data().await;
+        let dir = tempfile::tempdir().unwrap();
+        let opts = ExportOpts {
+            batch_size: 2,
+            ..ExportOpts::new(ExportTarget::Jsonl {
+                dir: dir.path().join("export"),
+            })
+        };
+
+        let report = run_export(&client, &opts).await.unwrap();
+        assert_eq!(
+            report.records,
+            BTreeMap::from([("posts".to_string(), 1), ("users".to_string(), 3)])
+        );
+        assert_eq!(report.total_records(), 4);
+
+        let users = read_jsonl(&dir.path().join("export/users.jsonl"));
+        assert_eq!(
+            users,
+            vec![
+                serde_json::json!({ "id": 1, "name": "Alice", "age": 30, "tags": ["a", "b"] }),
+                serde_json::json!({ "id": 2, "name": "Bob", "age": 41, "address": { "city": "Oslo" } }),
+                serde_json::json!({ "id": 3, "name": "Carol", "age": 52 }),
+            ]
+        );
+        let posts = read_jsonl(&dir.path().join("export/posts.jsonl"));
+        assert_eq!(
+            posts,
+            vec![serde_json::json!({ "id": "hello", "author": "users:1", "title": "Hello" })]
+        );
+    }
+
+    #[tokio::test]
+    async fn exports_selected_tables_with_schema_types() {
+        let client = client_with_data().await;
+        let dir = tempfile::tempdir().unwrap();
+        let schema = Schema::new(vec![GeneratorTableDefinition {
+            name: "users".to_string(),
+            id: GeneratorIDDefinition {
+                id_type: Type::Int64,
+                generator: GeneratorConfig::Null,
+            },
+            fields: vec![GeneratorFieldDefinition {
+                name: "age".to_string(),
+                field_type: Type::Text,
+                generator: GeneratorConfig::Null,
+                nullable: false,
+                nullable_elements: false,
+                indexed: false,
+                constraints: FieldConstraints::default(),
+            }],
+        }]);
+        let opts = ExportOpts {
+            tables: vec!["users".to_string()],
+            schema: Some(schema),
+            ..ExportOpts::new(ExportTarget::Jsonl {
+                dir: dir.path().to_path_buf(),
+            })
+        };
+
+        let report = run_export(&client, &opts).await.unwrap();
+        assert_eq!(report.records, BTreeMap::from([("users".to_string(), 3)]));
+        assert!(!dir.path().join("posts.jsonl").exists());
+        let users = read_jsonl(&dir.path().join("users.jsonl"));
+        assert_eq!(users.len(), 3);
+        assert_eq!(users[0]["name"], "Alice");
+        // `age` is declared as text; values that don't match their declared
+        // type export as null.
+        assert_eq!(users[0]["age"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn empty_table_exports_an_empty_file() {
+        let client = client_with_data().await;
+        client
+            .inner()
+            .query("DEFINE TABLE empty SCHEMALESS;")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let opts = ExportOpts {
+            tables: vec!["empty".to_string()],
+            ..ExportOpts::new(ExportTarget::Jsonl {
+                dir: dir.path().to_path_buf(),
+            })
+        };
+
+        let report = run_export(&client, &opts).await.unwrap();
+        assert_eq!(report.total_records(), 0);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("empty.jsonl")).unwrap(),
+            ""
+        );
+    }
+
+    #[tokio::test]
+    async fn kafka_export_needs_a_schema() {
+        let client = client_with_data().await;
+        let opts = ExportOpts::new(ExportTarget::Kafka {
+            connection: KafkaConnectionOptions::plaintext("localhost:9092"),
+            topic_prefix: String::new(),
+            proto_dir: None,
+        });
+        let err = run_export(&client, &opts).await.unwrap_err();
+        assert!(err.to_string().contains("needs its schema"), "{err}");
+    }
+}
@@ -0,0 +1,18 @@
+//! Reporting for `--validate-checkpoint`.
+
+use surreal_sync_core::CheckpointValidity;
+
+/// Log a checkpoint validation outcome, failing when the checkpoint can no
+/// longer be resumed from so scripts can branch on the exit status.
+pub fn report_checkpoint_validity(
+    source: &str,
+    validity: CheckpointValidity,
+) -> anyhow::Result<()> {
+    match validity {
+        CheckpointValidity::Valid => {
+            tracing::info!("{source} checkpoint is valid; incremental sync can resume from it");
+            Ok(())
+        }
+        too_old => anyhow::bail!("{source} checkpoint is {too_old}"),
+    }
+}
@@ -0,0 +1,20 @@
+//! `--count-only` reporting.
+
+use surreal_sync_core::MigrationEstimate;
+
+/// Log the size report of a `--count-only` full sync as JSON.
+pub fn report_migration_estimate(estimate: &MigrationEstimate) -> anyhow::Result<()> {
+    let bytes = estimate
+        .total_approx_bytes
+        .map(|b| format!(", ~{b} bytes"))
+        .unwrap_or_default();
+    tracing::info!(
+        "{} full sync would move {} {} rows in {} tables{bytes}:\n{}",
+        estimate.source,
+        if estimate.exact { "exactly" } else { "about" },
+        estimate.total_rows,
+        estimate.tables.len(),
+        serde_json::to_string_pretty(estimate)?
+    );
+    Ok(())
+}
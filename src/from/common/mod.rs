@@ -1,11 +1,17 @@
 //! Common utilities for sync handlers.
 
+mod checkpoint;
+mod estimate;
 mod schema;
 mod sdk_version;
 mod sink;
 
+pub use checkpoint::report_checkpoint_validity;
+pub use estimate::report_migration_estimate;
 pub use schema::{
     extract_json_fields_from_schema, extract_postgresql_database, load_schema_if_provided,
+    report_inferred_schema, surreal_inferred_schema_ddl, surreal_schema_ddl,
+    validate_write_templates,
 };
 pub use sdk_version::{get_sdk_version, SdkVersion};
 pub use sink::{make_surreal2_sink, make_surreal3_sink};
@@ -2,7 +2,8 @@
 
 use anyhow::Context;
 use std::path::PathBuf;
-use surreal_sync_core::Schema;
+use surreal_sync_core::{Schema, WriteTemplate};
+use surreal_sync_surreal::DdlTarget;
 
 /// Load a schema file if provided.
 pub fn load_schema_if_provided(schema_file: &Option<PathBuf>) -> anyhow::Result<Option<Schema>> {
@@ -15,6 +16,55 @@ pub fn load_schema_if_provided(schema_file: &Option<PathBuf>) -> anyhow::Result<
     }
 }
 
+/// Log a schema inferred by `--infer-schema` as YAML, so it can be reviewed
+/// or saved and edited as a `--schema-file`.
+pub fn report_inferred_schema(schema: &Schema) -> anyhow::Result<()> {
+    tracing::info!(
+        "Inferred schema (usable as --schema-file):\n{}",
+        schema.to_yaml()?
+    );
+    Ok(())
+}
+
+/// SurrealQL defining every table, field and index of `schema` for the
+/// `target` SurrealDB version.
+pub fn surreal_schema_ddl(schema: &Schema, target: DdlTarget) -> String {
+    let ddl = surreal_sync_surreal::SurrealDdl;
+    schema
+        .tables
+        .iter()
+        .flat_map(|table| ddl.define_table(table, target))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// [`surreal_schema_ddl`] for a schema inferred by `--infer-schema`, whose
+/// text fields also accept durations.
+pub fn surreal_inferred_schema_ddl(schema: &Schema, target: DdlTarget) -> String {
+    let ddl = surreal_sync_surreal::SurrealDdl;
+    schema
+        .tables
+        .iter()
+        .flat_map(|table| ddl.define_inferred_table(table, target))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check `--write-template` placeholders against `schema`'s columns, when a
+/// schema was given.
+pub fn validate_write_templates(
+    templates: &[WriteTemplate],
+    schema: Option<&Schema>,
+) -> anyhow::Result<()> {
+    if let (Some(schema), false) = (schema, templates.is_empty()) {
+        let schema = schema.to_database_schema();
+        for template in templates {
+            template.validate(&schema)?;
+        }
+    }
+    Ok(())
+}
+
 /// Extract JSON field paths from a schema (e.g., ["users.profile_data", "products.metadata"]).
 /// This is used to auto-populate Neo4j JSON properties from the schema file.
 pub fn extract_json_fields_from_schema(schema: &Schema) -> Vec<String> {
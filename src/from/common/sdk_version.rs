@@ -23,7 +23,13 @@ pub async fn get_sdk_version(endpoint: &str, explicit: Option<&str>) -> anyhow::
         }
         None => {
             tracing::debug!("Auto-detecting SurrealDB server version...");
-            let detected = surreal_sync_surreal::version::detect_server_version(endpoint).await?;
+            // The server may still be starting when a sync is launched next to it
+            let detected = surreal_sync_surreal::version::detect_server_version_with_retries(
+                endpoint,
+                5,
+                std::time::Duration::from_millis(500),
+            )
+            .await?;
             let version = match detected {
                 surreal_sync_surreal::version::SurrealMajorVersion::V2 => SdkVersion::V2,
                 surreal_sync_surreal::version::SurrealMajorVersion::V3 => SdkVersion::V3,
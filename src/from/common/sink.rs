@@ -1,19 +1,69 @@
 //! Helpers for constructing SurrealDB sinks with config-driven options.
 
-use surreal_sync_core::ZeroTemporalPolicy;
+use surreal_sync_runtime::SurrealCliOpts;
 
-/// Build a SurrealDB v2 sink with the given zero-temporal policy.
+/// Build a SurrealDB v2 sink with the zero-temporal policy, write
+/// concurrency (global and per table), ordered tables, connection pool,
+/// write retries, write circuit breaker, version field, soft-delete mode, merge mode,
+/// changed-field patches, dead-letter table, conflict policy, large-field
+/// offload, write templates and dry-run mode from `opts`.
 pub fn make_surreal2_sink(
     client: surreal_sync_surreal::v2::SurrealClient,
-    zero_temporal: ZeroTemporalPolicy,
+    opts: &SurrealCliOpts,
 ) -> surreal_sync_surreal::v2::Surreal2Sink {
-    surreal_sync_surreal::v2::Surreal2Sink::with_zero_temporal_policy(client, zero_temporal)
+    let pool_opts = surreal_sync_surreal::v2::SurrealOpts {
+        surreal_endpoint: opts.surreal_endpoint.clone(),
+        surreal_username: opts.surreal_username.clone(),
+        surreal_password: opts.surreal_password.clone(),
+        write_retry: opts.write_retry(),
+    };
+    surreal_sync_surreal::v2::Surreal2Sink::with_zero_temporal_policy(client, opts.zero_temporal)
+        .with_write_concurrency(opts.write_concurrency)
+        .with_table_write_concurrency(opts.table_write_concurrency())
+        .with_ordered_tables(opts.ordered_tables.clone())
+        .with_write_retry(pool_opts.write_retry)
+        .with_connection_pool(pool_opts, opts.connection_pool_size)
+        .with_circuit_breaker(opts.circuit_breaker())
+        .with_version_field(opts.version_field.clone())
+        .with_soft_delete(opts.soft_delete())
+        .with_merge(opts.merge())
+        .with_skip_unchanged_fields(opts.skip_unchanged_fields)
+        .with_error_table(opts.error_table.clone())
+        .with_conflict_policy(opts.conflict_policy)
+        .with_large_fields(opts.large_fields())
+        .with_write_templates(opts.write_templates.clone())
+        .with_dry_run(opts.dry_run)
 }
 
-/// Build a SurrealDB v3 sink with the given zero-temporal policy.
+/// Build a SurrealDB v3 sink with the zero-temporal policy, write
+/// concurrency (global and per table), ordered tables, connection pool,
+/// write retries, write circuit breaker, version field, soft-delete mode, merge mode,
+/// changed-field patches, dead-letter table, conflict policy, large-field
+/// offload, write templates and dry-run mode from `opts`.
 pub fn make_surreal3_sink(
     client: surreal_sync_surreal::v3::SurrealClient,
-    zero_temporal: ZeroTemporalPolicy,
+    opts: &SurrealCliOpts,
 ) -> surreal_sync_surreal::v3::Surreal3Sink {
-    surreal_sync_surreal::v3::Surreal3Sink::with_zero_temporal_policy(client, zero_temporal)
+    let pool_opts = surreal_sync_surreal::v3::SurrealOpts {
+        surreal_endpoint: opts.surreal_endpoint.clone(),
+        surreal_username: opts.surreal_username.clone(),
+        surreal_password: opts.surreal_password.clone(),
+        write_retry: opts.write_retry(),
+    };
+    surreal_sync_surreal::v3::Surreal3Sink::with_zero_temporal_policy(client, opts.zero_temporal)
+        .with_write_concurrency(opts.write_concurrency)
+        .with_table_write_concurrency(opts.table_write_concurrency())
+        .with_ordered_tables(opts.ordered_tables.clone())
+        .with_write_retry(pool_opts.write_retry)
+        .with_connection_pool(pool_opts, opts.connection_pool_size)
+        .with_circuit_breaker(opts.circuit_breaker())
+        .with_version_field(opts.version_field.clone())
+        .with_soft_delete(opts.soft_delete())
+        .with_merge(opts.merge())
+        .with_skip_unchanged_fields(opts.skip_unchanged_fields)
+        .with_error_table(opts.error_table.clone())
+        .with_conflict_policy(opts.conflict_policy)
+        .with_large_fields(opts.large_fields())
+        .with_write_templates(opts.write_templates.clone())
+        .with_dry_run(opts.dry_run)
 }
@@ -4,10 +4,15 @@
 //! CLI command:
 //! - Import: `from csv --files ... --table ... --to-namespace ... --to-database ...`
 
-use super::transforms::load_transforms_from_args;
+use surreal_sync_surreal::DdlTarget;
+
+use super::transforms::load_transforms_with_columns;
 use super::{
-    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, SdkVersion,
+    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink,
+    report_inferred_schema, surreal_inferred_schema_ddl, surreal_schema_ddl,
+    validate_write_templates, SdkVersion,
 };
+
 use crate::CsvArgs;
 
 /// Run CSV import, dispatching to appropriate SDK version.
@@ -32,14 +37,19 @@ async fn run_v2(args: CsvArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let schema = load_schema_if_provided(&args.schema_file)?;
+    validate_write_templates(&args.surreal.write_templates, schema.as_ref())?;
 
     // Connect to SurrealDB using v2 SDK
     let surreal_opts = surreal_sync_surreal::v2::SurrealOpts {
-        surreal_endpoint: args.surreal.surreal_endpoint,
-        surreal_username: args.surreal.surreal_username,
-        surreal_password: args.surreal.surreal_password,
+        surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+        surreal_username: args.surreal.surreal_username.clone(),
+        surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -47,17 +57,28 @@ async fn run_v2(args: CsvArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
-
-    let config = surreal_sync::csv::Config {
+    if args.define_schema && !args.surreal.dry_run {
+        if let Some(schema) = &schema {
+            surreal
+                .query(surreal_schema_ddl(schema, DdlTarget::V2))
+                .await?
+                .check()?;
+        }
+    }
+    let mut config = surreal_sync::csv::Config {
         sources: vec![],
         files: args.files,
         s3_uris: args.s3_uris,
         http_uris: args.http_uris,
+        s3_credentials: args.aws.s3_credentials(),
+        http_options: args.http.http_options()?,
         table: args.table,
         batch_size: args.surreal.batch_size,
-        has_headers: args.has_headers,
-        delimiter: args.delimiter as u8,
+        dialect: surreal_sync::csv::CsvDialect {
+            delimiter: args.delimiter as u8,
+            quote: args.quote as u8,
+            has_headers: args.has_headers,
+        },
         id_field: args.id_field,
         id_columns: args.id_columns,
         column_names: args.column_names,
@@ -65,6 +86,18 @@ async fn run_v2(args: CsvArgs) -> anyhow::Result<()> {
         dry_run: args.surreal.dry_run,
         schema,
     };
+    if args.infer_schema {
+        let schema = surreal_sync::csv::infer_schema(&config, args.infer_sample_size).await?;
+        report_inferred_schema(&schema)?;
+        if !config.dry_run {
+            surreal
+                .query(surreal_inferred_schema_ddl(&schema, DdlTarget::V2))
+                .await?
+                .check()?;
+        }
+        config.schema = Some(schema);
+    }
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     surreal_sync::csv::sync_with_transforms(&sink, config, &pipeline, &apply_opts).await?;
 
     tracing::info!("CSV import completed successfully");
@@ -79,14 +112,19 @@ async fn run_v3(args: CsvArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let schema = load_schema_if_provided(&args.schema_file)?;
+    validate_write_templates(&args.surreal.write_templates, schema.as_ref())?;
 
     // Connect to SurrealDB using v3 SDK
     let surreal_opts = surreal_sync_surreal::v3::SurrealOpts {
-        surreal_endpoint: args.surreal.surreal_endpoint,
-        surreal_username: args.surreal.surreal_username,
-        surreal_password: args.surreal.surreal_password,
+        surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+        surreal_username: args.surreal.surreal_username.clone(),
+        surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -94,17 +132,28 @@ async fn run_v3(args: CsvArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
-
-    let config = surreal_sync::csv::Config {
+    if args.define_schema && !args.surreal.dry_run {
+        if let Some(schema) = &schema {
+            surreal
+                .query(surreal_schema_ddl(schema, DdlTarget::V3))
+                .await?
+                .check()?;
+        }
+    }
+    let mut config = surreal_sync::csv::Config {
         sources: vec![],
         files: args.files,
         s3_uris: args.s3_uris,
         http_uris: args.http_uris,
+        s3_credentials: args.aws.s3_credentials(),
+        http_options: args.http.http_options()?,
         table: args.table,
         batch_size: args.surreal.batch_size,
-        has_headers: args.has_headers,
-        delimiter: args.delimiter as u8,
+        dialect: surreal_sync::csv::CsvDialect {
+            delimiter: args.delimiter as u8,
+            quote: args.quote as u8,
+            has_headers: args.has_headers,
+        },
         id_field: args.id_field,
         id_columns: args.id_columns,
         column_names: args.column_names,
@@ -112,6 +161,18 @@ async fn run_v3(args: CsvArgs) -> anyhow::Result<()> {
         dry_run: args.surreal.dry_run,
         schema,
     };
+    if args.infer_schema {
+        let schema = surreal_sync::csv::infer_schema(&config, args.infer_sample_size).await?;
+        report_inferred_schema(&schema)?;
+        if !config.dry_run {
+            surreal
+                .query(surreal_inferred_schema_ddl(&schema, DdlTarget::V3))
+                .await?
+                .check()?;
+        }
+        config.schema = Some(schema);
+    }
+    let sink = make_surreal3_sink(surreal, &args.surreal);
     surreal_sync::csv::sync_with_transforms(&sink, config, &pipeline, &apply_opts).await?;
 
     tracing::info!("CSV import completed successfully");
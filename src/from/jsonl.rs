@@ -4,10 +4,15 @@
 //! CLI command:
 //! - Import: `from jsonl --path ... --to-namespace ... --to-database ...`
 
-use super::transforms::load_transforms_from_args;
+use surreal_sync_surreal::DdlTarget;
+
+use super::transforms::load_transforms_with_columns;
 use super::{
-    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, SdkVersion,
+    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink,
+    report_inferred_schema, surreal_inferred_schema_ddl, surreal_schema_ddl,
+    validate_write_templates, SdkVersion,
 };
+
 use crate::JsonlArgs;
 
 /// Run JSONL import, dispatching to appropriate SDK version.
@@ -32,16 +37,21 @@ async fn run_v2(args: JsonlArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
-    // Load and convert schema to DatabaseSchema for type-aware JSONL conversion
-    let schema = load_schema_if_provided(&args.schema_file)?.map(|s| s.to_database_schema());
+    // Converted to a DatabaseSchema for type-aware JSONL conversion below
+    let schema = load_schema_if_provided(&args.schema_file)?;
+    validate_write_templates(&args.surreal.write_templates, schema.as_ref())?;
 
     // Connect to SurrealDB using v2 SDK
     let surreal_opts = surreal_sync_surreal::v2::SurrealOpts {
-        surreal_endpoint: args.surreal.surreal_endpoint,
-        surreal_username: args.surreal.surreal_username,
-        surreal_password: args.surreal.surreal_password,
+        surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+        surreal_username: args.surreal.surreal_username.clone(),
+        surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -49,21 +59,42 @@ async fn run_v2(args: JsonlArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
-
+    if args.define_schema && !args.surreal.dry_run {
+        if let Some(schema) = &schema {
+            surreal
+                .query(surreal_schema_ddl(schema, DdlTarget::V2))
+                .await?
+                .check()?;
+        }
+    }
     // Create config with file source
-    let config = surreal_sync::jsonl::Config {
+    let mut config = surreal_sync::jsonl::Config {
         sources: vec![],
         files: vec![args.path.into()],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         id_field: args.id_field,
         id_columns: args.id_columns,
         conversion_rules: args.conversion_rules,
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
-        schema,
+        schema: schema.as_ref().map(|s| s.to_database_schema()),
+        on_error: args.on_error.into(),
     };
+    if args.infer_schema {
+        let schema = surreal_sync::jsonl::infer_schema(&config, args.infer_sample_size).await?;
+        report_inferred_schema(&schema)?;
+        if !config.dry_run {
+            surreal
+                .query(surreal_inferred_schema_ddl(&schema, DdlTarget::V2))
+                .await?
+                .check()?;
+        }
+        config.schema = Some(schema.to_database_schema());
+    }
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     surreal_sync::jsonl::sync_with_transforms(&sink, config, &pipeline, &apply_opts).await?;
 
     tracing::info!("JSONL import completed successfully");
@@ -78,16 +109,21 @@ async fn run_v3(args: JsonlArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
-    // Load and convert schema to DatabaseSchema for type-aware JSONL conversion
-    let schema = load_schema_if_provided(&args.schema_file)?.map(|s| s.to_database_schema());
+    // Converted to a DatabaseSchema for type-aware JSONL conversion below
+    let schema = load_schema_if_provided(&args.schema_file)?;
+    validate_write_templates(&args.surreal.write_templates, schema.as_ref())?;
 
     // Connect to SurrealDB using v3 SDK
     let surreal_opts = surreal_sync_surreal::v3::SurrealOpts {
-        surreal_endpoint: args.surreal.surreal_endpoint,
-        surreal_username: args.surreal.surreal_username,
-        surreal_password: args.surreal.surreal_password,
+        surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+        surreal_username: args.surreal.surreal_username.clone(),
+        surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -95,21 +131,42 @@ async fn run_v3(args: JsonlArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
-
+    if args.define_schema && !args.surreal.dry_run {
+        if let Some(schema) = &schema {
+            surreal
+                .query(surreal_schema_ddl(schema, DdlTarget::V3))
+                .await?
+                .check()?;
+        }
+    }
     // Create config with file source
-    let config = surreal_sync::jsonl::Config {
+    let mut config = surreal_sync::jsonl::Config {
         sources: vec![],
         files: vec![args.path.into()],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         id_field: args.id_field,
         id_columns: args.id_columns,
         conversion_rules: args.conversion_rules,
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
-        schema,
+        schema: schema.as_ref().map(|s| s.to_database_schema()),
+        on_error: args.on_error.into(),
     };
+    if args.infer_schema {
+        let schema = surreal_sync::jsonl::infer_schema(&config, args.infer_sample_size).await?;
+        report_inferred_schema(&schema)?;
+        if !config.dry_run {
+            surreal
+                .query(surreal_inferred_schema_ddl(&schema, DdlTarget::V3))
+                .await?
+                .check()?;
+        }
+        config.schema = Some(schema.to_database_schema());
+    }
+    let sink = make_surreal3_sink(surreal, &args.surreal);
     surreal_sync::jsonl::sync_with_transforms(&sink, config, &pipeline, &apply_opts).await?;
 
     tracing::info!("JSONL import completed successfully");
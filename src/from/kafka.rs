@@ -7,14 +7,20 @@
 use anyhow::Context;
 use surreal_sync_core::Schema;
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
-    get_sdk_version, make_surreal2_sink, make_surreal3_sink, parse_duration_to_secs, SdkVersion,
+    get_sdk_version, make_surreal2_sink, make_surreal3_sink, parse_duration_to_secs,
+    report_checkpoint_validity, SdkVersion,
 };
 use crate::KafkaArgs;
 
 /// Run Kafka streaming sync, dispatching to appropriate SDK version.
 pub async fn run(args: KafkaArgs) -> anyhow::Result<()> {
+    if args.validate_checkpoint {
+        let validity = surreal_sync_kafka::from_kafka::validate_checkpoint(&args.config).await?;
+        return report_checkpoint_validity("Kafka", validity);
+    }
+
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -36,7 +42,10 @@ async fn run_v2(args: KafkaArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     // Parse timeout duration
     let timeout_secs = parse_duration_to_secs(&args.timeout)
@@ -46,9 +55,10 @@ async fn run_v2(args: KafkaArgs) -> anyhow::Result<()> {
 
     // Connect to SurrealDB using v2 SDK
     let surreal_opts = surreal_sync_surreal::v2::SurrealOpts {
-        surreal_endpoint: args.surreal.surreal_endpoint,
-        surreal_username: args.surreal.surreal_username,
-        surreal_password: args.surreal.surreal_password,
+        surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+        surreal_username: args.surreal.surreal_username.clone(),
+        surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -56,7 +66,7 @@ async fn run_v2(args: KafkaArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = std::sync::Arc::new(make_surreal2_sink(surreal, args.surreal.zero_temporal));
+    let sink = std::sync::Arc::new(make_surreal2_sink(surreal, &args.surreal));
 
     let table_schema = if let Some(schema_path) = args.schema_file {
         let schema = Schema::from_file(&schema_path)
@@ -95,7 +105,10 @@ async fn run_v3(args: KafkaArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     // Parse timeout duration
     let timeout_secs = parse_duration_to_secs(&args.timeout)
@@ -105,9 +118,10 @@ async fn run_v3(args: KafkaArgs) -> anyhow::Result<()> {
 
     // Connect to SurrealDB using v3 SDK
     let surreal_opts = surreal_sync_surreal::v3::SurrealOpts {
-        surreal_endpoint: args.surreal.surreal_endpoint,
-        surreal_username: args.surreal.surreal_username,
-        surreal_password: args.surreal.surreal_password,
+        surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+        surreal_username: args.surreal.surreal_username.clone(),
+        surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -115,7 +129,7 @@ async fn run_v3(args: KafkaArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = std::sync::Arc::new(make_surreal3_sink(surreal, args.surreal.zero_temporal));
+    let sink = std::sync::Arc::new(make_surreal3_sink(surreal, &args.surreal));
 
     let table_schema = if let Some(schema_path) = args.schema_file {
         let schema = Schema::from_file(&schema_path)
@@ -37,5 +37,7 @@ pub mod transforms;
 pub(crate) use crate::config::parse_duration_to_secs;
 pub(crate) use common::{
     extract_json_fields_from_schema, extract_postgresql_database, get_sdk_version,
-    load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, SdkVersion,
+    load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, report_checkpoint_validity,
+    report_inferred_schema, report_migration_estimate, surreal_inferred_schema_ddl,
+    surreal_schema_ddl, validate_write_templates, SdkVersion,
 };
@@ -4,18 +4,37 @@
 //! CLI commands:
 //! - Full sync: `from mongodb full --connection-string ... --database ... --tables ... --checkpoints-surreal-table ...`
 //! - Incremental sync: `from mongodb incremental --connection-string ... --database ... --tables ... --checkpoints-surreal-table ...`
+//! - Recompute sync: `from mongodb recompute --connection-string ... --database ... --aggregation table=path.json`
 
 use anyhow::Context;
 use surreal_sync_core::Checkpoint;
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
-    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, SdkVersion,
+    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink,
+    report_checkpoint_validity, report_migration_estimate, SdkVersion,
 };
-use crate::{MongoDBFullArgs, MongoDBIncrementalArgs};
+use crate::{MongoDBFullArgs, MongoDBIncrementalArgs, MongoDBRecomputeArgs};
 
 /// Run MongoDB full sync, dispatching to appropriate SDK version.
 pub async fn run_full(args: MongoDBFullArgs) -> anyhow::Result<()> {
+    if args.count_only {
+        let source_opts = surreal_sync_mongodb_changestream_source::SourceOpts {
+            source_uri: args.connection_string,
+            source_database: Some(args.database),
+            collections: args.tables,
+            bson_converters: Default::default(),
+            column_filters: Default::default(),
+            array_relations: Default::default(),
+            include_collections: args.include_collections,
+            exclude_collections: args.exclude_collections,
+            projection: None,
+        };
+        let estimate =
+            surreal_sync_mongodb_changestream_source::estimate_collections(&source_opts).await?;
+        return report_migration_estimate(&estimate);
+    }
+
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -36,7 +55,10 @@ async fn run_full_v2(args: MongoDBFullArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     // Load and convert schema to DatabaseSchema for type-aware conversion
     let schema = load_schema_if_provided(&args.schema_file)?.map(|s| s.to_database_schema());
@@ -46,6 +68,7 @@ async fn run_full_v2(args: MongoDBFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -53,18 +76,26 @@ async fn run_full_v2(args: MongoDBFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     let source_opts = surreal_sync_mongodb_changestream_source::SourceOpts {
         source_uri: args.connection_string,
         source_database: Some(args.database),
         collections: args.tables,
+        bson_converters: Default::default(),
+        column_filters: args.columns.to_filters()?,
+        array_relations: load_array_relations(args.array_relations.as_deref())?,
+        include_collections: args.include_collections,
+        exclude_collections: args.exclude_collections,
+        projection: parse_projection(args.projection.as_deref())?,
     };
 
     let sync_opts = surreal_sync_mongodb_changestream_source::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
         schema,
+        conversion_workers: args.conversion_workers,
+        consistent_snapshot: args.consistent_snapshot,
     };
 
     // Handle checkpoint storage
@@ -126,7 +157,10 @@ async fn run_full_v3(args: MongoDBFullArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     let schema = load_schema_if_provided(&args.schema_file)?.map(|s| s.to_database_schema());
 
@@ -134,6 +168,7 @@ async fn run_full_v3(args: MongoDBFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -141,18 +176,26 @@ async fn run_full_v3(args: MongoDBFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
 
     let source_opts = surreal_sync_mongodb_changestream_source::SourceOpts {
         source_uri: args.connection_string,
         source_database: Some(args.database),
         collections: args.tables,
+        bson_converters: Default::default(),
+        column_filters: args.columns.to_filters()?,
+        array_relations: load_array_relations(args.array_relations.as_deref())?,
+        include_collections: args.include_collections,
+        exclude_collections: args.exclude_collections,
+        projection: parse_projection(args.projection.as_deref())?,
     };
 
     let sync_opts = surreal_sync_mongodb_changestream_source::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
         schema,
+        conversion_workers: args.conversion_workers,
+        consistent_snapshot: args.consistent_snapshot,
     };
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
@@ -227,13 +270,17 @@ async fn run_incremental_v2(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let _schema = load_schema_if_provided(&args.schema_file)?;
 
     let surreal_opts = surreal_sync_surreal::v2::SurrealOpts {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -264,6 +311,10 @@ async fn run_incremental_v2(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         }
     };
 
+    if args.validate_checkpoint {
+        return validate_mongodb_checkpoint(&args, &from_checkpoint).await;
+    }
+
     if let Some(ref to) = args.incremental_to {
         tracing::info!("Will stop at checkpoint: {}", to);
     }
@@ -284,6 +335,12 @@ async fn run_incremental_v2(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         source_uri: args.connection_string,
         source_database: Some(args.database),
         collections: args.tables,
+        bson_converters: Default::default(),
+        column_filters: args.columns.to_filters()?,
+        array_relations: Default::default(),
+        include_collections: args.include_collections,
+        exclude_collections: args.exclude_collections,
+        projection: parse_projection(args.projection.as_deref())?,
     };
 
     let surreal = surreal_sync_surreal::v2::surreal_connect(
@@ -292,7 +349,7 @@ async fn run_incremental_v2(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     surreal_sync_mongodb_changestream_source::run_incremental_sync_with_transforms(
         &sink,
@@ -318,13 +375,17 @@ async fn run_incremental_v3(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let _schema = load_schema_if_provided(&args.schema_file)?;
 
     let surreal_opts = surreal_sync_surreal::v3::SurrealOpts {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -355,6 +416,10 @@ async fn run_incremental_v3(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         }
     };
 
+    if args.validate_checkpoint {
+        return validate_mongodb_checkpoint(&args, &from_checkpoint).await;
+    }
+
     if let Some(ref to) = args.incremental_to {
         tracing::info!("Will stop at checkpoint: {}", to);
     }
@@ -375,6 +440,12 @@ async fn run_incremental_v3(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         source_uri: args.connection_string,
         source_database: Some(args.database),
         collections: args.tables,
+        bson_converters: Default::default(),
+        column_filters: args.columns.to_filters()?,
+        array_relations: Default::default(),
+        include_collections: args.include_collections,
+        exclude_collections: args.exclude_collections,
+        projection: parse_projection(args.projection.as_deref())?,
     };
 
     let surreal = surreal_sync_surreal::v3::surreal_connect(
@@ -383,7 +454,7 @@ async fn run_incremental_v3(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
 
     surreal_sync_mongodb_changestream_source::run_incremental_sync_with_transforms(
         &sink,
@@ -400,3 +471,153 @@ async fn run_incremental_v3(args: MongoDBIncrementalArgs) -> anyhow::Result<()>
     tracing::info!("Incremental sync completed successfully");
     Ok(())
 }
+
+/// Run MongoDB recompute-and-diff sync, dispatching to appropriate SDK version.
+pub async fn run_recompute(args: MongoDBRecomputeArgs) -> anyhow::Result<()> {
+    let opts = recompute_opts(&args)?;
+    let sdk_version = get_sdk_version(
+        &args.surreal.surreal_endpoint,
+        args.surreal.surreal_sdk_version.as_deref(),
+    )
+    .await?;
+
+    tracing::info!(
+        "Starting recompute sync from MongoDB to SurrealDB ({} tables)",
+        opts.views.len()
+    );
+    tracing::info!("Target: {}/{}", args.to_namespace, args.to_database);
+
+    match sdk_version {
+        SdkVersion::V2 => {
+            let surreal_opts = surreal_sync_surreal::v2::SurrealOpts {
+                surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+                surreal_username: args.surreal.surreal_username.clone(),
+                surreal_password: args.surreal.surreal_password.clone(),
+                write_retry: args.surreal.write_retry(),
+            };
+            let surreal = surreal_sync_surreal::v2::surreal_connect(
+                &surreal_opts,
+                &args.to_namespace,
+                &args.to_database,
+            )
+            .await?;
+            let sink = make_surreal2_sink(surreal, &args.surreal);
+            surreal_sync_mongodb_changestream_source::run_recompute(&sink, &opts).await
+        }
+        SdkVersion::V3 => {
+            let surreal_opts = surreal_sync_surreal::v3::SurrealOpts {
+                surreal_endpoint: args.surreal.surreal_endpoint.clone(),
+                surreal_username: args.surreal.surreal_username.clone(),
+                surreal_password: args.surreal.surreal_password.clone(),
+                write_retry: args.surreal.write_retry(),
+            };
+            let surreal = surreal_sync_surreal::v3::surreal_connect(
+                &surreal_opts,
+                &args.to_namespace,
+                &args.to_database,
+            )
+            .await?;
+            let sink = make_surreal3_sink(surreal, &args.surreal);
+            surreal_sync_mongodb_changestream_source::run_recompute(&sink, &opts).await
+        }
+    }
+}
+
+/// Read `--array-relations`: a JSON list of [`ArrayRelation`] specs.
+///
+/// [`ArrayRelation`]: surreal_sync_mongodb_changestream_source::ArrayRelation
+fn load_array_relations(
+    path: Option<&std::path::Path>,
+) -> anyhow::Result<Vec<surreal_sync_mongodb_changestream_source::ArrayRelation>> {
+    let Some(path) = path else {
+        return Ok(Vec::new());
+    };
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read array relations file {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid array relations file {}", path.display()))
+}
+
+/// Parse `--projection`: a JSON `find` projection such as `{"blob": 0}`.
+fn parse_projection(json: Option<&str>) -> anyhow::Result<Option<mongodb::bson::Document>> {
+    let Some(json) = json else {
+        return Ok(None);
+    };
+    let map: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(json).context("--projection must be a JSON object")?;
+    let projection = mongodb::bson::Document::try_from(map).context("Invalid --projection")?;
+    Ok(Some(projection))
+}
+
+/// Aggregation file of `--aggregation table=path`.
+#[derive(serde::Deserialize)]
+struct AggregationFile {
+    collection: Option<String>,
+    pipeline: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+fn recompute_opts(
+    args: &MongoDBRecomputeArgs,
+) -> anyhow::Result<surreal_sync_mongodb_changestream_source::RecomputeOpts> {
+    use surreal_sync_mongodb_changestream_source::RecomputeView;
+
+    let mut views: Vec<RecomputeView> = args
+        .tables
+        .iter()
+        .map(|table| RecomputeView {
+            table: table.clone(),
+            collection: table.clone(),
+            pipeline: Vec::new(),
+        })
+        .collect();
+    for spec in &args.aggregations {
+        let (table, path) = spec
+            .split_once('=')
+            .with_context(|| format!("--aggregation expects table=path, got '{spec}'"))?;
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read aggregation file {path}"))?;
+        let file: AggregationFile = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid aggregation file {path}"))?;
+        let pipeline = file
+            .pipeline
+            .into_iter()
+            .map(mongodb::bson::Document::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Invalid aggregation stage in {path}"))?;
+        views.retain(|v| v.table != table);
+        views.push(RecomputeView {
+            table: table.to_string(),
+            collection: file.collection.unwrap_or_else(|| table.to_string()),
+            pipeline,
+        });
+    }
+    if views.is_empty() {
+        anyhow::bail!("Recompute needs --tables or at least one --aggregation");
+    }
+
+    let interval = super::parse_duration_to_secs(&args.interval)?;
+    Ok(surreal_sync_mongodb_changestream_source::RecomputeOpts {
+        source_uri: args.connection_string.clone(),
+        source_database: args.database.clone(),
+        views,
+        batch_size: args.surreal.batch_size,
+        interval: std::time::Duration::from_secs(interval.max(0) as u64),
+        once: args.once,
+        bson_converters: Default::default(),
+    })
+}
+
+/// `--validate-checkpoint`: check the resume token against the oplog window.
+async fn validate_mongodb_checkpoint(
+    args: &MongoDBIncrementalArgs,
+    checkpoint: &surreal_sync_mongodb_changestream_source::MongoDBCheckpoint,
+) -> anyhow::Result<()> {
+    let client = mongodb::Client::with_uri_str(&args.connection_string).await?;
+    let validity = surreal_sync_mongodb_changestream_source::validate_checkpoint(
+        &client,
+        &args.database,
+        checkpoint,
+    )
+    .await?;
+    report_checkpoint_validity("MongoDB", validity)
+}
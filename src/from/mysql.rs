@@ -13,14 +13,37 @@ use surreal_sync_mysql::from_trigger::{MySQLCheckpoint, ReplicationTailOptions};
 use surreal_sync_runtime::{ApplyOpts, Pipeline};
 use surreal_sync_runtime::{InterleavedSnapshotConfig, NoopCheckpointer, SnapshotTransforms};
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
-    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, SdkVersion,
+    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink,
+    report_migration_estimate, SdkVersion,
 };
 use crate::{MySQLFullArgs, MySQLIncrementalArgs, MySQLSnapshotArgs, MySQLSyncArgs, SyncStrategy};
 
 /// Run MySQL full sync, dispatching by strategy then SDK version.
 pub async fn run_full(args: MySQLFullArgs) -> anyhow::Result<()> {
+    if args.count_only {
+        let pool = surreal_sync_mysql::from_trigger::new_mysql_pool_with_ssl(
+            &args.connection_string,
+            &args.tls.ssl_mode(),
+        )
+        .await?;
+        let mut conn = pool.get_conn().await?;
+        if let Some(database) = &args.database {
+            mysql_async::prelude::Queryable::query_drop(
+                &mut conn,
+                format!("USE `{}`", database.replace('`', "``")),
+            )
+            .await?;
+        }
+        let estimate =
+            surreal_sync_mysql::estimate_tables(&mut conn, &args.tables, args.approximate_counts)
+                .await?;
+        drop(conn);
+        pool.disconnect().await?;
+        return report_migration_estimate(&estimate);
+    }
+
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -35,7 +58,9 @@ pub async fn run_full(args: MySQLFullArgs) -> anyhow::Result<()> {
         }
         (SyncStrategy::InterleavedSnapshot, SdkVersion::V3) => {
             run_full_interleaved_snapshot_v3(args).await
-        }
+        }        (SyncStrategy::ConsistentSnapshot, _) => anyhow::bail!(
+            "--strategy consistent-snapshot is not supported by the MySQL trigger source; use `from mysql-binlog`"
+        ),
     }
 }
 
@@ -54,6 +79,7 @@ async fn run_full_v2(args: MySQLFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -61,14 +87,23 @@ async fn run_full_v2(args: MySQLFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
+    let id_column_overrides = surreal_sync_core::parse_id_column_overrides(&args.id_columns, None)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let keyset_columns = surreal_sync_core::parse_keyset_columns(&args.keyset_columns)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let column_filters = args.columns.to_filters()?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &column_filters)?;
     let source_opts = surreal_sync_mysql::from_trigger::SourceOpts {
         source_uri: args.connection_string,
         source_database: args.database,
         tables: args.tables,
         mysql_boolean_paths: args.boolean_paths,
-        id_column_overrides: Default::default(),
+        id_column_overrides,
+        keyset_columns,
+        column_filters,
         ssl: args.tls.ssl_mode(),
     };
 
@@ -76,7 +111,6 @@ async fn run_full_v2(args: MySQLFullArgs) -> anyhow::Result<()> {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
     };
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
 
     // Handle checkpoint storage
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
@@ -155,6 +189,7 @@ async fn run_full_v3(args: MySQLFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -162,14 +197,23 @@ async fn run_full_v3(args: MySQLFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
 
+    let id_column_overrides = surreal_sync_core::parse_id_column_overrides(&args.id_columns, None)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let keyset_columns = surreal_sync_core::parse_keyset_columns(&args.keyset_columns)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let column_filters = args.columns.to_filters()?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &column_filters)?;
     let source_opts = surreal_sync_mysql::from_trigger::SourceOpts {
         source_uri: args.connection_string,
         source_database: args.database,
         tables: args.tables,
         mysql_boolean_paths: args.boolean_paths,
-        id_column_overrides: Default::default(),
+        id_column_overrides,
+        keyset_columns,
+        column_filters,
         ssl: args.tls.ssl_mode(),
     };
 
@@ -177,7 +221,6 @@ async fn run_full_v3(args: MySQLFullArgs) -> anyhow::Result<()> {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
     };
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
 
     // Handle checkpoint storage
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
@@ -270,6 +313,7 @@ async fn run_incremental_v2(args: MySQLIncrementalArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -318,12 +362,16 @@ async fn run_incremental_v2(args: MySQLIncrementalArgs) -> anyhow::Result<()> {
         .with_context(|| format!("Invalid timeout format: {}", args.timeout))?;
     let deadline = chrono::Utc::now() + chrono::Duration::seconds(timeout_seconds);
 
+    let id_column_overrides = surreal_sync_core::parse_id_column_overrides(&args.id_columns, None)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
     let source_opts = surreal_sync_mysql::from_trigger::SourceOpts {
         source_uri: args.connection_string,
         source_database: args.database,
         tables: args.tables,
         mysql_boolean_paths: args.boolean_paths,
-        id_column_overrides: Default::default(),
+        id_column_overrides,
+        keyset_columns: Default::default(),
+        column_filters: args.columns.to_filters()?,
         ssl: args.tls.ssl_mode(),
     };
 
@@ -334,14 +382,17 @@ async fn run_incremental_v2(args: MySQLIncrementalArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     surreal_sync_mysql::from_trigger::run_incremental_sync_with_transforms(
         &sink,
         source_opts,
         from_checkpoint,
-        ReplicationTailOptions::stream(deadline, mysql_to),
+        ReplicationTailOptions::stream(deadline, mysql_to).with_auto_cleanup(args.auto_cleanup),
         &pipeline,
         &apply_opts,
     )
@@ -375,6 +426,7 @@ async fn resolve_mysql_database(
 /// Run a MySQL interleaved snapshot full sync, emitting the handoff
 /// position as a checkpoint (when checkpoint storage is configured) so a later
 /// `incremental` run can resume from the consistent end position.
+#[allow(clippy::too_many_arguments)]
 async fn mysql_snapshot_full<S, St>(
     sink: &S,
     connection_string: String,
@@ -383,6 +435,7 @@ async fn mysql_snapshot_full<S, St>(
     ssl: surreal_sync_mysql::from_trigger::SslMode,
     manager: Option<&SyncManager<St>>,
     transforms: &SnapshotTransforms,
+    id_column_overrides: surreal_sync_core::IdColumnOverrides,
 ) -> anyhow::Result<()>
 where
     S: SurrealSink,
@@ -393,16 +446,16 @@ where
     let database = resolve_mysql_database(&pool, &database).await?;
     let config = InterleavedSnapshotConfig { chunk_size };
     let mut checkpointer = NoopCheckpointer;
-    let final_seq =
-        surreal_sync_mysql::from_trigger::run_interleaved_snapshot_full_sync_with_transforms(
-            pool,
-            database,
-            sink,
-            &config,
-            &mut checkpointer,
-            transforms,
-        )
-        .await?;
+    let final_seq = surreal_sync_mysql::from_trigger::run_interleaved_snapshot_full_sync_with_transforms_and_overrides(
+        pool,
+        database,
+        sink,
+        &config,
+        &mut checkpointer,
+        transforms,
+        id_column_overrides,
+    )
+    .await?;
 
     if let Some(manager) = manager {
         let checkpoint = MySQLCheckpoint {
@@ -428,6 +481,7 @@ async fn run_full_interleaved_snapshot_v2(args: MySQLFullArgs) -> anyhow::Result
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -435,13 +489,18 @@ async fn run_full_interleaved_snapshot_v2(args: MySQLFullArgs) -> anyhow::Result
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let transforms = SnapshotTransforms {
         pipeline,
         apply_opts,
     };
+    let id_column_overrides = surreal_sync_core::parse_id_column_overrides(&args.id_columns, None)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
         (Some(dir), None) => {
@@ -456,6 +515,7 @@ async fn run_full_interleaved_snapshot_v2(args: MySQLFullArgs) -> anyhow::Result
                 args.tls.ssl_mode(),
                 Some(&manager),
                 &transforms,
+                id_column_overrides,
             )
             .await
         }
@@ -478,6 +538,7 @@ async fn run_full_interleaved_snapshot_v2(args: MySQLFullArgs) -> anyhow::Result
                 args.tls.ssl_mode(),
                 Some(&manager),
                 &transforms,
+                id_column_overrides,
             )
             .await
         }
@@ -490,6 +551,7 @@ async fn run_full_interleaved_snapshot_v2(args: MySQLFullArgs) -> anyhow::Result
                 args.tls.ssl_mode(),
                 None,
                 &transforms,
+                id_column_overrides,
             )
             .await
         }
@@ -507,6 +569,7 @@ async fn run_full_interleaved_snapshot_v3(args: MySQLFullArgs) -> anyhow::Result
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -514,13 +577,18 @@ async fn run_full_interleaved_snapshot_v3(args: MySQLFullArgs) -> anyhow::Result
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let transforms = SnapshotTransforms {
         pipeline,
         apply_opts,
     };
+    let id_column_overrides = surreal_sync_core::parse_id_column_overrides(&args.id_columns, None)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
         (Some(dir), None) => {
@@ -535,6 +603,7 @@ async fn run_full_interleaved_snapshot_v3(args: MySQLFullArgs) -> anyhow::Result
                 args.tls.ssl_mode(),
                 Some(&manager),
                 &transforms,
+                id_column_overrides,
             )
             .await
         }
@@ -551,6 +620,7 @@ async fn run_full_interleaved_snapshot_v3(args: MySQLFullArgs) -> anyhow::Result
                 args.tls.ssl_mode(),
                 Some(&manager),
                 &transforms,
+                id_column_overrides,
             )
             .await
         }
@@ -563,6 +633,7 @@ async fn run_full_interleaved_snapshot_v3(args: MySQLFullArgs) -> anyhow::Result
                 args.tls.ssl_mode(),
                 None,
                 &transforms,
+                id_column_overrides,
             )
             .await
         }
@@ -576,7 +647,10 @@ async fn run_full_interleaved_snapshot_v3(args: MySQLFullArgs) -> anyhow::Result
 /// full sync followed by incremental from the handed-off position, in one
 /// process.
 pub async fn run_sync(args: MySQLSyncArgs) -> anyhow::Result<()> {
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -600,6 +674,7 @@ async fn run_sync_v2(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -607,7 +682,7 @@ async fn run_sync_v2(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     mysql_orchestrate(&sink, args, pipeline, apply_opts).await
 }
 
@@ -623,6 +698,7 @@ async fn run_sync_v3(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -630,7 +706,7 @@ async fn run_sync_v3(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
     mysql_orchestrate(&sink, args, pipeline, apply_opts).await
 }
 
@@ -664,6 +740,8 @@ async fn mysql_orchestrate<S: SurrealSink>(
         tables: args.tables.clone(),
         mysql_boolean_paths: args.boolean_paths.clone(),
         id_column_overrides,
+        keyset_columns: Default::default(),
+        column_filters: args.columns.to_filters()?,
         ssl: args.tls.ssl_mode(),
     };
 
@@ -738,6 +816,7 @@ async fn run_incremental_v3(args: MySQLIncrementalArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -786,12 +865,16 @@ async fn run_incremental_v3(args: MySQLIncrementalArgs) -> anyhow::Result<()> {
         .with_context(|| format!("Invalid timeout format: {}", args.timeout))?;
     let deadline = chrono::Utc::now() + chrono::Duration::seconds(timeout_seconds);
 
+    let id_column_overrides = surreal_sync_core::parse_id_column_overrides(&args.id_columns, None)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
     let source_opts = surreal_sync_mysql::from_trigger::SourceOpts {
         source_uri: args.connection_string,
         source_database: args.database,
         tables: args.tables,
         mysql_boolean_paths: args.boolean_paths,
-        id_column_overrides: Default::default(),
+        id_column_overrides,
+        keyset_columns: Default::default(),
+        column_filters: args.columns.to_filters()?,
         ssl: args.tls.ssl_mode(),
     };
 
@@ -802,14 +885,17 @@ async fn run_incremental_v3(args: MySQLIncrementalArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     surreal_sync_mysql::from_trigger::run_incremental_sync_with_transforms(
         &sink,
         source_opts,
         from_checkpoint,
-        ReplicationTailOptions::stream(deadline, mysql_to),
+        ReplicationTailOptions::stream(deadline, mysql_to).with_auto_cleanup(args.auto_cleanup),
         &pipeline,
         &apply_opts,
     )
@@ -8,10 +8,10 @@
 use anyhow::Context;
 use surreal_sync_core::Checkpoint;
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
     extract_json_fields_from_schema, get_sdk_version, load_schema_if_provided, make_surreal2_sink,
-    make_surreal3_sink, SdkVersion,
+    make_surreal3_sink, report_migration_estimate, SdkVersion,
 };
 use crate::{Neo4jFullArgs, Neo4jIncrementalArgs};
 
@@ -29,8 +29,47 @@ fn parse_composite_database(database: &Option<String>) -> (Option<String>, Optio
     }
 }
 
+/// Parse `--relation-table TYPE=table` entries into a relationship type to
+/// edge table map.
+fn parse_relation_table_map(
+    entries: &[String],
+) -> anyhow::Result<std::collections::HashMap<String, String>> {
+    entries
+        .iter()
+        .map(|entry| match entry.split_once('=') {
+            Some((rel_type, table)) if !rel_type.is_empty() && !table.is_empty() => {
+                Ok((rel_type.to_string(), table.to_string()))
+            }
+            _ => anyhow::bail!("Invalid --relation-table '{entry}': expected TYPE=table"),
+        })
+        .collect()
+}
+
 /// Run Neo4j full sync, dispatching to appropriate SDK version.
 pub async fn run_full(args: Neo4jFullArgs) -> anyhow::Result<()> {
+    if args.count_only {
+        let (session_db, composite_constituent) = parse_composite_database(&args.database);
+        let source_opts = surreal_sync_neo4j_source::SourceOpts {
+            source_uri: args.connection_string,
+            source_database: session_db,
+            source_username: args.username,
+            source_password: args.password,
+            labels: args.tables,
+            include_labels: None,
+            exclude_labels: args.exclude_labels,
+            neo4j_timezone: args.timezone,
+            neo4j_json_properties: args.json_properties,
+            change_tracking_property: args.change_tracking_property,
+            assumed_start_timestamp: None,
+            allow_empty_tracking_timestamp: args.allow_empty_tracking_timestamp,
+            id_property: args.id_property,
+            composite_constituent,
+            relation_table_map: Default::default(),
+        };
+        let estimate = surreal_sync_neo4j_source::estimate_graph(&source_opts).await?;
+        return report_migration_estimate(&estimate);
+    }
+
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -51,7 +90,10 @@ async fn run_full_v2(args: Neo4jFullArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     let schema = load_schema_if_provided(&args.schema_file)?;
 
@@ -78,6 +120,7 @@ async fn run_full_v2(args: Neo4jFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -85,7 +128,7 @@ async fn run_full_v2(args: Neo4jFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     // Parse assumed_start_timestamp if provided
     let assumed_start_timestamp = if let Some(ts_str) = &args.assumed_start_timestamp {
@@ -99,12 +142,15 @@ async fn run_full_v2(args: Neo4jFullArgs) -> anyhow::Result<()> {
     };
 
     let (session_db, composite_constituent) = parse_composite_database(&args.database);
+    let relation_table_map = parse_relation_table_map(&args.relation_table)?;
     let source_opts = surreal_sync_neo4j_source::SourceOpts {
         source_uri: args.connection_string,
         source_database: session_db,
         source_username: args.username,
         source_password: args.password,
         labels: args.tables,
+        include_labels: None,
+        exclude_labels: args.exclude_labels,
         neo4j_timezone: args.timezone,
         neo4j_json_properties: json_properties,
         change_tracking_property: args.change_tracking_property,
@@ -112,11 +158,13 @@ async fn run_full_v2(args: Neo4jFullArgs) -> anyhow::Result<()> {
         allow_empty_tracking_timestamp: args.allow_empty_tracking_timestamp,
         id_property: args.id_property.clone(),
         composite_constituent,
+        relation_table_map,
     };
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
+        concurrency: args.concurrency,
     };
 
     // Handle checkpoint storage
@@ -178,7 +226,10 @@ async fn run_full_v3(args: Neo4jFullArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     let schema = load_schema_if_provided(&args.schema_file)?;
 
@@ -205,6 +256,7 @@ async fn run_full_v3(args: Neo4jFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -212,7 +264,7 @@ async fn run_full_v3(args: Neo4jFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
 
     // Parse assumed_start_timestamp if provided
     let assumed_start_timestamp = if let Some(ts_str) = &args.assumed_start_timestamp {
@@ -226,12 +278,15 @@ async fn run_full_v3(args: Neo4jFullArgs) -> anyhow::Result<()> {
     };
 
     let (session_db, composite_constituent) = parse_composite_database(&args.database);
+    let relation_table_map = parse_relation_table_map(&args.relation_table)?;
     let source_opts = surreal_sync_neo4j_source::SourceOpts {
         source_uri: args.connection_string,
         source_database: session_db,
         source_username: args.username,
         source_password: args.password,
         labels: args.tables,
+        include_labels: None,
+        exclude_labels: args.exclude_labels,
         neo4j_timezone: args.timezone,
         neo4j_json_properties: json_properties,
         change_tracking_property: args.change_tracking_property,
@@ -239,11 +294,13 @@ async fn run_full_v3(args: Neo4jFullArgs) -> anyhow::Result<()> {
         allow_empty_tracking_timestamp: args.allow_empty_tracking_timestamp,
         id_property: args.id_property.clone(),
         composite_constituent,
+        relation_table_map,
     };
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
+        concurrency: args.concurrency,
     };
 
     // Handle checkpoint storage
@@ -319,7 +376,10 @@ async fn run_incremental_v2(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     let schema = load_schema_if_provided(&args.schema_file)?;
 
@@ -346,6 +406,7 @@ async fn run_incremental_v2(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -408,12 +469,15 @@ async fn run_incremental_v2(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
     };
 
     let (session_db, composite_constituent) = parse_composite_database(&args.database);
+    let relation_table_map = parse_relation_table_map(&args.relation_table)?;
     let source_opts = surreal_sync_neo4j_source::SourceOpts {
         source_uri: args.connection_string,
         source_database: session_db,
         source_username: args.username,
         source_password: args.password,
         labels: args.tables,
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: args.timezone,
         neo4j_json_properties: json_properties,
         change_tracking_property: args.change_tracking_property,
@@ -421,6 +485,7 @@ async fn run_incremental_v2(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         allow_empty_tracking_timestamp: args.allow_empty_tracking_timestamp,
         id_property: args.id_property.clone(),
         composite_constituent,
+        relation_table_map,
     };
 
     let surreal = surreal_sync_surreal::v2::surreal_connect(
@@ -429,11 +494,12 @@ async fn run_incremental_v2(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
+        concurrency: 1,
     };
     surreal_sync_neo4j_source::run_incremental_sync_with_transforms(
         &sink,
@@ -463,7 +529,10 @@ async fn run_incremental_v3(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         tracing::info!("Running in dry-run mode - no data will be written");
     }
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
 
     let schema = load_schema_if_provided(&args.schema_file)?;
 
@@ -489,6 +558,7 @@ async fn run_incremental_v3(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -551,12 +621,15 @@ async fn run_incremental_v3(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
     };
 
     let (session_db, composite_constituent) = parse_composite_database(&args.database);
+    let relation_table_map = parse_relation_table_map(&args.relation_table)?;
     let source_opts = surreal_sync_neo4j_source::SourceOpts {
         source_uri: args.connection_string,
         source_database: session_db,
         source_username: args.username,
         source_password: args.password,
         labels: args.tables,
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: args.timezone,
         neo4j_json_properties: json_properties,
         change_tracking_property: args.change_tracking_property,
@@ -564,6 +637,7 @@ async fn run_incremental_v3(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         allow_empty_tracking_timestamp: args.allow_empty_tracking_timestamp,
         id_property: args.id_property.clone(),
         composite_constituent,
+        relation_table_map,
     };
 
     let surreal = surreal_sync_surreal::v3::surreal_connect(
@@ -572,11 +646,12 @@ async fn run_incremental_v3(args: Neo4jIncrementalArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
+        concurrency: 1,
     };
     surreal_sync_neo4j_source::run_incremental_sync_with_transforms(
         &sink,
@@ -622,4 +697,13 @@ mod tests {
         assert_eq!(db, Some("composite".to_string()));
         assert_eq!(constituent, Some("composite.db1".to_string()));
     }
+
+    #[test]
+    fn test_parse_relation_table_map() {
+        let map = parse_relation_table_map(&["ACTED_IN=appeared_in".to_string()]).unwrap();
+        assert_eq!(map.get("ACTED_IN").map(String::as_str), Some("appeared_in"));
+        assert!(parse_relation_table_map(&[]).unwrap().is_empty());
+        assert!(parse_relation_table_map(&["ACTED_IN".to_string()]).is_err());
+        assert!(parse_relation_table_map(&["=edge".to_string()]).is_err());
+    }
 }
@@ -9,7 +9,8 @@ use anyhow::Context;
 use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{Checkpoint, CheckpointStore, SyncManager};
 use surreal_sync_postgresql::from_pgoutput::{
-    request_snapshot, run_full_sync_cancellable_with_transforms,
+    request_snapshot, run_consistent_snapshot_full_sync_with_transforms,
+    run_full_sync_cancellable_with_transforms, run_initial_consistent_snapshot_with_transforms,
     run_initial_interleaved_snapshot_with_transforms,
     run_interleaved_snapshot_full_sync_with_transforms, run_replication_tail_with_transforms,
     InterleavedFullSyncOptions, PgoutputCheckpoint, ReplicationTailOptions, SourceOpts, SyncOpts,
@@ -18,7 +19,7 @@ use surreal_sync_runtime::SnapshotTransforms;
 use surreal_sync_runtime::{ApplyOpts, Pipeline};
 use tokio_util::sync::CancellationToken;
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
     get_sdk_version, make_surreal2_sink, make_surreal3_sink, parse_duration_to_secs, SdkVersion,
 };
@@ -222,13 +223,29 @@ where
             tracing::info!("Sequential PostgreSQL WAL full sync completed");
             Ok(None)
         }
+        SyncStrategy::ConsistentSnapshot => {
+            run_consistent_snapshot_full_sync_with_transforms(
+                sink,
+                source_opts,
+                sync_opts,
+                manager,
+                &cancel,
+                &transforms.pipeline,
+                &transforms.apply_opts,
+            )
+            .await?;
+            Ok(None)
+        }
     }
 }
 
 /// Run `from postgresql-pgoutput sync`.
 pub async fn run_sync(args: PostgreSQLPgoutputSyncArgs) -> anyhow::Result<()> {
     // Fail-fast on bad transforms config / worker spawn before connecting.
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -252,6 +269,7 @@ async fn run_sync_v2(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -259,7 +277,7 @@ async fn run_sync_v2(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     let checkpoint_dir = args.checkpoint_dir.clone();
     let checkpoints_surreal_table = args.checkpoints_surreal_table.clone();
     match (checkpoint_dir, checkpoints_surreal_table) {
@@ -305,6 +323,7 @@ async fn run_sync_v3(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -312,7 +331,7 @@ async fn run_sync_v3(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
     let checkpoint_dir = args.checkpoint_dir.clone();
     let checkpoints_surreal_table = args.checkpoints_surreal_table.clone();
     match (checkpoint_dir, checkpoints_surreal_table) {
@@ -423,6 +442,34 @@ where
                     .await?;
                     None
                 }
+                SyncStrategy::ConsistentSnapshot => {
+                    let Some(stream_start) = run_initial_consistent_snapshot_with_transforms(
+                        sink,
+                        &source_opts,
+                        &sync_opts,
+                        checkpoint_manager,
+                        &cancel,
+                        &transforms.pipeline,
+                        &transforms.apply_opts,
+                    )
+                    .await?
+                    else {
+                        tracing::info!(
+                            "Sync cancelled during consistent snapshot; the next run re-takes it"
+                        );
+                        return Ok(());
+                    };
+                    return run_replication_tail_with_transforms(
+                        sink,
+                        source_opts,
+                        stream_start,
+                        stream_options,
+                        checkpoint_manager,
+                        &transforms.pipeline,
+                        &transforms.apply_opts,
+                    )
+                    .await;
+                }
             };
 
             if let Some(outcome) = interleaved_outcome {
@@ -21,10 +21,10 @@ use surreal_sync_runtime::SurrealCliOpts as SurrealOpts;
 use surreal_sync_runtime::{ApplyOpts, Pipeline};
 use surreal_sync_runtime::{InterleavedSnapshotConfig, NoopCheckpointer, SnapshotTransforms};
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
     extract_postgresql_database, get_sdk_version, load_schema_if_provided, make_surreal2_sink,
-    make_surreal3_sink, SdkVersion,
+    make_surreal3_sink, report_migration_estimate, SdkVersion,
 };
 use crate::config::load_config;
 use crate::{
@@ -48,12 +48,18 @@ struct ResolvedTriggerFullArgs {
     strategy: SyncStrategy,
     chunk_size: usize,
     transforms_config: Option<PathBuf>,
+    column_filters: surreal_sync_core::ColumnFilters,
+    keyset_columns: surreal_sync_core::KeysetColumns,
+    parallel_reads: surreal_sync_core::ParallelReads,
+    count_only: bool,
+    approximate_counts: bool,
     surreal: SurrealOpts,
 }
 
 struct ResolvedTriggerIncrementalArgs {
     connection_string: String,
     tables: Vec<String>,
+    table_targets: std::collections::HashMap<String, String>,
     to_namespace: String,
     to_database: String,
     incremental_from: Option<String>,
@@ -62,10 +68,21 @@ struct ResolvedTriggerIncrementalArgs {
     timeout: String,
     schema_file: Option<PathBuf>,
     transforms_config: Option<PathBuf>,
+    column_filters: surreal_sync_core::ColumnFilters,
     surreal: SurrealOpts,
 }
 
 fn resolve_full_args(args: PostgreSQLTriggerFullArgs) -> anyhow::Result<ResolvedTriggerFullArgs> {
+    let column_filters = args.columns.to_filters()?;
+    let keyset_columns = surreal_sync_core::parse_keyset_columns(&args.keyset_columns)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let parallel_reads = surreal_sync_core::parse_parallel_reads(&args.parallel_reads)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    if !parallel_reads.is_empty() && args.strategy == SyncStrategy::InterleavedSnapshot {
+        tracing::warn!(
+            "--parallel-reads only applies to --strategy sequential-snapshot; tables are read serially"
+        );
+    }
     if let Some(ref config_path) = args.config_file {
         let cfg = load_config::<TriggerFullSource>(config_path)?;
         let pg = cfg.source.postgresql;
@@ -87,6 +104,11 @@ fn resolve_full_args(args: PostgreSQLTriggerFullArgs) -> anyhow::Result<Resolved
             strategy: args.strategy,
             chunk_size: args.chunk_size,
             transforms_config: args.transforms_config,
+            column_filters,
+            keyset_columns: keyset_columns.clone(),
+            parallel_reads: parallel_reads.clone(),
+            count_only: args.count_only,
+            approximate_counts: args.approximate_counts,
             surreal: SurrealOpts {
                 surreal_endpoint: sink.endpoint,
                 surreal_username: sink.username,
@@ -95,6 +117,27 @@ fn resolve_full_args(args: PostgreSQLTriggerFullArgs) -> anyhow::Result<Resolved
                 dry_run: sink.dry_run,
                 surreal_sdk_version: args.surreal.surreal_sdk_version.or(sink.sdk_version),
                 zero_temporal: sink.zero_temporal,
+                write_concurrency: sink.write_concurrency,
+                table_write_concurrency: args.surreal.table_write_concurrency.clone(),
+                ordered_tables: args.surreal.ordered_tables.clone(),
+                connection_pool_size: args.surreal.connection_pool_size,
+                write_max_attempts: args.surreal.write_max_attempts,
+                write_retry_backoff_ms: args.surreal.write_retry_backoff_ms,
+                write_failure_threshold: args.surreal.write_failure_threshold,
+                write_circuit_cooldown_secs: args.surreal.write_circuit_cooldown_secs,
+                write_circuit_deadline_secs: args.surreal.write_circuit_deadline_secs,
+                version_field: args.surreal.version_field.clone(),
+                soft_delete_field: args.surreal.soft_delete_field.clone(),
+                soft_delete_flag_field: args.surreal.soft_delete_flag_field.clone(),
+                merge_updates: args.surreal.merge_updates,
+                merge_arrays: args.surreal.merge_arrays.clone(),
+                merge_array_fields: args.surreal.merge_array_fields.clone(),
+                skip_unchanged_fields: args.surreal.skip_unchanged_fields,
+                error_table: args.surreal.error_table.clone(),
+                conflict_policy: args.surreal.conflict_policy,
+                large_field_threshold: args.surreal.large_field_threshold,
+                large_field_dir: args.surreal.large_field_dir.clone(),
+                write_templates: args.surreal.write_templates.clone(),
             },
         })
     } else {
@@ -115,14 +158,36 @@ fn resolve_full_args(args: PostgreSQLTriggerFullArgs) -> anyhow::Result<Resolved
             strategy: args.strategy,
             chunk_size: args.chunk_size,
             transforms_config: args.transforms_config,
+            column_filters,
+            keyset_columns,
+            parallel_reads,
+            count_only: args.count_only,
+            approximate_counts: args.approximate_counts,
             surreal: args.surreal,
         })
     }
 }
 
+/// Parse `--table-target TABLE=TARGET` entries.
+fn parse_table_targets(
+    entries: &[String],
+) -> anyhow::Result<std::collections::HashMap<String, String>> {
+    entries
+        .iter()
+        .map(|entry| match entry.split_once('=') {
+            Some((table, target)) if !table.is_empty() && !target.is_empty() => {
+                Ok((table.to_string(), target.to_string()))
+            }
+            _ => anyhow::bail!("Invalid --table-target '{entry}': expected TABLE=target"),
+        })
+        .collect()
+}
+
 fn resolve_incremental_args(
     args: PostgreSQLTriggerIncrementalArgs,
 ) -> anyhow::Result<ResolvedTriggerIncrementalArgs> {
+    let column_filters = args.columns.to_filters()?;
+    let table_targets = parse_table_targets(&args.table_target)?;
     if let Some(ref config_path) = args.config_file {
         let cfg = load_config::<TriggerIncrementalSource>(config_path)?;
         let pg = cfg.source.postgresql;
@@ -134,6 +199,7 @@ fn resolve_incremental_args(
             } else {
                 args.tables
             },
+            table_targets,
             to_namespace: args.to_namespace.unwrap_or(sink.namespace),
             to_database: args.to_database.unwrap_or(sink.database),
             incremental_from: args.incremental_from.or(pg.incremental_from),
@@ -148,6 +214,7 @@ fn resolve_incremental_args(
             },
             schema_file: args.schema_file.or(pg.schema_file),
             transforms_config: args.transforms_config,
+            column_filters,
             surreal: SurrealOpts {
                 surreal_endpoint: sink.endpoint,
                 surreal_username: sink.username,
@@ -156,6 +223,27 @@ fn resolve_incremental_args(
                 dry_run: sink.dry_run,
                 surreal_sdk_version: args.surreal.surreal_sdk_version.or(sink.sdk_version),
                 zero_temporal: sink.zero_temporal,
+                write_concurrency: sink.write_concurrency,
+                table_write_concurrency: args.surreal.table_write_concurrency.clone(),
+                ordered_tables: args.surreal.ordered_tables.clone(),
+                connection_pool_size: args.surreal.connection_pool_size,
+                write_max_attempts: args.surreal.write_max_attempts,
+                write_retry_backoff_ms: args.surreal.write_retry_backoff_ms,
+                write_failure_threshold: args.surreal.write_failure_threshold,
+                write_circuit_cooldown_secs: args.surreal.write_circuit_cooldown_secs,
+                write_circuit_deadline_secs: args.surreal.write_circuit_deadline_secs,
+                version_field: args.surreal.version_field.clone(),
+                soft_delete_field: args.surreal.soft_delete_field.clone(),
+                soft_delete_flag_field: args.surreal.soft_delete_flag_field.clone(),
+                merge_updates: args.surreal.merge_updates,
+                merge_arrays: args.surreal.merge_arrays.clone(),
+                merge_array_fields: args.surreal.merge_array_fields.clone(),
+                skip_unchanged_fields: args.surreal.skip_unchanged_fields,
+                error_table: args.surreal.error_table.clone(),
+                conflict_policy: args.surreal.conflict_policy,
+                large_field_threshold: args.surreal.large_field_threshold,
+                large_field_dir: args.surreal.large_field_dir.clone(),
+                write_templates: args.surreal.write_templates.clone(),
             },
         })
     } else {
@@ -164,6 +252,7 @@ fn resolve_incremental_args(
                 .connection_string
                 .ok_or_else(|| anyhow::anyhow!("--connection-string is required"))?,
             tables: args.tables,
+            table_targets,
             to_namespace: args
                 .to_namespace
                 .ok_or_else(|| anyhow::anyhow!("--to-namespace is required"))?,
@@ -176,6 +265,7 @@ fn resolve_incremental_args(
             timeout: args.timeout,
             schema_file: args.schema_file,
             transforms_config: args.transforms_config,
+            column_filters,
             surreal: args.surreal,
         })
     }
@@ -186,6 +276,26 @@ fn resolve_incremental_args(
 /// Run PostgreSQL trigger-based full sync, dispatching by strategy then SDK version.
 pub async fn run_full(args: PostgreSQLTriggerFullArgs) -> anyhow::Result<()> {
     let args = resolve_full_args(args)?;
+    if args.count_only {
+        let (client, connection) =
+            tokio_postgres::connect(&args.connection_string, tokio_postgres::NoTls).await?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("PostgreSQL connection error: {e}");
+            }
+        });
+        // Trigger-based sync reads tables unqualified, so count them in the
+        // schema the connection's search_path resolves them to.
+        let schema = surreal_sync_postgresql::current_schema(&client).await?;
+        let estimate = surreal_sync_postgresql::estimate_tables(
+            &client,
+            &schema,
+            &args.tables,
+            args.approximate_counts,
+        )
+        .await?;
+        return report_migration_estimate(&estimate);
+    }
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -200,7 +310,9 @@ pub async fn run_full(args: PostgreSQLTriggerFullArgs) -> anyhow::Result<()> {
         }
         (SyncStrategy::InterleavedSnapshot, SdkVersion::V3) => {
             run_full_interleaved_snapshot_v3(args).await
-        }
+        }        (SyncStrategy::ConsistentSnapshot, _) => anyhow::bail!(
+            "--strategy consistent-snapshot is not supported by the PostgreSQL trigger source; use `from postgresql-pgoutput`"
+        ),
     }
 }
 
@@ -218,6 +330,7 @@ async fn run_full_v2(args: ResolvedTriggerFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -225,21 +338,26 @@ async fn run_full_v2(args: ResolvedTriggerFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     let source_database = extract_postgresql_database(&args.connection_string);
     let source_opts = surreal_sync_postgresql::from_trigger::SourceOpts {
         source_uri: args.connection_string,
         source_database,
         tables: args.tables,
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: args.keyset_columns,
+        parallel_reads: args.parallel_reads,
+        column_filters: args.column_filters.clone(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
     };
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
         (Some(dir), None) => {
@@ -305,6 +423,7 @@ async fn run_full_v3(args: ResolvedTriggerFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -312,21 +431,26 @@ async fn run_full_v3(args: ResolvedTriggerFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
 
     let source_database = extract_postgresql_database(&args.connection_string);
     let source_opts = surreal_sync_postgresql::from_trigger::SourceOpts {
         source_uri: args.connection_string,
         source_database,
         tables: args.tables,
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: args.keyset_columns,
+        parallel_reads: args.parallel_reads,
+        column_filters: args.column_filters.clone(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
     };
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
         (Some(dir), None) => {
@@ -391,7 +515,11 @@ fn trigger_source_opts(
         source_uri: connection_string.to_string(),
         source_database,
         tables,
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     }
 }
 
@@ -447,6 +575,7 @@ async fn run_full_interleaved_snapshot_v2(args: ResolvedTriggerFullArgs) -> anyh
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -454,10 +583,11 @@ async fn run_full_interleaved_snapshot_v2(args: ResolvedTriggerFullArgs) -> anyh
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     let source_opts = trigger_source_opts(&args.connection_string, args.tables.clone());
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     let transforms = SnapshotTransforms {
         pipeline,
         apply_opts,
@@ -523,6 +653,7 @@ async fn run_full_interleaved_snapshot_v3(args: ResolvedTriggerFullArgs) -> anyh
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -530,10 +661,11 @@ async fn run_full_interleaved_snapshot_v3(args: ResolvedTriggerFullArgs) -> anyh
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
     let source_opts = trigger_source_opts(&args.connection_string, args.tables.clone());
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     let transforms = SnapshotTransforms {
         pipeline,
         apply_opts,
@@ -585,7 +717,10 @@ async fn run_full_interleaved_snapshot_v3(args: ResolvedTriggerFullArgs) -> anyh
 
 /// Run the combined `from postgresql-trigger sync` orchestrator.
 pub async fn run_sync(args: PostgreSQLTriggerSyncArgs) -> anyhow::Result<()> {
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -610,6 +745,7 @@ async fn run_sync_v2(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -617,7 +753,7 @@ async fn run_sync_v2(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     pg_trigger_orchestrate(&sink, args, pipeline, apply_opts).await
 }
 
@@ -634,6 +770,7 @@ async fn run_sync_v3(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -641,7 +778,7 @@ async fn run_sync_v3(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
     pg_trigger_orchestrate(&sink, args, pipeline, apply_opts).await
 }
 
@@ -740,6 +877,7 @@ async fn run_incremental_v2(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -791,7 +929,11 @@ async fn run_incremental_v2(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
         source_uri: args.connection_string,
         source_database,
         tables: args.tables,
+        table_targets: args.table_targets,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
 
     let surreal = surreal_sync_surreal::v2::surreal_connect(
@@ -800,9 +942,10 @@ async fn run_incremental_v2(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     surreal_sync_postgresql::from_trigger::run_incremental_sync_with_transforms(
         &sink,
         source_opts,
@@ -833,6 +976,7 @@ async fn run_incremental_v3(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -884,7 +1028,11 @@ async fn run_incremental_v3(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
         source_uri: args.connection_string,
         source_database,
         tables: args.tables,
+        table_targets: args.table_targets,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
 
     let surreal = surreal_sync_surreal::v3::surreal_connect(
@@ -893,9 +1041,10 @@ async fn run_incremental_v3(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     surreal_sync_postgresql::from_trigger::run_incremental_sync_with_transforms(
         &sink,
         source_opts,
@@ -909,3 +1058,20 @@ async fn run_incremental_v3(args: ResolvedTriggerIncrementalArgs) -> anyhow::Res
     tracing::info!("Incremental sync completed successfully");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table_targets() {
+        let map = parse_table_targets(&["tenant1.orders=t1_orders".to_string()]).unwrap();
+        assert_eq!(
+            map.get("tenant1.orders").map(String::as_str),
+            Some("t1_orders")
+        );
+        assert!(parse_table_targets(&[]).unwrap().is_empty());
+        assert!(parse_table_targets(&["tenant1.orders".to_string()]).is_err());
+        assert!(parse_table_targets(&["tenant1.orders=".to_string()]).is_err());
+    }
+}
@@ -16,14 +16,17 @@ use std::path::PathBuf;
 use surreal_sync::orchestrate_snapshot_then_incremental;
 use surreal_sync_core::SurrealSink;
 use surreal_sync_core::{Checkpoint, CheckpointStore, SyncManager, SyncPhase};
-use surreal_sync_postgresql::from_wal2json::{PostgreSQLLogicalCheckpoint, ReplicationTailOptions};
+use surreal_sync_postgresql::from_wal2json::{
+    PostgreSQLLogicalCheckpoint, ReplicationTailOptions, Wal2jsonFormat,
+};
 use surreal_sync_runtime::SnapshotTransforms;
 use surreal_sync_runtime::SurrealCliOpts as SurrealOpts;
 use surreal_sync_runtime::{ApplyOpts, Pipeline};
 
-use super::transforms::load_transforms_from_args;
+use super::transforms::load_transforms_with_columns;
 use super::{
-    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink, SdkVersion,
+    get_sdk_version, load_schema_if_provided, make_surreal2_sink, make_surreal3_sink,
+    report_checkpoint_validity, report_migration_estimate, SdkVersion,
 };
 use crate::config::load_config;
 use crate::{
@@ -39,6 +42,8 @@ use surreal_sync_postgresql::from_wal2json::toml_config::{
 struct ResolvedWal2jsonFullArgs {
     connection_string: String,
     slot: String,
+    publication: Option<String>,
+    create_publication: bool,
     tables: Vec<String>,
     schema: String,
     to_namespace: String,
@@ -49,12 +54,19 @@ struct ResolvedWal2jsonFullArgs {
     strategy: SyncStrategy,
     chunk_size: usize,
     transforms_config: Option<PathBuf>,
+    column_filters: surreal_sync_core::ColumnFilters,
+    keyset_columns: surreal_sync_core::KeysetColumns,
+    parallel_reads: surreal_sync_core::ParallelReads,
+    count_only: bool,
+    approximate_counts: bool,
     surreal: SurrealOpts,
 }
 
 struct ResolvedWal2jsonIncrementalArgs {
     connection_string: String,
     slot: String,
+    publication: Option<String>,
+    create_publication: bool,
     tables: Vec<String>,
     schema: String,
     to_namespace: String,
@@ -65,10 +77,24 @@ struct ResolvedWal2jsonIncrementalArgs {
     incremental_to: Option<String>,
     timeout: String,
     transforms_config: Option<PathBuf>,
+    column_filters: surreal_sync_core::ColumnFilters,
+    validate_checkpoint: bool,
+    apply_truncate: bool,
+    format_version: Wal2jsonFormat,
     surreal: SurrealOpts,
 }
 
 fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<ResolvedWal2jsonFullArgs> {
+    let column_filters = args.columns.to_filters()?;
+    let keyset_columns = surreal_sync_core::parse_keyset_columns(&args.keyset_columns)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let parallel_reads = surreal_sync_core::parse_parallel_reads(&args.parallel_reads)
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    if !parallel_reads.is_empty() && args.strategy == SyncStrategy::InterleavedSnapshot {
+        tracing::warn!(
+            "--parallel-reads only applies to --strategy sequential-snapshot; tables are read serially"
+        );
+    }
     if let Some(ref config_path) = args.config_file {
         let cfg = load_config::<Wal2jsonFullSource>(config_path)?;
         let pg = cfg.source.postgresql;
@@ -80,6 +106,8 @@ fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<Resolved
             } else {
                 pg.slot
             },
+            publication: args.publication.or(pg.publication),
+            create_publication: args.create_publication || pg.create_publication,
             tables: if args.tables.is_empty() {
                 pg.tables
             } else {
@@ -100,6 +128,11 @@ fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<Resolved
             strategy: args.strategy,
             chunk_size: args.chunk_size,
             transforms_config: args.transforms_config,
+            column_filters,
+            keyset_columns: keyset_columns.clone(),
+            parallel_reads: parallel_reads.clone(),
+            count_only: args.count_only,
+            approximate_counts: args.approximate_counts,
             surreal: SurrealOpts {
                 surreal_endpoint: sink.endpoint,
                 surreal_username: sink.username,
@@ -108,6 +141,27 @@ fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<Resolved
                 dry_run: sink.dry_run,
                 surreal_sdk_version: args.surreal.surreal_sdk_version.or(sink.sdk_version),
                 zero_temporal: sink.zero_temporal,
+                write_concurrency: sink.write_concurrency,
+                table_write_concurrency: args.surreal.table_write_concurrency.clone(),
+                ordered_tables: args.surreal.ordered_tables.clone(),
+                connection_pool_size: args.surreal.connection_pool_size,
+                write_max_attempts: args.surreal.write_max_attempts,
+                write_retry_backoff_ms: args.surreal.write_retry_backoff_ms,
+                write_failure_threshold: args.surreal.write_failure_threshold,
+                write_circuit_cooldown_secs: args.surreal.write_circuit_cooldown_secs,
+                write_circuit_deadline_secs: args.surreal.write_circuit_deadline_secs,
+                version_field: args.surreal.version_field.clone(),
+                soft_delete_field: args.surreal.soft_delete_field.clone(),
+                soft_delete_flag_field: args.surreal.soft_delete_flag_field.clone(),
+                merge_updates: args.surreal.merge_updates,
+                merge_arrays: args.surreal.merge_arrays.clone(),
+                merge_array_fields: args.surreal.merge_array_fields.clone(),
+                skip_unchanged_fields: args.surreal.skip_unchanged_fields,
+                error_table: args.surreal.error_table.clone(),
+                conflict_policy: args.surreal.conflict_policy,
+                large_field_threshold: args.surreal.large_field_threshold,
+                large_field_dir: args.surreal.large_field_dir.clone(),
+                write_templates: args.surreal.write_templates.clone(),
             },
         })
     } else {
@@ -116,6 +170,8 @@ fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<Resolved
                 .connection_string
                 .ok_or_else(|| anyhow::anyhow!("--connection-string is required"))?,
             slot: args.slot,
+            publication: args.publication,
+            create_publication: args.create_publication,
             tables: args.tables,
             schema: args.schema,
             to_namespace: args
@@ -130,6 +186,11 @@ fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<Resolved
             strategy: args.strategy,
             chunk_size: args.chunk_size,
             transforms_config: args.transforms_config,
+            column_filters,
+            keyset_columns,
+            parallel_reads,
+            count_only: args.count_only,
+            approximate_counts: args.approximate_counts,
             surreal: args.surreal,
         })
     }
@@ -138,6 +199,7 @@ fn resolve_full_args(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<Resolved
 fn resolve_incremental_args(
     args: PostgreSQLLogicalIncrementalArgs,
 ) -> anyhow::Result<ResolvedWal2jsonIncrementalArgs> {
+    let column_filters = args.columns.to_filters()?;
     if let Some(ref config_path) = args.config_file {
         let cfg = load_config::<Wal2jsonIncrementalSource>(config_path)?;
         let pg = cfg.source.postgresql;
@@ -149,6 +211,8 @@ fn resolve_incremental_args(
             } else {
                 pg.slot
             },
+            publication: args.publication.or(pg.publication),
+            create_publication: args.create_publication || pg.create_publication,
             tables: if args.tables.is_empty() {
                 pg.tables
             } else {
@@ -173,6 +237,10 @@ fn resolve_incremental_args(
                 pg.timeout.to_string()
             },
             transforms_config: args.transforms_config,
+            column_filters,
+            validate_checkpoint: args.validate_checkpoint,
+            apply_truncate: args.apply_truncate || pg.apply_truncate,
+            format_version: args.wal2json_format_version,
             surreal: SurrealOpts {
                 surreal_endpoint: sink.endpoint,
                 surreal_username: sink.username,
@@ -181,6 +249,27 @@ fn resolve_incremental_args(
                 dry_run: sink.dry_run,
                 surreal_sdk_version: args.surreal.surreal_sdk_version.or(sink.sdk_version),
                 zero_temporal: sink.zero_temporal,
+                write_concurrency: sink.write_concurrency,
+                table_write_concurrency: args.surreal.table_write_concurrency.clone(),
+                ordered_tables: args.surreal.ordered_tables.clone(),
+                connection_pool_size: args.surreal.connection_pool_size,
+                write_max_attempts: args.surreal.write_max_attempts,
+                write_retry_backoff_ms: args.surreal.write_retry_backoff_ms,
+                write_failure_threshold: args.surreal.write_failure_threshold,
+                write_circuit_cooldown_secs: args.surreal.write_circuit_cooldown_secs,
+                write_circuit_deadline_secs: args.surreal.write_circuit_deadline_secs,
+                version_field: args.surreal.version_field.clone(),
+                soft_delete_field: args.surreal.soft_delete_field.clone(),
+                soft_delete_flag_field: args.surreal.soft_delete_flag_field.clone(),
+                merge_updates: args.surreal.merge_updates,
+                merge_arrays: args.surreal.merge_arrays.clone(),
+                merge_array_fields: args.surreal.merge_array_fields.clone(),
+                skip_unchanged_fields: args.surreal.skip_unchanged_fields,
+                error_table: args.surreal.error_table.clone(),
+                conflict_policy: args.surreal.conflict_policy,
+                large_field_threshold: args.surreal.large_field_threshold,
+                large_field_dir: args.surreal.large_field_dir.clone(),
+                write_templates: args.surreal.write_templates.clone(),
             },
         })
     } else {
@@ -189,6 +278,8 @@ fn resolve_incremental_args(
                 .connection_string
                 .ok_or_else(|| anyhow::anyhow!("--connection-string is required"))?,
             slot: args.slot,
+            publication: args.publication,
+            create_publication: args.create_publication,
             tables: args.tables,
             schema: args.schema,
             to_namespace: args
@@ -203,6 +294,10 @@ fn resolve_incremental_args(
             incremental_to: args.incremental_to,
             timeout: args.timeout,
             transforms_config: args.transforms_config,
+            column_filters,
+            validate_checkpoint: args.validate_checkpoint,
+            apply_truncate: args.apply_truncate,
+            format_version: args.wal2json_format_version,
             surreal: args.surreal,
         })
     }
@@ -213,6 +308,23 @@ fn resolve_incremental_args(
 /// Run PostgreSQL WAL-based full sync, dispatching by strategy then SDK version.
 pub async fn run_full(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<()> {
     let args = resolve_full_args(args)?;
+    if args.count_only {
+        let (client, connection) =
+            tokio_postgres::connect(&args.connection_string, tokio_postgres::NoTls).await?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("PostgreSQL connection error: {e}");
+            }
+        });
+        let estimate = surreal_sync_postgresql::estimate_tables(
+            &client,
+            &args.schema,
+            &args.tables,
+            args.approximate_counts,
+        )
+        .await?;
+        return report_migration_estimate(&estimate);
+    }
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -227,7 +339,9 @@ pub async fn run_full(args: PostgreSQLLogicalFullArgs) -> anyhow::Result<()> {
         }
         (SyncStrategy::InterleavedSnapshot, SdkVersion::V3) => {
             run_full_interleaved_snapshot_v3(args).await
-        }
+        }        (SyncStrategy::ConsistentSnapshot, _) => anyhow::bail!(
+            "--strategy consistent-snapshot is not supported by the wal2json source; use `from postgresql-pgoutput`"
+        ),
     }
 }
 
@@ -247,6 +361,7 @@ async fn run_full_v2(args: ResolvedWal2jsonFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -254,21 +369,29 @@ async fn run_full_v2(args: ResolvedWal2jsonFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     let source_opts = surreal_sync_postgresql::from_wal2json::SourceOpts {
         connection_string: args.connection_string,
         slot_name: args.slot,
         tables: args.tables,
         schema: args.schema,
+        publication_name: args.publication.clone(),
+        create_publication: args.create_publication,
         relation_tables: vec![],
+        keyset_columns: args.keyset_columns,
+        parallel_reads: args.parallel_reads,
+        column_filters: args.column_filters.clone(),
+        apply_truncate: false,
+        format_version: Default::default(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
     };
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
         (Some(dir), None) => {
@@ -335,6 +458,7 @@ async fn run_full_v3(args: ResolvedWal2jsonFullArgs) -> anyhow::Result<()> {
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -342,21 +466,29 @@ async fn run_full_v3(args: ResolvedWal2jsonFullArgs) -> anyhow::Result<()> {
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
 
     let source_opts = surreal_sync_postgresql::from_wal2json::SourceOpts {
         connection_string: args.connection_string,
         slot_name: args.slot,
         tables: args.tables,
         schema: args.schema,
+        publication_name: args.publication.clone(),
+        create_publication: args.create_publication,
         relation_tables: vec![],
+        keyset_columns: args.keyset_columns,
+        parallel_reads: args.parallel_reads,
+        column_filters: args.column_filters.clone(),
+        apply_truncate: false,
+        format_version: Default::default(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
         batch_size: args.surreal.batch_size,
         dry_run: args.surreal.dry_run,
     };
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
 
     match (&args.checkpoint_dir, &args.checkpoints_surreal_table) {
         (Some(dir), None) => {
@@ -431,6 +563,7 @@ async fn run_incremental_v2(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -459,6 +592,10 @@ async fn run_incremental_v2(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
         }
     };
 
+    if args.validate_checkpoint {
+        return validate_wal2json_checkpoint(&args, &from_checkpoint).await;
+    }
+
     let to_checkpoint = args
         .incremental_to
         .as_ref()
@@ -479,17 +616,25 @@ async fn run_incremental_v2(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
 
     let source_opts = surreal_sync_postgresql::from_wal2json::SourceOpts {
         connection_string: args.connection_string,
         slot_name: args.slot,
         tables: args.tables,
         schema: args.schema,
+        publication_name: args.publication.clone(),
+        create_publication: args.create_publication,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: args.apply_truncate,
+        format_version: args.format_version,
     };
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     surreal_sync_postgresql::from_wal2json::run_incremental_sync_with_transforms(
         &sink,
         source_opts,
@@ -519,6 +664,7 @@ async fn run_incremental_v3(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
 
     let from_checkpoint = match (&args.incremental_from, &args.checkpoints_surreal_table) {
@@ -547,6 +693,10 @@ async fn run_incremental_v3(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
         }
     };
 
+    if args.validate_checkpoint {
+        return validate_wal2json_checkpoint(&args, &from_checkpoint).await;
+    }
+
     let to_checkpoint = args
         .incremental_to
         .as_ref()
@@ -567,17 +717,25 @@ async fn run_incremental_v3(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
 
     let source_opts = surreal_sync_postgresql::from_wal2json::SourceOpts {
         connection_string: args.connection_string,
         slot_name: args.slot,
         tables: args.tables,
         schema: args.schema,
+        publication_name: args.publication.clone(),
+        create_publication: args.create_publication,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: args.apply_truncate,
+        format_version: args.format_version,
     };
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     surreal_sync_postgresql::from_wal2json::run_incremental_sync_with_transforms(
         &sink,
         source_opts,
@@ -591,6 +749,25 @@ async fn run_incremental_v3(args: ResolvedWal2jsonIncrementalArgs) -> anyhow::Re
     Ok(())
 }
 
+/// `--validate-checkpoint`: check the LSN against the replication slot.
+async fn validate_wal2json_checkpoint(
+    args: &ResolvedWal2jsonIncrementalArgs,
+    checkpoint: &surreal_sync_postgresql::from_wal2json::PostgreSQLLogicalCheckpoint,
+) -> anyhow::Result<()> {
+    let (client, connection) =
+        tokio_postgres::connect(&args.connection_string, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::error!("PostgreSQL connection error: {e}");
+        }
+    });
+    let validity = surreal_sync_postgresql::from_wal2json::validate_checkpoint(
+        &client, &args.slot, checkpoint,
+    )
+    .await?;
+    report_checkpoint_validity("PostgreSQL", validity)
+}
+
 // =============================================================================
 // Interleaved snapshot strategy
 // =============================================================================
@@ -600,13 +777,22 @@ fn wal2json_source_opts(
     slot: &str,
     tables: Vec<String>,
     schema: &str,
+    publication_name: Option<String>,
+    create_publication: bool,
 ) -> surreal_sync_postgresql::from_wal2json::SourceOpts {
     surreal_sync_postgresql::from_wal2json::SourceOpts {
         connection_string: connection_string.to_string(),
         slot_name: slot.to_string(),
         tables,
         schema: schema.to_string(),
+        publication_name,
+        create_publication,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Default::default(),
     }
 }
 
@@ -659,6 +845,7 @@ async fn run_full_interleaved_snapshot_v2(args: ResolvedWal2jsonFullArgs) -> any
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -666,15 +853,18 @@ async fn run_full_interleaved_snapshot_v2(args: ResolvedWal2jsonFullArgs) -> any
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     let source_opts = wal2json_source_opts(
         &args.connection_string,
         &args.slot,
         args.tables.clone(),
         &args.schema,
+        args.publication.clone(),
+        args.create_publication,
     );
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     let transforms = SnapshotTransforms {
         pipeline,
         apply_opts,
@@ -740,6 +930,7 @@ async fn run_full_interleaved_snapshot_v3(args: ResolvedWal2jsonFullArgs) -> any
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -747,15 +938,18 @@ async fn run_full_interleaved_snapshot_v3(args: ResolvedWal2jsonFullArgs) -> any
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal.clone(), args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal.clone(), &args.surreal);
     let source_opts = wal2json_source_opts(
         &args.connection_string,
         &args.slot,
         args.tables.clone(),
         &args.schema,
+        args.publication.clone(),
+        args.create_publication,
     );
 
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) =
+        load_transforms_with_columns(args.transforms_config.as_deref(), &args.column_filters)?;
     let transforms = SnapshotTransforms {
         pipeline,
         apply_opts,
@@ -807,7 +1001,10 @@ async fn run_full_interleaved_snapshot_v3(args: ResolvedWal2jsonFullArgs) -> any
 
 /// Run the combined `from postgresql sync` orchestrator.
 pub async fn run_sync(args: PostgreSQLLogicalSyncArgs) -> anyhow::Result<()> {
-    let (pipeline, apply_opts) = load_transforms_from_args(args.transforms_config.as_deref())?;
+    let (pipeline, apply_opts) = load_transforms_with_columns(
+        args.transforms_config.as_deref(),
+        &args.columns.to_filters()?,
+    )?;
     let sdk_version = get_sdk_version(
         &args.surreal.surreal_endpoint,
         args.surreal.surreal_sdk_version.as_deref(),
@@ -832,6 +1029,7 @@ async fn run_sync_v2(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v2::surreal_connect(
         &surreal_opts,
@@ -839,7 +1037,7 @@ async fn run_sync_v2(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal2_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal2_sink(surreal, &args.surreal);
     wal2json_orchestrate(&sink, args, pipeline, apply_opts).await
 }
 
@@ -856,6 +1054,7 @@ async fn run_sync_v3(
         surreal_endpoint: args.surreal.surreal_endpoint.clone(),
         surreal_username: args.surreal.surreal_username.clone(),
         surreal_password: args.surreal.surreal_password.clone(),
+        write_retry: args.surreal.write_retry(),
     };
     let surreal = surreal_sync_surreal::v3::surreal_connect(
         &surreal_opts,
@@ -863,7 +1062,7 @@ async fn run_sync_v3(
         &args.to_database,
     )
     .await?;
-    let sink = make_surreal3_sink(surreal, args.surreal.zero_temporal);
+    let sink = make_surreal3_sink(surreal, &args.surreal);
     wal2json_orchestrate(&sink, args, pipeline, apply_opts).await
 }
 
@@ -884,13 +1083,19 @@ async fn wal2json_orchestrate<S: SurrealSink>(
         &args.slot,
         args.tables.clone(),
         &args.schema,
+        args.publication.clone(),
+        args.create_publication,
     );
-    let incremental_opts = wal2json_source_opts(
+    let mut incremental_opts = wal2json_source_opts(
         &args.connection_string,
         &args.slot,
         args.tables.clone(),
         &args.schema,
+        args.publication.clone(),
+        args.create_publication,
     );
+    incremental_opts.apply_truncate = args.apply_truncate;
+    incremental_opts.format_version = args.wal2json_format_version;
     let chunk_size = args.chunk_size;
     let transforms = SnapshotTransforms {
         pipeline: pipeline.clone(),
@@ -928,8 +1133,14 @@ async fn wal2json_orchestrate<S: SurrealSink>(
 /// Emit an ad-hoc `execute-snapshot` signal so a running `sync` snapshots the
 /// requested tables.
 pub async fn run_snapshot_signal(args: PostgreSQLLogicalSnapshotArgs) -> anyhow::Result<()> {
-    let source_opts =
-        wal2json_source_opts(&args.connection_string, &args.slot, vec![], &args.schema);
+    let source_opts = wal2json_source_opts(
+        &args.connection_string,
+        &args.slot,
+        vec![],
+        &args.schema,
+        None,
+        false,
+    );
     surreal_sync_postgresql::from_wal2json::request_snapshot(&source_opts, &args.tables).await?;
     tracing::info!(
         "Requested ad-hoc snapshot of tables {:?} via execute-snapshot signal",
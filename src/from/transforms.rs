@@ -1,3 +1,3 @@
 //! Re-export shared `--transforms-config` helpers from surreal-sync-runtime.
 
-pub use surreal_sync_runtime::load_transforms_from_args;
+pub use surreal_sync_runtime::load_transforms_with_columns;
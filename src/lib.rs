@@ -9,6 +9,7 @@
 //! | Source | Crate |
 //! |--------|-------|
 //! | Snowflake | `surreal-sync-snowflake` (`from_snowflake`) |
+//! | Other SQL databases (bring your own driver) | `surreal-sync-generic-sql` |
 //! | MySQL/MariaDB binlog | `surreal-sync-mysql` (`from_binlog`) |
 //! | SurrealDB sink | `surreal-sync-surreal` with feature `v2` or `v3` |
 //! | Checkpoint (CDC) | included in `surreal-sync-surreal`; or `surreal-sync-runtime::checkpoint_fs` |
@@ -21,6 +22,10 @@
 //! - [`testing`] — shared helpers for workspace integration / load tests
 //! - Thin re-exports used by the stock binary’s `from *` handlers and tests
 //!   ([`csv`], [`jsonl`], [`orchestrate_snapshot_then_incremental`])
+//! - [`migrate`] — one-shot full sync that picks the source from a URI scheme
+//! - [`audit`] — append-only log of sync runs
+//! - [`mapping`] — source column → SurrealDB field mapping documentation
+//! - [`export`] — SurrealDB → JSONL / Kafka export
 //!
 //! The CLI picks SurrealDB v2 vs v3 automatically; that logic lives in the
 //! binary, not in this library.
@@ -28,6 +33,20 @@
 /// Integration / load-test helpers (for workspace tests only — not for embedding).
 pub mod testing;
 
+/// Full sync dispatched on the source URI scheme (`surreal-sync migrate`).
+pub mod migrate;
+
+/// Append-only, hash-chained audit log of sync runs (`migrate --audit-log`).
+pub mod audit;
+
+/// Column → field mapping tables generated from the sync configuration
+/// (`migrate --mapping-doc`).
+pub mod mapping;
+
+/// SurrealDB as the source: export tables to JSONL files or Kafka topics
+/// (`surreal-sync export`).
+pub mod export;
+
 /// Run a watermark snapshot+stream full sync and then continue with the
 /// source's existing incremental runner from the handed-off stream position,
 /// all in one process.
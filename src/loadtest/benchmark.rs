@@ -0,0 +1,263 @@
+//! Loadtest benchmark command handler.
+//!
+//! Runs the full sync of an already populated source once per
+//! `(batch_size, write_concurrency)` combination, dropping the target
+//! database before each run, and prints the combinations sorted by
+//! throughput.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Args;
+use surreal_sync::migrate::{migrate, MigrateConfig};
+use surreal_sync_core::{SurrealConfig, SurrealSdkVersion};
+use surreal_sync_runtime::SurrealCliOpts;
+
+use crate::from::{get_sdk_version, SdkVersion};
+
+/// Arguments for benchmarking sink write parameters.
+#[derive(Args, Clone, Debug)]
+pub struct BenchmarkArgs {
+    /// Source URI of the populated data (any `migrate` scheme)
+    #[arg(value_name = "SOURCE_URI", env = "SOURCE_URI")]
+    pub source_uri: String,
+
+    /// Batch sizes to try (comma-separated)
+    #[arg(long, value_delimiter = ',', default_value = "100,500,1000,5000")]
+    pub batch_sizes: Vec<usize>,
+
+    /// Write concurrency levels to try (comma-separated)
+    #[arg(long, value_delimiter = ',', default_value = "1,2,4,8")]
+    pub concurrency_levels: Vec<usize>,
+
+    /// Tables, collections or labels to sync (comma-separated, empty means all)
+    #[arg(long, value_delimiter = ',')]
+    pub tables: Vec<String>,
+
+    /// Target table for CSV sources (default: the file name without extension)
+    #[arg(long)]
+    pub table: Option<String>,
+
+    /// Target SurrealDB namespace
+    #[arg(long)]
+    pub to_namespace: String,
+
+    /// Target SurrealDB database; it is dropped before every run
+    #[arg(long)]
+    pub to_database: String,
+
+    /// TOML file describing the transform pipeline (`[[transforms]]`).
+    #[arg(long, value_name = "PATH")]
+    pub transforms_config: Option<PathBuf>,
+
+    /// Connection flags; `--batch-size` and `--write-concurrency` are
+    /// replaced by each grid point
+    #[command(flatten)]
+    pub surreal: SurrealCliOpts,
+}
+
+/// Throughput of one grid point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkResult {
+    pub batch_size: usize,
+    pub concurrency: usize,
+    /// Records plus relations written.
+    pub written: u64,
+    pub elapsed: Duration,
+}
+
+impl BenchmarkResult {
+    /// Records and relations written per second.
+    pub fn throughput(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.written as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Run the benchmark grid and print the results table.
+pub async fn run_benchmark(args: BenchmarkArgs) -> anyhow::Result<()> {
+    if args.batch_sizes.is_empty() || args.concurrency_levels.is_empty() {
+        anyhow::bail!("--batch-sizes and --concurrency-levels need at least one value each");
+    }
+    if let Some(zero) = args
+        .batch_sizes
+        .iter()
+        .chain(&args.concurrency_levels)
+        .find(|v| **v == 0)
+    {
+        anyhow::bail!("Batch sizes and concurrency levels must be >= 1, got {zero}");
+    }
+    if args.surreal.dry_run {
+        anyhow::bail!("--dry-run writes nothing, so there is no throughput to benchmark");
+    }
+
+    let sdk_version = match get_sdk_version(
+        &args.surreal.surreal_endpoint,
+        args.surreal.surreal_sdk_version.as_deref(),
+    )
+    .await?
+    {
+        SdkVersion::V2 => SurrealSdkVersion::V2,
+        SdkVersion::V3 => SurrealSdkVersion::V3,
+    };
+    let config = MigrateConfig {
+        tables: args.tables.clone(),
+        file_table: args.table.clone(),
+        transforms_config: args.transforms_config.clone(),
+        sdk_version: Some(sdk_version),
+        ..Default::default()
+    };
+    let base = args
+        .surreal
+        .to_config(args.to_namespace.clone(), args.to_database.clone());
+
+    let grid_size = args.batch_sizes.len() * args.concurrency_levels.len();
+    let mut results = Vec::with_capacity(grid_size);
+    for &batch_size in &args.batch_sizes {
+        for &concurrency in &args.concurrency_levels {
+            tracing::info!(
+                "Benchmark run {}/{grid_size}: batch_size={batch_size}, concurrency={concurrency}",
+                results.len() + 1
+            );
+            let surreal = SurrealConfig {
+                batch_size,
+                write_concurrency: concurrency,
+                ..base.clone()
+            };
+            reset_target(&surreal, sdk_version).await?;
+            let report = migrate(&args.source_uri, &surreal, &config)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Benchmark run batch_size={batch_size}, concurrency={concurrency} failed"
+                    )
+                })?;
+            let result = BenchmarkResult {
+                batch_size,
+                concurrency,
+                written: report.total_records() + report.total_relations(),
+                elapsed: report.elapsed,
+            };
+            tracing::info!(
+                "  {} written in {:?} ({:.0}/s)",
+                result.written,
+                result.elapsed,
+                result.throughput()
+            );
+            results.push(result);
+        }
+    }
+    reset_target(&base, sdk_version).await?;
+
+    sort_by_throughput(&mut results);
+    println!("{}", format_results(&results));
+    if let Some(best) = results.first() {
+        println!(
+            "Best: --batch-size {} --write-concurrency {} ({:.0} records/s)",
+            best.batch_size,
+            best.concurrency,
+            best.throughput()
+        );
+    }
+    Ok(())
+}
+
+/// Drop the target database so every run starts from an empty target.
+async fn reset_target(surreal: &SurrealConfig, version: SurrealSdkVersion) -> anyhow::Result<()> {
+    let statement = format!(
+        "REMOVE DATABASE IF EXISTS `{}`",
+        surreal.database.replace('`', "\\`")
+    );
+    match version {
+        SurrealSdkVersion::V2 => {
+            let client = surrealdb2::engine::any::connect(&surreal.endpoint).await?;
+            client
+                .signin(surrealdb2::opt::auth::Root {
+                    username: &surreal.username,
+                    password: &surreal.password,
+                })
+                .await?;
+            client.use_ns(&surreal.namespace).await?;
+            client.query(statement).await?.check()?;
+        }
+        SurrealSdkVersion::V3 => {
+            let client = surrealdb3::engine::any::connect(&surreal.endpoint).await?;
+            client
+                .signin(surrealdb3::opt::auth::Root {
+                    username: surreal.username.clone(),
+                    password: surreal.password.clone(),
+                })
+                .await?;
+            client.use_ns(&surreal.namespace).await?;
+            client.query(statement).await?.check()?;
+        }
+    }
+    Ok(())
+}
+
+/// Highest throughput first; ties keep grid order.
+fn sort_by_throughput(results: &mut [BenchmarkResult]) {
+    results.sort_by(|a, b| b.throughput().total_cmp(&a.throughput()));
+}
+
+/// `(batch_size, concurrency) → throughput` table, in the given order.
+fn format_results(results: &[BenchmarkResult]) -> String {
+    let mut out = format!(
+        "{:>10}  {:>11}  {:>10}  {:>10}  {:>12}\n",
+        "batch_size", "concurrency", "written", "elapsed", "records/s"
+    );
+    for r in results {
+        out.push_str(&format!(
+            "{:>10}  {:>11}  {:>10}  {:>9.2}s  {:>12.0}\n",
+            r.batch_size,
+            r.concurrency,
+            r.written,
+            r.elapsed.as_secs_f64(),
+            r.throughput()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(batch_size: usize, concurrency: usize, millis: u64) -> BenchmarkResult {
+        BenchmarkResult {
+            batch_size,
+            concurrency,
+            written: 10_000,
+            elapsed: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_results_sorted_by_throughput() {
+        let mut results = vec![
+            result(100, 1, 4000),
+            result(1000, 4, 1000),
+            result(500, 2, 2000),
+            result(100, 8, 0),
+        ];
+        sort_by_throughput(&mut results);
+        let order: Vec<_> = results
+            .iter()
+            .map(|r| (r.batch_size, r.concurrency))
+            .collect();
+        assert_eq!(order, vec![(1000, 4), (500, 2), (100, 1), (100, 8)]);
+        assert_eq!(results[0].throughput(), 10_000.0);
+
+        let table = format_results(&results);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].contains("records/s"));
+        assert!(lines[1].trim_start().starts_with("1000"));
+        assert!(lines[1].trim_end().ends_with("10000"));
+    }
+}
@@ -3,7 +3,7 @@
 use anyhow::Context;
 use loadtest_distributed::{
     build_cluster_config,
-    generator::{ConfigGenerator, DockerComposeGenerator, KubernetesGenerator},
+    generator::{ConfigGenerator, DockerComposeGenerator, KubernetesGenerator, NomadGenerator},
     GenerateArgs, Platform, SourceType,
 };
 use loadtest_populate_kafka::generate_proto_for_table;
@@ -48,10 +48,11 @@ pub async fn run_loadtest_generate(args: GenerateArgs) -> anyhow::Result<()> {
         args.surrealdb_image.clone(),
     )?;
 
-    // Set schema content for Kubernetes ConfigMap embedding
-    if platforms.contains(&Platform::Kubernetes) {
+    // Set schema content for Kubernetes ConfigMap and Nomad template embedding
+    if platforms.contains(&Platform::Kubernetes) || platforms.contains(&Platform::Nomad) {
         config.schema_content = Some(schema_content.clone());
-
+    }
+    if platforms.contains(&Platform::Kubernetes) {
         // For Kafka source, generate proto files and embed in ConfigMap
         if source_type == SourceType::Kafka {
             let mut proto_contents = std::collections::HashMap::new();
@@ -104,6 +105,14 @@ pub async fn run_loadtest_generate(args: GenerateArgs) -> anyhow::Result<()> {
                     tracing::info!("Generated: {}", path.display());
                 }
             }
+            Platform::Nomad => {
+                let generator = NomadGenerator;
+                let content = generator.generate(&config)?;
+                let path = output_dir.join(generator.filename());
+                std::fs::write(&path, &content)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                tracing::info!("Generated: {}", path.display());
+            }
         }
     }
 
@@ -146,6 +155,10 @@ pub async fn run_loadtest_generate(args: GenerateArgs) -> anyhow::Result<()> {
         tracing::info!("  Kubernetes:");
         tracing::info!("    kubectl apply -f {:?}/kubernetes/", output_dir);
     }
+    if platforms.contains(&Platform::Nomad) {
+        tracing::info!("  Nomad:");
+        tracing::info!("    nomad job run {:?}/loadtest.nomad", output_dir);
+    }
 
     Ok(())
 }
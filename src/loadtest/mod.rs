@@ -1,8 +1,10 @@
 //! Loadtest command handlers.
 //!
-//! This module contains handlers for loadtest populate, verify, and generate commands.
+//! This module contains handlers for loadtest populate, verify, generate and
+//! benchmark commands.
 
 pub mod aggregate_server;
+pub mod benchmark;
 pub mod generate;
 pub mod populate;
 pub mod populate_verify;
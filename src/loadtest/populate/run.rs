@@ -521,7 +521,8 @@ pub async fn run_populate(source: PopulateSource) -> anyhow::Result<()> {
                 // Create a fresh populator (and thus a fresh DataGenerator) for each table.
                 // See MySQL populator comment above for detailed explanation.
                 let mut populator =
-                    loadtest_populate_csv::CSVPopulator::new(schema.clone(), args.common.seed);
+                    loadtest_populate_csv::CSVPopulator::new(schema.clone(), args.common.seed)
+                        .with_dialect(args.dialect());
 
                 let output_path = args.output_dir.join(format!("{table_name}.csv"));
 
@@ -16,6 +16,7 @@ pub async fn run_verify_v2(args: VerifyArgs) -> anyhow::Result<()> {
     } else {
         args.tables.iter().map(|s| s.as_str()).collect()
     };
+    let compare_options = args.compare_options()?;
 
     // Create metrics builder
     let tables_vec: Vec<String> = tables.iter().map(|s| s.to_string()).collect();
@@ -123,7 +124,7 @@ pub async fn run_verify_v2(args: VerifyArgs) -> anyhow::Result<()> {
             args.seed,
             table_name,
         ) {
-            Ok(v) => v,
+            Ok(v) => v.with_compare_options(compare_options.clone()),
             Err(e) => {
                 let error_msg = format!("Failed to create verifier for table '{table_name}': {e}");
                 tracing::error!("{}", error_msg);
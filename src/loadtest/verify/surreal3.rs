@@ -16,6 +16,11 @@ pub async fn run_verify_v3(args: VerifyArgs) -> anyhow::Result<()> {
     } else {
         args.tables.iter().map(|s| s.as_str()).collect()
     };
+    let compare_options = loadtest_verify_surreal3::CompareOptions::parse(
+        &args.compare_fields,
+        &args.skip_compare_fields,
+    )?
+    .with_float_epsilon(args.float_epsilon);
 
     // Create metrics builder
     let tables_vec: Vec<String> = tables.iter().map(|s| s.to_string()).collect();
@@ -124,7 +129,7 @@ pub async fn run_verify_v3(args: VerifyArgs) -> anyhow::Result<()> {
             args.seed,
             table_name,
         ) {
-            Ok(v) => v,
+            Ok(v) => v.with_compare_options(compare_options.clone()),
             Err(e) => {
                 let error_msg =
                     format!("Failed to create v3 verifier for table '{table_name}': {e}");
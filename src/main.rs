@@ -82,9 +82,9 @@
 //! - PostgreSQL: `postgresql:sequence:123` (trigger-based audit table)
 //! - MySQL: `mysql:sequence:456` (trigger-based audit table)
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
-use surreal_sync_runtime::SurrealCliOpts as SurrealOpts;
+use surreal_sync_runtime::{ColumnFilterCliOpts, SurrealCliOpts as SurrealOpts};
 
 // Shared with binary mysql-binlog CLI glue (also used by other `from *` clap args).
 pub(crate) use mysql_binlog::{
@@ -126,6 +126,16 @@ mod snowflake;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Serve /health, /ready and /metrics on this address (e.g. 0.0.0.0:9090)
+    #[cfg(feature = "health-server")]
+    #[arg(
+        long,
+        global = true,
+        value_name = "ADDR",
+        env = "SURREAL_SYNC_HEALTH_LISTEN"
+    )]
+    health_listen: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -136,6 +146,14 @@ enum Commands {
         source: Box<FromSource>,
     },
 
+    /// One-shot full sync from any supported source, picked by URI scheme
+    /// (postgres://, mysql://, mongodb://, bolt://, kafka://, file://, s3://)
+    Migrate(Box<MigrateArgs>),
+
+    /// Export SurrealDB tables to JSONL files or Kafka topics (SurrealDB as
+    /// the source)
+    Export(Box<ExportArgs>),
+
     /// Load testing utilities for populating and verifying test data
     Loadtest {
         #[command(subcommand)]
@@ -143,6 +161,190 @@ enum Commands {
     },
 }
 
+/// AWS credentials for `s3://` sources (default: the SDK credential chain)
+#[derive(Args)]
+struct AwsS3Args {
+    /// Named AWS profile to read S3 objects with
+    #[arg(long, value_name = "PROFILE")]
+    aws_profile: Option<String>,
+
+    /// IAM role to assume (via STS) for S3 reads, e.g. a cross-account
+    /// read role. The base credentials come from --aws-profile or the
+    /// default chain; temporary credentials are refreshed before expiry.
+    #[arg(long, value_name = "ARN")]
+    aws_role_arn: Option<String>,
+
+    /// Session name for --aws-role-arn
+    #[arg(long, default_value = "surreal-sync", requires = "aws_role_arn")]
+    aws_role_session_name: String,
+}
+
+impl AwsS3Args {
+    fn s3_credentials(&self) -> surreal_sync::csv::S3Credentials {
+        use surreal_sync::csv::S3Credentials;
+        match (&self.aws_role_arn, &self.aws_profile) {
+            (Some(role_arn), source_profile) => S3Credentials::AssumeRole {
+                role_arn: role_arn.clone(),
+                session_name: self.aws_role_session_name.clone(),
+                source_profile: source_profile.clone(),
+            },
+            (None, Some(profile)) => S3Credentials::Profile(profile.clone()),
+            (None, None) => S3Credentials::DefaultChain,
+        }
+    }
+}
+
+/// Authentication and timeout for `http://` / `https://` sources
+#[derive(Args)]
+struct HttpSourceArgs {
+    /// Bearer token sent as `Authorization: Bearer <TOKEN>` to HTTP sources
+    #[arg(long, value_name = "TOKEN", env = "HTTP_BEARER_TOKEN")]
+    http_bearer_token: Option<String>,
+
+    /// Extra request header for HTTP sources, as `Name: Value` (can specify
+    /// multiple). Headers are dropped when a redirect leaves the host
+    #[arg(long = "http-header", value_name = "HEADER")]
+    http_headers: Vec<String>,
+
+    /// Give up on an HTTP download after this long (e.g. 30s, 5m)
+    #[arg(long, value_name = "DURATION")]
+    http_timeout: Option<String>,
+}
+
+impl HttpSourceArgs {
+    fn http_options(&self) -> anyhow::Result<surreal_sync::csv::HttpOptions> {
+        let headers = self
+            .http_headers
+            .iter()
+            .map(|header| {
+                let (name, value) = header.split_once(':').ok_or_else(|| {
+                    anyhow::anyhow!("--http-header must be `Name: Value`, got: {header}")
+                })?;
+                Ok((name.trim().to_string(), value.trim().to_string()))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let timeout = match &self.http_timeout {
+            Some(timeout) => Some(std::time::Duration::from_secs(u64::try_from(
+                from::parse_duration_to_secs(timeout)?,
+            )?)),
+            None => None,
+        };
+        Ok(surreal_sync::csv::HttpOptions {
+            bearer_token: self.http_bearer_token.clone(),
+            headers,
+            timeout,
+        })
+    }
+}
+
+#[derive(Args)]
+struct MigrateArgs {
+    /// Source URI; its scheme selects the source (see `surreal_sync::migrate`)
+    #[arg(value_name = "SOURCE_URI", env = "SOURCE_URI")]
+    source_uri: String,
+
+    /// Tables, collections or labels to sync (comma-separated, empty means all)
+    #[arg(long, value_delimiter = ',')]
+    tables: Vec<String>,
+
+    /// Target table for CSV sources (default: the file name without extension)
+    #[arg(long)]
+    table: Option<String>,
+
+    /// Target SurrealDB namespace
+    #[arg(long)]
+    to_namespace: String,
+
+    /// Target SurrealDB database
+    #[arg(long)]
+    to_database: String,
+
+    /// How long a kafka:// source consumes before the sync ends (e.g. 60s, 5m)
+    #[arg(long, default_value = "60s")]
+    kafka_timeout: String,
+
+    /// TOML file describing the transform pipeline (`[[transforms]]`).
+    #[arg(long, value_name = "PATH")]
+    transforms_config: Option<PathBuf>,
+
+    /// Schema file whose `validations` queries run after the sync; the
+    /// command fails if any of them returns rows
+    #[arg(long, value_name = "PATH")]
+    schema_file: Option<PathBuf>,
+
+    /// Write the column → SurrealDB field mapping of --schema-file, with the
+    /// column filters and transforms applied, to this file (HTML for .html,
+    /// Markdown otherwise) and exit without syncing
+    #[arg(long, value_name = "PATH", requires = "schema_file")]
+    mapping_doc: Option<PathBuf>,
+
+    /// Append a hash-chained JSON record of this run (counts, outcome,
+    /// redacted config hash) to this file
+    #[arg(long, value_name = "PATH")]
+    audit_log: Option<PathBuf>,
+
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    #[command(flatten)]
+    aws: AwsS3Args,
+
+    #[command(flatten)]
+    surreal: SurrealOpts,
+}
+
+/// Destination of `export`
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportTo {
+    /// One `<table>.jsonl` file per table in --output-dir
+    Jsonl,
+    /// One topic per table (`<topic-prefix><table>`), protobuf encoded
+    Kafka,
+}
+
+#[derive(Args)]
+struct ExportArgs {
+    /// Where to export to
+    #[arg(long, value_enum)]
+    to: ExportTo,
+
+    /// SurrealDB namespace to export
+    #[arg(long)]
+    from_namespace: String,
+
+    /// SurrealDB database to export
+    #[arg(long)]
+    from_database: String,
+
+    /// Tables to export (comma-separated, empty means all)
+    #[arg(long, value_delimiter = ',')]
+    tables: Vec<String>,
+
+    /// Directory for the JSONL files (--to jsonl)
+    #[arg(long, value_name = "DIR", required_if_eq("to", "jsonl"))]
+    output_dir: Option<PathBuf>,
+
+    /// Kafka bootstrap servers (--to kafka)
+    #[arg(long, required_if_eq("to", "kafka"))]
+    kafka_brokers: Option<String>,
+
+    /// Prefix of the per-table topic names (--to kafka)
+    #[arg(long, default_value = "")]
+    topic_prefix: String,
+
+    /// Write each table's `.proto` file here, for `from kafka --proto-path`
+    /// (--to kafka)
+    #[arg(long, value_name = "DIR")]
+    proto_dir: Option<PathBuf>,
+
+    /// Schema file giving the exported field types; required for --to kafka
+    #[arg(long, value_name = "PATH", required_if_eq("to", "kafka"))]
+    schema_file: Option<PathBuf>,
+
+    #[command(flatten)]
+    surreal: SurrealOpts,
+}
+
 /// Available source databases for the `from` command
 #[derive(Subcommand)]
 enum FromSource {
@@ -222,6 +424,9 @@ enum MongoDBCommands {
     Full(MongoDBFullArgs),
     /// Incremental sync from MongoDB using change streams
     Incremental(MongoDBIncrementalArgs),
+    /// Incremental sync by periodically recomputing aggregations and writing
+    /// only the difference (for sources without usable change streams)
+    Recompute(MongoDBRecomputeArgs),
 }
 
 #[derive(Args)]
@@ -238,6 +443,20 @@ struct MongoDBFullArgs {
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
 
+    /// Only sync these collections (comma-separated, empty means all)
+    #[arg(long, value_delimiter = ',')]
+    include_collections: Vec<String>,
+
+    /// Never sync these collections (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    exclude_collections: Vec<String>,
+
+    /// JSON projection applied to every collection (e.g. '{"blob": 0}').
+    /// Full sync pushes it into `find`; changed documents are projected
+    /// after decode. `_id` is always kept.
+    #[arg(long, value_name = "JSON")]
+    projection: Option<String>,
+
     /// Target SurrealDB namespace
     #[arg(long)]
     to_namespace: String,
@@ -263,6 +482,32 @@ struct MongoDBFullArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Count rows per table and estimate their size instead of syncing.
+    /// Logs a JSON migration estimate and exits without converting or
+    /// writing anything (SurrealDB is not contacted).
+    #[arg(long)]
+    count_only: bool,
+
+    /// Workers converting BSON documents to records while the next batch is
+    /// read (raise on CPU-bound syncs of large or deeply nested documents)
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    conversion_workers: usize,
+
+    /// Read every collection at the cluster time of the start checkpoint, so
+    /// the start and end checkpoints match and `incremental` neither misses
+    /// nor replays a change (replica set or sharded cluster, MongoDB 5.0+)
+    #[arg(long)]
+    consistent_snapshot: bool,
+
+    /// JSON file listing embedded arrays to write as relations, one edge per
+    /// element (`[{"collection", "field", "relation", "target_table",
+    /// "target_id", "properties"}]`)
+    #[arg(long, value_name = "PATH")]
+    array_relations: Option<PathBuf>,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -281,6 +526,20 @@ struct MongoDBIncrementalArgs {
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
 
+    /// Only sync these collections (comma-separated, empty means all)
+    #[arg(long, value_delimiter = ',')]
+    include_collections: Vec<String>,
+
+    /// Never sync these collections (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    exclude_collections: Vec<String>,
+
+    /// JSON projection applied to every collection (e.g. '{"blob": 0}').
+    /// Full sync pushes it into `find`; changed documents are projected
+    /// after decode. `_id` is always kept.
+    #[arg(long, value_name = "JSON")]
+    projection: Option<String>,
+
     /// Target SurrealDB namespace
     #[arg(long)]
     to_namespace: String,
@@ -315,6 +574,56 @@ struct MongoDBIncrementalArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Check that the starting checkpoint is still within the source's
+    /// retention window, report valid / too old, and exit without syncing
+    #[arg(long)]
+    validate_checkpoint: bool,
+
+    #[command(flatten)]
+    surreal: SurrealOpts,
+}
+
+#[derive(Args)]
+struct MongoDBRecomputeArgs {
+    /// MongoDB connection string
+    #[arg(long, env = "MONGODB_URI")]
+    connection_string: String,
+
+    /// MongoDB database name
+    #[arg(long, env = "MONGODB_DATABASE")]
+    database: String,
+
+    /// Collections recomputed as-is into the table of the same name
+    /// (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    tables: Vec<String>,
+
+    /// Table computed by an aggregation (`table=path`, repeatable). The JSON
+    /// file holds `{"collection": ..., "pipeline": [...]}`; `collection`
+    /// defaults to the table name.
+    #[arg(long = "aggregation", value_name = "TABLE=PATH")]
+    aggregations: Vec<String>,
+
+    /// Pause between the end of one pass and the start of the next
+    /// (e.g. "300", "30s", "5m")
+    #[arg(long, default_value = "60")]
+    interval: String,
+
+    /// Run a single pass and exit
+    #[arg(long)]
+    once: bool,
+
+    /// Target SurrealDB namespace
+    #[arg(long)]
+    to_namespace: String,
+
+    /// Target SurrealDB database
+    #[arg(long)]
+    to_database: String,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -355,6 +664,16 @@ struct Neo4jFullArgs {
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
 
+    /// Labels to skip (comma-separated). Nodes carrying any of them, and
+    /// relationships touching those nodes, are not migrated.
+    #[arg(long, value_delimiter = ',')]
+    exclude_labels: Option<Vec<String>>,
+
+    /// Number of labels to migrate in parallel, each over its own Neo4j
+    /// connection
+    #[arg(long, default_value = "1")]
+    concurrency: usize,
+
     /// Timezone for local datetime conversion
     #[arg(long, default_value = "UTC", env = "NEO4J_TIMEZONE")]
     timezone: String,
@@ -404,11 +723,25 @@ struct Neo4jFullArgs {
     #[arg(long, default_value = "id")]
     id_property: String,
 
+    /// Edge table for a relationship type (`TYPE=table`, repeatable).
+    /// Unmapped types use the lowercased type name.
+    #[arg(long = "relation-table", value_name = "TYPE=TABLE")]
+    relation_table: Vec<String>,
+
     /// TOML file describing the transform pipeline (`[[transforms]]`).
     /// Omit for identity (docs pass through unchanged; no transform stage dispatch).
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Count rows per table and estimate their size instead of syncing.
+    /// Logs a JSON migration estimate and exits without converting or
+    /// writing anything (SurrealDB is not contacted).
+    #[arg(long)]
+    count_only: bool,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -499,11 +832,19 @@ struct Neo4jIncrementalArgs {
     #[arg(long, default_value = "id")]
     id_property: String,
 
+    /// Edge table for a relationship type (`TYPE=table`, repeatable).
+    /// Unmapped types use the lowercased type name.
+    #[arg(long = "relation-table", value_name = "TYPE=TABLE")]
+    relation_table: Vec<String>,
+
     /// TOML file describing the transform pipeline (`[[transforms]]`).
     /// Omit for identity (docs pass through unchanged; no transform stage dispatch).
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -572,6 +913,33 @@ struct PostgreSQLTriggerFullArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Per-table keyset sort columns for chunked full-sync reads
+    /// (`table=col1,col2`, repeatable). Lets composite-key tables, or tables
+    /// without a primary key, page by key instead of OFFSET scans.
+    #[arg(long = "keyset-columns", value_name = "TABLE=COLS")]
+    keyset_columns: Vec<String>,
+
+    /// Per-table full-sync read parallelism (`table=N`, repeatable). The
+    /// table is split into N ranges of its leading integer/uuid key column,
+    /// read concurrently on separate connections. Applies to the
+    /// sequential-snapshot strategy.
+    #[arg(long = "parallel-reads", value_name = "TABLE=N")]
+    parallel_reads: Vec<String>,
+
+    /// Count rows per table and estimate their size instead of syncing.
+    /// Logs a JSON migration estimate and exits without converting or
+    /// writing anything (SurrealDB is not contacted).
+    #[arg(long)]
+    count_only: bool,
+
+    /// With --count-only, use the database's table statistics instead of
+    /// exact COUNT(*) queries (faster on large tables, less precise)
+    #[arg(long, requires = "count_only")]
+    approximate_counts: bool,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -586,10 +954,16 @@ struct PostgreSQLTriggerIncrementalArgs {
     #[arg(long, env = "POSTGRESQL_URI")]
     connection_string: Option<String>,
 
-    /// Tables to sync (comma-separated, empty means all tables)
+    /// Tables to sync (comma-separated, empty means all tables). Entries may
+    /// be schema-qualified (`tenant1.orders`)
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
 
+    /// SurrealDB table for a source table (`SCHEMA.TABLE=target`,
+    /// repeatable). Unmapped tables outside `public` sync to `SCHEMA_TABLE`.
+    #[arg(long = "table-target", value_name = "TABLE=TARGET")]
+    table_target: Vec<String>,
+
     /// Target SurrealDB namespace
     #[arg(long)]
     to_namespace: Option<String>,
@@ -625,6 +999,9 @@ struct PostgreSQLTriggerIncrementalArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -665,6 +1042,9 @@ struct PostgreSQLTriggerSyncArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -750,9 +1130,34 @@ struct MySQLFullArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     tls: MySQLTlsArgs,
 
+    /// Optional per-table primary-key overrides (`table=col1,col2`, repeatable).
+    /// When omitted, primary keys are discovered from INFORMATION_SCHEMA.
+    #[arg(long = "id-columns", value_name = "TABLE=COLS")]
+    id_columns: Vec<String>,
+
+    /// Per-table keyset sort columns for chunked full-sync reads
+    /// (`table=col1,col2`, repeatable). Lets composite-key tables, or tables
+    /// without a primary key, page by key instead of OFFSET scans.
+    #[arg(long = "keyset-columns", value_name = "TABLE=COLS")]
+    keyset_columns: Vec<String>,
+
+    /// Count rows per table and estimate their size instead of syncing.
+    /// Logs a JSON migration estimate and exits without converting or
+    /// writing anything (SurrealDB is not contacted).
+    #[arg(long)]
+    count_only: bool,
+
+    /// With --count-only, use the database's table statistics instead of
+    /// exact COUNT(*) queries (faster on large tables, less precise)
+    #[arg(long, requires = "count_only")]
+    approximate_counts: bool,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -800,6 +1205,11 @@ struct MySQLIncrementalArgs {
     #[arg(long, default_value = "3600")]
     timeout: String,
 
+    /// Delete audit table rows once they have been applied to SurrealDB,
+    /// keeping `surreal_sync_changes` from growing without bound
+    #[arg(long)]
+    auto_cleanup: bool,
+
     /// Schema file for type-aware conversion
     #[arg(long, value_name = "PATH")]
     schema_file: Option<PathBuf>,
@@ -809,6 +1219,14 @@ struct MySQLIncrementalArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Optional per-table primary-key overrides (`table=col1,col2`, repeatable).
+    /// When omitted, primary keys are discovered from INFORMATION_SCHEMA.
+    #[arg(long = "id-columns", value_name = "TABLE=COLS")]
+    id_columns: Vec<String>,
+
     #[command(flatten)]
     tls: MySQLTlsArgs,
 
@@ -860,6 +1278,9 @@ struct MySQLSyncArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     /// Optional per-table primary-key overrides (`table=col1,col2`, repeatable).
     /// When omitted, primary keys are discovered from INFORMATION_SCHEMA.
     #[arg(long = "id-columns", value_name = "TABLE=COLS")]
@@ -988,6 +1409,9 @@ struct PostgreSQLPgoutputSyncArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -1035,6 +1459,14 @@ struct PostgreSQLLogicalFullArgs {
     #[arg(long, default_value = "surreal_sync_slot")]
     slot: String,
 
+    /// Publication whose tables are streamed; other tables are skipped by wal2json
+    #[arg(long)]
+    publication: Option<String>,
+
+    /// Create the publication for the configured tables when it does not exist
+    #[arg(long)]
+    create_publication: bool,
+
     /// Tables to sync (comma-separated, empty means all tables)
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
@@ -1076,6 +1508,33 @@ struct PostgreSQLLogicalFullArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Per-table keyset sort columns for chunked full-sync reads
+    /// (`table=col1,col2`, repeatable). Lets composite-key tables, or tables
+    /// without a primary key, page by key instead of OFFSET scans.
+    #[arg(long = "keyset-columns", value_name = "TABLE=COLS")]
+    keyset_columns: Vec<String>,
+
+    /// Per-table full-sync read parallelism (`table=N`, repeatable). The
+    /// table is split into N ranges of its leading integer/uuid key column,
+    /// read concurrently on separate connections. Applies to the
+    /// sequential-snapshot strategy.
+    #[arg(long = "parallel-reads", value_name = "TABLE=N")]
+    parallel_reads: Vec<String>,
+
+    /// Count rows per table and estimate their size instead of syncing.
+    /// Logs a JSON migration estimate and exits without converting or
+    /// writing anything (SurrealDB is not contacted).
+    #[arg(long)]
+    count_only: bool,
+
+    /// With --count-only, use the database's table statistics instead of
+    /// exact COUNT(*) queries (faster on large tables, less precise)
+    #[arg(long, requires = "count_only")]
+    approximate_counts: bool,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -1094,6 +1553,14 @@ struct PostgreSQLLogicalIncrementalArgs {
     #[arg(long, default_value = "surreal_sync_slot")]
     slot: String,
 
+    /// Publication whose tables are streamed; other tables are skipped by wal2json
+    #[arg(long)]
+    publication: Option<String>,
+
+    /// Create the publication for the configured tables when it does not exist
+    #[arg(long)]
+    create_publication: bool,
+
     /// Tables to sync (comma-separated, empty means all tables)
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
@@ -1137,6 +1604,25 @@ struct PostgreSQLLogicalIncrementalArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Mirror source TRUNCATEs by deleting every record of the matching
+    /// SurrealDB table. Off by default (a full-table delete is destructive);
+    /// truncations are then logged and skipped
+    #[arg(long)]
+    apply_truncate: bool,
+
+    /// wal2json output format to stream with: 2 (one message per change) or
+    /// 1 (one message per transaction, for servers or tooling that expect it)
+    #[arg(long, value_name = "1|2", default_value = "2")]
+    wal2json_format_version: surreal_sync_postgresql::from_wal2json::Wal2jsonFormat,
+
+    /// Check that the starting checkpoint is still within the source's
+    /// retention window, report valid / too old, and exit without syncing
+    #[arg(long)]
+    validate_checkpoint: bool,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -1152,6 +1638,14 @@ struct PostgreSQLLogicalSyncArgs {
     #[arg(long, default_value = "surreal_sync_slot")]
     slot: String,
 
+    /// Publication whose tables are streamed; other tables are skipped by wal2json
+    #[arg(long)]
+    publication: Option<String>,
+
+    /// Create the publication for the configured tables when it does not exist
+    #[arg(long)]
+    create_publication: bool,
+
     /// Tables to sync (comma-separated, empty means all tables)
     #[arg(long, value_delimiter = ',')]
     tables: Vec<String>,
@@ -1185,6 +1679,20 @@ struct PostgreSQLLogicalSyncArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Mirror source TRUNCATEs by deleting every record of the matching
+    /// SurrealDB table. Off by default (a full-table delete is destructive);
+    /// truncations are then logged and skipped
+    #[arg(long)]
+    apply_truncate: bool,
+
+    /// wal2json output format to stream with: 2 (one message per change) or
+    /// 1 (one message per transaction, for servers or tooling that expect it)
+    #[arg(long, value_name = "1|2", default_value = "2")]
+    wal2json_format_version: surreal_sync_postgresql::from_wal2json::Wal2jsonFormat,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -1242,6 +1750,14 @@ struct KafkaArgs {
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    /// Check that the starting checkpoint is still within the source's
+    /// retention window, report valid / too old, and exit without syncing
+    #[arg(long)]
+    validate_checkpoint: bool,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -1285,6 +1801,10 @@ struct CsvArgs {
     #[arg(long, default_value = ",")]
     delimiter: char,
 
+    /// CSV quote character
+    #[arg(long, default_value = "\"")]
+    quote: char,
+
     /// Field to use as record ID (optional)
     #[arg(long)]
     id_field: Option<String>,
@@ -1294,7 +1814,8 @@ struct CsvArgs {
     #[arg(long, value_delimiter = ',')]
     id_columns: Vec<String>,
 
-    /// Column names when has_headers is false (comma-separated)
+    /// Column names when has_headers is false (comma-separated). Without
+    /// them, columns map to `--schema-file` fields in order
     #[arg(long, value_delimiter = ',')]
     column_names: Option<Vec<String>>,
 
@@ -1303,14 +1824,39 @@ struct CsvArgs {
     emit_metrics: Option<PathBuf>,
 
     /// Schema file for type-aware conversion
-    #[arg(long, value_name = "PATH")]
+    #[arg(long, value_name = "PATH", conflicts_with = "infer_schema")]
     schema_file: Option<PathBuf>,
 
+    /// Infer the schema from the first `--infer-sample-size` records, define
+    /// the SurrealDB table (SCHEMAFULL), fields and indexes from it, then
+    /// import with it. Columns first seen after the sample are not defined
+    #[arg(long)]
+    infer_schema: bool,
+
+    /// Number of records sampled by `--infer-schema`
+    #[arg(long, default_value_t = 1000, requires = "infer_schema")]
+    infer_sample_size: usize,
+
+    /// Define the SurrealDB tables (SCHEMAFULL), fields and indexes from
+    /// `--schema-file` before importing, with `ASSERT` clauses for declared
+    /// field constraints (min/max, pattern)
+    #[arg(long, requires = "schema_file")]
+    define_schema: bool,
+
     /// TOML file describing the transform pipeline (`[[transforms]]`).
     /// Omit for identity (docs pass through unchanged; no transform stage dispatch).
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
+    #[command(flatten)]
+    aws: AwsS3Args,
+
+    #[command(flatten)]
+    http: HttpSourceArgs,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
@@ -1342,23 +1888,66 @@ struct JsonlArgs {
     #[arg(long, value_delimiter = ',')]
     id_columns: Vec<String>,
 
-    /// Conversion rules (format: 'type="page_id",page_id page:page_id')
+    /// Conversion rules (format: 'type="page_id",page_id page:page_id'), with an
+    /// optional ',path=field.field' or ',path=field[].field' for nested references
     #[arg(long = "rule", value_name = "RULE")]
     conversion_rules: Vec<String>,
 
     /// Schema file for type-aware conversion
-    #[arg(long, value_name = "PATH")]
+    #[arg(long, value_name = "PATH", conflicts_with = "infer_schema")]
     schema_file: Option<PathBuf>,
 
+    /// Infer the schema from the first `--infer-sample-size` records of each
+    /// file, define the SurrealDB tables (SCHEMAFULL), fields and indexes from
+    /// it, then import with it. Fields first seen after the sample are not
+    /// defined
+    #[arg(long)]
+    infer_schema: bool,
+
+    /// Number of records sampled by `--infer-schema`
+    #[arg(long, default_value_t = 1000, requires = "infer_schema")]
+    infer_sample_size: usize,
+
+    /// Define the SurrealDB tables (SCHEMAFULL), fields and indexes from
+    /// `--schema-file` before importing, with `ASSERT` clauses for declared
+    /// field constraints (min/max, pattern)
+    #[arg(long, requires = "schema_file")]
+    define_schema: bool,
+
+    /// What to do with a line that is not valid JSON or has no usable ID
+    #[arg(long, value_enum, default_value = "abort")]
+    on_error: JsonlOnError,
+
     /// TOML file describing the transform pipeline (`[[transforms]]`).
     /// Omit for identity (docs pass through unchanged; no transform stage dispatch).
     #[arg(long, value_name = "PATH")]
     transforms_config: Option<PathBuf>,
 
+    #[command(flatten)]
+    columns: ColumnFilterCliOpts,
+
     #[command(flatten)]
     surreal: SurrealOpts,
 }
 
+/// `--on-error` choices for the JSONL import
+#[derive(Clone, Copy, ValueEnum)]
+enum JsonlOnError {
+    /// Fail the import
+    Abort,
+    /// Log the line, skip it and report the number skipped at the end
+    Skip,
+}
+
+impl From<JsonlOnError> for surreal_sync::jsonl::OnError {
+    fn from(choice: JsonlOnError) -> Self {
+        match choice {
+            JsonlOnError::Abort => Self::Abort,
+            JsonlOnError::Skip => Self::SkipAndLog,
+        }
+    }
+}
+
 // =============================================================================
 // Loadtest Commands
 // =============================================================================
@@ -1383,6 +1972,10 @@ enum LoadtestCommand {
 
     /// Run HTTP server to aggregate metrics from distributed workers
     AggregateServer(AggregateServerArgs),
+
+    /// Sync populated data once per batch size / write concurrency pair and
+    /// rank the pairs by throughput
+    Benchmark(Box<loadtest::benchmark::BenchmarkArgs>),
 }
 
 /// Source database to populate with test data
@@ -1450,14 +2043,186 @@ async fn run() -> anyhow::Result<()> {
 
     let cli = Cli::parse();
 
+    #[cfg(feature = "health-server")]
+    if let Some(listen) = &cli.health_listen {
+        surreal_sync_runtime::health_server::spawn(listen).await?;
+    }
+
     match cli.command {
         Commands::From { source } => handle_from_command(*source).await?,
+        Commands::Migrate(args) => handle_migrate_command(*args).await?,
+        Commands::Export(args) => handle_export_command(*args).await?,
         Commands::Loadtest { command } => handle_loadtest_command(*command).await?,
     }
 
     Ok(())
 }
 
+/// `migrate --mapping-doc`: render the resolved mapping instead of syncing.
+fn write_mapping_doc(args: &MigrateArgs, path: &std::path::Path) -> anyhow::Result<()> {
+    use surreal_sync::mapping::{render_mapping, resolve_mapping, MappingFormat};
+
+    let schema = from::load_schema_if_provided(&args.schema_file)?
+        .ok_or_else(|| anyhow::anyhow!("--mapping-doc needs --schema-file"))?;
+    let transforms = match &args.transforms_config {
+        Some(config) => surreal_sync_runtime::load_transforms_config(config)?,
+        None => surreal_sync_runtime::TransformsConfig::identity(),
+    };
+    let mapping = resolve_mapping(
+        &schema,
+        surreal_sync::migrate::SourceKind::from_uri(&args.source_uri)?,
+        &args.columns.to_filters()?,
+        &transforms,
+    );
+    std::fs::write(
+        path,
+        render_mapping(&mapping, MappingFormat::from_path(path)),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to write mapping to {}: {e}", path.display()))?;
+    tracing::info!(
+        "Wrote the mapping of {} columns to {}",
+        mapping.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+async fn handle_migrate_command(args: MigrateArgs) -> anyhow::Result<()> {
+    if let Some(path) = &args.mapping_doc {
+        return write_mapping_doc(&args, path);
+    }
+    let schema = from::load_schema_if_provided(&args.schema_file)?;
+    from::validate_write_templates(&args.surreal.write_templates, schema.as_ref())?;
+    let config = surreal_sync::migrate::MigrateConfig {
+        tables: args.tables,
+        file_table: args.table,
+        transforms_config: args.transforms_config,
+        column_filters: args.columns.to_filters()?,
+        s3_credentials: args.aws.s3_credentials(),
+        kafka_timeout: std::time::Duration::from_secs(u64::try_from(
+            from::parse_duration_to_secs(&args.kafka_timeout)?,
+        )?),
+        sdk_version: args
+            .surreal
+            .surreal_sdk_version
+            .as_deref()
+            .map(str::parse)
+            .transpose()?,
+        validations: schema.map(|schema| schema.validations).unwrap_or_default(),
+    };
+    let surreal = args.surreal.to_config(args.to_namespace, args.to_database);
+    let started_at = chrono::Utc::now();
+    let result = surreal_sync::migrate::migrate(&args.source_uri, &surreal, &config).await;
+    if let Some(path) = &args.audit_log {
+        let entry = surreal_sync::audit::AuditEntry::for_migrate(
+            &args.source_uri,
+            &surreal,
+            &config,
+            started_at,
+            &result,
+        );
+        let record = surreal_sync::audit::AuditLog::new(path).append(entry)?;
+        tracing::info!(
+            "Recorded run {} in audit log {}",
+            record.run_id,
+            path.display()
+        );
+    }
+    let report = result?;
+    tracing::info!(
+        "Migrated {} records and {} relations from {} in {:?}",
+        report.total_records(),
+        report.total_relations(),
+        report.source,
+        report.elapsed
+    );
+    for (table, count) in report.records.iter().chain(&report.relations) {
+        tracing::info!("  {table}: {count}");
+    }
+    if let Some(estimate) = &report.estimate {
+        let total = estimate.total();
+        tracing::info!(
+            "Dry run: would issue {} write queries in {} batches ({} bytes of payload)",
+            total.queries,
+            total.batches,
+            total.bytes
+        );
+        for (table, t) in &estimate.tables {
+            tracing::info!(
+                "  {table}: {} queries, {} batches, {} records, {} bytes",
+                t.queries,
+                t.batches,
+                t.records,
+                t.bytes
+            );
+        }
+    }
+    for failure in &report.validation_failures {
+        tracing::error!(
+            "Validation '{}' failed: {} violating rows",
+            failure.name,
+            failure.violations
+        );
+        for sample in &failure.samples {
+            tracing::error!("  {sample}");
+        }
+    }
+    if !report.validations_passed() {
+        anyhow::bail!(
+            "{} of {} validations failed",
+            report.validation_failures.len(),
+            config.validations.len()
+        );
+    }
+    Ok(())
+}
+
+async fn handle_export_command(args: ExportArgs) -> anyhow::Result<()> {
+    use surreal_sync::export::{ExportOpts, ExportTarget};
+
+    let target = match args.to {
+        ExportTo::Jsonl => ExportTarget::Jsonl {
+            dir: args
+                .output_dir
+                .ok_or_else(|| anyhow::anyhow!("--to jsonl needs --output-dir"))?,
+        },
+        ExportTo::Kafka => ExportTarget::Kafka {
+            connection: surreal_sync_kafka::producer::KafkaConnectionOptions::plaintext(
+                args.kafka_brokers
+                    .ok_or_else(|| anyhow::anyhow!("--to kafka needs --kafka-brokers"))?,
+            ),
+            topic_prefix: args.topic_prefix,
+            proto_dir: args.proto_dir,
+        },
+    };
+    let opts = ExportOpts {
+        tables: args.tables,
+        batch_size: args.surreal.batch_size,
+        schema: from::load_schema_if_provided(&args.schema_file)?,
+        sdk_version: args
+            .surreal
+            .surreal_sdk_version
+            .as_deref()
+            .map(str::parse)
+            .transpose()?,
+        ..ExportOpts::new(target)
+    };
+    let surreal = args
+        .surreal
+        .to_config(args.from_namespace, args.from_database);
+    let report = surreal_sync::export::export(&surreal, &opts).await?;
+    tracing::info!(
+        "Exported {} records to {} in {:?}",
+        report.total_records(),
+        opts.target,
+        report.elapsed
+    );
+    for (table, count) in &report.records {
+        tracing::info!("  {table}: {count}");
+    }
+    Ok(())
+}
+
 // =============================================================================
 // From Command Handler
 // =============================================================================
@@ -1467,6 +2232,7 @@ async fn handle_from_command(source: FromSource) -> anyhow::Result<()> {
         FromSource::MongoDB { command } => match command {
             MongoDBCommands::Full(args) => from::mongodb::run_full(args).await?,
             MongoDBCommands::Incremental(args) => from::mongodb::run_incremental(args).await?,
+            MongoDBCommands::Recompute(args) => from::mongodb::run_recompute(args).await?,
         },
         FromSource::Neo4j { command } => match command {
             Neo4jCommands::Full(args) => from::neo4j::run_full(args).await?,
@@ -1532,6 +2298,7 @@ async fn handle_loadtest_command(command: LoadtestCommand) -> anyhow::Result<()>
         LoadtestCommand::Populate { source } => loadtest::populate::run_populate(source).await?,
         LoadtestCommand::Verify { args } => loadtest::verify::run_verify(args).await?,
         LoadtestCommand::Generate(args) => loadtest::generate::run_loadtest_generate(args).await?,
+        LoadtestCommand::Benchmark(args) => loadtest::benchmark::run_benchmark(*args).await?,
         LoadtestCommand::AggregateServer(args) => {
             loadtest::aggregate_server::run_aggregate_server(args).await?
         }
@@ -0,0 +1,389 @@
+//! Source column → SurrealDB field mapping documentation.
+//!
+//! [`resolve_mapping`] walks a sync schema with the same converters the sync
+//! uses: source column types come from the source's DDL mapper, SurrealDB
+//! field types from [`SurrealDdl`] (which follows what the sinks write), and
+//! column filters and transform stages are applied per field. The result is
+//! rendered as a Markdown or HTML table with [`render_mapping`], so the
+//! handed-off documentation cannot drift from the configuration it was
+//! generated from.
+
+use std::path::Path;
+
+use surreal_sync_core::{ColumnFilters, Schema, Type};
+use surreal_sync_runtime::{ConfiguredStage, Redaction, TransformsConfig, UnicodeForm};
+use surreal_sync_surreal::SurrealDdl;
+
+use crate::migrate::SourceKind;
+
+/// One source column and the SurrealDB field it is written to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMapping {
+    pub table: String,
+    pub source_column: String,
+    /// Column type in the source's own terms, when the source has a DDL
+    /// mapping (PostgreSQL, MySQL).
+    pub source_type: Option<String>,
+    /// Type name as written in a schema file.
+    pub universal_type: String,
+    /// `None` when the column is filtered out and not synced.
+    pub target_field: Option<String>,
+    pub target_type: Option<String>,
+    /// Transform stages that change this field, in pipeline order.
+    pub transforms: Vec<String>,
+}
+
+/// Output format of [`render_mapping`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingFormat {
+    Markdown,
+    Html,
+}
+
+impl MappingFormat {
+    /// HTML for `.html` / `.htm` files, Markdown otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") => {
+                Self::Html
+            }
+            _ => Self::Markdown,
+        }
+    }
+}
+
+/// The mapping of every column of `schema` for a sync from `source` with
+/// `filters` and the `transforms` pipeline.
+pub fn resolve_mapping(
+    schema: &Schema,
+    source: SourceKind,
+    filters: &ColumnFilters,
+    transforms: &TransformsConfig,
+) -> Vec<FieldMapping> {
+    let ddl = SurrealDdl;
+    let mut mappings = Vec::new();
+    for table in &schema.tables {
+        let id_type = &table.id.id_type;
+        let mut id = FieldMapping {
+            table: table.name.clone(),
+            source_column: "id".to_string(),
+            source_type: source_type(source, id_type),
+            universal_type: universal_type_name(id_type),
+            target_field: Some("id".to_string()),
+            target_type: Some(format!("record<{}>", table.name)),
+            transforms: Vec::new(),
+        };
+        let mut original_ids = Vec::new();
+        for stage in &transforms.stages {
+            match stage {
+                ConfiguredStage::FlattenId(cfg) if matches!(id_type, Type::Array { .. }) => {
+                    id.transforms.push(format!(
+                        "flatten_id: key parts joined with {:?}",
+                        cfg.separator
+                    ));
+                }
+                ConfiguredStage::NormalizeId(cfg)
+                    if is_text(id_type) && applies_to(&cfg.tables, &table.name) =>
+                {
+                    let mut steps = Vec::new();
+                    if let Some(form) = cfg.unicode {
+                        steps.push(match form {
+                            UnicodeForm::Nfc => "NFC",
+                            UnicodeForm::Nfkc => "NFKC",
+                        });
+                    }
+                    if cfg.strip_accents {
+                        steps.push("strip accents");
+                    }
+                    if cfg.lowercase {
+                        steps.push("lowercase");
+                    }
+                    id.transforms
+                        .push(format!("normalize_id: {}", steps.join(", ")));
+                    if let Some(field) = &cfg.original_field {
+                        original_ids.push((field.clone(), "normalize_id: original id"));
+                    }
+                }
+                ConfiguredStage::SourceId(cfg) if applies_to(&cfg.tables, &table.name) => {
+                    original_ids.push((cfg.field.clone(), "source_id: id as read from the source"));
+                }
+                ConfiguredStage::Command(cfg) => id.transforms.push(command_note(&cfg.command)),
+                _ => {}
+            }
+        }
+        let originals: Vec<FieldMapping> = original_ids
+            .into_iter()
+            .map(|(field, note)| FieldMapping {
+                target_field: Some(field),
+                target_type: Some(ddl.to_field_type(id_type, false)),
+                transforms: vec![note.to_string()],
+                ..id.clone()
+            })
+            .collect();
+        mappings.push(id);
+        mappings.extend(originals);
+
+        for field in &table.fields {
+            let kept = filters.keeps(&table.name, &field.name);
+            let transforms = if kept {
+                transforms
+                    .stages
+                    .iter()
+                    .filter_map(|stage| match stage {
+                        ConfiguredStage::Redact(cfg)
+                            if can_hold_text(&field.field_type)
+                                && applies_to(&cfg.tables, &table.name)
+                                && applies_to(&cfg.fields, &field.name) =>
+                        {
+                            Some(format!(
+                                "redact: {}",
+                                cfg.patterns
+                                    .iter()
+                                    .map(|(pattern, redaction)| match redaction {
+                                        Redaction::Mask(mask) => {
+                                            format!("/{pattern}/ → {mask:?}")
+                                        }
+                                        Redaction::Hash => {
+                                            format!("/{pattern}/ → sha256")
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ))
+                        }
+                        ConfiguredStage::Command(cfg) => Some(command_note(&cfg.command)),
+                        _ => None,
+                    })
+                    .collect()
+            } else {
+                vec!["excluded by column filters".to_string()]
+            };
+            mappings.push(FieldMapping {
+                table: table.name.clone(),
+                source_column: field.name.clone(),
+                source_type: source_type(source, &field.field_type),
+                universal_type: universal_type_name(&field.field_type),
+                target_field: kept.then(|| field.name.clone()),
+                target_type: kept.then(|| ddl.to_field_type(&field.field_type, field.nullable)),
+                transforms,
+            });
+        }
+    }
+    mappings
+}
+
+/// Render `mappings` as one table.
+pub fn render_mapping(mappings: &[FieldMapping], format: MappingFormat) -> String {
+    const HEADERS: [&str; 7] = [
+        "Table",
+        "Source column",
+        "Source type",
+        "Universal type",
+        "SurrealDB field",
+        "SurrealDB type",
+        "Transforms",
+    ];
+    let rows = mappings.iter().map(|m| {
+        [
+            m.table.clone(),
+            m.source_column.clone(),
+            m.source_type.clone().unwrap_or_else(|| "-".to_string()),
+            m.universal_type.clone(),
+            m.target_field.clone().unwrap_or_else(|| "-".to_string()),
+            m.target_type.clone().unwrap_or_else(|| "-".to_string()),
+            if m.transforms.is_empty() {
+                "-".to_string()
+            } else {
+                m.transforms.join("; ")
+            },
+        ]
+    });
+    match format {
+        MappingFormat::Markdown => {
+            let mut out = format!("| {} |\n", HEADERS.join(" | "));
+            out.push_str(&format!("|{}\n", "---|".repeat(HEADERS.len())));
+            for row in rows {
+                let cells: Vec<String> = row.iter().map(|c| c.replace('|', "\\|")).collect();
+                out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            }
+            out
+        }
+        MappingFormat::Html => {
+            let cells = |tag: &str, row: &[String]| {
+                row.iter()
+                    .map(|c| format!("<{tag}>{}</{tag}>", escape_html(c)))
+                    .collect::<String>()
+            };
+            let headers: Vec<String> = HEADERS.iter().map(|h| h.to_string()).collect();
+            let mut out = String::from("<table>\n<thead>\n");
+            out.push_str(&format!("<tr>{}</tr>\n", cells("th", &headers)));
+            out.push_str("</thead>\n<tbody>\n");
+            for row in rows {
+                out.push_str(&format!("<tr>{}</tr>\n", cells("td", &row)));
+            }
+            out.push_str("</tbody>\n</table>\n");
+            out
+        }
+    }
+}
+
+/// Column type in the source's DDL, for sources that have a mapping.
+fn source_type(source: SourceKind, sync_type: &Type) -> Option<String> {
+    match source {
+        SourceKind::PostgreSQL => {
+            use surreal_sync_postgresql::types::ToDdl;
+            Some(surreal_sync_postgresql::types::PostgreSQLDdl.to_ddl(sync_type))
+        }
+        SourceKind::MySQL => {
+            use surreal_sync_mysql::ToDdl;
+            Some(surreal_sync_mysql::MySQLDdl.to_ddl(sync_type))
+        }
+        _ => None,
+    }
+}
+
+/// Schema-file spelling of `sync_type`: `var_char(255)`, `array<int>`.
+fn universal_type_name(sync_type: &Type) -> String {
+    fn name(value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Object(map) => {
+                let base = map.get("type").map(name).unwrap_or_default();
+                if let Some(element) = map.get("element_type") {
+                    return format!("{base}<{}>", name(element));
+                }
+                let params: Vec<String> = map
+                    .iter()
+                    .filter(|(k, _)| k.as_str() != "type")
+                    .map(|(_, v)| name(v))
+                    .collect();
+                if params.is_empty() {
+                    base
+                } else {
+                    format!("{base}({})", params.join(", "))
+                }
+            }
+            serde_json::Value::Array(items) => items.iter().map(name).collect::<Vec<_>>().join("|"),
+            other => other.to_string(),
+        }
+    }
+    serde_json::to_value(sync_type)
+        .map(|v| name(&v))
+        .unwrap_or_else(|_| format!("{sync_type:?}"))
+}
+
+fn applies_to(names: &[String], name: &str) -> bool {
+    names.is_empty() || names.iter().any(|n| n == name)
+}
+
+fn is_text(sync_type: &Type) -> bool {
+    matches!(
+        sync_type,
+        Type::Text | Type::Char { .. } | Type::VarChar { .. }
+    )
+}
+
+/// Whether values of `sync_type` can contain strings the redact stage scans.
+fn can_hold_text(sync_type: &Type) -> bool {
+    is_text(sync_type)
+        || matches!(
+            sync_type,
+            Type::Enum { .. }
+                | Type::Set { .. }
+                | Type::Array { .. }
+                | Type::Object
+                | Type::Json
+                | Type::Jsonb
+        )
+}
+
+fn command_note(argv: &[String]) -> String {
+    format!("command `{}` (may change any field)", argv.join(" "))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+tables:
+  - name: users
+    id:
+      type: text
+      generator:
+        type: uuid_v4
+    fields:
+      - name: email
+        type:
+          type: var_char
+          length: 255
+        nullable: true
+        generator:
+          type: pattern
+          pattern: "user_{index}@example.com"
+      - name: age
+        type: int
+        generator:
+          type: int_range
+          min: 18
+          max: 80
+      - name: notes
+        type: text
+        generator:
+          type: pattern
+          pattern: "note {index}"
+"#;
+
+    const TRANSFORMS: &str = r#"
+[[transforms]]
+type = "normalize_id"
+lowercase = true
+original_field = "source_id"
+
+[[transforms]]
+type = "redact"
+fields = ["email", "age"]
+
+[[transforms.patterns]]
+pattern = '@.*'
+replacement = "@example.com"
+"#;
+
+    #[test]
+    fn test_mapping_snapshot() {
+        let schema = Schema::from_yaml(SCHEMA).unwrap();
+        let mut filters = ColumnFilters::new();
+        filters.exclude("users", ["notes"]);
+        let transforms = surreal_sync_runtime::parse_transforms_toml(TRANSFORMS).unwrap();
+
+        let mapping = resolve_mapping(&schema, SourceKind::PostgreSQL, &filters, &transforms);
+        assert_eq!(
+            render_mapping(&mapping, MappingFormat::Markdown),
+            r#"| Table | Source column | Source type | Universal type | SurrealDB field | SurrealDB type | Transforms |
+|---|---|---|---|---|---|---|
+| users | id | TEXT | text | id | record<users> | normalize_id: lowercase |
+| users | id | TEXT | text | source_id | string | normalize_id: original id |
+| users | email | VARCHAR(255) | var_char(255) | email | option<string> | redact: /@.*/ → "@example.com" |
+| users | age | INTEGER | int | age | int | - |
+| users | notes | TEXT | text | - | - | excluded by column filters |
+"#
+        );
+
+        let html = render_mapping(&mapping, MappingFormat::Html);
+        assert!(html.contains(
+            "<tr><td>users</td><td>age</td><td>INTEGER</td><td>int</td><td>age</td><td>int</td><td>-</td></tr>"
+        ));
+        assert!(html.contains("<td>record&lt;users&gt;</td>"));
+        assert_eq!(
+            MappingFormat::from_path(Path::new("mapping.HTML")),
+            MappingFormat::Html
+        );
+    }
+}
@@ -4,7 +4,7 @@
 //! (or `Surreal2Sink`). This module is only for the surreal-sync CLI, which
 //! auto-detects SurrealDB v2 vs v3.
 
-use surreal_sync_runtime::{load_transforms_from_args, SinkConnect};
+use surreal_sync_runtime::{load_transforms_with_columns, SinkConnect};
 
 pub use surreal_sync_mysql::from_binlog::cli::{
     run_snapshot_signal, Commands, Pipeline, SnapshotModeArg, SyncArgs, SyncStrategy, TlsArgs,
@@ -17,8 +17,10 @@ use crate::from::{get_sdk_version, SdkVersion};
 pub async fn run_command(command: Commands) -> anyhow::Result<()> {
     match command {
         Commands::Sync(args) => {
-            let (pipeline, apply_opts) =
-                load_transforms_from_args(args.transforms_config.as_deref())?;
+            let (pipeline, apply_opts) = load_transforms_with_columns(
+                args.transforms_config.as_deref(),
+                &args.columns.to_filters()?,
+            )?;
             run_sync_autodetect(*args, pipeline, apply_opts).await
         }
         Commands::Snapshot(args) => run_snapshot_signal(args).await,
@@ -47,6 +47,8 @@ pub async fn run_full_sync_e2e(
             "all_types_users.metadata=settings.notifications".to_string()
         ]),
         id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
         ssl: Default::default(),
     };
 
@@ -124,6 +126,8 @@ pub async fn run_incremental_e2e(
         tables: vec![],
         mysql_boolean_paths: Some(vec!["all_types_posts.post_categories".to_string()]),
         id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
         ssl: Default::default(),
     };
 
@@ -0,0 +1,189 @@
+//! Kafka message headers mapped into record fields via `--header-field`.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use surreal_sync::testing::generate_test_id;
+use surreal_sync_core::SurrealSink;
+use surreal_sync_core::{Change, Relation, Row, Value};
+use surreal_sync_kafka::from_kafka::{Config as KafkaConfig, MissingHeader};
+use surreal_sync_kafka::producer::container::KafkaContainer;
+use surreal_sync_kafka::producer::{
+    KafkaTestProducer, UserMessage, UserMetadata, UserPreferences, UserSettings,
+};
+use tokio::time::sleep;
+
+struct CaptureSink {
+    changes: Mutex<Vec<Change>>,
+}
+
+#[async_trait::async_trait]
+impl SurrealSink for CaptureSink {
+    async fn write_rows(&self, rows: &[Row]) -> anyhow::Result<()> {
+        let mut changes = self.changes.lock().expect("lock");
+        for row in rows {
+            changes.push(Change::update(
+                row.table.clone(),
+                row.id.clone(),
+                row.fields.clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn write_relations(&self, _relations: &[Relation]) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn apply_change(&self, change: &Change) -> anyhow::Result<()> {
+        self.changes.lock().expect("lock").push(change.clone());
+        Ok(())
+    }
+
+    async fn apply_relation_change(
+        &self,
+        _change: &surreal_sync_core::RelationChange,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+fn test_user(id: &str, name: &str) -> UserMessage {
+    UserMessage {
+        id: id.to_string(),
+        account_balance: 10.5,
+        metadata: UserMetadata {
+            preferences: UserPreferences {
+                theme: "dark".to_string(),
+                language: "en".to_string(),
+            },
+            tags: vec!["tagged".to_string()],
+            settings: UserSettings {
+                notifications: true,
+                privacy: "strict".to_string(),
+            },
+        },
+        validation_logic: "function validate() { return true; }".to_string(),
+        reference_id: "507f1f77bcf86cd799439011".to_string(),
+        name: name.to_string(),
+        email: format!("{id}@example.com"),
+        age: 30,
+        active: true,
+        created_at: Utc::now(),
+        score: 1.0,
+    }
+}
+
+#[tokio::test]
+async fn kafka_headers_become_record_fields() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter("surreal_sync_kafka=info")
+        .try_init()
+        .ok();
+
+    let test_id = generate_test_id();
+
+    let mut kafka = KafkaContainer::new(&format!("test-kafka-hdr-{test_id}"));
+    kafka.start()?;
+    kafka.wait_until_ready(30).await?;
+    let kafka_broker = &kafka.broker_address;
+
+    let topic = format!("test-users-hdr-{test_id}");
+    let producer = KafkaTestProducer::new(kafka_broker).await?;
+    producer.create_topic_if_not_exists(&topic, 1).await?;
+    sleep(Duration::from_millis(500)).await;
+
+    producer
+        .publish_user_with_headers(
+            &topic,
+            &test_user("user_001", "Alice"),
+            &[("tenant-id", b"acme"), ("schema-version", b"3")],
+        )
+        .await?;
+    // No schema-version header: the field is nulled per --missing-header.
+    producer
+        .publish_user_with_headers(
+            &topic,
+            &test_user("user_002", "Bob"),
+            &[("tenant-id", b"globex")],
+        )
+        .await?;
+    sleep(Duration::from_millis(200)).await;
+
+    let proto_dir = tempfile::tempdir()?;
+    let user_proto_path = proto_dir.path().join("user.proto");
+    std::fs::write(
+        &user_proto_path,
+        include_str!("../../crates/kafka/proto/user.proto"),
+    )?;
+
+    let config = KafkaConfig {
+        proto_path: user_proto_path.to_string_lossy().to_string(),
+        brokers: vec![kafka_broker.to_string()],
+        group_id: format!("test-group-hdr-{test_id}"),
+        topic: topic.clone(),
+        message_type: "User".to_string(),
+        payload_encoding: Default::default(),
+        schema_registry_url: None,
+        buffer_size: 1000,
+        session_timeout_ms: "6000".to_string(),
+        num_consumers: 1,
+        auto_offset_reset: Default::default(),
+        partitions: Vec::new(),
+        kafka_batch_size: 100,
+        decode_workers: 1,
+        table_name: Some("people".to_string()),
+        use_message_key_as_id: false,
+        id_field: "id".to_string(),
+        id_columns: Vec::new(),
+        header_fields: vec![
+            "tenant-id=tenant_id".parse()?,
+            "schema-version=schema_version:int".parse()?,
+        ],
+        missing_header: MissingHeader::Null,
+        max_messages: Some(2),
+        sasl_username: None,
+        sasl_password: None,
+        sasl_mechanism: None,
+        security_protocol: None,
+        ssl_ca_location: None,
+        ssl_certificate_location: None,
+        ssl_key_location: None,
+        ssl_key_password: None,
+    };
+
+    let sink = Arc::new(CaptureSink {
+        changes: Mutex::new(Vec::new()),
+    });
+    let deadline = Utc::now() + chrono::Duration::seconds(20);
+    surreal_sync_kafka::from_kafka::run_incremental_sync(sink.clone(), config, deadline, None)
+        .await?;
+
+    let changes = sink.changes.lock().expect("lock").clone();
+    assert_eq!(changes.len(), 2, "expected two changes, got {changes:?}");
+    let fields_of = |name: &str| {
+        changes
+            .iter()
+            .filter_map(|c| c.fields.as_ref())
+            .find(|f| f.get("name") == Some(&Value::Text(name.to_string())))
+            .cloned()
+            .unwrap_or_else(|| panic!("no change for {name}: {changes:?}"))
+    };
+
+    let alice = fields_of("Alice");
+    assert_eq!(
+        alice.get("tenant_id"),
+        Some(&Value::Text("acme".to_string()))
+    );
+    assert_eq!(alice.get("schema_version"), Some(&Value::Int64(3)));
+
+    let bob = fields_of("Bob");
+    assert_eq!(
+        bob.get("tenant_id"),
+        Some(&Value::Text("globex".to_string()))
+    );
+    assert_eq!(bob.get("schema_version"), Some(&Value::Null));
+
+    Ok(())
+}
@@ -119,14 +119,21 @@ async fn test_kafka_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
         group_id: format!("test-group-users-{test_id}"),
         topic: users_topic.clone(),
         message_type: "User".to_string(),
+        payload_encoding: Default::default(),
+        schema_registry_url: None,
         buffer_size: 1000,
         session_timeout_ms: "6000".to_string(),
         num_consumers: 1,
+        auto_offset_reset: Default::default(),
+        partitions: Vec::new(),
         kafka_batch_size: 100,
+        decode_workers: 1,
         table_name: Some("all_types_users".to_string()),
         use_message_key_as_id: false,
         id_field: "id".to_string(),
         id_columns: Vec::new(),
+        header_fields: Vec::new(),
+        missing_header: Default::default(),
         max_messages: None,
         sasl_username: None,
         sasl_password: None,
@@ -180,14 +187,21 @@ async fn test_kafka_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
                 group_id: format!("test-group-posts-{test_id}"),
                 topic: posts_topic.clone(),
                 message_type: "Post".to_string(),
+                payload_encoding: Default::default(),
+                schema_registry_url: None,
                 buffer_size: 1000,
                 session_timeout_ms: "6000".to_string(),
                 num_consumers: 1,
+                auto_offset_reset: Default::default(),
+                partitions: Vec::new(),
                 kafka_batch_size: 100,
+                decode_workers: 1,
                 table_name: Some("all_types_posts".to_string()),
                 use_message_key_as_id: false,
                 id_field: "id".to_string(),
                 id_columns: Vec::new(),
+                header_fields: Vec::new(),
+                missing_header: Default::default(),
                 max_messages: None,
                 sasl_username: None,
                 sasl_password: None,
@@ -233,14 +247,21 @@ async fn test_kafka_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
                 group_id: format!("test-group-relations-{test_id}"),
                 topic: relations_topic.clone(),
                 message_type: "UserPostRelation".to_string(),
+                payload_encoding: Default::default(),
+                schema_registry_url: None,
                 buffer_size: 1000,
                 session_timeout_ms: "6000".to_string(),
                 num_consumers: 1,
+                auto_offset_reset: Default::default(),
+                partitions: Vec::new(),
                 kafka_batch_size: 100,
+                decode_workers: 1,
                 table_name: Some("authored_by".to_string()),
                 use_message_key_as_id: false,
                 id_field: "id".to_string(),
                 id_columns: Vec::new(),
+                header_fields: Vec::new(),
+                missing_header: Default::default(),
                 max_messages: None,
                 sasl_username: None,
                 sasl_password: None,
@@ -309,14 +330,21 @@ async fn test_kafka_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
                 group_id: format!("test-group-posts-{test_id}"),
                 topic: posts_topic.clone(),
                 message_type: "Post".to_string(),
+                payload_encoding: Default::default(),
+                schema_registry_url: None,
                 buffer_size: 1000,
                 session_timeout_ms: "6000".to_string(),
                 num_consumers: 1,
+                auto_offset_reset: Default::default(),
+                partitions: Vec::new(),
                 kafka_batch_size: 100,
+                decode_workers: 1,
                 table_name: Some("all_types_posts".to_string()),
                 use_message_key_as_id: false,
                 id_field: "id".to_string(),
                 id_columns: Vec::new(),
+                header_fields: Vec::new(),
+                missing_header: Default::default(),
                 max_messages: None,
                 sasl_username: None,
                 sasl_password: None,
@@ -362,14 +390,21 @@ async fn test_kafka_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
                 group_id: format!("test-group-relations-{test_id}"),
                 topic: relations_topic.clone(),
                 message_type: "UserPostRelation".to_string(),
+                payload_encoding: Default::default(),
+                schema_registry_url: None,
                 buffer_size: 1000,
                 session_timeout_ms: "6000".to_string(),
                 num_consumers: 1,
+                auto_offset_reset: Default::default(),
+                partitions: Vec::new(),
                 kafka_batch_size: 100,
+                decode_workers: 1,
                 table_name: Some("authored_by".to_string()),
                 use_message_key_as_id: false,
                 id_field: "id".to_string(),
                 id_columns: Vec::new(),
+                header_fields: Vec::new(),
+                missing_header: Default::default(),
                 max_messages: None,
                 sasl_username: None,
                 sasl_password: None,
@@ -0,0 +1,132 @@
+//! JSON-encoded Kafka payloads (`--payload-encoding json`) synced to SurrealDB.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use surreal_sync::testing::surreal::{cleanup_auto, connect_auto, SurrealConnection};
+use surreal_sync::testing::{generate_test_id, TestConfig};
+use surreal_sync_kafka::from_kafka::{Config as KafkaConfig, PayloadEncoding};
+use surreal_sync_kafka::producer::container::KafkaContainer;
+use surreal_sync_kafka::producer::KafkaTestProducer;
+use tokio::time::sleep;
+
+const TABLE: &str = "json_people";
+
+async fn person(
+    conn: &SurrealConnection,
+    id: &str,
+) -> Result<(Option<String>, Option<i64>), Box<dyn std::error::Error>> {
+    let query = format!("SELECT name, age FROM {TABLE}:{id}");
+    let (name, age) = match conn {
+        SurrealConnection::V2(client) => {
+            let mut response = client.query(query).await?;
+            (response.take((0, "name"))?, response.take((0, "age"))?)
+        }
+        SurrealConnection::V3(client) => {
+            let mut response = client.query(query).await?;
+            (response.take((0, "name"))?, response.take((0, "age"))?)
+        }
+    };
+    Ok((name, age))
+}
+
+#[tokio::test]
+async fn kafka_json_payloads_sync_to_surrealdb() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter("surreal_sync_kafka=info")
+        .try_init()
+        .ok();
+
+    let surrealdb = surreal_sync::testing::shared_containers::shared_surrealdb();
+    let test_id = generate_test_id();
+
+    let mut kafka = KafkaContainer::new(&format!("test-kafka-json-{test_id}"));
+    kafka.start()?;
+    kafka.wait_until_ready(30).await?;
+    let kafka_broker = &kafka.broker_address;
+
+    let topic = format!("test-people-json-{test_id}");
+    let producer = KafkaTestProducer::new(kafka_broker).await?;
+    producer.create_topic_if_not_exists(&topic, 1).await?;
+    sleep(Duration::from_millis(500)).await;
+
+    producer
+        .publish_json(
+            &topic,
+            "person_001",
+            &serde_json::json!({ "id": "person_001", "name": "Alice", "age": 30 }),
+        )
+        .await?;
+    producer
+        .publish_json(
+            &topic,
+            "person_002",
+            &serde_json::json!({ "id": "person_002", "name": "Bob", "age": 41 }),
+        )
+        .await?;
+    sleep(Duration::from_millis(200)).await;
+
+    let surreal_config = TestConfig::with_surreal_endpoint(test_id, &surrealdb.ws_endpoint());
+    let conn = connect_auto(&surreal_config).await?;
+    cleanup_auto(&conn, &[TABLE]).await?;
+
+    // No --proto-path / --message-type: JSON payloads need no schema.
+    let config = KafkaConfig {
+        proto_path: String::new(),
+        brokers: vec![kafka_broker.to_string()],
+        group_id: format!("test-group-json-{test_id}"),
+        topic: topic.clone(),
+        message_type: String::new(),
+        payload_encoding: PayloadEncoding::Json,
+        schema_registry_url: None,
+        buffer_size: 1000,
+        session_timeout_ms: "6000".to_string(),
+        num_consumers: 1,
+        auto_offset_reset: Default::default(),
+        partitions: Vec::new(),
+        kafka_batch_size: 100,
+        decode_workers: 1,
+        table_name: Some(TABLE.to_string()),
+        use_message_key_as_id: false,
+        id_field: "id".to_string(),
+        id_columns: Vec::new(),
+        header_fields: Vec::new(),
+        missing_header: Default::default(),
+        max_messages: Some(2),
+        sasl_username: None,
+        sasl_password: None,
+        sasl_mechanism: None,
+        security_protocol: None,
+        ssl_ca_location: None,
+        ssl_certificate_location: None,
+        ssl_key_location: None,
+        ssl_key_password: None,
+    };
+
+    let deadline = Utc::now() + chrono::Duration::seconds(20);
+    match &conn {
+        SurrealConnection::V2(client) => {
+            let sink = Arc::new(surreal_sync_surreal::v2::Surreal2Sink::new(client.clone()));
+            surreal_sync_kafka::from_kafka::run_incremental_sync(sink, config, deadline, None)
+                .await?;
+        }
+        SurrealConnection::V3(client) => {
+            let sink = Arc::new(surreal_sync_surreal::v3::Surreal3Sink::new(client.clone()));
+            surreal_sync_kafka::from_kafka::run_incremental_sync(sink, config, deadline, None)
+                .await?;
+        }
+    }
+
+    assert_eq!(
+        person(&conn, "person_001").await?,
+        (Some("Alice".to_string()), Some(30))
+    );
+    assert_eq!(
+        person(&conn, "person_002").await?,
+        (Some("Bob".to_string()), Some(41))
+    );
+
+    cleanup_auto(&conn, &[TABLE]).await?;
+    Ok(())
+}
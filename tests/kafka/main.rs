@@ -3,7 +3,10 @@
 //! Tests for Kafka incremental sync functionality. Kafka is a streaming-only source
 //! that does not require full sync or checkpoint management.
 
+mod headers_lib;
 mod incremental_sync_lib;
+mod json_payload_lib;
 mod kafka_transforms_config_cli;
+mod resume_from_group_offsets;
 mod sasl_ssl_mtls_sync;
 mod transforms_lib;
@@ -78,14 +78,21 @@ async fn test_kafka_sasl_ssl_mtls_sync() -> Result<(), Box<dyn std::error::Error
         group_id: format!("test-group-sasl-ssl-{test_id}"),
         topic: users_topic,
         message_type: "User".to_string(),
+        payload_encoding: Default::default(),
+        schema_registry_url: None,
         buffer_size: 1000,
         session_timeout_ms: "6000".to_string(),
         num_consumers: 1,
+        auto_offset_reset: Default::default(),
+        partitions: Vec::new(),
         kafka_batch_size: 100,
+        decode_workers: 1,
         table_name: Some("all_types_users".to_string()),
         use_message_key_as_id: false,
         id_field: "id".to_string(),
         id_columns: Vec::new(),
+        header_fields: Vec::new(),
+        missing_header: Default::default(),
         max_messages: None,
         sasl_username: Some(secrets.sasl_username.clone()),
         sasl_password: Some(secrets.sasl_password.clone()),
@@ -134,14 +134,21 @@ async fn kafka_external_mutate_rewrites_name_through_source_driver(
         group_id: format!("test-group-xf-{test_id}"),
         topic: topic.clone(),
         message_type: "User".to_string(),
+        payload_encoding: Default::default(),
+        schema_registry_url: None,
         buffer_size: 1000,
         session_timeout_ms: "6000".to_string(),
         num_consumers: 1,
+        auto_offset_reset: Default::default(),
+        partitions: Vec::new(),
         kafka_batch_size: 100,
+        decode_workers: 1,
         table_name: Some("people".to_string()),
         use_message_key_as_id: false,
         id_field: "id".to_string(),
         id_columns: Vec::new(),
+        header_fields: Vec::new(),
+        missing_header: Default::default(),
         max_messages: Some(2),
         sasl_username: None,
         sasl_password: None,
@@ -8,7 +8,7 @@
 //! 5. Clean up all test data
 
 use loadtest_populate_csv::CSVPopulator;
-use surreal_sync::csv::{sync, Config, FileSource};
+use surreal_sync::csv::{sync, Config, CsvDialect, FileSource};
 use surreal_sync::testing::surreal::{connect_auto, SurrealConnection};
 use surreal_sync::testing::{generate_test_id, TestConfig};
 use surreal_sync_core::Schema;
@@ -24,6 +24,17 @@ const BATCH_SIZE: usize = 10;
 /// alignment between the sync's ID parsing and the verifier's expectations.
 #[tokio::test]
 async fn test_csv_loadtest_small_scale() -> Result<(), Box<dyn std::error::Error>> {
+    run_csv_loadtest(CsvDialect::default()).await
+}
+
+/// Same workflow with header-less TSV files, whose columns map to the schema
+/// fields in order.
+#[tokio::test]
+async fn test_csv_loadtest_tsv_without_headers() -> Result<(), Box<dyn std::error::Error>> {
+    run_csv_loadtest(CsvDialect::tsv().with_headers(false)).await
+}
+
+async fn run_csv_loadtest(dialect: CsvDialect) -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing for debug output
     tracing_subscriber::fmt()
         .with_env_filter("surreal_sync=info,loadtest=info")
@@ -70,7 +81,7 @@ async fn test_csv_loadtest_small_scale() -> Result<(), Box<dyn std::error::Error
 
     // Create a fresh populator for each table so sequential IDs start from the configured start value
     for table_name in &table_names {
-        let mut populator = CSVPopulator::new(schema.clone(), SEED);
+        let mut populator = CSVPopulator::new(schema.clone(), SEED).with_dialect(dialect);
         let output_path = temp_dir.path().join(format!("{table_name}.csv"));
         let metrics = populator.populate(table_name, &output_path, crate::common::row_count())?;
         tracing::info!(
@@ -93,10 +104,11 @@ async fn test_csv_loadtest_small_scale() -> Result<(), Box<dyn std::error::Error
             files: vec![],
             s3_uris: vec![],
             http_uris: vec![],
+            s3_credentials: Default::default(),
+            http_options: Default::default(),
             table: table_name.clone(),
             batch_size: BATCH_SIZE,
-            has_headers: true,
-            delimiter: b',',
+            dialect,
             id_field: Some("id".to_string()),
             id_columns: Vec::new(),
             column_names: None,
@@ -304,10 +316,11 @@ async fn test_csv_debug_field_extraction() -> Result<(), Box<dyn std::error::Err
         files: vec![],
         s3_uris: vec![],
         http_uris: vec![],
+        s3_credentials: Default::default(),
+        http_options: Default::default(),
         table: "users".to_string(),
         batch_size: BATCH_SIZE,
-        has_headers: true,
-        delimiter: b',',
+        dialect: CsvDialect::default(),
         id_field: Some("id".to_string()),
         id_columns: Vec::new(),
         column_names: None,
@@ -8,7 +8,7 @@
 //! 5. Clean up all test data
 
 use loadtest_populate_jsonl::JsonlPopulator;
-use surreal_sync::jsonl::{sync, Config, FileSource};
+use surreal_sync::jsonl::{sync, Config, FileSource, OnError};
 use surreal_sync::testing::surreal::{connect_auto, SurrealConnection};
 use surreal_sync::testing::{generate_test_id, TestConfig};
 use surreal_sync_core::Schema;
@@ -93,12 +93,15 @@ async fn test_jsonl_loadtest_small_scale() -> Result<(), Box<dyn std::error::Err
             files: vec![],
             s3_uris: vec![],
             http_uris: vec![],
+            s3_credentials: Default::default(),
+            http_options: Default::default(),
             id_field: "id".to_string(),
             id_columns: Vec::new(),
             conversion_rules: vec![],
             batch_size: BATCH_SIZE,
             dry_run: false,
             schema: Some(schema.to_database_schema()), // Pass schema for type-aware conversion
+            on_error: OnError::Abort,
         };
 
         match &conn {
@@ -174,14 +174,21 @@ async fn test_kafka_loadtest_small_scale() -> Result<(), Box<dyn std::error::Err
                     group_id: format!("loadtest-{table_name}-{test_id}"),
                     topic: topic_name.clone(),
                     message_type,
+                    payload_encoding: Default::default(),
+                    schema_registry_url: None,
                     buffer_size: 1000,
                     session_timeout_ms: "6000".to_string(),
                     num_consumers: 1,
+                    auto_offset_reset: Default::default(),
+                    partitions: Vec::new(),
                     kafka_batch_size: BATCH_SIZE,
+                    decode_workers: 1,
                     table_name: Some(table_name.clone()),
                     use_message_key_as_id: false,
                     id_field: "id".to_string(),
                     id_columns: Vec::new(),
+                    header_fields: Vec::new(),
+                    missing_header: Default::default(),
                     max_messages: None,
                     sasl_username: None,
                     sasl_password: None,
@@ -308,14 +315,21 @@ async fn test_kafka_loadtest_small_scale() -> Result<(), Box<dyn std::error::Err
                     group_id: format!("loadtest-{table_name}-{test_id}"),
                     topic: topic_name.clone(),
                     message_type,
+                    payload_encoding: Default::default(),
+                    schema_registry_url: None,
                     buffer_size: 1000,
                     session_timeout_ms: "6000".to_string(),
                     num_consumers: 1,
+                    auto_offset_reset: Default::default(),
+                    partitions: Vec::new(),
                     kafka_batch_size: BATCH_SIZE,
+                    decode_workers: 1,
                     table_name: Some(table_name.clone()),
                     use_message_key_as_id: false,
                     id_field: "id".to_string(),
                     id_columns: Vec::new(),
+                    header_fields: Vec::new(),
+                    missing_header: Default::default(),
                     max_messages: None,
                     sasl_username: None,
                     sasl_password: None,
@@ -90,12 +90,20 @@ async fn test_mongodb_incremental_loadtest_small_scale() -> Result<(), Box<dyn s
         source_uri: mongodb_uri.clone(),
         source_database: Some(mongodb_database.clone()),
         collections: vec![],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
     };
 
     let sync_opts = surreal_sync_mongodb_changestream_source::SyncOpts {
         batch_size: BATCH_SIZE,
         dry_run: false,
         schema: None,
+        conversion_workers: 1,
+        consistent_snapshot: false,
     };
 
     // Create sync manager with filesystem checkpoint store
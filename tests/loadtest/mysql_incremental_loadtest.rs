@@ -101,6 +101,8 @@ async fn test_mysql_incremental_loadtest_small_scale() -> Result<(), Box<dyn std
         tables: vec![],
         mysql_boolean_paths: None,
         id_column_overrides: Default::default(),
+        keyset_columns: Default::default(),
+        column_filters: Default::default(),
         ssl: Default::default(),
     };
 
@@ -123,6 +123,8 @@ async fn test_neo4j_loadtest_small_scale() -> Result<(), Box<dyn std::error::Err
         source_username: Some(container.username.clone()),
         source_password: Some(container.password.clone()),
         labels: vec![],
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: "UTC".to_string(),
         // Tell Neo4j sync to parse these properties as JSON objects (stored as strings in Neo4j)
         neo4j_json_properties: Some(vec!["products.metadata".to_string()]),
@@ -131,11 +133,13 @@ async fn test_neo4j_loadtest_small_scale() -> Result<(), Box<dyn std::error::Err
         allow_empty_tracking_timestamp: false,
         id_property: "id".to_string(),
         composite_constituent: None,
+        relation_table_map: Default::default(),
     };
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: BATCH_SIZE,
         dry_run: false,
+        concurrency: 1,
     };
 
     // Create version-appropriate sink and run sync
@@ -112,7 +112,11 @@ async fn test_postgresql_loadtest_small_scale() -> Result<(), Box<dyn std::error
         source_uri: pg_conn_string.clone(),
         source_database: Some("public".to_string()),
         tables: vec![],
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
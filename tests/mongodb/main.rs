@@ -1,6 +1,8 @@
+mod mongodb_collection_filter_lib;
 mod mongodb_full_sync_only_cli;
 mod mongodb_full_sync_only_lib;
 mod mongodb_incremental_sync_only_cli;
 mod mongodb_incremental_sync_only_lib;
+mod mongodb_resume_lib;
 mod mongodb_transforms_config_cli;
 mod mongodb_transforms_lib;
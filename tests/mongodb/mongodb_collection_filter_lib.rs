@@ -0,0 +1,124 @@
+//! MongoDB full sync collection include/exclude filtering E2E test
+
+use mongodb::bson::doc;
+use surreal_sync::testing::surreal::{cleanup_auto, connect_auto, SurrealConnection};
+use surreal_sync::testing::{generate_test_id, TestConfig};
+use surreal_sync_mongodb_changestream_source::{SourceOpts, SyncOpts};
+
+const COLLECTIONS: [&str; 3] = ["users", "orders", "analytics"];
+
+async fn count_records(
+    conn: &SurrealConnection,
+    table: &str,
+) -> Result<i64, Box<dyn std::error::Error>> {
+    let query = format!("SELECT count() FROM {table} GROUP ALL");
+    let count: Option<i64> = match conn {
+        SurrealConnection::V2(client) => client.query(query).await?.take((0, "count"))?,
+        SurrealConnection::V3(client) => client.query(query).await?.take((0, "count"))?,
+    };
+    Ok(count.unwrap_or(0))
+}
+
+async fn full_sync(
+    conn: &SurrealConnection,
+    source_opts: SourceOpts,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sync_opts = SyncOpts {
+        batch_size: 100,
+        dry_run: false,
+        schema: None,
+        conversion_workers: 1,
+        consistent_snapshot: false,
+    };
+    match conn {
+        SurrealConnection::V2(client) => {
+            let sink = surreal_sync_surreal::v2::Surreal2Sink::new(client.clone());
+            surreal_sync_mongodb_changestream_source::migrate_from_mongodb(
+                &sink,
+                source_opts,
+                sync_opts,
+            )
+            .await?;
+        }
+        SurrealConnection::V3(client) => {
+            let sink = surreal_sync_surreal::v3::Surreal3Sink::new(client.clone());
+            surreal_sync_mongodb_changestream_source::migrate_from_mongodb(
+                &sink,
+                source_opts,
+                sync_opts,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mongodb_full_sync_collection_filter() -> Result<(), Box<dyn std::error::Error>> {
+    let surrealdb = surreal_sync::testing::shared_containers::shared_surrealdb();
+    let container = surreal_sync::testing::shared_containers::shared_mongodb().await;
+    let test_id = generate_test_id();
+
+    let client =
+        surreal_sync::testing::mongodb::connect_mongodb(&container.connection_uri()).await?;
+    let mongodb_database = format!("test_filter_{test_id}");
+    let db = client.database(&mongodb_database);
+    for name in COLLECTIONS {
+        let collection = db.collection::<mongodb::bson::Document>(name);
+        collection.drop().await.ok();
+        collection
+            .insert_many(vec![
+                doc! { "_id": "1", "name": format!("{name}-1") },
+                doc! { "_id": "2", "name": format!("{name}-2") },
+            ])
+            .await?;
+    }
+
+    let surreal_config = TestConfig::with_surreal_endpoint(test_id, &surrealdb.ws_endpoint());
+    let conn = connect_auto(&surreal_config).await?;
+
+    let source_opts = SourceOpts {
+        source_uri: container.connection_uri(),
+        source_database: Some(mongodb_database.clone()),
+        collections: vec![],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
+    };
+
+    // Exclude only: everything but `analytics`
+    cleanup_auto(&conn, &COLLECTIONS).await?;
+    full_sync(
+        &conn,
+        SourceOpts {
+            exclude_collections: vec!["analytics".to_string()],
+            ..source_opts.clone()
+        },
+    )
+    .await?;
+    assert_eq!(count_records(&conn, "users").await?, 2);
+    assert_eq!(count_records(&conn, "orders").await?, 2);
+    assert_eq!(count_records(&conn, "analytics").await?, 0);
+
+    // Include wins over unlisted collections, exclude wins over include
+    cleanup_auto(&conn, &COLLECTIONS).await?;
+    full_sync(
+        &conn,
+        SourceOpts {
+            include_collections: vec!["users".to_string(), "analytics".to_string()],
+            exclude_collections: vec!["analytics".to_string()],
+            ..source_opts
+        },
+    )
+    .await?;
+    assert_eq!(count_records(&conn, "users").await?, 2);
+    assert_eq!(count_records(&conn, "orders").await?, 0);
+    assert_eq!(count_records(&conn, "analytics").await?, 0);
+
+    cleanup_auto(&conn, &COLLECTIONS).await?;
+    db.drop().await?;
+    Ok(())
+}
@@ -12,6 +12,21 @@ use surreal_sync::testing::{
 
 #[tokio::test]
 async fn test_mongodb_full_sync_lib() -> Result<(), Box<dyn std::error::Error>> {
+    run_full_sync_lib(1000, 1).await
+}
+
+/// One document per batch across several conversion workers, so later
+/// batches can finish converting before earlier ones.
+#[tokio::test]
+async fn test_mongodb_full_sync_lib_parallel_conversion() -> Result<(), Box<dyn std::error::Error>>
+{
+    run_full_sync_lib(1, 4).await
+}
+
+async fn run_full_sync_lib(
+    batch_size: usize,
+    conversion_workers: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing for debug output
     tracing_subscriber::fmt()
         .with_env_filter("surreal_sync=debug")
@@ -44,12 +59,20 @@ async fn test_mongodb_full_sync_lib() -> Result<(), Box<dyn std::error::Error>>
         source_uri: container.connection_uri(),
         source_database: Some(mongodb_database.clone()),
         collections: vec![],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
     };
 
     let sync_opts = surreal_sync_mongodb_changestream_source::SyncOpts {
-        batch_size: 1000,
+        batch_size,
         dry_run: false,
         schema: None,
+        conversion_workers,
+        consistent_snapshot: false,
     };
 
     // Execute full sync with appropriate sink based on detected version
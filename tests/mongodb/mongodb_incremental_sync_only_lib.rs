@@ -47,12 +47,20 @@ async fn test_mongodb_incremental_sync_lib() -> Result<(), Box<dyn std::error::E
         source_uri: container.connection_uri(),
         source_database: Some(mongodb_database.clone()),
         collections: vec![],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
     };
 
     let sync_opts = surreal_sync_mongodb_changestream_source::SyncOpts {
         batch_size: 1000,
         dry_run: false,
         schema: None,
+        conversion_workers: 1,
+        consistent_snapshot: false,
     };
 
     // Run full sync on empty collection to establish baseline checkpoint
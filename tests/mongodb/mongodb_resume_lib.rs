@@ -0,0 +1,202 @@
+//! MongoDB incremental sync resume E2E test
+//!
+//! Stops a change stream part-way, then resumes it from the stored resume
+//! token and checks that already-applied events are not processed again.
+
+use futures::StreamExt;
+use mongodb::bson::{doc, Document};
+use mongodb::options::ChangeStreamOptions;
+use surreal_sync::testing::surreal::{cleanup_auto, connect_auto, SurrealConnection};
+use surreal_sync::testing::{generate_test_id, TestConfig};
+use surreal_sync_mongodb_changestream_source::{
+    get_current_checkpoint, MongoDBCheckpoint, SourceOpts,
+};
+
+const TABLE: &str = "events";
+
+async fn count_named(
+    conn: &SurrealConnection,
+    name: &str,
+) -> Result<i64, Box<dyn std::error::Error>> {
+    let query = format!("SELECT count() FROM {TABLE} WHERE name = '{name}' GROUP ALL");
+    let count: Option<i64> = match conn {
+        SurrealConnection::V2(client) => client.query(query).await?.take((0, "count"))?,
+        SurrealConnection::V3(client) => client.query(query).await?.take((0, "count"))?,
+    };
+    Ok(count.unwrap_or(0))
+}
+
+async fn delete_named(
+    conn: &SurrealConnection,
+    name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let query = format!("DELETE {TABLE} WHERE name = '{name}'");
+    match conn {
+        SurrealConnection::V2(client) => {
+            client.query(query).await?.check()?;
+        }
+        SurrealConnection::V3(client) => {
+            client.query(query).await?.check()?;
+        }
+    }
+    Ok(())
+}
+
+async fn incremental_sync(
+    conn: &SurrealConnection,
+    source_opts: SourceOpts,
+    from: MongoDBCheckpoint,
+    until: Option<MongoDBCheckpoint>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let deadline = chrono::Utc::now() + chrono::Duration::seconds(30);
+    match conn {
+        SurrealConnection::V2(client) => {
+            let sink = surreal_sync_surreal::v2::Surreal2Sink::new(client.clone());
+            surreal_sync_mongodb_changestream_source::run_incremental_sync(
+                &sink,
+                source_opts,
+                from,
+                deadline,
+                until,
+            )
+            .await?;
+        }
+        SurrealConnection::V3(client) => {
+            let sink = surreal_sync_surreal::v3::Surreal3Sink::new(client.clone());
+            surreal_sync_mongodb_changestream_source::run_incremental_sync(
+                &sink,
+                source_opts,
+                from,
+                deadline,
+                until,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mongodb_incremental_sync_resumes_from_token() -> Result<(), Box<dyn std::error::Error>>
+{
+    let surrealdb = surreal_sync::testing::shared_containers::shared_surrealdb();
+    let container = surreal_sync::testing::shared_containers::shared_mongodb().await;
+    let test_id = generate_test_id();
+
+    let client =
+        surreal_sync::testing::mongodb::connect_mongodb(&container.connection_uri()).await?;
+    let mongodb_database = format!("test_resume_{test_id}");
+    let db = client.database(&mongodb_database);
+    let collection = db.collection::<Document>(TABLE);
+    collection.drop().await.ok();
+    db.create_collection(TABLE).await?;
+
+    let surreal_config = TestConfig::with_surreal_endpoint(test_id, &surrealdb.ws_endpoint());
+    let conn = connect_auto(&surreal_config).await?;
+    cleanup_auto(&conn, &[TABLE]).await?;
+
+    let source_opts = SourceOpts {
+        source_uri: container.connection_uri(),
+        source_database: Some(mongodb_database.clone()),
+        collections: vec![],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
+    };
+
+    let start = get_current_checkpoint(&client, &mongodb_database).await?;
+    for name in ["a", "b", "c"] {
+        collection
+            .insert_one(doc! { "_id": name, "name": name })
+            .await?;
+    }
+
+    // Resume token of the "b" insert: the point where the first run stops
+    let mut options = ChangeStreamOptions::default();
+    options.resume_after = Some(mongodb::bson::from_slice(&start.resume_token)?);
+    let mut stream = db.watch().with_options(options).await?;
+    let mut tokens = Vec::new();
+    while tokens.len() < 2 {
+        let event = stream.next().await.ok_or("change stream ended")??;
+        tokens.push(mongodb::bson::to_vec(&event.id)?);
+    }
+    drop(stream);
+    let stopped_at = MongoDBCheckpoint {
+        resume_token: tokens[1].clone(),
+        timestamp: chrono::Utc::now(),
+    };
+
+    // First run is "killed" right after applying "b"
+    incremental_sync(&conn, source_opts.clone(), start, Some(stopped_at.clone())).await?;
+    assert_eq!(count_named(&conn, "a").await?, 1);
+    assert_eq!(count_named(&conn, "b").await?, 1);
+    assert_eq!(count_named(&conn, "c").await?, 0);
+
+    // Replaying "a" or "b" after the restart would bring these back
+    delete_named(&conn, "a").await?;
+    delete_named(&conn, "b").await?;
+    collection
+        .insert_one(doc! { "_id": "d", "name": "d" })
+        .await?;
+
+    incremental_sync(&conn, source_opts, stopped_at, None).await?;
+    assert_eq!(count_named(&conn, "a").await?, 0);
+    assert_eq!(count_named(&conn, "b").await?, 0);
+    assert_eq!(count_named(&conn, "c").await?, 1);
+    assert_eq!(count_named(&conn, "d").await?, 1);
+
+    cleanup_auto(&conn, &[TABLE]).await?;
+    db.drop().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mongodb_incremental_sync_falls_back_to_timestamp(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let surrealdb = surreal_sync::testing::shared_containers::shared_surrealdb();
+    let container = surreal_sync::testing::shared_containers::shared_mongodb().await;
+    let test_id = generate_test_id();
+
+    let client =
+        surreal_sync::testing::mongodb::connect_mongodb(&container.connection_uri()).await?;
+    let mongodb_database = format!("test_resume_fallback_{test_id}");
+    let db = client.database(&mongodb_database);
+    let collection = db.collection::<Document>(TABLE);
+    collection.drop().await.ok();
+    db.create_collection(TABLE).await?;
+
+    let surreal_config = TestConfig::with_surreal_endpoint(test_id, &surrealdb.ws_endpoint());
+    let conn = connect_auto(&surreal_config).await?;
+    cleanup_auto(&conn, &[TABLE]).await?;
+
+    let source_opts = SourceOpts {
+        source_uri: container.connection_uri(),
+        source_database: Some(mongodb_database.clone()),
+        collections: vec![],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
+    };
+
+    // An unreadable token still starts from the checkpoint timestamp
+    let corrupted = MongoDBCheckpoint {
+        resume_token: vec![1, 2, 3],
+        timestamp: chrono::Utc::now() - chrono::Duration::seconds(1),
+    };
+    collection
+        .insert_one(doc! { "_id": "a", "name": "a" })
+        .await?;
+
+    incremental_sync(&conn, source_opts, corrupted, None).await?;
+    assert_eq!(count_named(&conn, "a").await?, 1);
+
+    cleanup_auto(&conn, &[TABLE]).await?;
+    db.drop().await?;
+    Ok(())
+}
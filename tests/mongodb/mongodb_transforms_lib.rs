@@ -131,11 +131,19 @@ async fn identity_and_external_mutate_incremental() -> Result<(), Box<dyn std::e
         source_uri: container.connection_uri(),
         source_database: Some(db_name.clone()),
         collections: vec!["people".to_string()],
+        bson_converters: Default::default(),
+        column_filters: Default::default(),
+        array_relations: Default::default(),
+        include_collections: vec![],
+        exclude_collections: vec![],
+        projection: None,
     };
     let sync_opts = SyncOpts {
         batch_size: 100,
         dry_run: false,
         schema: None,
+        conversion_workers: 1,
+        consistent_snapshot: false,
     };
 
     let sink = CaptureSink::new();
@@ -262,11 +270,19 @@ async fn external_mutate_full_sync_rows() -> Result<(), Box<dyn std::error::Erro
             source_uri: container.connection_uri(),
             source_database: Some(db_name),
             collections: vec!["people".to_string()],
+            bson_converters: Default::default(),
+            column_filters: Default::default(),
+            array_relations: Default::default(),
+            include_collections: vec![],
+            exclude_collections: vec![],
+            projection: None,
         },
         SyncOpts {
             batch_size: 100,
             dry_run: false,
             schema: None,
+            conversion_workers: 1,
+            consistent_snapshot: false,
         },
         None,
         &pipeline,
@@ -93,6 +93,8 @@ async fn test_neo4j_composite_full_sync_lib() -> Result<(), Box<dyn std::error::
         source_username: Some(neo4j_user.clone()),
         source_password: Some(neo4j_pass.clone()),
         labels: vec![],
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: "UTC".to_string(),
         neo4j_json_properties: Some(vec![
             "all_types_users.metadata".to_string(),
@@ -103,11 +105,13 @@ async fn test_neo4j_composite_full_sync_lib() -> Result<(), Box<dyn std::error::
         allow_empty_tracking_timestamp: false,
         id_property: "id".to_string(),
         composite_constituent: Some(alias_name.clone()),
+        relation_table_map: Default::default(),
     };
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: 1000,
         dry_run: false,
+        concurrency: 1,
     };
 
     match &conn {
@@ -57,6 +57,8 @@ async fn test_neo4j_full_sync_lib() -> Result<(), Box<dyn std::error::Error>> {
         source_username: Some(container.username.clone()),
         source_password: Some(container.password.clone()),
         labels: vec![],
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: "UTC".to_string(),
         neo4j_json_properties: Some(vec![
             "all_types_users.metadata".to_string(),
@@ -67,11 +69,13 @@ async fn test_neo4j_full_sync_lib() -> Result<(), Box<dyn std::error::Error>> {
         allow_empty_tracking_timestamp: false,
         id_property: "id".to_string(),
         composite_constituent: None,
+        relation_table_map: Default::default(),
     };
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: 1000,
         dry_run: false,
+        concurrency: 4,
     };
 
     // Execute full sync with appropriate sink based on detected version
@@ -64,6 +64,8 @@ async fn test_neo4j_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
         source_username: Some(container.username.clone()),
         source_password: Some(container.password.clone()),
         labels: vec![],
+        include_labels: None,
+        exclude_labels: None,
         neo4j_timezone: "UTC".to_string(),
         neo4j_json_properties: Some(vec![
             "all_types_users.metadata".to_string(),
@@ -74,11 +76,13 @@ async fn test_neo4j_incremental_sync_lib() -> Result<(), Box<dyn std::error::Err
         allow_empty_tracking_timestamp: false,
         id_property: "id".to_string(),
         composite_constituent: None,
+        relation_table_map: Default::default(),
     };
 
     let sync_opts = surreal_sync_neo4j_source::SyncOpts {
         batch_size: 1000,
         dry_run: false,
+        concurrency: 1,
     };
 
     // Run full sync with empty data to verify it works (no checkpoint tracking)
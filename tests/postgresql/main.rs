@@ -1,7 +1,9 @@
+mod postgresql_count_only_lib;
 mod postgresql_fk_incremental_test;
 mod postgresql_fk_sync_test;
 mod postgresql_full_sync_only_cli;
 mod postgresql_full_sync_only_lib;
 mod postgresql_incremental_sync_only_cli;
 mod postgresql_incremental_sync_only_lib;
+mod postgresql_list_tables_lib;
 mod postgresql_transforms_config_cli;
@@ -0,0 +1,63 @@
+//! PostgreSQL `--count-only` migration estimate E2E test
+
+use surreal_sync::testing::generate_test_id;
+
+#[tokio::test]
+async fn test_postgresql_estimate_tables() -> Result<(), Box<dyn std::error::Error>> {
+    let container = surreal_sync::testing::shared_containers::shared_postgres().await;
+    let test_id = generate_test_id();
+    let test_conn_str =
+        surreal_sync::testing::shared_containers::create_postgres_test_db(container, test_id)
+            .await?;
+
+    let (pg_client, pg_connection) =
+        tokio_postgres::connect(&test_conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        if let Err(e) = pg_connection.await {
+            eprintln!("PostgreSQL connection error: {e}");
+        }
+    });
+
+    pg_client
+        .batch_execute(
+            "CREATE TABLE estimate_orders (id INT PRIMARY KEY, note TEXT);
+             INSERT INTO estimate_orders SELECT g, repeat('x', 100) FROM generate_series(1, 500) g;
+             CREATE TABLE estimate_tags (id INT PRIMARY KEY);
+             INSERT INTO estimate_tags SELECT g FROM generate_series(1, 3) g;
+             CREATE TABLE surreal_sync_changes (id INT PRIMARY KEY);
+             ANALYZE estimate_orders;",
+        )
+        .await?;
+
+    let exact = surreal_sync_postgresql::estimate_tables(&pg_client, "public", &[], false).await?;
+    assert_eq!(exact.source, "postgresql");
+    let names: Vec<&str> = exact.tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["estimate_orders", "estimate_tags"]);
+    assert_eq!(exact.total_rows, 503);
+    assert!(exact.exact);
+    let orders = &exact.tables[0];
+    let bytes = orders.approx_bytes.expect("analyzed table has a size");
+    assert!(bytes >= 500 * 100, "orders estimated at {bytes} bytes");
+
+    let approximate = surreal_sync_postgresql::estimate_tables(
+        &pg_client,
+        "public",
+        &["estimate_orders".to_string()],
+        true,
+    )
+    .await?;
+    assert_eq!(approximate.tables.len(), 1);
+    assert_eq!(approximate.tables[0].rows, 500);
+    assert!(!approximate.exact);
+
+    let missing = surreal_sync_postgresql::estimate_tables(
+        &pg_client,
+        "public",
+        &["estimate_missing".to_string()],
+        false,
+    )
+    .await;
+    assert!(missing.is_err());
+
+    Ok(())
+}
@@ -68,7 +68,11 @@ async fn test_trigger_fk_incremental_only() -> Result<(), Box<dyn std::error::Er
         source_uri: test_conn_str.clone(),
         source_database: Some(format!("test_{test_id}")),
         tables: vec![],
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
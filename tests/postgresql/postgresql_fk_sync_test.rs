@@ -89,7 +89,11 @@ async fn test_postgresql_fk_full_sync() -> Result<(), Box<dyn std::error::Error>
         source_uri: test_conn_str.clone(),
         source_database: Some(format!("test_{test_id}")),
         tables: vec![],
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
     let sync_opts = surreal_sync_postgresql::SyncOpts {
         batch_size: 1000,
@@ -275,7 +279,11 @@ async fn test_postgresql_fk_config_override() -> Result<(), Box<dyn std::error::
         source_uri: test_conn_str.clone(),
         source_database: Some(format!("test_{test_id}")),
         tables: vec![],
+        table_targets: Default::default(),
         relation_tables: vec!["mentorship".to_string()],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
     let sync_opts = surreal_sync_postgresql::SyncOpts {
         batch_size: 1000,
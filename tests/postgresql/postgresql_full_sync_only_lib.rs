@@ -50,7 +50,11 @@ async fn test_postgresql_full_sync_lib() -> Result<(), Box<dyn std::error::Error
         source_uri: test_conn_str.clone(),
         source_database: Some(format!("test_{test_id}")),
         tables: vec![],
+        table_targets: Default::default(),
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
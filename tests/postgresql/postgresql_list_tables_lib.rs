@@ -0,0 +1,50 @@
+//! PostgreSQL table introspection E2E test
+
+use surreal_sync::testing::generate_test_id;
+use surreal_sync_core::SourceEntity;
+
+#[tokio::test]
+async fn test_postgresql_list_tables() -> Result<(), Box<dyn std::error::Error>> {
+    let container = surreal_sync::testing::shared_containers::shared_postgres().await;
+    let test_id = generate_test_id();
+    let test_conn_str =
+        surreal_sync::testing::shared_containers::create_postgres_test_db(container, test_id)
+            .await?;
+
+    let (pg_client, pg_connection) =
+        tokio_postgres::connect(&test_conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        if let Err(e) = pg_connection.await {
+            eprintln!("PostgreSQL connection error: {e}");
+        }
+    });
+
+    pg_client
+        .batch_execute(
+            "CREATE TABLE list_people (id INT PRIMARY KEY, name TEXT);
+             INSERT INTO list_people SELECT g, 'p' || g FROM generate_series(1, 25) g;
+             CREATE TABLE list_never_analyzed (id INT PRIMARY KEY);
+             CREATE TABLE surreal_sync_changes (id INT PRIMARY KEY);
+             CREATE VIEW list_people_view AS SELECT * FROM list_people;
+             ANALYZE list_people;",
+        )
+        .await?;
+
+    let tables = surreal_sync_postgresql::list_tables(&pg_client).await?;
+
+    assert!(
+        tables.contains(&SourceEntity::new("list_people", Some(25))),
+        "{tables:?}"
+    );
+    let never_analyzed = tables
+        .iter()
+        .find(|t| t.name == "list_never_analyzed")
+        .expect("unanalyzed table listed");
+    // PostgreSQL 14+ reports -1 (unknown) before the first ANALYZE; older
+    // versions report 0.
+    assert!(matches!(never_analyzed.approx_count, None | Some(0)));
+    assert!(tables.iter().all(|t| t.name != "surreal_sync_changes"));
+    assert!(tables.iter().all(|t| t.name != "list_people_view"));
+
+    Ok(())
+}
@@ -2,5 +2,6 @@ mod postgresql_logical_fk_incremental_test;
 mod postgresql_logical_full_sync_only_cli;
 mod postgresql_logical_full_sync_only_lib;
 mod postgresql_logical_incremental_sync_only_cli;
+mod postgresql_logical_pkless_table_test;
 mod postgresql_logical_surrealdb_checkpoints_cli;
 mod postgresql_logical_transforms_config_cli;
@@ -78,7 +78,14 @@ async fn test_wal2json_fk_incremental_only() -> Result<(), Box<dyn std::error::E
         slot_name: slot_name.clone(),
         tables: table_names.clone(),
         schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Default::default(),
     };
 
     let sync_opts = surreal_sync_postgresql::SyncOpts {
@@ -60,7 +60,14 @@ async fn test_postgresql_logical_full_sync_lib() -> Result<(), Box<dyn std::erro
         slot_name: "surreal_sync_lib_test_slot".to_string(),
         tables: table_names,
         schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
         relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Default::default(),
     };
 
     // Create SurrealDB sync options
@@ -0,0 +1,183 @@
+//! WAL/wal2json sync of a table without a primary key.
+//!
+//! Flow:
+//! 1. Create a PK-less table with rows in PostgreSQL
+//! 2. Full sync (records get ids synthesized from every column value)
+//! 3. Incremental sync fails while the table's REPLICA IDENTITY is DEFAULT
+//! 4. Set REPLICA IDENTITY FULL, UPDATE and DELETE rows, incremental sync
+//! 5. Verify the updated row replaced its old record and the deleted one is gone
+
+use surreal_sync::testing::surreal::{connect_auto, SurrealConnection};
+use surreal_sync::testing::{generate_test_id, TestConfig};
+
+#[tokio::test]
+async fn test_wal2json_pkless_table_update() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter("surreal_sync=info,surreal_sync_postgresql=info")
+        .try_init()
+        .ok();
+
+    let surrealdb = surreal_sync::testing::shared_containers::shared_surrealdb();
+    let container = surreal_sync::testing::shared_containers::shared_postgres().await;
+
+    let test_id = generate_test_id();
+    let test_conn_str =
+        surreal_sync::testing::shared_containers::create_postgres_test_db(container, test_id)
+            .await?;
+
+    let (pg_client, pg_conn) =
+        tokio_postgres::connect(&test_conn_str, tokio_postgres::NoTls).await?;
+    tokio::spawn(async move {
+        if let Err(e) = pg_conn.await {
+            eprintln!("PG connection error: {e}");
+        }
+    });
+
+    pg_client
+        .batch_execute(
+            "
+        CREATE TABLE events (kind TEXT NOT NULL, hits INT NOT NULL);
+        INSERT INTO events VALUES ('click', 1), ('view', 5);
+    ",
+        )
+        .await?;
+
+    let surreal_config = TestConfig::with_surreal_endpoint(test_id, &surrealdb.ws_endpoint());
+    let conn = connect_auto(&surreal_config).await?;
+
+    let checkpoint_dir = format!(".test-pkless-wal2json-{test_id}");
+    surreal_sync::testing::checkpoint::cleanup_checkpoint_dir(&checkpoint_dir)?;
+
+    let slot_name = format!("pkless_wal2json_{test_id}");
+    let source_opts = surreal_sync_postgresql::from_wal2json::SourceOpts {
+        connection_string: test_conn_str.clone(),
+        slot_name: slot_name.clone(),
+        tables: vec!["events".to_string()],
+        schema: "public".to_string(),
+        publication_name: None,
+        create_publication: false,
+        relation_tables: vec![],
+        keyset_columns: Default::default(),
+        parallel_reads: Default::default(),
+        column_filters: Default::default(),
+        apply_truncate: false,
+        format_version: Default::default(),
+    };
+    let sync_opts = surreal_sync_postgresql::SyncOpts {
+        batch_size: 1000,
+        dry_run: false,
+    };
+
+    let checkpoint_store =
+        surreal_sync_runtime::checkpoint_fs::FilesystemStore::new(&checkpoint_dir);
+    let sync_manager = surreal_sync_core::SyncManager::new(checkpoint_store);
+
+    match &conn {
+        SurrealConnection::V2(client) => {
+            let sink = surreal_sync_surreal::v2::Surreal2Sink::new(client.clone());
+            surreal_sync_postgresql::from_wal2json::run_full_sync(
+                &sink,
+                source_opts.clone(),
+                sync_opts.clone(),
+                Some(&sync_manager),
+            )
+            .await?;
+        }
+        SurrealConnection::V3(client) => {
+            let sink = surreal_sync_surreal::v3::Surreal3Sink::new(client.clone());
+            surreal_sync_postgresql::from_wal2json::run_full_sync(
+                &sink,
+                source_opts.clone(),
+                sync_opts.clone(),
+                Some(&sync_manager),
+            )
+            .await?;
+        }
+    }
+    assert_eq!(event_hits(&conn).await?, vec![1, 5]);
+
+    let checkpoint_file = surreal_sync_runtime::checkpoint_fs::get_checkpoint_for_phase(
+        &checkpoint_dir,
+        surreal_sync_core::SyncPhase::FullSyncStart,
+    )
+    .await?;
+    let sync_checkpoint: surreal_sync_postgresql::from_wal2json::PostgreSQLLogicalCheckpoint =
+        checkpoint_file.parse()?;
+
+    // Without REPLICA IDENTITY FULL updates cannot be matched to records.
+    let err = run_incremental(&conn, &source_opts, &sync_checkpoint)
+        .await
+        .expect_err("incremental sync should require REPLICA IDENTITY FULL");
+    assert!(
+        err.to_string().contains("REPLICA IDENTITY FULL"),
+        "unexpected error: {err}"
+    );
+
+    pg_client
+        .batch_execute(
+            "
+        ALTER TABLE events REPLICA IDENTITY FULL;
+        UPDATE events SET hits = 2 WHERE kind = 'click';
+        DELETE FROM events WHERE kind = 'view';
+    ",
+        )
+        .await?;
+    run_incremental(&conn, &source_opts, &sync_checkpoint).await?;
+
+    // The update moved `click` to the id of its new values; no stale record
+    // of the old row is left behind.
+    assert_eq!(event_hits(&conn).await?, vec![2]);
+
+    pg_client
+        .execute(
+            &format!("SELECT pg_drop_replication_slot('{slot_name}')"),
+            &[],
+        )
+        .await
+        .ok();
+
+    surreal_sync::testing::checkpoint::cleanup_checkpoint_dir(&checkpoint_dir)?;
+    Ok(())
+}
+
+async fn run_incremental(
+    conn: &SurrealConnection,
+    source_opts: &surreal_sync_postgresql::from_wal2json::SourceOpts,
+    checkpoint: &surreal_sync_postgresql::from_wal2json::PostgreSQLLogicalCheckpoint,
+) -> anyhow::Result<()> {
+    let deadline = chrono::Utc::now() + chrono::Duration::seconds(10);
+    match conn {
+        SurrealConnection::V2(client) => {
+            let sink = surreal_sync_surreal::v2::Surreal2Sink::new(client.clone());
+            surreal_sync_postgresql::from_wal2json::run_incremental_sync(
+                &sink,
+                source_opts.clone(),
+                checkpoint.clone(),
+                deadline,
+                None,
+            )
+            .await
+        }
+        SurrealConnection::V3(client) => {
+            let sink = surreal_sync_surreal::v3::Surreal3Sink::new(client.clone());
+            surreal_sync_postgresql::from_wal2json::run_incremental_sync(
+                &sink,
+                source_opts.clone(),
+                checkpoint.clone(),
+                deadline,
+                None,
+            )
+            .await
+        }
+    }
+}
+
+/// `hits` of every `events` record, ascending.
+async fn event_hits(conn: &SurrealConnection) -> Result<Vec<i64>, Box<dyn std::error::Error>> {
+    let query = "SELECT VALUE hits FROM events ORDER BY hits";
+    let hits = match conn {
+        SurrealConnection::V2(client) => client.query(query).await?.take(0)?,
+        SurrealConnection::V3(client) => client.query(query).await?.take(0)?,
+    };
+    Ok(hits)
+}
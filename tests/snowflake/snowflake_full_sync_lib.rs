@@ -120,6 +120,7 @@ async fn snowflake_full_sync_ingests_selected_table() {
             surreal_endpoint: sdb.ws_endpoint(),
             surreal_username: "root".to_string(),
             surreal_password: "root".to_string(),
+            write_retry: Default::default(),
         };
         let surreal = surreal_sync_surreal::v3::surreal_connect(&opts, &ns, &sdb_name)
             .await
@@ -133,6 +134,7 @@ async fn snowflake_full_sync_ingests_selected_table() {
             surreal_endpoint: sdb.ws_endpoint(),
             surreal_username: "root".to_string(),
             surreal_password: "root".to_string(),
+            write_retry: Default::default(),
         };
         let surreal = surreal_sync_surreal::v2::surreal_connect(&opts, &ns, &sdb_name)
             .await